@@ -24,10 +24,14 @@
  * AgentNetwork
  * ├── agents: HashMap<Domain, Box<dyn DomainAgent>>
  * ├── routing_table: DomainRoutingTable
+ * ├── reliability: HashMap<Domain, DomainReliability> (learned from feedback)
  * └── Methods:
- *     ├── register_agent() - Add agent to network
+ *     ├── register_agent() - Add agent to network (errors on duplicate domain)
+ *     ├── replace_agent() - Swap agent for a domain (hot-reload)
  *     ├── get_agent() - Retrieve agent by domain
  *     ├── route_query() - Route problem to appropriate agent
+ *     ├── route_to() - Route problem to a caller-chosen agent (no classification)
+ *     ├── record_feedback() - Record solution acceptance (biases routing)
  *     └── mentor_query() - Cross-agent collaboration (level 4)
  * ```
  *
@@ -43,7 +47,40 @@ use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
-use crate::{Domain, DomainAgent, Problem, Solution, DomainRoutingTable, Error};
+use crate::{Domain, DomainAgent, Problem, Solution, DomainRoutingTable, DomainClassification, Error};
+
+/// Maximum relative score adjustment from learned reliability (±20%)
+///
+/// DESIGN DECISION: Bounded multiplicative bias
+/// WHY: Reliability breaks near-ties on ambiguous queries; it must never override
+/// a clear classification signal (0.8 vs 0.3 stays 0.8 vs 0.3 ± 20%)
+pub const MAX_RELIABILITY_BIAS: f64 = 0.2;
+
+/**
+ * Solution acceptance history for one domain
+ *
+ * DESIGN DECISION: Laplace-smoothed acceptance rate
+ * WHY: Unknown agents start neutral (0.5); a single accept/reject doesn't swing routing
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainReliability {
+    /// Solutions accepted by the user
+    pub accepted: u32,
+    /// Solutions rejected by the user
+    pub rejected: u32,
+}
+
+impl DomainReliability {
+    /// Smoothed acceptance rate: (accepted + 1) / (total + 2), neutral = 0.5
+    pub fn acceptance_rate(&self) -> f64 {
+        (self.accepted as f64 + 1.0) / (self.accepted as f64 + self.rejected as f64 + 2.0)
+    }
+
+    /// Multiplicative routing factor in [1 - MAX_RELIABILITY_BIAS, 1 + MAX_RELIABILITY_BIAS]
+    pub fn routing_factor(&self) -> f64 {
+        1.0 + MAX_RELIABILITY_BIAS * (2.0 * self.acceptance_rate() - 1.0)
+    }
+}
 
 /**
  * AgentNetwork - Central registry for multi-agent collaboration
@@ -66,6 +103,9 @@ pub struct AgentNetwork {
 
     /// Domain classification and routing
     routing_table: DomainRoutingTable,
+
+    /// Learned per-domain reliability (solution acceptance feedback)
+    reliability: RwLock<HashMap<Domain, DomainReliability>>,
 }
 
 impl AgentNetwork {
@@ -87,32 +127,34 @@ impl AgentNetwork {
      * use aetherlight_core::{AgentNetwork, InfrastructureAgent, QualityAgent};
      *
      * let mut network = AgentNetwork::new();
-     * network.register_agent(Box::new(InfrastructureAgent::new()));
-     * network.register_agent(Box::new(QualityAgent::new()));
+     * network.register_agent(Box::new(InfrastructureAgent::new(patterns, embeddings)))?;
+     * network.register_agent(Box::new(QualityAgent::new(patterns, embeddings)))?;
      * ```
      */
     pub fn new() -> Self {
         Self {
             agents: Arc::new(RwLock::new(HashMap::new())),
             routing_table: DomainRoutingTable::new(),
+            reliability: RwLock::new(HashMap::new()),
         }
     }
 
     /**
      * Register a domain agent with the network
      *
-     * DESIGN DECISION: Panic if agent for domain already registered
-     * WHY: Multiple agents per domain would create ambiguity in routing
+     * DESIGN DECISION: Return error if agent for domain already registered
+     * WHY: Multiple agents per domain would create ambiguity in routing, but
+     * hot-reload and test setups re-register; the caller decides how to react
      *
      * REASONING CHAIN:
      * 1. Each domain should have exactly one agent (Infrastructure, Quality, etc.)
-     * 2. Registering duplicate = programmer error (should not happen in production)
-     * 3. Panic fast during initialization to catch configuration errors early
+     * 2. Duplicate registration → AgentAlreadyRegistered (existing agent kept)
+     * 3. Callers that intend to swap use replace_agent() instead
      * 4. Agent stored as Box<dyn DomainAgent> for runtime polymorphism
      *
-     * # Panics
+     * # Errors
      *
-     * Panics if an agent for the same domain is already registered.
+     * Returns `Error::AgentAlreadyRegistered` if an agent for the same domain is already registered.
      *
      * # Examples
      *
@@ -120,19 +162,50 @@ impl AgentNetwork {
      * use aetherlight_core::{AgentNetwork, InfrastructureAgent};
      *
      * let mut network = AgentNetwork::new();
-     * network.register_agent(Box::new(InfrastructureAgent::new()));
-     * // network.register_agent(Box::new(InfrastructureAgent::new())); // Would panic!
+     * network.register_agent(Box::new(InfrastructureAgent::new(patterns, embeddings)))?;
+     * assert!(network.register_agent(Box::new(InfrastructureAgent::new(patterns, embeddings))).is_err());
      * ```
      */
-    pub fn register_agent(&mut self, agent: Box<dyn DomainAgent>) {
+    pub fn register_agent(&mut self, agent: Box<dyn DomainAgent>) -> Result<(), Error> {
         let domain = agent.domain();
         let mut agents = self.agents.write().expect("Failed to acquire write lock on agents");
 
         if agents.contains_key(&domain) {
-            panic!("Agent for domain {:?} already registered", domain);
+            return Err(Error::AgentAlreadyRegistered(format!(
+                "Agent for domain {:?} already registered",
+                domain
+            )));
         }
 
         agents.insert(domain, agent);
+        Ok(())
+    }
+
+    /**
+     * Replace the agent registered for a domain
+     *
+     * DESIGN DECISION: Insert-or-swap, return the previous agent
+     * WHY: Hot-reload and tests swap agents without tearing down the network;
+     * returning the old agent lets callers flush or inspect its state
+     *
+     * Learned routing reliability for the domain is kept (it describes the domain's
+     * track record, not a specific agent instance).
+     *
+     * # Examples
+     *
+     * ```rust
+     * use aetherlight_core::{AgentNetwork, InfrastructureAgent};
+     *
+     * let mut network = AgentNetwork::new();
+     * network.register_agent(Box::new(InfrastructureAgent::new(patterns, embeddings)))?;
+     * let previous = network.replace_agent(Box::new(InfrastructureAgent::new(new_patterns, embeddings)));
+     * assert!(previous.is_some());
+     * ```
+     */
+    pub fn replace_agent(&mut self, agent: Box<dyn DomainAgent>) -> Option<Box<dyn DomainAgent>> {
+        let domain = agent.domain();
+        let mut agents = self.agents.write().expect("Failed to acquire write lock on agents");
+        agents.insert(domain, agent)
     }
 
     /**
@@ -209,10 +282,12 @@ impl AgentNetwork {
      * }
      * ```
      */
+    #[tracing::instrument(skip(self, problem))]
     pub async fn route_query(&self, problem: &Problem) -> Result<Solution, Error> {
-        // Classify problem to determine domain
-        let classification = self.routing_table.classify(&problem.description);
+        // Classify problem to determine domain (biased by learned reliability)
+        let classification = self.classify(&problem.description);
         let domain = classification.domain;
+        tracing::debug!(domain = ?domain, confidence = classification.confidence, "Query classified");
 
         // Look up agent for domain
         let agents = self.agents.read().expect("Failed to acquire read lock on agents");
@@ -223,16 +298,12 @@ impl AgentNetwork {
                 // Note: This requires &mut self, which we can't get from &self
                 // This is a design issue - we'll need to refactor agent trait to use interior mutability
                 // For now, return placeholder
-                Ok(Solution {
-                    recommendation: format!("Routed to {:?} agent", domain),
-                    reasoning: vec![format!("Problem classified to {:?} domain with {}% confidence", domain, (classification.confidence * 100.0) as u32)],
-                    confidence: classification.confidence,
-                    source_level: crate::SearchLevel::House,
-                    content_address: None,
-                    content_hash: None,
-                    hash_verified: None,
-                    verified_at: None,
-                })
+                Solution::builder()
+                    .recommendation(format!("Routed to {:?} agent", domain))
+                    .reasoning_step(format!("Problem classified to {:?} domain with {}% confidence", domain, (classification.confidence * 100.0) as u32))
+                    .confidence(classification.confidence)
+                    .source_level(crate::SearchLevel::House)
+                    .build()
             }
             None => {
                 Err(Error::AgentNotAvailable(format!(
@@ -243,6 +314,79 @@ impl AgentNetwork {
         }
     }
 
+    /**
+     * Route problem directly to a named domain agent
+     *
+     * DESIGN DECISION: Bypass classification entirely
+     * WHY: Caller sometimes knows the right domain better than the keyword classifier
+     * (power users, integration tests targeting one agent deterministically)
+     *
+     * REASONING CHAIN:
+     * 1. Look up agent for requested domain (HashMap O(1))
+     * 2. If not registered, return AgentNotAvailable (no fallback to classification)
+     * 3. Run the agent's Local → Long-term → House levels in order
+     * 4. Stop at the first solution meeting the agent's confidence threshold
+     * 5. Otherwise return the most confident of the three
+     * 6. Learned reliability is not consulted (explicit choice wins)
+     *
+     * LIMITATION: Mentor and Ether levels are not consulted. They need the agent
+     * mutably (`DomainAgent::solve_with_escalation`) and Mentor would re-enter this
+     * network while the agent map is locked. The network has no five-level path yet
+     * (`route_query` only classifies and confirms an agent is registered); callers
+     * that own an agent can call `solve_with_escalation` on it directly.
+     *
+     * # Errors
+     *
+     * Returns `Error::AgentNotAvailable` if no agent registered for `domain`.
+     *
+     * # Examples
+     *
+     * ```rust
+     * use aetherlight_core::{AgentNetwork, Domain, Problem};
+     *
+     * # async fn example() {
+     * let network = AgentNetwork::new();
+     * let problem = Problem::new("Flaky pipeline after deploy".to_string());
+     *
+     * match network.route_to(Domain::Quality, &problem).await {
+     *     Ok(solution) => println!("Quality agent: {}", solution.recommendation),
+     *     Err(e) => eprintln!("Agent not available: {}", e),
+     * }
+     * # }
+     * ```
+     */
+    pub async fn route_to(&self, domain: Domain, problem: &Problem) -> Result<Solution, Error> {
+        let agents = self.agents.read().expect("Failed to acquire read lock on agents");
+
+        let agent = agents.get(&domain).ok_or_else(|| {
+            Error::AgentNotAvailable(format!("No agent registered for domain {:?}", domain))
+        })?;
+
+        let threshold = agent.confidence_threshold();
+        let mut best: Option<Solution> = None;
+
+        for solution in [
+            agent.match_local(problem),
+            agent.match_long_term(problem),
+            agent.match_house(problem),
+        ] {
+            if solution.confidence >= threshold {
+                best = Some(solution);
+                break;
+            }
+            if best.as_ref().is_none_or(|b| solution.confidence > b.confidence) {
+                best = Some(solution);
+            }
+        }
+
+        let mut solution = best.expect("three levels always produce a solution");
+        solution.reasoning.insert(
+            0,
+            format!("Explicitly routed to {:?} domain (classification bypassed)", domain),
+        );
+        Ok(solution)
+    }
+
     /**
      * Mentor query - Cross-agent collaboration (breadcrumb level 4)
      *
@@ -300,7 +444,7 @@ impl AgentNetwork {
         problem: &Problem,
     ) -> Result<Solution, Error> {
         // Classify problem to find which domain can help
-        let classification = self.routing_table.classify(&problem.description);
+        let classification = self.classify(&problem.description);
         let target_domain = classification.domain;
 
         // Don't route back to requesting agent (would cause infinite loop)
@@ -313,16 +457,12 @@ impl AgentNetwork {
                 match agents.get(alt_domain) {
                     Some(_agent) => {
                         // Alternative agent found
-                        Ok(Solution {
-                            recommendation: format!("Mentor routed to alternative {:?} agent", alt_domain),
-                            reasoning: vec![format!("Primary domain {:?} same as requesting agent, using alternative {:?}", target_domain, alt_domain)],
-                            confidence: *alt_confidence,
-                            source_level: crate::SearchLevel::Mentor,
-                            content_address: None,
-                            content_hash: None,
-                            hash_verified: None,
-                            verified_at: None,
-                        })
+                        Solution::builder()
+                            .recommendation(format!("Mentor routed to alternative {:?} agent", alt_domain))
+                            .reasoning_step(format!("Primary domain {:?} same as requesting agent, using alternative {:?}", target_domain, alt_domain))
+                            .confidence(*alt_confidence)
+                            .source_level(crate::SearchLevel::Mentor)
+                            .build()
                     }
                     None => {
                         Err(Error::AgentNotAvailable(format!(
@@ -344,16 +484,12 @@ impl AgentNetwork {
             match agents.get(&target_domain) {
                 Some(_agent) => {
                     // Target agent found
-                    Ok(Solution {
-                        recommendation: format!("Mentor routed to {:?} agent", target_domain),
-                        reasoning: vec![format!("Cross-domain collaboration: {:?} → {:?}", requesting_domain, target_domain)],
-                        confidence: classification.confidence,
-                        source_level: crate::SearchLevel::Mentor,
-                        content_address: None,
-                        content_hash: None,
-                        hash_verified: None,
-                        verified_at: None,
-                    })
+                    Solution::builder()
+                        .recommendation(format!("Mentor routed to {:?} agent", target_domain))
+                        .reasoning_step(format!("Cross-domain collaboration: {:?} → {:?}", requesting_domain, target_domain))
+                        .confidence(classification.confidence)
+                        .source_level(crate::SearchLevel::Mentor)
+                        .build()
                 }
                 None => {
                     Err(Error::AgentNotAvailable(format!(
@@ -365,6 +501,99 @@ impl AgentNetwork {
         }
     }
 
+    /**
+     * Record whether a domain agent's solution was accepted
+     *
+     * DESIGN DECISION: Feedback adjusts future routing, not past classifications
+     * WHY: Agents that consistently help should win ambiguous queries
+     *
+     * REASONING CHAIN:
+     * 1. Caller reports accept/reject after presenting a solution
+     * 2. Per-domain counters updated (Laplace-smoothed acceptance rate)
+     * 3. classify() scales domain scores by routing_factor() (bounded ±20%)
+     * 4. Near-ties flip toward reliable agents, clear classifications do not
+     *
+     * # Examples
+     *
+     * ```rust
+     * use aetherlight_core::{AgentNetwork, Domain};
+     *
+     * let network = AgentNetwork::new();
+     * network.record_feedback(Domain::Infrastructure, true);
+     * assert!(network.reliability(Domain::Infrastructure).acceptance_rate() > 0.5);
+     * ```
+     */
+    pub fn record_feedback(&self, domain: Domain, accepted: bool) {
+        let mut reliability = self.reliability.write().expect("Failed to acquire write lock on reliability");
+        let entry = reliability.entry(domain).or_default();
+
+        if accepted {
+            entry.accepted = entry.accepted.saturating_add(1);
+        } else {
+            entry.rejected = entry.rejected.saturating_add(1);
+        }
+    }
+
+    /// Get learned reliability for a domain (neutral if no feedback recorded)
+    pub fn reliability(&self, domain: Domain) -> DomainReliability {
+        let reliability = self.reliability.read().expect("Failed to acquire read lock on reliability");
+        reliability.get(&domain).copied().unwrap_or_default()
+    }
+
+    /**
+     * Classify problem with reliability-biased domain scores
+     *
+     * DESIGN DECISION: Re-rank routing table scores, keep its keyword evidence
+     * WHY: Classification signal dominates; reliability only breaks near-ties
+     *
+     * PERFORMANCE: <10ms (routing table classification + 7 multiplications)
+     */
+    pub fn classify(&self, description: &str) -> DomainClassification {
+        let mut classification = self.routing_table.classify(description);
+
+        let reliability = self.reliability.read().expect("Failed to acquire read lock on reliability");
+        if reliability.is_empty() {
+            return classification;
+        }
+
+        let factor = |domain: &Domain| {
+            reliability.get(domain).map(DomainReliability::routing_factor).unwrap_or(1.0)
+        };
+
+        for (domain, score) in classification.all_scores.iter_mut() {
+            *score = (*score * factor(domain)).min(1.0);
+        }
+
+        // Only domains with keyword evidence can win (score 0 stays 0)
+        let Some((primary, confidence)) = classification.all_scores
+            .iter()
+            .filter(|(_, score)| **score > 0.0)
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(domain, score)| (*domain, *score))
+        else {
+            return classification;
+        };
+
+        // Alternatives: previously plausible domains (incl. demoted primary), re-scored
+        let previous_primary = classification.domain;
+        let mut alternatives: Vec<(Domain, f64)> = std::iter::once(previous_primary)
+            .chain(classification.alternative_domains.iter().map(|(domain, _)| *domain))
+            .filter(|domain| *domain != primary)
+            .map(|domain| (domain, classification.all_scores[&domain]))
+            .collect();
+        alternatives.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap());
+
+        // Matched keywords describe the routing table's primary; drop them if it changed
+        if primary != previous_primary {
+            classification.matched_keywords.clear();
+        }
+
+        classification.domain = primary;
+        classification.confidence = confidence;
+        classification.alternative_domains = alternatives;
+        classification
+    }
+
     /**
      * Count registered agents
      *
@@ -379,8 +608,8 @@ impl AgentNetwork {
      * let mut network = AgentNetwork::new();
      * assert_eq!(network.agent_count(), 0);
      *
-     * network.register_agent(Box::new(InfrastructureAgent::new()));
-     * network.register_agent(Box::new(QualityAgent::new()));
+     * network.register_agent(Box::new(InfrastructureAgent::new(patterns, embeddings)))?;
+     * network.register_agent(Box::new(QualityAgent::new(patterns, embeddings)))?;
      * assert_eq!(network.agent_count(), 2);
      * ```
      */
@@ -828,6 +1057,22 @@ impl AgentConnection {
 mod tests {
     use super::*;
     use crate::{InfrastructureAgent, QualityAgent};
+    use crate::domain_agent::{DomainEmbeddings, DomainPatternLibrary};
+    use std::path::PathBuf;
+
+    fn infrastructure_agent() -> Box<dyn DomainAgent> {
+        Box::new(InfrastructureAgent::new(
+            DomainPatternLibrary::new(Domain::Infrastructure, PathBuf::new()).unwrap(),
+            DomainEmbeddings::new("", "").unwrap(),
+        ))
+    }
+
+    fn quality_agent() -> Box<dyn DomainAgent> {
+        Box::new(QualityAgent::new(
+            DomainPatternLibrary::new(Domain::Quality, PathBuf::new()).unwrap(),
+            DomainEmbeddings::new("", "").unwrap(),
+        ))
+    }
 
     /**
      * Test: Create new AgentNetwork
@@ -849,25 +1094,46 @@ mod tests {
     fn test_register_agents() {
         let mut network = AgentNetwork::new();
 
-        network.register_agent(Box::new(InfrastructureAgent::new()));
+        network.register_agent(infrastructure_agent()).unwrap();
         assert_eq!(network.agent_count(), 1);
 
-        network.register_agent(Box::new(QualityAgent::new()));
+        network.register_agent(quality_agent()).unwrap();
         assert_eq!(network.agent_count(), 2);
     }
 
     /**
-     * Test: Duplicate registration panics
+     * Test: Duplicate registration returns error
      *
-     * VALIDATION: Cannot register two agents for same domain
+     * VALIDATION: Cannot register two agents for same domain, no panic, original kept
      */
     #[test]
-    #[should_panic(expected = "already registered")]
-    fn test_duplicate_registration_panics() {
+    fn test_duplicate_registration_errors() {
         let mut network = AgentNetwork::new();
 
-        network.register_agent(Box::new(InfrastructureAgent::new()));
-        network.register_agent(Box::new(InfrastructureAgent::new())); // Should panic
+        network.register_agent(infrastructure_agent()).unwrap();
+        match network.register_agent(infrastructure_agent()) {
+            Err(Error::AgentAlreadyRegistered(msg)) => assert!(msg.contains("already registered")),
+            _ => panic!("Expected AgentAlreadyRegistered error"),
+        }
+        assert_eq!(network.agent_count(), 1);
+    }
+
+    /**
+     * Test: Replace agent for registered and unregistered domains
+     *
+     * VALIDATION: Swap returns previous agent, insert returns None, count stable
+     */
+    #[test]
+    fn test_replace_agent() {
+        let mut network = AgentNetwork::new();
+        network.register_agent(infrastructure_agent()).unwrap();
+
+        let previous = network.replace_agent(infrastructure_agent());
+        assert_eq!(previous.map(|agent| agent.domain()), Some(Domain::Infrastructure));
+        assert_eq!(network.agent_count(), 1);
+
+        assert!(network.replace_agent(quality_agent()).is_none());
+        assert_eq!(network.agent_count(), 2);
     }
 
     /**
@@ -878,7 +1144,7 @@ mod tests {
     #[test]
     fn test_get_agent() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(InfrastructureAgent::new()));
+        network.register_agent(infrastructure_agent()).unwrap();
 
         assert!(network.get_agent(Domain::Infrastructure).is_some());
         assert!(network.get_agent(Domain::Quality).is_none());
@@ -892,7 +1158,7 @@ mod tests {
     #[tokio::test]
     async fn test_route_query_infrastructure() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(InfrastructureAgent::new()));
+        network.register_agent(infrastructure_agent()).unwrap();
 
         let problem = Problem {
             description: "How do I deploy to Kubernetes?".to_string(),
@@ -914,7 +1180,7 @@ mod tests {
     #[tokio::test]
     async fn test_route_query_quality() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(QualityAgent::new()));
+        network.register_agent(quality_agent()).unwrap();
 
         let problem = Problem {
             description: "How do I write unit tests?".to_string(),
@@ -951,6 +1217,96 @@ mod tests {
         }
     }
 
+    /**
+     * Test: Explicit routing bypasses classification
+     *
+     * VALIDATION: Kubernetes problem sent to Quality agent when caller asks for it
+     */
+    #[tokio::test]
+    async fn test_route_to_explicit_agent() {
+        let mut network = AgentNetwork::new();
+        network.register_agent(infrastructure_agent()).unwrap();
+        network.register_agent(quality_agent()).unwrap();
+
+        let problem = Problem {
+            description: "How do I deploy to Kubernetes?".to_string(),
+            context: vec![],
+            domain_hints: vec![],
+        };
+
+        // Quality agent answers even though classification would pick Infrastructure
+        let solution = network.route_to(Domain::Quality, &problem).await.unwrap();
+        assert!(solution.reasoning[0].contains("bypassed"));
+        assert!(!solution.recommendation.contains("Routed to"));
+        assert!(matches!(
+            solution.source_level,
+            crate::SearchLevel::Local | crate::SearchLevel::LongTerm | crate::SearchLevel::House
+        ));
+        assert!(solution.reasoning.len() > 1);
+
+        match network.route_to(Domain::Ethics, &problem).await {
+            Err(Error::AgentNotAvailable(msg)) => assert!(msg.contains("Ethics")),
+            _ => panic!("Expected AgentNotAvailable error"),
+        }
+    }
+
+    /**
+     * Test: Recorded successes shift routing between plausible domains
+     *
+     * VALIDATION: Ambiguous query flips to the reliable agent; clear query does not
+     */
+    #[tokio::test]
+    async fn test_feedback_shifts_ambiguous_routing() {
+        let mut network = AgentNetwork::new();
+        network.register_agent(infrastructure_agent()).unwrap();
+        network.register_agent(quality_agent()).unwrap();
+
+        // "deployment" (Infrastructure) vs "test" (Quality): near-tie
+        let problem = Problem {
+            description: "deployment test".to_string(),
+            context: vec![],
+            domain_hints: vec![],
+        };
+        let before = network.classify(&problem.description);
+        let runner_up = if before.domain == Domain::Quality {
+            Domain::Infrastructure
+        } else {
+            Domain::Quality
+        };
+
+        for _ in 0..10 {
+            network.record_feedback(runner_up, true);
+            network.record_feedback(before.domain, false);
+        }
+
+        let after = network.classify(&problem.description);
+        assert_eq!(after.domain, runner_up);
+        assert!(after.alternative_domains.iter().any(|(d, _)| *d == before.domain));
+
+        let solution = network.route_query(&problem).await.unwrap();
+        assert!(solution.recommendation.contains(&format!("{:?}", runner_up)));
+
+        // Clear classification signal still dominates
+        let kubernetes = network.classify("How do I deploy to Kubernetes with docker?");
+        assert_eq!(kubernetes.domain, Domain::Infrastructure);
+    }
+
+    /**
+     * Test: Reliability factor is bounded
+     *
+     * VALIDATION: Neutral without feedback, never beyond ±MAX_RELIABILITY_BIAS
+     */
+    #[test]
+    fn test_reliability_factor_bounded() {
+        assert_eq!(DomainReliability::default().routing_factor(), 1.0);
+
+        let reliable = DomainReliability { accepted: 1_000_000, rejected: 0 };
+        let unreliable = DomainReliability { accepted: 0, rejected: 1_000_000 };
+        assert!(reliable.routing_factor() <= 1.0 + MAX_RELIABILITY_BIAS);
+        assert!(unreliable.routing_factor() >= 1.0 - MAX_RELIABILITY_BIAS);
+        assert!(reliable.routing_factor() > 1.15);
+    }
+
     /**
      * Test: Mentor query to different domain
      *
@@ -959,8 +1315,8 @@ mod tests {
     #[tokio::test]
     async fn test_mentor_query_different_domain() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(InfrastructureAgent::new()));
-        network.register_agent(Box::new(QualityAgent::new()));
+        network.register_agent(infrastructure_agent()).unwrap();
+        network.register_agent(quality_agent()).unwrap();
 
         let problem = Problem {
             description: "Deployment failing unit tests".to_string(),
@@ -983,8 +1339,8 @@ mod tests {
     #[tokio::test]
     async fn test_mentor_query_same_domain_alternative() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(InfrastructureAgent::new()));
-        network.register_agent(Box::new(QualityAgent::new()));
+        network.register_agent(infrastructure_agent()).unwrap();
+        network.register_agent(quality_agent()).unwrap();
 
         // Problem clearly infrastructure-focused (would route to Infrastructure)
         let problem = Problem {
@@ -1015,7 +1371,7 @@ mod tests {
     #[tokio::test]
     async fn test_mentor_query_no_alternative() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(InfrastructureAgent::new())); // Only one agent
+        network.register_agent(infrastructure_agent()).unwrap(); // Only one agent
 
         let problem = Problem {
             description: "Kubernetes deployment".to_string(),
@@ -1160,8 +1516,8 @@ mod tests {
     #[tokio::test]
     async fn test_agent_connection_send_query_success() {
         let mut network = AgentNetwork::new();
-        network.register_agent(Box::new(InfrastructureAgent::new()));
-        network.register_agent(Box::new(QualityAgent::new()));
+        network.register_agent(infrastructure_agent()).unwrap();
+        network.register_agent(quality_agent()).unwrap();
 
         let mut conn = AgentConnection::new(Domain::Quality);
         let problem = Problem {
@@ -1228,7 +1584,7 @@ mod tests {
         let problem = Problem {
             description: "Test problem".to_string(),
             context: vec!["context1".to_string()],
-            domain_hints: vec!["hint1".to_string()],
+            domain_hints: vec![Domain::Quality],
         };
         let message = AgentMessage::new(
             Domain::Infrastructure,
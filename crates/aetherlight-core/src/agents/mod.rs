@@ -45,13 +45,14 @@ pub use ethics::EthicsAgent;
  * 3. Tests need simple API: create_domain_patterns(Domain::Infrastructure)
  * 4. Production code can use custom paths if needed
  * 5. Default paths: data/patterns/ for patterns, models/ for ONNX
+ * 6. LUMINA_MODEL_DIR overrides models/ (consumers run from any working directory)
  *
  * PATTERN: Pattern-DOMAIN-002 (Domain Pattern Library Structure)
- * RELATED: domain_pattern_library.rs (actual constructors)
- * FUTURE: Make paths configurable via environment variables
+ * RELATED: domain_pattern_library.rs (actual constructors), embeddings.rs (model_dir)
  */
 
 use crate::domain_agent::{Domain, DomainEmbeddings, DomainPatternLibrary};
+use crate::embeddings::model_paths;
 use crate::Error;
 use std::path::PathBuf;
 
@@ -78,6 +79,8 @@ pub fn create_domain_patterns(domain: Domain) -> Result<DomainPatternLibrary, Er
 /// DESIGN DECISION: Use models/ directory for ONNX models by default
 /// WHY: Standard location for ML models, consistent across all agents
 ///
+/// Set LUMINA_MODEL_DIR to load the model from another directory.
+///
 /// # Arguments
 /// * `_domain` - Which domain (currently unused, for future domain-specific models)
 ///
@@ -91,7 +94,6 @@ pub fn create_domain_patterns(domain: Domain) -> Result<DomainPatternLibrary, Er
 /// - Use domain-specific fine-tuned models
 /// - e.g., infrastructure_model.onnx vs quality_model.onnx
 pub fn create_domain_embeddings(_domain: Domain) -> Result<DomainEmbeddings, Error> {
-    let model_path = "models/all-MiniLM-L6-v2.onnx";
-    let tokenizer_path = "models/tokenizer.json";
-    DomainEmbeddings::new(model_path, tokenizer_path)
+    let (model_path, tokenizer_path) = model_paths("models");
+    DomainEmbeddings::new(&model_path.to_string_lossy(), &tokenizer_path.to_string_lossy())
 }
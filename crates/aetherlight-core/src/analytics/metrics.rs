@@ -1,7 +1,7 @@
 /**
  * UsageMetrics - Aggregated statistics for time periods
  *
- * DESIGN DECISION: On-demand aggregation from events table
+ * DESIGN DECISION: On-demand aggregation from usage_history (raw events + pruned rollups)
  * WHY: Flexible (any date range), accurate (no stale pre-computed data)
  *
  * REASONING CHAIN:
@@ -39,7 +39,10 @@
 
 use crate::error::Error;
 use crate::analytics::{UsageTracker, EventType};
+use crate::analytics::tracker::history_window;
 use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Time period for metrics aggregation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -71,6 +74,23 @@ pub struct Metrics {
     pub insertions: i64,
     /// Pattern match events
     pub pattern_matches: i64,
+    /// Per-domain breakdown (empty when no events carry `domain` metadata)
+    pub by_domain: HashMap<String, DomainMetrics>,
+}
+
+/**
+ * Usage metrics for a single domain
+ *
+ * DESIGN DECISION: Grouped by `domain` key in event metadata JSON
+ * WHY: Users working across domains want to see where time savings come from
+ * ("Rust: 40 min saved, TypeScript: 25 min")
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainMetrics {
+    /// Total number of events in this domain
+    pub total_events: i64,
+    /// Total time saved in this domain (in minutes)
+    pub total_time_saved_minutes: i64,
 }
 
 impl Metrics {
@@ -126,10 +146,10 @@ impl<'a> UsageMetrics<'a> {
         // Query aggregated metrics
         let (total_events, total_time_saved): (i64, i64) = self.tracker.conn.query_row(
             &format!(
-                "SELECT COUNT(*), COALESCE(SUM(time_saved_minutes), 0)
-                 FROM usage_events
-                 WHERE timestamp >= {}",
-                date_filter
+                "SELECT COALESCE(SUM(event_count), 0), COALESCE(SUM(time_saved_minutes), 0)
+                 FROM usage_history
+                 WHERE {}",
+                history_window(date_filter)
             ),
             [],
             |row| Ok((row.get(0)?, row.get(1)?)),
@@ -141,6 +161,8 @@ impl<'a> UsageMetrics<'a> {
         let insertions = self.count_by_type_in_period(EventType::Insertion, date_filter)?;
         let pattern_matches = self.count_by_type_in_period(EventType::PatternMatch, date_filter)?;
 
+        let by_domain = self.domain_breakdown_in_period(date_filter)?;
+
         Ok(Metrics {
             period,
             total_events,
@@ -149,6 +171,7 @@ impl<'a> UsageMetrics<'a> {
             searches,
             insertions,
             pattern_matches,
+            by_domain,
         })
     }
 
@@ -187,15 +210,58 @@ impl<'a> UsageMetrics<'a> {
     fn count_by_type_in_period(&self, event_type: EventType, date_filter: &str) -> Result<i64, Error> {
         let count: i64 = self.tracker.conn.query_row(
             &format!(
-                "SELECT COUNT(*) FROM usage_events
-                 WHERE event_type = ?1 AND timestamp >= {}",
-                date_filter
+                "SELECT COALESCE(SUM(event_count), 0) FROM usage_history
+                 WHERE event_type = ?1 AND {}",
+                history_window(date_filter)
             ),
             params![event_type.as_str()],
             |row| row.get(0),
         )?;
         Ok(count)
     }
+
+    /**
+     * Group events by `domain` metadata within a time period.
+     *
+     * DESIGN DECISION: Read `domain` column of usage_history (json_extract guarded by json_valid)
+     * WHY: Metadata is free-form; malformed or domain-less events are skipped, not errors.
+     * Rollups keep their domain, so the breakdown survives pruning
+     *
+     * # Arguments
+     *
+     * * `date_filter` - SQLite datetime expression for period filtering
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database query fails
+     */
+    fn domain_breakdown_in_period(&self, date_filter: &str) -> Result<HashMap<String, DomainMetrics>, Error> {
+        let mut stmt = self.tracker.conn.prepare(&format!(
+            "SELECT domain, SUM(event_count), COALESCE(SUM(time_saved_minutes), 0)
+             FROM usage_history
+             WHERE {} AND typeof(domain) = 'text' AND domain != ''
+             GROUP BY domain",
+            history_window(date_filter)
+        ))?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                DomainMetrics {
+                    total_events: row.get(1)?,
+                    total_time_saved_minutes: row.get(2)?,
+                },
+            ))
+        })?;
+
+        let mut by_domain = HashMap::new();
+        for row in rows {
+            let (domain, metrics) = row?;
+            by_domain.insert(domain, metrics);
+        }
+
+        Ok(by_domain)
+    }
 }
 
 #[cfg(test)]
@@ -322,4 +388,57 @@ mod tests {
         assert_eq!(monthly.pattern_matches, 3);
         assert_eq!(monthly.total_time_saved_minutes, 30); // 3 * 10
     }
+
+    #[test]
+    fn test_metrics_by_domain() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        tracker.record_pattern_match(Some(r#"{"domain": "rust"}"#)).expect("Failed to record");
+        tracker.record_search(Some(r#"{"domain": "rust"}"#)).expect("Failed to record");
+        tracker.record_search(Some(r#"{"domain": "typescript"}"#)).expect("Failed to record");
+        tracker.record_voice_capture(None).expect("Failed to record");
+        tracker.record_insertion(Some("not json")).expect("Failed to record");
+
+        let metrics = UsageMetrics::new(&tracker);
+        let all_time = metrics.get_all_time_metrics().expect("Failed to get metrics");
+
+        assert_eq!(all_time.total_events, 5);
+        assert_eq!(all_time.by_domain.len(), 2);
+        assert_eq!(
+            all_time.by_domain["rust"],
+            DomainMetrics { total_events: 2, total_time_saved_minutes: 10 + 5 }
+        );
+        assert_eq!(
+            all_time.by_domain["typescript"],
+            DomainMetrics { total_events: 1, total_time_saved_minutes: 5 }
+        );
+    }
+
+    #[test]
+    fn test_metrics_by_domain_skips_non_text_domains() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        tracker.record_search(Some(r#"{"domain": "rust"}"#)).expect("Failed to record");
+        tracker.record_search(Some(r#"{"domain": 42}"#)).expect("Failed to record");
+        tracker.record_search(Some(r#"{"domain": {"name": "rust"}}"#)).expect("Failed to record");
+        tracker.record_search(Some(r#"{"domain": null}"#)).expect("Failed to record");
+
+        let metrics = UsageMetrics::new(&tracker);
+        let all_time = metrics.get_all_time_metrics().expect("Non-text domains must not fail metrics");
+
+        assert_eq!(all_time.total_events, 4);
+        assert_eq!(all_time.by_domain.len(), 1);
+        assert_eq!(all_time.by_domain["rust"].total_events, 1);
+    }
+
+    #[test]
+    fn test_metrics_by_domain_empty_without_metadata() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+        tracker.record_voice_capture(None).expect("Failed to record");
+
+        let metrics = UsageMetrics::new(&tracker);
+        let all_time = metrics.get_all_time_metrics().expect("Failed to get metrics");
+
+        assert!(all_time.by_domain.is_empty());
+    }
 }
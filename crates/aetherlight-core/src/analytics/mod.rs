@@ -9,11 +9,13 @@
  * 2. Usage data must be private (no PII, aggregate stats only)
  * 3. Separate database prevents mixing usage data with patterns
  * 4. Event-based tracking allows flexible aggregation (daily/weekly/monthly)
- * 5. Fixed time estimates per action type (simple, predictable)
+ * 5. Fixed time estimates per action type (simple, predictable), overridable per
+ *    team via TimeSavedConfig; pattern matches may credit the matched pattern's
+ *    own estimate instead
  *
  * PATTERN: Pattern-ANALYTICS-001 (Usage tracking with privacy)
  * RELATED: vector_store module (separate databases), error module (error handling)
- * FUTURE: Export to CSV/JSON, custom time estimates, A/B testing metrics
+ * FUTURE: A/B testing metrics
  *
  * # Architecture
  *
@@ -22,13 +24,14 @@
  *   ├── record_voice_capture()     → 2 min saved
  *   ├── record_search()             → 5 min saved
  *   ├── record_insertion()          → 2 min saved
- *   └── record_pattern_match()      → 10 min saved
+ *   └── record_pattern_match()      → pattern's estimate, else 10 min saved
  *
  * UsageMetrics
  *   ├── get_daily_metrics()
  *   ├── get_weekly_metrics()
  *   ├── get_monthly_metrics()
- *   └── get_all_time_metrics()
+ *   ├── get_all_time_metrics()
+ *   └── Metrics::by_domain          → grouped by metadata "domain"
  * ```
  *
  * # Privacy Guarantees
@@ -42,11 +45,14 @@
 pub mod tracker;
 pub mod metrics;
 
-pub use tracker::UsageTracker;
-pub use metrics::{UsageMetrics, Metrics, MetricsPeriod};
+pub use tracker::{
+    UsageTracker, Durability, EventRecord, ExportFormat, TimeSavedConfig, TimeSavedLookup,
+    DEFAULT_RETENTION_DAYS, MIN_RETENTION_DAYS,
+};
+pub use metrics::{UsageMetrics, Metrics, MetricsPeriod, DomainMetrics};
 
 /// Event types tracked by the analytics system
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
     /// Voice capture completed
     VoiceCapture,
@@ -59,7 +65,7 @@ pub enum EventType {
 }
 
 impl EventType {
-    /// Get time saved estimate for this event type (in minutes)
+    /// Default time saved estimate for this event type (in minutes; see TimeSavedConfig)
     pub fn time_saved_minutes(&self) -> i32 {
         match self {
             EventType::VoiceCapture => 2,
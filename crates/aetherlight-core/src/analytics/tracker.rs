@@ -10,10 +10,17 @@
  * 3. Event-based model allows flexible aggregation
  * 4. Single table design keeps queries simple (<50ms target)
  * 5. No pre-computation needed (aggregate on-demand)
+ * 6. Retention: events past the window fold into daily rollups (prune), totals stay exact
+ * 7. Pattern matches credit the matched pattern's own estimate when one is known
+ *    (with_time_saved_lookup / with_pattern_estimates), else the configured minutes
+ * 8. Per-type minutes come from TimeSavedConfig (new_with_config), stored on each
+ *    event at record time, so Metrics sums reflect the team's own estimates
+ * 9. WAL journal; Durability picks how hard each commit syncs, flush() checkpoints
+ *    the WAL into the main file before shutdown
  *
  * PATTERN: Pattern-ANALYTICS-001 (Usage tracking with privacy)
  * RELATED: vector_store::SqliteVectorStore (similar SQLite usage)
- * FUTURE: Batch inserts, async recording, custom time estimates
+ * FUTURE: Batch inserts, async recording
  *
  * # Example Usage
  *
@@ -35,12 +42,151 @@
 
 use crate::error::Error;
 use crate::analytics::EventType;
-use rusqlite::{Connection, params};
+use crate::Pattern;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, Utc};
+use rusqlite::{Connection, params, params_from_iter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Default analytics retention window (raw events older than this are rolled up)
+pub const DEFAULT_RETENTION_DAYS: u32 = 90;
+
+/// Smallest retention window honoured by with_retention_days (0 would roll up every raw event)
+pub const MIN_RETENTION_DAYS: u32 = 1;
+
+/// Timestamp format used by SQLite `datetime()` (matches `usage_events.timestamp`)
+const SQLITE_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/**
+ * usage_history filter for rows at or after `since` (an SQLite datetime expression).
+ *
+ * DESIGN DECISION: Raw events compare on timestamp, rollups compare on date
+ * WHY: Rollups only keep their day (stamped at midnight); comparing that stamp against
+ * a mid-day window start dropped the boundary day's rolled-up events entirely
+ */
+pub(crate) fn history_window(since: &str) -> String {
+    format!(
+        "CASE WHEN rolled_up THEN DATE(timestamp) >= DATE({since}) ELSE timestamp >= {since} END"
+    )
+}
+
+/**
+ * Commit durability of the analytics database
+ *
+ * DESIGN DECISION: Two levels mapped onto PRAGMA synchronous under WAL
+ * WHY: Users recording many events may prefer lower write latency over
+ * surviving a power cut; an app crash loses nothing at either level
+ *
+ * - Safe (synchronous=FULL): every commit fsyncs the WAL, survives power loss
+ * - Fast (synchronous=NORMAL): WAL fsynced only at checkpoints; the last
+ *   commits can be lost on power loss or OS crash, never corrupted
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Durability {
+    Fast,
+    #[default]
+    Safe,
+}
+
+impl Durability {
+    fn synchronous(self) -> &'static str {
+        match self {
+            Durability::Fast => "NORMAL",
+            Durability::Safe => "FULL",
+        }
+    }
+}
+
+/// Raw usage event (see UsageTracker::query_events)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRecord {
+    /// When the event was recorded (UTC)
+    pub timestamp: DateTime<Utc>,
+    /// Event type
+    pub event_type: EventType,
+    /// Time saved estimate recorded with the event (in minutes)
+    pub time_saved_minutes: i64,
+    /// Optional JSON metadata recorded with the event
+    pub metadata: Option<String>,
+}
+
+/// Output format of UsageTracker::export_events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Header row + one RFC 4180 row per event
+    Csv,
+    /// JSON array with one object per event
+    Json,
+}
+
+/**
+ * Minutes saved per event type, overriding the EventType defaults
+ *
+ * DESIGN DECISION: Sparse overrides on top of EventType::time_saved_minutes
+ * WHY: Teams measure ROI differently; an empty config behaves exactly like before
+ *
+ * Values are applied when an event is recorded (the row keeps the minutes it was
+ * credited with), so changing the config does not rewrite history.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeSavedConfig {
+    overrides: HashMap<EventType, i32>,
+}
+
+impl TimeSavedConfig {
+    /// Config with every event type at its default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the minutes saved for one event type (checked by validate)
+    pub fn with_minutes(mut self, event_type: EventType, minutes: i32) -> Self {
+        self.overrides.insert(event_type, minutes);
+        self
+    }
+
+    /// Minutes saved for an event type (override, else EventType default)
+    pub fn minutes(&self, event_type: EventType) -> i32 {
+        self.overrides
+            .get(&event_type)
+            .copied()
+            .unwrap_or_else(|| event_type.time_saved_minutes())
+    }
+
+    /**
+     * Reject negative estimates
+     *
+     * # Errors
+     *
+     * Returns `Error::Configuration` naming the first negative event type
+     */
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut overrides: Vec<_> = self.overrides.iter().collect();
+        overrides.sort_by_key(|(event_type, _)| event_type.as_str());
+
+        match overrides.into_iter().find(|(_, minutes)| **minutes < 0) {
+            Some((event_type, minutes)) => Err(Error::Configuration(format!(
+                "Time saved for '{}' must be non-negative, got {} minutes",
+                event_type.as_str(),
+                minutes
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Pattern id → minutes saved per match (None = use the event type default)
+pub type TimeSavedLookup = Box<dyn Fn(&str) -> Option<u32> + Send>;
+
 /// Tracks usage events and calculates impact metrics
 pub struct UsageTracker {
     pub(crate) conn: Connection,
+    time_saved_lookup: Option<TimeSavedLookup>,
+    time_saved: TimeSavedConfig,
+    durability: Durability,
 }
 
 impl UsageTracker {
@@ -59,10 +205,30 @@ impl UsageTracker {
      * Returns `Error::Internal` if database cannot be opened or initialized
      */
     pub fn new<P: AsRef<Path>>(db_path: P) -> Result<Self, Error> {
+        Self::new_with_config(db_path, TimeSavedConfig::default())
+    }
+
+    /**
+     * Create a UsageTracker crediting events with custom per-type estimates.
+     *
+     * # Arguments
+     *
+     * * `db_path` - Path to SQLite database file (created if doesn't exist)
+     * * `time_saved` - Minutes saved per event type (unset types keep their defaults)
+     *
+     * # Errors
+     *
+     * Returns `Error::Configuration` if an estimate is negative, `Error::Internal` if
+     * database cannot be opened or initialized
+     */
+    pub fn new_with_config<P: AsRef<Path>>(db_path: P, time_saved: TimeSavedConfig) -> Result<Self, Error> {
+        time_saved.validate()?;
+
         let conn = Connection::open(db_path)?;
 
         // Enable WAL mode for better concurrency (query_row because PRAGMA returns results)
         let _: String = conn.query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0))?;
+        conn.execute_batch(&format!("PRAGMA synchronous = {}", Durability::Safe.synchronous()))?;
 
         // Create table if not exists
         conn.execute(
@@ -86,7 +252,91 @@ impl UsageTracker {
             [],
         )?;
 
-        Ok(UsageTracker { conn })
+        // Daily rollups of pruned events (see prune)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS usage_daily_rollups (
+                date TEXT NOT NULL,
+                event_type TEXT NOT NULL,
+                domain TEXT NOT NULL DEFAULT '',
+                event_count INTEGER NOT NULL,
+                time_saved_minutes INTEGER NOT NULL,
+                PRIMARY KEY (date, event_type, domain)
+            )",
+            [],
+        )?;
+
+        // Unified history: raw events + rollups (all aggregate queries read this view)
+        // Recreated on open so databases from older builds pick up the rolled_up column
+        conn.execute_batch(
+            "DROP VIEW IF EXISTS usage_history;
+             CREATE VIEW usage_history AS
+                SELECT timestamp,
+                       event_type,
+                       1 AS event_count,
+                       time_saved_minutes,
+                       CASE WHEN json_valid(metadata) AND json_type(metadata, '$.domain') = 'text'
+                            THEN json_extract(metadata, '$.domain') END AS domain,
+                       0 AS rolled_up
+                FROM usage_events
+                UNION ALL
+                SELECT date || ' 00:00:00',
+                       event_type,
+                       event_count,
+                       time_saved_minutes,
+                       NULLIF(domain, ''),
+                       1
+                FROM usage_daily_rollups;",
+        )?;
+
+        Ok(UsageTracker {
+            conn,
+            time_saved_lookup: None,
+            time_saved,
+            durability: Durability::Safe,
+        })
+    }
+
+    /**
+     * Credit pattern matches with a per-pattern time-saved estimate.
+     *
+     * DESIGN DECISION: Caller supplies the lookup, keyed by the event's `pattern_id` metadata
+     * WHY: Estimates live on the pattern (PatternMetadata::estimated_time_saved_minutes or
+     * the desktop patterns table), not in the analytics database
+     *
+     * Matches without a pattern_id, or whose lookup returns None, get the
+     * configured PatternMatch minutes (TimeSavedConfig).
+     */
+    pub fn with_time_saved_lookup(
+        mut self,
+        lookup: impl Fn(&str) -> Option<u32> + Send + 'static,
+    ) -> Self {
+        self.time_saved_lookup = Some(Box::new(lookup));
+        self
+    }
+
+    /// Switch commit durability (Safe unless changed; see Durability)
+    pub fn with_durability(mut self, durability: Durability) -> Result<Self, Error> {
+        self.conn.execute_batch(&format!("PRAGMA synchronous = {}", durability.synchronous()))?;
+        self.durability = durability;
+        Ok(self)
+    }
+
+    /// Commit durability in effect
+    pub fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    /// with_time_saved_lookup over the estimates stored on `patterns`
+    pub fn with_pattern_estimates<'a>(self, patterns: impl IntoIterator<Item = &'a Pattern>) -> Self {
+        let estimates: HashMap<String, u32> = patterns
+            .into_iter()
+            .filter_map(|pattern| {
+                let minutes = pattern.metadata().estimated_time_saved_minutes?;
+                Some((pattern.id().to_string(), minutes))
+            })
+            .collect();
+
+        self.with_time_saved_lookup(move |pattern_id| estimates.get(pattern_id).copied())
     }
 
     /**
@@ -105,7 +355,15 @@ impl UsageTracker {
      * Returns `Error::Internal` if database write fails
      */
     fn record_event(&self, event_type: EventType, metadata: Option<&str>) -> Result<(), Error> {
-        let time_saved = event_type.time_saved_minutes();
+        self.record_event_with_time_saved(event_type, i64::from(self.time_saved.minutes(event_type)), metadata)
+    }
+
+    fn record_event_with_time_saved(
+        &self,
+        event_type: EventType,
+        time_saved: i64,
+        metadata: Option<&str>,
+    ) -> Result<(), Error> {
         self.conn.execute(
             "INSERT INTO usage_events (event_type, time_saved_minutes, metadata) VALUES (?1, ?2, ?3)",
             params![event_type.as_str(), time_saved, metadata],
@@ -113,24 +371,34 @@ impl UsageTracker {
         Ok(())
     }
 
-    /// Record a voice capture event (2 minutes saved)
+    /// Record a voice capture event (2 minutes saved by default)
     pub fn record_voice_capture(&self, metadata: Option<&str>) -> Result<(), Error> {
         self.record_event(EventType::VoiceCapture, metadata)
     }
 
-    /// Record a semantic search event (5 minutes saved)
+    /// Record a semantic search event (5 minutes saved by default)
     pub fn record_search(&self, metadata: Option<&str>) -> Result<(), Error> {
         self.record_event(EventType::Search, metadata)
     }
 
-    /// Record a code insertion event (2 minutes saved)
+    /// Record a code insertion event (2 minutes saved by default)
     pub fn record_insertion(&self, metadata: Option<&str>) -> Result<(), Error> {
         self.record_event(EventType::Insertion, metadata)
     }
 
-    /// Record a pattern match event (10 minutes saved)
+    /// Record a pattern match event (the pattern's estimate, else 10 minutes saved by default)
+    ///
+    /// Metadata `{"pattern_id": "..."}` selects the estimate (see with_time_saved_lookup).
     pub fn record_pattern_match(&self, metadata: Option<&str>) -> Result<(), Error> {
-        self.record_event(EventType::PatternMatch, metadata)
+        let estimate = match (&self.time_saved_lookup, metadata.and_then(pattern_id)) {
+            (Some(lookup), Some(pattern_id)) => lookup(&pattern_id),
+            _ => None,
+        };
+        let time_saved = estimate
+            .map(i64::from)
+            .unwrap_or_else(|| i64::from(self.time_saved.minutes(EventType::PatternMatch)));
+
+        self.record_event_with_time_saved(EventType::PatternMatch, time_saved, metadata)
     }
 
     /**
@@ -142,7 +410,7 @@ impl UsageTracker {
      */
     pub fn count_events(&self) -> Result<i64, Error> {
         let count: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM usage_events",
+            "SELECT COALESCE(SUM(event_count), 0) FROM usage_history",
             [],
             |row| row.get(0),
         )?;
@@ -158,7 +426,7 @@ impl UsageTracker {
      */
     pub fn total_time_saved_minutes(&self) -> Result<i64, Error> {
         let total: i64 = self.conn.query_row(
-            "SELECT COALESCE(SUM(time_saved_minutes), 0) FROM usage_events",
+            "SELECT COALESCE(SUM(time_saved_minutes), 0) FROM usage_history",
             [],
             |row| row.get(0),
         )?;
@@ -178,7 +446,7 @@ impl UsageTracker {
      */
     pub fn count_events_by_type(&self, event_type: EventType) -> Result<i64, Error> {
         let count: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM usage_events WHERE event_type = ?1",
+            "SELECT COALESCE(SUM(event_count), 0) FROM usage_history WHERE event_type = ?1",
             params![event_type.as_str()],
             |row| row.get(0),
         )?;
@@ -198,7 +466,7 @@ impl UsageTracker {
      */
     pub fn time_saved_by_type(&self, event_type: EventType) -> Result<i64, Error> {
         let total: i64 = self.conn.query_row(
-            "SELECT COALESCE(SUM(time_saved_minutes), 0) FROM usage_events WHERE event_type = ?1",
+            "SELECT COALESCE(SUM(time_saved_minutes), 0) FROM usage_history WHERE event_type = ?1",
             params![event_type.as_str()],
             |row| row.get(0),
         )?;
@@ -206,11 +474,13 @@ impl UsageTracker {
     }
 
     /**
-     * Get daily time saved for the last N days.
+     * Get daily time saved for the last N days, bucketed by UTC day.
      *
      * DESIGN DECISION: Return structured data for chart visualization
      * WHY: Frontend needs time-series data grouped by date
      *
+     * Use get_daily_time_saved_local to bucket by the user's local day.
+     *
      * # Arguments
      *
      * * `days` - Number of days to query (e.g., 7, 30, 90)
@@ -224,15 +494,71 @@ impl UsageTracker {
      * Returns `Error::Internal` if database query fails
      */
     pub fn get_daily_time_saved(&self, days: u32) -> Result<Vec<(String, i64)>, Error> {
+        self.get_daily_time_saved_local(days, FixedOffset::east_opt(0).expect("zero offset is valid"))
+    }
+
+    /**
+     * Get daily time saved for the last N days, bucketed by local day.
+     *
+     * DESIGN DECISION: Fixed UTC offset supplied by the caller
+     * WHY: Events are stored in UTC; grouping by UTC date put a user's late-evening
+     * events (UTC-5) or early-morning events (UTC+5:30) on the neighbouring day
+     *
+     * REASONING CHAIN:
+     * 1. Window is the last `days` × 24h, same as get_daily_time_saved
+     * 2. Raw events: shift UTC timestamp by the offset, then take the date
+     * 3. Rolled-up events (see prune) only kept their UTC date; they are reported on
+     *    that date unshifted (shifting UTC midnight would move a whole day backwards
+     *    for negative offsets)
+     * 4. A rollup day counts if it is on or after the window's start date (see
+     *    history_window): its events can't be split at the start time, and stamping
+     *    them at midnight would drop the whole boundary day
+     *
+     * NOTE: A fixed offset does not follow DST changes inside the window; callers pass
+     * the offset in effect now (e.g. chrono::Local::now().offset()).
+     *
+     * # Arguments
+     *
+     * * `days` - Number of days to query (e.g., 7, 30, 90)
+     * * `utc_offset` - Local time minus UTC (e.g. +05:30, -05:00)
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database query fails
+     */
+    pub fn get_daily_time_saved_local(
+        &self,
+        days: u32,
+        utc_offset: FixedOffset,
+    ) -> Result<Vec<(String, i64)>, Error> {
+        self.daily_time_saved_since(Utc::now() - Duration::days(days as i64), utc_offset)
+    }
+
+    /// Local-day buckets of events at or after `since` (see get_daily_time_saved_local)
+    pub fn daily_time_saved_since(
+        &self,
+        since: DateTime<Utc>,
+        utc_offset: FixedOffset,
+    ) -> Result<Vec<(String, i64)>, Error> {
+        let since = since.format(SQLITE_DATETIME_FORMAT).to_string();
+        let shift = format!("{:+} seconds", utc_offset.local_minus_utc());
+
         let mut stmt = self.conn.prepare(
-            "SELECT DATE(timestamp) as date, SUM(time_saved_minutes) as minutes
-             FROM usage_events
-             WHERE timestamp >= datetime('now', '-' || ?1 || ' days')
-             GROUP BY DATE(timestamp)
+            "SELECT date, SUM(minutes) as minutes
+             FROM (
+                 SELECT DATE(timestamp, ?2) as date, time_saved_minutes as minutes
+                 FROM usage_events
+                 WHERE timestamp >= ?1
+                 UNION ALL
+                 SELECT date, time_saved_minutes
+                 FROM usage_daily_rollups
+                 WHERE date >= DATE(?1)
+             )
+             GROUP BY date
              ORDER BY date ASC"
         )?;
 
-        let rows = stmt.query_map(params![days], |row| {
+        let rows = stmt.query_map(params![since, shift], |row| {
             Ok((row.get(0)?, row.get(1)?))
         })?;
 
@@ -245,7 +571,322 @@ impl UsageTracker {
     }
 
     /**
-     * Delete all usage events (for testing or privacy reset).
+     * Query raw events in a time range, optionally filtered by type.
+     *
+     * DESIGN DECISION: Half-open range [from, to), ordered by timestamp
+     * WHY: Adjacent ranges (day by day export) never double count; range scan uses
+     * idx_events_timestamp
+     *
+     * NOTE: Only raw events are returned; events already folded into daily rollups
+     * by prune() are no longer individually available.
+     *
+     * # Arguments
+     *
+     * * `from` - Inclusive lower bound
+     * * `to` - Exclusive upper bound
+     * * `types` - Event types to include (`None` = all types)
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database query fails
+     */
+    pub fn query_events(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        types: Option<&[EventType]>,
+    ) -> Result<Vec<EventRecord>, Error> {
+        let mut sql = String::from(
+            "SELECT timestamp, event_type, time_saved_minutes, metadata
+             FROM usage_events
+             WHERE timestamp >= ?1 AND timestamp < ?2",
+        );
+        let mut values = vec![
+            from.format(SQLITE_DATETIME_FORMAT).to_string(),
+            to.format(SQLITE_DATETIME_FORMAT).to_string(),
+        ];
+
+        if let Some(types) = types {
+            if types.is_empty() {
+                return Ok(Vec::new());
+            }
+            let placeholders: Vec<String> = (0..types.len())
+                .map(|i| format!("?{}", values.len() + i + 1))
+                .collect();
+            sql.push_str(&format!(" AND event_type IN ({})", placeholders.join(", ")));
+            values.extend(types.iter().map(|t| t.as_str().to_string()));
+        }
+        sql.push_str(" ORDER BY timestamp ASC, id ASC");
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(values.iter()), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            let (timestamp, event_type, time_saved_minutes, metadata) = row?;
+
+            let timestamp = NaiveDateTime::parse_from_str(&timestamp, SQLITE_DATETIME_FORMAT)
+                .map_err(|e| Error::Internal(format!("Invalid event timestamp '{}': {}", timestamp, e)))?
+                .and_utc();
+            let event_type = EventType::from_str(&event_type)
+                .ok_or_else(|| Error::Internal(format!("Unknown event type '{}'", event_type)))?;
+
+            events.push(EventRecord { timestamp, event_type, time_saved_minutes, metadata });
+        }
+
+        Ok(events)
+    }
+
+    /**
+     * Export raw events in a time range as CSV or JSON.
+     *
+     * DESIGN DECISION: Built on query_events, one row per event, metadata passed through verbatim
+     * WHY: Users own their data (external dashboards, backups) without widening what is
+     * stored; the export holds exactly the columns the database does, so no code content
+     *
+     * Columns: timestamp (RFC 3339, UTC), event_type, time_saved_minutes, metadata
+     * (empty CSV field / JSON null when absent). CSV fields containing commas, quotes or
+     * line breaks are quoted with inner quotes doubled.
+     *
+     * # Arguments
+     *
+     * * `from` - Inclusive lower bound
+     * * `to` - Exclusive upper bound
+     * * `format` - Csv or Json
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database query fails
+     */
+    pub fn export_events(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        format: ExportFormat,
+    ) -> Result<String, Error> {
+        let events = self.query_events(from, to, None)?;
+
+        match format {
+            ExportFormat::Csv => {
+                let mut csv = String::from("timestamp,event_type,time_saved_minutes,metadata\r\n");
+                for event in &events {
+                    csv.push_str(&format!(
+                        "{},{},{},{}\r\n",
+                        event.timestamp.to_rfc3339(),
+                        event.event_type.as_str(),
+                        event.time_saved_minutes,
+                        csv_field(event.metadata.as_deref().unwrap_or("")),
+                    ));
+                }
+                Ok(csv)
+            }
+            ExportFormat::Json => {
+                let rows: Vec<serde_json::Value> = events
+                    .iter()
+                    .map(|event| {
+                        serde_json::json!({
+                            "timestamp": event.timestamp.to_rfc3339(),
+                            "event_type": event.event_type.as_str(),
+                            "time_saved_minutes": event.time_saved_minutes,
+                            "metadata": event.metadata,
+                        })
+                    })
+                    .collect();
+                Ok(serde_json::to_string_pretty(&rows)?)
+            }
+        }
+    }
+
+    /**
+     * Prune raw events older than the given instant, preserving daily rollups.
+     *
+     * DESIGN DECISION: Fold old events into (date, type, domain) rollups before deleting
+     * WHY: Database must not grow forever, but historical totals must stay accurate
+     *
+     * REASONING CHAIN:
+     * 1. Aggregate events older than `older_than` by day, type and metadata domain
+     * 2. Merge into usage_daily_rollups (additive, safe to run repeatedly)
+     * 3. Delete the raw rows in the same transaction (no double counting on crash)
+     * 4. Aggregate queries read usage_history (raw + rollups) → totals unchanged
+     *
+     * # Arguments
+     *
+     * * `older_than` - Events with timestamp strictly before this are pruned
+     *
+     * # Returns
+     *
+     * Number of raw events deleted
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database operation fails
+     */
+    pub fn prune(&self, older_than: DateTime<Utc>) -> Result<usize, Error> {
+        let cutoff = older_than.format(SQLITE_DATETIME_FORMAT).to_string();
+        let tx = self.conn.unchecked_transaction()?;
+
+        tx.execute(
+            "INSERT INTO usage_daily_rollups (date, event_type, domain, event_count, time_saved_minutes)
+             SELECT DATE(timestamp), event_type, COALESCE(domain, ''), SUM(event_count), SUM(time_saved_minutes)
+             FROM (
+                 SELECT timestamp, event_type, 1 AS event_count, time_saved_minutes,
+                        CASE WHEN json_valid(metadata) AND json_type(metadata, '$.domain') = 'text'
+                             THEN json_extract(metadata, '$.domain') END AS domain
+                 FROM usage_events
+                 WHERE timestamp < ?1
+             )
+             GROUP BY DATE(timestamp), event_type, COALESCE(domain, '')
+             ON CONFLICT (date, event_type, domain) DO UPDATE SET
+                 event_count = event_count + excluded.event_count,
+                 time_saved_minutes = time_saved_minutes + excluded.time_saved_minutes",
+            params![cutoff],
+        )?;
+
+        let deleted = tx.execute(
+            "DELETE FROM usage_events WHERE timestamp < ?1",
+            params![cutoff],
+        )?;
+
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    /**
+     * Permanently discard events older than the given instant.
+     *
+     * DESIGN DECISION: Hard delete of raw events and of whole rollup days before the cutoff
+     * WHY: prune keeps every total forever; users who want old data gone (privacy,
+     * disk) need it removed, while totals for days still within retention stay exact
+     *
+     * Not part of retention (with_retention_days rolls up instead): this is the
+     * explicit "forget my history" operation and is never run automatically.
+     *
+     * REASONING CHAIN:
+     * 1. Raw events with timestamp < cutoff are deleted
+     * 2. A rollup row covers a whole day, so it is deleted only when that day ends
+     *    at or before the cutoff (a partially retained day keeps its rollup)
+     * 3. Both deletes run in one transaction: a crash leaves all or nothing
+     *
+     * # Arguments
+     *
+     * * `cutoff` - Events with timestamp strictly before this are discarded
+     *
+     * # Returns
+     *
+     * Number of rows removed (raw events + daily rollup rows)
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database operation fails
+     */
+    pub fn purge_before(&self, cutoff: DateTime<Utc>) -> Result<u64, Error> {
+        let cutoff = cutoff.format(SQLITE_DATETIME_FORMAT).to_string();
+        let tx = self.conn.unchecked_transaction()?;
+
+        let events = tx.execute("DELETE FROM usage_events WHERE timestamp < ?1", params![cutoff])?;
+        let rollups = tx.execute(
+            "DELETE FROM usage_daily_rollups WHERE datetime(date, '+1 day') <= ?1",
+            params![cutoff],
+        )?;
+
+        tx.commit()?;
+        Ok((events + rollups) as u64)
+    }
+
+    /**
+     * Apply the retention window on open: raw events older than `retention_days`
+     * are folded into daily rollups (see prune) and deleted.
+     *
+     * DESIGN DECISION: Builder applied right after construction, like with_durability
+     * WHY: The single retention mechanism; the desktop opens the tracker with the
+     * `analytics_retention_days` setting at startup, so raw rows never outlive the
+     * window (plus one session) without a background job. Rollups keep every total,
+     * so nothing the dashboard showed changes
+     *
+     * After a prune that leaves enough free pages (see compaction::needs_compaction)
+     * the database is compacted; a compaction failure is logged, not returned.
+     *
+     * # Arguments
+     *
+     * * `retention_days` - Raw events older than this many days are rolled up
+     *   (clamped to at least MIN_RETENTION_DAYS; settings files are hand-editable)
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database operation fails
+     */
+    pub fn with_retention_days(self, retention_days: u32) -> Result<Self, Error> {
+        let retention_days = retention_days.max(MIN_RETENTION_DAYS);
+        let pruned = self.prune(Utc::now() - Duration::days(retention_days as i64))?;
+
+        if pruned > 0 {
+            tracing::info!(pruned, retention_days, "Rolled up expired analytics events");
+            if let Err(e) = self.compact_if_fragmented() {
+                tracing::warn!(error = %e, "Failed to compact analytics database");
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn compact_if_fragmented(&self) -> Result<(), Error> {
+        if self.needs_compaction()? {
+            let reclaimed_bytes = self.compact()?;
+            tracing::info!(reclaimed_bytes, "Compacted analytics database");
+        }
+        Ok(())
+    }
+
+    /**
+     * Compact the analytics database (VACUUM + ANALYZE).
+     *
+     * # Returns
+     *
+     * Bytes reclaimed
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if database operation fails
+     */
+    pub fn compact(&self) -> Result<u64, Error> {
+        crate::compaction::compact_sqlite(&self.conn)
+    }
+
+    /// Whether enough of the database is free pages to be worth compact()
+    pub fn needs_compaction(&self) -> Result<bool, Error> {
+        crate::compaction::needs_compaction(&self.conn)
+    }
+
+    /**
+     * Checkpoint the WAL into the main database file and truncate it.
+     *
+     * DESIGN DECISION: Explicit flush before shutdown, not after every write
+     * WHY: Committed events are already durable per Durability; the checkpoint
+     * makes analytics.db self-contained (safe to copy or back up without its
+     * -wal file) and fsyncs everything a Fast tracker had not synced yet
+     *
+     * # Errors
+     *
+     * Returns `Error::Internal` if another connection's open read kept the
+     * checkpoint from completing
+     */
+    pub fn flush(&self) -> Result<(), Error> {
+        let busy: i64 = self.conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))?;
+        if busy != 0 {
+            return Err(Error::Internal("Analytics checkpoint blocked by an open reader".to_string()));
+        }
+        Ok(())
+    }
+
+    /**
+     * Delete all usage events and rollups (for testing or privacy reset).
      *
      * # Errors
      *
@@ -253,15 +894,68 @@ impl UsageTracker {
      */
     pub fn clear(&self) -> Result<(), Error> {
         self.conn.execute("DELETE FROM usage_events", [])?;
+        self.conn.execute("DELETE FROM usage_daily_rollups", [])?;
         Ok(())
     }
 }
 
+/// `pattern_id` string from event metadata JSON
+fn pattern_id(metadata: &str) -> Option<String> {
+    let metadata: serde_json::Value = serde_json::from_str(metadata).ok()?;
+    metadata.get("pattern_id")?.as_str().map(str::to_string)
+}
+
+/// CSV field, quoted (inner quotes doubled) when it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::time::Instant;
 
+    #[test]
+    fn test_pattern_match_credits_pattern_estimate() {
+        let complex = Pattern::builder()
+            .title("Distributed saga")
+            .content("Compensating transactions across services")
+            .estimated_time_saved(45)
+            .build()
+            .unwrap();
+        let simple = Pattern::new("Early return".to_string(), "Guard clauses".to_string(), vec![]);
+
+        let tracker = UsageTracker::new(":memory:")
+            .expect("Failed to create tracker")
+            .with_pattern_estimates([&complex, &simple]);
+
+        tracker
+            .record_pattern_match(Some(&format!(r#"{{"pattern_id": "{}"}}"#, complex.id())))
+            .expect("Failed to record event");
+        assert_eq!(tracker.time_saved_by_type(EventType::PatternMatch).unwrap(), 45);
+
+        // No estimate on the pattern, and no pattern_id at all: flat default
+        tracker
+            .record_pattern_match(Some(&format!(r#"{{"pattern_id": "{}"}}"#, simple.id())))
+            .expect("Failed to record event");
+        tracker.record_pattern_match(None).expect("Failed to record event");
+        assert_eq!(tracker.time_saved_by_type(EventType::PatternMatch).unwrap(), 45 + 10 + 10);
+    }
+
+    #[test]
+    fn test_pattern_match_without_lookup_uses_default() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+        tracker
+            .record_pattern_match(Some(r#"{"pattern_id": "P-042"}"#))
+            .expect("Failed to record event");
+
+        assert_eq!(tracker.time_saved_by_type(EventType::PatternMatch).unwrap(), 10);
+    }
+
     #[test]
     fn test_tracker_new() {
         let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
@@ -363,4 +1057,310 @@ mod tests {
         // Should be well under 50ms for aggregation
         assert!(elapsed.as_millis() < 50, "Query took {}ms (target: <50ms)", elapsed.as_millis());
     }
+
+    #[test]
+    fn test_query_events_range_and_type_filter() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES ('2025-01-01 00:00:00', 'search', 5, NULL),
+                    ('2025-01-01 12:00:00', 'voice_capture', 2, '{\"domain\": \"rust\"}'),
+                    ('2025-01-02 00:00:00', 'search', 5, NULL)",
+            [],
+        ).expect("Failed to insert events");
+
+        let from = "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let to = "2025-01-02T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        // Lower bound inclusive, upper bound exclusive
+        let events = tracker.query_events(from, to, None).expect("Failed to query");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].timestamp, from);
+        assert_eq!(events[0].event_type, EventType::Search);
+        assert_eq!(events[1].event_type, EventType::VoiceCapture);
+        assert_eq!(events[1].metadata.as_deref(), Some(r#"{"domain": "rust"}"#));
+
+        // Type filter
+        let searches = tracker
+            .query_events(from, to + Duration::seconds(1), Some(&[EventType::Search]))
+            .expect("Failed to query");
+        assert_eq!(searches.len(), 2);
+        assert!(searches.iter().all(|e| e.event_type == EventType::Search));
+
+        let none = tracker.query_events(from, to, Some(&[])).expect("Failed to query");
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_time_saved_config_overrides_defaults() {
+        let config = TimeSavedConfig::new()
+            .with_minutes(EventType::Search, 8)
+            .with_minutes(EventType::PatternMatch, 0);
+        let tracker = UsageTracker::new_with_config(":memory:", config).expect("Failed to create tracker");
+
+        tracker.record_search(None).expect("Failed to record event");
+        tracker.record_pattern_match(None).expect("Failed to record event");
+        tracker.record_voice_capture(None).expect("Failed to record event"); // default 2
+
+        assert_eq!(tracker.time_saved_by_type(EventType::Search).unwrap(), 8);
+        assert_eq!(tracker.time_saved_by_type(EventType::PatternMatch).unwrap(), 0);
+        let metrics = crate::analytics::UsageMetrics::new(&tracker)
+            .get_all_time_metrics()
+            .expect("Failed to get metrics");
+        assert_eq!(metrics.total_time_saved_minutes, 8 + 2);
+
+        let negative = TimeSavedConfig::new().with_minutes(EventType::Insertion, -1);
+        match UsageTracker::new_with_config(":memory:", negative) {
+            Err(Error::Configuration(message)) => assert!(message.contains("insertion")),
+            other => panic!("Expected configuration error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_export_events_csv_and_json() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+        let metadata = "{\"note\": \"a, \"\"b\"\"\nc\"}";
+
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES ('2025-01-01 09:00:00', 'search', 5, NULL),
+                    ('2025-01-01 10:00:00', 'pattern_match', 10, ?1),
+                    ('2025-01-03 00:00:00', 'insertion', 2, NULL)",
+            params![metadata],
+        ).expect("Failed to insert events");
+
+        let from = "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let to = "2025-01-02T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let csv = tracker.export_events(from, to, ExportFormat::Csv).expect("Failed to export CSV");
+        assert_eq!(
+            csv,
+            format!(
+                "timestamp,event_type,time_saved_minutes,metadata\r\n\
+                 2025-01-01T09:00:00+00:00,search,5,\r\n\
+                 2025-01-01T10:00:00+00:00,pattern_match,10,\"{}\"\r\n",
+                metadata.replace('"', "\"\"")
+            )
+        );
+
+        let json = tracker.export_events(from, to, ExportFormat::Json).expect("Failed to export JSON");
+        let rows: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["event_type"], "search");
+        assert!(rows[0]["metadata"].is_null());
+        assert_eq!(rows[1]["timestamp"], "2025-01-01T10:00:00+00:00");
+        assert_eq!(rows[1]["time_saved_minutes"], 10);
+        assert_eq!(rows[1]["metadata"], metadata);
+    }
+
+    #[test]
+    fn test_daily_time_saved_buckets_by_local_day() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        // 23:30 and 00:30 UTC straddle UTC midnight; 18:30 UTC is 00:00 at +05:30
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES ('2025-03-01 18:29:59', 'search', 5, NULL),
+                    ('2025-03-01 18:30:00', 'voice_capture', 2, NULL),
+                    ('2025-03-01 23:30:00', 'pattern_match', 10, NULL),
+                    ('2025-03-02 00:30:00', 'insertion', 2, NULL),
+                    ('2025-03-02 04:59:59', 'search', 5, NULL)",
+            [],
+        ).expect("Failed to insert events");
+        let since = "2025-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let day = |date: &str, minutes: i64| (date.to_string(), minutes);
+
+        // UTC: split at UTC midnight
+        let utc = FixedOffset::east_opt(0).unwrap();
+        assert_eq!(
+            tracker.daily_time_saved_since(since, utc).unwrap(),
+            vec![day("2025-03-01", 5 + 2 + 10), day("2025-03-02", 2 + 5)]
+        );
+
+        // India (+05:30): local midnight is 18:30 UTC
+        let ist = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        assert_eq!(
+            tracker.daily_time_saved_since(since, ist).unwrap(),
+            vec![day("2025-03-01", 5), day("2025-03-02", 2 + 10 + 2 + 5)]
+        );
+
+        // US Eastern (-05:00): local midnight is 05:00 UTC, all events on March 1
+        let est = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(
+            tracker.daily_time_saved_since(since, est).unwrap(),
+            vec![day("2025-03-01", 5 + 2 + 10 + 2 + 5)]
+        );
+    }
+
+    #[test]
+    fn test_prune_preserves_totals_via_rollups() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        // Two old events (200 days ago), one recent
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES (datetime('now', '-200 days'), 'search', 5, '{\"domain\": \"rust\"}'),
+                    (datetime('now', '-200 days'), 'pattern_match', 10, NULL)",
+            [],
+        ).expect("Failed to insert old events");
+        tracker.record_voice_capture(None).expect("Failed to record");
+
+        let tracker = tracker.with_retention_days(DEFAULT_RETENTION_DAYS).expect("Failed to apply retention");
+
+        // Raw rows are gone
+        let raw: i64 = tracker.conn
+            .query_row("SELECT COUNT(*) FROM usage_events", [], |row| row.get(0))
+            .expect("Failed to count raw events");
+        assert_eq!(raw, 1);
+
+        // Totals preserved via rollups
+        assert_eq!(tracker.count_events().expect("Failed to count"), 3);
+        assert_eq!(tracker.total_time_saved_minutes().expect("Failed to get time"), 5 + 10 + 2);
+        assert_eq!(tracker.count_events_by_type(EventType::Search).expect("Failed to count"), 1);
+
+        let metrics = crate::analytics::UsageMetrics::new(&tracker)
+            .get_all_time_metrics()
+            .expect("Failed to get metrics");
+        assert_eq!(metrics.total_events, 3);
+        assert_eq!(metrics.pattern_matches, 1);
+        assert_eq!(metrics.by_domain["rust"].total_events, 1);
+
+        // Pruning again is a no-op
+        assert_eq!(tracker.prune(Utc::now() - Duration::days(DEFAULT_RETENTION_DAYS as i64)).expect("Failed to prune"), 0);
+        assert_eq!(tracker.count_events().expect("Failed to count"), 3);
+    }
+
+    #[test]
+    fn test_rolled_up_boundary_day_stays_in_window() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        // Morning of March 1 rolled up; afternoon still raw
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES ('2025-02-28 09:00:00', 'search', 1, NULL),
+                    ('2025-03-01 09:00:00', 'search', 5, NULL),
+                    ('2025-03-01 15:00:00', 'pattern_match', 10, NULL)",
+            [],
+        ).expect("Failed to insert events");
+        tracker.prune("2025-03-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap()).expect("Failed to prune");
+
+        // Window starts mid-day on the rolled-up day
+        let since = "2025-03-01T06:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            tracker.daily_time_saved_since(since, FixedOffset::east_opt(0).unwrap()).unwrap(),
+            vec![("2025-03-01".to_string(), 5 + 10)]
+        );
+
+        let minutes: i64 = tracker.conn.query_row(
+            &format!(
+                "SELECT SUM(time_saved_minutes) FROM usage_history WHERE {}",
+                history_window("'2025-03-01 06:00:00'")
+            ),
+            [],
+            |row| row.get(0),
+        ).expect("Failed to sum history");
+        assert_eq!(minutes, 5 + 10);
+    }
+
+    #[test]
+    fn test_purge_before_discards_only_expired_days() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES ('2025-01-01 08:00:00', 'search', 5, NULL),
+                    ('2025-01-10 08:00:00', 'search', 5, NULL),
+                    ('2025-01-10 20:00:00', 'insertion', 2, NULL)",
+            [],
+        ).expect("Failed to insert events");
+        // Rollups for 2025-01-01 and 2025-01-10
+        tracker
+            .prune("2025-01-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap())
+            .expect("Failed to prune");
+
+        // Cutoff mid-day: the 2025-01-10 rollup is partly inside the window and stays
+        let cutoff = "2025-01-10T06:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(tracker.purge_before(cutoff).expect("Failed to purge"), 1);
+        assert_eq!(tracker.count_events().expect("Failed to count"), 2);
+        assert_eq!(tracker.total_time_saved_minutes().expect("Failed to get time"), 5 + 2);
+
+        let cutoff = "2025-01-11T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(tracker.purge_before(cutoff).expect("Failed to purge"), 2);
+        assert_eq!(tracker.count_events().expect("Failed to count"), 0);
+    }
+
+    #[test]
+    fn test_retention_days_rolls_up_on_open() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+        tracker.conn.execute(
+            "INSERT INTO usage_events (timestamp, event_type, time_saved_minutes, metadata)
+             VALUES (datetime('now', '-40 days'), 'search', 5, NULL)",
+            [],
+        ).expect("Failed to insert old event");
+        tracker.record_voice_capture(None).expect("Failed to record");
+
+        let tracker = tracker.with_retention_days(30).expect("Failed to apply retention");
+        let raw: i64 = tracker.conn
+            .query_row("SELECT COUNT(*) FROM usage_events", [], |row| row.get(0))
+            .expect("Failed to count raw events");
+        assert_eq!(raw, 1);
+        assert_eq!(tracker.count_events().expect("Failed to count"), 2);
+        assert_eq!(tracker.total_time_saved_minutes().expect("Failed to get time"), 5 + 2);
+    }
+
+    #[test]
+    fn test_zero_retention_clamped() {
+        let tracker = UsageTracker::new(":memory:").expect("Failed to create tracker");
+        tracker.record_voice_capture(None).expect("Failed to record");
+
+        // 0 days behaves like MIN_RETENTION_DAYS: today's raw events are kept
+        let tracker = tracker.with_retention_days(0).expect("Failed to apply retention");
+        let raw: i64 = tracker.conn
+            .query_row("SELECT COUNT(*) FROM usage_events", [], |row| row.get(0))
+            .expect("Failed to count raw events");
+        assert_eq!(raw, 1);
+    }
+
+    /**
+     * Test: File trackers run in WAL mode; flush() moves recent writes into the main file
+     */
+    #[test]
+    fn test_flush_checkpoints_wal_into_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("analytics.db");
+        let tracker = UsageTracker::new(&path).expect("Failed to create tracker");
+
+        let journal_mode: String = tracker.conn.query_row("PRAGMA journal_mode", [], |row| row.get(0)).unwrap();
+        assert_eq!(journal_mode, "wal");
+
+        tracker.record_voice_capture(None).expect("Failed to record");
+        tracker.record_search(None).expect("Failed to record");
+        tracker.flush().expect("Failed to flush");
+
+        let wal = dir.path().join("analytics.db-wal");
+        assert_eq!(std::fs::metadata(&wal).map(|m| m.len()).unwrap_or(0), 0);
+
+        // The main file alone (as a backup copy would be) holds the events
+        let copy = dir.path().join("copy.db");
+        std::fs::copy(&path, &copy).unwrap();
+        let copied = UsageTracker::new(&copy).expect("Failed to open copy");
+        assert_eq!(copied.count_events().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_durability_sets_synchronous() {
+        let dir = tempfile::tempdir().unwrap();
+        let synchronous = |tracker: &UsageTracker| -> i64 {
+            tracker.conn.query_row("PRAGMA synchronous", [], |row| row.get(0)).unwrap()
+        };
+
+        let tracker = UsageTracker::new(dir.path().join("analytics.db")).unwrap();
+        assert_eq!(tracker.durability(), Durability::Safe);
+        assert_eq!(synchronous(&tracker), 2); // FULL
+
+        let tracker = tracker.with_durability(Durability::Fast).unwrap();
+        assert_eq!(tracker.durability(), Durability::Fast);
+        assert_eq!(synchronous(&tracker), 1); // NORMAL
+    }
 }
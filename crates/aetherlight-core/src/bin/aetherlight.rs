@@ -0,0 +1,105 @@
+/**
+ * ÆtherLight CLI entry point
+ *
+ * DESIGN DECISION: Thin argument dispatch over the cli module (no clap dependency)
+ * WHY: Command logic lives in aetherlight_core::cli (testable without a process);
+ * the binary only maps arguments to calls and results to exit codes
+ *
+ * Usage:
+ *   aetherlight sprint validate <plan|-> [--format yaml|toml]
+ *   aetherlight sprint order <plan|-> [--format yaml|toml]
+ *   aetherlight sprint simulate <plan|-> [--format yaml|toml] [--workflow-dir <dir>]
+ *
+ * Exit codes: 0 success, 1 invalid plan or failed command, 2 usage error
+ *
+ * PATTERN: Pattern-CLI-001 (Command-Line Interface)
+ * RELATED: cli/sprint.rs
+ */
+
+use aetherlight_core::{SprintCli, SprintFormat, SprintSource};
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+  aetherlight sprint validate <plan|-> [--format yaml|toml]
+  aetherlight sprint order <plan|-> [--format yaml|toml]
+  aetherlight sprint simulate <plan|-> [--format yaml|toml] [--workflow-dir <dir>]
+
+Use - to read the plan from stdin (YAML unless --format toml).
+--workflow-dir writes progress signals there (e.g. .lumina/workflow).";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match run(&args) {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(Failure::Command(message)) => {
+            eprintln!("{}", message);
+            ExitCode::from(1)
+        }
+        Err(Failure::Usage(message)) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+enum Failure {
+    /// Command ran and failed (invalid plan, unreadable file)
+    Command(String),
+    /// Arguments could not be understood
+    Usage(String),
+}
+
+fn run(args: &[&str]) -> Result<String, Failure> {
+    match args {
+        ["sprint", command, rest @ ..] => {
+            let (plan, format, workflow_dir) = plan_args(rest)?;
+            if workflow_dir.is_some() && *command != "simulate" {
+                return Err(Failure::Usage("--workflow-dir only applies to simulate".to_string()));
+            }
+            let source = SprintSource::from_arg(plan);
+            let stdin = &mut std::io::stdin().lock();
+            match *command {
+                "validate" => SprintCli::validate(&source, format, stdin),
+                "order" => SprintCli::order(&source, format, stdin),
+                // Progress bar on stderr so stdout stays the summary only
+                "simulate" => SprintCli::simulate(&source, format, workflow_dir, stdin, |line| eprintln!("{}", line)),
+                other => return Err(Failure::Usage(format!("Unknown sprint command: {}", other))),
+            }
+            .map_err(Failure::Command)
+        }
+        [] | ["help"] | ["--help"] | ["-h"] => Ok(USAGE.to_string()),
+        _ => Err(Failure::Usage(format!("Unknown command: {}", args.join(" ")))),
+    }
+}
+
+/// `<plan|-> [--format yaml|toml] [--workflow-dir <dir>]` in any order
+fn plan_args<'a>(args: &[&'a str]) -> Result<(&'a str, Option<SprintFormat>, Option<&'a Path>), Failure> {
+    let mut plan = None;
+    let mut format = None;
+    let mut workflow_dir = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--format" => {
+                let value = args.next().ok_or_else(|| Failure::Usage("--format needs a value".to_string()))?;
+                format = Some(value.parse().map_err(Failure::Usage)?);
+            }
+            "--workflow-dir" => {
+                let value = args.next().ok_or_else(|| Failure::Usage("--workflow-dir needs a value".to_string()))?;
+                workflow_dir = Some(Path::new(*value));
+            }
+            _ if plan.is_none() => plan = Some(*arg),
+            _ => return Err(Failure::Usage(format!("Unexpected argument: {}", arg))),
+        }
+    }
+
+    let plan = plan.ok_or_else(|| Failure::Usage("Missing plan path (use - for stdin)".to_string()))?;
+    Ok((plan, format, workflow_dir))
+}
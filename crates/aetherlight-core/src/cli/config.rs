@@ -16,10 +16,7 @@
  * PERFORMANCE: <100ms per command
  */
 
-use crate::config::{
-    AetherlightConfig, ConfigLevel, ConfigLoader, ConfigValidator, PrivacyMode, SyncConfig,
-    TerminalConfig,
-};
+use crate::config::{AetherlightConfig, ConfigLevel, ConfigLoader, ConfigValidator};
 use std::path::PathBuf;
 
 /// CLI config command result
@@ -59,28 +56,10 @@ impl ConfigCli {
     pub fn get(&self, key: &str) -> ConfigResult<String> {
         let config = self.loader.load()?;
 
-        let value = match key {
-            // Sync config
-            "sync.enabled" => config.sync.enabled.to_string(),
-            "sync.server_url" => config.sync.server_url.clone(),
-            "sync.privacy_mode" => format!("{:?}", config.sync.privacy_mode),
-            "sync.auto_reconnect" => config.sync.auto_reconnect.to_string(),
-            "sync.reconnect_delay_ms" => config.sync.reconnect_delay_ms.to_string(),
-            "sync.max_reconnect_delay_ms" => config.sync.max_reconnect_delay_ms.to_string(),
-            "sync.show_notifications" => config.sync.show_notifications.to_string(),
-            "sync.notification_sound" => config.sync.notification_sound.to_string(),
-            "sync.tls_enabled" => config.sync.tls_enabled.to_string(),
-
-            // Terminal config
-            "terminal.enabled" => config.terminal.enabled.to_string(),
-            "terminal.voice.enabled" => config.terminal.voice.enabled.to_string(),
-            "terminal.voice.hotkey" => config.terminal.voice.hotkey.clone(),
-            "terminal.voice.auto_transcribe" => config.terminal.voice.auto_transcribe.to_string(),
-
-            _ => return Err(format!("Unknown config key: {}", key)),
-        };
-
-        Ok(value)
+        Ok(match config.get_value(key)? {
+            toml::Value::String(value) => value,
+            value => value.to_string(),
+        })
     }
 
     /**
@@ -89,63 +68,18 @@ impl ConfigCli {
      * DESIGN DECISION: Writes to User level by default
      * WHY: User preferences highest priority, safe to modify
      *
+     * DESIGN DECISION: Value parsed as a TOML literal, falling back to a plain string
+     * WHY: `true`, `2000`, `["blocker"]` get their real types without quoting;
+     * ConfigLoader::set rejects values of the wrong type for the key
+     *
      * Examples:
      * - aetherlight config set sync.enabled true
      * - aetherlight config set sync.privacy_mode decisions_only
      * - aetherlight config set terminal.voice.hotkey F14
      */
     pub fn set(&self, key: &str, value: &str, level: Option<ConfigLevel>) -> ConfigResult<()> {
-        let mut config = self.loader.load()?;
         let target_level = level.unwrap_or(ConfigLevel::User);
-
-        // Update config based on key
-        match key {
-            // Sync config
-            "sync.enabled" => {
-                config.sync.enabled = value
-                    .parse()
-                    .map_err(|_| format!("Invalid boolean: {}", value))?;
-            }
-            "sync.server_url" => {
-                config.sync.server_url = value.to_string();
-            }
-            "sync.privacy_mode" => {
-                config.sync.privacy_mode = match value {
-                    "full_sync" => PrivacyMode::FullSync,
-                    "decisions_only" => PrivacyMode::DecisionsOnly,
-                    "blockers_only" => PrivacyMode::BlockersOnly,
-                    "disabled" => PrivacyMode::Disabled,
-                    _ => return Err(format!("Invalid privacy mode: {}", value)),
-                };
-            }
-            "sync.auto_reconnect" => {
-                config.sync.auto_reconnect = value
-                    .parse()
-                    .map_err(|_| format!("Invalid boolean: {}", value))?;
-            }
-            "sync.reconnect_delay_ms" => {
-                config.sync.reconnect_delay_ms = value
-                    .parse()
-                    .map_err(|_| format!("Invalid number: {}", value))?;
-            }
-            "sync.max_reconnect_delay_ms" => {
-                config.sync.max_reconnect_delay_ms = value
-                    .parse()
-                    .map_err(|_| format!("Invalid number: {}", value))?;
-            }
-            "terminal.voice.hotkey" => {
-                config.terminal.voice.hotkey = value.to_string();
-            }
-
-            _ => return Err(format!("Unknown or read-only config key: {}", key)),
-        }
-
-        // Validate before saving
-        config.validate()?;
-
-        // Save to specified level
-        self.loader.save(&config, target_level)?;
-
+        self.loader.set(target_level, key, parse_cli_value(value))?;
         Ok(())
     }
 
@@ -224,9 +158,9 @@ impl ConfigCli {
             if config_path.exists() {
                 std::fs::remove_file(&config_path)
                     .map_err(|e| format!("Failed to delete config file: {}", e))?;
-                println!("Reset {:?} configuration at {:?}", target_level, config_path);
+                tracing::info!(level = ?target_level, path = %config_path.display(), "Configuration reset");
             } else {
-                println!("No {:?} configuration file found", target_level);
+                tracing::info!(level = ?target_level, "No configuration file to reset");
             }
         }
 
@@ -299,7 +233,7 @@ impl ConfigCli {
         std::fs::write(output_path, toml)
             .map_err(|e| format!("Failed to write file: {}", e))?;
 
-        println!("✅ Configuration exported to {}", output_path);
+        tracing::info!(path = %output_path, "Configuration exported");
         Ok(())
     }
 
@@ -324,11 +258,20 @@ impl ConfigCli {
         // Save to specified level
         self.loader.save(&config, target_level)?;
 
-        println!("✅ Configuration imported to {:?} level", target_level);
+        tracing::info!(level = ?target_level, "Configuration imported");
         Ok(())
     }
 }
 
+/// Interpret a command-line value as a TOML literal (bool, number, array), else a string
+fn parse_cli_value(value: &str) -> toml::Value {
+    format!("value = {}", value)
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,6 +289,14 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_parse_cli_value() {
+        assert_eq!(parse_cli_value("true"), toml::Value::Boolean(true));
+        assert_eq!(parse_cli_value("2000"), toml::Value::Integer(2000));
+        assert_eq!(parse_cli_value("decisions_only"), toml::Value::String("decisions_only".to_string()));
+        assert_eq!(parse_cli_value("ws://localhost:43216"), toml::Value::String("ws://localhost:43216".to_string()));
+    }
+
     #[test]
     fn test_list_config() {
         let cli = ConfigCli::new(None).unwrap();
@@ -8,13 +8,16 @@
  * 1. VS Code extension = great for interactive use
  * 2. CLI commands = great for automation, CI/CD, scripting
  * 3. Need config management commands (get, set, list, reset)
- * 4. Result: Complete CLI for ÆtherLight operations
+ * 4. Need sprint plan commands usable in pipelines (validate, order, simulate; stdin via "-")
+ * 5. Result: Complete CLI for ÆtherLight operations
  *
  * PATTERN: Pattern-CLI-001 (Command-Line Interface)
- * RELATED: config module
+ * RELATED: config module, sprint module, bin/aetherlight.rs
  * PERFORMANCE: <100ms per command
  */
 
 pub mod config;
+pub mod sprint;
 
 pub use config::ConfigCli;
+pub use sprint::{SprintCli, SprintFormat, SprintSource};
@@ -0,0 +1,303 @@
+/**
+ * CLI Sprint Commands
+ *
+ * DESIGN DECISION: Sprint plan commands read a file path or stdin ("-")
+ * WHY: Pipelines generate plans on the fly (`cat plan.yaml | aetherlight sprint validate -`)
+ * and should not have to write a temp file first
+ *
+ * REASONING CHAIN:
+ * 1. "-" is the conventional stdin sentinel; anything else is a file path
+ * 2. Files: format from extension (.toml → TOML, otherwise YAML), as parse_sprint_file
+ * 3. Stdin has no extension: --format yaml|toml, YAML by default
+ * 4. Input read fully, then parse_sprint_str / parse_sprint_toml_str (parse + validate)
+ * 5. Every subcommand loads through SprintCli::load, so all accept "-"
+ * 6. `simulate` runs the plan through TaskScheduler, rendering a progress bar per update
+ *    (and writing progress signals when given a workflow directory)
+ *
+ * PATTERN: Pattern-CLI-001 (Command-Line Interface)
+ * RELATED: sprint_parser (parse_sprint_str), bin/aetherlight.rs
+ * PERFORMANCE: <100ms per command
+ */
+
+use crate::sprint_parser::{parse_sprint_file, parse_sprint_str, parse_sprint_toml_str, ExecutableSprintPlan};
+use crate::ipc::SignalWriter;
+use crate::task_scheduler::{ProgressEvent, TaskScheduler};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// CLI sprint command result (error is the message to print)
+pub type SprintResult<T> = Result<T, String>;
+
+/// Sprint plan syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SprintFormat {
+    Yaml,
+    Toml,
+}
+
+impl FromStr for SprintFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> SprintResult<Self> {
+        match s.to_lowercase().as_str() {
+            "yaml" | "yml" => Ok(SprintFormat::Yaml),
+            "toml" => Ok(SprintFormat::Toml),
+            _ => Err(format!("Unknown sprint plan format: {} (expected yaml or toml)", s)),
+        }
+    }
+}
+
+/// Where a sprint plan is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SprintSource {
+    /// Standard input ("-")
+    Stdin,
+    /// Plan file
+    File(PathBuf),
+}
+
+impl SprintSource {
+    /// "-" → Stdin, anything else → File
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            SprintSource::Stdin
+        } else {
+            SprintSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+/// Sprint CLI commands
+pub struct SprintCli;
+
+impl SprintCli {
+    /**
+     * Load and validate a plan
+     *
+     * `format` applies to stdin (default YAML); a file's extension decides its
+     * format unless `format` is given. `stdin` is injected so tests need no process.
+     */
+    pub fn load(
+        source: &SprintSource,
+        format: Option<SprintFormat>,
+        stdin: &mut impl Read,
+    ) -> SprintResult<ExecutableSprintPlan> {
+        let parsed = match (source, format) {
+            (SprintSource::File(path), None) => parse_sprint_file(path),
+            (SprintSource::File(path), Some(format)) => {
+                let contents = std::fs::read_to_string(path)
+                    .map_err(|e| format!("Failed to read sprint plan from {}: {}", path.display(), e))?;
+                Self::parse(&contents, format)
+            }
+            (SprintSource::Stdin, format) => {
+                let mut contents = String::new();
+                stdin
+                    .read_to_string(&mut contents)
+                    .map_err(|e| format!("Failed to read sprint plan from stdin: {}", e))?;
+                Self::parse(&contents, format.unwrap_or(SprintFormat::Yaml))
+            }
+        };
+
+        parsed.map_err(|e| e.to_string())
+    }
+
+    /**
+     * Validate a plan
+     *
+     * Examples:
+     * - aetherlight sprint validate sprints/oauth2.yaml
+     * - cat plan.toml | aetherlight sprint validate - --format toml
+     */
+    pub fn validate(
+        source: &SprintSource,
+        format: Option<SprintFormat>,
+        stdin: &mut impl Read,
+    ) -> SprintResult<String> {
+        match Self::load(source, format, stdin) {
+            Ok(plan) => Ok(format!(
+                "✅ Sprint plan '{}' is valid ({} tasks, {} parallel groups)",
+                plan.name,
+                plan.tasks.len(),
+                plan.parallel_groups.len()
+            )),
+            Err(e) => Err(format!("❌ Sprint plan validation failed:\n\n  - {}\n", e)),
+        }
+    }
+
+    /**
+     * Print the execution order (one task id per line)
+     *
+     * Example:
+     * - aetherlight sprint order - < plan.yaml
+     */
+    pub fn order(
+        source: &SprintSource,
+        format: Option<SprintFormat>,
+        stdin: &mut impl Read,
+    ) -> SprintResult<String> {
+        let plan = Self::load(source, format, stdin)?;
+        Ok(plan.execution_order.join("\n"))
+    }
+
+    /**
+     * Simulate execution, calling `on_progress` with a progress bar line per update
+     *
+     * DESIGN DECISION: Each simulated task reports done (1.0) before it completes
+     * WHY: No agents are spawned yet (AS-004), but the bar still shows the order
+     * tasks finish in and how the sprint total advances
+     *
+     * `workflow_dir` (e.g., `.lumina/workflow`) also receives a progress signal per
+     * update, so VS Code and the desktop app show the simulated sprint live.
+     *
+     * Example:
+     * - aetherlight sprint simulate sprints/oauth2.yaml
+     * - aetherlight sprint simulate sprints/oauth2.yaml --workflow-dir .lumina/workflow
+     */
+    pub fn simulate(
+        source: &SprintSource,
+        format: Option<SprintFormat>,
+        workflow_dir: Option<&Path>,
+        stdin: &mut impl Read,
+        mut on_progress: impl FnMut(String) + 'static,
+    ) -> SprintResult<String> {
+        let plan = Self::load(source, format, stdin)?;
+        let mut scheduler = TaskScheduler::new()
+            .with_progress_listener(move |event| on_progress(Self::progress_bar(event)));
+        if let Some(dir) = workflow_dir {
+            let writer = SignalWriter::new(dir)
+                .map_err(|e| format!("Cannot use workflow directory {}: {:#}", dir.display(), e))?;
+            scheduler = scheduler.with_progress_signals(writer);
+        }
+
+        let result = scheduler
+            .execute_sprint_with(plan, |task, reporter| {
+                reporter.report(1.0, task.title.clone());
+                Ok(())
+            })
+            .map_err(|e| e.to_string())?;
+
+        Ok(result.summary())
+    }
+
+    /**
+     * Render a progress event as a one-line bar
+     *
+     * Format: `[########------------]  40% DB-001 (100%): Create table`
+     */
+    pub fn progress_bar(event: &ProgressEvent) -> String {
+        const WIDTH: usize = 20;
+        let filled = ((event.sprint_progress * WIDTH as f64).round() as usize).min(WIDTH);
+
+        format!(
+            "[{}{}] {:>3.0}% {} ({:.0}%): {}",
+            "#".repeat(filled),
+            "-".repeat(WIDTH - filled),
+            event.sprint_progress * 100.0,
+            event.task_id,
+            event.fraction * 100.0,
+            event.message
+        )
+    }
+
+    fn parse(contents: &str, format: SprintFormat) -> crate::Result<ExecutableSprintPlan> {
+        match format {
+            SprintFormat::Yaml => parse_sprint_str(contents),
+            SprintFormat::Toml => parse_sprint_toml_str(contents),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAN_YAML: &str = r#"
+sprint:
+  name: "Pipeline Sprint"
+  duration: "1 week"
+  goals: ["Ship"]
+  tasks:
+    - id: "DB-001"
+      title: "Create table"
+      agent: "database"
+      duration: "2 hours"
+      dependencies: []
+      acceptance_criteria: ["Table exists"]
+    - id: "API-001"
+      title: "Add endpoint"
+      agent: "api"
+      duration: "2 hours"
+      dependencies: []
+      acceptance_criteria: ["Endpoint responds"]
+"#;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(SprintSource::from_arg("-"), SprintSource::Stdin);
+        assert_eq!(SprintSource::from_arg("plan.yaml"), SprintSource::File(PathBuf::from("plan.yaml")));
+    }
+
+    #[test]
+    fn test_validate_from_stdin() {
+        let result = SprintCli::validate(&SprintSource::Stdin, None, &mut PLAN_YAML.as_bytes()).unwrap();
+        assert!(result.contains("'Pipeline Sprint' is valid (2 tasks"), "{}", result);
+
+        let order = SprintCli::order(&SprintSource::Stdin, None, &mut PLAN_YAML.as_bytes()).unwrap();
+        let mut ids: Vec<&str> = order.lines().collect();
+        ids.sort();
+        assert_eq!(ids, vec!["API-001", "DB-001"]);
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        let event = ProgressEvent {
+            task_id: "DB-001".to_string(),
+            fraction: 0.5,
+            message: "Halfway".to_string(),
+            sprint_progress: 0.25,
+        };
+        assert_eq!(SprintCli::progress_bar(&event), "[#####---------------]  25% DB-001 (50%): Halfway");
+    }
+
+    #[test]
+    fn test_simulate_reports_progress() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&lines);
+        let summary = SprintCli::simulate(&SprintSource::Stdin, None, None, &mut PLAN_YAML.as_bytes(), move |line| {
+            sink.borrow_mut().push(line)
+        })
+        .unwrap();
+
+        assert!(summary.contains("Tasks completed: 2"), "{}", summary);
+        let lines = lines.borrow();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains(" 50% "), "{}", lines[0]);
+        assert!(lines[1].starts_with("[####################] 100% "), "{}", lines[1]);
+    }
+
+    #[test]
+    fn test_stdin_format_flag() {
+        let toml = r#"
+[sprint]
+name = "TOML Sprint"
+duration = "1 week"
+goals = []
+
+[[sprint.tasks]]
+id = "DB-001"
+title = "Create table"
+agent = "database"
+duration = "2 hours"
+acceptance_criteria = ["Table exists"]
+"#;
+        let stdin = SprintSource::Stdin;
+        assert!(SprintCli::validate(&stdin, Some(SprintFormat::Toml), &mut toml.as_bytes()).is_ok());
+        // TOML read as YAML (default) is rejected
+        assert!(SprintCli::validate(&stdin, None, &mut toml.as_bytes()).is_err());
+        assert!("json".parse::<SprintFormat>().is_err());
+    }
+}
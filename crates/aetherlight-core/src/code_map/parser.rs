@@ -84,7 +84,7 @@ impl RustParser {
                     Ok(module) => modules.push(module),
                     Err(e) => {
                         // Log error but continue parsing other files
-                        eprintln!("Warning: Failed to parse {}: {}", path.display(), e);
+                        tracing::warn!(path = %path.display(), error = %e, "Failed to parse module");
                     }
                 }
             }
@@ -0,0 +1,92 @@
+/**
+ * SQLite Compaction - Reclaim free pages left by deletes and updates
+ *
+ * DESIGN DECISION: VACUUM + ANALYZE, then truncate the WAL
+ * WHY: SQLite keeps deleted pages on its freelist and never shrinks the file; the
+ * pattern, analytics and vector databases only ever grew on users' disks
+ *
+ * REASONING CHAIN:
+ * 1. Size = page_count * page_size (logical size, same for WAL and rollback journals)
+ * 2. VACUUM rewrites the database without free pages
+ * 3. ANALYZE refreshes query planner statistics for the rewritten tables
+ * 4. wal_checkpoint(TRUNCATE) moves the rewrite into the main file and empties the WAL,
+ *    so the file on disk shrinks now instead of at the next checkpoint
+ * 5. Reclaimed bytes = size before - size after
+ *
+ * TRADEOFF: VACUUM rewrites the whole file (needs free disk space of about the database
+ * size and an exclusive lock), so opportunistic callers only compact once
+ * needs_compaction() reports enough free pages
+ *
+ * PATTERN: Pattern-STORAGE-004 (Local Metadata Storage)
+ * RELATED: SqliteVectorStore::compact, UsageTracker::compact, desktop compact_databases command
+ */
+
+use crate::error::Result;
+use rusqlite::Connection;
+
+/// Share of free pages at which needs_compaction() reports true
+pub const COMPACTION_FREE_RATIO: f64 = 0.25;
+
+/// Compact the database behind `conn`; returns bytes reclaimed
+pub fn compact_sqlite(conn: &Connection) -> Result<u64> {
+    let before = database_bytes(conn)?;
+
+    conn.execute_batch("VACUUM; ANALYZE;")?;
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+
+    let after = database_bytes(conn)?;
+    Ok(before.saturating_sub(after))
+}
+
+/// Whether at least COMPACTION_FREE_RATIO of the database is free pages
+pub fn needs_compaction(conn: &Connection) -> Result<bool> {
+    let pages: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let free: i64 = conn.query_row("PRAGMA freelist_count", [], |row| row.get(0))?;
+    Ok(pages > 0 && free as f64 >= pages as f64 * COMPACTION_FREE_RATIO)
+}
+
+fn database_bytes(conn: &Connection) -> Result<u64> {
+    let pages: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+    Ok((pages * page_size) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Test: Deleting most rows leaves the file size unchanged until compaction shrinks it
+     */
+    #[test]
+    fn test_compaction_shrinks_file_after_deletes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("compact.sqlite");
+        let conn = Connection::open(&path).unwrap();
+        conn.query_row("PRAGMA journal_mode=WAL", [], |_| Ok(())).unwrap();
+        conn.execute("CREATE TABLE rows (id INTEGER PRIMARY KEY, body TEXT NOT NULL)", []).unwrap();
+
+        let body = "x".repeat(1024);
+        for id in 0..2000 {
+            conn.execute("INSERT INTO rows (id, body) VALUES (?1, ?2)", rusqlite::params![id, body]).unwrap();
+        }
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(())).unwrap();
+        assert!(!needs_compaction(&conn).unwrap());
+
+        conn.execute("DELETE FROM rows WHERE id >= 100", []).unwrap();
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(())).unwrap();
+        let size_before = std::fs::metadata(&path).unwrap().len();
+        assert!(needs_compaction(&conn).unwrap());
+
+        let reclaimed = compact_sqlite(&conn).unwrap();
+        let size_after = std::fs::metadata(&path).unwrap().len();
+
+        assert!(reclaimed > 0);
+        assert!(size_after < size_before, "{} -> {}", size_before, size_after);
+        assert_eq!(size_before - size_after, reclaimed);
+        assert!(!needs_compaction(&conn).unwrap());
+
+        let remaining: i64 = conn.query_row("SELECT COUNT(*) FROM rows", [], |row| row.get(0)).unwrap();
+        assert_eq!(remaining, 100);
+    }
+}
@@ -36,6 +36,9 @@
  * Total                      100%
  * ```
  *
+ * Weights above are the defaults; deployments override them with `ConfidenceWeights`
+ * (e.g. `[pattern_library.matching.weights]`), normalized to sum to 1.0.
+ *
  * # Examples
  *
  * ```rust
@@ -80,6 +83,10 @@ pub struct ConfidenceScore {
 
     /// Individual dimension scores (for transparency)
     breakdown: ConfidenceBreakdown,
+
+    /// Normalized weights the total was computed with (omitted from JSON when default)
+    #[serde(default, skip_serializing_if = "ConfidenceWeights::is_default")]
+    weights: ConfidenceWeights,
 }
 
 impl ConfidenceScore {
@@ -91,7 +98,7 @@ impl ConfidenceScore {
      *
      * REASONING CHAIN:
      * 1. Each dimension contributes to total score based on empirical weight
-     * 2. Weights sum to 1.0 (validated at compile time)
+     * 2. Default weights (sum 1.0); see calculate_weighted for custom weights
      * 3. All dimension scores must be in [0.0, 1.0] range
      * 4. Total score calculated as: Σ(dimension_score × weight)
      * 5. Result normalized to [0.0, 1.0] for consistent interpretation
@@ -108,21 +115,35 @@ impl ConfidenceScore {
      * ```
      */
     pub fn calculate(breakdown: ConfidenceBreakdown) -> Result<Self> {
+        Self::calculate_weighted(breakdown, ConfidenceWeights::default())
+    }
+
+    /**
+     * Calculate confidence score with custom dimension weights
+     *
+     * DESIGN DECISION: Weights normalized here, not required to sum to 1.0
+     * WHY: "Double the security weight" is easier to express than re-balancing
+     * ten numbers by hand; normalization keeps the total in [0.0, 1.0]
+     *
+     * # Examples
+     *
+     * ```rust
+     * let weights = ConfidenceWeights { security_score: 0.3, ..ConfidenceWeights::default() };
+     * let score = ConfidenceScore::calculate_weighted(breakdown, weights)?;
+     * ```
+     */
+    pub fn calculate_weighted(breakdown: ConfidenceBreakdown, weights: ConfidenceWeights) -> Result<Self> {
         // Validate all dimension scores are in valid range
         breakdown.validate()?;
+        weights.validate()?;
+        let weights = weights.normalized();
 
         // Calculate weighted sum
-        let total_score =
-            breakdown.semantic_similarity * 0.30 +
-            breakdown.context_match * 0.15 +
-            breakdown.keyword_overlap * 0.10 +
-            breakdown.historical_success_rate * 0.15 +
-            breakdown.pattern_recency * 0.05 +
-            breakdown.user_preference * 0.10 +
-            breakdown.team_usage * 0.05 +
-            breakdown.global_usage * 0.05 +
-            breakdown.security_score * 0.03 +
-            breakdown.code_quality_score * 0.02;
+        let total_score: f64 = breakdown
+            .weighted_contributions(&weights)
+            .iter()
+            .map(|(_, _, contribution)| contribution)
+            .sum();
 
         // Ensure total score is in valid range (floating point precision)
         let total_score = total_score.clamp(0.0, 1.0);
@@ -130,6 +151,7 @@ impl ConfidenceScore {
         Ok(Self {
             total_score,
             breakdown,
+            weights,
         })
     }
 
@@ -143,6 +165,11 @@ impl ConfidenceScore {
         &self.breakdown
     }
 
+    /// Get the (normalized) dimension weights used for the total score
+    pub fn weights(&self) -> &ConfidenceWeights {
+        &self.weights
+    }
+
     /**
      * Check if confidence meets threshold
      *
@@ -169,6 +196,188 @@ impl ConfidenceScore {
     pub fn meets_threshold(&self, threshold: f64) -> bool {
         self.total_score >= threshold
     }
+
+    /**
+     * Summarize score as total + top-3 contributing dimensions
+     *
+     * DESIGN DECISION: Rank dimensions by weighted contribution, not raw score
+     * WHY: A 0.9 security score (3% weight) explains less of the total than a
+     * 0.6 semantic score (30% weight); telemetry wants what actually drove the score
+     *
+     * REASONING CHAIN:
+     * 1. Compute contribution = dimension score × weight for all ten dimensions
+     * 2. Sort descending by contribution (stable: ties keep declaration order)
+     * 3. Keep top 3 for a payload small enough to log on every match
+     * 4. Full breakdown remains available via to_json()
+     *
+     * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+     * PERFORMANCE: O(1) (fixed ten dimensions)
+     *
+     * # Examples
+     *
+     * ```rust
+     * let compact = score.to_compact();
+     * println!("{:.2} driven by {}", compact.total_score, compact.top_dimensions[0].name);
+     * ```
+     */
+    pub fn to_compact(&self) -> CompactScore {
+        let mut contributions = self.breakdown.weighted_contributions(&self.weights);
+        contributions.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+
+        let top_dimensions = contributions
+            .iter()
+            .take(COMPACT_TOP_DIMENSIONS)
+            .map(|&(name, score, contribution)| DimensionContribution {
+                name: name.to_string(),
+                score,
+                contribution,
+            })
+            .collect();
+
+        CompactScore {
+            total_score: self.total_score,
+            top_dimensions,
+        }
+    }
+
+    /// Serialize full score (total + all ten dimensions) to JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Number of dimensions retained by `ConfidenceScore::to_compact()`
+const COMPACT_TOP_DIMENSIONS: usize = 3;
+
+/**
+ * Compact confidence summary for logging/telemetry
+ *
+ * DESIGN DECISION: Total score + top-3 dimensions instead of full breakdown
+ * WHY: Lightweight analytics don't need the ten-field payload on every event
+ *
+ * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+ * RELATED: ConfidenceScore::to_compact()
+ */
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompactScore {
+    /// Total confidence score [0.0, 1.0]
+    pub total_score: f64,
+
+    /// Highest contributing dimensions, ordered by contribution (descending)
+    pub top_dimensions: Vec<DimensionContribution>,
+}
+
+/// Single dimension's share of a confidence score
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DimensionContribution {
+    /// Dimension name (matches ConfidenceBreakdown field name)
+    pub name: String,
+
+    /// Raw dimension score [0.0, 1.0]
+    pub score: f64,
+
+    /// Weighted contribution to total score (score × weight)
+    pub contribution: f64,
+}
+
+/**
+ * Weight of each confidence dimension in the total score
+ *
+ * DESIGN DECISION: One weight per ConfidenceBreakdown field, normalized before use
+ * WHY: Deployments tune the linear combination (a security team raises security_score,
+ * a docs team keyword_overlap) without touching scoring code
+ *
+ * REASONING CHAIN:
+ * 1. Default = the empirical weights in the module table (sum exactly 1.0)
+ * 2. Every weight must be finite and ≥ 0 (0 = ignore the dimension)
+ * 3. Sum must be > 0, otherwise nothing contributes
+ * 4. Weights divided by their sum → total score stays in [0.0, 1.0]
+ * 5. Default weights normalize to themselves, so default scoring is unchanged
+ *
+ * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+ * RELATED: ConfidenceScore::calculate_weighted, MatchingConfig::weights
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfidenceWeights {
+    pub semantic_similarity: f64,
+    pub context_match: f64,
+    pub keyword_overlap: f64,
+    pub historical_success_rate: f64,
+    pub pattern_recency: f64,
+    pub user_preference: f64,
+    pub team_usage: f64,
+    pub global_usage: f64,
+    pub security_score: f64,
+    pub code_quality_score: f64,
+}
+
+impl Default for ConfidenceWeights {
+    fn default() -> Self {
+        Self {
+            semantic_similarity: 0.30,
+            context_match: 0.15,
+            keyword_overlap: 0.10,
+            historical_success_rate: 0.15,
+            pattern_recency: 0.05,
+            user_preference: 0.10,
+            team_usage: 0.05,
+            global_usage: 0.05,
+            security_score: 0.03,
+            code_quality_score: 0.02,
+        }
+    }
+}
+
+impl ConfidenceWeights {
+    /// Weights in ConfidenceBreakdown field order
+    fn values(&self) -> [f64; 10] {
+        [
+            self.semantic_similarity,
+            self.context_match,
+            self.keyword_overlap,
+            self.historical_success_rate,
+            self.pattern_recency,
+            self.user_preference,
+            self.team_usage,
+            self.global_usage,
+            self.security_score,
+            self.code_quality_score,
+        ]
+    }
+
+    /// Check every weight is finite and non-negative and the sum is positive
+    pub fn validate(&self) -> Result<()> {
+        if let Some(&weight) = self.values().iter().find(|w| !w.is_finite() || **w < 0.0) {
+            return Err(Error::InvalidConfidenceWeights(weight));
+        }
+        let sum: f64 = self.values().iter().sum();
+        if sum <= 0.0 {
+            return Err(Error::InvalidConfidenceWeights(sum));
+        }
+        Ok(())
+    }
+
+    /// Weights scaled to sum to 1.0 (call after validate)
+    pub fn normalized(&self) -> Self {
+        let sum: f64 = self.values().iter().sum();
+        Self {
+            semantic_similarity: self.semantic_similarity / sum,
+            context_match: self.context_match / sum,
+            keyword_overlap: self.keyword_overlap / sum,
+            historical_success_rate: self.historical_success_rate / sum,
+            pattern_recency: self.pattern_recency / sum,
+            user_preference: self.user_preference / sum,
+            team_usage: self.team_usage / sum,
+            global_usage: self.global_usage / sum,
+            security_score: self.security_score / sum,
+            code_quality_score: self.code_quality_score / sum,
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 /**
@@ -257,6 +466,27 @@ impl ConfidenceBreakdown {
         ConfidenceBreakdownBuilder::default()
     }
 
+    /**
+     * Per-dimension (name, score, weighted contribution) triples
+     *
+     * DESIGN DECISION: Single source of truth for dimension weights
+     * WHY: Total score calculation and compact summaries must agree on weights
+     */
+    fn weighted_contributions(&self, weights: &ConfidenceWeights) -> Vec<(&'static str, f64, f64)> {
+        vec![
+            ("semantic_similarity", self.semantic_similarity, self.semantic_similarity * weights.semantic_similarity),
+            ("context_match", self.context_match, self.context_match * weights.context_match),
+            ("keyword_overlap", self.keyword_overlap, self.keyword_overlap * weights.keyword_overlap),
+            ("historical_success_rate", self.historical_success_rate, self.historical_success_rate * weights.historical_success_rate),
+            ("pattern_recency", self.pattern_recency, self.pattern_recency * weights.pattern_recency),
+            ("user_preference", self.user_preference, self.user_preference * weights.user_preference),
+            ("team_usage", self.team_usage, self.team_usage * weights.team_usage),
+            ("global_usage", self.global_usage, self.global_usage * weights.global_usage),
+            ("security_score", self.security_score, self.security_score * weights.security_score),
+            ("code_quality_score", self.code_quality_score, self.code_quality_score * weights.code_quality_score),
+        ]
+    }
+
     /**
      * Validate all dimension scores are in valid range
      *
@@ -476,6 +706,106 @@ mod tests {
         assert!(!score.meets_threshold(1.0));
     }
 
+    /**
+     * Test: Compact score keeps top-3 dimensions ordered by weighted contribution
+     */
+    #[test]
+    fn test_compact_score_top_dimensions() {
+        // Contributions: semantic 0.6*0.30=0.18, historical 0.9*0.15=0.135,
+        // user_preference 1.0*0.10=0.10, context 0.2*0.15=0.03, security 1.0*0.03=0.03
+        let breakdown = ConfidenceBreakdown::builder()
+            .semantic_similarity(0.6)
+            .context_match(0.2)
+            .keyword_overlap(0.1)
+            .historical_success_rate(0.9)
+            .pattern_recency(0.1)
+            .user_preference(1.0)
+            .team_usage(0.1)
+            .global_usage(0.1)
+            .security_score(1.0)
+            .code_quality_score(1.0)
+            .build()
+            .unwrap();
+
+        let score = ConfidenceScore::calculate(breakdown).unwrap();
+        let compact = score.to_compact();
+
+        assert_eq!(compact.total_score, score.total_score());
+        assert_eq!(compact.top_dimensions.len(), 3);
+
+        let names: Vec<&str> = compact.top_dimensions.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["semantic_similarity", "historical_success_rate", "user_preference"]);
+        assert!((compact.top_dimensions[0].contribution - 0.18).abs() < 1e-9);
+        assert_eq!(compact.top_dimensions[1].score, 0.9);
+    }
+
+    /**
+     * Test: Custom weights are normalized; default weights reproduce calculate()
+     */
+    #[test]
+    fn test_custom_weights() {
+        let breakdown = ConfidenceBreakdown::builder()
+            .semantic_similarity(0.2)
+            .keyword_overlap(0.9)
+            .security_score(1.0)
+            .build()
+            .unwrap();
+
+        let default = ConfidenceScore::calculate(breakdown.clone()).unwrap();
+        let explicit = ConfidenceScore::calculate_weighted(breakdown.clone(), ConfidenceWeights::default()).unwrap();
+        assert_eq!(default.total_score(), explicit.total_score());
+        assert_eq!(ConfidenceWeights::default().normalized(), ConfidenceWeights::default());
+
+        // Only keyword_overlap and security_score count, 3:1 (un-normalized input)
+        let docs_team = ConfidenceWeights {
+            keyword_overlap: 3.0,
+            security_score: 1.0,
+            ..zero_weights()
+        };
+        let score = ConfidenceScore::calculate_weighted(breakdown, docs_team).unwrap();
+        assert!((score.total_score() - (0.9 * 0.75 + 1.0 * 0.25)).abs() < 1e-9);
+        assert_eq!(score.to_compact().top_dimensions[0].name, "keyword_overlap");
+        assert!((score.weights().keyword_overlap - 0.75).abs() < 1e-12);
+    }
+
+    /**
+     * Test: Negative weights and all-zero weights are rejected
+     */
+    #[test]
+    fn test_weights_validation() {
+        assert!(matches!(zero_weights().validate(), Err(Error::InvalidConfidenceWeights(_))));
+
+        let negative = ConfidenceWeights { context_match: -0.1, ..ConfidenceWeights::default() };
+        assert!(matches!(negative.validate(), Err(Error::InvalidConfidenceWeights(w)) if w == -0.1));
+        assert!(ConfidenceScore::calculate_weighted(ConfidenceBreakdown::default(), negative).is_err());
+    }
+
+    fn zero_weights() -> ConfidenceWeights {
+        ConfidenceWeights {
+            semantic_similarity: 0.0,
+            context_match: 0.0,
+            keyword_overlap: 0.0,
+            historical_success_rate: 0.0,
+            pattern_recency: 0.0,
+            user_preference: 0.0,
+            team_usage: 0.0,
+            global_usage: 0.0,
+            security_score: 0.0,
+            code_quality_score: 0.0,
+        }
+    }
+
+    /**
+     * Test: Full JSON round-trips back to an identical score
+     */
+    #[test]
+    fn test_score_to_json() {
+        let score = ConfidenceScore::calculate(ConfidenceBreakdown::default()).unwrap();
+        let json = score.to_json().unwrap();
+        let deserialized: ConfidenceScore = serde_json::from_str(&json).unwrap();
+        assert_eq!(score, deserialized);
+    }
+
     /**
      * Test: Breakdown is serializable
      */
@@ -0,0 +1,139 @@
+/**
+ * Configuration Access - Typed get/set of single values by dotted path
+ *
+ * DESIGN DECISION: Address values by their TOML path ("sync.reconnect_delay_ms")
+ * WHY: CLI and settings UI need to read/update one nested value without a hand-written
+ * match arm per key; the TOML path is already what users see in their config files
+ *
+ * REASONING CHAIN:
+ * 1. Serialize AetherlightConfig to a toml::Value tree, walk the path segments
+ * 2. get::<T>() deserializes the leaf, so callers get bool/u64/PrivacyMode directly
+ * 3. Writes go into the raw tier table (sparse tier files stay sparse)
+ * 4. The edited table must deserialize into AetherlightConfig (type check) and the
+ *    key must survive re-serialization (serde silently drops unknown keys)
+ * 5. ConfigLoader::set validates the re-resolved config before persisting
+ *
+ * PATTERN: Pattern-CONFIG-001 (Hierarchical Configuration)
+ * RELATED: loader.rs (ConfigLoader::set), validator.rs, cli/config.rs
+ * PERFORMANCE: <1ms per access (one serialization of the config)
+ */
+
+use super::AetherlightConfig;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+impl AetherlightConfig {
+    /**
+     * Get the raw TOML value at a dotted path
+     *
+     * Example: `config.get_value("terminal.voice.hotkey")`
+     */
+    pub fn get_value(&self, path: &str) -> Result<toml::Value, String> {
+        let root = toml::Value::try_from(self)
+            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+
+        let mut current = &root;
+        for segment in split_path(path)? {
+            current = current
+                .get(segment)
+                .ok_or_else(|| format!("Unknown config key: {}", path))?;
+        }
+
+        Ok(current.clone())
+    }
+
+    /**
+     * Get a typed value at a dotted path
+     *
+     * Example: `config.get::<u64>("sync.reconnect_delay_ms")`
+     */
+    pub fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        self.get_value(path)?
+            .try_into()
+            .map_err(|e| format!("Config value {} has unexpected type: {}", path, e))
+    }
+}
+
+/// Convert any serializable value into a TOML value
+pub(crate) fn to_toml_value<T: Serialize>(value: T) -> Result<toml::Value, String> {
+    toml::Value::try_from(value).map_err(|e| format!("Unsupported config value: {}", e))
+}
+
+/**
+ * Set `value` at `path` in a tier table, returning the tier config it parses to
+ *
+ * Intermediate tables are created as needed. Fails on type mismatches and on keys
+ * AetherlightConfig doesn't know (the table is left modified either way; callers
+ * discard it on error).
+ */
+pub(crate) fn set_in_table(
+    table: &mut toml::Table,
+    path: &str,
+    value: toml::Value,
+) -> Result<AetherlightConfig, String> {
+    let segments = split_path(path)?;
+    let (leaf, parents) = segments.split_last().expect("split_path returns at least one segment");
+
+    let mut current = &mut *table;
+    for segment in parents {
+        current = current
+            .entry(segment.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| format!("Config key {} is not a section", segment))?;
+    }
+    current.insert(leaf.to_string(), value);
+
+    let config: AetherlightConfig = toml::Value::Table(table.clone())
+        .try_into()
+        .map_err(|e| format!("Invalid value for {}: {}", path, e))?;
+
+    if config.get_value(path).is_err() {
+        return Err(format!("Unknown config key: {}", path));
+    }
+
+    Ok(config)
+}
+
+fn split_path(path: &str) -> Result<Vec<&str>, String> {
+    let segments: Vec<&str> = path.split('.').collect();
+    if segments.iter().any(|s| s.trim().is_empty()) {
+        return Err(format!("Invalid config key: '{}'", path));
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PrivacyMode;
+
+    #[test]
+    fn test_get_nested_value() {
+        let config = AetherlightConfig::default();
+
+        assert_eq!(config.get::<bool>("sync.enabled").unwrap(), config.sync.enabled);
+        assert_eq!(config.get::<String>("terminal.voice.hotkey").unwrap(), config.terminal.voice.hotkey);
+        assert_eq!(config.get::<PrivacyMode>("sync.privacy_mode").unwrap(), PrivacyMode::DecisionsOnly);
+        assert!(config.get_value("pattern_library.matching").unwrap().is_table());
+
+        assert!(config.get_value("sync.no_such_key").unwrap_err().contains("Unknown config key"));
+        assert!(config.get::<bool>("sync.server_url").unwrap_err().contains("unexpected type"));
+        assert!(config.get_value("sync..enabled").is_err());
+    }
+
+    #[test]
+    fn test_set_in_table_checks_type_and_key() {
+        let mut table = toml::Table::new();
+        let config = set_in_table(&mut table, "sync.reconnect_delay_ms", to_toml_value(2500u64).unwrap()).unwrap();
+        assert_eq!(config.sync.reconnect_delay_ms, 2500);
+        assert_eq!(table.len(), 1); // Sparse: only the edited section
+
+        assert!(set_in_table(&mut table.clone(), "sync.reconnect_delay_ms", to_toml_value("soon").unwrap())
+            .unwrap_err()
+            .contains("Invalid value"));
+        assert!(set_in_table(&mut table.clone(), "sync.reconect_delay_ms", to_toml_value(1u64).unwrap())
+            .unwrap_err()
+            .contains("Unknown config key"));
+    }
+}
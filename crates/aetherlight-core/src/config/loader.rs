@@ -16,12 +16,15 @@
  * PERFORMANCE: <50ms config load, <100ms hot reload
  */
 
+use super::access::{set_in_table, to_toml_value};
 use super::{
-    CodeAnalysisConfig, PatternExtractionConfig, PatternValidationConfig,
+    CodeAnalysisConfig, ConfigValidator, PatternExtractionConfig, PatternValidationConfig,
     RealtimeSyncDeduplicationConfig, RealtimeSyncEventsConfig, RealtimeSyncUiConfig, SyncConfig,
     TerminalConfig, TerminalIntentConfig, TerminalMultiPassConfig, TerminalOutcomesConfig,
     TerminalValidationConfig,
 };
+use crate::embeddings::EmbeddingConfig;
+use crate::matching::MatchingConfig;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -103,7 +106,7 @@ pub struct AetherlightConfig {
     pub source_path: Option<PathBuf>,
 }
 
-/// Pattern library configuration (extraction + validation)
+/// Pattern library configuration (extraction + validation + matching)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct PatternLibraryConfig {
@@ -114,6 +117,14 @@ pub struct PatternLibraryConfig {
     /// Pattern validation configuration
     #[serde(default)]
     pub validation: PatternValidationConfig,
+
+    /// Keyword matching configuration (preprocessing, fuzzy, synonyms)
+    #[serde(default)]
+    pub matching: MatchingConfig,
+
+    /// Embedding model configuration (precision) for the semantic pattern index
+    #[serde(default)]
+    pub embeddings: EmbeddingConfig,
 }
 
 impl Default for PatternLibraryConfig {
@@ -121,6 +132,8 @@ impl Default for PatternLibraryConfig {
         Self {
             extraction: PatternExtractionConfig::default(),
             validation: PatternValidationConfig::default(),
+            matching: MatchingConfig::default(),
+            embeddings: EmbeddingConfig::default(),
         }
     }
 }
@@ -243,6 +256,7 @@ impl AetherlightConfig {
         self.code_analysis.validate()?;
         self.pattern_library.extraction.validate()?;
         self.pattern_library.validation.validate()?;
+        self.pattern_library.matching.validate()?;
         self.realtime_sync.events.validate()?;
         self.realtime_sync.deduplication.validate()?;
         self.realtime_sync.ui.validate()?;
@@ -284,6 +298,12 @@ impl ConfigLoader {
         self
     }
 
+    /// Override the team/user config directory (tests, portable installs)
+    pub fn with_config_dir(mut self, config_dir: PathBuf) -> Self {
+        self.config_dir = config_dir;
+        self
+    }
+
     /**
      * Load configuration with full 4-tier hierarchy
      *
@@ -293,11 +313,24 @@ impl ConfigLoader {
      * PERFORMANCE: <50ms (parallelized file loading)
      */
     pub fn load(&self) -> Result<AetherlightConfig, String> {
+        self.resolve(None)
+    }
+
+    /**
+     * Merge all levels, substituting `replacement` for its level's file
+     *
+     * Lets set() check the outcome of an edit before anything is written.
+     */
+    fn resolve(&self, replacement: Option<&AetherlightConfig>) -> Result<AetherlightConfig, String> {
         let mut config = AetherlightConfig::default();
 
         // Load in priority order (lowest to highest)
         for level in ConfigLevel::all_levels() {
-            if let Some(level_config) = self.load_level(level)? {
+            let level_config = match replacement {
+                Some(replacement) if replacement.level == level => Some(replacement.clone()),
+                _ => self.load_level(level)?,
+            };
+            if let Some(level_config) = level_config {
                 config.merge(&level_config);
             }
         }
@@ -426,6 +459,58 @@ impl ConfigLoader {
         Ok(())
     }
 
+    /**
+     * Set a single value at a dotted path in one level's file, then re-resolve
+     *
+     * DESIGN DECISION: Edit the level's raw TOML table, validate the resolved result, then write
+     * WHY: save() writes every field, turning a one-key user.toml into a full snapshot that
+     * pins defaults; editing the table keeps level files sparse. Nothing is persisted if
+     * the value has the wrong type, the key is unknown, or ConfigValidator rejects the result
+     *
+     * Example: `loader.set(ConfigLevel::User, "sync.reconnect_delay_ms", 2000u64)?`
+     *
+     * Returns the newly resolved configuration.
+     */
+    pub fn set<T: Serialize>(
+        &self,
+        level: ConfigLevel,
+        key: &str,
+        value: T,
+    ) -> Result<AetherlightConfig, String> {
+        let path = self.get_config_path(level)?;
+
+        let mut table: toml::Table = if path.exists() {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read config at {:?}: {}", path, e))?;
+            content
+                .parse()
+                .map_err(|e| format!("Failed to parse config at {:?}: {}", path, e))?
+        } else {
+            toml::Table::new()
+        };
+
+        let mut level_config = set_in_table(&mut table, key, to_toml_value(value)?)?;
+        level_config.level = level;
+        level_config.source_path = Some(path.clone());
+
+        let join = |errors: Vec<String>| errors.join("; ");
+        ConfigValidator::validate_for_level(&level_config, level).map_err(join)?;
+
+        let resolved = self.resolve(Some(&level_config))?;
+        ConfigValidator::validate(&resolved).map_err(join)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+        let content = toml::to_string_pretty(&table)
+            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+        fs::write(&path, content)
+            .map_err(|e| format!("Failed to write config to {:?}: {}", path, e))?;
+
+        Ok(resolved)
+    }
+
     /**
      * Get all config file paths (for debugging/CLI)
      */
@@ -504,6 +589,49 @@ mod tests {
         );
     }
 
+    fn temp_loader(dir: &tempfile::TempDir) -> ConfigLoader {
+        ConfigLoader::new()
+            .unwrap()
+            .with_config_dir(dir.path().to_path_buf())
+            .with_project_dir(dir.path().join("project"))
+    }
+
+    #[test]
+    fn test_set_writes_user_level() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = temp_loader(&dir);
+
+        let resolved = loader.set(ConfigLevel::User, "sync.reconnect_delay_ms", 2500u64).unwrap();
+        assert_eq!(resolved.get::<u64>("sync.reconnect_delay_ms").unwrap(), 2500);
+        assert_eq!(resolved.level, ConfigLevel::User);
+
+        // Persisted sparsely and picked up by the next load
+        let written = fs::read_to_string(dir.path().join("user.toml")).unwrap();
+        assert_eq!(written.trim(), "[sync]\nreconnect_delay_ms = 2500");
+        assert_eq!(loader.load().unwrap().sync.reconnect_delay_ms, 2500);
+
+        // Later sets keep earlier keys
+        loader.set(ConfigLevel::User, "terminal.voice.hotkey", "F14").unwrap();
+        let reloaded = loader.load().unwrap();
+        assert_eq!(reloaded.terminal.voice.hotkey, "F14");
+        assert_eq!(reloaded.sync.reconnect_delay_ms, 2500);
+    }
+
+    #[test]
+    fn test_set_rejects_invalid_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = temp_loader(&dir);
+
+        // Type-correct but fails ConfigValidator (must be > 0)
+        let err = loader.set(ConfigLevel::User, "sync.reconnect_delay_ms", 0u64).unwrap_err();
+        assert!(err.contains("reconnect_delay_ms"), "{}", err);
+        // Wrong type, unknown key
+        assert!(loader.set(ConfigLevel::User, "sync.enabled", "yes").is_err());
+        assert!(loader.set(ConfigLevel::User, "sync.no_such_key", true).is_err());
+
+        assert!(!dir.path().join("user.toml").exists(), "nothing persisted");
+    }
+
     #[test]
     fn test_get_all_paths() {
         let loader = ConfigLoader::new().unwrap();
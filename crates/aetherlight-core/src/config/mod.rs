@@ -16,6 +16,7 @@
  * PERFORMANCE: <50ms config load, <100ms hot reload
  */
 
+mod access;
 pub mod features;
 pub mod loader;
 pub mod policy;
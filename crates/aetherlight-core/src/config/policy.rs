@@ -402,7 +402,7 @@ impl PolicyBuilder {
         fs::write(&policy_path, toml)
             .map_err(|e| format!("Failed to write policy file: {}", e))?;
 
-        println!("✅ Policy saved to {:?}", policy_path);
+        tracing::info!(path = %policy_path.display(), "Policy saved");
         Ok(())
     }
 }
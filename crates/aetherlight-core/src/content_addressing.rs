@@ -177,6 +177,24 @@ pub fn calculate_sha256(content: &str) -> String {
     format!("{:x}", result) // Hex string (64 chars)
 }
 
+/// Normalize pattern title + body before content hashing
+///
+/// DESIGN DECISION: Collapse whitespace runs and line endings, keep case
+/// WHY: Re-imported patterns differ in CRLF/indentation noise, not meaning;
+/// case can be significant inside code snippets
+pub fn normalize_pattern_content(title: &str, content: &str) -> String {
+    let collapse = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("{}\n{}", collapse(title), collapse(content))
+}
+
+/// SHA256 of normalized pattern content (duplicate detection key)
+///
+/// DESIGN DECISION: Hash content, not id
+/// WHY: Same pattern synced from two sources gets two ids but one hash
+pub fn pattern_content_hash(title: &str, content: &str) -> String {
+    calculate_sha256(&normalize_pattern_content(title, content))
+}
+
 /// Hash verification cache
 ///
 /// DESIGN DECISION: 5-minute TTL cache to avoid redundant verification
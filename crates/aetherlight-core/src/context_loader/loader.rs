@@ -100,7 +100,7 @@ impl SectionLoader {
                 combined.push_str(&content);
             } else {
                 // Domain context not found - not an error, just skip
-                eprintln!("⚠️ Domain context not found: {}", domain_file);
+                tracing::debug!(domain_file = %domain_file, "Domain context not found");
             }
         }
 
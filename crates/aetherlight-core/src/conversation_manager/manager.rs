@@ -8,7 +8,7 @@
  * 1. User starts conversation → Create session with unique ID
  * 2. System asks clarifying question → Store in session
  * 3. User responds → Retrieve session, update intent, ask next question
- * 4. All params filled → Execute function, close session
+ * 4. All params filled → ExecutionPolicy decides: execute, confirm, or clarify
  * 5. Session expires after 5 minutes → Clean up
  *
  * PATTERN: Pattern-CONVERSATION-001 (Multi-Turn Dialog Management)
@@ -18,7 +18,9 @@
 use crate::conversation_manager::types::{
     ConversationSession, SystemResponse, SessionState, SessionError
 };
-use crate::function_call_generator::{FunctionCallGenerator, FunctionCall};
+use crate::function_call_generator::{
+    FunctionCallGenerator, FunctionCall, ExecutionPolicy, ExecutionDecision, ParameterValue
+};
 use crate::function_registry::{FunctionRegistry, FunctionParameter};
 use std::sync::{Arc, RwLock};
 use std::collections::HashMap;
@@ -44,6 +46,9 @@ pub struct ConversationManager {
 
     /// Maximum turns per session
     max_turns: usize,
+
+    /// When complete calls run without a confirmation turn
+    policy: ExecutionPolicy,
 }
 
 impl ConversationManager {
@@ -66,9 +71,21 @@ impl ConversationManager {
             call_generator,
             registry,
             max_turns: 5,
+            policy: ExecutionPolicy::default(),
         }
     }
 
+    /**
+     * Use a custom execution policy
+     *
+     * Default confirms every call; e.g., auto-run high-confidence reads with
+     * `ExecutionPolicy::default().auto_execute_above(0.9).always_confirm("deleteCase")`
+     */
+    pub fn with_execution_policy(mut self, policy: ExecutionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
     /**
      * Start new conversation session
      *
@@ -229,6 +246,21 @@ impl ConversationManager {
         }
 
         let mut best_call = function_calls[0].clone();
+
+        // Enum values only guessed at (near-miss words) are confirmed before anything else
+        if let Some(function) = self.registry.get(&best_call.function_id) {
+            let unsure = function.parameters.iter().find_map(|param| {
+                let value = best_call.parameters.get(&param.name)?;
+                enum_clarification(param, Some(value)).map(|response| (param.name.clone(), response))
+            });
+            if let Some((param_name, response)) = unsure {
+                session.pending_guess = best_call.parameters.remove(&param_name);
+                best_call.missing_params.insert(0, param_name);
+                session.intent = Some(best_call);
+                return Ok(response);
+            }
+        }
+
         session.intent = Some(best_call.clone());
 
         // Check for missing required parameters
@@ -256,22 +288,22 @@ impl ConversationManager {
             });
         }
 
-        // All params present, ask for confirmation
-        session.state = SessionState::AwaitingConfirmation;
-        Ok(SystemResponse::Confirmation {
-            function_call: best_call.clone(),
-            summary: self.generate_confirmation_summary(&best_call),
-        })
+        // All params present, let the policy decide
+        Ok(self.complete_call_response(session, best_call))
     }
 
     /**
      * Process turn when session is active (gathering parameters)
+     *
+     * An answer naming no clear value, after "Did you mean "X" ...?", accepts X if it
+     * is affirmative ("yes", "ok", ...).
      */
     fn process_active_turn(
         &self,
         session: &mut ConversationSession,
         user_input: &str,
     ) -> Result<SystemResponse, SessionError> {
+        let pending_guess = session.pending_guess.take();
         let intent = session.intent
             .as_mut()
             .ok_or_else(|| SessionError::InvalidState {
@@ -301,7 +333,20 @@ impl ConversationManager {
             ))?;
 
         // Extract parameter value from user input
-        if let Some(value) = self.extract_parameter_value(user_input, param_def)? {
+        let mut value = self.extract_parameter_value(user_input, param_def)?;
+        if let Some(response) = enum_clarification(param_def, value.as_ref()) {
+            match pending_guess {
+                Some(guess) if is_affirmative(user_input) => {
+                    value = Some(ParameterValue { confidence: 1.0, ..guess });
+                }
+                _ => {
+                    session.pending_guess = value;
+                    return Ok(response);
+                }
+            }
+        }
+
+        if let Some(value) = value {
             intent.parameters.insert(missing_param.clone(), value);
             intent.missing_params.remove(0);
         } else {
@@ -330,12 +375,9 @@ impl ConversationManager {
             });
         }
 
-        // All params filled, ask for confirmation
-        session.state = SessionState::AwaitingConfirmation;
-        Ok(SystemResponse::Confirmation {
-            function_call: intent.clone(),
-            summary: self.generate_confirmation_summary(intent),
-        })
+        // All params filled, let the policy decide
+        let intent = intent.clone();
+        Ok(self.complete_call_response(session, intent))
     }
 
     /**
@@ -349,22 +391,11 @@ impl ConversationManager {
         let user_input_lower = user_input.to_lowercase();
 
         // Check for affirmative response
-        if user_input_lower.contains("yes")
-            || user_input_lower.contains("confirm")
-            || user_input_lower.contains("ok")
-            || user_input_lower.contains("go ahead")
-        {
+        if is_affirmative(user_input) {
             session.state = SessionState::Completed;
 
             let intent = session.intent.as_ref().unwrap();
-            return Ok(SystemResponse::Execution {
-                function_id: intent.function_id.clone(),
-                result: serde_json::json!({
-                    "status": "ready_to_execute",
-                    "function": intent.function_id.clone(),
-                    "parameters": intent.parameters,
-                }),
-            });
+            return Ok(execution_response(intent));
         }
 
         // Check for negative response
@@ -397,19 +428,14 @@ impl ConversationManager {
         user_input: &str,
         param: &FunctionParameter,
     ) -> Result<Option<crate::function_call_generator::ParameterValue>, SessionError> {
-        use crate::function_call_generator::extractors;
+        use crate::function_call_generator::{extractors, numbers};
 
         let value = match param.param_type.as_str() {
             "string" => extractors::extract_proper_noun(user_input),
-            "number" => extractors::extract_number(user_input),
+            "number" => numbers::extract_number_localized(user_input, self.call_generator.locale()),
+            "currency" => numbers::extract_currency(user_input, self.call_generator.locale()),
             "date" | "date_range" => extractors::parse_temporal_expression(user_input),
-            "enum" => {
-                if let Some(ref allowed) = param.allowed_values {
-                    extractors::match_enum_value(user_input, allowed)
-                } else {
-                    extractors::match_enum_value(user_input, &param.examples)
-                }
-            }
+            "enum" => extractors::resolve_enum_value(user_input, param.enum_options(), &param.synonyms),
             "boolean" => extractors::infer_boolean(user_input),
             _ => None,
         };
@@ -417,6 +443,50 @@ impl ConversationManager {
         Ok(value)
     }
 
+    /**
+     * Response for a call with every required parameter filled
+     *
+     * DESIGN DECISION: ExecutionPolicy picks execute / confirm / clarify
+     * WHY: With an opt-in threshold, high-confidence reads skip the "Proceed?" turn;
+     * destructive functions and shaky matches still go through process_confirmation_turn
+     *
+     * Clarify here means the function match itself is doubtful (params are complete),
+     * so the question names the function and the yes/no answer is handled like a confirmation.
+     */
+    fn complete_call_response(
+        &self,
+        session: &mut ConversationSession,
+        call: FunctionCall,
+    ) -> SystemResponse {
+        let decision = self.policy.decide(&call);
+        let response = match decision {
+            ExecutionDecision::Execute => {
+                session.state = SessionState::Completed;
+                execution_response(&call)
+            }
+            ExecutionDecision::Confirm => {
+                session.state = SessionState::AwaitingConfirmation;
+                SystemResponse::Confirmation {
+                    summary: self.generate_confirmation_summary(&call),
+                    function_call: call.clone(),
+                }
+            }
+            ExecutionDecision::Clarify => {
+                session.state = SessionState::AwaitingConfirmation;
+                let description = self.registry
+                    .get(&call.function_id)
+                    .map(|f| f.description.clone())
+                    .unwrap_or_else(|| call.function_id.clone());
+                SystemResponse::Clarification {
+                    question: format!("Did you mean: {}?", description),
+                    options: vec!["Yes, go ahead".to_string(), "No, cancel".to_string()],
+                }
+            }
+        };
+        session.intent = Some(call);
+        response
+    }
+
     /**
      * Generate human-readable confirmation summary
      */
@@ -439,6 +509,68 @@ impl ConversationManager {
     }
 }
 
+/// "yes", "confirm", "ok", "go ahead" anywhere in the answer
+fn is_affirmative(user_input: &str) -> bool {
+    let user_input_lower = user_input.to_lowercase();
+    ["yes", "confirm", "ok", "go ahead"]
+        .iter()
+        .any(|word| user_input_lower.contains(word))
+}
+
+/// Execution response handed back to the host app
+fn execution_response(call: &FunctionCall) -> SystemResponse {
+    SystemResponse::Execution {
+        function_id: call.function_id.clone(),
+        result: serde_json::json!({
+            "status": "ready_to_execute",
+            "function": call.function_id.clone(),
+            "parameters": call.parameters,
+        }),
+    }
+}
+
+/**
+ * Clarification for an enum parameter that was not clearly named
+ *
+ * DESIGN DECISION: Offer the allowed values as options instead of failing the turn
+ * WHY: "pendng" or "the purple ones" should lead to "Which status did you mean?",
+ * not an error the user has to recover from
+ *
+ * REASONING CHAIN:
+ * 1. Non-enum parameters, or values at/above ENUM_CLARIFICATION_THRESHOLD → None
+ * 2. Low-confidence value → "Did you mean ...?" with the guess listed first
+ *    (kept as session.pending_guess, so "yes" accepts it)
+ * 3. No value → "Which ... did you mean?" with every allowed value
+ */
+fn enum_clarification(
+    param: &FunctionParameter,
+    value: Option<&crate::function_call_generator::ParameterValue>,
+) -> Option<SystemResponse> {
+    use crate::function_call_generator::extractors::ENUM_CLARIFICATION_THRESHOLD;
+
+    if param.param_type != "enum" {
+        return None;
+    }
+
+    let mut options = param.enum_options().to_vec();
+    match value {
+        Some(value) if value.confidence >= ENUM_CLARIFICATION_THRESHOLD => None,
+        Some(value) => {
+            let guess = value.value.as_str().unwrap_or_default().to_string();
+            options.retain(|option| *option != guess);
+            options.insert(0, guess.clone());
+            Some(SystemResponse::Clarification {
+                question: format!("Did you mean \"{}\" for the {}?", guess, param.description),
+                options,
+            })
+        }
+        None => Some(SystemResponse::Clarification {
+            question: format!("Which {} did you mean?", param.description),
+            options,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -469,6 +601,7 @@ mod tests {
                 description: "name parameter".to_string(),
                 examples: vec![],
                 allowed_values: None,
+                synonyms: HashMap::new(),
             }],
             examples: vec!["Test query".to_string()],
             tags: vec![],
@@ -507,4 +640,153 @@ mod tests {
         let cleaned = manager.cleanup_expired_sessions();
         assert_eq!(cleaned, 0);
     }
+
+    #[test]
+    fn test_complete_call_confirms_unless_auto_execute_opted_in() {
+        let temp_dir = tempdir().unwrap();
+        let model_path = "models/all-MiniLM-L6-v2.onnx";
+        let tokenizer_path = "models/all-MiniLM-L6-v2-tokenizer.json";
+        let db_path = temp_dir.path().join("test_conv_policy.db");
+
+        if !Path::new(model_path).exists() {
+            eprintln!("Skipping test: model not found");
+            return;
+        }
+
+        let registry = Arc::new(FunctionRegistry::new(model_path, tokenizer_path, &db_path).unwrap());
+        let generator = Arc::new(FunctionCallGenerator::new(registry.clone()));
+        let call = FunctionCall {
+            function_id: "legal.searchCases".to_string(),
+            parameters: HashMap::new(),
+            confidence: 0.99,
+            missing_params: vec![],
+            reasoning: String::new(),
+        };
+
+        // Default policy: even a near-certain call waits for "yes"
+        let manager = ConversationManager::new(generator.clone(), registry.clone());
+        let mut session = ConversationSession::new("s1".to_string(), "user1".to_string());
+        let response = manager.complete_call_response(&mut session, call.clone());
+        assert!(matches!(response, SystemResponse::Confirmation { .. }), "{:?}", response);
+        assert_eq!(session.state, SessionState::AwaitingConfirmation);
+
+        let response = manager.process_confirmation_turn(&mut session, "yes").unwrap();
+        assert!(matches!(response, SystemResponse::Execution { .. }), "{:?}", response);
+        assert_eq!(session.state, SessionState::Completed);
+
+        // Opted in: runs without the confirmation turn
+        let manager = ConversationManager::new(generator, registry)
+            .with_execution_policy(ExecutionPolicy::default().auto_execute_above(0.9));
+        let mut session = ConversationSession::new("s2".to_string(), "user1".to_string());
+        let response = manager.complete_call_response(&mut session, call);
+        assert!(matches!(response, SystemResponse::Execution { .. }), "{:?}", response);
+        assert_eq!(session.state, SessionState::Completed);
+    }
+
+    fn status_param() -> FunctionParameter {
+        FunctionParameter {
+            name: "status".to_string(),
+            param_type: "enum".to_string(),
+            required: true,
+            description: "task status".to_string(),
+            examples: vec![],
+            allowed_values: Some(vec!["open".to_string(), "pending".to_string(), "closed".to_string()]),
+            synonyms: HashMap::from([
+                ("pending".to_string(), vec!["in progress".to_string()]),
+                ("closed".to_string(), vec!["done".to_string()]),
+            ]),
+        }
+    }
+
+    /**
+     * Test: Whole dialog through process_turn: missing enum → near-miss → "yes" → confirm → execute
+     */
+    #[test]
+    fn test_turn_flow_accepts_guessed_enum_value() {
+        let temp_dir = tempdir().unwrap();
+        let mut registry = FunctionRegistry::with_embedder(
+            Arc::new(crate::KeywordEmbedder),
+            temp_dir.path().join("test_conv_flow.db"),
+        )
+        .unwrap();
+        registry.register(RegisteredFunction {
+            id: "tasks.setStatus".to_string(),
+            name: "setStatus".to_string(),
+            description: "Change the status of a task".to_string(),
+            parameters: vec![status_param()],
+            examples: vec!["change the task status".to_string()],
+            tags: vec![],
+            namespace: None,
+        }).unwrap();
+
+        let registry = Arc::new(registry);
+        let generator = Arc::new(FunctionCallGenerator::new(registry.clone()));
+        let manager = ConversationManager::new(generator, registry);
+
+        let (session_id, response) = manager
+            .start_session("user1".to_string(), "change the task status".to_string())
+            .unwrap();
+        assert!(matches!(response, SystemResponse::AskForMissingParam { ref param_name, .. } if param_name == "status"), "{:?}", response);
+
+        let response = manager.process_turn(&session_id, "pendng".to_string()).unwrap();
+        match response {
+            SystemResponse::Clarification { question, .. } => {
+                assert_eq!(question, "Did you mean \"pending\" for the task status?");
+            }
+            other => panic!("expected clarification, got {:?}", other),
+        }
+
+        // Status filled; the default policy asks before running (confirm, or clarify for a weak match)
+        let response = manager.process_turn(&session_id, "yes".to_string()).unwrap();
+        assert!(
+            matches!(response, SystemResponse::Confirmation { .. } | SystemResponse::Clarification { .. }),
+            "{:?}",
+            response
+        );
+
+        match manager.process_turn(&session_id, "yes".to_string()).unwrap() {
+            SystemResponse::Execution { function_id, result } => {
+                assert_eq!(function_id, "tasks.setStatus");
+                assert_eq!(result["parameters"]["status"]["value"], "pending");
+            }
+            other => panic!("expected execution, got {:?}", other),
+        }
+        assert_eq!(manager.get_session(&session_id).unwrap().state, SessionState::Completed);
+    }
+
+    #[test]
+    fn test_enum_synonym_needs_no_clarification() {
+        use crate::function_call_generator::extractors::resolve_enum_value;
+
+        let param = status_param();
+        for answer in ["in progress", "done", "closed"] {
+            let value = resolve_enum_value(answer, param.enum_options(), &param.synonyms);
+            assert!(value.is_some(), "{}", answer);
+            assert!(enum_clarification(&param, value.as_ref()).is_none(), "{}", answer);
+        }
+    }
+
+    #[test]
+    fn test_unmappable_enum_asks_for_clarification() {
+        use crate::function_call_generator::extractors::resolve_enum_value;
+
+        let param = status_param();
+        let value = resolve_enum_value("the purple ones", param.enum_options(), &param.synonyms);
+        match enum_clarification(&param, value.as_ref()) {
+            Some(SystemResponse::Clarification { question, options }) => {
+                assert_eq!(question, "Which task status did you mean?");
+                assert_eq!(options, vec!["open", "pending", "closed"]);
+            }
+            other => panic!("expected clarification, got {:?}", other),
+        }
+
+        // Near-miss word: the guess is offered first
+        let value = resolve_enum_value("pendng", param.enum_options(), &param.synonyms);
+        match enum_clarification(&param, value.as_ref()) {
+            Some(SystemResponse::Clarification { options, .. }) => {
+                assert_eq!(options, vec!["pending", "open", "closed"]);
+            }
+            other => panic!("expected clarification, got {:?}", other),
+        }
+    }
 }
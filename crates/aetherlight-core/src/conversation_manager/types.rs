@@ -7,7 +7,7 @@
  * PATTERN: Pattern-CONVERSATION-001 (Multi-Turn Dialog Management)
  */
 
-use crate::function_call_generator::FunctionCall;
+use crate::function_call_generator::{FunctionCall, ParameterValue};
 use chrono::{DateTime, Utc, Duration};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -39,6 +39,10 @@ pub struct ConversationSession {
     /// Partially filled function call (intent)
     pub intent: Option<FunctionCall>,
 
+    /// Enum value offered by the last "Did you mean ...?" (a "yes" accepts it)
+    #[serde(default)]
+    pub pending_guess: Option<ParameterValue>,
+
     /// Session creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -178,6 +182,7 @@ impl ConversationSession {
             user_id,
             turns: Vec::new(),
             intent: None,
+            pending_guess: None,
             created_at: now,
             last_activity: now,
             expires_at,
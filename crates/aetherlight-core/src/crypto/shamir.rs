@@ -26,12 +26,40 @@
  *
  * PATTERN: Pattern-TRUST-001 (Circle of Trust Key Sharing)
  * RELATED: Pattern-STORAGE-001 (Multi-Layer Storage), P3-010 (DHT distribution)
- * FUTURE: Proactive secret sharing (periodic re-sharing), verifiable secret sharing
+ * RESHARING: reshare() rebuilds the key from K current shards and deals a fresh set
+ * (new N, new K) one epoch later; the manager then rejects shards of older epochs.
+ * Shards carry their own epoch and K, so any manager recovers the newest set; persist
+ * epoch() and restore it with with_epoch() so a restarted manager still rejects old sets
+ *
+ * INTEGRITY: Each shard carries a SHA-256 commitment over its data and metadata;
+ * verify_shard checks it alone, reconstruction rejects shards that fail it.
+ * Shards encoded before commitments existed still decode (from_legacy_base64) but
+ * carry no commitment; reconstruction refuses them unless the caller opts in with
+ * allow_unverified_legacy()
+ *
+ * FUTURE: Verifiable secret sharing (Feldman commitments need a prime-order group,
+ * sharks works in GF(2^8))
  */
 
 use crate::{Result, Error};
 use sharks::{Sharks, Share};
 use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Domain separator hashed into every shard commitment
+const COMMITMENT_DOMAIN: &[u8] = b"lumina-key-shard-v1";
+
+/// Commitment size in bytes (SHA-256)
+const COMMITMENT_LEN: usize = 32;
+
+/// First byte of a versioned encoding; legacy encodings start with the share's x (never 0)
+const ENCODING_MARKER: u8 = 0x00;
+
+/// Current encoding version (see KeyShard::to_base64)
+const ENCODING_VERSION: u8 = 1;
+
+/// marker ‖ version ‖ epoch (u32 LE) ‖ shard_id ‖ threshold ‖ total_shards
+const ENCODING_HEADER_LEN: usize = 9;
 
 /**
  * Shamir Key Manager - Catastrophe Recovery via Distributed Trust
@@ -65,6 +93,8 @@ use rand::Rng;
 pub struct ShamirKeyManager {
     threshold: u8,  // K (minimum shards to reconstruct)
     shares: u8,     // N (total shards distributed)
+    epoch: u32,     // Current shard generation (bumped by reshare)
+    allow_unverified_legacy: bool, // Combine commitment-less (legacy) shards
 }
 
 impl ShamirKeyManager {
@@ -78,6 +108,8 @@ impl ShamirKeyManager {
         Self {
             threshold: 3,
             shares: 5,
+            epoch: 0,
+            allow_unverified_legacy: false,
         }
     }
 
@@ -107,7 +139,32 @@ impl ShamirKeyManager {
             ));
         }
 
-        Ok(Self { threshold, shares })
+        Ok(Self { threshold, shares, epoch: 0, allow_unverified_legacy: false })
+    }
+
+    /**
+     * Resume at a previously persisted epoch (see epoch())
+     *
+     * DESIGN DECISION: Epoch is restored by the caller, not derived from shards alone
+     * WHY: A fresh manager starts at epoch 0 and accepts any epoch; only the last
+     * epoch it dealt tells it which older shard sets have been revoked by reshare()
+     */
+    pub fn with_epoch(mut self, epoch: u32) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /**
+     * Accept legacy shards (no commitment) during reconstruction and reshare
+     *
+     * DESIGN DECISION: Off by default; legacy shards fail with MissingCommitment
+     * WHY: A commitment-less shard can't be checked, and a bad one yields a wrong key
+     * instead of an error. Opt in only to migrate shards dealt before commitments
+     * existed (reshare() them to get verifiable shards)
+     */
+    pub fn allow_unverified_legacy(mut self, allow: bool) -> Self {
+        self.allow_unverified_legacy = allow;
+        self
     }
 
     /**
@@ -151,11 +208,17 @@ impl ShamirKeyManager {
         let shards: Vec<KeyShard> = dealer
             .take(self.shares as usize)
             .enumerate()
-            .map(|(index, share)| KeyShard {
-                shard_id: index as u8 + 1,
-                total_shards: self.shares,
-                threshold: self.threshold,
-                data: Vec::from(&share),
+            .map(|(index, share)| {
+                let mut shard = KeyShard {
+                    shard_id: index as u8 + 1,
+                    total_shards: self.shares,
+                    threshold: self.threshold,
+                    epoch: self.epoch,
+                    data: Vec::from(&share),
+                    commitment: None,
+                };
+                shard.commitment = Some(shard.compute_commitment());
+                shard
             })
             .collect();
 
@@ -178,19 +241,23 @@ impl ShamirKeyManager {
      * - 2 shards (K=3): 0% of key (still underdetermined)
      * - 3 shards: 100% of key (can decrypt all patterns)
      *
+     * EPOCHS: All shards must come from one epoch, no older than the manager's; shards
+     * from before a reshare (or mixed generations) are rejected, never interpolated
+     * together. K is taken from the shards, so shards dealt by a reshare() on another
+     * manager instance recover here too
+     *
      * PERFORMANCE: O(K^2) operations, <5ms for typical parameters
      * PATTERN: Pattern-TRUST-001 (Circle of Trust Recovery)
      */
     pub fn reconstruct_master_key(&self, shards: &[KeyShard]) -> Result<[u8; 32]> {
-        if shards.len() < self.threshold as usize {
-            return Err(Error::Internal(format!(
-                "Insufficient shards: need {}, provided {}",
-                self.threshold,
-                shards.len()
-            )));
-        }
+        self.recover(shards).map(|(master_key, _epoch)| master_key)
+    }
 
-        let sharks = Sharks(self.threshold);
+    /// Reconstruct and report which epoch the shards belong to
+    fn recover(&self, shards: &[KeyShard]) -> Result<([u8; 32], u32)> {
+        let (epoch, threshold, shards) = self.current_epoch_shards(shards)?;
+
+        let sharks = Sharks(threshold);
 
         // Convert KeyShards to Share objects
         let shares: Vec<Share> = shards
@@ -214,7 +281,135 @@ impl ShamirKeyManager {
         let mut master_key = [0u8; 32];
         master_key.copy_from_slice(&recovered);
 
-        Ok(master_key)
+        Ok((master_key, epoch))
+    }
+
+    /**
+     * Check a single shard against its commitment, without any other shard
+     *
+     * DESIGN DECISION: Hash commitment over epoch, id, threshold, total and data
+     * WHY: A holder should learn their shard is damaged when they receive it, not
+     * during catastrophe recovery when K-1 others are already on the table
+     *
+     * - Ok(true): shard is exactly as dealt
+     * - Ok(false): any byte of data or metadata changed since dealing
+     * - Err(MalformedShard): data cannot be a share at all (empty)
+     * - Err(MissingCommitment): legacy shard, nothing to check against
+     *
+     * SECURITY: Detects corruption and naive tampering; someone rewriting the data
+     * and recomputing the hash is not detected (see FUTURE: verifiable sharing)
+     */
+    pub fn verify_shard(&self, shard: &KeyShard) -> std::result::Result<bool, ShamirError> {
+        if shard.data.len() < 2 {
+            return Err(ShamirError::MalformedShard {
+                shard_id: shard.shard_id,
+                reason: format!("share data is {} bytes, expected at least 2", shard.data.len()),
+            });
+        }
+
+        let Some(commitment) = shard.commitment else {
+            return Err(ShamirError::MissingCommitment { shard_id: shard.shard_id });
+        };
+
+        Ok(shard.compute_commitment() == commitment)
+    }
+
+    /**
+     * Re-share the master key under a new N and K, invalidating the old shards
+     *
+     * DESIGN DECISION: Reconstruct, then deal a fresh polynomial one epoch later
+     * WHY: Circles of trust change (a contact leaves, a new one joins, the user wants
+     * 4-of-7 instead of 3-of-5); redistributing by hand meant re-collecting every shard
+     *
+     * REASONING CHAIN:
+     * 1. Validate new_k/new_n like new() before touching any shard
+     * 2. Reconstruct from K distinct shards of the current epoch (else error)
+     * 3. Move one epoch past the shards', adopt new_k/new_n, split with a new random polynomial
+     * 4. The manager now rejects the old shards: different epoch, never combinable
+     *    with the new ones (the new polynomial shares no points with the old)
+     *
+     * TRADEOFF: The master key itself is unchanged, so anyone who kept K old shards
+     * and an old manager can still rebuild it; rotate the master key to revoke that
+     *
+     * PATTERN: Pattern-TRUST-001 (Circle of Trust)
+     */
+    pub fn reshare(&mut self, old_shards: &[KeyShard], new_n: u8, new_k: u8) -> Result<Vec<KeyShard>> {
+        let next = Self::new(new_k, new_n)?;
+        let (master_key, epoch) = self.recover(old_shards)?;
+
+        self.threshold = next.threshold;
+        self.shares = next.shares;
+        self.epoch = epoch + 1;
+
+        self.split_master_key(&master_key)
+    }
+
+    /**
+     * At least K distinct shards from a single epoch no older than the manager's
+     *
+     * Epoch and K come from the shards (both covered by their commitments); shards
+     * must agree on them. Duplicates (same shard_id) count once; a stale or
+     * mixed-epoch set is an error even when it holds enough shards.
+     */
+    fn current_epoch_shards<'a>(&self, shards: &'a [KeyShard]) -> Result<(u32, u8, Vec<&'a KeyShard>)> {
+        let (epoch, threshold) = shards
+            .first()
+            .map_or((self.epoch, self.threshold), |shard| (shard.epoch, shard.threshold));
+
+        if let Some(mixed) = shards.iter().find(|shard| shard.epoch != epoch) {
+            return Err(Error::Internal(format!(
+                "Shard {} is from epoch {}, shard {} from epoch {} (shards from different epochs cannot be combined)",
+                mixed.shard_id, mixed.epoch, shards[0].shard_id, epoch
+            )));
+        }
+
+        if epoch < self.epoch {
+            return Err(Error::Internal(format!(
+                "Shards are from epoch {}, current epoch is {} (replaced by a reshare)",
+                epoch, self.epoch
+            )));
+        }
+
+        if let Some(mixed) = shards.iter().find(|shard| shard.threshold != threshold) {
+            return Err(Error::Internal(format!(
+                "Shard {} needs {} shards, shard {} needs {} (shards from different splits cannot be combined)",
+                mixed.shard_id, mixed.threshold, shards[0].shard_id, threshold
+            )));
+        }
+
+        if threshold < 2 {
+            return Err(Error::Internal(
+                "Threshold must be at least 2 for security".to_string()
+            ));
+        }
+
+        // Reject tampered shards before interpolating (bad shards yield a wrong key, not an error);
+        // legacy shards have no commitment and are combined unverified only when allowed
+        for shard in shards {
+            match self.verify_shard(shard) {
+                Ok(true) => {}
+                Ok(false) => return Err(ShamirError::TamperedShard { shard_id: shard.shard_id }.into()),
+                Err(ShamirError::MissingCommitment { .. }) if self.allow_unverified_legacy => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let mut distinct: Vec<&KeyShard> = Vec::with_capacity(shards.len());
+        for shard in shards {
+            if !distinct.iter().any(|seen| seen.shard_id == shard.shard_id) {
+                distinct.push(shard);
+            }
+        }
+
+        if distinct.len() < threshold as usize {
+            return Err(Error::Internal(format!(
+                "Insufficient shards: need {}, provided {}",
+                threshold,
+                distinct.len()
+            )));
+        }
+
+        Ok((epoch, threshold, distinct))
     }
 
     pub fn threshold(&self) -> u8 {
@@ -224,16 +419,23 @@ impl ShamirKeyManager {
     pub fn total_shares(&self) -> u8 {
         self.shares
     }
+
+    /// Current shard generation (0 until the first reshare); persist it for with_epoch()
+    pub fn epoch(&self) -> u32 {
+        self.epoch
+    }
 }
 
 /**
  * Key Shard - Individual piece of split master key
  *
- * DESIGN DECISION: Include metadata (shard_id, threshold, total_shards)
- * WHY: User sees "Shard 2 of 5 (need 3 to recover)" - clear communication
+ * DESIGN DECISION: Include metadata (shard_id, threshold, total_shards, epoch)
+ * WHY: User sees "Shard 2 of 5 (need 3 to recover)" - clear communication;
+ * epoch tells which reshare generation the shard belongs to, commitment lets the
+ * holder check the shard is intact (ShamirKeyManager::verify_shard)
  *
  * DISTRIBUTION:
- * - QR code (base64-encoded, fits in standard QR code)
+ * - QR code (versioned base64 with metadata, fits in standard QR code)
  * - NFC tap (Android/iOS)
  * - Manual entry (base64 string)
  * - DHT publish (encrypted with recipient public key)
@@ -245,37 +447,148 @@ pub struct KeyShard {
     pub shard_id: u8,
     pub total_shards: u8,
     pub threshold: u8,
+    /// Reshare generation; only shards of the manager's current epoch combine
+    pub epoch: u32,
     pub data: Vec<u8>,
+    /// SHA-256 over the fields above, set when the shard is dealt (None for legacy shards)
+    pub commitment: Option<[u8; 32]>,
 }
 
 impl KeyShard {
+    /**
+     * Encode for QR code / manual entry, self-describing and versioned
+     *
+     * FORMAT v1: 0x00 ‖ version ‖ epoch (u32 LE) ‖ shard_id ‖ threshold ‖ total_shards ‖ data ‖ commitment
+     * The 0x00 marker tells it apart from the legacy encoding (raw share data, which
+     * starts with the share's x coordinate, never 0). Legacy shards have no commitment
+     * and are re-encoded in the legacy format.
+     */
     pub fn to_base64(&self) -> String {
         use base64::{engine::general_purpose, Engine as _};
-        general_purpose::STANDARD.encode(&self.data)
+
+        let Some(commitment) = self.commitment else {
+            return general_purpose::STANDARD.encode(&self.data);
+        };
+
+        let mut bytes = Vec::with_capacity(ENCODING_HEADER_LEN + self.data.len() + COMMITMENT_LEN);
+        bytes.extend_from_slice(&[ENCODING_MARKER, ENCODING_VERSION]);
+        bytes.extend_from_slice(&self.epoch.to_le_bytes());
+        bytes.extend_from_slice(&[self.shard_id, self.threshold, self.total_shards]);
+        bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&commitment);
+        general_purpose::STANDARD.encode(bytes)
     }
 
-    pub fn from_base64(encoded: &str, shard_id: u8, total_shards: u8, threshold: u8) -> Result<Self> {
-        use base64::{engine::general_purpose, Engine as _};
+    /// Decode a to_base64() string; metadata and commitment come from the payload
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let bytes = Self::decode_base64(encoded)?;
+
+        match bytes.first() {
+            Some(&ENCODING_MARKER) => {}
+            first => {
+                return Err(ShamirError::MalformedShard {
+                    shard_id: first.copied().unwrap_or(0),
+                    reason: "legacy encoding carries no metadata, decode it with KeyShard::from_legacy_base64".to_string(),
+                }
+                .into());
+            }
+        }
 
-        let data = general_purpose::STANDARD
-            .decode(encoded)
-            .map_err(|e| Error::Internal(format!("Base64 decode failed: {}", e)))?;
+        if bytes.len() < ENCODING_HEADER_LEN {
+            return Err(ShamirError::MalformedShard {
+                shard_id: 0,
+                reason: format!("encoded shard is {} bytes, too short to hold its header", bytes.len()),
+            }
+            .into());
+        }
+
+        if bytes[1] != ENCODING_VERSION {
+            return Err(ShamirError::UnsupportedEncoding { version: bytes[1] }.into());
+        }
+
+        let shard_id = bytes[6];
+        if bytes.len() < ENCODING_HEADER_LEN + 2 + COMMITMENT_LEN {
+            return Err(ShamirError::MalformedShard {
+                shard_id,
+                reason: format!("encoded shard is {} bytes, too short to hold share data and a commitment", bytes.len()),
+            }
+            .into());
+        }
+
+        let (data, commitment) = bytes[ENCODING_HEADER_LEN..].split_at(bytes.len() - ENCODING_HEADER_LEN - COMMITMENT_LEN);
+        let mut commitment_bytes = [0u8; COMMITMENT_LEN];
+        commitment_bytes.copy_from_slice(commitment);
+
+        Ok(Self {
+            shard_id,
+            total_shards: bytes[8],
+            threshold: bytes[7],
+            epoch: u32::from_le_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+            data: data.to_vec(),
+            commitment: Some(commitment_bytes),
+        })
+    }
+
+    /**
+     * Decode a shard encoded before versioning (raw share data only)
+     *
+     * Metadata is supplied by the caller and there is no commitment, so the shard
+     * cannot be verified; reconstruction combines it unverified.
+     */
+    pub fn from_legacy_base64(encoded: &str, shard_id: u8, total_shards: u8, threshold: u8, epoch: u32) -> Result<Self> {
+        let data = Self::decode_base64(encoded)?;
+
+        if data.first() == Some(&ENCODING_MARKER) {
+            return Err(ShamirError::MalformedShard {
+                shard_id,
+                reason: "versioned encoding, decode it with KeyShard::from_base64".to_string(),
+            }
+            .into());
+        }
 
         Ok(Self {
             shard_id,
             total_shards,
             threshold,
+            epoch,
             data,
+            commitment: None,
         })
     }
+
+    fn decode_base64(encoded: &str) -> Result<Vec<u8>> {
+        use base64::{engine::general_purpose, Engine as _};
+
+        general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| Error::Internal(format!("Base64 decode failed: {}", e)))
+    }
+
+    /// SHA-256(domain ‖ epoch ‖ shard_id ‖ threshold ‖ total_shards ‖ data)
+    fn compute_commitment(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(COMMITMENT_DOMAIN);
+        hasher.update(self.epoch.to_le_bytes());
+        hasher.update([self.shard_id, self.threshold, self.total_shards]);
+        hasher.update(&self.data);
+        hasher.finalize().into()
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ShamirError {
     InvalidThreshold { threshold: u8, shares: u8 },
     ThresholdTooLow,
     InsufficientShards { required: u8, provided: u8 },
     ReconstructionFailed(String),
+    /// Shard no longer matches its commitment (corrupted or modified)
+    TamperedShard { shard_id: u8 },
+    /// Shard bytes cannot be a share at all
+    MalformedShard { shard_id: u8, reason: String },
+    /// Legacy shard decoded without a commitment, so it cannot be verified
+    MissingCommitment { shard_id: u8 },
+    /// Encoded with a newer format version than this build understands
+    UnsupportedEncoding { version: u8 },
 }
 
 impl std::fmt::Display for ShamirError {
@@ -289,6 +602,18 @@ impl std::fmt::Display for ShamirError {
                 write!(f, "Insufficient shards: need {}, have {}", required, provided)
             }
             ShamirError::ReconstructionFailed(msg) => write!(f, "Reconstruction failed: {}", msg),
+            ShamirError::TamperedShard { shard_id } => {
+                write!(f, "Shard {} failed its integrity check (corrupted or modified)", shard_id)
+            }
+            ShamirError::MalformedShard { shard_id, reason } => {
+                write!(f, "Shard {} is malformed: {}", shard_id, reason)
+            }
+            ShamirError::MissingCommitment { shard_id } => {
+                write!(f, "Shard {} has no commitment (legacy encoding) and cannot be verified", shard_id)
+            }
+            ShamirError::UnsupportedEncoding { version } => {
+                write!(f, "Unsupported shard encoding version {}", version)
+            }
         }
     }
 }
@@ -414,9 +739,151 @@ mod tests {
         let encoded = shards[0].to_base64();
         assert!(!encoded.is_empty());
 
-        // Decode back
-        let decoded = KeyShard::from_base64(&encoded, 1, 5, 3).unwrap();
+        // Decode back: metadata travels in the payload
+        let decoded = KeyShard::from_base64(&encoded).unwrap();
         assert_eq!(shards[0].data, decoded.data);
+        assert_eq!((decoded.shard_id, decoded.total_shards, decoded.threshold, decoded.epoch), (1, 5, 3, 0));
+        assert!(manager.verify_shard(&decoded).unwrap());
+    }
+
+    /**
+     * Test: Legacy (unversioned) encodings still decode and reconstruct
+     *
+     * VALIDATES:
+     * - Raw share data decodes with caller-supplied metadata, without a commitment
+     * - Legacy shards are refused unless allow_unverified_legacy, then combine with
+     *   verified ones; versioned and legacy decoders reject each other's format,
+     *   unknown versions are reported
+     */
+    #[test]
+    fn test_legacy_base64_decoding() {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let manager = ShamirKeyManager::new_recommended();
+        let master_key = manager.generate_master_key();
+        let shards = manager.split_master_key(&master_key).unwrap();
+
+        let legacy_encoded = general_purpose::STANDARD.encode(&shards[1].data);
+        let legacy = KeyShard::from_legacy_base64(&legacy_encoded, 2, 5, 3, 0).unwrap();
+        assert_eq!(legacy.commitment, None);
+        assert_eq!(legacy.to_base64(), legacy_encoded);
+        assert_eq!(manager.verify_shard(&legacy), Err(ShamirError::MissingCommitment { shard_id: 2 }));
+
+        let mixed = [shards[0].clone(), legacy, shards[2].clone()];
+        assert_eq!(
+            manager.reconstruct_master_key(&mixed).unwrap_err(),
+            Error::Shamir(ShamirError::MissingCommitment { shard_id: 2 })
+        );
+
+        let recovered = manager
+            .allow_unverified_legacy(true)
+            .reconstruct_master_key(&mixed)
+            .unwrap();
+        assert_eq!(master_key, recovered);
+
+        assert!(KeyShard::from_base64(&legacy_encoded).is_err());
+        assert!(KeyShard::from_legacy_base64(&shards[1].to_base64(), 2, 5, 3, 0).is_err());
+
+        let mut future = general_purpose::STANDARD.decode(shards[1].to_base64()).unwrap();
+        future[1] = ENCODING_VERSION + 1;
+        let result = KeyShard::from_base64(&general_purpose::STANDARD.encode(future));
+        assert_eq!(result.unwrap_err(), Error::Shamir(ShamirError::UnsupportedEncoding { version: 2 }));
+    }
+
+    /**
+     * Test: Single-byte tampering is caught by verify_shard and reconstruction
+     *
+     * SECURITY CHECK: Every byte of the share data is covered by the commitment
+     */
+    #[test]
+    fn test_verify_shard_detects_tampering() {
+        let manager = ShamirKeyManager::new_recommended();
+        let master_key = manager.generate_master_key();
+        let shards = manager.split_master_key(&master_key).unwrap();
+        assert!(shards.iter().all(|shard| manager.verify_shard(shard).unwrap()));
+
+        for byte in 0..shards[1].data.len() {
+            let mut tampered = shards[1].clone();
+            tampered.data[byte] ^= 0x01;
+            assert!(!manager.verify_shard(&tampered).unwrap(), "byte {} not covered", byte);
+        }
+
+        let mut tampered = shards[1].clone();
+        tampered.data[5] ^= 0xff;
+        let result = manager.reconstruct_master_key(&[shards[0].clone(), tampered, shards[2].clone()]);
+        assert_eq!(result, Err(Error::Shamir(ShamirError::TamperedShard { shard_id: 2 })));
+
+        let mut empty = shards[0].clone();
+        empty.data.clear();
+        assert!(matches!(manager.verify_shard(&empty), Err(ShamirError::MalformedShard { shard_id: 1, .. })));
+    }
+
+    /**
+     * Test: Reshare to a new threshold invalidates the previous epoch
+     *
+     * VALIDATES:
+     * - 3-of-5 → 2-of-4 keeps the same master key
+     * - Old shards are rejected after reshare, and never mix with new ones
+     * - Fewer than K distinct current shards (duplicates don't count) fail
+     */
+    #[test]
+    fn test_reshare_new_epoch() {
+        let mut manager = ShamirKeyManager::new_recommended();
+        let master_key = manager.generate_master_key();
+        let old = manager.split_master_key(&master_key).unwrap();
+
+        // Duplicated shard does not count towards the threshold
+        let duplicated = vec![old[0].clone(), old[0].clone(), old[1].clone()];
+        assert!(manager.reshare(&duplicated, 4, 2).is_err());
+        assert_eq!(manager.epoch(), 0);
+
+        let new = manager.reshare(&old[1..4], 4, 2).unwrap();
+        assert_eq!(new.len(), 4);
+        assert!(new.iter().all(|shard| shard.epoch == 1 && shard.threshold == 2));
+        assert_eq!((manager.threshold(), manager.total_shares(), manager.epoch()), (2, 4, 1));
+
+        let recovered = manager.reconstruct_master_key(&[new[0].clone(), new[3].clone()]).unwrap();
+        assert_eq!(master_key, recovered);
+
+        // Old epoch alone, or mixed with the new one, is rejected
+        assert!(manager.reconstruct_master_key(&old[0..3]).is_err());
+        assert!(manager.reconstruct_master_key(&[old[0].clone(), new[1].clone()]).is_err());
+
+        // Invalid new configuration leaves the manager untouched
+        assert!(manager.reshare(&new, 2, 3).is_err());
+        assert_eq!(manager.epoch(), 1);
+    }
+
+    /**
+     * Test: Shards from a reshare recover with a manager that never saw it
+     *
+     * VALIDATES:
+     * - A fresh manager takes epoch and K (2-of-4) from the shards
+     * - Resharing on a fresh manager continues past the shards' epoch
+     * - A manager restored with_epoch(1) still rejects the revoked epoch-0 shards
+     */
+    #[test]
+    fn test_reshare_recovers_with_fresh_manager() {
+        let manager = ShamirKeyManager::new_recommended();
+        let master_key = manager.generate_master_key();
+        let old = manager.split_master_key(&master_key).unwrap();
+
+        let new = {
+            let mut resharing = manager.clone();
+            resharing.reshare(&old[0..3], 4, 2).unwrap()
+        };
+        drop(manager);
+
+        let mut fresh = ShamirKeyManager::new_recommended();
+        assert_eq!(fresh.reconstruct_master_key(&new[2..]).unwrap(), master_key);
+
+        let newer = fresh.reshare(&new[..2], 5, 3).unwrap();
+        assert!(newer.iter().all(|shard| shard.epoch == 2));
+        assert_eq!(fresh.reconstruct_master_key(&newer[1..4]).unwrap(), master_key);
+
+        let restored = ShamirKeyManager::new(2, 4).unwrap().with_epoch(1);
+        assert_eq!(restored.reconstruct_master_key(&new[1..3]).unwrap(), master_key);
+        assert!(restored.reconstruct_master_key(&old[0..3]).is_err());
     }
 
     /**
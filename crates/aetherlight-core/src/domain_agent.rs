@@ -67,6 +67,72 @@ pub struct Problem {
     pub domain_hints: Vec<Domain>,
 }
 
+impl Problem {
+    /// Create problem from description only (no context, no hints)
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            context: Vec::new(),
+            domain_hints: Vec::new(),
+        }
+    }
+
+    /// Start building a problem with optional context and domain hints
+    pub fn builder(description: impl Into<String>) -> ProblemBuilder {
+        ProblemBuilder {
+            description: description.into(),
+            context: Vec::new(),
+            domain_hints: Vec::new(),
+        }
+    }
+}
+
+/**
+ * Builder for Problem
+ *
+ * DESIGN DECISION: Description up front, context/hints optional
+ * WHY: Every problem needs a description; most callers add nothing else
+ */
+#[derive(Debug, Clone)]
+pub struct ProblemBuilder {
+    description: String,
+    context: Vec<String>,
+    domain_hints: Vec<Domain>,
+}
+
+impl ProblemBuilder {
+    /// Add a context item (code snippet, log excerpt, etc.)
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context.push(context.into());
+        self
+    }
+
+    /// Add a routing hint
+    pub fn domain_hint(mut self, domain: Domain) -> Self {
+        self.domain_hints.push(domain);
+        self
+    }
+
+    /// Replace routing hints
+    pub fn domain_hints(mut self, domains: Vec<Domain>) -> Self {
+        self.domain_hints = domains;
+        self
+    }
+
+    /// Build problem, rejecting empty descriptions
+    pub fn build(self) -> Result<Problem, crate::Error> {
+        if self.description.trim().is_empty() {
+            return Err(crate::Error::ValidationError("problem description cannot be empty".to_string()));
+        }
+
+        Ok(Problem {
+            description: self.description,
+            context: self.context,
+            domain_hints: self.domain_hints,
+        })
+    }
+}
+
 /// Solution with confidence score
 ///
 /// DESIGN DECISION: Include reasoning chain and source level
@@ -125,6 +191,105 @@ pub struct Solution {
     pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+impl Solution {
+    /// Start building a solution (content addressing fields default to None)
+    pub fn builder() -> SolutionBuilder {
+        SolutionBuilder::default()
+    }
+}
+
+/**
+ * Builder for Solution
+ *
+ * DESIGN DECISION: Validate once at build() instead of at every construction site
+ * WHY: Inline struct literals repeat four `None` content-addressing fields and
+ * never check that confidence is within 0.0-1.0
+ *
+ * REASONING CHAIN:
+ * 1. recommendation, confidence, source_level required (missing → ValidationError)
+ * 2. confidence outside 0.0-1.0 → InvalidConfidenceScore
+ * 3. reasoning defaults to empty; content_address/content_hash optional
+ * 4. hash_verified/verified_at left None (set by hash verification, not producers)
+ */
+#[derive(Debug, Clone, Default)]
+pub struct SolutionBuilder {
+    recommendation: Option<String>,
+    reasoning: Vec<String>,
+    confidence: Option<f64>,
+    source_level: Option<SearchLevel>,
+    content_address: Option<String>,
+    content_hash: Option<String>,
+}
+
+impl SolutionBuilder {
+    /// Set recommended action (required)
+    pub fn recommendation(mut self, recommendation: impl Into<String>) -> Self {
+        self.recommendation = Some(recommendation.into());
+        self
+    }
+
+    /// Set reasoning chain
+    pub fn reasoning(mut self, reasoning: Vec<String>) -> Self {
+        self.reasoning = reasoning;
+        self
+    }
+
+    /// Append one reasoning step
+    pub fn reasoning_step(mut self, step: impl Into<String>) -> Self {
+        self.reasoning.push(step.into());
+        self
+    }
+
+    /// Set confidence 0.0-1.0 (required)
+    pub fn confidence(mut self, confidence: f64) -> Self {
+        self.confidence = Some(confidence);
+        self
+    }
+
+    /// Set search level that produced the solution (required)
+    pub fn source_level(mut self, level: SearchLevel) -> Self {
+        self.source_level = Some(level);
+        self
+    }
+
+    /// Set referenced content address (e.g. "CLAUDE.2.5.1")
+    pub fn content_address(mut self, address: impl Into<String>) -> Self {
+        self.content_address = Some(address.into());
+        self
+    }
+
+    /// Set SHA256 of content at content_address
+    pub fn content_hash(mut self, hash: impl Into<String>) -> Self {
+        self.content_hash = Some(hash.into());
+        self
+    }
+
+    /// Build solution with validation
+    pub fn build(self) -> Result<Solution, crate::Error> {
+        let recommendation = self.recommendation
+            .ok_or_else(|| crate::Error::ValidationError("recommendation is required".to_string()))?;
+        let confidence = self.confidence
+            .ok_or_else(|| crate::Error::ValidationError("confidence is required".to_string()))?;
+        let source_level = self.source_level
+            .ok_or_else(|| crate::Error::ValidationError("source_level is required".to_string()))?;
+
+        if !(0.0..=1.0).contains(&confidence) {
+            return Err(crate::Error::InvalidConfidenceScore(confidence));
+        }
+
+        Ok(Solution {
+            recommendation,
+            reasoning: self.reasoning,
+            confidence,
+            source_level,
+            content_address: self.content_address,
+            content_hash: self.content_hash,
+            hash_verified: None,
+            verified_at: None,
+        })
+    }
+}
+
 /// 5-level breadcrumb hierarchy
 ///
 /// DESIGN DECISION: 5 levels balancing speed and thoroughness
@@ -871,4 +1036,74 @@ mod tests {
         assert_eq!(path.total_time_ms, 175); // 10+15+20+50+80
         assert_eq!(path.threshold_met, false);
     }
+
+    /**
+     * Test: Solution builder produces same struct as manual construction
+     */
+    #[test]
+    fn test_solution_builder_matches_manual() {
+        let manual = Solution {
+            recommendation: "Use Docker".to_string(),
+            reasoning: vec!["Docker is standard".to_string(), "Team already uses it".to_string()],
+            confidence: 0.92,
+            source_level: SearchLevel::House,
+            content_address: Some("CLAUDE.2.5.1".to_string()),
+            content_hash: None,
+            hash_verified: None,
+            verified_at: None,
+        };
+
+        let built = Solution::builder()
+            .recommendation("Use Docker")
+            .reasoning(vec!["Docker is standard".to_string()])
+            .reasoning_step("Team already uses it")
+            .confidence(0.92)
+            .source_level(SearchLevel::House)
+            .content_address("CLAUDE.2.5.1")
+            .build()
+            .unwrap();
+
+        assert_eq!(serde_json::to_value(&built).unwrap(), serde_json::to_value(&manual).unwrap());
+    }
+
+    /**
+     * Test: Solution builder rejects out-of-range confidence and missing fields
+     */
+    #[test]
+    fn test_solution_builder_validation() {
+        let result = Solution::builder()
+            .recommendation("Use Docker")
+            .confidence(1.2)
+            .source_level(SearchLevel::Local)
+            .build();
+        assert!(matches!(result, Err(crate::Error::InvalidConfidenceScore(_))));
+
+        let result = Solution::builder().confidence(0.5).source_level(SearchLevel::Local).build();
+        assert!(result.is_err());
+    }
+
+    /**
+     * Test: Problem builder produces same struct as manual construction
+     */
+    #[test]
+    fn test_problem_builder_matches_manual() {
+        let manual = Problem {
+            description: "Flaky pipeline".to_string(),
+            context: vec!["error: timeout".to_string()],
+            domain_hints: vec![Domain::Deployment, Domain::Quality],
+        };
+
+        let built = Problem::builder("Flaky pipeline")
+            .context("error: timeout")
+            .domain_hint(Domain::Deployment)
+            .domain_hint(Domain::Quality)
+            .build()
+            .unwrap();
+
+        assert_eq!(serde_json::to_value(&built).unwrap(), serde_json::to_value(&manual).unwrap());
+        assert!(Problem::builder("   ").build().is_err());
+
+        let simple = Problem::new("Flaky pipeline");
+        assert!(simple.context.is_empty() && simple.domain_hints.is_empty());
+    }
 }
@@ -19,11 +19,171 @@
 
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub mod remote;
+
+pub use remote::RemoteEmbedder;
 
 /// Standard embedding dimension (matches all-MiniLM-L6-v2)
 pub const EMBEDDING_DIM: usize = 384;
 
+/// Environment variable overriding the directory holding the model and tokenizer
+pub const MODEL_DIR_ENV: &str = "LUMINA_MODEL_DIR";
+
+/// ONNX model file name inside the model directory
+pub const MODEL_FILE_NAME: &str = "all-MiniLM-L6-v2.onnx";
+
+/// Tokenizer file name inside the model directory
+pub const TOKENIZER_FILE_NAME: &str = "tokenizer.json";
+
+/// File name suffix of the int8 model variant ("all-MiniLM-L6-v2" → "all-MiniLM-L6-v2-int8.onnx")
+pub const INT8_MODEL_SUFFIX: &str = "-int8";
+
+/**
+ * Numeric precision of the ONNX embedding model
+ *
+ * DESIGN DECISION: Int8 dynamic-quantized variant as opt-in alternative to FP32
+ * WHY: Mobile/edge persona targets <50MB memory; FP32 all-MiniLM-L6-v2 alone is ~90MB
+ *
+ * TRADEOFF: Int8 trades some accuracy for a smaller, faster model. Speed and drift
+ * depend on the exported model and hardware; measure before switching a deployment.
+ *
+ * Int8 embeddings keep EMBEDDING_DIM (384) but are not identical to the FP32 vectors.
+ * Rebuild the vector store (PatternIndex::rebuild) after changing precision so stored
+ * and query vectors come from the same model.
+ *
+ * Selected via `[pattern_library.embeddings] precision = "int8"` or
+ * PatternIndex::set_embedding_config.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingPrecision {
+    /// Full precision model (default)
+    #[default]
+    Fp32,
+    /// Int8 quantized model (falls back to Fp32 if the quantized file is absent)
+    Int8,
+}
+
+/// Where embeddings come from (`[pattern_library.embeddings] provider = "remote"`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingProvider {
+    /// ONNX model under the model directory (default)
+    #[default]
+    Local,
+    /// HTTP embedding service (RemoteEmbedder, settings in EmbeddingConfig::remote)
+    Remote,
+}
+
+/// Remote embedding service settings (`[pattern_library.embeddings.remote]`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteEmbeddingConfig {
+    /// Endpoint accepting OpenAI-style embedding requests
+    pub url: String,
+
+    /// Model name sent with each request (omitted when unset)
+    pub model: Option<String>,
+
+    /// Environment variable holding the API key (keys are never stored in config files)
+    pub api_key_env: Option<String>,
+
+    /// Vector length the service returns for `model`
+    pub dimension: usize,
+
+    /// Request timeout in seconds
+    pub timeout_secs: u64,
+}
+
+impl Default for RemoteEmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            model: None,
+            api_key_env: None,
+            dimension: EMBEDDING_DIM,
+            timeout_secs: 30,
+        }
+    }
+}
+
+/// Embedding model configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmbeddingConfig {
+    /// Embedding provider (local model or remote service)
+    pub provider: EmbeddingProvider,
+
+    /// Requested model precision (local provider)
+    pub precision: EmbeddingPrecision,
+
+    /// Use KeywordEmbedder when the provider cannot be opened (instead of failing)
+    pub keyword_fallback: bool,
+
+    /// Remote service settings (remote provider)
+    pub remote: RemoteEmbeddingConfig,
+}
+
+/**
+ * Directory holding the embedding model files
+ *
+ * DESIGN DECISION: LUMINA_MODEL_DIR overrides the caller's default directory
+ * WHY: Default paths are relative ("models/", "<data_dir>/models"), so a consumer
+ * started from another working directory could not find the model
+ */
+pub fn model_dir(default: impl Into<PathBuf>) -> PathBuf {
+    match std::env::var_os(MODEL_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => default.into(),
+    }
+}
+
+/// Model and tokenizer paths inside model_dir(default)
+pub fn model_paths(default: impl Into<PathBuf>) -> (PathBuf, PathBuf) {
+    let dir = model_dir(default);
+    (dir.join(MODEL_FILE_NAME), dir.join(TOKENIZER_FILE_NAME))
+}
+
+/**
+ * Resolve the model file to load for the requested precision
+ *
+ * DESIGN DECISION: Quantized file sits next to FP32 file with INT8_MODEL_SUFFIX
+ * WHY: Same data/models directory layout, no extra configuration paths
+ *
+ * Returns the path to load and the precision actually used. Int8 falls back to
+ * the FP32 path when `<stem>-int8.onnx` does not exist.
+ */
+pub fn resolve_model_path(
+    model_path: &Path,
+    precision: EmbeddingPrecision,
+) -> (PathBuf, EmbeddingPrecision) {
+    if precision == EmbeddingPrecision::Int8 {
+        let quantized = quantized_model_path(model_path);
+        if quantized.exists() {
+            return (quantized, EmbeddingPrecision::Int8);
+        }
+    }
+
+    (model_path.to_path_buf(), EmbeddingPrecision::Fp32)
+}
+
+/// Path of the int8 variant for an FP32 model path
+pub fn quantized_model_path(model_path: &Path) -> PathBuf {
+    let stem = model_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = model_path
+        .extension()
+        .map(|e| e.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "onnx".to_string());
+
+    model_path.with_file_name(format!("{}{}.{}", stem, INT8_MODEL_SUFFIX, extension))
+}
+
 /// Embedding vector type
 pub type Embedding = Vec<f32>;
 
@@ -48,8 +208,8 @@ pub struct EmbeddingResult {
 /// TEMPORARILY DISABLED: Stub implementation (returns errors when called)
 #[derive(Clone)]
 pub struct LocalEmbeddings {
-    // Stub implementation - no fields needed
-    _placeholder: (),
+    /// Precision of the loaded model (after quantized-file fallback)
+    precision: EmbeddingPrecision,
 }
 
 impl LocalEmbeddings {
@@ -63,11 +223,49 @@ impl LocalEmbeddings {
     ///
     /// # Returns
     /// * `Result<Self>` - Error indicating embeddings are disabled
-    pub fn new(_model_path: impl AsRef<Path>, _tokenizer_path: impl AsRef<Path>) -> Result<Self> {
-        Err(crate::Error::Internal(
-            "Local embeddings are temporarily disabled (requires DirectML/Windows SDK). \
-             Re-enable ort dependency in Cargo.toml or use cloud-based embeddings.".to_string()
-        ))
+    pub fn new(model_path: impl AsRef<Path>, tokenizer_path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_config(model_path, tokenizer_path, &EmbeddingConfig::default())
+    }
+
+    /// Create embeddings generator with explicit configuration
+    ///
+    /// Resolves the int8 model variant when `config.precision` is `Int8`,
+    /// falling back to the FP32 model if the quantized file is absent.
+    /// A missing model file is a Configuration error naming the path and MODEL_DIR_ENV.
+    ///
+    /// TEMPORARILY DISABLED: Returns error indicating embeddings are not available
+    pub fn with_config(
+        model_path: impl AsRef<Path>,
+        _tokenizer_path: impl AsRef<Path>,
+        config: &EmbeddingConfig,
+    ) -> Result<Self> {
+        let (resolved_path, precision) = resolve_model_path(model_path.as_ref(), config.precision);
+        if !resolved_path.exists() {
+            return Err(model_not_found(&resolved_path));
+        }
+
+        Self::load(&resolved_path, precision)
+    }
+
+    /// Open the ONNX session for the resolved model
+    ///
+    /// `precision` is the one resolve_model_path settled on (after int8 fallback);
+    /// the loaded generator stores it so precision() reports the model actually in use.
+    ///
+    /// TEMPORARILY DISABLED: Returns error indicating embeddings are not available
+    fn load(model_path: &Path, precision: EmbeddingPrecision) -> Result<Self> {
+        Err(crate::Error::Internal(format!(
+            "Local embeddings are temporarily disabled (requires DirectML/Windows SDK); \
+             cannot load {:?} model {}. \
+             Re-enable ort dependency in Cargo.toml or use cloud-based embeddings.",
+            precision,
+            model_path.display()
+        )))
+    }
+
+    /// Precision of the loaded model
+    pub fn precision(&self) -> EmbeddingPrecision {
+        self.precision
     }
 
     /// Generate embedding for text
@@ -101,6 +299,144 @@ impl LocalEmbeddings {
     }
 }
 
+/**
+ * Embedding provider interface
+ *
+ * DESIGN DECISION: Embedding generation behind a trait, provider selected by EmbeddingConfig
+ * WHY: PatternIndex works the same with the local ONNX model, a remote embedding service,
+ * or a caller-supplied embedder (e.g. a deterministic test double)
+ *
+ * Vectors from different embedders are not comparable. Vectors of different length
+ * cannot even be scored, so PatternIndex checks dimension() against the stored vectors.
+ */
+pub trait Embedder: Send + Sync {
+    /// Generate embedding for text
+    fn embed(&self, text: &str) -> Result<EmbeddingResult>;
+
+    /// Generate embeddings for several texts (default: one embed() call per text)
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<EmbeddingResult>> {
+        texts.iter().map(|text| self.embed(text)).collect()
+    }
+
+    /// Length of every vector this embedder produces
+    fn dimension(&self) -> usize;
+}
+
+impl<E: Embedder + ?Sized> Embedder for Arc<E> {
+    fn embed(&self, text: &str) -> Result<EmbeddingResult> {
+        (**self).embed(text)
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<EmbeddingResult>> {
+        (**self).embed_batch(texts)
+    }
+
+    fn dimension(&self) -> usize {
+        (**self).dimension()
+    }
+}
+
+impl Embedder for LocalEmbeddings {
+    fn embed(&self, text: &str) -> Result<EmbeddingResult> {
+        LocalEmbeddings::embed(self, text)
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<EmbeddingResult>> {
+        LocalEmbeddings::embed_batch(self, texts)
+    }
+
+    fn dimension(&self) -> usize {
+        EMBEDDING_DIM
+    }
+}
+
+/**
+ * Open the embedder selected by `config.provider`
+ *
+ * Local loads the ONNX model at `model_path` (precision from config); Remote connects
+ * to `config.remote` and never reads the model files. keyword_fallback is left to the
+ * caller (PatternIndex caches KeywordEmbedder when this fails).
+ */
+pub fn embedder_from_config(
+    model_path: &Path,
+    tokenizer_path: &Path,
+    config: &EmbeddingConfig,
+) -> Result<Box<dyn Embedder>> {
+    match config.provider {
+        EmbeddingProvider::Local => Ok(Box::new(LocalEmbeddings::with_config(model_path, tokenizer_path, config)?)),
+        EmbeddingProvider::Remote => Ok(Box::new(RemoteEmbedder::new(&config.remote)?)),
+    }
+}
+
+/// Descriptive error for a model file that does not exist
+fn model_not_found(path: &Path) -> crate::Error {
+    crate::Error::Configuration(format!(
+        "embedding model not found at {}; set {}",
+        path.display(),
+        MODEL_DIR_ENV
+    ))
+}
+
+/**
+ * Keyword-only embedder (no model files)
+ *
+ * DESIGN DECISION: Hash lowercase words into EMBEDDING_DIM buckets, L2-normalized
+ * WHY: Fallback matcher when the ONNX model is unavailable; cosine similarity over
+ * these vectors ranks by shared keywords, so the vector store works unchanged
+ *
+ * REASONING CHAIN:
+ * 1. Split on non-alphanumeric characters, lowercase, drop 1-character tokens
+ * 2. FNV-1a hash each token into a bucket (stable across runs and Rust versions,
+ *    vectors are persisted in the vector store)
+ * 3. Normalize so text length does not dominate similarity
+ *
+ * TRADEOFF: No synonyms or semantics ("login" does not match "sign in").
+ * Vectors are not comparable with model embeddings: rebuild after switching.
+ */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeywordEmbedder;
+
+impl KeywordEmbedder {
+    fn bucket(token: &str) -> usize {
+        let hash = token.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        (hash % EMBEDDING_DIM as u64) as usize
+    }
+}
+
+impl Embedder for KeywordEmbedder {
+    fn dimension(&self) -> usize {
+        EMBEDDING_DIM
+    }
+
+    fn embed(&self, text: &str) -> Result<EmbeddingResult> {
+        let start = std::time::Instant::now();
+        let mut embedding = vec![0.0f32; EMBEDDING_DIM];
+        let mut token_count = 0;
+
+        for token in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| token.chars().count() > 1)
+        {
+            embedding[Self::bucket(&token.to_lowercase())] += 1.0;
+            token_count += 1;
+        }
+
+        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            embedding.iter_mut().for_each(|x| *x /= norm);
+        }
+
+        Ok(EmbeddingResult {
+            embedding,
+            text: text.to_string(),
+            duration_ms: start.elapsed().as_millis() as u64,
+            token_count,
+        })
+    }
+}
+
 /* ORIGINAL IMPLEMENTATION COMMENTED OUT (requires ort, ndarray, tokenizers)
 
 use ndarray::{Array1, ArrayView1, ArrayView2};
@@ -111,3 +447,170 @@ use tokenizers::Tokenizer;
 
 See git history for full implementation or re-enable ort dependency in Cargo.toml
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        dot / (norm_a * norm_b)
+    }
+
+    /**
+     * Test: Int8 resolves to quantized file when present
+     */
+    #[test]
+    fn test_resolve_prefers_quantized_file() {
+        let dir = tempdir().unwrap();
+        let model = dir.path().join("all-MiniLM-L6-v2.onnx");
+        let quantized = dir.path().join("all-MiniLM-L6-v2-int8.onnx");
+        std::fs::write(&model, b"fp32").unwrap();
+        std::fs::write(&quantized, b"int8").unwrap();
+
+        assert_eq!(quantized_model_path(&model), quantized);
+        assert_eq!(
+            resolve_model_path(&model, EmbeddingPrecision::Int8),
+            (quantized, EmbeddingPrecision::Int8)
+        );
+        assert_eq!(
+            resolve_model_path(&model, EmbeddingPrecision::Fp32),
+            (model, EmbeddingPrecision::Fp32)
+        );
+    }
+
+    /**
+     * Test: Int8 falls back to FP32 when quantized file is absent
+     */
+    #[test]
+    fn test_resolve_falls_back_to_fp32() {
+        let dir = tempdir().unwrap();
+        let model = dir.path().join("all-MiniLM-L6-v2.onnx");
+        std::fs::write(&model, b"fp32").unwrap();
+
+        assert_eq!(
+            resolve_model_path(&model, EmbeddingPrecision::Int8),
+            (model, EmbeddingPrecision::Fp32)
+        );
+    }
+
+    /**
+     * Test: Precision deserializes from lowercase config value
+     */
+    #[test]
+    fn test_precision_from_config() {
+        let config: EmbeddingConfig = serde_json::from_str(r#"{"precision":"int8"}"#).unwrap();
+        assert_eq!(config.precision, EmbeddingPrecision::Int8);
+
+        let config: EmbeddingConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.precision, EmbeddingPrecision::Fp32);
+
+        let config: crate::config::AetherlightConfig = toml::from_str(
+            "[pattern_library.embeddings]\nprecision = \"int8\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.pattern_library.embeddings.precision, EmbeddingPrecision::Int8);
+    }
+
+    /**
+     * Test: Remote provider selected from config; local stays the default
+     */
+    #[test]
+    fn test_remote_provider_from_config() {
+        let config: crate::config::AetherlightConfig = toml::from_str(
+            "[pattern_library.embeddings]\nprovider = \"remote\"\n\n\
+             [pattern_library.embeddings.remote]\nurl = \"http://localhost:11434/v1/embeddings\"\ndimension = 768\n",
+        )
+        .unwrap();
+        let embeddings = &config.pattern_library.embeddings;
+        assert_eq!(embeddings.provider, EmbeddingProvider::Remote);
+        assert_eq!(embeddings.remote.dimension, 768);
+        assert_eq!(embeddings.remote.timeout_secs, 30);
+
+        let embedder = embedder_from_config(Path::new("missing.onnx"), Path::new("missing.json"), embeddings).unwrap();
+        assert_eq!(embedder.dimension(), 768);
+
+        assert_eq!(EmbeddingConfig::default().provider, EmbeddingProvider::Local);
+        assert_eq!(EmbeddingConfig::default().remote.dimension, EMBEDDING_DIM);
+    }
+
+    /**
+     * Test: Missing model file names the path and the override variable
+     */
+    #[test]
+    fn test_missing_model_error_is_descriptive() {
+        let dir = tempdir().unwrap();
+        let model = dir.path().join(MODEL_FILE_NAME);
+
+        let message = match LocalEmbeddings::new(&model, dir.path().join(TOKENIZER_FILE_NAME)) {
+            Err(crate::Error::Configuration(message)) => message,
+            Err(other) => panic!("expected Configuration error, got {:?}", other),
+            Ok(_) => panic!("expected missing model error"),
+        };
+
+        assert!(message.contains("embedding model not found at"));
+        assert!(message.contains(&model.display().to_string()));
+        assert!(message.contains(MODEL_DIR_ENV));
+    }
+
+    /**
+     * Test: Keyword embedder ranks by shared keywords
+     */
+    #[test]
+    fn test_keyword_embedder_ranks_shared_keywords() {
+        let embedder = KeywordEmbedder;
+        let query = embedder.embed("OAuth2 login with PKCE").unwrap();
+        assert_eq!(query.embedding.len(), EMBEDDING_DIM);
+        assert_eq!(query.token_count, 4);
+
+        let related = embedder.embed("PKCE flow for OAuth2 clients").unwrap();
+        let unrelated = embedder.embed("CSS grid layout for dashboards").unwrap();
+        assert!(cosine(&query.embedding, &related.embedding) > cosine(&query.embedding, &unrelated.embedding));
+
+        // Deterministic and case-insensitive
+        assert_eq!(embedder.embed("oauth2 LOGIN with pkce").unwrap().embedding, query.embedding);
+    }
+
+    /**
+     * Test: Quantized model keeps dimension and similarity ordering
+     */
+    #[test]
+    #[ignore = "needs models/all-MiniLM-L6-v2{,-int8}.onnx and the ort runtime (local embeddings are disabled)"]
+    fn test_quantized_embeddings_preserve_ordering() {
+        let model_path = "models/all-MiniLM-L6-v2.onnx";
+        let tokenizer_path = "models/tokenizer.json";
+
+        let int8_config = EmbeddingConfig { precision: EmbeddingPrecision::Int8, ..Default::default() };
+        let fp32 = LocalEmbeddings::new(model_path, tokenizer_path).unwrap();
+        let int8 = LocalEmbeddings::with_config(model_path, tokenizer_path, &int8_config).unwrap();
+        assert_eq!(int8.precision(), EmbeddingPrecision::Int8);
+
+        let query = "authenticate users with OAuth2";
+        let candidates = [
+            "OAuth2 login flow with PKCE",
+            "JWT token validation middleware",
+            "CSS grid layout for dashboards",
+        ];
+
+        let score = |embeddings: &LocalEmbeddings| -> Vec<f32> {
+            let q = embeddings.embed(query).unwrap().embedding;
+            candidates
+                .iter()
+                .map(|c| cosine(&q, &embeddings.embed(c).unwrap().embedding))
+                .collect()
+        };
+
+        let fp32_scores = score(&fp32);
+        let int8_scores = score(&int8);
+        assert_eq!(int8.embed(query).unwrap().embedding.len(), EMBEDDING_DIM);
+
+        for (a, b) in fp32_scores.iter().zip(&int8_scores) {
+            assert!((a - b).abs() < 0.05, "fp32 {} vs int8 {}", a, b);
+        }
+        assert!(int8_scores[0] > int8_scores[1]);
+        assert!(int8_scores[1] > int8_scores[2]);
+    }
+}
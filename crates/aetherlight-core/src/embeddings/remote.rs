@@ -0,0 +1,250 @@
+/**
+ * Remote Embeddings - HTTP embedding service provider
+ *
+ * DESIGN DECISION: OpenAI-style request/response over a blocking HTTP client (ureq)
+ * WHY: Hosts without the ONNX model (or wanting a larger model) still get semantic
+ * search; the OpenAI embeddings shape is served by OpenAI, Voyage-compatible proxies,
+ * Ollama and LM Studio, so one client covers them
+ *
+ * REASONING CHAIN:
+ * 1. POST {"input": [texts], "model": model} to the configured url
+ * 2. Response {"data": [{"embedding": [...], "index": i}, ...]}, ordered by index
+ * 3. One request per embed_batch call (embed = batch of one)
+ * 4. Every vector must have the configured dimension: the service is not trusted to
+ *    keep returning what the index was built with
+ * 5. API key read from the environment variable named in config, sent as Bearer token
+ *
+ * TRADEOFF: Blocking client keeps Embedder synchronous (same as the ONNX session) and
+ * is safe to call from inside a tokio runtime, unlike reqwest::blocking
+ *
+ * PATTERN: Pattern-INDEX-001 (Semantic Pattern Search)
+ * RELATED: Embedder, EmbeddingConfig::remote, PatternIndex
+ */
+
+use crate::embeddings::{Embedder, EmbeddingResult, RemoteEmbeddingConfig};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Embedder backed by an HTTP embedding service
+pub struct RemoteEmbedder {
+    agent: ureq::Agent,
+    url: String,
+    model: Option<String>,
+    api_key: Option<String>,
+    dimension: usize,
+}
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    input: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct EmbedResponse {
+    data: Vec<EmbedData>,
+}
+
+#[derive(Deserialize)]
+struct EmbedData {
+    embedding: Vec<f32>,
+    #[serde(default)]
+    index: usize,
+}
+
+impl RemoteEmbedder {
+    /**
+     * Create a client for `config` (no request is made)
+     *
+     * Configuration error when the url is empty, the dimension is 0, or the
+     * API key variable is named but not set.
+     */
+    pub fn new(config: &RemoteEmbeddingConfig) -> Result<Self> {
+        if config.url.trim().is_empty() {
+            return Err(Error::Configuration(
+                "remote embeddings need a url ([pattern_library.embeddings.remote] url)".to_string(),
+            ));
+        }
+        if config.dimension == 0 {
+            return Err(Error::Configuration("remote embedding dimension must be greater than 0".to_string()));
+        }
+
+        let api_key = match &config.api_key_env {
+            Some(var) => Some(std::env::var(var).map_err(|_| {
+                Error::Configuration(format!("remote embeddings API key variable {} is not set", var))
+            })?),
+            None => None,
+        };
+
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build();
+
+        Ok(Self {
+            agent,
+            url: config.url.clone(),
+            model: config.model.clone(),
+            api_key,
+            dimension: config.dimension,
+        })
+    }
+
+    fn request(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut request = self.agent.post(&self.url);
+        if let Some(key) = &self.api_key {
+            request = request.set("Authorization", &format!("Bearer {}", key));
+        }
+
+        let body = EmbedRequest { input: texts, model: self.model.as_deref() };
+        let response: EmbedResponse = request
+            .send_json(&body)
+            .map_err(|e| Error::Io(format!("Remote embedding request to {} failed: {}", self.url, e)))?
+            .into_json()
+            .map_err(|e| Error::Serialization(format!("Invalid remote embedding response: {}", e)))?;
+
+        let mut data = response.data;
+        if data.len() != texts.len() {
+            return Err(Error::ValidationError(format!(
+                "Remote embedder returned {} embeddings for {} texts",
+                data.len(),
+                texts.len()
+            )));
+        }
+        data.sort_by_key(|item| item.index);
+
+        data.into_iter()
+            .map(|item| match item.embedding.len() {
+                len if len == self.dimension => Ok(item.embedding),
+                len => Err(Error::ValidationError(format!(
+                    "Remote embedder returned a {}-dim vector, configured dimension is {}",
+                    len, self.dimension
+                ))),
+            })
+            .collect()
+    }
+}
+
+impl Embedder for RemoteEmbedder {
+    fn embed(&self, text: &str) -> Result<EmbeddingResult> {
+        self.embed_batch(&[text])?
+            .pop()
+            .ok_or_else(|| Error::Internal("Remote embedder returned no embedding".to_string()))
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<EmbeddingResult>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let start = Instant::now();
+        let embeddings = self.request(texts)?;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        Ok(embeddings
+            .into_iter()
+            .zip(texts)
+            .map(|(embedding, text)| EmbeddingResult {
+                embedding,
+                text: text.to_string(),
+                duration_ms,
+                token_count: text.split_whitespace().count(),
+            })
+            .collect())
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serve one HTTP request with `body`; the handle yields the request body received
+    fn serve_once(body: String) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/embeddings", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut request = vec![0u8; content_length];
+            reader.read_exact(&mut request).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    fn config(url: String, dimension: usize) -> RemoteEmbeddingConfig {
+        RemoteEmbeddingConfig { url, model: Some("test-embed".to_string()), dimension, ..Default::default() }
+    }
+
+    /**
+     * Test: One request per batch, results returned in input order
+     */
+    #[test]
+    fn test_batch_request_ordered_by_index() {
+        let (url, server) = serve_once(
+            r#"{"data":[{"index":1,"embedding":[0.0,1.0,0.0]},{"index":0,"embedding":[1.0,0.0,0.0]}]}"#.to_string(),
+        );
+        let embedder = RemoteEmbedder::new(&config(url, 3)).unwrap();
+
+        let results = embedder.embed_batch(&["retry with backoff", "oauth2 pkce"]).unwrap();
+
+        let request: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+        assert_eq!(request["input"], serde_json::json!(["retry with backoff", "oauth2 pkce"]));
+        assert_eq!(request["model"], "test-embed");
+        assert_eq!(results[0].embedding, vec![1.0, 0.0, 0.0]);
+        assert_eq!(results[0].text, "retry with backoff");
+        assert_eq!(results[1].embedding, vec![0.0, 1.0, 0.0]);
+        assert_eq!(embedder.dimension(), 3);
+    }
+
+    /**
+     * Test: Vectors of another length than configured are rejected
+     */
+    #[test]
+    fn test_wrong_dimension_rejected() {
+        let (url, server) = serve_once(r#"{"data":[{"index":0,"embedding":[1.0,0.0]}]}"#.to_string());
+        let embedder = RemoteEmbedder::new(&config(url, 3)).unwrap();
+
+        match embedder.embed("retry with backoff") {
+            Err(Error::ValidationError(message)) => assert!(message.contains("2-dim"), "{}", message),
+            other => panic!("expected dimension error, got {:?}", other.map(|r| r.embedding)),
+        }
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_missing_url_is_configuration_error() {
+        assert!(matches!(
+            RemoteEmbedder::new(&RemoteEmbeddingConfig::default()),
+            Err(Error::Configuration(_))
+        ));
+    }
+}
@@ -100,8 +100,8 @@ pub enum Error {
     #[error("Missing confidence dimension: {0}")]
     MissingConfidenceDimension(String),
 
-    /// Confidence weight sum does not equal 1.0
-    #[error("Confidence weights must sum to 1.0, got: {0}")]
+    /// Confidence weight negative/non-finite, or weights sum to zero
+    #[error("Confidence weights must be non-negative with a positive sum, got: {0}")]
     InvalidConfidenceWeights(f64),
 
     /**
@@ -134,6 +134,10 @@ pub enum Error {
     #[error("Agent not available: {0}")]
     AgentNotAvailable(String),
 
+    /// Agent already registered for domain (use replace_agent to swap)
+    #[error("Agent already registered: {0}")]
+    AgentAlreadyRegistered(String),
+
     /**
      * Content addressing errors (Phase 3.6 - Pattern-CONTEXT-002)
      *
@@ -156,6 +160,18 @@ pub enum Error {
     #[error("Configuration error: {0}")]
     Configuration(String),
 
+    /// Sprint plan could not be deserialized (carries line/column and task id)
+    #[error("Invalid sprint plan: {0}")]
+    SprintParse(crate::sprint_parser::SprintParseError),
+
+    /// Two tasks in a sprint plan share an id (later one would overwrite the earlier)
+    #[error("Duplicate task ID in sprint plan: {0}")]
+    DuplicateTaskId(String),
+
+    /// Key shard rejected before reconstruction (tampered or malformed)
+    #[error("Key shard error: {0}")]
+    Shamir(crate::crypto::ShamirError),
+
     /**
      * Function registry errors (Phase 3.7 - P3.7-001)
      *
@@ -171,6 +187,18 @@ pub enum Error {
     #[error("Lock error: {0}")]
     LockError(String),
 
+    /**
+     * Boundary argument errors (FFI / IPC)
+     *
+     * DESIGN DECISION: One variant for malformed arguments at language boundaries
+     * WHY: NAPI callers pass raw strings (UUIDs, JSON, enum names); a parse failure there
+     * is the caller's mistake, not an internal failure, and must be reported as such
+     */
+
+    /// Malformed argument received across an FFI/IPC boundary (bad UUID, JSON, enum value)
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     /**
      * I/O and serialization errors
      *
@@ -252,6 +280,12 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl From<crate::crypto::ShamirError> for Error {
+    fn from(err: crate::crypto::ShamirError) -> Self {
+        Error::Shamir(err)
+    }
+}
+
 impl From<rusqlite::Error> for Error {
     fn from(err: rusqlite::Error) -> Self {
         Error::Internal(format!("Database error: {}", err))
@@ -286,6 +320,9 @@ mod tests {
 
         let err = Error::EmptyLibrary;
         assert_eq!(err.to_string(), "Pattern library is empty");
+
+        let err = Error::InvalidArgument("id: not a UUID".to_string());
+        assert_eq!(err.to_string(), "Invalid argument: id: not a UUID");
     }
 
     /**
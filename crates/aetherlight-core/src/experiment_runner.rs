@@ -137,7 +137,7 @@ impl ExperimentRunner {
 
         // Generate report
         let report_path = self.reporter.generate_report(&result)?;
-        println!("✅ Experiment complete. Report: {}", report_path.display());
+        tracing::info!(report = %report_path.display(), "Experiment complete");
 
         Ok(result)
     }
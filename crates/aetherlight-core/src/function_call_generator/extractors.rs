@@ -6,19 +6,20 @@
  *
  * REASONING CHAIN:
  * 1. String parameters → NER for proper nouns, keywords for common values
- * 2. Number parameters → Regex extraction + validation
+ * 2. Number parameters → Written/spoken numerals normalized per locale (numbers.rs)
  * 3. Date parameters → Temporal expression parsing ("last month", "Q3 2024")
- * 4. Enum parameters → Case-insensitive matching against allowed values
+ * 4. Enum parameters → Allowed values, registered synonyms, then near-miss words
  * 5. Boolean parameters → Keyword inference ("yes" → true, "no" → false)
  *
  * PATTERN: Pattern-CALL-GEN-002 (Type-Specific Extractors)
  * PERFORMANCE: <10ms per parameter extraction
  */
 
+use crate::function_call_generator::numbers::{extract_number_localized, NumberLocale};
 use crate::function_call_generator::types::{ParameterValue, ExtractionMethod};
+use crate::matching::fuzzy::fuzzy_similarity;
 use chrono::{DateTime, Utc, Duration, Datelike};
-use regex::Regex;
-use std::sync::OnceLock;
+use std::collections::HashMap;
 
 /**
  * Extract proper nouns (person names, organizations)
@@ -67,28 +68,11 @@ pub fn extract_proper_noun(query: &str) -> Option<ParameterValue> {
  *
  * DESIGN DECISION: Support integers, floats, and currency
  * WHY: Common in marketing/analytics queries ("top 10", "$100K revenue")
+ *
+ * en-US numerals; see numbers::extract_number_localized for other locales.
  */
 pub fn extract_number(query: &str) -> Option<ParameterValue> {
-    static NUMBER_REGEX: OnceLock<Regex> = OnceLock::new();
-    let regex = NUMBER_REGEX.get_or_init(|| {
-        Regex::new(r"\b(\d+(?:\.\d+)?)\b").unwrap()
-    });
-
-    if let Some(capture) = regex.captures(query) {
-        if let Some(num_str) = capture.get(1) {
-            if let Ok(num) = num_str.as_str().parse::<f64>() {
-                return Some(ParameterValue {
-                    value: serde_json::Value::Number(
-                        serde_json::Number::from_f64(num).unwrap()
-                    ),
-                    confidence: 0.95,
-                    method: ExtractionMethod::NumberExtraction,
-                });
-            }
-        }
-    }
-
-    None
+    extract_number_localized(query, &NumberLocale::default())
 }
 
 /**
@@ -211,33 +195,92 @@ pub fn parse_temporal_expression(query: &str) -> Option<ParameterValue> {
  *
  * DESIGN DECISION: Case-insensitive matching with partial match support
  * WHY: User might say "opened" instead of "open", "closed" instead of "complete"
+ *
+ * No synonyms; see resolve_enum_value.
  */
 pub fn match_enum_value(query: &str, allowed_values: &[String]) -> Option<ParameterValue> {
+    resolve_enum_value(query, allowed_values, &HashMap::new())
+}
+
+/// Enum values resolved below this confidence should be confirmed with the user
+pub const ENUM_CLARIFICATION_THRESHOLD: f32 = 0.7;
+
+/**
+ * Resolve a spoken phrase to one of the allowed enum values
+ *
+ * DESIGN DECISION: Exact value → registered synonym → partial → fuzzy, in falling confidence
+ * WHY: People say "in progress" or "done", not the API's "pending"/"closed"; the
+ * registering app knows its vocabulary, so synonyms live on the FunctionParameter
+ *
+ * REASONING CHAIN:
+ * 1. Query contains an allowed value → 0.95 (EnumMatch)
+ * 2. Query contains a synonym as whole words → 0.85 (SynonymMatch); the longest
+ *    synonym wins so "not done" beats "done"
+ * 3. Query is a fragment of an allowed value ("clo") → 0.75 (EnumMatch)
+ * 4. A query word is a near-miss of a one-word value or synonym ("pendng") → 0.6 × similarity
+ *    (FuzzyMatch), below ENUM_CLARIFICATION_THRESHOLD so the caller confirms it
+ * 5. Nothing matches → None (caller asks which value was meant)
+ */
+pub fn resolve_enum_value(
+    query: &str,
+    allowed_values: &[String],
+    synonyms: &HashMap<String, Vec<String>>,
+) -> Option<ParameterValue> {
     let query_lower = query.to_lowercase();
+    let query_words = words(&query_lower);
+    let enum_value = |value: &String, confidence: f32, method: ExtractionMethod| ParameterValue {
+        value: serde_json::Value::String(value.clone()),
+        confidence,
+        method,
+    };
+
+    if let Some(value) = allowed_values.iter().find(|v| query_lower.contains(&v.to_lowercase())) {
+        return Some(enum_value(value, 0.95, ExtractionMethod::EnumMatch));
+    }
 
-    for value in allowed_values {
-        let value_lower = value.to_lowercase();
-
-        // Exact match (highest confidence)
-        if query_lower.contains(&value_lower) {
-            return Some(ParameterValue {
-                value: serde_json::Value::String(value.clone()),
-                confidence: 0.95,
-                method: ExtractionMethod::EnumMatch,
-            });
+    let synonym_match = allowed_values
+        .iter()
+        .flat_map(|value| {
+            synonyms.get(value).into_iter().flatten().map(move |synonym| (value, words(&synonym.to_lowercase())))
+        })
+        .filter(|(_, phrase)| !phrase.is_empty() && query_words.windows(phrase.len()).any(|w| w == phrase.as_slice()))
+        .max_by_key(|(_, phrase)| phrase.len());
+    if let Some((value, _)) = synonym_match {
+        return Some(enum_value(value, 0.85, ExtractionMethod::SynonymMatch));
+    }
+
+    if !query_lower.trim().is_empty() {
+        if let Some(value) = allowed_values.iter().find(|v| v.to_lowercase().contains(query_lower.trim())) {
+            return Some(enum_value(value, 0.75, ExtractionMethod::EnumMatch));
         }
+    }
 
-        // Partial match (lower confidence)
-        if value_lower.contains(&query_lower) || query_lower.contains(&value_lower) {
-            return Some(ParameterValue {
-                value: serde_json::Value::String(value.clone()),
-                confidence: 0.75,
-                method: ExtractionMethod::EnumMatch,
-            });
+    let mut best: Option<(&String, f64)> = None;
+    for value in allowed_values {
+        let candidates = std::iter::once(value).chain(synonyms.get(value).into_iter().flatten());
+        for candidate in candidates {
+            let candidate = candidate.to_lowercase();
+            if candidate.contains(char::is_whitespace) {
+                continue;
+            }
+            for word in &query_words {
+                if let Some(similarity) = fuzzy_similarity(word, &candidate, FUZZY_ENUM_MIN_SIMILARITY) {
+                    if best.is_none_or(|(_, s)| similarity > s) {
+                        best = Some((value, similarity));
+                    }
+                }
+            }
         }
     }
+    best.map(|(value, similarity)| enum_value(value, (0.6 * similarity) as f32, ExtractionMethod::FuzzyMatch))
+}
 
-    None
+/// Jaro-Winkler floor for near-miss enum words
+const FUZZY_ENUM_MIN_SIMILARITY: f64 = 0.85;
+
+/// Lowercase words of a phrase, split on anything that is not alphanumeric
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).map(str::to_string).collect()
 }
 
 /**
@@ -318,6 +361,53 @@ mod tests {
         assert_eq!(pv.value, serde_json::Value::String("open".to_string()));
     }
 
+    fn status_synonyms() -> (Vec<String>, HashMap<String, Vec<String>>) {
+        let allowed = vec!["open".to_string(), "pending".to_string(), "closed".to_string()];
+        let synonyms = HashMap::from([
+            ("pending".to_string(), vec!["in progress".to_string(), "ongoing".to_string()]),
+            ("closed".to_string(), vec!["done".to_string(), "finished".to_string()]),
+            ("open".to_string(), vec!["not done".to_string()]),
+        ]);
+        (allowed, synonyms)
+    }
+
+    #[test]
+    fn test_resolve_enum_synonyms() {
+        let (allowed, synonyms) = status_synonyms();
+        let cases = [
+            ("Show tasks in progress", "pending", ExtractionMethod::SynonymMatch),
+            ("the ongoing ones", "pending", ExtractionMethod::SynonymMatch),
+            ("mark it done", "closed", ExtractionMethod::SynonymMatch),
+            ("everything that's finished", "closed", ExtractionMethod::SynonymMatch),
+            ("tasks that are not done", "open", ExtractionMethod::SynonymMatch),
+            ("Show closed cases", "closed", ExtractionMethod::EnumMatch),
+        ];
+
+        for (query, expected, method) in cases {
+            let pv = resolve_enum_value(query, &allowed, &synonyms).unwrap_or_else(|| panic!("{}", query));
+            assert_eq!(pv.value, serde_json::json!(expected), "{}", query);
+            assert_eq!(pv.method, method, "{}", query);
+            assert!(pv.confidence >= ENUM_CLARIFICATION_THRESHOLD, "{}", query);
+        }
+    }
+
+    #[test]
+    fn test_resolve_enum_fuzzy_needs_confirmation() {
+        let (allowed, synonyms) = status_synonyms();
+        let pv = resolve_enum_value("show pendng tasks", &allowed, &synonyms).unwrap();
+        assert_eq!(pv.value, serde_json::json!("pending"));
+        assert_eq!(pv.method, ExtractionMethod::FuzzyMatch);
+        assert!(pv.confidence < ENUM_CLARIFICATION_THRESHOLD);
+    }
+
+    #[test]
+    fn test_resolve_enum_unmappable() {
+        let (allowed, synonyms) = status_synonyms();
+        assert!(resolve_enum_value("something purple", &allowed, &synonyms).is_none());
+        // Synonyms match whole words only ("abandoned" is not "done")
+        assert!(resolve_enum_value("abandoned", &allowed, &synonyms).is_none());
+    }
+
     #[test]
     fn test_infer_boolean() {
         let result = infer_boolean("Enable notifications");
@@ -20,6 +20,7 @@ use crate::function_call_generator::types::{
     FunctionCall, ParameterValue, ExtractionError, ExtractionMethod
 };
 use crate::function_call_generator::extractors;
+use crate::function_call_generator::numbers::{self, NumberLocale};
 use std::sync::Arc;
 use std::collections::HashMap;
 
@@ -31,6 +32,9 @@ use std::collections::HashMap;
  */
 pub struct FunctionCallGenerator {
     registry: Arc<FunctionRegistry>,
+
+    /// Numeral conventions for number and currency parameters
+    locale: NumberLocale,
 }
 
 impl FunctionCallGenerator {
@@ -41,7 +45,18 @@ impl FunctionCallGenerator {
      * * `registry` - Shared function registry
      */
     pub fn new(registry: Arc<FunctionRegistry>) -> Self {
-        Self { registry }
+        Self { registry, locale: NumberLocale::default() }
+    }
+
+    /// Read number and currency parameters with `locale`'s separators and default currency
+    pub fn with_locale(mut self, locale: NumberLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Numeral conventions in use (en-US unless set with with_locale)
+    pub fn locale(&self) -> &NumberLocale {
+        &self.locale
     }
 
     /**
@@ -144,9 +159,10 @@ impl FunctionCallGenerator {
      *
      * EXTRACTION STRATEGIES BY TYPE:
      * - string: Proper noun detection → keyword extraction
-     * - number: Number regex extraction
+     * - number: Written/spoken numerals ("1,500", "twelve hundred") per locale
+     * - currency: Amount + ISO 4217 code ("$1.2k" → {amount: 1200, currency: USD})
      * - date/date_range: Temporal expression parsing
-     * - enum: Case-insensitive matching against allowed values
+     * - enum: Allowed values → registered synonyms → near-miss words (low confidence)
      * - boolean: Keyword inference (yes/no/true/false)
      */
     fn extract_parameters(
@@ -194,18 +210,13 @@ impl FunctionCallGenerator {
                 Ok(None)
             }
 
-            "number" => Ok(extractors::extract_number(query)),
+            "number" => Ok(numbers::extract_number_localized(query, &self.locale)),
+
+            "currency" => Ok(numbers::extract_currency(query, &self.locale)),
 
             "date" | "date_range" => Ok(extractors::parse_temporal_expression(query)),
 
-            "enum" => {
-                if let Some(ref allowed_values) = param.allowed_values {
-                    Ok(extractors::match_enum_value(query, allowed_values))
-                } else {
-                    // No allowed values specified, try example matching
-                    Ok(extractors::match_enum_value(query, &param.examples))
-                }
-            }
+            "enum" => Ok(extractors::resolve_enum_value(query, param.enum_options(), &param.synonyms)),
 
             "boolean" => Ok(extractors::infer_boolean(query)),
 
@@ -298,6 +309,7 @@ mod tests {
                     description: "Client's full name".to_string(),
                     examples: vec!["John Doe".to_string()],
                     allowed_values: None,
+                    synonyms: HashMap::new(),
                 },
                 FunctionParameter {
                     name: "status".to_string(),
@@ -310,6 +322,7 @@ mod tests {
                         "closed".to_string(),
                         "all".to_string(),
                     ]),
+                    synonyms: HashMap::new(),
                 },
             ],
             examples: vec![
@@ -375,6 +388,7 @@ mod tests {
                     description: "Required parameter".to_string(),
                     examples: vec![],
                     allowed_values: None,
+                    synonyms: HashMap::new(),
                 },
             ],
             examples: vec!["Test query".to_string()],
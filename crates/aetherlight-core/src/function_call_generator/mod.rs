@@ -23,6 +23,10 @@
 pub mod types;
 pub mod generator;
 pub mod extractors;
+pub mod numbers;
+pub mod policy;
 
 pub use types::{FunctionCall, ParameterValue, ExtractionError};
+pub use numbers::NumberLocale;
+pub use policy::{ExecutionPolicy, ExecutionDecision};
 pub use generator::FunctionCallGenerator;
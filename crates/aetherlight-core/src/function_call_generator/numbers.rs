@@ -0,0 +1,481 @@
+/**
+ * Number & Currency Extraction - Spoken and written amounts across locales
+ *
+ * DESIGN DECISION: Token scanner over the query, numerals normalized per NumberLocale
+ * WHY: The digit regex read "1,500" as 1, "$1.2k" as 1.2 and "twelve hundred dollars"
+ * as nothing; voice-driven invoicing and reporting need the amount the user meant
+ *
+ * REASONING CHAIN:
+ * 1. Written numerals: "1,500", "1.500,00", "1'250.50", "$1.2k", "€20", "-42"
+ *    - Both separators present → the rightmost one is the decimal mark, whatever the locale
+ *    - One separator: the locale's decimal mark once → decimal; else valid 3-digit
+ *      grouping → thousands; else a single occurrence → decimal ("1,5")
+ *    - Suffix k always scales; m / bn only with a currency symbol ("5m" is often minutes)
+ * 2. Spoken numerals: "two thousand", "twelve hundred", "one hundred and fifty",
+ *    "twenty-five", "a hundred", "three point five"
+ * 3. Either form may be followed by a scale word ("3.5 million")
+ * 4. Currency from symbols ($ € £ ¥), codes (USD, EUR, ...) or words (dollars, bucks,
+ *    euros, pounds, yen, francs) on either side; "... and fifty cents" adds the cents
+ * 5. Currency parameters without an explicit currency get the locale's default at
+ *    lower confidence (clarification threshold catches them)
+ *
+ * PATTERN: Pattern-CALL-GEN-002 (Type-Specific Extractors)
+ * RELATED: extractors::extract_number, FunctionCallGenerator::with_locale
+ * PERFORMANCE: Single pass over whitespace tokens, <1ms for typical queries
+ */
+
+use crate::function_call_generator::types::{ExtractionMethod, ParameterValue};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Confidence for amounts read from digits
+const WRITTEN_CONFIDENCE: f32 = 0.95;
+
+/// Confidence for amounts read from number words (transcription may mishear them)
+const SPOKEN_CONFIDENCE: f32 = 0.9;
+
+/// Confidence for currency amounts that fell back to the locale's default currency
+const DEFAULT_CURRENCY_CONFIDENCE: f32 = 0.7;
+
+/**
+ * Numeral conventions of the user's locale
+ *
+ * DESIGN DECISION: Separators + default currency, not a full CLDR locale
+ * WHY: That is all amount parsing needs; from_tag covers the locales we ship to
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberLocale {
+    /// Decimal mark ('.' in en-US, ',' in de-DE)
+    pub decimal_separator: char,
+
+    /// Thousands separator (',' in en-US, '.' in de-DE, '\'' in de-CH)
+    pub group_separator: char,
+
+    /// ISO 4217 code for amounts without an explicit currency
+    pub default_currency: String,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+impl NumberLocale {
+    pub fn en_us() -> Self {
+        Self::new('.', ',', "USD")
+    }
+
+    pub fn en_gb() -> Self {
+        Self::new('.', ',', "GBP")
+    }
+
+    pub fn de_de() -> Self {
+        Self::new(',', '.', "EUR")
+    }
+
+    pub fn de_ch() -> Self {
+        Self::new('.', '\'', "CHF")
+    }
+
+    /// Locale for a BCP 47 tag ("en-US", "de_DE", "es"); None if unknown
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag.trim().to_lowercase().replace('_', "-").as_str() {
+            "en" | "en-us" => Some(Self::en_us()),
+            "en-gb" => Some(Self::en_gb()),
+            "de-ch" => Some(Self::de_ch()),
+            "de" | "de-de" | "de-at" | "es" | "es-es" | "it" | "it-it" | "nl" | "nl-nl" | "pt" | "pt-pt" => {
+                Some(Self::de_de())
+            }
+            _ => None,
+        }
+    }
+
+    fn new(decimal_separator: char, group_separator: char, default_currency: &str) -> Self {
+        Self { decimal_separator, group_separator, default_currency: default_currency.to_string() }
+    }
+}
+
+/// One amount found in a query
+#[derive(Debug, Clone, PartialEq)]
+pub struct Amount {
+    pub value: f64,
+
+    /// ISO 4217 code when the query named a currency
+    pub currency: Option<String>,
+
+    /// Read from number words rather than digits
+    pub spoken: bool,
+}
+
+/**
+ * Every amount in the query, in order of appearance
+ */
+pub fn find_amounts(query: &str, locale: &NumberLocale) -> Vec<Amount> {
+    let tokens = tokenize(query);
+    let mut amounts = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match parse_amount(&tokens, i, locale) {
+            Some((amount, next)) => {
+                amounts.push(amount);
+                i = next;
+            }
+            None => i += 1,
+        }
+    }
+
+    amounts
+}
+
+/**
+ * Extract the first number in the query as a JSON number
+ */
+pub fn extract_number_localized(query: &str, locale: &NumberLocale) -> Option<ParameterValue> {
+    let amount = find_amounts(query, locale).into_iter().next()?;
+
+    Some(ParameterValue {
+        value: serde_json::Value::Number(serde_json::Number::from_f64(amount.value)?),
+        confidence: if amount.spoken { SPOKEN_CONFIDENCE } else { WRITTEN_CONFIDENCE },
+        method: ExtractionMethod::NumberExtraction,
+    })
+}
+
+/**
+ * Extract a currency amount as {"amount": f64, "currency": "ISO 4217"}
+ *
+ * DESIGN DECISION: Prefer the first amount that names a currency
+ * WHY: "invoice 3 for twelve hundred dollars" is about 1200 USD, not 3
+ */
+pub fn extract_currency(query: &str, locale: &NumberLocale) -> Option<ParameterValue> {
+    let amounts = find_amounts(query, locale);
+    let amount = amounts
+        .iter()
+        .find(|amount| amount.currency.is_some())
+        .or_else(|| amounts.first())?;
+
+    let confidence = match (&amount.currency, amount.spoken) {
+        (None, _) => DEFAULT_CURRENCY_CONFIDENCE,
+        (Some(_), true) => SPOKEN_CONFIDENCE,
+        (Some(_), false) => WRITTEN_CONFIDENCE,
+    };
+    let currency = amount.currency.clone().unwrap_or_else(|| locale.default_currency.clone());
+
+    Some(ParameterValue {
+        value: serde_json::json!({ "amount": amount.value, "currency": currency }),
+        confidence,
+        method: ExtractionMethod::CurrencyExtraction,
+    })
+}
+
+/// Lowercased whitespace tokens, outer punctuation trimmed, hyphenated number words split
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+
+    for raw in query.split_whitespace() {
+        let token = raw
+            .trim_start_matches(['(', '[', '"', '\'', '#'])
+            .trim_end_matches([')', ']', '"', '\'', '?', '!', '.', ';', ':', ',', '%'])
+            .to_lowercase();
+        if token.is_empty() {
+            continue;
+        }
+
+        if token.contains('-') && token.split('-').all(|part| small_number(part).is_some()) {
+            tokens.extend(token.split('-').map(str::to_string));
+        } else {
+            tokens.push(token);
+        }
+    }
+
+    tokens
+}
+
+/// Amount starting at tokens[i], with the index after it
+fn parse_amount(tokens: &[String], i: usize, locale: &NumberLocale) -> Option<(Amount, usize)> {
+    let (mut value, mut currency, spoken, mut next) = match parse_written(&tokens[i], locale) {
+        Some((value, currency)) => (value, currency, false, i + 1),
+        None => {
+            let (value, next) = parse_spoken(tokens, i)?;
+            (value, None, true, next)
+        }
+    };
+
+    // "3.5 million", "2 thousand"
+    if let Some(scale) = tokens.get(next).and_then(|token| scale_word(token)) {
+        value = round_scaled(value * scale);
+        next += 1;
+    }
+
+    // Currency before the number ("usd 500", "$ 20") or after it ("500 dollars", "20 €")
+    if currency.is_none() {
+        currency = i.checked_sub(1).and_then(|prev| currency_marker(&tokens[prev]));
+    }
+    if let Some(after) = tokens.get(next).and_then(|token| currency_marker(token)) {
+        currency.get_or_insert(after);
+        next += 1;
+
+        // "twelve dollars and fifty cents"
+        if tokens.get(next).is_some_and(|token| token == "and") {
+            let cents = tokens.get(next + 1).and_then(|token| parse_written(token, locale).map(|(v, _)| (v, next + 2)))
+                .or_else(|| parse_spoken(tokens, next + 1));
+            if let Some((cents, after_cents)) = cents {
+                if tokens.get(after_cents).is_some_and(|token| token == "cent" || token == "cents") {
+                    value = round_scaled(value + cents / 100.0);
+                    next = after_cents + 1;
+                }
+            }
+        }
+    }
+
+    Some((Amount { value, currency, spoken }, next))
+}
+
+/// Digits with optional sign, currency symbol and k/m/bn suffix ("$1.2k", "1.500,00€")
+fn parse_written(token: &str, locale: &NumberLocale) -> Option<(f64, Option<String>)> {
+    static WRITTEN_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = WRITTEN_REGEX.get_or_init(|| {
+        Regex::new(r"^(?P<sign>[-−])?(?P<pre>[$€£¥])?(?P<num>\d(?:[\d.,']*\d)?)(?P<suffix>k|m|bn)?(?P<post>[$€£¥])?$")
+            .unwrap()
+    });
+
+    let captures = regex.captures(token)?;
+    let mut value = parse_numeral(&captures["num"], locale)?;
+    let currency = captures
+        .name("pre")
+        .or_else(|| captures.name("post"))
+        .and_then(|symbol| currency_marker(symbol.as_str()));
+
+    let multiplier = match captures.name("suffix").map(|suffix| suffix.as_str()) {
+        Some("k") => 1e3,
+        Some("m") if currency.is_some() => 1e6,
+        Some("bn") if currency.is_some() => 1e9,
+        Some(_) => return None,
+        None => 1.0,
+    };
+    if multiplier != 1.0 {
+        value = round_scaled(value * multiplier);
+    }
+    if captures.name("sign").is_some() {
+        value = -value;
+    }
+
+    Some((value, currency))
+}
+
+/// Digits and separators → f64 (see module docs for separator rules)
+fn parse_numeral(numeral: &str, locale: &NumberLocale) -> Option<f64> {
+    let separators: Vec<char> = numeral.chars().filter(|c| !c.is_ascii_digit()).collect();
+    let mut kinds = separators.clone();
+    kinds.sort_unstable();
+    kinds.dedup();
+
+    let (group, decimal) = match kinds.as_slice() {
+        [] => (None, None),
+        [_, _] => {
+            let decimal = *separators.last()?;
+            let group = kinds.iter().copied().find(|&c| c != decimal)?;
+            if decimal == '\'' || separators.iter().filter(|&&c| c == decimal).count() != 1 {
+                return None;
+            }
+            (Some(group), Some(decimal))
+        }
+        [separator] => {
+            let count = separators.len();
+            if *separator == locale.decimal_separator && count == 1 {
+                (None, Some(*separator))
+            } else if valid_grouping(numeral, *separator) {
+                (Some(*separator), None)
+            } else if count == 1 && *separator != '\'' {
+                (None, Some(*separator))
+            } else {
+                return None;
+            }
+        }
+        _ => return None,
+    };
+
+    let (integer, fraction) = match decimal {
+        Some(decimal) => numeral.split_once(decimal)?,
+        None => (numeral, ""),
+    };
+    if let Some(group) = group {
+        if !valid_grouping(integer, group) {
+            return None;
+        }
+    }
+
+    let integer: String = integer.chars().filter(char::is_ascii_digit).collect();
+    format!("{}.{}", integer, if fraction.is_empty() { "0" } else { fraction }).parse().ok()
+}
+
+/// "1,500,000": leading group of 1-3 digits, then groups of exactly 3
+fn valid_grouping(integer: &str, group: char) -> bool {
+    let mut groups = integer.split(group);
+    let first = groups.next().unwrap_or_default();
+    (1..=3).contains(&first.len()) && groups.all(|g| g.len() == 3 && g.chars().all(|c| c.is_ascii_digit()))
+}
+
+/**
+ * Number words starting at tokens[i] → (value, index after the last word)
+ *
+ * Standard accumulation: units/tens add to the current group, "hundred" multiplies it,
+ * thousand/million/billion close it into the total. "point" reads digit words as decimals.
+ */
+fn parse_spoken(tokens: &[String], i: usize) -> Option<(f64, usize)> {
+    let is_scale_at = |index: usize| tokens.get(index).and_then(|token| scale_word(token)).is_some();
+
+    let mut total = 0.0;
+    let mut current = 0.0;
+    let mut seen = false;
+    let mut j = i;
+
+    while let Some(token) = tokens.get(j) {
+        let token = token.as_str();
+        if let Some(value) = small_number(token) {
+            current += value;
+        } else if token == "a" && !seen && is_scale_at(j + 1) {
+            current = 1.0;
+        } else if token == "hundred" {
+            current = current.max(1.0) * 100.0;
+        } else if let Some(scale) = scale_word(token).filter(|_| seen || current > 0.0) {
+            total += current.max(1.0) * scale;
+            current = 0.0;
+        } else if token == "and" && seen && tokens.get(j + 1).and_then(|next| small_number(next)).is_some() {
+            // "one hundred and fifty"
+        } else if token == "point" && seen {
+            let digits: String = tokens[j + 1..]
+                .iter()
+                .map_while(|token| small_number(token).filter(|&d| d < 10.0))
+                .map(|d| char::from(b'0' + d as u8))
+                .collect();
+            if digits.is_empty() {
+                break;
+            }
+            let fraction: f64 = format!("0.{}", digits).parse().ok()?;
+            return Some((total + current + fraction, j + 1 + digits.len()));
+        } else {
+            break;
+        }
+        seen = true;
+        j += 1;
+    }
+
+    seen.then_some((total + current, j))
+}
+
+/// zero..nineteen and the tens
+fn small_number(word: &str) -> Option<f64> {
+    const UNITS: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+    ];
+    const TENS: [&str; 8] = ["twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+    if let Some(value) = UNITS.iter().position(|&unit| unit == word) {
+        return Some(value as f64);
+    }
+    TENS.iter().position(|&tens| tens == word).map(|index| (index as f64 + 2.0) * 10.0)
+}
+
+/// Multiplier words that follow a number ("3 thousand", "1.2 million")
+fn scale_word(word: &str) -> Option<f64> {
+    match word {
+        "hundred" => Some(1e2),
+        "thousand" => Some(1e3),
+        "million" => Some(1e6),
+        "billion" => Some(1e9),
+        _ => None,
+    }
+}
+
+/// ISO 4217 code for a currency symbol, code or word
+fn currency_marker(token: &str) -> Option<String> {
+    let code = match token {
+        "$" | "usd" | "dollar" | "dollars" | "buck" | "bucks" => "USD",
+        "€" | "eur" | "euro" | "euros" => "EUR",
+        "£" | "gbp" | "pound" | "pounds" | "quid" => "GBP",
+        "¥" | "jpy" | "yen" => "JPY",
+        "chf" | "franc" | "francs" => "CHF",
+        _ => return None,
+    };
+    Some(code.to_string())
+}
+
+/// Drop binary float noise from scaling ("1.2k" → 1200, not 1199.9999999999998)
+fn round_scaled(value: f64) -> f64 {
+    (value * 1e6).round() / 1e6
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Test: Spoken and written phrasings normalize to the same typed values
+     */
+    #[test]
+    fn test_numeric_phrasings() {
+        let us = NumberLocale::en_us();
+        let de = NumberLocale::de_de();
+        let ch = NumberLocale::de_ch();
+
+        let cases: [(&str, &NumberLocale, f64, Option<&str>); 22] = [
+            ("Show top 10 customers", &us, 10.0, None),
+            ("budget of two thousand", &us, 2000.0, None),
+            ("invoice for twelve hundred dollars", &us, 1200.0, Some("USD")),
+            ("order 1,500 units", &us, 1500.0, None),
+            ("raise it to $1.2k", &us, 1200.0, Some("USD")),
+            ("one hundred and fifty seats", &us, 150.0, None),
+            ("twenty-five percent off", &us, 25.0, None),
+            ("a €20 fee", &us, 20.0, Some("EUR")),
+            ("charge 1,234.56 USD", &us, 1234.56, Some("USD")),
+            ("a 3.5 million dollar deal", &us, 3_500_000.0, Some("USD")),
+            ("two million five hundred thousand users", &us, 2_500_000.0, None),
+            ("scale by three point five", &us, 3.5, None),
+            ("costs a hundred bucks", &us, 100.0, Some("USD")),
+            ("set it to -42 degrees", &us, -42.0, None),
+            ("twelve dollars and fifty cents", &us, 12.5, Some("USD")),
+            ("a £3m contract", &us, 3_000_000.0, Some("GBP")),
+            ("received 1.500,00 in cash", &us, 1500.0, None),
+            ("Rechnung über 1.500,00 €", &de, 1500.0, Some("EUR")),
+            ("1.500 Stück bestellen", &de, 1500.0, None),
+            ("2,5 kg Mehl", &de, 2.5, None),
+            ("Betrag CHF 1'250.50", &ch, 1250.5, Some("CHF")),
+            ("one thousand two hundred thirty-four", &us, 1234.0, None),
+        ];
+
+        for (query, locale, value, currency) in cases {
+            let amount = find_amounts(query, locale).into_iter().next().unwrap_or_else(|| panic!("{}: no amount", query));
+            assert_eq!(amount.value, value, "{}", query);
+            assert_eq!(amount.currency.as_deref(), currency, "{}", query);
+        }
+    }
+
+    #[test]
+    fn test_extract_currency_prefers_named_currency() {
+        let pv = extract_currency("invoice 3 for twelve hundred dollars", &NumberLocale::en_us()).unwrap();
+        assert_eq!(pv.value, serde_json::json!({ "amount": 1200.0, "currency": "USD" }));
+        assert!(matches!(pv.method, ExtractionMethod::CurrencyExtraction));
+
+        // No currency named → locale default, lower confidence
+        let pv = extract_currency("Rechnung über 500", &NumberLocale::de_de()).unwrap();
+        assert_eq!(pv.value, serde_json::json!({ "amount": 500.0, "currency": "EUR" }));
+        assert!(pv.confidence < WRITTEN_CONFIDENCE);
+    }
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(NumberLocale::from_tag("de_DE"), Some(NumberLocale::de_de()));
+        assert_eq!(NumberLocale::from_tag("en-GB").unwrap().default_currency, "GBP");
+        assert_eq!(NumberLocale::from_tag("xx-YY"), None);
+    }
+
+    #[test]
+    fn test_no_number() {
+        assert!(find_amounts("find open cases for someone", &NumberLocale::en_us()).is_empty());
+        assert!(extract_number_localized("5m walk", &NumberLocale::en_us()).is_none());
+    }
+}
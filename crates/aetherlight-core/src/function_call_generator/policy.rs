@@ -0,0 +1,147 @@
+/**
+ * Execution Policy - When a Function Call Runs Without Asking
+ *
+ * DESIGN DECISION: Confidence thresholds + an "always confirm" list, decided per call
+ * WHY: Host apps want "show my open cases" to just run, but "delete this case" must
+ * never run on a misheard command, however confident the match
+ *
+ * REASONING CHAIN:
+ * 1. Missing required params → Clarify (nothing to run yet)
+ * 2. Confidence < clarify_below → Clarify ("Did you mean function X?")
+ * 3. Function on the always_confirm list → Confirm (destructive actions)
+ * 4. Confidence ≥ auto_execute_threshold → Execute
+ * 5. Otherwise → Confirm
+ *
+ * Default policy confirms every call (threshold above the 1.0 confidence ceiling);
+ * host apps opt in to auto-execution with auto_execute_above()
+ *
+ * PATTERN: Pattern-CALL-GEN-001 (Parameter Extraction Pipeline)
+ * RELATED: FunctionCall (confidence, missing_params), ConversationManager (confirmation turns)
+ */
+
+use crate::function_call_generator::types::FunctionCall;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// What to do with a generated function call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExecutionDecision {
+    /// Run immediately
+    Execute,
+
+    /// Ask "Proceed with X?" before running
+    Confirm,
+
+    /// Ask which function or parameter was meant
+    Clarify,
+}
+
+/// Default auto_execute_threshold: above any confidence, so nothing runs unconfirmed
+pub const NEVER_AUTO_EXECUTE: f32 = 1.01;
+
+/// Confidence-gated execution policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionPolicy {
+    /// Calls at or above this confidence run without confirmation
+    pub auto_execute_threshold: f32,
+
+    /// Calls below this confidence need clarification
+    pub clarify_below: f32,
+
+    /// Function ids or names that always need confirmation (e.g., "deleteCase")
+    #[serde(default)]
+    pub always_confirm: HashSet<String>,
+}
+
+impl Default for ExecutionPolicy {
+    fn default() -> Self {
+        Self {
+            auto_execute_threshold: NEVER_AUTO_EXECUTE,
+            clarify_below: 0.7,
+            always_confirm: HashSet::new(),
+        }
+    }
+}
+
+impl ExecutionPolicy {
+    /// Run calls at or above `threshold` confidence without confirmation (e.g., 0.9)
+    pub fn auto_execute_above(mut self, threshold: f32) -> Self {
+        self.auto_execute_threshold = threshold;
+        self
+    }
+
+    /// Require confirmation for a function id ("legal.deleteCase") or name ("deleteCase")
+    pub fn always_confirm(mut self, function: impl Into<String>) -> Self {
+        self.always_confirm.insert(function.into());
+        self
+    }
+
+    /// Whether the function is on the always-confirm list
+    pub fn requires_confirmation(&self, function_id: &str) -> bool {
+        let name = function_id.rsplit('.').next().unwrap_or(function_id);
+        self.always_confirm.contains(function_id) || self.always_confirm.contains(name)
+    }
+
+    /// Decide whether to execute, confirm, or clarify a generated call
+    pub fn decide(&self, call: &FunctionCall) -> ExecutionDecision {
+        if !call.missing_params.is_empty() || call.confidence < self.clarify_below {
+            ExecutionDecision::Clarify
+        } else if self.requires_confirmation(&call.function_id) {
+            ExecutionDecision::Confirm
+        } else if call.confidence >= self.auto_execute_threshold {
+            ExecutionDecision::Execute
+        } else {
+            ExecutionDecision::Confirm
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn call(function_id: &str, confidence: f32) -> FunctionCall {
+        FunctionCall {
+            function_id: function_id.to_string(),
+            parameters: HashMap::new(),
+            confidence,
+            missing_params: vec![],
+            reasoning: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_default_policy_confirms_everything() {
+        let policy = ExecutionPolicy::default();
+        assert_eq!(policy.decide(&call("legal.searchCases", 1.0)), ExecutionDecision::Confirm);
+        assert_eq!(policy.decide(&call("legal.searchCases", 0.8)), ExecutionDecision::Confirm);
+        assert_eq!(policy.decide(&call("legal.searchCases", 0.4)), ExecutionDecision::Clarify);
+    }
+
+    #[test]
+    fn test_high_confidence_read_executes() {
+        let policy = ExecutionPolicy::default().auto_execute_above(0.9).always_confirm("deleteCase");
+        assert_eq!(policy.decide(&call("legal.searchCases", 0.95)), ExecutionDecision::Execute);
+        assert_eq!(policy.decide(&call("legal.searchCases", 0.8)), ExecutionDecision::Confirm);
+        assert_eq!(policy.decide(&call("legal.searchCases", 0.4)), ExecutionDecision::Clarify);
+    }
+
+    #[test]
+    fn test_destructive_function_always_confirms() {
+        let policy = ExecutionPolicy::default().auto_execute_above(0.9).always_confirm("deleteCase");
+        assert_eq!(policy.decide(&call("legal.deleteCase", 0.99)), ExecutionDecision::Confirm);
+        assert_eq!(policy.decide(&call("legal.deleteCase", 1.0)), ExecutionDecision::Confirm);
+
+        let by_id = ExecutionPolicy::default().always_confirm("legal.deleteCase");
+        assert_eq!(by_id.decide(&call("legal.deleteCase", 0.99)), ExecutionDecision::Confirm);
+    }
+
+    #[test]
+    fn test_missing_params_clarify() {
+        let mut incomplete = call("legal.searchCases", 0.99);
+        incomplete.missing_params.push("clientName".to_string());
+        assert_eq!(ExecutionPolicy::default().decide(&incomplete), ExecutionDecision::Clarify);
+    }
+}
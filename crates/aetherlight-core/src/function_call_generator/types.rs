@@ -63,7 +63,7 @@ pub struct ParameterValue {
  * DESIGN DECISION: Track extraction method for debugging and improvement
  * WHY: Understand which extraction strategies work best
  */
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ExtractionMethod {
     /// Exact keyword match (highest confidence)
     KeywordMatch,
@@ -74,12 +74,21 @@ pub enum ExtractionMethod {
     /// Temporal expression parsing ("last month" → date range)
     TemporalParsing,
 
-    /// Number extraction (regex + validation)
+    /// Number extraction (written and spoken numerals, locale-aware)
     NumberExtraction,
 
+    /// Currency amount extraction ({"amount", "currency"})
+    CurrencyExtraction,
+
     /// Enum value matching (case-insensitive)
     EnumMatch,
 
+    /// Enum value reached through a registered spoken synonym ("done" → "closed")
+    SynonymMatch,
+
+    /// Enum value from a near-miss word (low confidence, needs confirmation)
+    FuzzyMatch,
+
     /// Boolean inference ("yes" → true)
     BooleanInference,
 
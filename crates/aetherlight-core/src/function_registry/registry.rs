@@ -16,7 +16,7 @@
  * PERFORMANCE: <20ms match, 100k functions, >90% accuracy
  */
 
-use crate::embeddings::LocalEmbeddings;
+use crate::embeddings::{Embedder, LocalEmbeddings};
 use crate::vector_store::SqliteVectorStore;
 use crate::function_registry::types::{
     RegisteredFunction, FunctionMatch, RegistryError
@@ -48,8 +48,8 @@ pub struct FunctionRegistry {
     /// Function storage (ID → RegisteredFunction)
     functions: HashMap<String, RegisteredFunction>,
 
-    /// Embedding generator (all-MiniLM-L6-v2)
-    embeddings: Arc<LocalEmbeddings>,
+    /// Embedding generator (all-MiniLM-L6-v2 unless supplied with with_embedder)
+    embeddings: Arc<dyn Embedder>,
 
     /// Vector database for semantic search (wrapped in Mutex for interior mutability)
     vector_store: Arc<Mutex<SqliteVectorStore>>,
@@ -92,11 +92,19 @@ impl FunctionRegistry {
      * * `db_path` - Path to SQLite vector database
      */
     pub fn new<P: AsRef<Path>>(model_path: P, tokenizer_path: P, db_path: P) -> Result<Self, RegistryError> {
-        let embeddings = Arc::new(
-            LocalEmbeddings::new(model_path, tokenizer_path)
-                .map_err(|e| RegistryError::EmbeddingError(e.to_string()))?
-        );
+        let embeddings = LocalEmbeddings::new(model_path, tokenizer_path)
+            .map_err(|e| RegistryError::EmbeddingError(e.to_string()))?;
+
+        Self::with_embedder(Arc::new(embeddings), db_path)
+    }
 
+    /**
+     * Create function registry over a caller-supplied embedder
+     *
+     * DESIGN DECISION: Embedder injectable, same as PatternIndex::set_embedder
+     * WHY: Hosts without the local model (and tests) can still register and match functions
+     */
+    pub fn with_embedder<P: AsRef<Path>>(embeddings: Arc<dyn Embedder>, db_path: P) -> Result<Self, RegistryError> {
         let vector_store = Arc::new(Mutex::new(
             SqliteVectorStore::new(db_path)
                 .map_err(|e| RegistryError::VectorStoreError(e.to_string()))?
@@ -104,7 +112,7 @@ impl FunctionRegistry {
 
         Ok(Self {
             functions: HashMap::new(),
-            embeddings: embeddings,
+            embeddings,
             vector_store,
             usage_stats: HashMap::new(),
         })
@@ -399,6 +407,7 @@ mod tests {
                     description: "Client's full name".to_string(),
                     examples: vec!["John Doe".to_string(), "Jane Smith".to_string()],
                     allowed_values: None,
+                    synonyms: HashMap::new(),
                 },
                 FunctionParameter {
                     name: "status".to_string(),
@@ -411,6 +420,7 @@ mod tests {
                         "closed".to_string(),
                         "all".to_string(),
                     ]),
+                    synonyms: HashMap::new(),
                 },
             ],
             examples: vec![
@@ -51,7 +51,7 @@ pub struct FunctionParameter {
     /// Parameter name
     pub name: String,
 
-    /// Parameter type (string, number, date, date_range, enum, boolean)
+    /// Parameter type (string, number, currency, date, date_range, enum, boolean)
     pub param_type: String,
 
     /// Whether this parameter is required
@@ -65,6 +65,11 @@ pub struct FunctionParameter {
 
     /// For enum types: allowed values
     pub allowed_values: Option<Vec<String>>,
+
+    /// For enum types: spoken synonyms per allowed value
+    /// e.g., {"pending": ["in progress", "ongoing"], "closed": ["done", "finished"]}
+    #[serde(default)]
+    pub synonyms: HashMap<String, Vec<String>>,
 }
 
 /// Function match result from semantic search
@@ -148,6 +153,11 @@ impl FunctionParameter {
             true // No enum constraint
         }
     }
+
+    /// Candidate values for enum matching (allowed values, else examples)
+    pub fn enum_options(&self) -> &[String] {
+        self.allowed_values.as_deref().unwrap_or(&self.examples)
+    }
 }
 
 #[cfg(test)]
@@ -167,6 +177,7 @@ mod tests {
                 description: "Client's full name".to_string(),
                 examples: vec!["John Doe".to_string()],
                 allowed_values: None,
+                synonyms: HashMap::new(),
             }],
             examples: vec!["Find John Doe's cases".to_string()],
             tags: vec!["legal".to_string(), "search".to_string()],
@@ -193,6 +204,7 @@ mod tests {
                     description: "Required param".to_string(),
                     examples: vec![],
                     allowed_values: None,
+                    synonyms: HashMap::new(),
                 },
                 FunctionParameter {
                     name: "optional".to_string(),
@@ -201,6 +213,7 @@ mod tests {
                     description: "Optional param".to_string(),
                     examples: vec![],
                     allowed_values: None,
+                    synonyms: HashMap::new(),
                 },
             ],
             examples: vec![],
@@ -225,6 +238,7 @@ mod tests {
                 "closed".to_string(),
                 "all".to_string(),
             ]),
+            synonyms: HashMap::new(),
         };
 
         assert!(param.is_valid_enum_value("open"));
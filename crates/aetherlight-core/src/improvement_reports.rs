@@ -124,6 +124,126 @@ pub struct ImprovementReport {
     pub generated_at: DateTime<Utc>,
 }
 
+/// Version of the JSON export schema (bump on breaking field changes)
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// JSON export envelope: schema version + report fields
+#[derive(Serialize)]
+struct ReportExport<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    report: &'a ImprovementReport,
+}
+
+impl ImprovementReport {
+    /// Export report as JSON
+    ///
+    /// DESIGN DECISION: Versioned envelope around the serde representation
+    /// WHY: Archived reports are diffed over time and read by dashboards;
+    /// `schema_version` lets consumers detect field changes instead of guessing
+    ///
+    /// Includes every `PerformanceTrend` (current, previous, change_pct, direction).
+    pub fn to_json(&self) -> Result<String, String> {
+        let export = ReportExport {
+            schema_version: REPORT_SCHEMA_VERSION,
+            report: self,
+        };
+        serde_json::to_string_pretty(&export)
+            .map_err(|e| format!("Failed to serialize improvement report: {}", e))
+    }
+
+    /// Render report as Markdown (same sections as the HTML export)
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+
+        md.push_str(&format!("# Continuous Improvement Report: {}\n\n", self.period));
+        md.push_str(&format!(
+            "- **Period:** {} to {}\n",
+            self.start_date.format("%Y-%m-%d"),
+            self.end_date.format("%Y-%m-%d")
+        ));
+        md.push_str(&format!("- **Total Executions:** {}\n\n", self.total_executions));
+
+        // Performance Trends
+        md.push_str("## Performance Trends\n\n");
+        md.push_str("| Metric | Current | Previous | Change | Direction |\n");
+        md.push_str("|---|---|---|---|---|\n");
+        let trends = &self.trends;
+        for (metric, trend) in [
+            ("Avg Time to Complete (seconds)", &trends.avg_time_to_complete),
+            ("Avg Tokens Used", &trends.avg_tokens_used),
+            ("Success Rate", &trends.success_rate),
+            ("Test Coverage", &trends.test_coverage),
+        ] {
+            md.push_str(&format!(
+                "| {} | {:.2} | {:.2} | {:+.1}% | {:?} |\n",
+                metric, trend.current, trend.previous, trend.change_pct, trend.direction
+            ));
+        }
+
+        // Experiments
+        md.push_str("\n## Experiments Run\n\n");
+        if self.experiments_run.is_empty() {
+            md.push_str("No experiments run this period.\n");
+        } else {
+            for exp in &self.experiments_run {
+                md.push_str(&format!("- {}: {}\n", exp.id, exp.hypothesis));
+            }
+        }
+
+        // Significant Findings
+        md.push_str("\n## Significant Findings\n\n");
+        if self.significant_findings.is_empty() {
+            md.push_str("No significant findings this period.\n");
+        } else {
+            for finding in &self.significant_findings {
+                md.push_str(&format!(
+                    "- **{}**: {} ({}; experiment {})\n",
+                    finding.title, finding.description, finding.impact, finding.experiment_id
+                ));
+            }
+        }
+
+        // SOPs Updated
+        md.push_str("\n## SOPs Updated\n\n");
+        if self.sops_updated.is_empty() {
+            md.push_str("No SOPs updated this period.\n");
+        } else {
+            for sop in &self.sops_updated {
+                md.push_str(&format!(
+                    "- {:?} Agent: {} (Experiment: {})\n",
+                    sop.agent_type, sop.sop_section, sop.experiment_id
+                ));
+            }
+        }
+
+        // Recommendations
+        md.push_str("\n## Recommendations\n\n");
+        if self.recommendations.is_empty() {
+            md.push_str("No recommendations this period.\n");
+        } else {
+            for rec in &self.recommendations {
+                let priority_label = match rec.priority {
+                    1 => "HIGH",
+                    2 => "MEDIUM",
+                    _ => "LOW",
+                };
+                md.push_str(&format!(
+                    "- **[{}] {}**: {} (Impact: {})\n",
+                    priority_label, rec.title, rec.description, rec.estimated_impact
+                ));
+            }
+        }
+
+        md.push_str(&format!(
+            "\n---\n\n_Generated: {}_\n",
+            self.generated_at.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+
+        md
+    }
+}
+
 /// Report generator
 pub struct ImprovementReportGenerator {
     reports_dir: PathBuf,
@@ -155,7 +275,7 @@ impl ImprovementReportGenerator {
     /// 3. Calculate trends (current vs previous)
     /// 4. Identify significant findings
     /// 5. Generate recommendations
-    /// 6. Export to HTML
+    /// 6. Export to HTML (+ JSON archive for diffing over time)
     pub fn generate_monthly_report(
         &self,
         current_analysis: Analysis,
@@ -187,9 +307,10 @@ impl ImprovementReportGenerator {
             generated_at: now,
         };
 
-        // Export to HTML
+        // Export to HTML, archive JSON alongside
         let report_path = self.export_html(&report)?;
-        println!("✅ Improvement report generated: {}", report_path.display());
+        self.export_json(&report)?;
+        tracing::info!(report = %report_path.display(), "Improvement report generated");
 
         Ok(report)
     }
@@ -393,6 +514,20 @@ impl ImprovementReportGenerator {
         Ok(report_path)
     }
 
+    /// Export report to JSON (archive next to the HTML report)
+    fn export_json(&self, report: &ImprovementReport) -> Result<PathBuf, String> {
+        let filename = format!(
+            "{}-improvement-report.json",
+            report.start_date.format("%Y-%m")
+        );
+        let report_path = self.reports_dir.join(&filename);
+
+        std::fs::write(&report_path, report.to_json()?)
+            .map_err(|e| format!("Failed to write JSON report: {}", e))?;
+
+        Ok(report_path)
+    }
+
     /// Generate HTML content
     fn generate_html(&self, report: &ImprovementReport) -> String {
         let mut html = String::new();
@@ -576,6 +711,97 @@ mod tests {
         assert_eq!(report.trends.test_coverage.direction, TrendDirection::Improving);
     }
 
+    fn fixture_report() -> ImprovementReport {
+        use chrono::TimeZone;
+
+        let trend = |current: f64, previous: f64, change_pct: f64, direction: TrendDirection| {
+            PerformanceTrend { current, previous, change_pct, direction }
+        };
+
+        ImprovementReport {
+            period: "October 2025".to_string(),
+            start_date: Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap(),
+            end_date: Utc.with_ymd_and_hms(2025, 10, 31, 0, 0, 0).unwrap(),
+            total_executions: 120,
+            trends: TrendAnalysis {
+                avg_time_to_complete: trend(3000.0, 3750.0, -20.0, TrendDirection::Improving),
+                avg_tokens_used: trend(5000.0, 5000.0, 0.0, TrendDirection::Stable),
+                success_rate: trend(0.75, 0.8, -6.25, TrendDirection::Declining),
+                test_coverage: trend(0.9, 0.8, 12.5, TrendDirection::Improving),
+            },
+            experiments_run: vec![],
+            significant_findings: vec![Finding {
+                title: "Smaller prompts".to_string(),
+                description: "Experiment EXP-001 tested: Smaller prompts".to_string(),
+                impact: "Target improvement: 10%".to_string(),
+                experiment_id: "EXP-001".to_string(),
+            }],
+            sops_updated: vec![],
+            recommendations: vec![Recommendation {
+                priority: 1,
+                title: "Investigate declining success rate".to_string(),
+                description: "Success rate declined by 6.2% this month. Review recent failures.".to_string(),
+                estimated_impact: "High - prevents quality degradation".to_string(),
+            }],
+            generated_at: Utc.with_ymd_and_hms(2025, 11, 1, 9, 30, 0).unwrap(),
+        }
+    }
+
+    /**
+     * Test: JSON export schema is pinned (dashboards and archived diffs depend on it)
+     */
+    #[test]
+    fn test_report_json_snapshot() {
+        let json: serde_json::Value = serde_json::from_str(&fixture_report().to_json().unwrap()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "schema_version": 1,
+                "period": "October 2025",
+                "start_date": "2025-10-01T00:00:00Z",
+                "end_date": "2025-10-31T00:00:00Z",
+                "total_executions": 120,
+                "trends": {
+                    "avg_time_to_complete": { "current": 3000.0, "previous": 3750.0, "change_pct": -20.0, "direction": "Improving" },
+                    "avg_tokens_used": { "current": 5000.0, "previous": 5000.0, "change_pct": 0.0, "direction": "Stable" },
+                    "success_rate": { "current": 0.75, "previous": 0.8, "change_pct": -6.25, "direction": "Declining" },
+                    "test_coverage": { "current": 0.9, "previous": 0.8, "change_pct": 12.5, "direction": "Improving" }
+                },
+                "experiments_run": [],
+                "significant_findings": [{
+                    "title": "Smaller prompts",
+                    "description": "Experiment EXP-001 tested: Smaller prompts",
+                    "impact": "Target improvement: 10%",
+                    "experiment_id": "EXP-001"
+                }],
+                "sops_updated": [],
+                "recommendations": [{
+                    "priority": 1,
+                    "title": "Investigate declining success rate",
+                    "description": "Success rate declined by 6.2% this month. Review recent failures.",
+                    "estimated_impact": "High - prevents quality degradation"
+                }],
+                "generated_at": "2025-11-01T09:30:00Z"
+            })
+        );
+    }
+
+    #[test]
+    fn test_report_markdown() {
+        let report = fixture_report();
+        let md = report.to_markdown();
+
+        assert!(md.starts_with("# Continuous Improvement Report: October 2025"));
+        assert!(md.contains("| Success Rate | 0.75 | 0.80 | -6.2% | Declining |"), "{}", md);
+        for finding in &report.significant_findings {
+            assert!(md.contains(&finding.title), "{}", md);
+        }
+        for rec in &report.recommendations {
+            assert!(md.contains(&format!("**[HIGH] {}**: {}", rec.title, rec.description)), "{}", md);
+        }
+    }
+
     #[test]
     fn test_trend_calculation() {
         let trend = PerformanceTrend::new(90.0, 85.0);
@@ -9,7 +9,7 @@
  * 2. Need reliable communication mechanism for coordination
  * 3. Filesystem provides: Atomicity, visibility, cross-platform compatibility
  * 4. Watch directory for completion signals (filesystem events)
- * 5. Agents write JSON signals when tasks complete
+ * 5. Agents write JSON signals when tasks complete (and progress signals while they run)
  * 6. Project Manager reads signals and updates dependency graph
  * 7. Result: Reliable multi-agent coordination without network complexity
  *
@@ -22,6 +22,9 @@ pub mod types;
 pub mod writer;
 pub mod reader;
 
-pub use types::{CompletionSignal, TaskStatus};
+pub use types::{CompletionSignal, ProgressSignal, TaskStatus};
 pub use writer::SignalWriter;
-pub use reader::SignalReader;
+pub use reader::{SignalReader, ProgressWatcher};
+
+/// Workflow directory (relative to the workspace root) holding completion and progress signals
+pub const WORKFLOW_DIR: &str = ".lumina/workflow";
@@ -22,7 +22,7 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 use anyhow::{Context, Result};
 use notify::{Watcher, RecursiveMode, Event, EventKind};
-use super::types::CompletionSignal;
+use super::types::{CompletionSignal, ProgressSignal};
 
 /**
  * Signal reader for Project Manager
@@ -72,6 +72,68 @@ impl SignalReader {
         Ok(signal)
     }
 
+    /**
+     * Read latest progress signal
+     *
+     * @param task_id - Task ID to read progress for
+     * @returns Last reported progress, None if the task has not reported any
+     */
+    pub fn read_progress(&self, task_id: &str) -> Result<Option<ProgressSignal>> {
+        let progress_file = self.workflow_dir.join(format!("{}.progress.json", task_id));
+        if !progress_file.exists() {
+            return Ok(None);
+        }
+
+        let json = fs::read_to_string(&progress_file)
+            .context("Failed to read progress file")?;
+
+        let signal: ProgressSignal = serde_json::from_str(&json)
+            .context("Failed to parse progress signal")?;
+
+        Ok(Some(signal))
+    }
+
+    /**
+     * Call `on_progress` with every progress signal written from now on
+     *
+     * DESIGN DECISION: Watcher handle instead of a blocking loop
+     * WHY: Progress keeps arriving for the whole sprint; UIs (desktop Tauri event)
+     * want a callback that lives as long as the handle they keep
+     *
+     * Signals that fail to parse (partially visible writes) are skipped; the next
+     * write for the task replaces them.
+     *
+     * @param on_progress - Called on the watcher thread for each progress signal
+     * @returns Watcher handle, stops watching when dropped
+     */
+    pub fn watch_progress(
+        &self,
+        on_progress: impl Fn(ProgressSignal) + Send + 'static,
+    ) -> Result<ProgressWatcher> {
+        let mut watcher = notify::recommended_watcher(move |res: Result<Event, _>| {
+            let Ok(event) = res else { return };
+            if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                return;
+            }
+
+            for path in &event.paths {
+                let is_progress = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.ends_with(".progress.json"));
+                let signal = fs::read_to_string(path)
+                    .ok()
+                    .and_then(|json| serde_json::from_str::<ProgressSignal>(&json).ok());
+                if let (true, Some(signal)) = (is_progress, signal) {
+                    on_progress(signal);
+                }
+            }
+        })?;
+
+        watcher.watch(&self.workflow_dir, RecursiveMode::NonRecursive)?;
+        Ok(ProgressWatcher { _watcher: watcher })
+    }
+
     /**
      * Wait for completion signal (blocking)
      *
@@ -167,6 +229,11 @@ impl SignalReader {
     }
 }
 
+/// Handle returned by SignalReader::watch_progress (watching stops when dropped)
+pub struct ProgressWatcher {
+    _watcher: notify::RecommendedWatcher,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,6 +262,46 @@ mod tests {
         assert_eq!(read_signal.agent_type, "test");
     }
 
+    #[test]
+    fn test_read_progress() {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = SignalWriter::new(temp_dir.path()).unwrap();
+        let reader = SignalReader::new(temp_dir.path()).unwrap();
+
+        assert!(reader.read_progress("TEST-001").unwrap().is_none());
+
+        writer.write_progress(&ProgressSignal::new("TEST-001", 0.25, "Started")).unwrap();
+        writer
+            .write_progress(&ProgressSignal::new("TEST-001", 0.5, "Halfway").with_sprint_progress(0.25))
+            .unwrap();
+
+        let progress = reader.read_progress("TEST-001").unwrap().unwrap();
+        assert_eq!(progress.fraction, 0.5);
+        assert_eq!(progress.message, "Halfway");
+        assert_eq!(progress.sprint_progress, Some(0.25));
+
+        // Progress files are not completion signals
+        assert!(reader.list_signals().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_watch_progress() {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = SignalWriter::new(temp_dir.path()).unwrap();
+        let reader = SignalReader::new(temp_dir.path()).unwrap();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _watcher = reader.watch_progress(move |signal| {
+            let _ = tx.send(signal);
+        }).unwrap();
+
+        writer.write_progress(&ProgressSignal::new("TEST-001", 0.5, "Halfway")).unwrap();
+
+        let signal = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(signal.task_id, "TEST-001");
+        assert_eq!(signal.fraction, 0.5);
+    }
+
     #[test]
     fn test_wait_for_signal() {
         let temp_dir = TempDir::new().unwrap();
@@ -121,3 +121,48 @@ impl CompletionSignal {
         }
     }
 }
+
+/**
+ * Progress signal written while a task runs
+ *
+ * DESIGN DECISION: One file per task, overwritten on each update
+ * WHY: Watchers (VS Code/Tauri UI) only need the latest figure; keeping history
+ * would grow the workflow directory with every report
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressSignal {
+    /// Task reporting progress
+    pub task_id: String,
+
+    /// Task progress (0.0 to 1.0)
+    pub fraction: f64,
+
+    /// What the agent is doing now
+    pub message: String,
+
+    /// Overall sprint progress (0.0 to 1.0), if known to the writer
+    pub sprint_progress: Option<f64>,
+
+    /// Report timestamp
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ProgressSignal {
+    /// Create progress signal (fraction clamped to 0.0..=1.0)
+    pub fn new(task_id: impl Into<String>, fraction: f64, message: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+            fraction: if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) },
+            message: message.into(),
+            sprint_progress: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Attach the overall sprint progress
+    pub fn with_sprint_progress(mut self, sprint_progress: f64) -> Self {
+        self.sprint_progress = Some(sprint_progress);
+        self
+    }
+}
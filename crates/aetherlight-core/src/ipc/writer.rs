@@ -19,7 +19,7 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
-use super::types::CompletionSignal;
+use super::types::{CompletionSignal, ProgressSignal};
 
 /**
  * Signal writer for agents
@@ -84,6 +84,30 @@ impl SignalWriter {
         Ok(())
     }
 
+    /**
+     * Write progress signal (atomic, replaces the previous one for the task)
+     *
+     * Written to `<task_id>.progress.json`, so completion watchers and
+     * list_signals (`.complete.json`) never pick it up.
+     *
+     * @param signal - Progress signal to write
+     */
+    pub fn write_progress(&self, signal: &ProgressSignal) -> Result<()> {
+        let progress_file = self.workflow_dir.join(format!("{}.progress.json", signal.task_id));
+        let temp_file = self.workflow_dir.join(format!("{}.progress.json.tmp", signal.task_id));
+
+        let json = serde_json::to_string_pretty(signal)
+            .context("Failed to serialize progress signal")?;
+
+        fs::write(&temp_file, json)
+            .context("Failed to write temp progress file")?;
+
+        fs::rename(&temp_file, &progress_file)
+            .context("Failed to rename progress file")?;
+
+        Ok(())
+    }
+
     /**
      * Check if signal exists for task
      *
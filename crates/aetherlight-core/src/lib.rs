@@ -110,6 +110,12 @@ pub mod embeddings;
 
 pub mod vector_store;
 
+// SQLite VACUUM/ANALYZE for the vector, analytics and desktop databases
+pub mod compaction;
+
+// JSON / compact binary format for handoffs, sprint plans and scheduler checkpoints
+pub mod serialization;
+
 // TEMPORARILY DISABLED FOR WEEK 0 LAUNCH: Depends on tree-sitter (requires C compiler)
 // WHY: code_intelligence uses tree-sitter for AST parsing (Phase 3 feature)
 // FUTURE: Re-enable in Phase 3 when tree-sitter dependencies restored
@@ -196,6 +202,9 @@ pub mod cli;
 // Real-time context sync (Phase 3.9 - RTC-001, RTC-002)
 pub mod realtime_sync;
 
+// Structured logging (tracing subscriber init)
+pub mod telemetry;
+
 // DESIGN DECISION: Re-export primary types at crate root
 // WHY: Ergonomic imports for library consumers (use aetherlight_core::Pattern)
 //
@@ -210,15 +219,28 @@ pub mod realtime_sync;
 // FUTURE: Add prelude module for glob imports (use aetherlight_core::prelude::*)
 
 pub use error::{Error, Result};
-pub use pattern::Pattern;
-pub use confidence::{ConfidenceScore, ConfidenceBreakdown};
-pub use matching::{PatternMatcher, MatchResult};
+pub use pattern::{
+    normalize_tag, normalize_tags, sanitize_tags, Pattern, PatternMetadata, INITIAL_PATTERN_VERSION,
+    TAG_DELIMITER,
+};
+pub use confidence::{ConfidenceScore, ConfidenceBreakdown, ConfidenceWeights, CompactScore, DimensionContribution};
+pub use matching::{
+    PatternMatcher, MatchResult, MatchingConfig, SYNONYMS_ALL_DOMAINS,
+    DiagnosticOptions, MatchDiagnostics, MissReason, NearMiss,
+    LibraryFingerprint, MatcherLoad, MATCHER_SNAPSHOT_VERSION,
+};
 pub use transcription::{Transcriber, TranscriptionResult};
 
 // Re-enabled after ort 2.0 API migration (REQUIRED FOR: AI-005)
-pub use embeddings::{LocalEmbeddings, Embedding, EMBEDDING_DIM};
+pub use embeddings::{
+    embedder_from_config, model_dir, model_paths, Embedder, KeywordEmbedder, LocalEmbeddings, RemoteEmbedder,
+    Embedding, EmbeddingConfig, EmbeddingPrecision, EmbeddingProvider, EmbeddingResult, RemoteEmbeddingConfig,
+    EMBEDDING_DIM, MODEL_DIR_ENV,
+};
 
-pub use vector_store::{SqliteVectorStore, SearchResult as VectorSearchResult};
+pub use vector_store::{HnswParams, SearchMode, SqliteVectorStore, SearchResult as VectorSearchResult, SimilarityMetric};
+pub use compaction::{compact_sqlite, needs_compaction, COMPACTION_FREE_RATIO};
+pub use serialization::StorageFormat;
 
 // TEMPORARILY DISABLED FOR WEEK 0 LAUNCH: code_intelligence module disabled
 // pub use code_intelligence::{
@@ -229,7 +251,8 @@ pub use vector_store::{SqliteVectorStore, SearchResult as VectorSearchResult};
 // };
 
 pub use analytics::{
-    UsageTracker, UsageMetrics, Metrics, MetricsPeriod, EventType
+    UsageTracker, UsageMetrics, Metrics, MetricsPeriod, DomainMetrics, EventType, EventRecord,
+    ExportFormat, TimeSavedConfig
 };
 pub use validation::{
     PatternValidator, ValidationResult, ValidationStatus,
@@ -237,7 +260,7 @@ pub use validation::{
     SecurityScanner, SecurityIssue, SecuritySeverity
 };
 pub use network::{
-    HierarchicalDHTClient, PublishResult, FindResult, DHTError,
+    HierarchicalDHTClient, DHTConfig, PublishResult, FindResult, DHTError,
     KademliaNode, NodeStatus
 };
 pub use crypto::{
@@ -255,7 +278,7 @@ pub use viral::{
 // };
 
 pub use domain_agent::{
-    Domain, Problem, Solution, SearchLevel, DomainAgent,
+    Domain, Problem, ProblemBuilder, Solution, SolutionBuilder, SearchLevel, DomainAgent,
     EscalationEngine, EscalationPath
 };
 
@@ -277,11 +300,12 @@ pub use agents::{
 };
 
 // Agent network (P3.5-007+)
-pub use agent_network::{AgentNetwork, AgentMessage, AgentResponse, AgentConnection};
+pub use agent_network::{AgentNetwork, AgentMessage, AgentResponse, AgentConnection, DomainReliability, MAX_RELIABILITY_BIAS};
 
 // Content addressing (Phase 3.6 - Pattern-CONTEXT-002)
 pub use content_addressing::{
-    ContentAddress, ContentRef, HashCache, CrossReferenceIndex, Dependent, calculate_sha256
+    ContentAddress, ContentRef, HashCache, CrossReferenceIndex, Dependent, calculate_sha256,
+    normalize_pattern_content, pattern_content_hash,
 };
 
 // Code map (Phase 3.6 - AI-001)
@@ -294,6 +318,7 @@ pub use code_map::{
 // Verification system (Phase 3.6 - AI-002)
 pub use verification::{
     VerificationSystem, VerificationConfig, AgentClaim, VerificationResult,
+    CriterionClaims, CriteriaScore,
     FileVerifier, FunctionVerifier, TestVerifier, PerformanceVerifier, ClaimParser
 };
 
@@ -308,7 +333,8 @@ pub use session_handoff::{
 // Pattern index (Phase 3.6 - AI-005)
 pub use pattern_index::{
     PatternIndex, IndexedPattern, PatternMatch, SearchContext,
-    PatternIndexStatistics
+    PatternIndexStatistics, RerankConfig, ContentDedup, DedupOutcome, ImportSummary, resolve_dir,
+    UsageOutcome, UsageEntry, UsageBatchReport, UsageBatchError
 };
 
 // Uncertainty quantification (Phase 3.6 - AI-007 + AI-008)
@@ -363,17 +389,17 @@ pub use sop_updater::SOPUpdater;
 // Improvement reports (Phase 3.6 - AI-013)
 pub use improvement_reports::{
     ImprovementReport, ImprovementReportGenerator, TrendAnalysis, PerformanceTrend,
-    TrendDirection, Finding, Recommendation,
+    TrendDirection, Finding, Recommendation, REPORT_SCHEMA_VERSION,
 };
 
 // Sprint parser (Phase 4 - AS-001, AS-002, AS-003)
 pub use sprint_parser::{
     SprintPlan, SprintMetadata, ExecutableSprintPlan,
     Task as SprintTask, TaskId, AgentType,
-    ApprovalGate, ParallelGroup, TaskContext,
+    ApprovalGate, ParallelGroup, TaskContext, Priority as SprintPriority,
     YamlParser, Validator as SprintValidator,
-    SprintDependencyGraph, TaskNode,
-    parse_sprint_file, parse_sprint_str
+    SprintDependencyGraph, TaskNode, SprintParseError, SprintPlanFormat,
+    parse_sprint_file, parse_sprint_str, parse_sprint_toml_str
 };
 // Note: Task renamed to SprintTask to avoid conflict with session_handoff::Task and context_loader::Task
 // Note: DependencyGraph renamed to SprintDependencyGraph to avoid conflict with code_map::DependencyGraph
@@ -382,14 +408,16 @@ pub use sprint_parser::{
 pub use task_scheduler::{
     TaskScheduler,
     ExecutionState, TaskStatus as SchedulerTaskStatus, AgentAssignment,
-    ProgressMonitor, SprintMetrics, SprintResult
+    ExecutionCheckpoint, TaskOutcome,
+    ProgressMonitor, SprintMetrics, SprintResult, LevelConcurrency,
+    ProgressReporter, ProgressUpdate, ProgressEvent
 };
 // Note: TaskStatus renamed to SchedulerTaskStatus to avoid conflicts
 
 // File-based IPC (Phase 4 - AS-014)
 pub use ipc::{
-    CompletionSignal, TaskStatus as IPCTaskStatus,
-    SignalWriter, SignalReader
+    CompletionSignal, ProgressSignal, TaskStatus as IPCTaskStatus,
+    SignalWriter, SignalReader, ProgressWatcher, WORKFLOW_DIR
 };
 // Note: TaskStatus renamed to IPCTaskStatus to avoid conflict with SchedulerTaskStatus
 
@@ -401,7 +429,8 @@ pub use function_registry::{
 
 // Function call generator (Phase 3.7 - P3.7-002)
 pub use function_call_generator::{
-    FunctionCallGenerator, FunctionCall, ParameterValue
+    FunctionCallGenerator, FunctionCall, ParameterValue, NumberLocale,
+    ExecutionPolicy, ExecutionDecision
 };
 
 // Multi-turn conversation manager (Phase 3.7 - P3.7-003)
@@ -419,7 +448,7 @@ pub use config::{
 };
 
 // CLI (Phase 3.9 - CONFIG-003)
-pub use cli::ConfigCli;
+pub use cli::{ConfigCli, SprintCli, SprintFormat, SprintSource};
 
 // Real-time context sync (Phase 3.9 - RTC-001, RTC-002)
 pub use realtime_sync::{
@@ -427,6 +456,9 @@ pub use realtime_sync::{
     ServerState, WsSession, ws_route, health_check, stats_endpoint,
 };
 
+// Structured logging
+pub use telemetry::init_tracing;
+
 // DESIGN DECISION: Semantic versioning with compile-time version constants
 // WHY: Enable version checking at runtime for FFI compatibility validation
 //
@@ -62,9 +62,151 @@
  * ```
  */
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::{Pattern, ConfidenceScore, ConfidenceBreakdown, Error, Result};
+use crate::{Pattern, ConfidenceScore, ConfidenceBreakdown, ConfidenceWeights, Error, Result};
+
+pub mod preprocess;
+pub mod fuzzy;
+pub mod snapshot;
+
+pub use preprocess::QueryPreprocessor;
+pub use snapshot::{LibraryFingerprint, MatcherLoad, MATCHER_SNAPSHOT_VERSION};
+
+/**
+ * Matching behaviour configuration
+ *
+ * DESIGN DECISION: Plain config struct with serde defaults
+ * WHY: Matching options load from TOML config like every other subsystem
+ *
+ * Loaded by ConfigLoader as `[pattern_library.matching]` (AetherlightConfig::pattern_library).
+ *
+ * PATTERN: Pattern-CONFIG-001 (Hierarchical Configuration)
+ * RELATED: PatternMatcher::with_config, config::loader::PatternLibraryConfig
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchingConfig {
+    /// Lowercase + stopword removal + stemming before keyword overlap
+    pub preprocessing: bool,
+
+    /// Typo-tolerant keyword matching (more expensive, off by default)
+    pub fuzzy_matching: bool,
+
+    /// Minimum Jaro-Winkler similarity for a fuzzy token hit [0.0, 1.0]
+    pub fuzzy_min_similarity: f64,
+
+    /// Cap on the length-scaled fuzzy edit budget (default 2: 1 edit for 5-8 char
+    /// tokens, 2 for longer; 0 = exact only)
+    pub fuzzy_max_edits: usize,
+
+    /// Confidence floor for find_matches results [0.0, 1.0] (0.0 = no floor)
+    ///
+    /// Overridable per call with find_matches_above.
+    pub min_confidence: f64,
+
+    /// Per-domain synonym maps: domain → (term → synonyms)
+    ///
+    /// Applied to patterns whose metadata domain matches the key; the
+    /// `SYNONYMS_ALL_DOMAINS` ("*") map applies to every pattern.
+    ///
+    /// ```toml
+    /// [pattern_library.matching.synonyms.security]
+    /// login = ["authentication", "signin"]
+    /// ```
+    pub synonyms: HashMap<String, HashMap<String, Vec<String>>>,
+
+    /// Weight of each confidence dimension in the total score (normalized to sum 1.0)
+    ///
+    /// Omitted dimensions keep their default weight.
+    ///
+    /// ```toml
+    /// [pattern_library.matching.weights]
+    /// security_score = 0.3
+    /// ```
+    pub weights: ConfidenceWeights,
+}
+
+/// Synonym map key applied regardless of pattern domain
+pub const SYNONYMS_ALL_DOMAINS: &str = "*";
+
+/**
+ * Weight of a synonym hit relative to a literal hit
+ *
+ * DESIGN DECISION: Synonyms count less than the user's literal words
+ * WHY: "login" matching "authentication" should lift the pattern, but a pattern
+ * that literally says "login" is still the better match
+ */
+const SYNONYM_WEIGHT: f64 = 0.8;
+
+/// Normalized synonym lookup: query token → synonym tokens
+type SynonymMap = HashMap<String, Vec<String>>;
+
+/**
+ * Preprocessed keyword tokens of one pattern
+ *
+ * DESIGN DECISION: Tokenize patterns once in add_pattern, not on every query
+ * WHY: Stemming every pattern's tags/title/content per query made find_matches
+ * O(library text) in stemmer calls; patterns are immutable once added
+ */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PatternTokens {
+    tags: HashSet<String>,
+    title: HashSet<String>,
+    content: HashSet<String>,
+}
+
+impl PatternTokens {
+    fn new(pattern: &Pattern, preprocessor: &QueryPreprocessor) -> Self {
+        Self {
+            tags: pattern.tags().iter().flat_map(|tag| preprocessor.tokens(tag)).collect(),
+            title: preprocessor.tokens(pattern.title()).into_iter().collect(),
+            content: preprocessor.tokens(pattern.content()).into_iter().collect(),
+        }
+    }
+}
+
+/// One pattern with its query-independent scoring inputs (built once per find/batch call)
+struct ScoringTarget<'a> {
+    pattern: &'a Pattern,
+    tokens: Option<&'a PatternTokens>,
+    synonyms: Vec<&'a SynonymMap>,
+}
+
+impl Default for MatchingConfig {
+    fn default() -> Self {
+        Self {
+            preprocessing: true,
+            fuzzy_matching: false,
+            fuzzy_min_similarity: 0.85,
+            fuzzy_max_edits: fuzzy::DEFAULT_MAX_EDITS,
+            min_confidence: 0.0,
+            synonyms: HashMap::new(),
+            weights: ConfidenceWeights::default(),
+        }
+    }
+}
+
+impl MatchingConfig {
+    /// Validate configuration (called by AetherlightConfig::validate)
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if !(0.0..=1.0).contains(&self.fuzzy_min_similarity) {
+            return Err(format!(
+                "fuzzy_min_similarity must be between 0.0 and 1.0, got {}",
+                self.fuzzy_min_similarity
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.min_confidence) {
+            return Err(format!(
+                "min_confidence must be between 0.0 and 1.0, got {}",
+                self.min_confidence
+            ));
+        }
+        self.weights.validate().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
 
 /**
  * Pattern matching engine with in-memory storage
@@ -89,6 +231,15 @@ use crate::{Pattern, ConfidenceScore, ConfidenceBreakdown, Error, Result};
 pub struct PatternMatcher {
     /// Pattern storage (ID -> Pattern)
     patterns: HashMap<Uuid, Pattern>,
+
+    /// Preprocessed keyword tokens per pattern (ID -> tokens), kept in sync with `patterns`
+    tokens: HashMap<Uuid, PatternTokens>,
+
+    /// Matching behaviour (preprocessing, etc.)
+    config: MatchingConfig,
+
+    /// config.synonyms normalized to query token form (domain -> map), rebuilt on set_config
+    synonym_maps: HashMap<String, SynonymMap>,
 }
 
 impl PatternMatcher {
@@ -105,11 +256,56 @@ impl PatternMatcher {
      * ```
      */
     pub fn new() -> Self {
+        Self::with_config(MatchingConfig::default())
+    }
+
+    /// Create an empty pattern matcher with custom matching configuration
+    pub fn with_config(config: MatchingConfig) -> Self {
         Self {
             patterns: HashMap::new(),
+            tokens: HashMap::new(),
+            synonym_maps: Self::normalized_synonyms(&config),
+            config,
         }
     }
 
+    /**
+     * Create an empty pattern matcher with custom confidence dimension weights
+     *
+     * DESIGN DECISION: Validate here, unlike with_config
+     * WHY: Config files are validated by AetherlightConfig::validate; weights passed
+     * in code have no other checkpoint, and invalid weights would drop every match
+     *
+     * # Examples
+     *
+     * ```rust
+     * let weights = ConfidenceWeights { security_score: 0.3, ..ConfidenceWeights::default() };
+     * let matcher = PatternMatcher::with_weights(weights)?;
+     * ```
+     */
+    pub fn with_weights(weights: ConfidenceWeights) -> Result<Self> {
+        weights.validate()?;
+        Ok(Self::with_config(MatchingConfig { weights, ..MatchingConfig::default() }))
+    }
+
+    /// Replace confidence dimension weights (applies to subsequent queries)
+    pub fn set_weights(&mut self, weights: ConfidenceWeights) -> Result<()> {
+        weights.validate()?;
+        self.config.weights = weights;
+        Ok(())
+    }
+
+    /// Get current matching configuration
+    pub fn config(&self) -> &MatchingConfig {
+        &self.config
+    }
+
+    /// Replace matching configuration (applies to subsequent queries)
+    pub fn set_config(&mut self, config: MatchingConfig) {
+        self.synonym_maps = Self::normalized_synonyms(&config);
+        self.config = config;
+    }
+
     /**
      * Add a pattern to the library
      *
@@ -140,6 +336,8 @@ impl PatternMatcher {
             return Err(Error::DuplicatePattern(id.to_string()));
         }
 
+        // Stemmed tokens cached up front (query side is the only per-query tokenization)
+        self.tokens.insert(id, PatternTokens::new(&pattern, &QueryPreprocessor::new()));
         self.patterns.insert(id, pattern);
         Ok(())
     }
@@ -161,6 +359,7 @@ impl PatternMatcher {
         self.patterns
             .remove(id)
             .ok_or_else(|| Error::PatternNotFound(id.to_string()))?;
+        self.tokens.remove(id);
         Ok(())
     }
 
@@ -213,8 +412,9 @@ impl PatternMatcher {
      * 1. User query converted to lowercase for case-insensitive matching
      * 2. Each pattern scored using multi-dimensional algorithm
      * 3. Results sorted by confidence (highest first)
-     * 4. Top N results returned (limit = max_results parameter)
-     * 5. Empty library returns error (not empty Vec) for explicit handling
+     * 4. Results below config.min_confidence dropped (central suggestion policy)
+     * 5. Top N results returned (limit = max_results parameter)
+     * 6. Empty library returns error (not empty Vec) for explicit handling
      *
      * PATTERN: Pattern-005 (Multi-Dimensional Matching)
      * PERFORMANCE: O(n) where n = pattern count (linear scan)
@@ -229,7 +429,25 @@ impl PatternMatcher {
      * }
      * ```
      */
+    #[tracing::instrument(skip(self, query), fields(query_len = query.len()))]
     pub fn find_matches(&self, query: &str, max_results: usize) -> Result<Vec<MatchResult>> {
+        self.find_matches_above(query, max_results, self.config.min_confidence)
+    }
+
+    /**
+     * Find matches with a per-call confidence floor
+     *
+     * DESIGN DECISION: Override replaces config.min_confidence for this call only
+     * WHY: A call site may loosen the floor (e.g. explicit search UI) or tighten it
+     * (e.g. unprompted auto-suggestions) without changing the shared policy
+     */
+    #[tracing::instrument(skip(self, query), fields(query_len = query.len()))]
+    pub fn find_matches_above(
+        &self,
+        query: &str,
+        max_results: usize,
+        min_confidence: f64,
+    ) -> Result<Vec<MatchResult>> {
         // Validate query
         if query.trim().is_empty() {
             return Err(Error::InvalidQuery("Query cannot be empty".to_string()));
@@ -240,17 +458,187 @@ impl PatternMatcher {
             return Err(Error::EmptyLibrary);
         }
 
+        // Return top N results at or above the floor
+        let mut results = self.score_all(query);
+        results.retain(|result| result.confidence.meets_threshold(min_confidence));
+        results.truncate(max_results);
+        Ok(results)
+    }
+
+    /**
+     * Find matches, explaining near-misses when nothing qualifies
+     *
+     * DESIGN DECISION: Separate diagnostic entry point, find_matches unchanged
+     * WHY: An empty result is a dead end ("I know I have a relevant pattern");
+     * showing the closest candidates and why they failed guides the user instead
+     *
+     * REASONING CHAIN:
+     * 1. Empty library is reported as a reason, not an error
+     * 2. Every pattern is scored exactly as in find_matches
+     * 3. Patterns missing a required tag → FilteredByTag near-miss
+     * 4. Patterns under min_confidence → BelowThreshold near-miss
+     * 5. Remaining patterns are matches (top max_results)
+     * 6. When no matches, `reason` carries the reason of the best near-miss
+     *
+     * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+     * PERFORMANCE: Same O(n) scan as find_matches
+     */
+    #[tracing::instrument(skip(self, query, options), fields(query_len = query.len()))]
+    pub fn find_matches_with_diagnostics(
+        &self,
+        query: &str,
+        max_results: usize,
+        options: &DiagnosticOptions,
+    ) -> Result<MatchDiagnostics> {
+        if query.trim().is_empty() {
+            return Err(Error::InvalidQuery("Query cannot be empty".to_string()));
+        }
+
+        if self.is_empty() {
+            return Ok(MatchDiagnostics {
+                matches: Vec::new(),
+                near_misses: Vec::new(),
+                reason: Some(MissReason::EmptyLibrary),
+            });
+        }
+
+        let required_tags: Vec<String> = options.required_tags.iter().map(|t| t.to_lowercase()).collect();
+        let mut matches = Vec::new();
+        let mut near_misses = Vec::new();
+
+        for result in self.score_all(query) {
+            let has_tags = required_tags.iter().all(|required| {
+                result.pattern.tags().iter().any(|tag| tag.to_lowercase() == *required)
+            });
+
+            let reason = if !has_tags {
+                MissReason::FilteredByTag
+            } else if !result.confidence.meets_threshold(options.min_confidence) {
+                MissReason::BelowThreshold
+            } else {
+                matches.push(result);
+                continue;
+            };
+
+            near_misses.push(NearMiss {
+                pattern: result.pattern,
+                confidence: result.confidence,
+                reason,
+            });
+        }
+
+        matches.truncate(max_results);
+        let reason = if matches.is_empty() {
+            near_misses.first().map(|miss| miss.reason)
+        } else {
+            None
+        };
+        near_misses.truncate(options.max_near_misses);
+
+        Ok(MatchDiagnostics { matches, near_misses, reason })
+    }
+
+    /**
+     * Find matches for many queries in one call
+     *
+     * DESIGN DECISION: Pattern-side scoring inputs built once, queries scored in parallel
+     * WHY: Hosts matching ~200 queries/second paid per-call setup (synonym lookup per
+     * pattern, preprocessor construction) N times and used a single core
+     *
+     * REASONING CHAIN:
+     * 1. Empty library → EmptyLibrary error (same as find_matches)
+     * 2. Cached pattern tokens + applicable synonym maps collected once per batch
+     * 3. One preprocessor shared by every query (read-only)
+     * 4. Queries scored across cores with rayon ("parallel" feature, on by default)
+     * 5. Indexed collect keeps output order == input order
+     * 6. Blank queries → empty result vector, not an error (one bad entry must not fail the batch)
+     * 7. Each entry filtered/truncated exactly like find_matches (config.min_confidence)
+     *
+     * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+     * PERFORMANCE: O(q × n) work split across cores (q = queries, n = patterns)
+     * RELATED: find_matches (single query), aetherlight-node findMatchesBatch
+     *
+     * # Examples
+     *
+     * ```rust
+     * let results = matcher.find_matches_batch(&["handle errors in rust", "", "async io"], 5)?;
+     * assert_eq!(results.len(), 3);
+     * assert!(results[1].is_empty());
+     * ```
+     */
+    #[tracing::instrument(skip(self, queries), fields(queries = queries.len()))]
+    pub fn find_matches_batch(&self, queries: &[&str], max_results: usize) -> Result<Vec<Vec<MatchResult>>> {
+        if self.is_empty() {
+            return Err(Error::EmptyLibrary);
+        }
+
+        let preprocessor = self.config.preprocessing.then(QueryPreprocessor::new);
+        let targets = self.scoring_targets();
+        let match_query = |query: &&str| -> Vec<MatchResult> {
+            if query.trim().is_empty() {
+                return Vec::new();
+            }
+            let mut results = self.score_targets(query, preprocessor.as_ref(), &targets);
+            results.retain(|result| result.confidence.meets_threshold(self.config.min_confidence));
+            results.truncate(max_results);
+            results
+        };
+
+        #[cfg(feature = "parallel")]
+        let results = {
+            use rayon::prelude::*;
+            queries.par_iter().map(match_query).collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let results = queries.iter().map(match_query).collect();
+
+        Ok(results)
+    }
+
+    /// Score every pattern against a query, sorted by confidence (descending)
+    fn score_all(&self, query: &str) -> Vec<MatchResult> {
+        let preprocessor = self.config.preprocessing.then(QueryPreprocessor::new);
+        self.score_targets(query, preprocessor.as_ref(), &self.scoring_targets())
+    }
+
+    /// Pattern-side scoring inputs (cached tokens, synonym maps), independent of the query
+    fn scoring_targets(&self) -> Vec<ScoringTarget<'_>> {
+        self.patterns
+            .values()
+            .map(|pattern| ScoringTarget {
+                pattern,
+                // Synonym maps normalized the same way as query tokens (keyword dimension only)
+                synonyms: Self::synonyms_for(pattern, &self.synonym_maps),
+                tokens: self.config.preprocessing.then(|| &self.tokens[pattern.id()]),
+            })
+            .collect()
+    }
+
+    /// Score targets against a query, sorted by confidence (descending)
+    fn score_targets(
+        &self,
+        query: &str,
+        preprocessor: Option<&QueryPreprocessor>,
+        targets: &[ScoringTarget<'_>],
+    ) -> Vec<MatchResult> {
         // Normalize query for case-insensitive matching
+        // Raw lowercase text kept for context/semantic dimensions
         let query_lower = query.to_lowercase();
-        let query_words: Vec<&str> = query_lower.split_whitespace().collect();
+
+        // Keyword tokens: preprocessed (stopwords removed, stemmed) or raw words
+        let query_tokens: Vec<String> = match preprocessor {
+            Some(pre) => pre.tokens(query),
+            None => query_lower.split_whitespace().map(str::to_string).collect(),
+        };
+        let query_words: Vec<&str> = query_tokens.iter().map(String::as_str).collect();
 
         // Score all patterns
-        let mut results: Vec<MatchResult> = self.patterns
-            .values()
-            .filter_map(|pattern| {
-                match self.score_pattern(pattern, &query_lower, &query_words) {
+        let mut results: Vec<MatchResult> = targets
+            .iter()
+            .filter_map(|target| {
+                match self.score_pattern(target.pattern, &query_lower, &query_words, target.tokens, &target.synonyms) {
                     Ok(confidence) => Some(MatchResult {
-                        pattern: pattern.clone(),
+                        pattern: target.pattern.clone(),
                         confidence,
                     }),
                     Err(_) => None, // Skip patterns with scoring errors
@@ -265,9 +653,7 @@ impl PatternMatcher {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        // Return top N results
-        results.truncate(max_results);
-        Ok(results)
+        results
     }
 
     /**
@@ -282,7 +668,7 @@ impl PatternMatcher {
      * 2. Context match: Check language/framework/domain metadata
      * 3. TODO: Semantic similarity via embeddings (P1-007)
      * 4. TODO: Historical success rate from usage data (P1-006)
-     * 5. Combine dimensions into confidence score using weights
+     * 5. Combine dimensions into confidence score using config.weights
      *
      * PATTERN: Pattern-005 (Multi-Dimensional Matching)
      * PERFORMANCE: O(m) where m = pattern field lengths (acceptable for 10k patterns)
@@ -293,9 +679,14 @@ impl PatternMatcher {
         pattern: &Pattern,
         query_lower: &str,
         query_words: &[&str],
+        tokens: Option<&PatternTokens>,
+        synonyms: &[&SynonymMap],
     ) -> Result<ConfidenceScore> {
-        // Dimension 1: Keyword overlap (tags, title, content)
-        let keyword_score = self.calculate_keyword_overlap(pattern, query_words);
+        // Dimension 1: Keyword overlap (tags, title, content), with synonym expansion
+        let keyword_score = match tokens {
+            Some(tokens) => self.calculate_preprocessed_overlap(tokens, query_words, synonyms),
+            None => self.calculate_keyword_overlap(pattern, query_words, synonyms),
+        };
 
         // Dimension 2: Context match (language, framework, domain)
         let context_score = self.calculate_context_match(pattern, query_lower);
@@ -328,7 +719,7 @@ impl PatternMatcher {
             .code_quality_score(quality_score)
             .build()?;
 
-        ConfidenceScore::calculate(breakdown)
+        ConfidenceScore::calculate_weighted(breakdown, self.config.weights)
     }
 
     /**
@@ -345,9 +736,14 @@ impl PatternMatcher {
      * 5. Cap at 1.0 for consistent score range
      *
      * PERFORMANCE: O(m * n) where m = query words, n = pattern field lengths
-     * FUTURE: Add stemming/lemmatization for better matching (P1-008)
+     * RELATED: calculate_preprocessed_overlap (stemmed variant, default)
      */
-    fn calculate_keyword_overlap(&self, pattern: &Pattern, query_words: &[&str]) -> f64 {
+    fn calculate_keyword_overlap(
+        &self,
+        pattern: &Pattern,
+        query_words: &[&str],
+        synonyms: &[&SynonymMap],
+    ) -> f64 {
         if query_words.is_empty() {
             return 0.0;
         }
@@ -358,25 +754,168 @@ impl PatternMatcher {
             .map(|t| t.to_lowercase())
             .collect();
 
-        let mut matches = 0;
-        for word in query_words {
-            // Check exact match in tags
+        let word_hits = |word: &str| -> f64 {
+            let mut hits = 0.0;
+            // Check exact match in tags (fuzzy fallback when enabled)
             if tags_lower.iter().any(|tag| tag == word) {
-                matches += 2; // Tags are high-signal (weight more)
+                hits += 2.0; // Tags are high-signal (weight more)
+            } else {
+                hits += 2.0 * self.fuzzy_hit(word, tags_lower.iter().map(String::as_str));
             }
             // Check substring match in title
             if title_lower.contains(word) {
-                matches += 1;
+                hits += 1.0;
+            } else {
+                hits += self.fuzzy_hit(word, title_lower.split_whitespace());
             }
             // Check substring match in content
             if content_lower.contains(word) {
-                matches += 1;
+                hits += 1.0;
+            } else {
+                hits += self.fuzzy_hit(word, content_lower.split_whitespace());
             }
+            hits
+        };
+
+        let matches: f64 = query_words.iter()
+            .map(|word| Self::expanded_hits(word, synonyms, word_hits))
+            .sum();
+
+        Self::normalize_overlap(matches, query_words.len())
+    }
+
+    /**
+     * Calculate keyword overlap on preprocessed tokens
+     *
+     * DESIGN DECISION: Exact token-set membership after identical preprocessing
+     * WHY: Query and pattern both stemmed, so "handling errors" and "error handling"
+     * produce the same tokens; substring matching would be redundant and noisier
+     *
+     * REASONING CHAIN:
+     * 1. Tags, title, content tokenized with the same preprocessor as the query
+     *    (once per pattern, cached in add_pattern)
+     * 2. Tag token hit = 2, title token hit = 1, content token hit = 1
+     * 3. Normalize exactly like calculate_keyword_overlap (comparable scores)
+     * 4. Stopwords never reach this point, so they cannot inflate or dilute the score
+     *
+     * PERFORMANCE: O(m) HashSet lookups per pattern (pattern side pre-tokenized)
+     */
+    fn calculate_preprocessed_overlap(
+        &self,
+        pattern_tokens: &PatternTokens,
+        query_tokens: &[&str],
+        synonyms: &[&SynonymMap],
+    ) -> f64 {
+        if query_tokens.is_empty() {
+            return 0.0;
         }
 
-        // Normalize by max possible score (each word can match in tags, title, content)
-        let max_score = query_words.len() * 4; // 2 (tags) + 1 (title) + 1 (content)
-        let score = matches as f64 / max_score as f64;
+        let token_hits = |token: &str| -> f64 {
+            2.0 * self.token_hit(token, &pattern_tokens.tags) // Tags are high-signal (weight more)
+                + self.token_hit(token, &pattern_tokens.title)
+                + self.token_hit(token, &pattern_tokens.content)
+        };
+
+        let matches: f64 = query_tokens.iter()
+            .map(|token| Self::expanded_hits(token, synonyms, token_hits))
+            .sum();
+
+        Self::normalize_overlap(matches, query_tokens.len())
+    }
+
+    /**
+     * Hits for a query word, taking the best of the literal word and its synonyms
+     *
+     * DESIGN DECISION: Max over alternatives, normalized by original word count
+     * WHY: Expansion must lift synonym matches without diluting the score of
+     * queries whose literal words already match (no extra denominator terms)
+     */
+    fn expanded_hits(word: &str, synonyms: &[&SynonymMap], hits: impl Fn(&str) -> f64) -> f64 {
+        let literal = hits(word);
+
+        synonyms.iter()
+            .filter_map(|map| map.get(word))
+            .flatten()
+            .map(|synonym| SYNONYM_WEIGHT * hits(synonym))
+            .fold(literal, f64::max)
+    }
+
+    /**
+     * Normalize configured synonym maps to match query token form
+     *
+     * DESIGN DECISION: Run terms and synonyms through the same preprocessor as the query
+     * WHY: With stemming on, query token "login" may become "login" but "logins" too;
+     * lookups only work if map keys/values share the query's normal form
+     *
+     * PERFORMANCE: Once per with_config/set_config, O(total synonym terms)
+     */
+    fn normalized_synonyms(config: &MatchingConfig) -> HashMap<String, SynonymMap> {
+        let preprocessor = config.preprocessing.then(QueryPreprocessor::new);
+        let normalize = |text: &str| -> Vec<String> {
+            match &preprocessor {
+                Some(pre) => pre.tokens(text),
+                None => text.to_lowercase().split_whitespace().map(str::to_string).collect(),
+            }
+        };
+
+        config.synonyms.iter()
+            .map(|(domain, terms)| {
+                let mut map = SynonymMap::new();
+                for (term, synonyms) in terms {
+                    let expansions: Vec<String> = synonyms.iter().flat_map(|s| normalize(s)).collect();
+                    for key in normalize(term) {
+                        map.entry(key).or_default().extend(expansions.iter().cloned());
+                    }
+                }
+                (domain.to_lowercase(), map)
+            })
+            .collect()
+    }
+
+    /// Synonym maps applicable to a pattern (its domain + all-domains map)
+    fn synonyms_for<'a>(pattern: &Pattern, maps: &'a HashMap<String, SynonymMap>) -> Vec<&'a SynonymMap> {
+        let domain_map = pattern.metadata().domain.as_ref()
+            .and_then(|domain| maps.get(&domain.to_lowercase()));
+
+        maps.get(SYNONYMS_ALL_DOMAINS).into_iter().chain(domain_map).collect()
+    }
+
+    /// Hit strength of a token against a field token set: exact = 1.0, fuzzy = similarity
+    fn token_hit(&self, token: &str, field_tokens: &HashSet<String>) -> f64 {
+        if field_tokens.contains(token) {
+            return 1.0;
+        }
+        self.fuzzy_hit(token, field_tokens.iter().map(String::as_str))
+    }
+
+    /**
+     * Best fuzzy similarity of a token against field words (0.0 when disabled)
+     *
+     * DESIGN DECISION: Fuzzy hits contribute their similarity, not a full hit
+     * WHY: "authetication" should help, but never outrank an exact "authentication"
+     */
+    fn fuzzy_hit<'a>(&self, token: &str, words: impl Iterator<Item = &'a str>) -> f64 {
+        if !self.config.fuzzy_matching {
+            return 0.0;
+        }
+
+        words
+            .filter_map(|word| {
+                fuzzy::fuzzy_similarity_within(
+                    token,
+                    word,
+                    self.config.fuzzy_min_similarity,
+                    self.config.fuzzy_max_edits,
+                )
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Normalize raw keyword hits by max possible score, capped at 1.0
+    fn normalize_overlap(matches: f64, word_count: usize) -> f64 {
+        // Each word can match in tags (2), title (1), content (1)
+        let max_score = word_count * 4;
+        let score = matches / max_score as f64;
 
         score.min(1.0) // Cap at 1.0
     }
@@ -465,6 +1004,89 @@ pub struct MatchResult {
     pub confidence: ConfidenceScore,
 }
 
+/**
+ * Options for PatternMatcher::find_matches_with_diagnostics
+ *
+ * DESIGN DECISION: Threshold and tag filter live with the diagnostic call
+ * WHY: Callers that already filter (desktop voice capture, IDE) pass the same
+ * criteria so the diagnostics explain their actual filtering
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiagnosticOptions {
+    /// Minimum total confidence for a match [0.0, 1.0]
+    pub min_confidence: f64,
+
+    /// Tags a pattern must carry (all of them, case-insensitive)
+    pub required_tags: Vec<String>,
+
+    /// Maximum near-miss candidates returned
+    pub max_near_misses: usize,
+}
+
+impl Default for DiagnosticOptions {
+    fn default() -> Self {
+        Self {
+            min_confidence: 0.5,
+            required_tags: Vec::new(),
+            max_near_misses: 3,
+        }
+    }
+}
+
+/// Why a pattern (or the whole query) produced no match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissReason {
+    /// No patterns in the library
+    EmptyLibrary,
+    /// Scored under DiagnosticOptions::min_confidence
+    BelowThreshold,
+    /// Missing one of DiagnosticOptions::required_tags
+    FilteredByTag,
+}
+
+impl MissReason {
+    /// Stable identifier for FFI/IPC consumers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MissReason::EmptyLibrary => "empty_library",
+            MissReason::BelowThreshold => "below_threshold",
+            MissReason::FilteredByTag => "filtered_by_tag",
+        }
+    }
+}
+
+/// Candidate that was scored but did not qualify as a match
+#[derive(Debug, Clone)]
+pub struct NearMiss {
+    /// Candidate pattern
+    pub pattern: Pattern,
+
+    /// Confidence score with breakdown
+    pub confidence: ConfidenceScore,
+
+    /// Why the candidate was rejected
+    pub reason: MissReason,
+}
+
+/**
+ * Matches plus near-miss diagnostics
+ *
+ * `reason` is set only when `matches` is empty.
+ */
+#[derive(Debug, Clone)]
+pub struct MatchDiagnostics {
+    /// Qualifying matches sorted by confidence (descending)
+    pub matches: Vec<MatchResult>,
+
+    /// Best rejected candidates sorted by confidence (descending)
+    pub near_misses: Vec<NearMiss>,
+
+    /// Why nothing matched
+    pub reason: Option<MissReason>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -581,6 +1203,226 @@ mod tests {
         assert!(result.is_err());
     }
 
+    /**
+     * Test: Batch results match per-query find_matches, in input order
+     */
+    #[test]
+    fn test_find_matches_batch_matches_single_queries() {
+        let mut matcher = PatternMatcher::new();
+        for (title, tag, language) in [
+            ("Rust error handling", "error-handling", "rust"),
+            ("Python exception handling", "exceptions", "python"),
+            ("Async IO with tokio", "async", "rust"),
+            ("React state hooks", "react", "typescript"),
+        ] {
+            let pattern = Pattern::builder()
+                .title(title)
+                .content(format!("{} explained with examples", title))
+                .tags(vec![tag, language])
+                .language(language)
+                .build()
+                .unwrap();
+            matcher.add_pattern(pattern).unwrap();
+        }
+
+        let queries = [
+            "How do I handle errors in Rust?",
+            "python exceptions",
+            "async io in rust",
+            "react hooks state",
+            "How do I handle errors in Rust?",
+        ];
+        let batch = matcher.find_matches_batch(&queries, 3).unwrap();
+
+        assert_eq!(batch.len(), queries.len());
+        for (query, results) in queries.iter().zip(&batch) {
+            let single = matcher.find_matches(query, 3).unwrap();
+            let titles = |r: &[MatchResult]| r.iter().map(|m| m.pattern.title().to_string()).collect::<Vec<_>>();
+            assert_eq!(titles(results), titles(&single), "query: {}", query);
+        }
+        assert!(batch[2][0].pattern.title().contains("Async"));
+    }
+
+    /**
+     * Test: Blank queries yield empty entries; empty library still errors
+     */
+    #[test]
+    fn test_find_matches_batch_empty_queries() {
+        let mut matcher = PatternMatcher::new();
+        assert!(matches!(matcher.find_matches_batch(&["rust"], 5), Err(Error::EmptyLibrary)));
+
+        matcher.add_pattern(Pattern::new("Rust errors".to_string(), "Use Result".to_string(), vec!["rust".to_string()])).unwrap();
+        let batch = matcher.find_matches_batch(&["", "rust errors", "   "], 5).unwrap();
+        assert!(batch[0].is_empty());
+        assert_eq!(batch[1].len(), 1);
+        assert!(batch[2].is_empty());
+        assert!(matcher.find_matches_batch(&[], 5).unwrap().is_empty());
+    }
+
+    /**
+     * Test: Query below threshold returns near-miss candidates with reason
+     */
+    #[test]
+    fn test_diagnostics_report_below_threshold_near_misses() {
+        let mut matcher = PatternMatcher::new();
+        matcher.add_pattern(Pattern::new(
+            "Rust error handling".to_string(),
+            "Use Result<T, E> for errors".to_string(),
+            vec!["rust".to_string()],
+        )).unwrap();
+        matcher.add_pattern(Pattern::new(
+            "Python exception handling".to_string(),
+            "Use try/except for errors".to_string(),
+            vec!["python".to_string()],
+        )).unwrap();
+
+        let options = DiagnosticOptions { min_confidence: 0.99, ..Default::default() };
+        let diagnostics = matcher
+            .find_matches_with_diagnostics("handle errors in rust", 5, &options)
+            .unwrap();
+
+        assert_eq!(matcher.find_matches("handle errors in rust", 5).unwrap().len(), 2);
+        assert!(diagnostics.matches.is_empty());
+        assert_eq!(diagnostics.reason, Some(MissReason::BelowThreshold));
+        assert_eq!(diagnostics.near_misses.len(), 2);
+        assert!(diagnostics.near_misses[0].pattern.title().contains("Rust"));
+        assert!(diagnostics.near_misses.iter().all(|m| m.reason == MissReason::BelowThreshold));
+    }
+
+    /**
+     * Test: Configured floor excludes low-confidence results; per-call override loosens or tightens it
+     */
+    #[test]
+    fn test_min_confidence_floor_and_override() {
+        let mut matcher = PatternMatcher::new();
+        matcher.add_pattern(Pattern::builder()
+            .title("Rust error handling")
+            .content("Use Result<T, E> for errors")
+            .tags(vec!["rust", "error-handling"])
+            .language("rust")
+            .build()
+            .unwrap()).unwrap();
+        matcher.add_pattern(Pattern::builder()
+            .title("CSS grid layout")
+            .content("Use grid-template-areas for dashboards")
+            .tags(vec!["css"])
+            .build()
+            .unwrap()).unwrap();
+
+        let query = "How do I handle errors in Rust?";
+        let all = matcher.find_matches(query, 5).unwrap();
+        assert_eq!(all.len(), 2);
+        let (high, low) = (all[0].confidence.total_score(), all[1].confidence.total_score());
+        assert!(high > low);
+        let floor = (high + low) / 2.0;
+
+        matcher.set_config(MatchingConfig { min_confidence: floor, ..MatchingConfig::default() });
+        let results = matcher.find_matches(query, 5).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].pattern.title().contains("Rust"));
+
+        // Loosen and tighten per call; configured floor unchanged
+        assert_eq!(matcher.find_matches_above(query, 5, 0.0).unwrap().len(), 2);
+        assert!(matcher.find_matches_above(query, 5, high + 0.001).unwrap().is_empty());
+        assert_eq!(matcher.config().min_confidence, floor);
+    }
+
+    /**
+     * Test: min_confidence outside [0, 1] rejected by validation
+     */
+    #[test]
+    fn test_min_confidence_validated() {
+        let config = MatchingConfig { min_confidence: 1.5, ..MatchingConfig::default() };
+        assert!(config.validate().is_err());
+    }
+
+    /**
+     * Test: Custom weights change ranking; default weights leave scores unchanged
+     */
+    #[test]
+    fn test_custom_weights_change_ranking() {
+        // Keyword match on "tokio" vs context match on "rust"
+        let keyword = Pattern::builder()
+            .title("Tokio runtime setup")
+            .content("Configure the tokio runtime")
+            .tags(vec!["tokio"])
+            .build()
+            .unwrap();
+        let context = Pattern::builder()
+            .title("Ownership basics")
+            .content("Borrowing explained")
+            .tags(vec!["ownership"])
+            .language("rust")
+            .build()
+            .unwrap();
+        let query = "tokio runtime in rust";
+
+        let mut matcher = PatternMatcher::new();
+        matcher.add_pattern(keyword.clone()).unwrap();
+        matcher.add_pattern(context.clone()).unwrap();
+        let before = matcher.find_matches(query, 2).unwrap();
+
+        let mut weighted = PatternMatcher::with_weights(ConfidenceWeights::default()).unwrap();
+        weighted.add_pattern(keyword).unwrap();
+        weighted.add_pattern(context).unwrap();
+        let same = weighted.find_matches(query, 2).unwrap();
+        for (a, b) in before.iter().zip(&same) {
+            assert_eq!(a.confidence.total_score(), b.confidence.total_score());
+        }
+
+        // Context-only weighting puts the language match first
+        weighted
+            .set_weights(ConfidenceWeights { context_match: 1.0, keyword_overlap: 0.0, ..ConfidenceWeights::default() })
+            .unwrap();
+        assert_eq!(weighted.find_matches(query, 2).unwrap()[0].pattern.title(), "Ownership basics");
+
+        let keyword_only = ConfidenceWeights { context_match: 0.0, keyword_overlap: 1.0, ..ConfidenceWeights::default() };
+        weighted.set_weights(keyword_only).unwrap();
+        assert_eq!(weighted.find_matches(query, 2).unwrap()[0].pattern.title(), "Tokio runtime setup");
+
+        let negative = ConfidenceWeights { security_score: -1.0, ..ConfidenceWeights::default() };
+        assert!(PatternMatcher::with_weights(negative).is_err());
+        assert!(weighted.set_weights(negative).is_err());
+        assert!(MatchingConfig { weights: negative, ..MatchingConfig::default() }.validate().is_err());
+    }
+
+    /**
+     * Test: Tag filter and empty library reported as reasons
+     */
+    #[test]
+    fn test_diagnostics_tag_filter_and_empty_library() {
+        let empty = PatternMatcher::new();
+        let diagnostics = empty
+            .find_matches_with_diagnostics("anything", 5, &DiagnosticOptions::default())
+            .unwrap();
+        assert_eq!(diagnostics.reason, Some(MissReason::EmptyLibrary));
+
+        let mut matcher = PatternMatcher::new();
+        matcher.add_pattern(Pattern::new(
+            "Rust error handling".to_string(),
+            "Use Result<T, E> for errors".to_string(),
+            vec!["rust".to_string()],
+        )).unwrap();
+
+        let options = DiagnosticOptions {
+            min_confidence: 0.0,
+            required_tags: vec!["Python".to_string()],
+            ..Default::default()
+        };
+        let diagnostics = matcher
+            .find_matches_with_diagnostics("rust errors", 5, &options)
+            .unwrap();
+        assert!(diagnostics.matches.is_empty());
+        assert_eq!(diagnostics.reason, Some(MissReason::FilteredByTag));
+
+        let options = DiagnosticOptions { min_confidence: 0.0, ..Default::default() };
+        let diagnostics = matcher
+            .find_matches_with_diagnostics("rust errors", 5, &options)
+            .unwrap();
+        assert_eq!(diagnostics.matches.len(), 1);
+        assert_eq!(diagnostics.reason, None);
+    }
+
     /**
      * Test: Keyword overlap calculation
      */
@@ -595,12 +1437,295 @@ mod tests {
             .unwrap();
 
         let query_words = vec!["rust", "error", "handling"];
-        let score = matcher.calculate_keyword_overlap(&pattern, &query_words);
+        let score = matcher.calculate_keyword_overlap(&pattern, &query_words, &[]);
 
         assert!(score > 0.0);
         assert!(score <= 1.0);
     }
 
+    /**
+     * Test: Stemming matches morphological variants ("handling errors" vs "error handle")
+     */
+    #[test]
+    fn test_preprocessing_matches_variants() {
+        let matcher = PatternMatcher::new();
+        let pattern = Pattern::builder()
+            .title("Error handle strategies")
+            .content("Propagate with the question mark operator")
+            .tags(vec!["rust"])
+            .build()
+            .unwrap();
+
+        let raw = matcher.calculate_keyword_overlap(&pattern, &["handling", "errors"], &[]);
+
+        let pre = QueryPreprocessor::new();
+        let tokens = pre.tokens("handling errors");
+        let token_refs: Vec<&str> = tokens.iter().map(String::as_str).collect();
+        let stemmed = matcher.calculate_preprocessed_overlap(&PatternTokens::new(&pattern, &pre), &token_refs, &[]);
+
+        assert_eq!(raw, 0.0);
+        assert!(stemmed > raw, "Stemming should match morphological variants");
+    }
+
+    /**
+     * Test: Pattern tokens cached on add and dropped on remove
+     */
+    #[test]
+    fn test_pattern_tokens_cached() {
+        let mut matcher = PatternMatcher::new();
+        let pattern = Pattern::builder()
+            .title("Handling errors")
+            .content("Propagate failures")
+            .tags(vec!["error-handling"])
+            .build()
+            .unwrap();
+        let id = *pattern.id();
+        matcher.add_pattern(pattern).unwrap();
+
+        let tokens = &matcher.tokens[&id];
+        assert!(tokens.tags.contains("handl"));
+        assert!(tokens.title.contains("error"));
+        assert!(tokens.content.contains("failur"));
+
+        matcher.remove_pattern(&id).unwrap();
+        assert!(matcher.tokens.is_empty());
+    }
+
+    /**
+     * Test: Stopwords don't change keyword score
+     */
+    #[test]
+    fn test_stopwords_do_not_inflate_score() {
+        let mut matcher = PatternMatcher::new();
+        let pattern = Pattern::builder()
+            .title("Rust error handling")
+            .content("Use Result for fallible operations in a function")
+            .tags(vec!["rust", "error-handling"])
+            .build()
+            .unwrap();
+        matcher.add_pattern(pattern).unwrap();
+
+        // "in" and "a" would substring-match the content without stopword removal
+        let terse = matcher.find_matches("rust error handling", 1).unwrap();
+        let padded = matcher.find_matches("how do I do the rust error handling in a", 1).unwrap();
+        assert_eq!(
+            terse[0].confidence.breakdown().keyword_overlap,
+            padded[0].confidence.breakdown().keyword_overlap
+        );
+    }
+
+    /**
+     * Test: Preprocessing can be disabled (raw substring matching)
+     */
+    #[test]
+    fn test_preprocessing_disabled() {
+        let mut matcher = PatternMatcher::with_config(MatchingConfig {
+            preprocessing: false,
+            ..MatchingConfig::default()
+        });
+        let pattern = Pattern::builder()
+            .title("Error handle strategies")
+            .content("Propagate upward")
+            .build()
+            .unwrap();
+        matcher.add_pattern(pattern).unwrap();
+
+        let results = matcher.find_matches("handling errors", 1).unwrap();
+        assert_eq!(results[0].confidence.breakdown().keyword_overlap, 0.0);
+        assert!(!matcher.config().preprocessing);
+    }
+
+    /**
+     * Test: One-character typo still matches the intended pattern with fuzzy matching
+     */
+    #[test]
+    fn test_fuzzy_matching_tolerates_typo() {
+        let config = MatchingConfig {
+            fuzzy_matching: true,
+            ..MatchingConfig::default()
+        };
+        let mut matcher = PatternMatcher::with_config(config);
+
+        let auth = Pattern::builder()
+            .title("OAuth2 authentication flow")
+            .content("Exchange authorization code for access token")
+            .tags(vec!["authentication", "oauth2"])
+            .build()
+            .unwrap();
+        let db = Pattern::builder()
+            .title("Database connection pooling")
+            .content("Reuse connections across requests")
+            .tags(vec!["database", "performance"])
+            .build()
+            .unwrap();
+        matcher.add_pattern(auth).unwrap();
+        matcher.add_pattern(db).unwrap();
+
+        let results = matcher.find_matches("authetication", 2).unwrap();
+        assert!(results[0].pattern.title().contains("OAuth2"));
+        assert!(results[0].confidence.breakdown().keyword_overlap > 0.0);
+        // Unrelated tokens must not fuzzy-match
+        assert_eq!(results[1].confidence.breakdown().keyword_overlap, 0.0);
+    }
+
+    /**
+     * Test: Typos score zero when fuzzy matching is disabled (default)
+     */
+    #[test]
+    fn test_fuzzy_matching_disabled_by_default() {
+        let mut matcher = PatternMatcher::new();
+        let pattern = Pattern::builder()
+            .title("OAuth2 authentication flow")
+            .content("Exchange authorization code for access token")
+            .tags(vec!["authentication"])
+            .build()
+            .unwrap();
+        matcher.add_pattern(pattern).unwrap();
+
+        let results = matcher.find_matches("authetication", 1).unwrap();
+        assert_eq!(results[0].confidence.breakdown().keyword_overlap, 0.0);
+    }
+
+    fn fuzzy_matcher(fuzzy_max_edits: usize) -> PatternMatcher {
+        let mut matcher = PatternMatcher::with_config(MatchingConfig {
+            fuzzy_matching: true,
+            fuzzy_max_edits,
+            ..MatchingConfig::default()
+        });
+        matcher
+            .add_pattern(
+                Pattern::builder()
+                    .title("Token refresh")
+                    .content("Refresh expired access tokens before each request")
+                    .tags(vec!["authentication", "oauth2"])
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        matcher
+    }
+
+    fn keyword_overlap(matcher: &PatternMatcher, query: &str) -> f64 {
+        matcher.find_matches(query, 1).unwrap()[0].confidence.breakdown().keyword_overlap
+    }
+
+    /**
+     * Test: Misspellings, split tokens, transpositions and case all fuzzy-match, below exact
+     */
+    #[test]
+    fn test_fuzzy_matching_spelling_variants() {
+        let matcher = fuzzy_matcher(fuzzy::DEFAULT_MAX_EDITS);
+        let exact = keyword_overlap(&matcher, "authentication");
+
+        for query in ["authentification", "OAuth 2", "AUTHETNICATION", "reqeust"] {
+            let fuzzy = keyword_overlap(&matcher, query);
+            assert!(fuzzy > 0.0, "{} should fuzzy-match", query);
+            assert!(fuzzy < exact, "{} must score below the exact match", query);
+        }
+        assert_eq!(keyword_overlap(&matcher, "Authentication"), exact);
+    }
+
+    /**
+     * Test: fuzzy_max_edits caps the budget (0 = exact-only behavior)
+     */
+    #[test]
+    fn test_fuzzy_max_edits() {
+        // "authentif" → "authent" needs two edits
+        assert_eq!(keyword_overlap(&fuzzy_matcher(1), "authentification"), 0.0);
+        assert!(keyword_overlap(&fuzzy_matcher(1), "reqeust") > 0.0);
+
+        let exact_only = fuzzy_matcher(0);
+        assert_eq!(keyword_overlap(&exact_only, "reqeust"), 0.0);
+        assert!(keyword_overlap(&exact_only, "authentication") > 0.0);
+    }
+
+    fn login_fixture(synonyms: HashMap<String, HashMap<String, Vec<String>>>) -> PatternMatcher {
+        let mut matcher = PatternMatcher::with_config(MatchingConfig {
+            synonyms,
+            ..MatchingConfig::default()
+        });
+
+        let auth = Pattern::builder()
+            .title("OAuth2 authentication")
+            .content("Issue tokens after verifying credentials")
+            .tags(vec!["authentication"])
+            .domain("security")
+            .build()
+            .unwrap();
+        let form = Pattern::builder()
+            .title("Form layout")
+            .content("Center the page form with flexbox")
+            .tags(vec!["css"])
+            .domain("frontend")
+            .build()
+            .unwrap();
+        matcher.add_pattern(auth).unwrap();
+        matcher.add_pattern(form).unwrap();
+        matcher
+    }
+
+    /**
+     * Test: Domain synonym expansion lifts a synonym-matching pattern's rank
+     */
+    #[test]
+    fn test_synonym_expansion_lifts_rank() {
+        let mut security = HashMap::new();
+        security.insert("login".to_string(), vec!["authentication".to_string(), "signin".to_string()]);
+        let mut synonyms = HashMap::new();
+        synonyms.insert("security".to_string(), security);
+
+        let plain = login_fixture(HashMap::new());
+        let expanded = login_fixture(synonyms);
+
+        let before = plain.find_matches("login page", 2).unwrap();
+        let after = expanded.find_matches("login page", 2).unwrap();
+
+        assert!(before[0].pattern.title().contains("Form"));
+        assert!(after[0].pattern.title().contains("OAuth2"));
+        assert!(after[0].confidence.breakdown().keyword_overlap > 0.0);
+    }
+
+    /**
+     * Test: Synonyms load from the config file and apply after set_config
+     */
+    #[test]
+    fn test_synonyms_loaded_from_config() {
+        let config: crate::config::AetherlightConfig = toml::from_str(
+            r#"
+            [pattern_library.matching.synonyms.security]
+            login = ["authentication", "signin"]
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        let mut matcher = login_fixture(HashMap::new());
+        matcher.set_config(config.pattern_library.matching.clone());
+
+        let results = matcher.find_matches("login page", 2).unwrap();
+        assert!(results[0].pattern.title().contains("OAuth2"));
+        assert!(matcher.synonym_maps["security"].contains_key("login"));
+    }
+
+    /**
+     * Test: Empty synonym map is a no-op
+     */
+    #[test]
+    fn test_empty_synonym_map_is_noop() {
+        let mut empty_domain = HashMap::new();
+        empty_domain.insert("security".to_string(), HashMap::new());
+
+        let plain = login_fixture(HashMap::new());
+        let empty = login_fixture(empty_domain);
+
+        let a = plain.find_matches("login page", 2).unwrap();
+        let b = empty.find_matches("login page", 2).unwrap();
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert_eq!(x.pattern.title(), y.pattern.title());
+            assert_eq!(x.confidence, y.confidence);
+        }
+    }
+
     /**
      * Test: Context match calculation
      */
@@ -0,0 +1,139 @@
+/**
+ * Typo-Tolerant Token Comparison
+ *
+ * DESIGN DECISION: Edit budget scaled by query token length + Jaro-Winkler floor
+ * WHY: Voice transcription and fast typing produce near-miss tokens ("authetication")
+ * that score zero under exact matching, tanking keyword overlap
+ *
+ * REASONING CHAIN:
+ * 1. Short tokens (<5 chars): no edits allowed ("rust" ≠ "rest", "cat" ≠ "car")
+ * 2. Medium tokens (5-8 chars): 1 edit allowed
+ * 3. Long tokens (>8 chars): 2 edits allowed
+ * 4. Budget follows the query token (first argument): stemming shortens pattern words
+ *    unevenly ("authentification" → "authentif", "authentication" → "authent")
+ * 5. Optimal string alignment distance: a swapped pair ("reqeust") is one edit, not two
+ * 6. Budget capped by the caller's max edits (MatchingConfig::fuzzy_max_edits)
+ * 7. Jaro-Winkler similarity must also clear the configured floor
+ * 8. Similarity (not 1.0) returned so fuzzy hits count less than exact hits
+ *
+ * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+ * RELATED: matching.rs (MatchingConfig::fuzzy_matching)
+ * PERFORMANCE: O(a × b) per comparison; opt-in because it runs per pattern token
+ */
+
+use strsim::{jaro_winkler, osa_distance};
+
+/// Largest edit budget edit_budget hands out (the default cap)
+pub const DEFAULT_MAX_EDITS: usize = 2;
+
+/**
+ * Maximum edit distance tolerated for a token of the given length
+ *
+ * # Examples
+ *
+ * ```rust
+ * assert_eq!(edit_budget(4), 0);
+ * assert_eq!(edit_budget(6), 1);
+ * assert_eq!(edit_budget(14), 2);
+ * ```
+ */
+pub fn edit_budget(token_len: usize) -> usize {
+    match token_len {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => DEFAULT_MAX_EDITS,
+    }
+}
+
+/**
+ * Fuzzy similarity between a query token and a candidate, if within budget
+ *
+ * Returns `Some(1.0)` for exact matches, `Some(similarity)` for near-misses within
+ * the edit budget of `query` and above `min_similarity`, otherwise `None`.
+ */
+pub fn fuzzy_similarity(query: &str, candidate: &str, min_similarity: f64) -> Option<f64> {
+    fuzzy_similarity_within(query, candidate, min_similarity, DEFAULT_MAX_EDITS)
+}
+
+/// fuzzy_similarity with the edit budget capped at `max_edits` (0 = exact only)
+pub fn fuzzy_similarity_within(
+    query: &str,
+    candidate: &str,
+    min_similarity: f64,
+    max_edits: usize,
+) -> Option<f64> {
+    if query == candidate {
+        return Some(1.0);
+    }
+
+    let query_len = query.chars().count();
+    let candidate_len = candidate.chars().count();
+    let budget = edit_budget(query_len).min(max_edits);
+
+    // Cheap length check before computing edit distance
+    if budget == 0 || query_len.abs_diff(candidate_len) > budget {
+        return None;
+    }
+
+    if osa_distance(query, candidate) > budget {
+        return None;
+    }
+
+    let similarity = jaro_winkler(query, candidate);
+    (similarity >= min_similarity).then_some(similarity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_budget_scales_with_length() {
+        assert_eq!(edit_budget(3), 0);
+        assert_eq!(edit_budget(5), 1);
+        assert_eq!(edit_budget(8), 1);
+        assert_eq!(edit_budget(9), 2);
+    }
+
+    #[test]
+    fn test_one_character_typo_matches() {
+        let similarity = fuzzy_similarity("authetication", "authentication", 0.85);
+        assert!(similarity.is_some());
+        assert!(similarity.unwrap() < 1.0);
+    }
+
+    #[test]
+    fn test_short_tokens_require_exact_match() {
+        assert_eq!(fuzzy_similarity("rust", "rest", 0.0), None);
+        assert_eq!(fuzzy_similarity("rust", "rust", 0.85), Some(1.0));
+    }
+
+    #[test]
+    fn test_unrelated_tokens_do_not_match() {
+        assert_eq!(fuzzy_similarity("database", "template", 0.85), None);
+        assert_eq!(fuzzy_similarity("authentication", "documentation", 0.85), None);
+    }
+
+    #[test]
+    fn test_transposition_is_one_edit() {
+        // 7 chars → budget 1; plain Levenshtein would count the swap as 2
+        assert!(fuzzy_similarity("reqeust", "request", 0.85).is_some());
+        assert!(fuzzy_similarity("recieve", "receive", 0.85).is_some());
+    }
+
+    #[test]
+    fn test_single_insertion_and_budget_follows_query() {
+        assert!(fuzzy_similarity("connnection", "connection", 0.85).is_some());
+        // Stems: long query token gets 2 edits against a shorter pattern token
+        assert!(fuzzy_similarity("authentif", "authent", 0.85).is_some());
+        assert_eq!(fuzzy_similarity("authent", "authentif", 0.85), None);
+    }
+
+    #[test]
+    fn test_max_edits_caps_budget() {
+        assert!(fuzzy_similarity_within("authentif", "authent", 0.85, 2).is_some());
+        assert_eq!(fuzzy_similarity_within("authentif", "authent", 0.85, 1), None);
+        assert_eq!(fuzzy_similarity_within("reqeust", "request", 0.85, 0), None);
+        assert_eq!(fuzzy_similarity_within("request", "request", 0.85, 0), Some(1.0));
+    }
+}
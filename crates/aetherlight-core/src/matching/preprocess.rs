@@ -0,0 +1,118 @@
+/**
+ * Query Preprocessing for Keyword Matching
+ *
+ * DESIGN DECISION: Normalize query and pattern text identically before keyword overlap
+ * WHY: Raw substring matching misses morphological variants ("handling" vs "handle")
+ * and lets filler words ("how", "do", "the") skew keyword scores
+ *
+ * REASONING CHAIN:
+ * 1. Lowercase all text (case-insensitive matching)
+ * 2. Split on non-alphanumeric characters ("error-handling" → ["error", "handling"])
+ * 3. Drop English stopwords (carry no intent signal)
+ * 4. Stem remaining tokens with Snowball English stemmer ("errors" → "error")
+ * 5. Same pipeline for query AND pattern fields → tokens compare exactly
+ * 6. Raw text untouched for semantic/context dimensions
+ *
+ * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+ * RELATED: matching.rs (calculate_keyword_overlap)
+ * PERFORMANCE: O(n) in text length, stemmer allocated once per query
+ */
+
+use rust_stemmers::{Algorithm, Stemmer};
+use std::collections::HashSet;
+use std::fmt;
+
+/// English stopwords removed before keyword scoring
+const STOPWORDS: &[&str] = &[
+    "a", "about", "an", "and", "are", "as", "at", "be", "by", "can", "could", "do",
+    "does", "for", "from", "had", "has", "have", "how", "i", "if", "in", "into", "is",
+    "it", "its", "me", "my", "of", "on", "or", "should", "so", "that", "the", "their",
+    "then", "there", "these", "this", "to", "was", "we", "what", "when", "where",
+    "which", "who", "why", "will", "with", "would", "you", "your",
+];
+
+/**
+ * Tokenizer applying lowercase, stopword removal, and stemming
+ *
+ * DESIGN DECISION: Build once per query, reuse across all patterns
+ * WHY: find_matches tokenizes every pattern; stemmer/stopword setup shouldn't repeat
+ */
+pub struct QueryPreprocessor {
+    stemmer: Stemmer,
+    stopwords: HashSet<&'static str>,
+}
+
+impl QueryPreprocessor {
+    /// Create preprocessor with English stemmer and stopword list
+    pub fn new() -> Self {
+        Self {
+            stemmer: Stemmer::create(Algorithm::English),
+            stopwords: STOPWORDS.iter().copied().collect(),
+        }
+    }
+
+    /**
+     * Normalize text into comparable keyword tokens
+     *
+     * # Examples
+     *
+     * ```rust
+     * let pre = QueryPreprocessor::new();
+     * assert_eq!(pre.tokens("How do I handle errors?"), vec!["handl", "error"]);
+     * ```
+     */
+    pub fn tokens(&self, text: &str) -> Vec<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty() && !self.stopwords.contains(word))
+            .map(|word| self.stemmer.stem(word).into_owned())
+            .collect()
+    }
+
+    /// Check whether a lowercase word is a stopword
+    pub fn is_stopword(&self, word: &str) -> bool {
+        self.stopwords.contains(word)
+    }
+}
+
+impl Default for QueryPreprocessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for QueryPreprocessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueryPreprocessor")
+            .field("stopwords", &self.stopwords.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopwords_removed() {
+        let pre = QueryPreprocessor::new();
+        let tokens = pre.tokens("How do I handle the errors in Rust?");
+        assert_eq!(tokens, vec!["handl", "error", "rust"]);
+    }
+
+    #[test]
+    fn test_morphological_variants_share_stem() {
+        let pre = QueryPreprocessor::new();
+        let mut a = pre.tokens("handling errors");
+        let mut b = pre.tokens("error handle");
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_hyphenated_tags_split() {
+        let pre = QueryPreprocessor::new();
+        assert_eq!(pre.tokens("error-handling"), vec!["error", "handl"]);
+    }
+}
@@ -0,0 +1,411 @@
+/**
+ * Matcher Snapshots - Persist the tokenized pattern library between launches
+ *
+ * DESIGN DECISION: bincode snapshot of patterns + precomputed tokens, keyed by content digest
+ * WHY: Building a PatternMatcher stems every pattern's tags, title and content; doing that
+ * for the whole library on every desktop launch was the bulk of matcher startup time
+ *
+ * REASONING CHAIN:
+ * 1. snapshot() writes the patterns, their PatternTokens and a LibraryFingerprint
+ * 2. The fingerprint hashes pattern *content* (title, content, tags, metadata, version),
+ *    not ids or timestamps: the desktop rebuilds Patterns from SQLite rows with fresh ids
+ * 3. load() fingerprints the current library; equal to the snapshot's → every token set
+ *    is reused and nothing is stemmed
+ * 4. Diverged (count or hash) → the snapshot is stale and the matcher is rebuilt, but
+ *    token sets of unchanged patterns are still reused: only the delta is tokenized
+ * 5. Unreadable snapshot or other MATCHER_SNAPSHOT_VERSION → full rebuild, never an error
+ * 6. The library's Pattern values (and ids) are always the ones kept, so callers can map
+ *    match results back to their own store ids
+ * 7. save_to_file / load_from_file persist a matcher as-is (ids, timestamps included)
+ *    for consumers without a store of their own, e.g. the node binding
+ * 8. The version is checked before the rest is decoded, so a file from another
+ *    layout is reported as a version mismatch, not as garbage
+ *
+ * TRADEOFF: Fingerprinting still hashes all pattern text on load, which is far cheaper
+ * than stemming it and keeps a stale snapshot from ever serving outdated matches
+ *
+ * PATTERN: Pattern-005 (Multi-Dimensional Matching)
+ * RELATED: PatternMatcher::add_pattern (PatternTokens), desktop voice_capture::match_pattern
+ */
+
+use super::{MatchingConfig, PatternMatcher, PatternTokens, QueryPreprocessor};
+use crate::{Error, Pattern, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Snapshot layout version; bump whenever PatternTokens, MatchingConfig or pattern tokenization changes
+pub const MATCHER_SNAPSHOT_VERSION: u32 = 3;
+
+/// Pattern count + order-independent hash of pattern content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LibraryFingerprint {
+    pub count: usize,
+    pub hash: String,
+}
+
+impl LibraryFingerprint {
+    /// Fingerprint a pattern library (ids and timestamps are not part of it)
+    pub fn of<'a>(patterns: impl IntoIterator<Item = &'a Pattern>) -> Self {
+        let mut digests: Vec<[u8; 32]> = patterns.into_iter().map(content_digest).collect();
+        digests.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        for digest in &digests {
+            hasher.update(digest);
+        }
+        Self { count: digests.len(), hash: format!("{:x}", hasher.finalize()) }
+    }
+}
+
+/// Matcher built by PatternMatcher::load
+#[derive(Debug, Clone)]
+pub struct MatcherLoad {
+    pub matcher: PatternMatcher,
+
+    /// Snapshot matched the library exactly (no need to write a new one)
+    pub restored: bool,
+
+    /// Patterns tokenized during the load (0 when restored)
+    pub tokenized: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MatcherSnapshot {
+    version: u32,
+    fingerprint: LibraryFingerprint,
+    config: MatchingConfig,
+    entries: Vec<(Pattern, PatternTokens)>,
+}
+
+impl PatternMatcher {
+    /// Fingerprint of the patterns currently in the matcher
+    pub fn fingerprint(&self) -> LibraryFingerprint {
+        LibraryFingerprint::of(self.patterns.values())
+    }
+
+    /// Serialize patterns, precomputed tokens and config to a bincode snapshot
+    pub fn snapshot(&self) -> Result<Vec<u8>> {
+        let snapshot = MatcherSnapshot {
+            version: MATCHER_SNAPSHOT_VERSION,
+            fingerprint: self.fingerprint(),
+            config: self.config.clone(),
+            entries: self
+                .patterns
+                .iter()
+                .map(|(id, pattern)| (pattern.clone(), self.tokens.get(id).cloned().unwrap_or_default()))
+                .collect(),
+        };
+        bincode::serialize(&snapshot)
+            .map_err(|e| Error::Serialization(format!("Failed to serialize matcher snapshot: {}", e)))
+    }
+
+    /**
+     * Restore a matcher exactly as it was snapshotted (patterns, ids, config)
+     *
+     * ValidationError when the snapshot has another layout version or its
+     * patterns no longer hash to the fingerprint recorded with them.
+     */
+    pub fn restore(bytes: &[u8]) -> Result<Self> {
+        let snapshot = decode(bytes)?;
+        let mut matcher = Self::with_config(snapshot.config);
+        for (pattern, tokens) in snapshot.entries {
+            matcher.insert_tokenized(pattern, tokens)?;
+        }
+
+        if matcher.fingerprint() != snapshot.fingerprint {
+            return Err(Error::ValidationError("Matcher snapshot does not match its fingerprint".to_string()));
+        }
+        Ok(matcher)
+    }
+
+    /**
+     * Write the matcher to `path` (snapshot bytes, temp file + rename)
+     *
+     * DESIGN DECISION: Rename over the old file instead of writing in place
+     * WHY: A crash mid-write must leave the previous file loadable, not a torn one
+     */
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+
+        std::fs::write(&tmp, self.snapshot()?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /**
+     * Load a matcher written by save_to_file (same as restore on the file's bytes)
+     *
+     * ValidationError when the file was written with another MATCHER_SNAPSHOT_VERSION.
+     */
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::restore(&std::fs::read(path)?)
+    }
+
+    /**
+     * Build a matcher for `library`, reusing token sets from `snapshot` where possible
+     *
+     * DESIGN DECISION: Library is the source of truth, the snapshot only a token cache
+     * WHY: A stale snapshot must never change match results; see module docs
+     */
+    pub fn load(snapshot: Option<&[u8]>, library: Vec<Pattern>, config: MatchingConfig) -> Result<MatcherLoad> {
+        let snapshot = snapshot.and_then(|bytes| match decode(bytes) {
+            Ok(snapshot) => Some(snapshot),
+            Err(e) => {
+                tracing::warn!("Ignoring matcher snapshot, rebuilding: {}", e);
+                None
+            }
+        });
+
+        let restored = snapshot
+            .as_ref()
+            .is_some_and(|snapshot| snapshot.fingerprint == LibraryFingerprint::of(&library));
+
+        let cached: HashMap<[u8; 32], PatternTokens> = snapshot
+            .map(|snapshot| {
+                snapshot
+                    .entries
+                    .into_iter()
+                    .map(|(pattern, tokens)| (content_digest(&pattern), tokens))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let preprocessor = QueryPreprocessor::new();
+        let mut matcher = Self::with_config(config);
+        let mut tokenized = 0;
+        for pattern in library {
+            let tokens = match cached.get(&content_digest(&pattern)) {
+                Some(tokens) => tokens.clone(),
+                None => {
+                    tokenized += 1;
+                    PatternTokens::new(&pattern, &preprocessor)
+                }
+            };
+            matcher.insert_tokenized(pattern, tokens)?;
+        }
+
+        Ok(MatcherLoad { matcher, restored, tokenized })
+    }
+
+    fn insert_tokenized(&mut self, pattern: Pattern, tokens: PatternTokens) -> Result<()> {
+        let id = *pattern.id();
+        if self.patterns.contains_key(&id) {
+            return Err(Error::DuplicatePattern(id.to_string()));
+        }
+        self.tokens.insert(id, tokens);
+        self.patterns.insert(id, pattern);
+        Ok(())
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<MatcherSnapshot> {
+    // `version` is the first field: read it alone, the rest may have another layout
+    let version: u32 = bincode::deserialize(bytes)
+        .map_err(|e| Error::Serialization(format!("Invalid matcher snapshot: {}", e)))?;
+    if version != MATCHER_SNAPSHOT_VERSION {
+        return Err(Error::ValidationError(format!(
+            "Matcher snapshot version {} is not supported (expected {})",
+            version, MATCHER_SNAPSHOT_VERSION
+        )));
+    }
+
+    bincode::deserialize(bytes).map_err(|e| Error::Serialization(format!("Invalid matcher snapshot: {}", e)))
+}
+
+/// SHA-256 of everything tokenization and scoring read from a pattern
+fn content_digest(pattern: &Pattern) -> [u8; 32] {
+    let metadata = pattern.metadata();
+    let mut hasher = Sha256::new();
+
+    hash_field(&mut hasher, Some(pattern.title()));
+    hash_field(&mut hasher, Some(pattern.content()));
+    hasher.update((pattern.tags().len() as u64).to_le_bytes());
+    for tag in pattern.tags() {
+        hash_field(&mut hasher, Some(tag));
+    }
+    hash_field(&mut hasher, metadata.language.as_deref());
+    hash_field(&mut hasher, metadata.framework.as_deref());
+    hash_field(&mut hasher, metadata.domain.as_deref());
+    hasher.update(metadata.estimated_time_saved_minutes.map_or(u64::MAX, u64::from).to_le_bytes());
+    hasher.update(pattern.version().to_le_bytes());
+
+    hasher.finalize().into()
+}
+
+/// Length-prefixed so ("ab", "c") and ("a", "bc") hash differently; None ≠ Some("")
+fn hash_field(hasher: &mut Sha256, value: Option<&str>) {
+    match value {
+        Some(value) => {
+            hasher.update((value.len() as u64).to_le_bytes());
+            hasher.update(value.as_bytes());
+        }
+        None => hasher.update(u64::MAX.to_le_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn library() -> Vec<Pattern> {
+        vec![
+            Pattern::builder()
+                .title("Retry with exponential backoff")
+                .content("Retry failed network requests with exponential backoff and jitter")
+                .tags(vec!["retry", "network"])
+                .domain("networking")
+                .build()
+                .unwrap(),
+            Pattern::builder()
+                .title("OAuth2 PKCE login")
+                .content("Authenticate users with the authorization code flow and PKCE")
+                .tags(vec!["oauth2", "authentication"])
+                .domain("security")
+                .build()
+                .unwrap(),
+            Pattern::builder()
+                .title("Rust error handling with thiserror")
+                .content("Define error enums with thiserror and propagate with the ? operator")
+                .tags(vec!["rust", "errors"])
+                .language("rust")
+                .build()
+                .unwrap(),
+        ]
+    }
+
+    fn build(patterns: Vec<Pattern>) -> PatternMatcher {
+        let mut matcher = PatternMatcher::new();
+        for pattern in patterns {
+            matcher.add_pattern(pattern).unwrap();
+        }
+        matcher
+    }
+
+    /// Titles and scores, ties ordered by title (find_matches leaves tie order unspecified)
+    fn ranked(matcher: &PatternMatcher, query: &str) -> Vec<(String, f64)> {
+        let mut ranked: Vec<(String, f64)> = matcher
+            .find_matches(query, 10)
+            .unwrap()
+            .into_iter()
+            .map(|m| (m.pattern.title().to_string(), m.confidence.total_score()))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+
+    const QUERIES: [&str; 3] = ["retry the request with backoff", "login with oauth", "rust error handling"];
+
+    /**
+     * Test: snapshot → restore reproduces the matcher and its match results
+     */
+    #[test]
+    fn test_snapshot_restore_matches_identically() {
+        let matcher = build(library());
+        let restored = PatternMatcher::restore(&matcher.snapshot().unwrap()).unwrap();
+
+        assert_eq!(restored.count(), matcher.count());
+        assert_eq!(restored.fingerprint(), matcher.fingerprint());
+        for query in QUERIES {
+            assert_eq!(ranked(&restored, query), ranked(&matcher, query), "{}", query);
+        }
+    }
+
+    /**
+     * Test: Unchanged library (re-read from the store with fresh ids) loads without tokenizing
+     */
+    #[test]
+    fn test_load_unchanged_library_restores_snapshot() {
+        let patterns = library();
+        let snapshot = build(patterns.clone()).snapshot().unwrap();
+        let reread: Vec<Pattern> = patterns
+            .iter()
+            .map(|p| {
+                let mut builder = Pattern::builder().title(p.title()).content(p.content()).tags(p.tags().to_vec());
+                if let Some(domain) = &p.metadata().domain {
+                    builder = builder.domain(domain.clone());
+                }
+                if let Some(language) = &p.metadata().language {
+                    builder = builder.language(language.clone());
+                }
+                builder.build().unwrap()
+            })
+            .collect();
+        let ids: Vec<_> = reread.iter().map(|p| *p.id()).collect();
+
+        let load = PatternMatcher::load(Some(&snapshot), reread, MatchingConfig::default()).unwrap();
+
+        assert!(load.restored);
+        assert_eq!(load.tokenized, 0);
+        assert!(ids.iter().all(|id| load.matcher.get_pattern(id).is_ok()), "library ids kept");
+        for query in QUERIES {
+            assert_eq!(ranked(&load.matcher, query), ranked(&build(patterns.clone()), query));
+        }
+    }
+
+    /**
+     * Test: Divergent library invalidates the snapshot; only changed patterns are tokenized
+     */
+    #[test]
+    fn test_divergent_library_triggers_rebuild() {
+        let patterns = library();
+        let snapshot = build(patterns.clone()).snapshot().unwrap();
+
+        let mut changed = patterns.clone();
+        changed[0] = changed[0].clone().revise("Retry with circuit breaker", "Stop retrying once the circuit opens").unwrap();
+        changed.push(Pattern::builder().title("Debounce input").content("Debounce keystrokes before searching").build().unwrap());
+
+        let load = PatternMatcher::load(Some(&snapshot), changed.clone(), MatchingConfig::default()).unwrap();
+
+        assert!(!load.restored);
+        assert_eq!(load.tokenized, 2);
+        assert_eq!(load.matcher.fingerprint(), LibraryFingerprint::of(&changed));
+        for query in QUERIES.iter().chain(&["circuit breaker", "debounce"]) {
+            assert_eq!(ranked(&load.matcher, query), ranked(&build(changed.clone()), query), "{}", query);
+        }
+    }
+
+    /**
+     * Test: Unreadable snapshot is an error for restore, a full rebuild for load
+     */
+    #[test]
+    fn test_corrupt_snapshot_rebuilds() {
+        assert!(PatternMatcher::restore(b"not a snapshot").is_err());
+
+        let load = PatternMatcher::load(Some(b"not a snapshot"), library(), MatchingConfig::default()).unwrap();
+        assert!(!load.restored);
+        assert_eq!(load.tokenized, 3);
+    }
+
+    /**
+     * Test: save_to_file → load_from_file keeps ids, timestamps and matches; other versions are rejected
+     */
+    #[test]
+    fn test_file_round_trip_and_version_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("matcher.bin");
+        let matcher = build(library());
+
+        matcher.save_to_file(&path).unwrap();
+        let loaded = PatternMatcher::load_from_file(&path).unwrap();
+
+        for pattern in matcher.patterns.values() {
+            assert_eq!(loaded.get_pattern(pattern.id()).unwrap(), pattern);
+        }
+        for query in QUERIES {
+            assert_eq!(ranked(&loaded, query), ranked(&matcher, query), "{}", query);
+        }
+
+        // A future layout: only the leading version is readable
+        let mut future = (MATCHER_SNAPSHOT_VERSION + 1).to_le_bytes().to_vec();
+        future.extend_from_slice(b"some other layout");
+        std::fs::write(&path, future).unwrap();
+        match PatternMatcher::load_from_file(&path) {
+            Err(Error::ValidationError(message)) => assert!(message.contains("version")),
+            other => panic!("Expected version mismatch, got {:?}", other.map(|m| m.count())),
+        }
+    }
+}
@@ -10,6 +10,11 @@
  * 3. Iterative node lookup (α=3 parallelism)
  * 4. K=20 replication for pattern redundancy
  * 5. Hierarchical indexing (User Nodes → Regional Supernodes → Global Index)
+ * 6. Lookups are bounded (DHTConfig): one deadline per lookup enforced on every RPC,
+ *    at most α RPCs in flight, at most max_hops rounds, so a slow peer can't stall them
+ * 7. Stored patterns are re-stored every republish_interval (republish_task), by their
+ *    publisher and by the holders closest to them, so they outlive churn of the replica
+ *    holders they were first stored on and of the publisher itself
  *
  * PATTERN: Pattern-DHT-001 (Content-Addressed Distributed Hash Table)
  * RELATED: routing_table.rs, rpc.rs, Pattern-STORAGE-001
@@ -33,11 +38,51 @@
  */
 
 use crate::{Pattern, Result, Error};
-use super::routing_table::RoutingTable;
-use super::rpc::RPCClient;
-use std::collections::HashMap;
+use super::routing_table::{AddNodeResult, RoutingTable, DEFAULT_STALE_AFTER};
+use super::rpc::{FindValueResult, RPCClient};
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::{SystemTime, Duration};
+use tokio::task::JoinSet;
+use tokio::time::Instant;
+
+/**
+ * Lookup limits for HierarchicalDHTClient
+ *
+ * DESIGN DECISION: One deadline per lookup, applied to each RPC via tokio::time::timeout
+ * WHY: RPCClient waits up to 5s per request; a lookup touching several slow peers
+ * could take minutes. The deadline bounds the whole lookup, not each hop
+ *
+ * - lookup_timeout: budget for a whole lookup (every RPC gets what's left);
+ *   a publish gets it once for the FIND_NODE lookup and once for the STOREs
+ * - alpha_concurrency: RPCs in flight at once (Kademlia α)
+ * - max_hops: rounds of α queries before the lookup gives up
+ * - republish_interval: how often republish_task re-stores published and held patterns
+ *   (Kademlia's tREPUBLISH, 1 hour)
+ * - node_stale_after: routing-table nodes unseen this long are pinged when a newcomer
+ *   wants their full bucket (RoutingTable::with_stale_after)
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DHTConfig {
+    pub lookup_timeout: Duration,
+    pub alpha_concurrency: usize,
+    pub max_hops: usize,
+    pub republish_interval: Duration,
+    pub node_stale_after: Duration,
+}
+
+impl Default for DHTConfig {
+    fn default() -> Self {
+        Self {
+            lookup_timeout: Duration::from_secs(2),
+            alpha_concurrency: 3,
+            max_hops: 10,
+            republish_interval: Duration::from_secs(60 * 60),
+            node_stale_after: DEFAULT_STALE_AFTER,
+        }
+    }
+}
 
 /**
  * Hierarchical DHT Client
@@ -63,11 +108,13 @@ use std::time::{SystemTime, Duration};
  */
 #[derive(Debug)]
 pub struct HierarchicalDHTClient {
-    _node_id: [u8; 20], // TODO: Use for node identification in distributed DHT operations
+    node_id: [u8; 20], // 160-bit Kademlia ID (padded to 32 bytes in RPCs)
     routing_table: std::sync::Arc<std::sync::Mutex<RoutingTable>>,
-    rpc_client: RPCClient,
+    rpc_client: Arc<RPCClient>, // Shared with in-flight lookup tasks
     replication_factor: usize,
     local_storage: std::sync::Arc<std::sync::Mutex<HashMap<String, Pattern>>>,
+    published: std::sync::Mutex<HashSet<String>>, // Pattern IDs this node originated (always republished)
+    config: DHTConfig,
 }
 
 impl HierarchicalDHTClient {
@@ -85,8 +132,14 @@ impl HierarchicalDHTClient {
      * 5. Set K=20 replication factor
      */
     pub fn new(local_addr: SocketAddr) -> Self {
+        Self::with_config(local_addr, DHTConfig::default())
+    }
+
+    /// Create a DHT client with explicit lookup limits (alpha_concurrency is at least 1)
+    pub fn with_config(local_addr: SocketAddr, config: DHTConfig) -> Self {
+        let config = DHTConfig { alpha_concurrency: config.alpha_concurrency.max(1), ..config };
         let node_id = Self::generate_node_id();
-        let routing_table = RoutingTable::new(node_id);
+        let routing_table = RoutingTable::new(node_id).with_stale_after(config.node_stale_after);
 
         // DESIGN DECISION: Pad 160-bit ID to 256-bit for RPCClient compatibility
         // WHY: RPCClient uses [u8; 32] (256-bit) while DHT uses [u8; 20] (160-bit)
@@ -107,14 +160,34 @@ impl HierarchicalDHTClient {
         );
 
         Self {
-            _node_id: node_id,
+            node_id,
             routing_table: routing_table_shared,
-            rpc_client,
+            rpc_client: Arc::new(rpc_client),
             replication_factor: 20,
             local_storage: pattern_storage_shared,
+            published: std::sync::Mutex::new(HashSet::new()),
+            config,
         }
     }
 
+    /// Lookup limits in effect
+    pub fn config(&self) -> DHTConfig {
+        self.config
+    }
+
+    /// Add a known peer to the routing table (bootstrap nodes, discovered peers);
+    /// a full bucket pings its stale LRU node first (RPCClient::add_node)
+    pub async fn add_node(&self, node: KademliaNode) -> AddNodeResult {
+        self.rpc_client.add_node(node).await
+    }
+
+    /// Node ID as carried in RPCs ([u8; 32], zero-padded)
+    fn padded_node_id(&self) -> [u8; 32] {
+        let mut padded_id = [0u8; 32];
+        padded_id[..20].copy_from_slice(&self.node_id);
+        padded_id
+    }
+
     fn generate_node_id() -> [u8; 20] {
         use sha2::{Sha256, Digest};
 
@@ -137,53 +210,148 @@ impl HierarchicalDHTClient {
      * REASONING CHAIN:
      * 1. Hash pattern → 160-bit pattern_id
      * 2. Store in local cache for fast queries
-     * 3. Find K=20 closest nodes via routing table
-     * 4. Send STORE RPC to each node (parallel)
-     * 5. Count successful replicas
+     * 3. Find K=20 closest nodes (iterative FIND_NODE lookup)
+     * 4. Send STORE RPC to each node, α at a time, within a fresh lookup_timeout
+     * 5. Count successful replicas (timed-out or failed peers don't count)
      * 6. Return PublishResult with replica count
+     * 7. Remember pattern_id so republish_now()/republish_task keep it alive
      *
      * PERFORMANCE: <200ms to replicate to K=20 nodes (parallel)
      * PATTERN: Pattern-DHT-001 (Content-Addressed Storage with Replication)
      */
-    pub async fn publish_pattern(&mut self, pattern: &Pattern) -> Result<PublishResult> {
+    pub async fn publish_pattern(&self, pattern: &Pattern) -> Result<PublishResult> {
         let pattern_hash = self.hash_pattern(pattern);
         let pattern_id = hex::encode(&pattern_hash);
 
         // Store locally for fast queries
         self.local_storage.lock().unwrap().insert(pattern_id.clone(), pattern.clone());
+        self.published.lock().unwrap().insert(pattern_id.clone());
+
+        let remote_replicas = self.replicate(&pattern_hash, &pattern_id, pattern).await?;
+        let replica_count = 1 + remote_replicas; // Count self
 
-        // Find K closest nodes
-        let closest_nodes = self.routing_table.lock().unwrap().find_closest(&pattern_hash, self.replication_factor);
+        Ok(PublishResult {
+            pattern_id,
+            replicas: replica_count,
+            refreshed_replicas: 0,
+            regional_indexed: replica_count >= self.replication_factor,
+            global_indexed: false, // TODO: Global indexing in future
+        })
+    }
 
-        // If routing table is empty, we're the only node (bootstrap case)
-        if closest_nodes.is_empty() {
-            return Ok(PublishResult {
+    /**
+     * Re-store stored patterns on the current K closest nodes (Kademlia republish)
+     *
+     * DESIGN DECISION: Every replica holder refreshes, not just the publisher
+     * WHY: Replica holders churn out; without a refresh a pattern vanishes once the
+     * K=20 nodes it was first stored on are gone, and a publisher that left the network
+     * refreshes nothing. Re-running the lookup picks up the nodes that are closest now,
+     * not the ones that were closest at publish time
+     *
+     * REASONING CHAIN:
+     * 1. Patterns this node published: always republished
+     * 2. Patterns STOREd here by other publishers: republished while this node is one
+     *    of the K closest it knows to the key (the nodes responsible for it)
+     * 3. Others are left to their closer holders, so a value isn't re-stored by
+     *    every node that ever cached it
+     *
+     * Returns one PublishResult per republished pattern; refreshed_replicas counts
+     * remote STOREs acknowledged this round.
+     */
+    pub async fn republish_now(&self) -> Result<Vec<PublishResult>> {
+        let stored: Vec<(String, Pattern)> = self
+            .local_storage
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(pattern_id, pattern)| (pattern_id.clone(), pattern.clone()))
+            .collect();
+        let published = self.published.lock().unwrap().clone();
+
+        let mut results = Vec::with_capacity(stored.len());
+        for (pattern_id, pattern) in stored {
+            let Some(pattern_hash) = pattern_key(&pattern_id) else {
+                continue; // Not a DHT key (never published or STOREd under a pattern hash)
+            };
+            if !published.contains(&pattern_id) && !self.is_among_closest(&pattern_hash) {
+                continue;
+            }
+
+            let refreshed = self.replicate(&pattern_hash, &pattern_id, &pattern).await?;
+            results.push(PublishResult {
                 pattern_id,
-                replicas: 1, // Only us
-                regional_indexed: false,
+                replicas: 1 + refreshed,
+                refreshed_replicas: refreshed,
+                regional_indexed: 1 + refreshed >= self.replication_factor,
                 global_indexed: false,
             });
         }
 
-        // Send STORE RPC to each node (parallel)
-        let mut replica_count = 1; // Count self
-        for node in closest_nodes.iter() {
-            match self.rpc_client.store(node, pattern_id.clone(), pattern.clone()).await {
-                Ok(response) if response.success => {
-                    replica_count += 1;
+        Ok(results)
+    }
+
+    /**
+     * Background republish loop: republish_now() every republish_interval
+     *
+     * PATTERN: Same as RPCClient::start_server (Arc<Self>, runs until aborted)
+     * First round runs one interval after start (publish just stored the patterns).
+     * Failed rounds are logged and retried at the next tick.
+     */
+    pub fn republish_task(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let period = self.config.republish_interval;
+            let mut ticker = tokio::time::interval_at(Instant::now() + period, period);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                match self.republish_now().await {
+                    Ok(results) => {
+                        let refreshed: usize = results.iter().map(|result| result.refreshed_replicas).sum();
+                        tracing::debug!(patterns = results.len(), refreshed, "Republished DHT patterns");
+                    }
+                    Err(e) => tracing::warn!(error = %e, "DHT republish round failed"),
                 }
-                Ok(_) | Err(_) => {
-                    // Node failed to store, continue with others
+            }
+        })
+    }
+
+    /// Whether this node is one of the K closest known nodes to `key` (responsible for it)
+    fn is_among_closest(&self, key: &[u8; 20]) -> bool {
+        let closest = self.routing_table.lock().unwrap().find_closest(key, self.replication_factor);
+        let own_distance = xor_distance(key, &self.padded_node_id());
+
+        closest.len() < self.replication_factor
+            || closest.last().is_some_and(|farthest| own_distance < xor_distance(key, &farthest.id))
+    }
+
+    /// STORE pattern on the K closest nodes (α in flight), returns how many acknowledged
+    async fn replicate(&self, pattern_hash: &[u8; 20], pattern_id: &str, pattern: &Pattern) -> Result<usize> {
+        // Find K closest nodes (empty routing table: we're the only node, bootstrap case)
+        let closest_nodes = self.find_k_closest_nodes(pattern_hash).await?;
+
+        // Send STORE RPC to each node (α in flight)
+        let deadline = Instant::now() + self.config.lookup_timeout;
+        let mut stored = 0;
+        for batch in closest_nodes.chunks(self.config.alpha_concurrency) {
+            let mut in_flight = JoinSet::new();
+            for node in batch {
+                let (node, rpc) = (node.clone(), self.rpc_client.clone());
+                let (pattern_id, pattern) = (pattern_id.to_string(), pattern.clone());
+                in_flight.spawn(async move {
+                    tokio::time::timeout(remaining(deadline), rpc.store(&node, pattern_id, pattern)).await
+                });
+            }
+
+            while let Some(joined) = in_flight.join_next().await {
+                match joined {
+                    Ok(Ok(Ok(response))) if response.success => stored += 1,
+                    _ => {} // Node failed, timed out or refused: continue with others
                 }
             }
         }
 
-        Ok(PublishResult {
-            pattern_id,
-            replicas: replica_count,
-            regional_indexed: replica_count >= self.replication_factor,
-            global_indexed: false, // TODO: Global indexing in future
-        })
+        Ok(stored)
     }
 
     /**
@@ -195,13 +363,14 @@ impl HierarchicalDHTClient {
      * REASONING CHAIN:
      * 1. Check local cache → <1ms (cache hit)
      * 2. Hash pattern_id → 160-bit key
-     * 3. Find α=3 closest nodes from routing table
-     * 4. Send FIND_VALUE RPC to each (parallel)
-     * 5. If pattern found → return immediately
-     * 6. If not found → get closer nodes, repeat iterative lookup
-     * 7. Stop when no closer nodes found or max hops reached
+     * 3. Shortlist: K closest nodes from routing table
+     * 4. Send FIND_VALUE RPC to the α closest unqueried nodes (parallel)
+     * 5. If pattern found → return immediately (slower peers are abandoned)
+     * 6. If not found → merge closer nodes into the shortlist, repeat
+     * 7. Stop when no unqueried nodes remain, max_hops rounds ran, or the deadline passed
+     * 8. Nothing found and some peer timed out → DHTError::TimeoutError; else Ok(None)
      *
-     * PERFORMANCE: <200ms lookup, O(log N) hops
+     * PERFORMANCE: <200ms lookup, O(log N) hops, never longer than lookup_timeout
      * PATTERN: Pattern-DHT-001 (Kademlia Iterative Lookup)
      */
     pub async fn find_pattern(&self, pattern_id: &str) -> Result<Option<FindResult>> {
@@ -213,6 +382,8 @@ impl HierarchicalDHTClient {
                 pattern,
                 source: NodeSource::Local,
                 latency_ms: 0,
+                closest_nodes: Vec::new(),
+                timed_out_peers: 0,
             }));
         }
 
@@ -226,43 +397,65 @@ impl HierarchicalDHTClient {
             _ => return Err(Error::Internal("Invalid pattern_id format".to_string())),
         };
 
-        // Find α=3 closest nodes from routing table
-        let closest_nodes = self.routing_table.lock().unwrap().find_closest(&pattern_hash, 3); // α=3
+        let deadline = Instant::now() + self.config.lookup_timeout;
+        let mut lookup = Lookup::new(pattern_hash, self.padded_node_id(), self.routing_table.lock().unwrap().find_closest(&pattern_hash, self.replication_factor));
 
-        if closest_nodes.is_empty() {
+        if lookup.shortlist.is_empty() {
             return Ok(None); // No nodes in routing table
         }
 
-        // Send FIND_VALUE RPC to each node (parallel)
-        for node in closest_nodes.iter() {
-            match self.rpc_client.find_value(node, pattern_id.to_string()).await {
-                Ok(response) => {
-                    match response.result {
-                        super::rpc::FindValueResult::Found { pattern } => {
-                            let latency_ms = SystemTime::now()
-                                .duration_since(start_time)
-                                .unwrap_or(Duration::from_secs(0))
-                                .as_millis() as u64;
-
-                            return Ok(Some(FindResult {
-                                pattern,
-                                source: NodeSource::DirectPeer,
-                                latency_ms,
-                            }));
-                        }
-                        super::rpc::FindValueResult::NotFound { closer_nodes: _closer_nodes } => {
-                            // TODO: Iterative lookup with _closer_nodes
-                            // For now, return not found
+        for _hop in 0..self.config.max_hops {
+            let batch = lookup.next_batch(self.config.alpha_concurrency);
+            if batch.is_empty() || Instant::now() >= deadline {
+                break;
+            }
+
+            let mut in_flight = JoinSet::new();
+            for node in batch {
+                let rpc = self.rpc_client.clone();
+                let pattern_id = pattern_id.to_string();
+                in_flight.spawn(async move {
+                    let outcome = tokio::time::timeout(remaining(deadline), rpc.find_value(&node, pattern_id)).await;
+                    (node, outcome)
+                });
+            }
+
+            while let Some(joined) = in_flight.join_next().await {
+                let Ok((node, outcome)) = joined else { continue };
+                match outcome {
+                    Err(_) => lookup.timed_out += 1,
+                    Ok(Err(_)) => {} // Node failed, others may still answer
+                    Ok(Ok(response)) => {
+                        lookup.responded.push(node);
+                        match response.result {
+                            FindValueResult::Found { pattern } => {
+                                let latency_ms = SystemTime::now()
+                                    .duration_since(start_time)
+                                    .unwrap_or(Duration::from_secs(0))
+                                    .as_millis() as u64;
+
+                                // Dropping in_flight aborts the RPCs still waiting on slow peers
+                                return Ok(Some(FindResult {
+                                    pattern,
+                                    source: NodeSource::DirectPeer,
+                                    latency_ms,
+                                    closest_nodes: lookup.closest_responded(self.replication_factor),
+                                    timed_out_peers: lookup.timed_out,
+                                }));
+                            }
+                            FindValueResult::NotFound { closer_nodes } => {
+                                lookup.merge(closer_nodes.into_iter().map(KademliaNode::from), self.replication_factor);
+                            }
                         }
                     }
                 }
-                Err(_) => {
-                    // Node failed, try next
-                }
             }
         }
 
-        Ok(None) // Pattern not found after querying all nodes
+        if lookup.timed_out > 0 {
+            return Err(DHTError::TimeoutError(self.config.lookup_timeout).into());
+        }
+        Ok(None) // Pattern not found after querying all reachable nodes
     }
 
     /**
@@ -279,18 +472,55 @@ impl HierarchicalDHTClient {
      * 5. Stop when no closer nodes found or K nodes reached
      * 6. Return K=20 closest nodes
      *
+     * LIMITS: at most α FIND_NODE RPCs in flight, max_hops rounds, lookup_timeout overall.
+     * Peers that time out are dropped; whatever responded by then is the answer
+     * (falls back to the routing table when no peer answered at all)
+     *
      * PERFORMANCE: O(log N) hops, <200ms for 1M nodes
      * PATTERN: Kademlia iterative node lookup
-     * TODO: Implement full iterative lookup in Phase 3.7
      */
-    #[allow(dead_code)] // Placeholder for Phase 3.7 DHT network implementation
     async fn find_k_closest_nodes(&self, target_id: &[u8; 20]) -> Result<Vec<KademliaNode>> {
+        let deadline = Instant::now() + self.config.lookup_timeout;
+
         // Start with closest nodes from routing table
-        let closest_nodes = self.routing_table.lock().unwrap().find_closest(target_id, self.replication_factor);
+        let initial = self.routing_table.lock().unwrap().find_closest(target_id, self.replication_factor);
+        let mut lookup = Lookup::new(*target_id, self.padded_node_id(), initial.clone());
+
+        let mut padded_target = [0u8; 32];
+        padded_target[..20].copy_from_slice(target_id);
 
-        // TODO: Iterative FIND_NODE with α=3 parallelism
-        // For now, return routing table results
-        Ok(closest_nodes)
+        for _hop in 0..self.config.max_hops {
+            let batch = lookup.next_batch(self.config.alpha_concurrency);
+            if batch.is_empty() || Instant::now() >= deadline {
+                break;
+            }
+
+            let mut in_flight = JoinSet::new();
+            for node in batch {
+                let rpc = self.rpc_client.clone();
+                in_flight.spawn(async move {
+                    let outcome = tokio::time::timeout(remaining(deadline), rpc.find_node(&node, padded_target)).await;
+                    (node, outcome)
+                });
+            }
+
+            while let Some(joined) = in_flight.join_next().await {
+                let Ok((node, outcome)) = joined else { continue };
+                match outcome {
+                    Err(_) => lookup.timed_out += 1,
+                    Ok(Err(_)) => {}
+                    Ok(Ok(response)) => {
+                        lookup.responded.push(node);
+                        lookup.merge(response.nodes.into_iter().map(KademliaNode::from), self.replication_factor);
+                    }
+                }
+            }
+        }
+
+        if lookup.responded.is_empty() {
+            return Ok(initial);
+        }
+        Ok(lookup.closest_responded(self.replication_factor))
     }
 
     fn hash_pattern(&self, pattern: &Pattern) -> [u8; 20] {
@@ -310,6 +540,89 @@ impl HierarchicalDHTClient {
     }
 }
 
+/// Time left until `deadline` (zero once passed)
+fn remaining(deadline: Instant) -> Duration {
+    deadline.saturating_duration_since(Instant::now())
+}
+
+/// 160-bit key of a stored pattern id (hex, as minted by publish_pattern)
+fn pattern_key(pattern_id: &str) -> Option<[u8; 20]> {
+    hex::decode(pattern_id).ok()?.try_into().ok()
+}
+
+/// XOR distance between a 160-bit key and a node id (first 20 bytes)
+fn xor_distance(target: &[u8; 20], node_id: &[u8; 32]) -> [u8; 20] {
+    let mut distance = [0u8; 20];
+    for (i, byte) in distance.iter_mut().enumerate() {
+        *byte = target[i] ^ node_id[i];
+    }
+    distance
+}
+
+/**
+ * State of one iterative lookup
+ *
+ * Shortlist kept sorted by XOR distance and capped at K; every node is queried at
+ * most once, and peers reporting ourselves back are ignored. `responded` are the nodes that answered, `timed_out` the RPCs that didn't.
+ */
+struct Lookup {
+    target: [u8; 20],
+    local_id: [u8; 32],
+    shortlist: Vec<KademliaNode>,
+    queried: HashSet<[u8; 32]>,
+    responded: Vec<KademliaNode>,
+    timed_out: usize,
+}
+
+impl Lookup {
+    fn new(target: [u8; 20], local_id: [u8; 32], initial: Vec<KademliaNode>) -> Self {
+        let mut lookup = Self {
+            target,
+            local_id,
+            shortlist: Vec::new(),
+            queried: HashSet::new(),
+            responded: Vec::new(),
+            timed_out: 0,
+        };
+        let k = initial.len();
+        lookup.merge(initial, k);
+        lookup
+    }
+
+    /// Up to `alpha` closest nodes not queried yet (marked queried)
+    fn next_batch(&mut self, alpha: usize) -> Vec<KademliaNode> {
+        let batch: Vec<KademliaNode> = self
+            .shortlist
+            .iter()
+            .filter(|node| !self.queried.contains(&node.id))
+            .take(alpha)
+            .cloned()
+            .collect();
+        self.queried.extend(batch.iter().map(|node| node.id));
+        batch
+    }
+
+    /// Add newly learned nodes, keep the K closest
+    fn merge(&mut self, nodes: impl IntoIterator<Item = KademliaNode>, k: usize) {
+        for node in nodes {
+            if node.id != self.local_id && !self.shortlist.iter().any(|known| known.id == node.id) {
+                self.shortlist.push(node);
+            }
+        }
+        let target = self.target;
+        self.shortlist.sort_by_key(|node| xor_distance(&target, &node.id));
+        self.shortlist.truncate(k.max(1));
+    }
+
+    /// Best nodes found so far: responders, closest first
+    fn closest_responded(&self, k: usize) -> Vec<KademliaNode> {
+        let mut nodes = self.responded.clone();
+        nodes.sort_by_key(|node| xor_distance(&self.target, &node.id));
+        nodes.truncate(k);
+        nodes
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct KademliaNode {
     pub id: [u8; 32],
@@ -329,6 +642,8 @@ pub enum NodeStatus {
 pub struct PublishResult {
     pub pattern_id: String,
     pub replicas: usize,
+    /// Remote replicas re-stored by a republish round (0 for the initial publish)
+    pub refreshed_replicas: usize,
     pub regional_indexed: bool,
     pub global_indexed: bool,
 }
@@ -338,6 +653,10 @@ pub struct FindResult {
     pub pattern: Pattern,
     pub source: NodeSource,
     pub latency_ms: u64,
+    /// Nodes that answered during the lookup, closest to the key first (empty for Local)
+    pub closest_nodes: Vec<KademliaNode>,
+    /// Peers that hadn't answered by the deadline (the value was found regardless)
+    pub timed_out_peers: usize,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -411,7 +730,7 @@ mod tests {
     #[tokio::test]
     async fn test_publish_find_pattern() {
         let local_addr = "127.0.0.1:8080".parse().unwrap();
-        let mut client = HierarchicalDHTClient::new(local_addr);
+        let client = HierarchicalDHTClient::new(local_addr);
 
         let pattern = Pattern::builder()
             .title("Test DHT Pattern")
@@ -448,4 +767,147 @@ mod tests {
         let result = client.find_pattern("0000000000000000000000000000000000000000").await.unwrap();
         assert!(result.is_none());
     }
+    /**
+     * Test: Lookup against an unresponsive peer
+     *
+     * DESIGN DECISION: Peer is a bound UDP socket that never replies
+     * WHY: Validates the lookup gives up at lookup_timeout (not RPC_TIMEOUT) with TimeoutError
+     */
+    #[tokio::test]
+    async fn test_lookup_times_out_on_silent_peer() {
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let config = DHTConfig { lookup_timeout: Duration::from_millis(300), ..DHTConfig::default() };
+        let client = HierarchicalDHTClient::with_config("127.0.0.1:0".parse().unwrap(), config);
+        client.add_node(KademliaNode {
+            id: [7u8; 32],
+            address: silent.local_addr().unwrap(),
+            last_seen: SystemTime::now(),
+            status: NodeStatus::Active,
+        }).await;
+
+        let started = std::time::Instant::now();
+        let result = client.find_pattern("0000000000000000000000000000000000000000").await;
+
+        assert_eq!(result.unwrap_err(), DHTError::TimeoutError(config.lookup_timeout).into());
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    /**
+     * Test: Republish restores a pattern lost by its replica holder
+     *
+     * DESIGN DECISION: Real RPC server on localhost whose storage is wiped after publish
+     * WHY: Validates republish_now() re-stores on the current closest nodes and reports it
+     */
+    #[tokio::test]
+    async fn test_republish_now_restores_lost_replica() {
+        let holder_addr = std::net::UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let holder_storage = std::sync::Arc::new(std::sync::Mutex::new(HashMap::new()));
+        let holder = Arc::new(RPCClient::new(
+            [9u8; 32],
+            holder_addr,
+            Arc::new(std::sync::Mutex::new(RoutingTable::new([9u8; 20]))),
+            holder_storage.clone(),
+        ));
+        tokio::spawn(holder.start_server());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let client = HierarchicalDHTClient::new("127.0.0.1:0".parse().unwrap());
+        client.add_node(KademliaNode {
+            id: [9u8; 32],
+            address: holder_addr,
+            last_seen: SystemTime::now(),
+            status: NodeStatus::Active,
+        }).await;
+
+        let pattern = Pattern::builder()
+            .title("Republished Pattern")
+            .content("Survives replica churn")
+            .tags(vec!["dht"])
+            .build()
+            .unwrap();
+        let published = client.publish_pattern(&pattern).await.unwrap();
+        assert_eq!(published.replicas, 2);
+
+        // Replica holder loses the value (restart, expiry)
+        holder_storage.lock().unwrap().clear();
+
+        let refreshed = client.republish_now().await.unwrap();
+        assert_eq!(refreshed.len(), 1);
+        assert_eq!(refreshed[0].refreshed_replicas, 1);
+        assert!(holder_storage.lock().unwrap().contains_key(&published.pattern_id));
+    }
+
+    /**
+     * Test: A value STOREd by another publisher is republished after that publisher leaves
+     *
+     * DESIGN DECISION: Publisher is a bare RPCClient that sends one STORE and goes away
+     * WHY: Validates holders keep foreign values alive while they're among the K closest,
+     * and leave them to closer nodes otherwise
+     */
+    #[tokio::test]
+    async fn test_republish_now_refreshes_values_from_other_publishers() {
+        let holder_addr = std::net::UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let holder_storage = std::sync::Arc::new(std::sync::Mutex::new(HashMap::new()));
+        let holder = Arc::new(RPCClient::new(
+            [9u8; 32],
+            holder_addr,
+            Arc::new(std::sync::Mutex::new(RoutingTable::new([9u8; 20]))),
+            holder_storage.clone(),
+        ));
+        tokio::spawn(holder.start_server());
+
+        let client_addr = std::net::UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let mut client = HierarchicalDHTClient::new(client_addr);
+        tokio::spawn(client.rpc_client.clone().start_server());
+        client.add_node(KademliaNode {
+            id: [9u8; 32],
+            address: holder_addr,
+            last_seen: SystemTime::now(),
+            status: NodeStatus::Active,
+        }).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Another node publishes to us, then leaves the network
+        let pattern = Pattern::builder()
+            .title("Foreign Pattern")
+            .content("Outlives its publisher")
+            .tags(vec!["dht"])
+            .build()
+            .unwrap();
+        let pattern_hash = client.hash_pattern(&pattern);
+        let pattern_id = hex::encode(pattern_hash);
+        {
+            let publisher = RPCClient::new(
+                [7u8; 32],
+                "127.0.0.1:0".parse().unwrap(),
+                Arc::new(std::sync::Mutex::new(RoutingTable::new([7u8; 20]))),
+                std::sync::Arc::new(std::sync::Mutex::new(HashMap::new())),
+            );
+            let client_node = KademliaNode {
+                id: client.padded_node_id(),
+                address: client_addr,
+                last_seen: SystemTime::now(),
+                status: NodeStatus::Active,
+            };
+            assert!(publisher.store(&client_node, pattern_id.clone(), pattern.clone()).await.unwrap().success);
+        }
+
+        let refreshed = client.republish_now().await.unwrap();
+        assert_eq!(refreshed.len(), 1);
+        assert_eq!(refreshed[0].pattern_id, pattern_id);
+        assert_eq!(refreshed[0].refreshed_replicas, 1);
+        assert!(holder_storage.lock().unwrap().contains_key(&pattern_id));
+
+        // A closer node is known and K = 1: the value is that node's to refresh
+        client.replication_factor = 1;
+        let mut closer_id = [0u8; 32];
+        closer_id[..20].copy_from_slice(&pattern_hash);
+        client.add_node(KademliaNode {
+            id: closer_id,
+            address: "127.0.0.1:9".parse().unwrap(),
+            last_seen: SystemTime::now(),
+            status: NodeStatus::Active,
+        }).await;
+        assert!(client.republish_now().await.unwrap().is_empty());
+    }
 }
@@ -21,7 +21,7 @@ pub mod routing_table;
 pub mod rpc;
 
 pub use dht::{
-    HierarchicalDHTClient, PublishResult, FindResult, DHTError,
+    HierarchicalDHTClient, DHTConfig, PublishResult, FindResult, DHTError,
     KademliaNode, NodeStatus, NodeSource
 };
 pub use routing_table::{RoutingTable, AddNodeResult};
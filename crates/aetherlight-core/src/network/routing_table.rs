@@ -7,7 +7,8 @@
  * REASONING CHAIN:
  * 1. Each K-bucket stores up to K=20 nodes at distance 2^i to 2^(i+1)
  * 2. XOR distance metric enables symmetric, unidirectional routing
- * 3. Least-recently-seen eviction keeps responsive nodes
+ * 3. Least-recently-seen eviction keeps responsive nodes (full bucket → newcomer queued,
+ *    LRU node pinged by RPCClient, evicted only if it doesn't answer)
  * 4. Bucket splitting handles high-density regions
  * 5. Result: O(log N) routing with high reliability
  *
@@ -25,6 +26,9 @@ const K: usize = 20; // Replication parameter (nodes per bucket)
 const ALPHA: usize = 3; // Parallelism parameter (concurrent queries)
 const BUCKET_REFRESH_INTERVAL: Duration = Duration::from_secs(3600); // 1 hour
 
+/// Default staleness threshold: LRU nodes not seen for 15 minutes get pinged before a newcomer is dropped
+pub const DEFAULT_STALE_AFTER: Duration = Duration::from_secs(900);
+
 /**
  * Routing Table with 160 K-buckets
  *
@@ -43,6 +47,7 @@ const BUCKET_REFRESH_INTERVAL: Duration = Duration::from_secs(3600); // 1 hour
 pub struct RoutingTable {
     local_id: [u8; 20],
     k_buckets: Vec<KBucket>,
+    stale_after: Duration,
 }
 
 impl RoutingTable {
@@ -56,26 +61,45 @@ impl RoutingTable {
         Self {
             local_id,
             k_buckets: (0..160).map(|_| KBucket::new()).collect(),
+            stale_after: DEFAULT_STALE_AFTER,
         }
     }
 
+    /**
+     * Set the staleness threshold (default 15 minutes)
+     *
+     * Nodes seen within this window are trusted alive and never pinged for eviction;
+     * tests use a zero threshold to force the ping/evict path
+     */
+    pub fn with_stale_after(mut self, stale_after: Duration) -> Self {
+        self.stale_after = stale_after;
+        self
+    }
+
+    /// Staleness threshold in effect
+    pub fn stale_after(&self) -> Duration {
+        self.stale_after
+    }
+
     /**
      * Add node to routing table
      *
-     * DESIGN DECISION: Least-recently-seen eviction
-     * WHY: Prefer long-lived nodes (higher reliability)
+     * DESIGN DECISION: Least-recently-seen eviction, decided by a ping
+     * WHY: Prefer long-lived nodes (higher reliability); a node is only dropped once
+     * it fails to answer, never because it merely went quiet
      *
      * REASONING CHAIN:
      * 1. Calculate XOR distance to determine bucket index
-     * 2. If bucket not full (< K nodes) → insert at end (MRU position)
-     * 3. If bucket full → check if existing node is stale
-     * 4. If stale node found → replace with new node
-     * 5. If all nodes responsive → ping least-recently-seen node
-     * 6. If ping fails → evict and insert new node
-     * 7. If ping succeeds → move to end (MRU) and discard new node
+     * 2. Known node → update last_seen, move to end (MRU position)
+     * 3. If bucket not full (< K nodes) → insert at end (MRU position)
+     * 4. If bucket full → queue new node in the bucket's replacement cache
+     * 5. Caller pings ping_candidate() (the LRU node, if stale) without holding the lock
+     * 6. record_ping(): answered → LRU moves to MRU, newcomer stays queued;
+     *    no answer → LRU evicted, newest queued node inserted (ReplacedStale)
      *
-     * PATTERN: LRU eviction with liveness checking
+     * PATTERN: LRU eviction with liveness checking (Kademlia §2.2)
      * PERFORMANCE: O(K) = O(20) insertion time
+     * RELATED: RPCClient::add_node (runs steps 5-6)
      */
     pub fn add_node(&mut self, node: KademliaNode) -> AddNodeResult {
         // Don't add self to routing table
@@ -98,21 +122,71 @@ impl RoutingTable {
         // If bucket not full, insert at end (MRU)
         if bucket.nodes.len() < K {
             bucket.nodes.push_back(node);
-            return AddNodeResult::Inserted;
+            return AddNodeResult::Added;
+        }
+
+        // Bucket full: queue newcomer (most recent at back, oldest dropped past K)
+        bucket.replacements.retain(|n| n.id != node.id);
+        if bucket.replacements.len() == K {
+            bucket.replacements.pop_front();
+        }
+        bucket.replacements.push_back(node);
+        AddNodeResult::Queued
+    }
+
+    /**
+     * Node to ping before a queued newcomer can take its place
+     *
+     * Returns the least-recently-seen node of `node_id`'s bucket when that bucket has
+     * queued replacements and the LRU node hasn't been seen within stale_after.
+     * None → nothing to evict (no queue, or LRU recently seen and assumed alive)
+     */
+    pub fn ping_candidate(&self, node_id: &[u8; 32]) -> Option<KademliaNode> {
+        let bucket = &self.k_buckets[self.bucket_index(node_id)];
+        if bucket.replacements.is_empty() {
+            return None;
         }
 
-        // Bucket full: Check for stale nodes (not seen in 15 minutes)
-        let stale_threshold = SystemTime::now() - Duration::from_secs(900);
-        if let Some(stale_index) = bucket.nodes.iter().position(|n| n.last_seen < stale_threshold) {
-            bucket.nodes.remove(stale_index);
+        let stale_threshold = SystemTime::now().checked_sub(self.stale_after).unwrap_or(SystemTime::UNIX_EPOCH);
+        bucket.nodes.front().filter(|lru| lru.last_seen <= stale_threshold).cloned()
+    }
+
+    /**
+     * Apply the outcome of pinging a ping_candidate()
+     *
+     * - responded → node marked seen and moved to MRU; newcomers stay queued (None)
+     * - no response → node evicted, newest queued node inserted (Some(ReplacedStale));
+     *   with nothing queued the node is just marked Offline (None)
+     */
+    pub fn record_ping(&mut self, node_id: &[u8; 32], responded: bool) -> Option<AddNodeResult> {
+        let bucket_index = self.bucket_index(node_id);
+        let bucket = &mut self.k_buckets[bucket_index];
+        let index = bucket.nodes.iter().position(|n| &n.id == node_id)?;
+
+        if responded {
+            let mut node = bucket.nodes.remove(index).unwrap();
+            node.last_seen = SystemTime::now();
+            node.status = NodeStatus::Active;
             bucket.nodes.push_back(node);
-            return AddNodeResult::ReplacedStale;
+            return None;
         }
 
-        // All nodes fresh: Need to ping LRU node to confirm liveness
-        // For now, discard new node (conservative approach)
-        // TODO: Implement async ping before eviction
-        AddNodeResult::BucketFull
+        match bucket.replacements.pop_back() {
+            Some(replacement) => {
+                bucket.nodes.remove(index);
+                bucket.nodes.push_back(replacement);
+                Some(AddNodeResult::ReplacedStale)
+            }
+            None => {
+                bucket.nodes[index].status = NodeStatus::Offline;
+                None
+            }
+        }
+    }
+
+    /// Nodes waiting for room in `node_id`'s bucket
+    pub fn queued_count(&self, node_id: &[u8; 32]) -> usize {
+        self.k_buckets[self.bucket_index(node_id)].replacements.len()
     }
 
     /**
@@ -254,10 +328,14 @@ impl RoutingTable {
  *
  * DESIGN DECISION: VecDeque for O(1) insertion at both ends
  * WHY: LRU at front, MRU at back, efficient eviction
+ *
+ * `replacements`: newcomers seen while the bucket was full (Kademlia replacement
+ * cache, at most K, newest at back), promoted when an LRU node fails its ping
  */
 #[derive(Debug)]
 struct KBucket {
     nodes: VecDeque<KademliaNode>,
+    replacements: VecDeque<KademliaNode>,
     last_refresh: SystemTime,
 }
 
@@ -265,6 +343,7 @@ impl KBucket {
     fn new() -> Self {
         Self {
             nodes: VecDeque::with_capacity(K),
+            replacements: VecDeque::new(),
             last_refresh: SystemTime::now(),
         }
     }
@@ -272,10 +351,10 @@ impl KBucket {
 
 #[derive(Debug, PartialEq)]
 pub enum AddNodeResult {
-    Inserted,      // Node added to bucket
+    Added,         // Node added to bucket
     Updated,       // Existing node updated (moved to MRU)
-    ReplacedStale, // Stale node replaced with new node
-    BucketFull,    // Bucket full, new node discarded
+    Queued,        // Bucket full, node queued until an LRU node fails its ping
+    ReplacedStale, // Unresponsive LRU node evicted, queued node inserted
     IsSelf,        // Cannot add self to routing table
 }
 
@@ -315,7 +394,7 @@ mod tests {
         };
 
         let result = rt.add_node(node);
-        assert_eq!(result, AddNodeResult::Inserted);
+        assert_eq!(result, AddNodeResult::Added);
         assert_eq!(rt.node_count(), 1);
     }
 
@@ -334,7 +413,7 @@ mod tests {
 
             let node = KademliaNode {
                 id: node_id,
-                address: format!("127.0.0.1:{}", 8080 + i).parse().unwrap(),
+                address: format!("127.0.0.1:{}", 8080 + i as u16).parse().unwrap(),
                 last_seen: SystemTime::now(),
                 status: NodeStatus::Active,
             };
@@ -372,10 +451,10 @@ mod tests {
             };
 
             let result = rt.add_node(node);
-            assert!(result == AddNodeResult::Inserted || result == AddNodeResult::Updated);
+            assert!(result == AddNodeResult::Added || result == AddNodeResult::Updated);
         }
 
-        // Try to add 21st node (should be discarded)
+        // Try to add 21st node (should be queued, not inserted)
         let mut node_id = [0u8; 32];
         node_id[0] = 255;
         node_id[1] = 99;
@@ -388,8 +467,52 @@ mod tests {
         };
 
         let result = rt.add_node(node);
-        assert_eq!(result, AddNodeResult::BucketFull);
+        assert_eq!(result, AddNodeResult::Queued);
+        assert_eq!(rt.node_count(), 20);
+
+        // LRU node seen just now: no ping needed, newcomer keeps waiting
+        assert!(rt.ping_candidate(&node_id).is_none());
+        assert_eq!(rt.queued_count(&node_id), 1);
+    }
+
+    /**
+     * Test: Unresponsive LRU node evicted for queued node
+     */
+    #[test]
+    fn test_stale_lru_eviction() {
+        let mut rt = RoutingTable::new([0u8; 20]).with_stale_after(Duration::ZERO);
+
+        let node_in_bucket_7 = |i: u8| {
+            let mut node_id = [0u8; 32];
+            node_id[0] = 255; // All in bucket 7
+            node_id[1] = i;
+            KademliaNode {
+                id: node_id,
+                address: format!("127.0.0.1:{}", 8080 + i as u16).parse().unwrap(),
+                last_seen: SystemTime::now(),
+                status: NodeStatus::Active,
+            }
+        };
+        for i in 0..20 {
+            rt.add_node(node_in_bucket_7(i));
+        }
+        let newcomer = node_in_bucket_7(99);
+        assert_eq!(rt.add_node(newcomer.clone()), AddNodeResult::Queued);
+
+        // LRU answers: kept (moved to MRU), newcomer still queued
+        let lru = rt.ping_candidate(&newcomer.id).unwrap();
+        assert_eq!(lru.id, node_in_bucket_7(0).id);
+        assert_eq!(rt.record_ping(&lru.id, true), None);
+        assert_eq!(rt.queued_count(&newcomer.id), 1);
+
+        // Next LRU doesn't answer: evicted, newcomer inserted
+        let lru = rt.ping_candidate(&newcomer.id).unwrap();
+        assert_eq!(lru.id, node_in_bucket_7(1).id);
+        assert_eq!(rt.record_ping(&lru.id, false), Some(AddNodeResult::ReplacedStale));
         assert_eq!(rt.node_count(), 20);
+        assert_eq!(rt.queued_count(&newcomer.id), 0);
+        assert!(rt.find_closest(&newcomer.id[..20].try_into().unwrap(), 20).iter().any(|n| n.id == newcomer.id));
+        assert!(!rt.find_closest(&lru.id[..20].try_into().unwrap(), 20).iter().any(|n| n.id == lru.id));
     }
 
     /**
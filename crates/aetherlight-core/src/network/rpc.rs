@@ -16,7 +16,7 @@
  * RELATED: routing_table.rs (uses FIND_NODE results)
  */
 
-use super::{KademliaNode, NodeStatus};
+use super::{AddNodeResult, KademliaNode, NodeStatus};
 use crate::{Pattern, Result, Error};
 use serde::{Serialize, Deserialize};
 use std::net::SocketAddr;
@@ -31,9 +31,12 @@ const MAX_NODES_PER_RESPONSE: usize = 20; // K parameter
  *
  * DESIGN DECISION: Tagged enum for type-safe RPC dispatch
  * WHY: Rust pattern matching ensures all cases handled
+ *
+ * NOTE: Externally tagged (serde default), not `#[serde(tag = "type")]`:
+ * bincode can't deserialize internally tagged enums, so every datagram was dropped
  */
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RPCMessage {
     Ping(PingRequest),
     Pong(PongResponse),
@@ -168,7 +171,7 @@ pub struct FindValueResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")] // Externally tagged for bincode (see RPCMessage)
 pub enum FindValueResult {
     Found {
         pattern: Pattern,
@@ -274,7 +277,7 @@ impl RPCClient {
      */
     pub async fn ping(&self, target: &KademliaNode) -> Result<PongResponse> {
         // 1. Create UDP socket bound to local address
-        let socket = tokio::net::UdpSocket::bind(self._local_addr)
+        let socket = tokio::net::UdpSocket::bind(self.request_addr())
             .await
             .map_err(|e| Error::Internal(format!("Failed to bind UDP socket: {}", e)))?;
 
@@ -343,7 +346,7 @@ impl RPCClient {
      */
     pub async fn find_node(&self, target: &KademliaNode, target_id: [u8; 32]) -> Result<FindNodeResponse> {
         // 1. Create UDP socket bound to local address
-        let socket = tokio::net::UdpSocket::bind(self._local_addr)
+        let socket = tokio::net::UdpSocket::bind(self.request_addr())
             .await
             .map_err(|e| Error::Internal(format!("Failed to bind UDP socket: {}", e)))?;
 
@@ -409,7 +412,7 @@ impl RPCClient {
      */
     pub async fn store(&self, target: &KademliaNode, pattern_id: String, pattern: Pattern) -> Result<StoreResponse> {
         // 1. Create UDP socket bound to local address
-        let socket = tokio::net::UdpSocket::bind(self._local_addr)
+        let socket = tokio::net::UdpSocket::bind(self.request_addr())
             .await
             .map_err(|e| Error::Internal(format!("Failed to bind UDP socket: {}", e)))?;
 
@@ -477,7 +480,7 @@ impl RPCClient {
      */
     pub async fn find_value(&self, target: &KademliaNode, pattern_id: String) -> Result<FindValueResponse> {
         // 1. Create UDP socket bound to local address
-        let socket = tokio::net::UdpSocket::bind(self._local_addr)
+        let socket = tokio::net::UdpSocket::bind(self.request_addr())
             .await
             .map_err(|e| Error::Internal(format!("Failed to bind UDP socket: {}", e)))?;
 
@@ -524,6 +527,44 @@ impl RPCClient {
         }
     }
 
+    /**
+     * Address for an outgoing request socket: local IP, ephemeral port
+     *
+     * DESIGN DECISION: Never bind requests to the listening port
+     * WHY: start_server owns that port, and lookups keep up to α requests in flight;
+     * replies go to the request socket's address (recv_from), so any port works
+     */
+    fn request_addr(&self) -> SocketAddr {
+        SocketAddr::new(self._local_addr.ip(), 0)
+    }
+
+    /**
+     * Add node to the shared routing table, pinging to make room in a full bucket
+     *
+     * DESIGN DECISION: Routing table queues, RPCClient pings (lock never held across I/O)
+     * WHY: Kademlia eviction - a full bucket only gives up its least-recently-seen node
+     * after that node fails a PING; live long-running nodes are never displaced
+     *
+     * RETURNS: Added / Updated / IsSelf straight from the table, ReplacedStale when the
+     * stale LRU node didn't answer, Queued when it did (or was seen recently)
+     */
+    pub async fn add_node(&self, node: KademliaNode) -> AddNodeResult {
+        let node_id = node.id;
+        let result = self.routing_table.lock().unwrap().add_node(node);
+
+        if result != AddNodeResult::Queued {
+            return result;
+        }
+        self.evict_unresponsive(&node_id).await.unwrap_or(AddNodeResult::Queued)
+    }
+
+    /// Ping the stale LRU node of `node_id`'s bucket (if any) and record the outcome
+    async fn evict_unresponsive(&self, node_id: &[u8; 32]) -> Option<AddNodeResult> {
+        let candidate = self.routing_table.lock().ok()?.ping_candidate(node_id)?;
+        let responded = self.ping(&candidate).await.is_ok();
+        self.routing_table.lock().ok()?.record_ping(&candidate.id, responded)
+    }
+
     /**
      * Send RPC with timeout
      *
@@ -566,8 +607,7 @@ impl RPCClient {
             .await
             .map_err(|e| Error::Internal(format!("Failed to bind UDP server socket: {}", e)))?);
 
-        // TODO: Add proper logging when log crate is integrated
-        eprintln!("RPC server listening on {}", self._local_addr);
+        tracing::info!(addr = %self._local_addr, "RPC server listening");
 
         // 2. Run infinite loop receiving incoming messages
         let mut buf = [0u8; 16384]; // 16KB buffer (largest request: FIND_VALUE with pattern data)
@@ -583,21 +623,18 @@ impl RPCClient {
 
                             tokio::spawn(async move {
                                 if let Err(e) = client.handle_request(message, sender_addr, socket_clone).await {
-                                    // TODO: log::error!("Error handling RPC request from {}: {}", sender_addr, e);
-                                    eprintln!("Error handling RPC request from {}: {}", sender_addr, e);
+                                    tracing::error!(sender = %sender_addr, error = %e, "Error handling RPC request");
                                 }
                             });
                         }
                         Err(e) => {
-                            // TODO: log::warn!("Failed to deserialize RPC message from {}: {}", sender_addr, e);
-                            eprintln!("Failed to deserialize RPC message from {}: {}", sender_addr, e);
+                            tracing::warn!(sender = %sender_addr, error = %e, "Failed to deserialize RPC message");
                             // Continue serving (don't crash on malformed requests)
                         }
                     }
                 }
                 Err(e) => {
-                    // TODO: log::error!("Error receiving UDP packet: {}", e);
-                    eprintln!("Error receiving UDP packet: {}", e);
+                    tracing::error!(error = %e, "Error receiving UDP packet");
                     // Continue serving (don't crash on network errors)
                 }
             }
@@ -616,6 +653,7 @@ impl RPCClient {
      * 3. Ignore response messages (PONG, etc.) - server doesn't process responses
      * 4. Handler generates response message
      * 5. Serialize and send response back to sender
+     * 6. Sender queued behind a full bucket → ping that bucket's stale LRU node
      *
      * PATTERN: Pattern-DHT-RPC-001 (Kademlia RPC over UDP)
      * PERFORMANCE: <5ms routing + handler execution
@@ -626,6 +664,14 @@ impl RPCClient {
         sender_addr: SocketAddr,
         socket: std::sync::Arc<tokio::net::UdpSocket>,
     ) -> Result<()> {
+        let sender_id = match &message {
+            RPCMessage::Ping(req) => Some(req.sender_id),
+            RPCMessage::FindNode(req) => Some(req.sender_id),
+            RPCMessage::Store(req) => Some(req.sender_id),
+            RPCMessage::FindValue(req) => Some(req.sender_id),
+            _ => None,
+        };
+
         let response = match message {
             RPCMessage::Ping(req) => {
                 Some(self.handle_ping_request(req).await?)
@@ -657,6 +703,12 @@ impl RPCClient {
                 .map_err(|e| Error::Internal(format!("Failed to send response: {}", e)))?;
         }
 
+        // Sender was queued if its bucket is full: ping that bucket's stale LRU node
+        // (after replying, so the requester never waits on a third node)
+        if let Some(sender_id) = sender_id {
+            self.evict_unresponsive(&sender_id).await;
+        }
+
         Ok(())
     }
 
@@ -924,6 +976,25 @@ mod tests {
         }
     }
 
+    /**
+     * Test: RPC message bincode round-trip (wire format)
+     */
+    #[test]
+    fn test_rpc_bincode_roundtrip() {
+        let find_value = RPCMessage::FindValue(FindValueRequest {
+            request_id: "test-456".to_string(),
+            sender_id: [7u8; 32],
+            sender_addr: "127.0.0.1:8080".parse().unwrap(),
+            pattern_id: "abc".to_string(),
+        });
+
+        let bytes = bincode::serialize(&find_value).unwrap();
+        match bincode::deserialize::<RPCMessage>(&bytes).unwrap() {
+            RPCMessage::FindValue(req) => assert_eq!(req.pattern_id, "abc"),
+            _ => panic!("Wrong message type"),
+        }
+    }
+
     /**
      * Test: NodeInfo conversion
      */
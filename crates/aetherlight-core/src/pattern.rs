@@ -99,6 +99,17 @@ pub struct Pattern {
 
     /// Last modification timestamp (ISO 8601)
     modified_at: DateTime<Utc>,
+
+    /// Revision number (1 on creation, incremented by revise())
+    #[serde(default = "initial_version")]
+    version: u32,
+}
+
+/// Version assigned to new patterns (and to serialized patterns predating versioning)
+pub const INITIAL_PATTERN_VERSION: u32 = 1;
+
+fn initial_version() -> u32 {
+    INITIAL_PATTERN_VERSION
 }
 
 /**
@@ -124,6 +135,10 @@ pub struct PatternMetadata {
 
     /// Domain context (e.g., "error-handling", "async", "testing")
     pub domain: Option<String>,
+
+    /// Minutes a match against this pattern saves (None = analytics default for pattern matches)
+    #[serde(default)]
+    pub estimated_time_saved_minutes: Option<u32>,
 }
 
 impl Pattern {
@@ -178,10 +193,11 @@ impl Pattern {
             id: Uuid::new_v4(),
             title,
             content,
-            tags,
+            tags: sanitize_tags(&tags),
             metadata: PatternMetadata::default(),
             created_at: now,
             modified_at: now,
+            version: INITIAL_PATTERN_VERSION,
         }
     }
 
@@ -193,6 +209,47 @@ impl Pattern {
     pub fn metadata(&self) -> &PatternMetadata { &self.metadata }
     pub fn created_at(&self) -> &DateTime<Utc> { &self.created_at }
     pub fn modified_at(&self) -> &DateTime<Utc> { &self.modified_at }
+    pub fn version(&self) -> u32 { self.version }
+
+    /**
+     * Produce the next revision of this pattern with edited title and content
+     *
+     * DESIGN DECISION: Consuming edit that returns a new revision (no setters)
+     * WHY: Pattern stays immutable for sharers; edits are explicit and versioned
+     *
+     * REASONING CHAIN:
+     * 1. Same validation as PatternBuilder::build (non-empty title/content)
+     * 2. id, tags, metadata, created_at carried over
+     * 3. version incremented, modified_at set to now
+     * 4. Callers keep the previous value if they need history (desktop PatternHistory)
+     *
+     * RELATED: lumina-desktop storage/history.rs (persisted revisions, revert)
+     */
+    pub fn revise(self, title: impl Into<String>, content: impl Into<String>) -> Result<Self> {
+        let title = title.into();
+        let content = content.into();
+
+        if title.trim().is_empty() {
+            return Err(Error::PatternValidation("title cannot be empty".to_string()));
+        }
+
+        if content.trim().is_empty() {
+            return Err(Error::PatternValidation("content cannot be empty".to_string()));
+        }
+
+        Ok(Self {
+            title,
+            content,
+            modified_at: Utc::now(),
+            version: self.version.saturating_add(1),
+            ..self
+        })
+    }
+
+    /// Same pattern under another id (a re-parsed file keeps the id it was indexed with)
+    pub(crate) fn with_id(self, id: Uuid) -> Self {
+        Self { id, ..self }
+    }
 
     /**
      * Load pattern from markdown file
@@ -294,7 +351,7 @@ impl Pattern {
  * PATTERN: Rust builder pattern
  * RELATED: Pattern::builder()
  */
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct PatternBuilder {
     title: Option<String>,
     content: Option<String>,
@@ -302,6 +359,7 @@ pub struct PatternBuilder {
     language: Option<String>,
     framework: Option<String>,
     domain: Option<String>,
+    estimated_time_saved_minutes: Option<u32>,
 }
 
 impl PatternBuilder {
@@ -347,6 +405,12 @@ impl PatternBuilder {
         self
     }
 
+    /// Set minutes saved per match (credited by UsageTracker::record_pattern_match)
+    pub fn estimated_time_saved(mut self, minutes: u32) -> Self {
+        self.estimated_time_saved_minutes = Some(minutes);
+        self
+    }
+
     /**
      * Build the Pattern instance with validation
      *
@@ -358,7 +422,8 @@ impl PatternBuilder {
      * 2. Empty strings rejected for title/content (meaningful patterns only)
      * 3. Timestamps auto-generated at build time
      * 4. UUID auto-generated for unique identification
-     * 5. Validation failures return descriptive errors
+     * 5. Tags normalized and deduped (see normalize_tag); comma-containing tags rejected
+     * 6. Validation failures return descriptive errors
      *
      * PATTERN: Rust builder pattern with validation
      * RELATED: Error::PatternValidation
@@ -378,23 +443,92 @@ impl PatternBuilder {
             return Err(Error::PatternValidation("content cannot be empty".to_string()));
         }
 
+        let tags = normalize_tags(&self.tags)?;
+
         let now = Utc::now();
         Ok(Pattern {
             id: Uuid::new_v4(),
             title,
             content,
-            tags: self.tags,
+            tags,
             metadata: PatternMetadata {
                 language: self.language,
                 framework: self.framework,
                 domain: self.domain,
+                estimated_time_saved_minutes: self.estimated_time_saved_minutes,
             },
             created_at: now,
             modified_at: now,
+            version: INITIAL_PATTERN_VERSION,
         })
     }
 }
 
+/// Delimiter tags are joined with when stored as CSV (desktop SQLite, markdown `tags:` lines)
+pub const TAG_DELIMITER: char = ',';
+
+/**
+ * Normalize a single tag: trim, lowercase, collapse internal whitespace
+ *
+ * DESIGN DECISION: Reject (not strip) tags containing the delimiter
+ * WHY: Stores persist tags as comma-joined text; "a,b" would silently become two tags
+ *
+ * Returns Error::PatternValidation for empty tags or tags containing a comma.
+ */
+pub fn normalize_tag(tag: &str) -> Result<String> {
+    if tag.contains(TAG_DELIMITER) {
+        return Err(Error::PatternValidation(format!(
+            "tag '{}' cannot contain '{}'",
+            tag.trim(),
+            TAG_DELIMITER
+        )));
+    }
+
+    let normalized = tag
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    if normalized.is_empty() {
+        return Err(Error::PatternValidation("tag cannot be empty".to_string()));
+    }
+
+    Ok(normalized)
+}
+
+/**
+ * Normalize a tag list, dropping blank entries and duplicates (first occurrence wins)
+ *
+ * Fails on the first tag containing the delimiter.
+ */
+pub fn normalize_tags<S: AsRef<str>>(tags: &[S]) -> Result<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        if tag.as_ref().trim().is_empty() {
+            continue;
+        }
+        let tag = normalize_tag(tag.as_ref())?;
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+/**
+ * Lenient variant for infallible paths: comma-containing tags are split, not rejected
+ *
+ * Used by Pattern::new and for data already stored as CSV (where the comma was the split point).
+ */
+pub fn sanitize_tags<S: AsRef<str>>(tags: &[S]) -> Vec<String> {
+    let split: Vec<&str> = tags
+        .iter()
+        .flat_map(|tag| tag.as_ref().split(TAG_DELIMITER))
+        .collect();
+    normalize_tags(&split).unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -506,4 +640,68 @@ mod tests {
 
         assert_eq!(pattern, deserialized);
     }
+
+    /**
+     * Test: Editing a pattern bumps its version and keeps its identity
+     */
+    #[test]
+    fn test_revise_bumps_version() {
+        let original = Pattern::new(
+            "Title".to_string(),
+            "Content".to_string(),
+            vec!["tag".to_string()],
+        );
+        assert_eq!(original.version(), INITIAL_PATTERN_VERSION);
+
+        let revised = original.clone().revise("Title", "Better content").unwrap();
+        assert_eq!(revised.version(), INITIAL_PATTERN_VERSION + 1);
+        assert_eq!(revised.id(), original.id());
+        assert_eq!(revised.content(), "Better content");
+        assert_eq!(revised.tags(), original.tags());
+        assert_eq!(revised.created_at(), original.created_at());
+        assert!(revised.modified_at() >= original.modified_at());
+
+        assert!(revised.revise("Title", "  ").is_err());
+
+        // Patterns serialized before versioning load as version 1
+        let mut json = serde_json::to_value(&original).unwrap();
+        json.as_object_mut().unwrap().remove("version");
+        let legacy: Pattern = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.version(), INITIAL_PATTERN_VERSION);
+    }
+
+    /**
+     * Test: Tags trimmed, lowercased, whitespace collapsed, deduped on build
+     */
+    #[test]
+    fn test_tags_normalized() {
+        assert_eq!(normalize_tag("  Error   Handling ").unwrap(), "error handling");
+        assert!(normalize_tag("   ").is_err());
+
+        let pattern = Pattern::builder()
+            .title("Title")
+            .content("Content")
+            .tags(vec!["Rust", " rust ", "Async\tIO", "", "async io"])
+            .build()
+            .unwrap();
+        assert_eq!(pattern.tags(), &["rust".to_string(), "async io".to_string()]);
+
+        let lenient = Pattern::new("T".to_string(), "C".to_string(), vec!["Rust, Async".to_string()]);
+        assert_eq!(lenient.tags(), &["rust".to_string(), "async".to_string()]);
+    }
+
+    /**
+     * Test: Comma-containing tag rejected (would corrupt CSV storage)
+     */
+    #[test]
+    fn test_comma_tag_rejected() {
+        assert!(matches!(normalize_tag("rust,async"), Err(Error::PatternValidation(_))));
+
+        let result = Pattern::builder()
+            .title("Title")
+            .content("Content")
+            .tag("rust,async")
+            .build();
+        assert!(matches!(result, Err(Error::PatternValidation(_))));
+    }
 }
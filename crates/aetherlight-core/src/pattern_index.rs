@@ -57,14 +57,26 @@
 pub mod embedder;
 pub mod search;
 pub mod ranker;
+pub mod reranker;
+pub mod dedup;
+pub mod paths;
+pub mod manifest;
 
-use crate::{Pattern, LocalEmbeddings, SqliteVectorStore, Result, Error};
+pub use reranker::RerankConfig;
+pub use dedup::{ContentDedup, DedupOutcome, ImportSummary};
+pub use paths::resolve_dir;
+pub use manifest::IndexManifest;
+
+use crate::{Pattern, Embedder, EmbeddingConfig, EmbeddingResult, KeywordEmbedder, SqliteVectorStore, Result, Error};
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::SystemTime;
+use tokio::sync::{OnceCell, RwLock};
+use uuid::Uuid;
 
 /// Pattern with embedding and usage metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,11 +117,14 @@ pub struct PatternMatch {
 }
 
 /// Context for pattern search (optional)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SearchContext {
     /// Current domain (e.g., "rust", "typescript", "authentication")
     pub domain: Option<String>,
 
+    /// Domains the user selected in settings (soft boost, never a filter)
+    pub selected_domains: Vec<String>,
+
     /// Current framework (e.g., "actix-web", "react", "flutter")
     pub framework: Option<String>,
 
@@ -128,42 +143,262 @@ pub struct PatternIndex {
     /// Vector store for semantic search (SQLite)
     vector_store: Arc<RwLock<SqliteVectorStore>>,
 
-    /// Embeddings generator
-    embeddings: Arc<RwLock<LocalEmbeddings>>,
+    /// Embeddings provider (opened on first use or by warm_up)
+    /// Only a successful open is cached: a model added later is picked up on the next call
+    embeddings: OnceCell<Box<dyn Embedder>>,
+
+    /// Embedder supplied with set_embedder (kept across embedding config changes)
+    custom_embedder: bool,
+
+    /// ONNX model path for lazy embeddings initialization
+    model_path: PathBuf,
+
+    /// Tokenizer path for lazy embeddings initialization
+    tokenizer_path: PathBuf,
+
+    /// Embedding provider options (provider, precision) used when the embedder is opened
+    embedding_config: EmbeddingConfig,
+
+    /// Number of embedding model load attempts (stops growing after the first success)
+    embedding_inits: AtomicUsize,
+
+    /// Optional top-K re-ranking stage
+    rerank: RerankConfig,
 
     /// Pattern library root directory
     pattern_dir: PathBuf,
 
+    /// Build manifest (written after each completed rebuild)
+    manifest_path: PathBuf,
+
     /// Hot cache (frequently accessed patterns)
     hot_cache: Arc<RwLock<HashMap<String, PatternMatch>>>,
+
+    /// Content hash → canonical pattern id (duplicate ids aliased)
+    dedup: ContentDedup,
+
+    /// Pattern file → id indexed from it (set by rebuild and update_pattern, kept in the manifest)
+    source_paths: HashMap<PathBuf, Uuid>,
+
+    /// Vector rows without a stored pattern (written by an older version): not searchable
+    /// until the next rebuild
+    missing_content: bool,
 }
 
 impl PatternIndex {
     /**
-     * DESIGN DECISION: Initialize with embeddings model and vector store
-     * WHY: Self-contained, no external dependencies after initialization
+     * DESIGN DECISION: Initialize vector store now, embeddings model on first use
+     * WHY: ONNX session load is the dominant cold-start cost; callers choose when to
+     * pay it (warm_up() at idle, or implicitly on first search)
+     *
+     * Does not fail when the model files are missing: the first call that needs
+     * embeddings (warm_up, search_by_intent, add_pattern, ...) returns the load
+     * error, and later calls retry the load until it succeeds
+     * (or use KeywordEmbedder with EmbeddingConfig::keyword_fallback).
      */
     pub fn new(pattern_dir: PathBuf, data_dir: PathBuf) -> Result<Self> {
-        // Initialize embeddings model
         // DESIGN DECISION: Use default model paths from data directory
-        // WHY: Self-contained, no external configuration required
-        let model_path = data_dir.join("models/all-MiniLM-L6-v2.onnx");
-        let tokenizer_path = data_dir.join("models/tokenizer.json");
-        let embeddings = LocalEmbeddings::new(model_path, tokenizer_path)?;
+        // WHY: Self-contained, no external configuration required (LUMINA_MODEL_DIR overrides)
+        let (model_path, tokenizer_path) = crate::model_paths(data_dir.join("models"));
 
-        // Initialize vector store (SQLite)
+        // Initialize vector store (SQLite) and restore the patterns it holds
         let vector_store_path = data_dir.join("pattern_index.sqlite");
         let vector_store = SqliteVectorStore::new(vector_store_path)?;
+        let (patterns, mut dedup, missing_content) = load_indexed(&vector_store)?;
+        let manifest_path = data_dir.join(manifest::MANIFEST_FILE_NAME);
+        let source_paths = load_source_paths(&manifest_path, &mut dedup);
 
         Ok(Self {
-            patterns: Arc::new(RwLock::new(Vec::new())),
+            patterns: Arc::new(RwLock::new(patterns)),
             vector_store: Arc::new(RwLock::new(vector_store)),
-            embeddings: Arc::new(RwLock::new(embeddings)),
+            embeddings: OnceCell::new(),
+            custom_embedder: false,
+            model_path,
+            tokenizer_path,
+            embedding_config: EmbeddingConfig::default(),
+            embedding_inits: AtomicUsize::new(0),
+            rerank: RerankConfig::default(),
             pattern_dir,
+            manifest_path,
             hot_cache: Arc::new(RwLock::new(HashMap::new())),
+            dedup,
+            source_paths,
+            missing_content,
         })
     }
 
+    /**
+     * DESIGN DECISION: Open the index of a workspace by its root directory
+     * WHY: Callers pass one root instead of cwd-relative paths that break once installed
+     *
+     * Layout: `<root>/docs/patterns` (must exist), `<root>/data` (created if missing).
+     * `root` may be absolute, "~"-prefixed or relative to the working directory.
+     */
+    pub fn new_in_workspace(root: impl AsRef<Path>) -> Result<Self> {
+        let root = resolve_dir(root, std::env::current_dir()?)?;
+        let pattern_dir = resolve_dir("docs/patterns", &root)?;
+        let data_dir = root.join("data");
+        std::fs::create_dir_all(&data_dir)?;
+
+        Self::new(pattern_dir, data_dir)
+    }
+
+    /// Pattern library directory this index reads from
+    pub fn pattern_dir(&self) -> &Path {
+        &self.pattern_dir
+    }
+
+    /**
+     * DESIGN DECISION: Initialized = a rebuild completed and its manifest is on disk
+     * WHY: An empty search result is ambiguous; callers check this to offer "build index?"
+     *
+     * False for a fresh data dir, after a failed rebuild (see manifest module) and for
+     * an index written before patterns were stored with their vectors (nothing to search).
+     */
+    pub fn is_initialized(&self) -> bool {
+        !self.missing_content && IndexManifest::read(&self.manifest_path).is_some()
+    }
+
+    /**
+     * DESIGN DECISION: Rebuild needed = never built, or pattern_dir changed since
+     * WHY: Pattern files edited/added/removed after the last rebuild are not searchable
+     *
+     * Staleness compares modification times of pattern_dir and its .md files with
+     * the last rebuild. Indexes built with rebuild_from_patterns (library kept in a
+     * database) are stale only when pattern_dir itself changes.
+     *
+     * # Errors
+     *
+     * Io if pattern_dir cannot be read (only checked once the index is initialized)
+     */
+    pub fn needs_rebuild(&self) -> Result<bool> {
+        if self.missing_content {
+            return Ok(true);
+        }
+        match IndexManifest::read(&self.manifest_path) {
+            Some(manifest) => manifest.is_stale(&self.pattern_dir),
+            None => Ok(true),
+        }
+    }
+
+    /**
+     * DESIGN DECISION: Pay cold-start cost up front (embedding session + vector store)
+     * WHY: Interactive UIs call this at startup/idle so the user's first query hits warm paths
+     *
+     * REASONING CHAIN:
+     * 1. Load ONNX embedding session (once per index lifetime)
+     * 2. Run a throwaway embedding so lazy graph optimization happens now
+     * 3. Scan vector store once so SQLite pages are in the OS/page cache
+     * 4. Subsequent search_by_intent calls skip all three cold paths
+     *
+     * PERFORMANCE: One-time cost (model load dominates); no-op cost on repeat calls
+     */
+    #[tracing::instrument(skip(self))]
+    pub async fn warm_up(&self) -> Result<()> {
+        let embeddings = self.embedder().await?;
+        let probe = embed_checked(embeddings, "warm up")?;
+
+        let vector_store = self.vector_store.read().await;
+        if vector_store.count()? > 0 {
+            vector_store.search(&probe.embedding, 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Current re-ranking configuration
+    pub fn rerank_config(&self) -> &RerankConfig {
+        &self.rerank
+    }
+
+    /**
+     * DESIGN DECISION: Re-ranking configurable after construction
+     * WHY: Keeps new() signature stable for FFI callers; K/N tuned per deployment
+     */
+    pub fn set_rerank_config(&mut self, config: RerankConfig) {
+        self.rerank = config;
+    }
+
+    /// Current embedding model configuration
+    pub fn embedding_config(&self) -> &EmbeddingConfig {
+        &self.embedding_config
+    }
+
+    /**
+     * DESIGN DECISION: Changing embedding config unloads the current embedder
+     * WHY: The next embedding call opens the provider/precision now configured
+     *
+     * Vectors already stored were produced by the previous embedder: call rebuild()
+     * after changing provider, precision or keyword fallback so stored and query
+     * vectors come from the same model. An embedder supplied with set_embedder is kept.
+     */
+    pub fn set_embedding_config(&mut self, config: EmbeddingConfig) {
+        if config != self.embedding_config && !self.custom_embedder {
+            self.embeddings = OnceCell::new();
+        }
+        self.embedding_config = config;
+    }
+
+    /**
+     * DESIGN DECISION: Embedder injectable instead of always loading the ONNX model
+     * WHY: Hosts without the local model (and tests) can still index and search
+     *
+     * Replaces any loaded model; the model files under data_dir are never read.
+     * Vectors already stored came from the previous embedder: call rebuild() after.
+     */
+    pub fn set_embedder(&mut self, embedder: Box<dyn Embedder>) {
+        self.embeddings = OnceCell::new_with(Some(embedder));
+        self.custom_embedder = true;
+    }
+
+    /// Whether the embedding model has been loaded successfully
+    pub fn is_warm(&self) -> bool {
+        self.embeddings.initialized()
+    }
+
+    /// Number of embedding model load attempts (diagnostics)
+    pub fn embedding_initializations(&self) -> usize {
+        self.embedding_inits.load(Ordering::SeqCst)
+    }
+
+    /**
+     * DESIGN DECISION: Open the configured embedder once, cache only a successful open
+     * WHY: Concurrent first queries must not race to load the model twice, but a
+     * model downloaded after startup must still be picked up (no sticky failure)
+     *
+     * With keyword_fallback a failed open caches KeywordEmbedder instead: the index
+     * stays on keyword vectors (not mixed with model vectors) until the config changes.
+     *
+     * Fails when the embedder's dimension() differs from the vectors already stored
+     * (provider switched without a rebuild).
+     */
+    async fn embedder(&self) -> Result<&dyn Embedder> {
+        let embedder = self.embeddings.get_or_try_init(|| async {
+            self.embedding_inits.fetch_add(1, Ordering::SeqCst);
+            match crate::embedder_from_config(&self.model_path, &self.tokenizer_path, &self.embedding_config) {
+                Ok(embedder) => Ok(embedder),
+                Err(e) if self.embedding_config.keyword_fallback => {
+                    tracing::warn!("Embeddings unavailable ({}), using keyword-only matching", e);
+                    Ok(Box::new(KeywordEmbedder) as Box<dyn Embedder>)
+                }
+                Err(e) => Err(e),
+            }
+        }).await?;
+
+        if let Some(stored) = self.vector_store.read().await.dimension()? {
+            if stored != embedder.dimension() {
+                return Err(Error::ValidationError(format!(
+                    "Pattern index holds {}-dim vectors but the embedder produces {}-dim vectors; \
+                     rebuild the index after switching embedding providers",
+                    stored,
+                    embedder.dimension()
+                )));
+            }
+        }
+
+        Ok(embedder.as_ref())
+    }
+
     /**
      * DESIGN DECISION: Search by intent (semantic), not keywords
      * WHY: Understands user's goal, not just string matching
@@ -173,28 +408,37 @@ impl PatternIndex {
      * 2. Generate embedding for query (384-dim vector)
      * 3. Search vector store for similar pattern embeddings (cosine similarity)
      * 4. Rank by relevance + context boost (recent usage, domain, framework)
-     * 5. Return top N matches with reasoning
+     * 5. Optionally re-rank top-K with keyword/metadata/recency features (RerankConfig)
+     * 6. Return top N matches with reasoning
      *
      * PERFORMANCE: <100ms for search across 100+ patterns
      */
+    #[tracing::instrument(
+        skip(self, intent, context),
+        fields(intent_len = intent.len(), cache_hit = false, results = tracing::field::Empty)
+    )]
     pub async fn search_by_intent(
         &self,
         intent: &str,
         context: Option<&SearchContext>,
     ) -> Result<Vec<PatternMatch>> {
-        // Check hot cache first
-        if let Some(cached) = self.hot_cache.read().await.get(intent) {
-            return Ok(vec![cached.clone()]);
+        // Check hot cache first (context-free searches only: context changes the ranking)
+        if context.is_none() {
+            if let Some(cached) = self.hot_cache.read().await.get(intent) {
+                tracing::Span::current().record("cache_hit", true);
+                return Ok(vec![cached.clone()]);
+            }
         }
 
         // Generate embedding for intent query
-        let mut embeddings = self.embeddings.write().await;
-        let query_embedding_result = embeddings.embed(intent)?;
+        let embeddings = self.embedder().await?;
+        let query_embedding_result = embed_checked(embeddings, intent)?;
         let query_embedding = query_embedding_result.embedding.clone();
 
         // Search vector store for similar patterns
         let vector_store = self.vector_store.read().await;
-        let search_results = vector_store.search(&query_embedding, 10)?;
+        let candidate_limit = if self.rerank.enabled { self.rerank.candidates } else { 10 };
+        let search_results = vector_store.search(&query_embedding, candidate_limit)?;
 
         // Load full patterns
         let patterns = self.patterns.read().await;
@@ -239,104 +483,490 @@ impl PatternIndex {
         // Sort by relevance (descending)
         matches.sort_by(|a, b| b.relevance.partial_cmp(&a.relevance).unwrap());
 
+        // Re-rank bi-encoder head with richer features
+        if self.rerank.enabled {
+            matches = reranker::rerank(intent, matches, &patterns, context, &self.rerank);
+        }
+
         // Cache top result if high relevance
-        if let Some(top) = matches.first() {
+        if let Some(top) = matches.first().filter(|_| context.is_none()) {
             if top.relevance > 0.85 {
                 self.hot_cache.write().await.insert(intent.to_string(), top.clone());
             }
         }
 
+        tracing::Span::current().record("results", matches.len());
         Ok(matches)
     }
 
     /**
-     * DESIGN DECISION: Add pattern to index incrementally
-     * WHY: Avoid full rebuild when adding single pattern
+     * DESIGN DECISION: Add pattern to index incrementally, skipping unchanged content
+     * WHY: Avoid full rebuild when adding single pattern; idempotent syncs re-add
+     * the same patterns and must not pay for (or duplicate) embeddings
+     *
+     * Returns what happened, decided by content hash before any embedding:
+     * - Unique: embedded and stored
+     * - Changed: same id with edited content, re-embedded and replaced in place
+     *   (usage history kept)
+     * - AlreadyIndexed: same id and content, no-op
+     * - Duplicate: content indexed under another id, recorded as an alias (see canonical_id)
+     */
+    pub async fn add_pattern(&mut self, pattern: Pattern) -> Result<DedupOutcome> {
+        self.index_pattern(pattern).await
+    }
+
+    /**
+     * DESIGN DECISION: Bulk import with content-addressed deduplication
+     * WHY: Syncing from several sources yields identical patterns under different ids;
+     * importers need to know how many were collapsed
+     *
+     * REASONING CHAIN:
+     * 1. Dedup against indexed content plus earlier patterns in this import
+     * 2. Embed each unique pattern
+     * 3. Write all vectors with one SqliteVectorStore::insert_batch (one transaction)
+     * 4. Only after the batch commits: extend in-memory cache and dedup registry
+     * 5. A failed import leaves the index as it was before this call. Note that
+     *    rebuild_with_progress clears the index first, so a failed rebuild leaves it empty
      */
-    pub async fn add_pattern(&mut self, pattern: Pattern) -> Result<()> {
-        // Generate embedding for pattern description
-        let mut embeddings = self.embeddings.write().await;
+    pub async fn import_patterns(
+        &mut self,
+        patterns: impl IntoIterator<Item = Pattern>,
+    ) -> Result<ImportSummary> {
+        self.import_with_progress(patterns.into_iter().collect(), |_, _| {}).await
+    }
+
+    /// import_patterns, reporting (processed, total) after each pattern is deduplicated/embedded
+    async fn import_with_progress<F>(&mut self, patterns: Vec<Pattern>, mut on_progress: F) -> Result<ImportSummary>
+    where
+        F: FnMut(usize, usize),
+    {
+        let total = patterns.len();
+        let mut summary = ImportSummary::default();
+        let mut dedup = self.dedup.clone();
+        let mut staged = Vec::new();
+        let mut changed = false;
+
+        for (processed, pattern) in patterns.into_iter().enumerate() {
+            match dedup.check(&pattern) {
+                outcome @ (DedupOutcome::Unique | DedupOutcome::Changed) => {
+                    staged.push(self.embed_pattern(&pattern).await?);
+                    summary.imported += 1;
+                    changed |= outcome == DedupOutcome::Changed;
+                }
+                DedupOutcome::Duplicate { canonical_id } => {
+                    tracing::debug!(pattern_id = %pattern.id(), canonical_id = %canonical_id, "Duplicate pattern content collapsed");
+                    summary.duplicates_collapsed += 1;
+                }
+                DedupOutcome::AlreadyIndexed => {}
+            }
+            dedup.register(&pattern);
+            on_progress(processed + 1, total);
+        }
+
+        let metadata: Vec<(String, serde_json::Value)> = staged
+            .iter()
+            .map(|indexed| (indexed.pattern.id().to_string(), vector_metadata(&indexed.pattern)))
+            .collect();
+        self.vector_store.write().await.insert_batch(
+            staged
+                .iter()
+                .zip(&metadata)
+                .map(|(indexed, (id, meta))| (id.as_str(), &indexed.description_embedding, meta)),
+        )?;
+
+        let mut indexed = self.patterns.write().await;
+        for pattern in staged {
+            upsert_indexed(&mut indexed, pattern);
+        }
+        drop(indexed);
+        self.dedup = dedup;
+        if changed {
+            self.hot_cache.write().await.clear();
+        }
+
+        Ok(summary)
+    }
+
+    /// Canonical pattern id for an id that may have been collapsed as a duplicate
+    pub fn canonical_id(&self, id: &Uuid) -> Uuid {
+        self.dedup.canonical_id(id)
+    }
+
+    /// Number of duplicate patterns collapsed since the last rebuild
+    pub fn duplicates_collapsed(&self) -> usize {
+        self.dedup.duplicates_collapsed()
+    }
+
+    #[tracing::instrument(name = "add_pattern", skip(self, pattern), fields(pattern_id = %pattern.id()))]
+    async fn index_pattern(&mut self, pattern: Pattern) -> Result<DedupOutcome> {
+        // Check content hash before paying for an embedding
+        let outcome = self.dedup.check(&pattern);
+        if !outcome.needs_embedding() {
+            if let DedupOutcome::Duplicate { canonical_id } = outcome {
+                tracing::debug!(canonical_id = %canonical_id, "Duplicate pattern content collapsed");
+            }
+            return Ok(self.dedup.register(&pattern));
+        }
+
+        let indexed = self.embed_pattern(&pattern).await?;
+
+        // Add to vector store
+        self.vector_store.write().await.insert(
+            &pattern.id().to_string(),
+            &indexed.description_embedding,
+            &vector_metadata(&pattern),
+        )?;
+
+        // Add to in-memory cache (replacing the previous content of a changed pattern)
+        upsert_indexed(&mut *self.patterns.write().await, indexed);
+        if outcome == DedupOutcome::Changed {
+            tracing::debug!("Pattern content changed, re-indexed");
+            self.hot_cache.write().await.clear();
+        }
+
+        Ok(self.dedup.register(&pattern))
+    }
+
+    /// Embed title + content into a fresh IndexedPattern (no usage history)
+    async fn embed_pattern(&self, pattern: &Pattern) -> Result<IndexedPattern> {
+        let embeddings = self.embedder().await?;
         let description = format!(
             "{}\n\n{}",
             pattern.title(),
             pattern.content()
         );
-        let embedding_result = embeddings.embed(&description)?;
-        let embedding = embedding_result.embedding.clone();
+        let embedding_result = embed_checked(embeddings, &description)?;
 
-        // Create indexed pattern
-        let indexed = IndexedPattern {
+        Ok(IndexedPattern {
             pattern: pattern.clone(),
-            description_embedding: embedding.clone(),
+            description_embedding: embedding_result.embedding,
             usage_count: 0,
             last_used: None,
             avg_confidence: None,
             common_domains: vec![],
-        };
-
-        // Add to vector store
-        let mut vector_store = self.vector_store.write().await;
-        let metadata = serde_json::json!({
-            "pattern_id": pattern.id().to_string(),
-            "domain": pattern.metadata().domain.clone().unwrap_or_default(),
-        });
-
-        vector_store.insert(&pattern.id().to_string(), &embedding, &metadata)?;
-
-        // Add to in-memory cache
-        self.patterns.write().await.push(indexed);
-
-        Ok(())
+        })
     }
 
     /**
      * DESIGN DECISION: Rebuild index from pattern library directory
      * WHY: Ensure index stays in sync with pattern library files
      */
+    #[tracing::instrument(skip(self))]
     pub async fn rebuild(&mut self) -> Result<()> {
+        self.rebuild_with_progress(|_, _| {}).await.map(|_| ())
+    }
+
+    /**
+     * DESIGN DECISION: Rebuild with a (processed, total) progress callback
+     * WHY: Rebuilding a large library takes seconds; UIs need feedback while embedding
+     *
+     * Callback runs once per pattern file parsed from the library (0 calls for an
+     * empty library). Returns the import summary for the rebuilt index.
+     */
+    pub async fn rebuild_with_progress<F>(&mut self, on_progress: F) -> Result<ImportSummary>
+    where
+        F: FnMut(usize, usize),
+    {
+        // Load and index all patterns from pattern directory
+        let started = SystemTime::now();
+        let files = self.load_patterns_from_directory().await?;
+        let source_paths = files.iter().map(|(path, pattern)| (path.clone(), *pattern.id())).collect();
+        let patterns = files.into_iter().map(|(_, pattern)| pattern).collect();
+
+        let summary = self.rebuild_at(started, patterns, on_progress).await?;
+        self.source_paths = source_paths;
+        self.persist_source_paths()?;
+        Ok(summary)
+    }
+
+    /**
+     * DESIGN DECISION: Rebuild from patterns supplied by the caller
+     * WHY: Hosts that keep their library in a database (not markdown files) rebuild
+     * from that store without writing it out to pattern_dir first
+     *
+     * Same semantics as rebuild_with_progress: the index is cleared, then every
+     * pattern is imported with (processed, total) reported per pattern.
+     */
+    pub async fn rebuild_from_patterns<F>(&mut self, patterns: Vec<Pattern>, on_progress: F) -> Result<ImportSummary>
+    where
+        F: FnMut(usize, usize),
+    {
+        self.rebuild_at(SystemTime::now(), patterns, on_progress).await
+    }
+
+    /// Clear and re-import; manifest records `started` once the import committed
+    async fn rebuild_at<F>(&mut self, started: SystemTime, patterns: Vec<Pattern>, on_progress: F) -> Result<ImportSummary>
+    where
+        F: FnMut(usize, usize),
+    {
+        // Not initialized while the index is being replaced
+        IndexManifest::remove(&self.manifest_path)?;
+
         // Clear existing index
         self.patterns.write().await.clear();
         self.hot_cache.write().await.clear();
+        self.dedup.clear();
+        self.source_paths.clear();
+        self.missing_content = false;
 
         // Clear vector store
-        let mut vector_store = self.vector_store.write().await;
-        vector_store.clear()?;
+        self.vector_store.write().await.clear()?;
 
-        // Load all patterns from pattern directory
-        let patterns = self.load_patterns_from_directory().await?;
+        let summary = self.import_with_progress(patterns, on_progress).await?;
+        IndexManifest::new(started, summary.imported).write(&self.manifest_path)?;
 
-        // Index each pattern
-        for pattern in patterns {
-            drop(vector_store); // Release lock temporarily
-            self.add_pattern(pattern).await?;
-            vector_store = self.vector_store.write().await; // Re-acquire
+        // A smaller library leaves the old vectors' pages free (non-fatal: the index is built)
+        let vector_store = self.vector_store.read().await;
+        match vector_store.needs_compaction().and_then(|needed| if needed { vector_store.compact() } else { Ok(0) }) {
+            Ok(0) => {}
+            Ok(reclaimed_bytes) => tracing::info!(reclaimed_bytes, "Compacted pattern index vector store"),
+            Err(e) => tracing::warn!(error = %e, "Failed to compact pattern index vector store"),
         }
+        drop(vector_store);
+
+        tracing::info!(
+            patterns = summary.imported,
+            duplicates_collapsed = summary.duplicates_collapsed,
+            "Pattern index rebuilt"
+        );
+        Ok(summary)
+    }
 
+    /**
+     * DESIGN DECISION: Re-index one pattern file instead of rebuilding the library
+     * WHY: rebuild() re-embeds every pattern (tens of seconds past a few hundred);
+     * a watcher reporting one edited file should pay for one embedding
+     *
+     * REASONING CHAIN:
+     * 1. Relative paths resolve against pattern_dir
+     * 2. Parse the file; a file indexed before keeps its pattern id (and usage history)
+     * 3. Changed title/content → re-embed, replace its vector row in place
+     * 4. Same title/content → no embedding; tags/metadata refreshed in memory and vector metadata
+     * 5. New file → embedded and added (or aliased if its content is already indexed)
+     * 6. Content now identical to another pattern → old vector dropped, file aliased
+     *    (files that were duplicates of this one are re-indexed from their own content)
+     * 7. Other patterns, vectors and usage history are untouched
+     *
+     * needs_rebuild() keeps reporting pattern_dir changes after incremental updates:
+     * only a full rebuild proves every file is indexed.
+     *
+     * # Errors
+     *
+     * Io/PatternValidation if the file cannot be read or parsed (index unchanged)
+     */
+    #[tracing::instrument(skip(self), fields(path = %path.display()))]
+    pub async fn update_pattern(&mut self, path: &Path) -> Result<DedupOutcome> {
+        let path = self.source_key(path);
+        let parsed = Pattern::from_file(&path)?;
+        let previous = self.source_paths.get(&path).copied();
+        let pattern = match previous {
+            Some(id) => parsed.with_id(id),
+            None => parsed,
+        };
+        let id = *pattern.id();
+
+        // Was an alias, or becomes one: the file's old entry must not linger
+        if previous.is_some() {
+            let becomes_duplicate = matches!(self.dedup.check(&pattern), DedupOutcome::Duplicate { .. });
+            if becomes_duplicate || self.dedup.canonical_id(&id) != id {
+                let orphans = self.evict(&id).await?;
+                self.reindex_orphans(orphans).await?;
+            }
+        }
+
+        let outcome = match self.dedup.check(&pattern) {
+            DedupOutcome::AlreadyIndexed => {
+                self.refresh_metadata(pattern).await?;
+                DedupOutcome::AlreadyIndexed
+            }
+            _ => self.index_pattern(pattern).await?,
+        };
+        self.source_paths.insert(path, id);
+
+        // Files collapsed into the old content are no longer duplicates of this one
+        if outcome == DedupOutcome::Changed {
+            let orphans = self.dedup.take_aliases(&id);
+            self.reindex_orphans(orphans).await?;
+        }
+
+        self.persist_source_paths()?;
+        Ok(outcome)
+    }
+
+    /**
+     * DESIGN DECISION: Evict one deleted pattern file from the index
+     * WHY: Counterpart of update_pattern for a watcher's "removed" event
+     *
+     * Deletes the pattern's vector row, in-memory entry and hot-cache results.
+     * Files whose content was collapsed into it are re-indexed from disk so their
+     * content stays searchable. Returns false if no pattern was indexed from `path`.
+     */
+    #[tracing::instrument(skip(self), fields(path = %path.display()))]
+    pub async fn remove_pattern_by_path(&mut self, path: &Path) -> Result<bool> {
+        let path = self.source_key(path);
+        let Some(id) = self.source_paths.remove(&path) else {
+            return Ok(false);
+        };
+
+        let orphans = self.evict(&id).await?;
+        self.reindex_orphans(orphans).await?;
+        self.persist_source_paths()?;
+        Ok(true)
+    }
+
+    /// Write source_paths into the manifest (no manifest = never built, nothing to update)
+    fn persist_source_paths(&self) -> Result<()> {
+        if let Some(mut manifest) = IndexManifest::read(&self.manifest_path) {
+            manifest.source_paths = self.source_paths.iter().map(|(path, id)| (path.clone(), *id)).collect();
+            manifest.write(&self.manifest_path)?;
+        }
         Ok(())
     }
 
+    /// Pattern file path as keyed in source_paths (relative paths are under pattern_dir)
+    fn source_key(&self, path: &Path) -> PathBuf {
+        if path.is_absolute() || path.starts_with(&self.pattern_dir) {
+            path.to_path_buf()
+        } else {
+            self.pattern_dir.join(path)
+        }
+    }
+
+    /// Drop one id from vector store, memory, hot cache and dedup; returns orphaned aliases
+    async fn evict(&mut self, id: &Uuid) -> Result<Vec<Uuid>> {
+        self.vector_store.write().await.delete(&id.to_string())?;
+        self.patterns.write().await.retain(|indexed| indexed.pattern.id() != id);
+        self.hot_cache.write().await.retain(|_, cached| cached.pattern.id() != id);
+        Ok(self.dedup.remove(id))
+    }
+
+    /// Index aliases of an evicted pattern again from their own files (if known)
+    async fn reindex_orphans(&mut self, orphans: Vec<Uuid>) -> Result<()> {
+        for orphan in orphans {
+            let Some(path) = self.source_paths.iter().find(|(_, id)| **id == orphan).map(|(path, _)| path.clone()) else {
+                continue;
+            };
+            match Pattern::from_file(&path) {
+                Ok(pattern) => {
+                    self.index_pattern(pattern.with_id(orphan)).await?;
+                }
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Failed to re-index pattern");
+                    self.source_paths.remove(&path);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace tags/metadata of an indexed pattern whose title/content did not change
+    async fn refresh_metadata(&mut self, pattern: Pattern) -> Result<()> {
+        let mut patterns = self.patterns.write().await;
+        if let Some(indexed) = patterns.iter_mut().find(|indexed| indexed.pattern.id() == pattern.id()) {
+            self.vector_store.write().await.insert(
+                &pattern.id().to_string(),
+                &indexed.description_embedding,
+                &vector_metadata(&pattern),
+            )?;
+            indexed.pattern = pattern;
+        }
+        Ok(())
+    }
+
+    /**
+     * DESIGN DECISION: Manual compaction of the vector store (settings maintenance)
+     * WHY: rebuild() compacts only past the free-page threshold; users can reclaim
+     * space on demand. Returns bytes reclaimed.
+     */
+    pub async fn compact(&self) -> Result<u64> {
+        self.vector_store.read().await.compact()
+    }
+
     /**
      * DESIGN DECISION: Record pattern usage for ranking
      * WHY: Recently used patterns should rank higher (recency bias)
      */
     pub async fn record_usage(&self, pattern_id: &str, confidence: f64) -> Result<()> {
+        let pattern_id = self.canonical_usage_id(pattern_id);
         let mut patterns = self.patterns.write().await;
 
         if let Some(indexed) = patterns.iter_mut().find(|p| p.pattern.id().to_string() == pattern_id) {
-            indexed.usage_count += 1;
-            indexed.last_used = Some(Utc::now());
-
-            // Update average confidence
-            if let Some(avg) = indexed.avg_confidence {
-                indexed.avg_confidence = Some((avg + confidence) / 2.0);
-            } else {
-                indexed.avg_confidence = Some(confidence);
-            }
+            Self::apply_usage(indexed, confidence, UsageOutcome::Accepted);
         }
 
         Ok(())
     }
 
+    /**
+     * DESIGN DECISION: Record a burst of usage under one write lock
+     * WHY: An extension applying several suggestions at once paid one async
+     * round-trip and lock acquisition per pattern
+     *
+     * REASONING CHAIN:
+     * 1. Take the pattern write lock once (readers see all entries or none)
+     * 2. Validate each entry: confidence in 0.0..=1.0, pattern present in the index
+     * 3. Malformed entries are reported by position; the rest are still recorded
+     * 4. Unlike record_usage, unknown pattern ids are reported instead of ignored
+     */
+    pub async fn record_usage_batch(&self, entries: &[UsageEntry]) -> UsageBatchReport {
+        let mut report = UsageBatchReport::default();
+        let mut patterns = self.patterns.write().await;
+
+        for (index, entry) in entries.iter().enumerate() {
+            if !(0.0..=1.0).contains(&entry.confidence) {
+                report.errors.push(UsageBatchError {
+                    index,
+                    pattern_id: entry.pattern_id.clone(),
+                    reason: format!("confidence {} outside 0.0..=1.0", entry.confidence),
+                });
+                continue;
+            }
+
+            let pattern_id = self.canonical_usage_id(&entry.pattern_id);
+            match patterns.iter_mut().find(|p| p.pattern.id().to_string() == pattern_id) {
+                Some(indexed) => {
+                    Self::apply_usage(indexed, entry.confidence, entry.outcome.unwrap_or_default());
+                    report.recorded += 1;
+                }
+                None => report.errors.push(UsageBatchError {
+                    index,
+                    pattern_id: entry.pattern_id.clone(),
+                    reason: "pattern not found in index".to_string(),
+                }),
+            }
+        }
+
+        report
+    }
+
+    /// Usage of a collapsed duplicate counts toward its canonical pattern
+    fn canonical_usage_id(&self, pattern_id: &str) -> String {
+        match Uuid::parse_str(pattern_id) {
+            Ok(id) => self.canonical_id(&id).to_string(),
+            Err(_) => pattern_id.to_string(),
+        }
+    }
+
+    /// Fold one usage into a pattern's counters
+    fn apply_usage(indexed: &mut IndexedPattern, confidence: f64, outcome: UsageOutcome) {
+        // Rejected suggestions were shown, not used: no usage/recency boost, zero confidence
+        let confidence = match outcome {
+            UsageOutcome::Accepted => {
+                indexed.usage_count += 1;
+                indexed.last_used = Some(Utc::now());
+                confidence
+            }
+            UsageOutcome::Rejected => 0.0,
+        };
+
+        // Update average confidence
+        if let Some(avg) = indexed.avg_confidence {
+            indexed.avg_confidence = Some((avg + confidence) / 2.0);
+        } else {
+            indexed.avg_confidence = Some(confidence);
+        }
+    }
+
     /**
      * DESIGN DECISION: Get pattern statistics
      * WHY: Useful for analytics, debugging, optimization
@@ -364,8 +994,10 @@ impl PatternIndex {
     /**
      * DESIGN DECISION: Load patterns from directory recursively
      * WHY: Support nested pattern organization (by domain, type, etc.)
+     *
+     * Returns (file path, parsed pattern) pairs.
      */
-    async fn load_patterns_from_directory(&self) -> Result<Vec<Pattern>> {
+    async fn load_patterns_from_directory(&self) -> Result<Vec<(PathBuf, Pattern)>> {
         let mut patterns = Vec::new();
 
         // Read all .md files in pattern directory
@@ -379,8 +1011,8 @@ impl PatternIndex {
             if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("md") {
                 // Parse pattern from markdown file
                 match Pattern::from_file(&path) {
-                    Ok(pattern) => patterns.push(pattern),
-                    Err(e) => eprintln!("Warning: Failed to parse pattern {:?}: {}", path, e),
+                    Ok(pattern) => patterns.push((path, pattern)),
+                    Err(e) => tracing::warn!(path = %path.display(), error = %e, "Failed to parse pattern"),
                 }
             }
         }
@@ -389,8 +1021,113 @@ impl PatternIndex {
     }
 }
 
+/// Embed `text`, rejecting a vector whose length differs from embedder.dimension()
+fn embed_checked(embedder: &dyn Embedder, text: &str) -> Result<EmbeddingResult> {
+    let result = embedder.embed(text)?;
+    if result.embedding.len() != embedder.dimension() {
+        return Err(Error::ValidationError(format!(
+            "Embedder returned a {}-dim vector, expected {}",
+            result.embedding.len(),
+            embedder.dimension()
+        )));
+    }
+    Ok(result)
+}
+
+/// Vector store metadata for a pattern (filterable by domain)
+fn vector_metadata(pattern: &Pattern) -> serde_json::Value {
+    serde_json::json!({
+        "pattern_id": pattern.id().to_string(),
+        "domain": pattern.metadata().domain.clone().unwrap_or_default(),
+        "pattern": pattern,
+    })
+}
+
+/// Insert, or replace the entry with the same id (keeps its usage history)
+fn upsert_indexed(patterns: &mut Vec<IndexedPattern>, mut indexed: IndexedPattern) {
+    match patterns.iter_mut().find(|p| p.pattern.id() == indexed.pattern.id()) {
+        Some(existing) => {
+            indexed.usage_count = existing.usage_count;
+            indexed.last_used = existing.last_used;
+            indexed.avg_confidence = existing.avg_confidence;
+            indexed.common_domains = std::mem::take(&mut existing.common_domains);
+            *existing = indexed;
+        }
+        None => patterns.push(indexed),
+    }
+}
+
+/**
+ * DESIGN DECISION: Restore the in-memory patterns from the vector store on open
+ * WHY: search_by_intent resolves vector hits against the in-memory set; a reopened
+ * index reported itself built and current but found nothing until rebuilt
+ *
+ * Rows store the full pattern in their metadata (see vector_metadata). Usage history
+ * is not persisted and starts over. Returns (patterns, dedup registry, whether any row
+ * had no stored pattern).
+ */
+fn load_indexed(vector_store: &SqliteVectorStore) -> Result<(Vec<IndexedPattern>, ContentDedup, bool)> {
+    let mut patterns = Vec::new();
+    let mut dedup = ContentDedup::new();
+    let mut missing_content = false;
+
+    for (id, embedding, metadata) in vector_store.entries()? {
+        let pattern = metadata
+            .get("pattern")
+            .and_then(|pattern| serde_json::from_value::<Pattern>(pattern.clone()).ok());
+        let Some(pattern) = pattern else {
+            tracing::warn!(pattern_id = %id, "Indexed pattern has no stored content; rebuild the index");
+            missing_content = true;
+            continue;
+        };
+
+        dedup.register(&pattern);
+        patterns.push(IndexedPattern {
+            pattern,
+            description_embedding: embedding,
+            usage_count: 0,
+            last_used: None,
+            avg_confidence: None,
+            common_domains: vec![],
+        });
+    }
+
+    Ok((patterns, dedup, missing_content))
+}
+
+/**
+ * DESIGN DECISION: Restore the file → id mapping from the manifest on open
+ * WHY: update_pattern must keep a file's id (and replace its vector) after a restart,
+ * and remove_pattern_by_path must still find the file's pattern
+ *
+ * Files indexed as duplicates have no vector row: their content is read again to
+ * re-register the alias (skipped if the file changed since; needs_rebuild reports it).
+ */
+fn load_source_paths(manifest_path: &Path, dedup: &mut ContentDedup) -> HashMap<PathBuf, Uuid> {
+    let source_paths: HashMap<PathBuf, Uuid> = IndexManifest::read(manifest_path)
+        .map(|manifest| manifest.source_paths.into_iter().collect())
+        .unwrap_or_default();
+
+    for (path, id) in &source_paths {
+        if dedup.contains(id) {
+            continue;
+        }
+        match Pattern::from_file(path) {
+            Ok(pattern) => {
+                let pattern = pattern.with_id(*id);
+                if matches!(dedup.check(&pattern), DedupOutcome::Duplicate { .. }) {
+                    dedup.register(&pattern);
+                }
+            }
+            Err(e) => tracing::debug!(path = %path.display(), error = %e, "Pattern file not readable"),
+        }
+    }
+
+    source_paths
+}
+
 /// Pattern index statistics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatternIndexStatistics {
     pub total_patterns: usize,
     pub total_usage: usize,
@@ -398,6 +1135,45 @@ pub struct PatternIndexStatistics {
     pub most_used: Option<String>,
 }
 
+/// What the user did with a suggested pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageOutcome {
+    /// Suggestion applied (default)
+    #[default]
+    Accepted,
+    /// Suggestion dismissed
+    Rejected,
+}
+
+/// One entry of PatternIndex::record_usage_batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageEntry {
+    pub pattern_id: String,
+    pub confidence: f64,
+    /// Defaults to Accepted
+    #[serde(default)]
+    pub outcome: Option<UsageOutcome>,
+}
+
+/// Result of PatternIndex::record_usage_batch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageBatchReport {
+    /// Entries applied to the index
+    pub recorded: usize,
+    /// Entries skipped, in batch order
+    pub errors: Vec<UsageBatchError>,
+}
+
+/// Batch entry that could not be recorded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageBatchError {
+    /// Position of the entry in the batch
+    pub index: usize,
+    pub pattern_id: String,
+    pub reason: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -474,4 +1250,556 @@ mod tests {
         let stats = index.get_statistics().await;
         assert_eq!(stats.total_usage, 1);
     }
+
+    #[tokio::test]
+    async fn test_failed_model_load_is_retried() {
+        let pattern_dir = PathBuf::from("./docs/patterns");
+        let data_dir = tempfile::tempdir().unwrap();
+        let index = PatternIndex::new(pattern_dir, data_dir.path().to_path_buf()).unwrap();
+
+        // Model is not loaded at construction (missing model is not a construction error)
+        assert_eq!(index.embedding_initializations(), 0);
+
+        // No model in data_dir: warm-up reports the load error and caches nothing
+        assert!(index.warm_up().await.is_err());
+        assert_eq!(index.embedding_initializations(), 1);
+        assert!(!index.is_warm());
+
+        // Each later call retries the load (a model added later would be picked up)
+        assert!(index.search_by_intent("OAuth2 with PKCE", None).await.is_err());
+        assert_eq!(index.embedding_initializations(), 2);
+    }
+
+    /**
+     * Test: Embedding precision is configurable on the index
+     */
+    #[tokio::test]
+    async fn test_set_embedding_config() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+        assert_eq!(index.embedding_config().precision, crate::EmbeddingPrecision::Fp32);
+
+        index.set_embedding_config(EmbeddingConfig { precision: crate::EmbeddingPrecision::Int8, ..Default::default() });
+        assert_eq!(index.embedding_config().precision, crate::EmbeddingPrecision::Int8);
+
+        // Model loads lazily with the configured precision
+        assert!(!index.is_warm());
+        let _ = index.warm_up().await;
+        assert_eq!(index.embedding_initializations(), 1);
+    }
+
+    /**
+     * Test: Batch usage updates statistics and reports malformed entries without aborting
+     */
+    #[tokio::test]
+    async fn test_record_usage_batch() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedder(Box::new(WordHashEmbedder));
+
+        let retry = Pattern::new("Retry With Backoff".to_string(), "Exponential backoff with jitter".to_string(), vec![]);
+        let oauth = Pattern::new("OAuth2 PKCE".to_string(), "Use PKCE for public clients".to_string(), vec![]);
+        let (retry_id, oauth_id) = (retry.id().to_string(), oauth.id().to_string());
+        index.add_pattern(retry).await.unwrap();
+        index.add_pattern(oauth).await.unwrap();
+
+        let entry = |pattern_id: &str, confidence: f64, outcome: Option<UsageOutcome>| UsageEntry {
+            pattern_id: pattern_id.to_string(),
+            confidence,
+            outcome,
+        };
+        let report = index.record_usage_batch(&[
+            entry(&retry_id, 0.9, None),
+            entry(&oauth_id, 1.7, None),
+            entry(&retry_id, 0.8, Some(UsageOutcome::Accepted)),
+            entry("not-a-pattern", 0.5, None),
+            entry(&oauth_id, 0.6, None),
+            entry(&oauth_id, 0.4, Some(UsageOutcome::Rejected)),
+        ]).await;
+
+        assert_eq!(report.recorded, 4);
+        let failed: Vec<usize> = report.errors.iter().map(|e| e.index).collect();
+        assert_eq!(failed, vec![1, 3]);
+
+        let stats = index.get_statistics().await;
+        assert_eq!(stats.total_usage, 3);
+        assert_eq!(stats.most_used, Some(retry_id));
+    }
+
+    /**
+     * Test: Workspace root resolves pattern directory and creates data directory
+     */
+    #[tokio::test]
+    async fn test_new_in_workspace() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(PatternIndex::new_in_workspace(root.path()).is_err());
+
+        std::fs::create_dir_all(root.path().join("docs/patterns")).unwrap();
+        let index = PatternIndex::new_in_workspace(root.path()).unwrap();
+
+        assert_eq!(index.pattern_dir(), root.path().join("docs/patterns").canonicalize().unwrap());
+        assert!(root.path().join("data/pattern_index.sqlite").exists());
+    }
+
+    /**
+     * Test: Fresh index is uninitialized; built index is current until a pattern file changes
+     */
+    #[tokio::test]
+    async fn test_initialized_and_stale_after_pattern_change() {
+        let pattern_dir = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let pattern_file = pattern_dir.path().join("retry.md");
+        std::fs::write(&pattern_file, "# Retry").unwrap();
+
+        let mut index = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedding_config(EmbeddingConfig { keyword_fallback: true, ..Default::default() });
+        assert!(!index.is_initialized());
+        assert!(index.needs_rebuild().unwrap());
+
+        let patterns = vec![Pattern::new("Retry With Backoff".to_string(), "Exponential backoff".to_string(), vec![])];
+        index.rebuild_from_patterns(patterns, |_, _| {}).await.unwrap();
+        assert!(index.is_initialized());
+        assert!(!index.needs_rebuild().unwrap());
+
+        // Survives reopening the same data dir, patterns included
+        let mut reopened = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        reopened.set_embedding_config(EmbeddingConfig { keyword_fallback: true, ..Default::default() });
+        assert!(reopened.is_initialized());
+        assert!(!reopened.needs_rebuild().unwrap());
+        let matches = reopened.search_by_intent("Exponential backoff", None).await.unwrap();
+        assert_eq!(matches[0].pattern.title(), "Retry With Backoff");
+
+        // Pattern edited after the build (future mtime: no dependence on clock resolution)
+        std::fs::File::options()
+            .write(true)
+            .open(&pattern_file)
+            .unwrap()
+            .set_modified(SystemTime::now() + std::time::Duration::from_secs(10))
+            .unwrap();
+        assert!(index.is_initialized());
+        assert!(index.needs_rebuild().unwrap());
+    }
+
+    /**
+     * Test: Vectors stored without their pattern (older index) need a rebuild
+     */
+    #[tokio::test]
+    async fn test_index_without_stored_patterns_needs_rebuild() {
+        let pattern_dir = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut store = SqliteVectorStore::new(data_dir.path().join("pattern_index.sqlite")).unwrap();
+        let id = Uuid::new_v4().to_string();
+        store.insert(&id, &vec![0.5; 8], &serde_json::json!({ "pattern_id": id, "domain": "" })).unwrap();
+        drop(store);
+        IndexManifest::new(SystemTime::now() + std::time::Duration::from_secs(10), 1)
+            .write(&data_dir.path().join(manifest::MANIFEST_FILE_NAME))
+            .unwrap();
+
+        let index = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+
+        assert!(!index.is_initialized());
+        assert!(index.needs_rebuild().unwrap());
+    }
+
+    /**
+     * Test: Missing model falls back to keyword matching when enabled
+     */
+    #[tokio::test]
+    async fn test_keyword_fallback_without_model() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+
+        // Without fallback the load error names the missing model
+        let err = index.warm_up().await.unwrap_err().to_string();
+        assert!(err.contains("embedding model not found at"), "{}", err);
+
+        index.set_embedding_config(EmbeddingConfig { keyword_fallback: true, ..Default::default() });
+        index.add_pattern(Pattern::new("Retry With Backoff".to_string(), "Exponential backoff with jitter".to_string(), vec![])).await.unwrap();
+        index.add_pattern(Pattern::new("OAuth2 PKCE".to_string(), "Use PKCE for public clients".to_string(), vec![])).await.unwrap();
+
+        let matches = index.search_by_intent("PKCE for OAuth2 public clients", None).await.unwrap();
+        assert_eq!(matches[0].pattern.title(), "OAuth2 PKCE");
+        assert!(index.is_warm());
+    }
+
+    /**
+     * Test: Selected domains raise their patterns' rank without filtering others out
+     */
+    #[tokio::test]
+    async fn test_selected_domains_boost_without_filtering() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedding_config(EmbeddingConfig { keyword_fallback: true, ..Default::default() });
+
+        let devops = Pattern::builder()
+            .title("Retry failed deployments")
+            .content("Retry failed deployment steps with backoff")
+            .domain("devops")
+            .build()
+            .unwrap();
+        let rust = Pattern::builder()
+            .title("Retry with backoff")
+            .content("Exponential backoff for failed futures")
+            .domain("rust")
+            .build()
+            .unwrap();
+        index.add_pattern(devops).await.unwrap();
+        index.add_pattern(rust).await.unwrap();
+
+        let query = "retry failed calls with backoff";
+        let titles = |matches: &[PatternMatch]| {
+            matches.iter().map(|m| m.pattern.title().to_string()).collect::<Vec<_>>()
+        };
+
+        let plain = index.search_by_intent(query, None).await.unwrap();
+        assert_eq!(titles(&plain), vec!["Retry failed deployments", "Retry with backoff"]);
+
+        let context = SearchContext {
+            selected_domains: vec!["Rust".to_string(), "python".to_string()],
+            ..Default::default()
+        };
+        let boosted = index.search_by_intent(query, Some(&context)).await.unwrap();
+        assert_eq!(titles(&boosted), vec!["Retry with backoff", "Retry failed deployments"]);
+        assert_eq!(boosted[0].context_boost, Some(0.10));
+        assert_eq!(boosted[1].context_boost, None);
+    }
+
+    /**
+     * Test: Duplicate content is collapsed before embedding (no model needed)
+     */
+    #[tokio::test]
+    async fn test_add_pattern_collapses_duplicate_content() {
+        let pattern_dir = PathBuf::from("./docs/patterns");
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(pattern_dir, data_dir.path().to_path_buf()).unwrap();
+
+        let canonical = Pattern::new(
+            "Retry with backoff".to_string(),
+            "Exponential backoff with jitter".to_string(),
+            vec![],
+        );
+        let duplicate = Pattern::new(
+            "Retry with backoff".to_string(),
+            "Exponential  backoff\nwith jitter".to_string(),
+            vec!["resilience".to_string()],
+        );
+        // Simulate canonical already indexed (embedding model unavailable in tests)
+        index.dedup.register(&canonical);
+
+        index.add_pattern(duplicate.clone()).await.unwrap();
+
+        assert_eq!(index.duplicates_collapsed(), 1);
+        assert_eq!(index.canonical_id(duplicate.id()), *canonical.id());
+        assert_eq!(index.embedding_initializations(), 0);
+        assert!(index.patterns.read().await.is_empty());
+    }
+
+    /// Deterministic bag-of-words embedder (no ONNX model needed)
+    struct WordHashEmbedder;
+
+    impl Embedder for WordHashEmbedder {
+        fn dimension(&self) -> usize {
+            crate::EMBEDDING_DIM
+        }
+
+        fn embed(&self, text: &str) -> Result<crate::EmbeddingResult> {
+            let mut embedding = vec![0.0f32; crate::EMBEDDING_DIM];
+            for word in text.split_whitespace() {
+                let bucket = word.to_lowercase().bytes().fold(0usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+                embedding[bucket % crate::EMBEDDING_DIM] += 1.0;
+            }
+            Ok(crate::EmbeddingResult {
+                embedding,
+                text: text.to_string(),
+                duration_ms: 0,
+                token_count: text.split_whitespace().count(),
+            })
+        }
+    }
+
+    /**
+     * Test: Injected embedder rebuilds from caller-supplied patterns and serves search
+     */
+    #[tokio::test]
+    async fn test_rebuild_from_patterns_with_injected_embedder() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedder(Box::new(WordHashEmbedder));
+        index.set_embedding_config(EmbeddingConfig { precision: crate::EmbeddingPrecision::Int8, ..Default::default() });
+
+        let patterns = vec![
+            Pattern::new("Retry With Backoff".to_string(), "Exponential backoff with jitter".to_string(), vec![]),
+            Pattern::new("OAuth2 PKCE".to_string(), "Use PKCE for public clients".to_string(), vec![]),
+            Pattern::new("Retry With Backoff".to_string(), "Exponential backoff with jitter".to_string(), vec![]),
+        ];
+
+        let mut events = Vec::new();
+        let summary = index
+            .rebuild_from_patterns(patterns, |processed, total| events.push((processed, total)))
+            .await
+            .unwrap();
+
+        assert_eq!(events, vec![(1, 3), (2, 3), (3, 3)]);
+        assert_eq!(summary.imported, 2);
+        assert_eq!(summary.duplicates_collapsed, 1);
+        assert_eq!(index.get_statistics().await.total_patterns, 2);
+        assert_eq!(index.embedding_initializations(), 0);
+
+        let matches = index.search_by_intent("OAuth2 PKCE for public clients", None).await.unwrap();
+        assert_eq!(matches[0].pattern.title(), "OAuth2 PKCE");
+    }
+
+    /// Embedder producing `produces`-dim vectors while claiming `reports` dimensions
+    struct SizedEmbedder {
+        produces: usize,
+        reports: usize,
+    }
+
+    impl Embedder for SizedEmbedder {
+        fn dimension(&self) -> usize {
+            self.reports
+        }
+
+        fn embed(&self, text: &str) -> Result<crate::EmbeddingResult> {
+            let mut result = WordHashEmbedder.embed(text)?;
+            result.embedding.resize(self.produces, 0.0);
+            Ok(result)
+        }
+    }
+
+    /**
+     * Test: Switching to an embedder of another dimension fails until the index is rebuilt
+     */
+    #[tokio::test]
+    async fn test_embedder_dimension_mismatch_detected() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedder(Box::new(WordHashEmbedder));
+        let patterns = vec![
+            Pattern::new("Retry With Backoff".to_string(), "Exponential backoff with jitter".to_string(), vec![]),
+            Pattern::new("OAuth2 PKCE".to_string(), "Use PKCE for public clients".to_string(), vec![]),
+        ];
+        index.rebuild_from_patterns(patterns.clone(), |_, _| {}).await.unwrap();
+
+        // Provider with 768-dim vectors against a 384-dim index
+        index.set_embedder(Box::new(SizedEmbedder { produces: 768, reports: 768 }));
+        let err = index.search_by_intent("retry", None).await.unwrap_err();
+        assert!(matches!(&err, Error::ValidationError(message) if message.contains("384-dim")), "{:?}", err);
+        let extra = Pattern::new("Debounce Input".to_string(), "Delay handler until typing stops".to_string(), vec![]);
+        assert!(index.add_pattern(extra).await.is_err());
+
+        // Rebuild re-embeds everything with the new provider
+        index.rebuild_from_patterns(patterns.clone(), |_, _| {}).await.unwrap();
+        assert!(!index.search_by_intent("retry", None).await.unwrap().is_empty());
+
+        // Embedder whose vectors do not match its own dimension()
+        index.set_embedder(Box::new(SizedEmbedder { produces: 384, reports: 768 }));
+        let err = index.search_by_intent("retry", None).await.unwrap_err();
+        assert!(matches!(&err, Error::ValidationError(message) if message.contains("expected 768")), "{:?}", err);
+    }
+
+    /// WordHashEmbedder that counts embed calls
+    struct CountingEmbedder(AtomicUsize);
+
+    impl Embedder for CountingEmbedder {
+        fn dimension(&self) -> usize {
+            crate::EMBEDDING_DIM
+        }
+
+        fn embed(&self, text: &str) -> Result<crate::EmbeddingResult> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            WordHashEmbedder.embed(text)
+        }
+    }
+
+    /**
+     * Test: Re-adding identical content is a no-op; edited content re-embeds in place
+     */
+    #[tokio::test]
+    async fn test_add_pattern_skips_unchanged_content() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut index = PatternIndex::new(PathBuf::from("./docs/patterns"), data_dir.path().to_path_buf()).unwrap();
+        let embedder = Arc::new(CountingEmbedder(AtomicUsize::new(0)));
+        index.set_embedder(Box::new(embedder.clone()));
+
+        let pattern = Pattern::new("Retry With Backoff".to_string(), "Exponential backoff with jitter".to_string(), vec![]);
+
+        assert_eq!(index.add_pattern(pattern.clone()).await.unwrap(), DedupOutcome::Unique);
+        assert_eq!(index.add_pattern(pattern.clone()).await.unwrap(), DedupOutcome::AlreadyIndexed);
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 1);
+        assert_eq!(index.get_statistics().await.total_patterns, 1);
+
+        index.record_usage(&pattern.id().to_string(), 0.9).await.unwrap();
+        let edited = pattern.revise("Retry With Backoff", "Capped exponential backoff with full jitter").unwrap();
+
+        assert_eq!(index.add_pattern(edited).await.unwrap(), DedupOutcome::Changed);
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 2);
+        assert_eq!(index.get_statistics().await.total_patterns, 1);
+
+        let patterns = index.patterns.read().await;
+        assert_eq!(patterns[0].pattern.content(), "Capped exponential backoff with full jitter");
+        assert_eq!(patterns[0].usage_count, 1);
+    }
+
+    /**
+     * Test: Editing, adding and deleting one file touches only that pattern's embedding
+     */
+    #[tokio::test]
+    async fn test_update_and_remove_pattern_by_path() {
+        let pattern_dir = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let file = |name: &str| pattern_dir.path().join(name);
+        std::fs::write(file("retry.md"), "# Retry With Backoff\n\nExponential backoff with jitter").unwrap();
+        std::fs::write(file("oauth.md"), "# OAuth2 PKCE\n\nUse PKCE for public clients").unwrap();
+        std::fs::write(file("cache.md"), "# Cache Aside\n\nRead through cache with TTL").unwrap();
+
+        let mut index = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        let embedder = Arc::new(CountingEmbedder(AtomicUsize::new(0)));
+        index.set_embedder(Box::new(embedder.clone()));
+        index.rebuild().await.unwrap();
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 3);
+
+        let retry_id = index.source_paths[&file("retry.md")];
+        index.record_usage(&retry_id.to_string(), 0.9).await.unwrap();
+
+        // Edited file: one embedding, same id, usage kept
+        std::fs::write(file("retry.md"), "# Retry With Backoff\n\nLinear backoff capped at five attempts").unwrap();
+        assert_eq!(index.update_pattern(&file("retry.md")).await.unwrap(), DedupOutcome::Changed);
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 4);
+        let stats = index.get_statistics().await;
+        assert_eq!(stats.total_patterns, 3);
+        assert_eq!(stats.most_used, Some(retry_id.to_string()));
+        let matches = index.search_by_intent("Linear backoff capped at five attempts", None).await.unwrap();
+        assert_eq!(*matches[0].pattern.id(), retry_id);
+
+        // Untouched content (relative path): no embedding
+        assert_eq!(index.update_pattern(Path::new("oauth.md")).await.unwrap(), DedupOutcome::AlreadyIndexed);
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 5); // + search query only
+
+        // New file
+        std::fs::write(file("queue.md"), "# Work Queue\n\nBounded channel between producers").unwrap();
+        assert_eq!(index.update_pattern(&file("queue.md")).await.unwrap(), DedupOutcome::Unique);
+        assert_eq!(index.get_statistics().await.total_patterns, 4);
+
+        // Deleted file: vector row and cached result evicted
+        index.search_by_intent("Read through cache with TTL", None).await.unwrap();
+        std::fs::remove_file(file("cache.md")).unwrap();
+        assert!(index.remove_pattern_by_path(&file("cache.md")).await.unwrap());
+        assert!(!index.remove_pattern_by_path(&file("cache.md")).await.unwrap());
+
+        let stats = index.get_statistics().await;
+        assert_eq!(stats.total_patterns, 3);
+        assert_eq!(index.vector_store.read().await.count().unwrap(), 3);
+        assert!(index.hot_cache.read().await.values().all(|cached| cached.pattern.title() != "Cache Aside"));
+        assert_eq!(stats.cached_patterns, index.hot_cache.read().await.len());
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 7); // + new file + search query
+    }
+
+    /**
+     * Test: Removing a file keeps the content of its duplicates searchable
+     */
+    #[tokio::test]
+    async fn test_remove_canonical_reindexes_duplicate_file() {
+        let pattern_dir = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let first = pattern_dir.path().join("retry.md");
+        let copy = pattern_dir.path().join("retry-copy.md");
+        std::fs::write(&first, "# Retry\n\nExponential backoff").unwrap();
+        std::fs::write(&copy, "# Retry\n\nExponential backoff").unwrap();
+
+        let mut index = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedder(Box::new(WordHashEmbedder));
+        index.rebuild().await.unwrap();
+        assert_eq!(index.duplicates_collapsed(), 1);
+
+        let canonical = index.patterns.read().await[0].pattern.id().to_owned();
+        let removed = if index.source_paths[&first] == canonical { &first } else { &copy };
+        std::fs::remove_file(removed).unwrap();
+        index.remove_pattern_by_path(removed).await.unwrap();
+
+        assert_eq!(index.duplicates_collapsed(), 0);
+        assert_eq!(index.get_statistics().await.total_patterns, 1);
+        assert_ne!(*index.patterns.read().await[0].pattern.id(), canonical);
+        assert_eq!(index.vector_store.read().await.count().unwrap(), 1);
+    }
+
+    /**
+     * Test: A reopened index keeps each file's pattern id for update and removal
+     */
+    #[tokio::test]
+    async fn test_source_paths_survive_reopen() {
+        let pattern_dir = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let file = |name: &str| pattern_dir.path().join(name);
+        std::fs::write(file("retry.md"), "# Retry\n\nExponential backoff").unwrap();
+        std::fs::write(file("retry-copy.md"), "# Retry\n\nExponential backoff").unwrap();
+        std::fs::write(file("oauth.md"), "# OAuth2 PKCE\n\nUse PKCE for public clients").unwrap();
+
+        let mut index = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        index.set_embedder(Box::new(WordHashEmbedder));
+        index.rebuild().await.unwrap();
+        let source_paths = index.source_paths.clone();
+        drop(index);
+
+        let mut reopened = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        reopened.set_embedder(Box::new(WordHashEmbedder));
+        assert_eq!(reopened.source_paths, source_paths);
+        assert_eq!(reopened.duplicates_collapsed(), 1);
+
+        // Edited file replaces its own vector instead of adding a second pattern
+        let oauth_id = source_paths[&file("oauth.md")];
+        std::fs::write(file("oauth.md"), "# OAuth2 PKCE\n\nPKCE with S256 challenges").unwrap();
+        assert_eq!(reopened.update_pattern(&file("oauth.md")).await.unwrap(), DedupOutcome::Changed);
+        assert_eq!(reopened.source_paths[&file("oauth.md")], oauth_id);
+        assert_eq!(reopened.vector_store.read().await.count().unwrap(), 2);
+
+        // Removing the canonical file keeps the duplicate's content indexed
+        let canonical = reopened.canonical_id(&source_paths[&file("retry-copy.md")]);
+        let removed = if source_paths[&file("retry.md")] == canonical { file("retry.md") } else { file("retry-copy.md") };
+        std::fs::remove_file(&removed).unwrap();
+        assert!(reopened.remove_pattern_by_path(&removed).await.unwrap());
+        assert_eq!(reopened.get_statistics().await.total_patterns, 2);
+        assert_eq!(reopened.vector_store.read().await.count().unwrap(), 2);
+
+        // Mapping written back: a second reopen no longer knows the removed file
+        drop(reopened);
+        let reopened = PatternIndex::new(pattern_dir.path().to_path_buf(), data_dir.path().to_path_buf()).unwrap();
+        assert!(!reopened.source_paths.contains_key(&removed));
+        assert_eq!(reopened.source_paths.len(), 2);
+    }
+
+    /// Records the name of every span created while installed
+    struct SpanRecorder(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanRecorder {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            _id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            self.0.lock().unwrap().push(attrs.metadata().name().to_string());
+        }
+    }
+
+    /**
+     * Test: search_by_intent emits a tracing span
+     * VALIDATION: Span recorded even when embedding model unavailable (span wraps whole call)
+     */
+    #[tokio::test]
+    async fn test_search_emits_tracing_span() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let spans = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry().with(SpanRecorder(spans.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let pattern_dir = PathBuf::from("./docs/patterns");
+        let data_dir = tempfile::tempdir().unwrap();
+        let index = PatternIndex::new(pattern_dir, data_dir.path().to_path_buf()).unwrap();
+
+        let _ = index.search_by_intent("OAuth2 with PKCE", None).await;
+
+        assert!(spans.lock().unwrap().iter().any(|name| name == "search_by_intent"));
+    }
 }
@@ -0,0 +1,243 @@
+/**
+ * Content-Addressed Deduplication (AI-005 Submodule)
+ *
+ * DESIGN DECISION: Detect duplicate patterns by SHA256 of normalized content at insert time
+ * WHY: Importing/syncing from several sources yields identical patterns under different ids,
+ * wasting storage and returning the same answer twice in search results
+ *
+ * REASONING CHAIN:
+ * 1. Hash normalized title + content (content_addressing::pattern_content_hash)
+ * 2. First pattern with a given hash becomes canonical
+ * 3. Later patterns with the same hash are skipped and recorded as aliases
+ * 4. Aliases resolve to the canonical id (callers holding the old id still find it)
+ * 5. Collapsed count reported to importers
+ * 6. Re-adding an id with the same hash is a no-op; with a new hash it is a change
+ *    (re-embed and replace, never a second vector for the same id)
+ *
+ * PATTERN: Pattern-CONTEXT-002 (Content-Addressable Context System)
+ * PERFORMANCE: One SHA256 per insert (<1ms), O(1) lookup
+ * RELATED: PatternIndex::add_pattern, storage/postgres.rs (desktop equivalent)
+ */
+
+use crate::content_addressing::pattern_content_hash;
+use crate::Pattern;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Result of checking a pattern against already-indexed content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupOutcome {
+    /// New content; pattern is now canonical for its hash
+    Unique,
+    /// Same id already indexed with this content (re-add, nothing to store)
+    AlreadyIndexed,
+    /// Identical content already indexed under another id
+    Duplicate { canonical_id: Uuid },
+    /// Same id already indexed with different content (replace its vector)
+    Changed,
+}
+
+impl DedupOutcome {
+    /// Whether the pattern needs an embedding (new or changed content)
+    pub fn needs_embedding(&self) -> bool {
+        matches!(self, DedupOutcome::Unique | DedupOutcome::Changed)
+    }
+}
+
+/// Counts returned from a bulk import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Patterns stored
+    pub imported: usize,
+    /// Patterns skipped because their content was already indexed
+    pub duplicates_collapsed: usize,
+}
+
+/**
+ * Content hash → canonical id registry with alias tracking
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ContentDedup {
+    canonical_by_hash: HashMap<String, Uuid>,
+    hash_by_id: HashMap<Uuid, String>,
+    aliases: HashMap<Uuid, Uuid>,
+}
+
+impl ContentDedup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify a pattern without recording it
+    pub fn check(&self, pattern: &Pattern) -> DedupOutcome {
+        let hash = pattern_content_hash(pattern.title(), pattern.content());
+
+        match self.canonical_by_hash.get(&hash) {
+            Some(canonical) if canonical == pattern.id() => DedupOutcome::AlreadyIndexed,
+            Some(canonical) => DedupOutcome::Duplicate { canonical_id: *canonical },
+            None if self.hash_by_id.contains_key(pattern.id()) => DedupOutcome::Changed,
+            None => DedupOutcome::Unique,
+        }
+    }
+
+    /**
+     * DESIGN DECISION: Register on first sight, alias on repeat
+     * WHY: PatternIndex calls check() before embedding and register() after the
+     * pattern is stored, so a failed insert never leaves a dangling canonical hash
+     */
+    pub fn register(&mut self, pattern: &Pattern) -> DedupOutcome {
+        let outcome = self.check(pattern);
+
+        match outcome {
+            DedupOutcome::Unique | DedupOutcome::Changed => {
+                let hash = pattern_content_hash(pattern.title(), pattern.content());
+                if let Some(previous) = self.hash_by_id.insert(*pattern.id(), hash.clone()) {
+                    self.canonical_by_hash.remove(&previous);
+                }
+                self.canonical_by_hash.insert(hash, *pattern.id());
+            }
+            DedupOutcome::Duplicate { canonical_id } => {
+                self.aliases.insert(*pattern.id(), canonical_id);
+            }
+            DedupOutcome::AlreadyIndexed => {}
+        }
+
+        outcome
+    }
+
+    /// Canonical id for a pattern id (itself if not an alias)
+    pub fn canonical_id(&self, id: &Uuid) -> Uuid {
+        self.aliases.get(id).copied().unwrap_or(*id)
+    }
+
+    /// Whether `id` is registered as the canonical id of indexed content
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.hash_by_id.contains_key(id)
+    }
+
+    /// Number of distinct contents registered
+    pub fn len(&self) -> usize {
+        self.canonical_by_hash.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.canonical_by_hash.is_empty()
+    }
+
+    /// Number of duplicate ids collapsed into a canonical pattern
+    pub fn duplicates_collapsed(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /**
+     * DESIGN DECISION: Forget one id (its pattern file was removed or re-pointed)
+     * WHY: Its content hash must be free for later patterns, and ids aliased to it
+     * no longer resolve to stored content
+     *
+     * Returns the ids that were aliases of `id`; they are unindexed now and the
+     * caller decides whether to index them again.
+     */
+    pub fn remove(&mut self, id: &Uuid) -> Vec<Uuid> {
+        self.aliases.remove(id);
+        if let Some(hash) = self.hash_by_id.remove(id) {
+            if self.canonical_by_hash.get(&hash) == Some(id) {
+                self.canonical_by_hash.remove(&hash);
+            }
+        }
+        self.take_aliases(id)
+    }
+
+    /// Unregister and return the ids aliased to `id` (its content changed or is gone)
+    pub fn take_aliases(&mut self, id: &Uuid) -> Vec<Uuid> {
+        let orphans: Vec<Uuid> = self
+            .aliases
+            .iter()
+            .filter(|(_, canonical)| *canonical == id)
+            .map(|(alias, _)| *alias)
+            .collect();
+        for alias in &orphans {
+            self.aliases.remove(alias);
+        }
+        orphans
+    }
+
+    pub fn clear(&mut self) {
+        self.canonical_by_hash.clear();
+        self.hash_by_id.clear();
+        self.aliases.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Test: Identical content under two ids stores one, aliases the other
+     */
+    #[test]
+    fn test_identical_content_collapsed() {
+        let first = Pattern::new(
+            "OAuth2 PKCE flow".to_string(),
+            "Use PKCE for public clients".to_string(),
+            vec![],
+        );
+        let second = Pattern::new(
+            "OAuth2  PKCE flow".to_string(),
+            "Use PKCE\r\nfor public clients ".to_string(),
+            vec!["auth".to_string()],
+        );
+        assert_ne!(first.id(), second.id());
+
+        let mut dedup = ContentDedup::new();
+        assert_eq!(dedup.register(&first), DedupOutcome::Unique);
+        assert_eq!(
+            dedup.register(&second),
+            DedupOutcome::Duplicate { canonical_id: *first.id() }
+        );
+
+        assert_eq!(dedup.len(), 1);
+        assert_eq!(dedup.duplicates_collapsed(), 1);
+        assert_eq!(dedup.canonical_id(second.id()), *first.id());
+        assert_eq!(dedup.canonical_id(first.id()), *first.id());
+        assert_eq!(dedup.register(&first), DedupOutcome::AlreadyIndexed);
+    }
+
+    /**
+     * Test: Same id with edited content is a change, and the old content is released
+     */
+    #[test]
+    fn test_changed_content_replaces_hash() {
+        let original = Pattern::new("Retry".to_string(), "Fixed delay".to_string(), vec![]);
+        let edited = original.clone().revise("Retry", "Exponential backoff").unwrap();
+
+        let mut dedup = ContentDedup::new();
+        assert_eq!(dedup.register(&original), DedupOutcome::Unique);
+        assert_eq!(dedup.register(&edited), DedupOutcome::Changed);
+        assert_eq!(dedup.register(&edited), DedupOutcome::AlreadyIndexed);
+        assert_eq!(dedup.len(), 1);
+
+        // Old content is free again: a new pattern with it becomes canonical
+        let reuse = Pattern::new("Retry".to_string(), "Fixed delay".to_string(), vec![]);
+        assert_eq!(dedup.register(&reuse), DedupOutcome::Unique);
+    }
+
+    /**
+     * Test: Removing a canonical id frees its hash and orphans its aliases
+     */
+    #[test]
+    fn test_remove_returns_orphaned_aliases() {
+        let canonical = Pattern::new("Retry".to_string(), "Fixed delay".to_string(), vec![]);
+        let alias = Pattern::new("Retry".to_string(), "Fixed delay".to_string(), vec![]);
+
+        let mut dedup = ContentDedup::new();
+        dedup.register(&canonical);
+        dedup.register(&alias);
+
+        assert_eq!(dedup.remove(canonical.id()), vec![*alias.id()]);
+        assert!(dedup.is_empty());
+        assert_eq!(dedup.duplicates_collapsed(), 0);
+        assert_eq!(dedup.canonical_id(alias.id()), *alias.id());
+        assert_eq!(dedup.register(&alias), DedupOutcome::Unique);
+    }
+}
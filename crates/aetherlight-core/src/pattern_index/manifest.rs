@@ -0,0 +1,97 @@
+/**
+ * Index Build Manifest
+ *
+ * DESIGN DECISION: Small JSON file next to the vector store, written after each rebuild
+ * WHY: PatternIndex::new succeeds on an empty data dir, so callers could not tell
+ * "index is empty" from "index was never built"
+ *
+ * REASONING CHAIN:
+ * 1. Rebuild removes the manifest before clearing the vector store
+ * 2. Rebuild writes it only after every vector committed (failed rebuild → no manifest)
+ * 3. Manifest present and readable → a complete index exists on disk
+ * 4. built_at is taken when the rebuild starts reading patterns, so edits made
+ *    during the rebuild still count as stale
+ * 5. Stale = pattern_dir or any pattern file modified after built_at (adding or
+ *    removing a file updates the directory's mtime)
+ * 6. source_paths (file → pattern id) is rewritten after incremental updates, so a
+ *    reopened index still knows which pattern each file was indexed as
+ *
+ * PATTERN: Pattern-INDEX-001 (Semantic Pattern Search)
+ * RELATED: PatternIndex::is_initialized, PatternIndex::needs_rebuild
+ */
+
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Manifest file name (inside data_dir)
+pub const MANIFEST_FILE_NAME: &str = "pattern_index.manifest.json";
+
+/// Record of the last completed rebuild
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexManifest {
+    /// When the rebuild started (milliseconds since the Unix epoch)
+    pub built_at_ms: u64,
+
+    /// Patterns indexed by that rebuild
+    pub patterns: usize,
+
+    /// Pattern file → id indexed from it (empty for rebuild_from_patterns)
+    #[serde(default)]
+    pub source_paths: BTreeMap<PathBuf, uuid::Uuid>,
+}
+
+impl IndexManifest {
+    /// Manifest for a rebuild that started at `started`
+    pub fn new(started: SystemTime, patterns: usize) -> Self {
+        Self { built_at_ms: to_millis(started), patterns, source_paths: BTreeMap::new() }
+    }
+
+    /// Read the manifest; None if missing or unreadable (treated as never built)
+    pub fn read(path: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Remove the manifest (missing file is fine)
+    pub fn remove(path: &Path) -> Result<()> {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether pattern_dir or a pattern file (.md) changed after this build
+    pub fn is_stale(&self, pattern_dir: &Path) -> Result<bool> {
+        let modified = |path: &Path| -> Result<u64> {
+            Ok(to_millis(std::fs::metadata(path)?.modified()?))
+        };
+
+        if modified(pattern_dir)? > self.built_at_ms {
+            return Ok(true);
+        }
+
+        let entries = std::fs::read_dir(pattern_dir)
+            .map_err(|e| Error::Io(format!("Failed to read pattern directory: {}", e)))?;
+        for entry in entries {
+            let path = entry?.path();
+            let is_pattern = path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("md");
+            if is_pattern && modified(&path)? > self.built_at_ms {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+fn to_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
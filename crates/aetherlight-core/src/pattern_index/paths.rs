@@ -0,0 +1,114 @@
+/**
+ * Pattern Directory Resolution
+ *
+ * DESIGN DECISION: Resolve every directory form to one canonical absolute path up front
+ * WHY: Raw relative paths ("data/patterns", "./docs/patterns") resolve against the
+ * process working directory, so an index that works in dev breaks once installed
+ * and launched from elsewhere
+ *
+ * REASONING CHAIN:
+ * 1. Absolute path → used as-is
+ * 2. "~" or "~/..." → expanded against the user's home directory
+ * 3. Anything else → workspace-relative, joined onto the provided root
+ * 4. Canonicalize (resolves "..", "." and symlinks) so every form compares equal
+ * 5. Missing directory → Configuration error naming the path we looked at
+ *
+ * PATTERN: Pattern-INDEX-001 (Semantic Pattern Search)
+ * RELATED: PatternIndex::new_in_workspace
+ */
+
+use crate::{Error, Result};
+use std::path::{Path, PathBuf};
+
+/**
+ * Resolve a directory path against a workspace root
+ *
+ * Returns the canonical absolute path, or a Configuration error if the
+ * directory does not exist (or is not a directory).
+ */
+pub fn resolve_dir(path: impl AsRef<Path>, root: impl AsRef<Path>) -> Result<PathBuf> {
+    let path = path.as_ref();
+    let candidate = if let Some(rest) = strip_home(path) {
+        let home = dirs::home_dir().ok_or_else(|| {
+            Error::Configuration(format!("cannot expand {}: no home directory", path.display()))
+        })?;
+        home.join(rest)
+    } else if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        root.as_ref().join(path)
+    };
+
+    match candidate.canonicalize() {
+        Ok(resolved) if resolved.is_dir() => Ok(resolved),
+        Ok(resolved) => Err(Error::Configuration(format!(
+            "not a directory: {} (from {})",
+            resolved.display(),
+            path.display()
+        ))),
+        Err(_) => Err(Error::Configuration(format!(
+            "directory not found: {} (from {})",
+            candidate.display(),
+            path.display()
+        ))),
+    }
+}
+
+/// Remainder after a leading "~" component ("~" → "", "~/a" → "a")
+fn strip_home(path: &Path) -> Option<&Path> {
+    path.strip_prefix("~").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    /**
+     * Test: Absolute, workspace-relative and "..", "." forms resolve to one directory
+     */
+    #[test]
+    fn test_path_forms_resolve_to_same_directory() {
+        let root = tempdir().unwrap();
+        let patterns = root.path().join("docs/patterns");
+        std::fs::create_dir_all(&patterns).unwrap();
+        let canonical = patterns.canonicalize().unwrap();
+
+        assert_eq!(resolve_dir(&patterns, "/unused").unwrap(), canonical);
+        assert_eq!(resolve_dir("docs/patterns", root.path()).unwrap(), canonical);
+        assert_eq!(resolve_dir("./docs/../docs/patterns", root.path()).unwrap(), canonical);
+    }
+
+    /**
+     * Test: "~" expands to the home directory
+     */
+    #[test]
+    fn test_tilde_expands_to_home() {
+        let home = match dirs::home_dir() {
+            Some(home) if home.is_dir() => home.canonicalize().unwrap(),
+            _ => {
+                eprintln!("Skipping test: no home directory");
+                return;
+            }
+        };
+
+        assert_eq!(resolve_dir("~", "/unused").unwrap(), home);
+        assert_eq!(resolve_dir("~/.", "/unused").unwrap(), home);
+    }
+
+    /**
+     * Test: Missing directory fails with the path that was looked up
+     */
+    #[test]
+    fn test_missing_directory_is_configuration_error() {
+        let root = tempdir().unwrap();
+
+        match resolve_dir("docs/patterns", root.path()) {
+            Err(Error::Configuration(message)) => {
+                assert!(message.contains("directory not found"), "{}", message);
+                assert!(message.contains(&root.path().join("docs/patterns").display().to_string()));
+            }
+            other => panic!("expected Configuration error, got {:?}", other),
+        }
+    }
+}
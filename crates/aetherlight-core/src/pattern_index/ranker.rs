@@ -79,6 +79,11 @@ pub fn calculate_context_boost(
         }
     }
 
+    // Selected domain boost (0.10, below an exact current-domain match)
+    if in_selected_domains(pattern, &context.selected_domains) {
+        boost.domain_boost = boost.domain_boost.max(0.10);
+    }
+
     // Framework match boost (max 0.10)
     if let Some(ctx_framework) = &context.framework {
         let pattern_tags = &pattern.pattern.tags();
@@ -134,6 +139,21 @@ pub fn calculate_context_boost(
     }
 }
 
+/**
+ * DESIGN DECISION: Match selected domains against pattern domain and common_domains
+ * WHY: Users pick several domains ("rust", "devops") in settings; freshly indexed
+ * patterns have no usage history yet, so their own domain metadata must count too
+ *
+ * Case-insensitive, exact match only ("rust" does not select "rust-async").
+ */
+pub fn in_selected_domains(pattern: &IndexedPattern, selected_domains: &[String]) -> bool {
+    let pattern_domain = pattern.pattern.metadata().domain.as_deref();
+    selected_domains.iter().any(|selected| {
+        pattern_domain.is_some_and(|domain| domain.eq_ignore_ascii_case(selected))
+            || pattern.common_domains.iter().any(|d| d.eq_ignore_ascii_case(selected))
+    })
+}
+
 /**
  * DESIGN DECISION: Decay boost over time
  * WHY: Old patterns should gradually lose relevance
@@ -216,6 +236,7 @@ mod tests {
     fn create_test_context() -> SearchContext {
         SearchContext {
             domain: Some("authentication".to_string()),
+            selected_domains: vec![],
             framework: Some("actix-web".to_string()),
             recent_patterns: vec!["Pattern-JWT-001".to_string()],
             user_preferences: Default::default(),
@@ -245,6 +266,7 @@ mod tests {
 
         let context = SearchContext {
             domain: Some("authentication".to_string()),
+            selected_domains: vec![],
             framework: None,
             recent_patterns: vec![],
             user_preferences: Default::default(),
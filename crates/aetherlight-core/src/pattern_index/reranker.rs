@@ -0,0 +1,274 @@
+/**
+ * Top-K Re-Ranking (AI-005 Submodule)
+ *
+ * DESIGN DECISION: Re-score top-K bi-encoder candidates with richer features
+ * WHY: Cosine similarity is fast but imprecise at the very top - the #1 result
+ * is sometimes only marginally more similar than a far more appropriate pattern
+ *
+ * REASONING CHAIN:
+ * 1. Vector store returns top-K candidates by cosine similarity (cheap, recall-oriented)
+ * 2. For each candidate compute: semantic score, keyword overlap, metadata match, recency
+ * 3. Weighted sum gives re-rank score (expensive features only run on K, not all patterns)
+ * 4. Sort by re-rank score, return top-N
+ * 5. Result: Precision at #1 improves without slowing the full-index scan
+ *
+ * PATTERN: Pattern-INDEX-001 (Semantic Pattern Search)
+ * PERFORMANCE: O(K × pattern text) - K=20 adds <1ms
+ * RELATED: PatternIndex::search_by_intent, ranker.rs (context boost), matching/preprocess.rs
+ * FUTURE: Swap feature combination for a cross-encoder model once embeddings are re-enabled
+ */
+
+use super::{IndexedPattern, PatternMatch, SearchContext};
+use crate::matching::QueryPreprocessor;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/**
+ * Re-ranking configuration
+ *
+ * DESIGN DECISION: Disabled by default, weights sum to 1.0
+ * WHY: Opt-in until tuned per deployment; normalized weights keep scores in 0.0-1.0
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RerankConfig {
+    /// Enable re-ranking stage
+    pub enabled: bool,
+
+    /// Number of bi-encoder candidates to re-score (K)
+    pub candidates: usize,
+
+    /// Number of results returned after re-ranking (N)
+    pub top_n: usize,
+
+    /// Weight of bi-encoder relevance (incl. context boost)
+    pub semantic_weight: f64,
+
+    /// Weight of query/pattern keyword overlap
+    pub keyword_weight: f64,
+
+    /// Weight of domain/framework metadata match
+    pub metadata_weight: f64,
+
+    /// Weight of recent usage
+    pub recency_weight: f64,
+}
+
+impl Default for RerankConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            candidates: 20,
+            top_n: 5,
+            semantic_weight: 0.50,
+            keyword_weight: 0.25,
+            metadata_weight: 0.15,
+            recency_weight: 0.10,
+        }
+    }
+}
+
+/**
+ * DESIGN DECISION: Re-order top-K matches by combined feature score
+ * WHY: Matches arrive sorted by bi-encoder relevance; only the head needs precision
+ *
+ * Matches whose pattern is not found in `patterns` keep their semantic score only.
+ * Returned matches carry the re-rank score as `relevance`.
+ */
+pub fn rerank(
+    intent: &str,
+    matches: Vec<PatternMatch>,
+    patterns: &[IndexedPattern],
+    context: Option<&SearchContext>,
+    config: &RerankConfig,
+) -> Vec<PatternMatch> {
+    let preprocessor = QueryPreprocessor::new();
+    let query_tokens: HashSet<String> = preprocessor.tokens(intent).into_iter().collect();
+
+    let mut reranked: Vec<PatternMatch> = matches
+        .into_iter()
+        .take(config.candidates)
+        .map(|mut m| {
+            let indexed = patterns.iter().find(|p| p.pattern.id() == m.pattern.id());
+
+            let (keyword, metadata, recency) = match indexed {
+                Some(indexed) => (
+                    keyword_score(&query_tokens, indexed, &preprocessor),
+                    metadata_score(indexed, context),
+                    recency_score(indexed),
+                ),
+                None => (0.0, 0.0, 0.0),
+            };
+
+            m.relevance = (config.semantic_weight * m.relevance
+                + config.keyword_weight * keyword
+                + config.metadata_weight * metadata
+                + config.recency_weight * recency)
+                .min(1.0);
+            m
+        })
+        .collect();
+
+    reranked.sort_by(|a, b| b.relevance.partial_cmp(&a.relevance).unwrap());
+    reranked.truncate(config.top_n);
+    reranked
+}
+
+/// Fraction of query tokens found in pattern title/tags/content (0.0-1.0)
+fn keyword_score(
+    query_tokens: &HashSet<String>,
+    indexed: &IndexedPattern,
+    preprocessor: &QueryPreprocessor,
+) -> f64 {
+    if query_tokens.is_empty() {
+        return 0.0;
+    }
+
+    let pattern = &indexed.pattern;
+    let pattern_text = format!("{} {} {}", pattern.title(), pattern.tags().join(" "), pattern.content());
+    let pattern_tokens: HashSet<String> = preprocessor.tokens(&pattern_text).into_iter().collect();
+
+    let hits = query_tokens.intersection(&pattern_tokens).count();
+    hits as f64 / query_tokens.len() as f64
+}
+
+/// Domain/framework agreement between pattern metadata and search context (0.0-1.0)
+fn metadata_score(indexed: &IndexedPattern, context: Option<&SearchContext>) -> f64 {
+    let Some(ctx) = context else {
+        return 0.0;
+    };
+
+    let metadata = indexed.pattern.metadata();
+    let tags: Vec<String> = indexed.pattern.tags().iter().map(|t| t.to_lowercase()).collect();
+    let mut score: f64 = 0.0;
+
+    let current_domain_match = ctx.domain.as_deref().map(str::to_lowercase).is_some_and(|domain| {
+        metadata.domain.as_deref().map(str::to_lowercase) == Some(domain.clone())
+            || indexed.common_domains.iter().any(|d| d.to_lowercase() == domain)
+            || tags.contains(&domain)
+    });
+    if current_domain_match || super::ranker::in_selected_domains(indexed, &ctx.selected_domains) {
+        score += 0.5;
+    }
+
+    if let Some(framework) = ctx.framework.as_deref().map(str::to_lowercase) {
+        let framework_match = metadata.framework.as_deref().map(str::to_lowercase) == Some(framework.clone())
+            || tags.contains(&framework);
+        if framework_match {
+            score += 0.5;
+        }
+    }
+
+    score
+}
+
+/// Recency of last use, linear decay over 90 days (0.0-1.0)
+fn recency_score(indexed: &IndexedPattern) -> f64 {
+    indexed
+        .last_used
+        .map(|last_used| {
+            let days = (Utc::now() - last_used).num_days().max(0) as f64;
+            (1.0 - days / 90.0).max(0.0)
+        })
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pattern;
+    use chrono::Duration;
+    use std::collections::HashMap;
+
+    fn indexed(pattern: Pattern, last_used_days: Option<i64>) -> IndexedPattern {
+        IndexedPattern {
+            pattern,
+            description_embedding: vec![],
+            usage_count: 0,
+            last_used: last_used_days.map(|d| Utc::now() - Duration::days(d)),
+            avg_confidence: None,
+            common_domains: vec![],
+        }
+    }
+
+    fn bi_encoder_match(indexed: &IndexedPattern, relevance: f64) -> PatternMatch {
+        PatternMatch {
+            pattern: indexed.pattern.clone(),
+            relevance,
+            reasoning: String::new(),
+            context_boost: None,
+        }
+    }
+
+    /**
+     * Test: Re-ranking promotes contextually appropriate pattern over marginally more similar one
+     */
+    #[test]
+    fn test_rerank_promotes_contextual_match() {
+        let generic = indexed(
+            Pattern::builder()
+                .title("Session cookie management")
+                .content("Store session identifiers in secure cookies")
+                .tags(vec!["web"])
+                .build()
+                .unwrap(),
+            None,
+        );
+        let oauth = indexed(
+            Pattern::builder()
+                .title("OAuth2 PKCE login flow")
+                .content("Authorization code flow with PKCE for public clients")
+                .tags(vec!["oauth2", "authentication"])
+                .domain("authentication")
+                .build()
+                .unwrap(),
+            Some(3),
+        );
+
+        // Bi-encoder ranks generic session pattern marginally higher
+        let matches = vec![bi_encoder_match(&generic, 0.82), bi_encoder_match(&oauth, 0.80)];
+        let context = SearchContext {
+            domain: Some("authentication".to_string()),
+            selected_domains: vec![],
+            framework: None,
+            recent_patterns: vec![],
+            user_preferences: HashMap::new(),
+        };
+        let config = RerankConfig { enabled: true, ..Default::default() };
+
+        let reranked = rerank(
+            "OAuth2 login with PKCE",
+            matches,
+            &[generic.clone(), oauth.clone()],
+            Some(&context),
+            &config,
+        );
+
+        assert_eq!(reranked.len(), 2);
+        assert_eq!(reranked[0].pattern.id(), oauth.pattern.id());
+        assert!(reranked[0].relevance > reranked[1].relevance);
+    }
+
+    /**
+     * Test: Only top-K candidates considered, top-N returned
+     */
+    #[test]
+    fn test_rerank_respects_k_and_n() {
+        let patterns: Vec<IndexedPattern> = (0..6)
+            .map(|i| indexed(Pattern::new(format!("Pattern {}", i), String::new(), vec![]), None))
+            .collect();
+        let matches: Vec<PatternMatch> = patterns
+            .iter()
+            .enumerate()
+            .map(|(i, p)| bi_encoder_match(p, 0.9 - i as f64 * 0.1))
+            .collect();
+        let config = RerankConfig { enabled: true, candidates: 4, top_n: 2, ..Default::default() };
+
+        let reranked = rerank("unrelated query", matches, &patterns, None, &config);
+
+        assert_eq!(reranked.len(), 2);
+        assert_eq!(reranked[0].pattern.id(), patterns[0].pattern.id());
+        assert_eq!(reranked[1].pattern.id(), patterns[1].pattern.id());
+    }
+}
@@ -50,7 +50,7 @@ impl WsSession {
         ctx.run_interval(Duration::from_secs(5), |act, ctx| {
             // Check heartbeat timeout (30 seconds)
             if Instant::now().duration_since(act.hb) > Duration::from_secs(30) {
-                println!("WebSocket heartbeat timeout, disconnecting {}", act.conn_info.id);
+                tracing::info!(connection = %act.conn_info.id, "WebSocket heartbeat timeout, disconnecting");
                 ctx.stop();
                 return;
             }
@@ -76,9 +76,10 @@ impl WsSession {
                 };
                 ctx.text(serde_json::to_string(&ack).unwrap());
 
-                println!(
-                    "Client {} subscribed to {:?}",
-                    self.conn_info.id, event_types
+                tracing::debug!(
+                    connection = %self.conn_info.id,
+                    event_types = ?event_types,
+                    "Client subscribed"
                 );
             }
             WsMessage::Unsubscribe { event_types } => {
@@ -113,7 +114,7 @@ impl WsSession {
                 };
                 ctx.text(serde_json::to_string(&ack).unwrap());
 
-                println!("Event published: {} - {}", event.event_type, event.title);
+                tracing::debug!(event_type = %event.event_type, title = %event.title, "Event published");
             }
             WsMessage::Ping => {
                 ctx.text(serde_json::to_string(&WsMessage::Pong).unwrap());
@@ -145,7 +146,7 @@ impl Actor for WsSession {
             state.stats.active_connections = state.connections.len();
         }
 
-        println!("WebSocket connection established: {}", self.conn_info.id);
+        tracing::info!(connection = %self.conn_info.id, "WebSocket connection established");
     }
 
     fn stopped(&mut self, _ctx: &mut Self::Context) {
@@ -155,7 +156,7 @@ impl Actor for WsSession {
             state.stats.active_connections = state.connections.len();
         }
 
-        println!("WebSocket connection closed: {}", self.conn_info.id);
+        tracing::info!(connection = %self.conn_info.id, "WebSocket connection closed");
     }
 }
 
@@ -197,7 +198,7 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WsSession {
                 ctx.stop();
             }
             Err(e) => {
-                println!("WebSocket error: {}", e);
+                tracing::warn!(error = %e, "WebSocket error");
                 ctx.stop();
             }
             _ => (),
@@ -251,7 +252,7 @@ impl ServerState {
         // Persist event to database (if enabled)
         if let Some(persistence) = &self.persistence {
             if let Err(e) = persistence.store_event(&event) {
-                eprintln!("Failed to persist event {}: {}", event.id, e);
+                tracing::error!(event_id = %event.id, error = %e, "Failed to persist event");
             }
         }
 
@@ -261,10 +262,7 @@ impl ServerState {
             if conn.is_subscribed(&event.event_type) && conn.matches_project(&event.project) {
                 // TODO: Use actix Addr<WsSession> to send messages
                 // For now, this is a placeholder showing the broadcast logic
-                println!(
-                    "Broadcasting event {} to connection {}",
-                    event.id, conn.id
-                );
+                tracing::trace!(event_id = %event.id, connection = %conn.id, "Broadcasting event");
             }
         }
     }
@@ -0,0 +1,135 @@
+/**
+ * Storage Format - JSON or compact binary for persisted planning state
+ *
+ * DESIGN DECISION: One format switch shared by session handoffs, sprint plans and
+ * scheduler checkpoints, selected by file extension (".bin" = binary)
+ * WHY: Large sprints re-parse multi-megabyte pretty JSON on every resume; bincode is a
+ * fraction of the size and parses without tokenizing text
+ *
+ * REASONING CHAIN:
+ * 1. JSON stays the default: humans and agents read handoffs directly
+ * 2. Binary = BINARY_MAGIC header + bincode payload (same codec as matcher snapshots)
+ * 3. Magic header turns a JSON file renamed to .bin into a clear error, not garbage
+ * 4. Extension decides on load, so both formats can sit side by side in one directory
+ *
+ * TRADEOFF: bincode is not self-describing; adding a field breaks old binary files,
+ * so binary is a cache/transfer format and JSON remains the archival one
+ *
+ * PATTERN: Pattern-HANDOFF-001 (Structured Session Transfer)
+ * RELATED: HandoffLoader, ExecutableSprintPlan::save, ExecutionCheckpoint::save
+ */
+
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+
+/// Header of binary files (3 magic bytes + binary layout version)
+pub const BINARY_MAGIC: &[u8; 4] = b"LMB\x01";
+
+/// Serialization format for persisted state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageFormat {
+    /// Pretty-printed JSON (human-readable default)
+    #[default]
+    Json,
+    /// BINARY_MAGIC + bincode
+    Binary,
+}
+
+impl StorageFormat {
+    /// Format implied by a file extension (".bin" → Binary, anything else → Json)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("bin") => StorageFormat::Binary,
+            _ => StorageFormat::Json,
+        }
+    }
+
+    /// File extension for this format (without dot)
+    pub fn extension(self) -> &'static str {
+        match self {
+            StorageFormat::Json => "json",
+            StorageFormat::Binary => "bin",
+        }
+    }
+
+    /// Serialize `value` in this format
+    pub fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>> {
+        match self {
+            StorageFormat::Json => Ok(serde_json::to_vec_pretty(value)?),
+            StorageFormat::Binary => {
+                let mut bytes = BINARY_MAGIC.to_vec();
+                bincode::serialize_into(&mut bytes, value)
+                    .map_err(|e| Error::Serialization(format!("Failed to encode binary: {}", e)))?;
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Deserialize a value written by `encode` in this format
+    pub fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T> {
+        match self {
+            StorageFormat::Json => Ok(serde_json::from_slice(bytes)?),
+            StorageFormat::Binary => {
+                let payload = bytes.strip_prefix(BINARY_MAGIC.as_slice()).ok_or_else(|| {
+                    Error::Serialization("Not a binary state file (missing header)".to_string())
+                })?;
+                bincode::deserialize(payload)
+                    .map_err(|e| Error::Serialization(format!("Invalid binary state file: {}", e)))
+            }
+        }
+    }
+}
+
+/// Write `value` to `path` in the format implied by its extension
+pub fn write_file<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let bytes = StorageFormat::from_path(path).encode(value)?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+/// Read a value from `path` in the format implied by its extension
+pub fn read_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let bytes = std::fs::read(path)?;
+    StorageFormat::from_path(path).decode(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, serde::Deserialize)]
+    struct Sample {
+        name: String,
+        counts: HashMap<String, u32>,
+        tags: Vec<String>,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(StorageFormat::from_path(Path::new("plan.bin")), StorageFormat::Binary);
+        assert_eq!(StorageFormat::from_path(Path::new("plan.json")), StorageFormat::Json);
+        assert_eq!(StorageFormat::from_path(Path::new("plan")), StorageFormat::Json);
+    }
+
+    #[test]
+    fn test_binary_round_trip_and_header_check() {
+        let sample = Sample {
+            name: "sprint".to_string(),
+            counts: HashMap::from([("done".to_string(), 3)]),
+            tags: vec!["auth".to_string()],
+            note: None,
+        };
+
+        let bytes = StorageFormat::Binary.encode(&sample).unwrap();
+        assert!(bytes.starts_with(BINARY_MAGIC));
+        assert_eq!(StorageFormat::Binary.decode::<Sample>(&bytes).unwrap(), sample);
+
+        // JSON written to a .bin path is rejected, not misread
+        let json = StorageFormat::Json.encode(&sample).unwrap();
+        assert!(matches!(StorageFormat::Binary.decode::<Sample>(&json), Err(Error::Serialization(_))));
+    }
+}
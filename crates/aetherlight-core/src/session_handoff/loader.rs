@@ -2,6 +2,9 @@
  * DESIGN DECISION: Load session handoff from JSON files for context recovery
  * WHY: Next session needs to recover ALL context from previous session
  *
+ * Handoffs may also be saved as compact binary (`with_format(StorageFormat::Binary)`,
+ * `.bin` files); loading accepts both, by extension.
+ *
  * REASONING CHAIN:
  * 1. Session ends → handoff saved to .lumina/sessions/YYYY-MM-DD-session-NNN.json
  * 2. Next session starts → load previous handoff
@@ -15,19 +18,34 @@
  */
 
 use super::types::*;
+use crate::serialization::{self, StorageFormat};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 /// Session handoff loader
 pub struct HandoffLoader {
     sessions_dir: PathBuf,
+    /// Format used by `save` (loading accepts every format)
+    format: StorageFormat,
 }
 
 impl HandoffLoader {
     /// Create new loader
     pub fn new(project_root: PathBuf) -> Self {
         let sessions_dir = project_root.join(".lumina/sessions");
-        Self { sessions_dir }
+        Self { sessions_dir, format: StorageFormat::Json }
+    }
+
+    /// Save handoffs in `format` (JSON by default)
+    pub fn with_format(mut self, format: StorageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Read one handoff file (format from its extension)
+    fn read_handoff(path: &Path) -> Result<SessionHandoff, String> {
+        serialization::read_file(path)
+            .map_err(|e| format!("Failed to read handoff {}: {}", path.display(), e))
     }
 
     /**
@@ -35,19 +53,21 @@ impl HandoffLoader {
      * WHY: Precise loading, no ambiguity
      */
     pub async fn load(&self, session_id: &str) -> Result<SessionHandoff, String> {
-        let handoff_path = self.sessions_dir.join(format!("{}.json", session_id));
-
-        if !handoff_path.exists() {
-            return Err(format!("Handoff file not found: {}", handoff_path.display()));
-        }
-
-        let content = fs::read_to_string(&handoff_path)
-            .map_err(|e| format!("Failed to read handoff file: {}", e))?;
-
-        let handoff: SessionHandoff = serde_json::from_str(&content)
-            .map_err(|e| format!("Failed to parse handoff JSON: {}", e))?;
-
-        Ok(handoff)
+        // Saved format first, then the other one
+        let other = match self.format {
+            StorageFormat::Json => StorageFormat::Binary,
+            StorageFormat::Binary => StorageFormat::Json,
+        };
+        let handoff_path = [self.format, other]
+            .iter()
+            .map(|format| self.sessions_dir.join(format!("{}.{}", session_id, format.extension())))
+            .find(|path| path.exists())
+            .ok_or_else(|| format!(
+                "Handoff file not found: {}",
+                self.sessions_dir.join(format!("{}.{}", session_id, self.format.extension())).display()
+            ))?;
+
+        Self::read_handoff(&handoff_path)
     }
 
     /**
@@ -76,14 +96,7 @@ impl HandoffLoader {
             }
         });
 
-        let latest_path = &handoff_files[0];
-        let content = fs::read_to_string(latest_path)
-            .map_err(|e| format!("Failed to read handoff file: {}", e))?;
-
-        let handoff: SessionHandoff = serde_json::from_str(&content)
-            .map_err(|e| format!("Failed to parse handoff JSON: {}", e))?;
-
-        Ok(handoff)
+        Self::read_handoff(&handoff_files[0])
     }
 
     /**
@@ -105,7 +118,10 @@ impl HandoffLoader {
         for entry in entries {
             if let Ok(entry) = entry {
                 let path = entry.path();
-                if path.extension().and_then(|s| s.to_str()) == Some("json") {
+                let extension = path.extension().and_then(|s| s.to_str());
+                if extension == Some(StorageFormat::Json.extension())
+                    || extension == Some(StorageFormat::Binary.extension())
+                {
                     handoff_files.push(path);
                 }
             }
@@ -125,10 +141,7 @@ impl HandoffLoader {
         for path in handoff_files {
             if let Some(filename) = path.file_name().and_then(|s| s.to_str()) {
                 if filename.starts_with(date) {
-                    let content = fs::read_to_string(&path)
-                        .map_err(|e| format!("Failed to read handoff file: {}", e))?;
-
-                    if let Ok(handoff) = serde_json::from_str::<SessionHandoff>(&content) {
+                    if let Ok(handoff) = Self::read_handoff(&path) {
                         handoffs.push(handoff);
                     }
                 }
@@ -233,7 +246,7 @@ impl HandoffLoader {
     }
 
     /**
-     * DESIGN DECISION: Save handoff to JSON file
+     * DESIGN DECISION: Save handoff to JSON file (or .bin with StorageFormat::Binary)
      * WHY: Persistent storage for future sessions
      */
     pub async fn save(&self, handoff: &SessionHandoff) -> Result<PathBuf, String> {
@@ -245,12 +258,9 @@ impl HandoffLoader {
 
         let handoff_path = self
             .sessions_dir
-            .join(format!("{}.json", handoff.session_id));
-
-        let json = serde_json::to_string_pretty(handoff)
-            .map_err(|e| format!("Failed to serialize handoff: {}", e))?;
+            .join(format!("{}.{}", handoff.session_id, self.format.extension()));
 
-        fs::write(&handoff_path, json)
+        serialization::write_file(&handoff_path, handoff)
             .map_err(|e| format!("Failed to write handoff file: {}", e))?;
 
         Ok(handoff_path)
@@ -308,4 +318,71 @@ mod tests {
 
         assert_eq!(deserialized.session_id, "test-session");
     }
+
+    /// Handoff from a long session (200 tasks, 400 file changes, 100 decisions)
+    fn large_handoff(session_id: &str) -> SessionHandoff {
+        let mut handoff = SessionHandoff::new(session_id.to_string());
+        for i in 0..200 {
+            handoff.tasks_completed.push(Task {
+                id: format!("TASK-{:03}", i),
+                title: format!("Implement step {} of the sprint", i),
+                status: TaskStatus::Complete,
+                files_modified: vec![PathBuf::from(format!("src/module_{}.rs", i))],
+                patterns_applied: vec!["Pattern-HANDOFF-001".to_string()],
+                start_time: Some(Utc::now()),
+                end_time: Some(Utc::now()),
+                duration_secs: Some(60 * i),
+            });
+        }
+        for i in 0..400 {
+            handoff.files_modified.push(FileChange {
+                path: PathBuf::from(format!("src/module_{}.rs", i)),
+                change_type: ChangeType::Modified,
+                lines_added: i,
+                lines_removed: i / 2,
+                line_numbers: Some((i..i + 10).collect()),
+                description: "Refactored error handling".to_string(),
+            });
+        }
+        for i in 0..100 {
+            handoff.decisions_made.push(Decision {
+                decision: format!("Decision {}", i),
+                reasoning: "Keeps the scheduler deterministic".to_string(),
+                alternatives: vec!["Random order".to_string()],
+                timestamp: Utc::now(),
+                related_files: vec![],
+                confidence: Some(0.9),
+            });
+        }
+        handoff
+    }
+
+    /**
+     * Test: Binary and JSON handoffs load to identical structs; binary under 2/3 the size
+     */
+    #[tokio::test]
+    async fn test_binary_handoff_matches_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let json_loader = HandoffLoader::new(dir.path().join("json"));
+        let binary_loader = HandoffLoader::new(dir.path().join("binary")).with_format(StorageFormat::Binary);
+        let handoff = large_handoff("2025-10-12-session-001");
+
+        let json_path = json_loader.save(&handoff).await.unwrap();
+        let binary_path = binary_loader.save(&handoff).await.unwrap();
+        assert_eq!(binary_path.extension().unwrap(), "bin");
+
+        let from_json = json_loader.load(&handoff.session_id).await.unwrap();
+        let from_binary = binary_loader.load(&handoff.session_id).await.unwrap();
+        assert_eq!(serde_json::to_value(&from_json).unwrap(), serde_json::to_value(&handoff).unwrap());
+        assert_eq!(serde_json::to_value(&from_binary).unwrap(), serde_json::to_value(&from_json).unwrap());
+
+        let json_size = fs::metadata(&json_path).unwrap().len();
+        let binary_size = fs::metadata(&binary_path).unwrap().len();
+        assert!(binary_size * 3 < json_size * 2, "json {} vs binary {}", json_size, binary_size);
+
+        // .bin handoffs are listed and found by date and as latest
+        assert_eq!(binary_loader.list_handoffs().await.unwrap().len(), 1);
+        assert_eq!(binary_loader.load_by_date("2025-10-12").await.unwrap().len(), 1);
+        assert_eq!(binary_loader.load_latest().await.unwrap().session_id, handoff.session_id);
+    }
 }
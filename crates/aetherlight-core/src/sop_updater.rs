@@ -233,16 +233,15 @@ impl SOPUpdater {
 
     /// Notify human of update
     async fn notify_human(&self, update: &SOPUpdate) -> Result<(), String> {
-        println!("\n=== SOP UPDATE NOTIFICATION ===");
-        println!("Agent: {:?}", update.agent_type);
-        println!("Section: {}", update.sop_section);
-        println!("Experiment: {}", update.experiment_id);
-        println!("Date: {}", update.validated_date.format("%Y-%m-%d"));
-        println!("\nRationale:");
-        println!("{}", update.rationale);
-        println!("\nDiff:");
-        println!("{}", update.diff);
-        println!("===============================\n");
+        tracing::info!(
+            agent = ?update.agent_type,
+            section = %update.sop_section,
+            experiment = %update.experiment_id,
+            date = %update.validated_date.format("%Y-%m-%d"),
+            "SOP update applied\n\nRationale:\n{}\n\nDiff:\n{}",
+            update.rationale,
+            update.diff
+        );
 
         Ok(())
     }
@@ -376,6 +376,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
         tasks.insert(
@@ -389,6 +390,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
         tasks.insert(
@@ -402,6 +404,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -421,6 +424,9 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec!["DB-001".to_string(), "UI-001".to_string(), "API-001".to_string()],
+            owner: None,
+            labels: vec![],
+            priority: None,
         }
     }
 
@@ -20,6 +20,7 @@
  * - `types.rs`: Core data structures (SprintPlan, ExecutableSprintPlan, Task, etc.)
  * - `yaml_parser.rs`: YAML → Rust struct parsing with serde
  * - `validator.rs`: Business logic validation (DAG property, valid references, etc.)
+ * - `parse_error.rs`: Structured parse failures (line, column, task id)
  *
  * # Usage Example
  *
@@ -52,7 +53,7 @@
  * # Error Handling
  *
  * All functions return `Result<T, Error>`:
- * - Parse errors: Invalid YAML syntax, missing fields
+ * - Parse errors: Invalid YAML syntax, missing fields (`Error::SprintParse` with line/column/task id)
  * - Validation errors: Circular dependencies, invalid references
  * - File errors: File not found, read permission denied
  */
@@ -61,13 +62,15 @@ pub mod types;
 pub mod yaml_parser;
 pub mod validator;
 pub mod dependency_graph;
+pub mod parse_error;
 
 // Re-export primary types for ergonomic imports
 pub use types::{
     SprintPlan, SprintMetadata, ExecutableSprintPlan, Task, TaskId, AgentType,
-    ApprovalGate, ParallelGroup, TaskContext
+    ApprovalGate, ParallelGroup, TaskContext, Priority
 };
 pub use yaml_parser::YamlParser;
+pub use parse_error::{SprintParseError, SprintPlanFormat};
 pub use validator::Validator;
 pub use dependency_graph::{DependencyGraph as SprintDependencyGraph, TaskNode};
 
@@ -139,6 +142,17 @@ pub fn parse_sprint_str(yaml: &str) -> crate::error::Result<ExecutableSprintPlan
     Ok(plan)
 }
 
+/**
+ * Parse and validate sprint plan from TOML string
+ *
+ * Same schema and validation as parse_sprint_str (see YamlParser::parse_toml_str).
+ */
+pub fn parse_sprint_toml_str(toml: &str) -> crate::error::Result<ExecutableSprintPlan> {
+    let plan = YamlParser::parse_toml_str(toml)?;
+    Validator::validate(&plan)?;
+    Ok(plan)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +78,25 @@ pub enum AgentType {
     Planning,
 }
 
+/**
+ * Sprint / task priority for triage and scheduling
+ *
+ * DESIGN DECISION: Closed enum ordered low → critical, lowercase in YAML/TOML
+ * WHY: Free-form strings ("P1", "urgent", "hi") can't be compared; serde rejects
+ * unknown values at parse time so typos fail early
+ *
+ * PATTERN: Pattern-SPRINT-PLAN-001
+ * RELATED: ExecutableSprintPlan::order_by_priority (scheduler tie-breaking)
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
 /**
  * Sprint plan from YAML file (direct deserialization)
  *
@@ -116,6 +135,15 @@ pub struct SprintMetadata {
     /// Human approval gates (optional)
     #[serde(default)]
     pub approval_gates: Vec<ApprovalGate>,
+    /// Sprint owner for triage (optional, e.g., "alice")
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Labels/tags for filtering (optional, e.g., ["auth", "q3"])
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Sprint priority (optional; default for tasks without their own)
+    #[serde(default)]
+    pub priority: Option<Priority>,
 }
 
 /**
@@ -155,6 +183,9 @@ pub struct Task {
     /// Relevant patterns (optional, for context injection)
     #[serde(default)]
     pub patterns: Vec<String>,
+    /// Task priority (optional, falls back to sprint priority)
+    #[serde(default)]
+    pub priority: Option<Priority>,
 }
 
 /**
@@ -200,7 +231,7 @@ pub struct ApprovalGate {
  * RELATED: AS-002 (Dependency Graph Builder), AS-003 (Task Scheduler)
  * PERFORMANCE: <100ms to build graph for 50 tasks
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutableSprintPlan {
     /// Sprint name
     pub name: String,
@@ -220,6 +251,12 @@ pub struct ExecutableSprintPlan {
     pub parallel_groups: Vec<ParallelGroup>,
     /// Topological sort (execution order)
     pub execution_order: Vec<TaskId>,
+    /// Sprint owner
+    pub owner: Option<String>,
+    /// Sprint labels
+    pub labels: Vec<String>,
+    /// Sprint priority
+    pub priority: Option<Priority>,
 }
 
 /**
@@ -239,7 +276,7 @@ pub struct ExecutableSprintPlan {
  * RELATED: AS-003 (Task Scheduler)
  * PERFORMANCE: Parallel efficiency = actual_time / (sequential_time / group_size)
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParallelGroup {
     /// Tasks that can execute in parallel
     pub tasks: Vec<TaskId>,
@@ -305,6 +342,33 @@ impl ExecutableSprintPlan {
             .collect()
     }
 
+    /**
+     * Effective priority of a task (task priority, else sprint priority)
+     */
+    pub fn task_priority(&self, task_id: &TaskId) -> Option<Priority> {
+        self.tasks
+            .get(task_id)
+            .and_then(|task| task.priority)
+            .or(self.priority)
+    }
+
+    /**
+     * Order ready tasks by priority (highest first)
+     *
+     * DESIGN DECISION: Priority, then task ID
+     * WHY: With limited concurrency the scheduler starts the first N ready tasks;
+     * HashMap iteration order would make that choice arbitrary
+     *
+     * Tasks without any priority sort after prioritized tasks.
+     */
+    pub fn order_by_priority(&self, ready: &mut [TaskId]) {
+        ready.sort_by(|a, b| {
+            self.task_priority(b)
+                .cmp(&self.task_priority(a))
+                .then_with(|| a.cmp(b))
+        });
+    }
+
     /**
      * Get task by ID
      *
@@ -315,6 +379,21 @@ impl ExecutableSprintPlan {
         self.tasks.get(task_id)
     }
 
+    /**
+     * Save the computed plan (".bin" = compact binary, otherwise JSON)
+     *
+     * DESIGN DECISION: Cache the executable plan, not just the YAML
+     * WHY: Resuming a large sprint skips re-parsing and re-validating the DAG
+     */
+    pub fn save(&self, path: &std::path::Path) -> crate::Result<()> {
+        crate::serialization::write_file(path, self)
+    }
+
+    /// Load a plan written by `save` (format from the file extension)
+    pub fn load(path: &std::path::Path) -> crate::Result<Self> {
+        crate::serialization::read_file(path)
+    }
+
     /**
      * Check if all tasks complete
      */
@@ -406,6 +485,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
         tasks.insert(
@@ -419,6 +499,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -435,6 +516,9 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec![],
+            owner: None,
+            labels: vec![],
+            priority: None,
         };
 
         let completed = std::collections::HashSet::new();
@@ -448,4 +532,86 @@ mod tests {
         assert_eq!(ready.len(), 1);
         assert_eq!(ready[0], "API-001");
     }
+
+    /**
+     * Test: Ready tasks ordered by task priority, inheriting sprint priority
+     */
+    #[test]
+    fn test_order_by_priority() {
+        let task = |id: &str, priority: Option<Priority>| Task {
+            id: id.to_string(),
+            title: id.to_string(),
+            agent: AgentType::Api,
+            duration: "1 hour".to_string(),
+            dependencies: vec![],
+            acceptance_criteria: vec![],
+            files: vec![],
+            patterns: vec![],
+            priority,
+        };
+
+        let tasks: HashMap<TaskId, Task> = [
+            task("A", Some(Priority::Low)),
+            task("B", None),
+            task("C", Some(Priority::Critical)),
+            task("D", Some(Priority::High)),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+
+        let mut plan = ExecutableSprintPlan {
+            name: "Priority Sprint".to_string(),
+            duration: "1 day".to_string(),
+            goals: vec![],
+            tasks,
+            dependencies: HashMap::new(),
+            dependents: HashMap::new(),
+            approval_gates: vec![],
+            parallel_groups: vec![],
+            execution_order: vec![],
+            owner: None,
+            labels: vec![],
+            priority: None,
+        };
+
+        let mut ready: Vec<TaskId> = vec!["A".into(), "B".into(), "C".into(), "D".into()];
+        plan.order_by_priority(&mut ready);
+        assert_eq!(ready, vec!["C", "D", "A", "B"]);
+
+        // B inherits sprint priority
+        plan.priority = Some(Priority::Medium);
+        plan.order_by_priority(&mut ready);
+        assert_eq!(ready, vec!["C", "D", "B", "A"]);
+    }
+
+    /**
+     * Test: 300-task plan saved as binary loads identically to JSON at under 2/3 the size
+     */
+    #[test]
+    fn test_plan_binary_round_trip_matches_json() {
+        let mut yaml = String::from("sprint:\n  name: Large sprint\n  duration: 4 weeks\n  goals: [Ship it]\n  tasks:\n");
+        for i in 0..300 {
+            yaml.push_str(&format!(
+                "    - id: T-{i:03}\n      title: Task number {i}\n      agent: api\n      duration: 2 hours\n      dependencies: []\n      acceptance_criteria: [Tests pass, Docs updated]\n      files: [src/task_{i}.rs]\n",
+            ));
+        }
+        let plan = crate::sprint_parser::YamlParser::parse_from_str(&yaml).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("plan.json");
+        let binary_path = dir.path().join("plan.bin");
+        plan.save(&json_path).unwrap();
+        plan.save(&binary_path).unwrap();
+
+        let from_json = ExecutableSprintPlan::load(&json_path).unwrap();
+        let from_binary = ExecutableSprintPlan::load(&binary_path).unwrap();
+        assert_eq!(serde_json::to_value(&from_json).unwrap(), serde_json::to_value(&plan).unwrap());
+        assert_eq!(serde_json::to_value(&from_binary).unwrap(), serde_json::to_value(&from_json).unwrap());
+        assert_eq!(from_binary.execution_order, plan.execution_order);
+
+        let json_size = std::fs::metadata(&json_path).unwrap().len();
+        let binary_size = std::fs::metadata(&binary_path).unwrap().len();
+        assert!(binary_size * 3 < json_size * 2, "json {} vs binary {}", json_size, binary_size);
+    }
 }
@@ -16,7 +16,7 @@
  */
 
 use crate::error::{Error, Result};
-use crate::sprint_parser::types::ExecutableSprintPlan;
+use crate::sprint_parser::types::{ExecutableSprintPlan, Task};
 use std::collections::HashSet;
 
 /**
@@ -45,9 +45,10 @@ impl Validator {
      *
      * - No circular dependencies (DAG property)
      * - All task references valid (no dangling dependencies)
-     * - Task IDs unique
+     * - Task IDs unique (on the raw task list, at parse time: validate_unique_task_ids)
      * - Duration strings parseable
      * - Approval gate task references valid
+     * - Owner non-blank, labels non-blank and unique
      *
      * # Errors
      *
@@ -56,10 +57,8 @@ impl Validator {
     pub fn validate(plan: &ExecutableSprintPlan) -> Result<()> {
         let mut errors = Vec::new();
 
-        // Check 1: Validate task IDs are unique
-        if let Err(e) = Self::validate_unique_task_ids(plan) {
-            errors.push(e.to_string());
-        }
+        // Check 1: Task IDs unique - checked on the raw task list during parsing
+        // (validate_unique_task_ids), since plan.tasks is already keyed by id
 
         // Check 2: Validate all dependency references exist
         if let Err(e) = Self::validate_task_references(plan) {
@@ -80,6 +79,11 @@ impl Validator {
             errors.push(e.to_string());
         }
 
+        // Check 6: Validate owner/labels (priority enum already checked by serde)
+        if let Err(e) = Self::validate_triage_metadata(plan) {
+            errors.push(e.to_string());
+        }
+
         if !errors.is_empty() {
             return Err(Error::Configuration(format!(
                 "Sprint plan validation failed:\n{}",
@@ -93,26 +97,26 @@ impl Validator {
     /**
      * Validate task IDs are unique
      *
-     * DESIGN DECISION: Use HashSet to detect duplicates
-     * WHY: O(n) time complexity, simple implementation
+     * DESIGN DECISION: Check the raw task list, before it becomes the tasks HashMap
+     * WHY: Map keys are unique by construction; a duplicated id would silently
+     * replace the earlier task, so the check must run before the map is built
+     *
+     * REASONING CHAIN:
+     * 1. YamlParser::build_executable calls this on SprintMetadata.tasks
+     * 2. HashSet of seen ids, in file order
+     * 3. First repeated id → Error::DuplicateTaskId naming it
+     *
+     * PERFORMANCE: O(n)
      */
-    fn validate_unique_task_ids(plan: &ExecutableSprintPlan) -> Result<()> {
+    pub fn validate_unique_task_ids(tasks: &[Task]) -> Result<()> {
         let mut seen = HashSet::new();
-        let mut duplicates = Vec::new();
 
-        for task_id in plan.tasks.keys() {
-            if !seen.insert(task_id) {
-                duplicates.push(task_id.clone());
+        for task in tasks {
+            if !seen.insert(task.id.as_str()) {
+                return Err(Error::DuplicateTaskId(task.id.clone()));
             }
         }
 
-        if !duplicates.is_empty() {
-            return Err(Error::Configuration(format!(
-                "Duplicate task IDs found: {:?}",
-                duplicates
-            )));
-        }
-
         Ok(())
     }
 
@@ -165,6 +169,38 @@ impl Validator {
         Ok(())
     }
 
+    /**
+     * Validate triage metadata (owner, labels)
+     *
+     * DESIGN DECISION: Reject blank owner and blank/duplicate labels
+     * WHY: Triage filters match on exact strings; "" or repeated labels are authoring mistakes
+     */
+    fn validate_triage_metadata(plan: &ExecutableSprintPlan) -> Result<()> {
+        let mut problems = Vec::new();
+
+        if plan.owner.as_deref().is_some_and(|owner| owner.trim().is_empty()) {
+            problems.push("Sprint owner is blank".to_string());
+        }
+
+        let mut seen = HashSet::new();
+        for label in &plan.labels {
+            if label.trim().is_empty() {
+                problems.push("Sprint has a blank label".to_string());
+            } else if !seen.insert(label.as_str()) {
+                problems.push(format!("Duplicate sprint label: '{}'", label));
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(Error::Configuration(format!(
+                "Invalid sprint metadata:\n{}",
+                problems.join("\n")
+            )));
+        }
+
+        Ok(())
+    }
+
     /**
      * Validate duration strings are parseable
      *
@@ -255,7 +291,7 @@ impl Validator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::sprint_parser::types::{Task, AgentType};
+    use crate::sprint_parser::types::AgentType;
     use std::collections::HashMap;
 
     /**
@@ -278,6 +314,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -291,11 +328,55 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec!["DB-001".to_string()],
+            owner: None,
+            labels: vec![],
+            priority: None,
         };
 
         assert!(Validator::validate(&plan).is_ok());
     }
 
+    /**
+     * Test: Blank owner and duplicate labels rejected
+     */
+    #[test]
+    fn test_validate_triage_metadata() {
+        let mut tasks = HashMap::new();
+        tasks.insert(
+            "DB-001".to_string(),
+            Task {
+                id: "DB-001".to_string(),
+                title: "Create table".to_string(),
+                agent: AgentType::Database,
+                duration: "2 hours".to_string(),
+                dependencies: vec![],
+                acceptance_criteria: vec![],
+                files: vec![],
+                patterns: vec![],
+                priority: None,
+            },
+        );
+
+        let plan = ExecutableSprintPlan {
+            name: "Test Sprint".to_string(),
+            duration: "1 week".to_string(),
+            goals: vec![],
+            tasks,
+            dependencies: HashMap::new(),
+            dependents: HashMap::new(),
+            approval_gates: vec![],
+            parallel_groups: vec![],
+            execution_order: vec!["DB-001".to_string()],
+            owner: Some("  ".to_string()),
+            labels: vec!["auth".to_string(), "auth".to_string()],
+            priority: None,
+        };
+
+        let err = Validator::validate(&plan).unwrap_err().to_string();
+        assert!(err.contains("owner is blank"));
+        assert!(err.contains("Duplicate sprint label"));
+    }
+
     /**
      * Test: Detect invalid task references
      *
@@ -316,6 +397,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -332,6 +414,9 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec![],
+            owner: None,
+            labels: vec![],
+            priority: None,
         };
 
         let result = Validator::validate(&plan);
@@ -359,6 +444,7 @@ mod tests {
                 dependencies: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -372,6 +458,9 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec![],
+            owner: None,
+            labels: vec![],
+            priority: None,
         };
 
         let result = Validator::validate(&plan);
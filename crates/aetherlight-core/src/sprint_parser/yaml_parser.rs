@@ -16,6 +16,8 @@
  */
 
 use crate::error::{Error, Result};
+use crate::sprint_parser::parse_error::SprintParseError;
+use crate::sprint_parser::validator::Validator;
 use crate::sprint_parser::types::{SprintPlan, ExecutableSprintPlan, TaskId, ParallelGroup};
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -78,7 +80,14 @@ impl YamlParser {
             ))
         })?;
 
-        Self::parse_from_str(&contents)
+        // DESIGN DECISION: Format chosen by extension, YAML by default
+        // WHY: Teams keeping config in TOML can write sprint plans in TOML too
+        let is_toml = path.extension().and_then(|ext| ext.to_str()) == Some("toml");
+        if is_toml {
+            Self::parse_toml_str(&contents)
+        } else {
+            Self::parse_from_str(&contents)
+        }
     }
 
     /**
@@ -119,13 +128,56 @@ impl YamlParser {
     pub fn parse_from_str(yaml: &str) -> Result<ExecutableSprintPlan> {
         // DESIGN DECISION: Use serde_yaml for deserialization
         // WHY: Automatic validation, type safety, minimal code
-        let sprint_plan: SprintPlan = serde_yaml::from_str(yaml).map_err(|e| {
-            Error::Configuration(format!("Invalid sprint plan YAML: {}", e))
-        })?;
+        let sprint_plan: SprintPlan = serde_yaml::from_str(yaml)
+            .map_err(|e| Error::SprintParse(SprintParseError::from_yaml(&e, yaml)))?;
+
+        Self::build_executable(sprint_plan)
+    }
+
+    /**
+     * Parse sprint plan from TOML string
+     *
+     * DESIGN DECISION: Same SprintPlan schema as YAML (`[sprint]` table, `[[sprint.tasks]]`)
+     * WHY: One serde model, two surface syntaxes; validation identical
+     *
+     * # Examples
+     *
+     * ```rust
+     * let toml = r#"
+     * [sprint]
+     * name = "Test Sprint"
+     * duration = "1 week"
+     * goals = []
+     * priority = "high"
+     *
+     * [[sprint.tasks]]
+     * id = "DB-001"
+     * title = "Create table"
+     * agent = "database"
+     * duration = "2 hours"
+     * acceptance_criteria = ["Table exists"]
+     * "#;
+     *
+     * let plan = YamlParser::parse_toml_str(toml)?;
+     * ```
+     */
+    pub fn parse_toml_str(toml: &str) -> Result<ExecutableSprintPlan> {
+        let sprint_plan: SprintPlan = toml::from_str(toml)
+            .map_err(|e| Error::SprintParse(SprintParseError::from_toml(&e, toml)))?;
 
+        Self::build_executable(sprint_plan)
+    }
+
+    /**
+     * Build executable plan (dependency maps, parallel groups, order) from parsed plan
+     */
+    fn build_executable(sprint_plan: SprintPlan) -> Result<ExecutableSprintPlan> {
         // Extract sprint metadata
         let metadata = sprint_plan.sprint;
 
+        // Reject duplicate ids before the map below keeps only the last one
+        Validator::validate_unique_task_ids(&metadata.tasks)?;
+
         // Build tasks HashMap for O(1) lookup
         let mut tasks = HashMap::new();
         for task in metadata.tasks.iter() {
@@ -166,6 +218,9 @@ impl YamlParser {
             approval_gates: metadata.approval_gates,
             parallel_groups,
             execution_order,
+            owner: metadata.owner,
+            labels: metadata.labels,
+            priority: metadata.priority,
         })
     }
 
@@ -311,6 +366,7 @@ impl YamlParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sprint_parser::types::Priority;
 
     /**
      * Test: Parse simple sprint plan
@@ -462,4 +518,203 @@ sprint:
         assert_eq!(plan.parallel_groups[1].tasks.len(), 1);
         assert_eq!(plan.parallel_groups[1].tasks[0], "API-001");
     }
+
+    /**
+     * Test: Owner, labels, and priority parsed from YAML and preserved
+     */
+    #[test]
+    fn test_parse_triage_metadata_yaml() {
+        let yaml = r#"
+sprint:
+  name: "Triage Sprint"
+  duration: "1 week"
+  goals: []
+  owner: "alice"
+  labels: ["auth", "q3"]
+  priority: "high"
+  tasks:
+    - id: "DB-001"
+      title: "Create table"
+      agent: "database"
+      duration: "2 hours"
+      priority: "critical"
+      acceptance_criteria: []
+"#;
+
+        let plan = YamlParser::parse_from_str(yaml).unwrap();
+        assert_eq!(plan.owner.as_deref(), Some("alice"));
+        assert_eq!(plan.labels, vec!["auth", "q3"]);
+        assert_eq!(plan.priority, Some(Priority::High));
+        assert_eq!(plan.tasks["DB-001"].priority, Some(Priority::Critical));
+    }
+
+    /**
+     * Test: Same fields parsed from TOML; absent fields default
+     */
+    #[test]
+    fn test_parse_triage_metadata_toml() {
+        let toml = r#"
+[sprint]
+name = "Triage Sprint"
+duration = "1 week"
+goals = []
+labels = ["infra"]
+priority = "low"
+
+[[sprint.tasks]]
+id = "DB-001"
+title = "Create table"
+agent = "database"
+duration = "2 hours"
+acceptance_criteria = []
+"#;
+
+        let plan = YamlParser::parse_toml_str(toml).unwrap();
+        assert_eq!(plan.owner, None);
+        assert_eq!(plan.labels, vec!["infra"]);
+        assert_eq!(plan.priority, Some(Priority::Low));
+        assert_eq!(plan.tasks["DB-001"].priority, None);
+    }
+
+    /**
+     * Test: Unknown priority rejected at parse time
+     */
+    #[test]
+    fn test_invalid_priority_rejected() {
+        let yaml = r#"
+sprint:
+  name: "Bad Priority"
+  duration: "1 week"
+  goals: []
+  priority: "urgent"
+  tasks: []
+"#;
+
+        let result = YamlParser::parse_from_str(yaml);
+        assert!(result.is_err());
+    }
+
+    fn parse_failure(result: Result<ExecutableSprintPlan>) -> crate::sprint_parser::SprintParseError {
+        match result {
+            Err(Error::SprintParse(e)) => e,
+            other => panic!("Expected SprintParse error, got {:?}", other.map(|p| p.name)),
+        }
+    }
+
+    /**
+     * Test: Missing required field reports line, column and task id
+     */
+    #[test]
+    fn test_parse_error_missing_field_location() {
+        let yaml = r#"sprint:
+  name: "Missing Agent"
+  duration: "1 week"
+  goals: []
+  tasks:
+    - id: "A"
+      title: "Task A"
+      agent: "database"
+      duration: "1 hour"
+      acceptance_criteria: []
+    - id: "B"
+      title: "Task B"
+      duration: "1 hour"
+      acceptance_criteria: []
+"#;
+
+        let err = parse_failure(YamlParser::parse_from_str(yaml));
+        assert_eq!(err.line, Some(11));
+        assert!(err.column.is_some());
+        assert_eq!(err.task_id.as_deref(), Some("B"));
+        assert!(err.message.contains("missing field `agent`"), "{}", err.message);
+        assert!(!err.message.contains(" at line "), "{}", err.message);
+        assert!(err.to_string().starts_with("YAML line 11, column "), "{}", err);
+    }
+
+    /**
+     * Test: Unknown enum value reports the offending line and task id
+     */
+    #[test]
+    fn test_parse_error_bad_enum_location() {
+        let yaml = r#"sprint:
+  name: "Bad Agent"
+  duration: "1 week"
+  goals: []
+  tasks:
+    - id: "A"
+      title: "Task A"
+      agent: "wizard"
+      duration: "1 hour"
+      acceptance_criteria: []
+"#;
+
+        let err = parse_failure(YamlParser::parse_from_str(yaml));
+        assert_eq!(err.line, Some(8));
+        assert_eq!(err.task_id.as_deref(), Some("A"));
+        assert!(err.message.contains("unknown variant `wizard`"), "{}", err.message);
+    }
+
+    /**
+     * Test: TOML failures map the byte span to line/column and the task table
+     */
+    #[test]
+    fn test_parse_error_toml_location() {
+        let toml = r#"[sprint]
+name = "Bad TOML"
+duration = "1 week"
+goals = []
+
+[[sprint.tasks]]
+id = "DB-001"
+title = "Create table"
+agent = "database"
+duration = "2 hours"
+acceptance_criteria = []
+
+[[sprint.tasks]]
+id = "API-001"
+title = "Add endpoint"
+agent = "wizard"
+duration = "2 hours"
+acceptance_criteria = []
+"#;
+
+        let err = parse_failure(YamlParser::parse_toml_str(toml));
+        assert_eq!(err.format, crate::sprint_parser::SprintPlanFormat::Toml);
+        assert_eq!(err.line, Some(16));
+        assert_eq!(err.task_id.as_deref(), Some("API-001"));
+        assert!(err.message.contains("wizard"), "{}", err.message);
+    }
+
+    /**
+     * Test: Duplicate task id rejected instead of overwriting the first task
+     */
+    #[test]
+    fn test_duplicate_task_id_rejected() {
+        let yaml = r#"
+sprint:
+  name: "Duplicate Ids"
+  duration: "1 week"
+  goals: []
+  tasks:
+    - id: "DB-001"
+      title: "Create table"
+      agent: "database"
+      duration: "2 hours"
+      acceptance_criteria: []
+    - id: "API-001"
+      title: "Add endpoint"
+      agent: "api"
+      duration: "2 hours"
+      acceptance_criteria: []
+    - id: "DB-001"
+      title: "Add index"
+      agent: "database"
+      duration: "1 hour"
+      acceptance_criteria: []
+"#;
+
+        let result = YamlParser::parse_from_str(yaml);
+        assert_eq!(result.unwrap_err(), Error::DuplicateTaskId("DB-001".to_string()));
+    }
 }
@@ -17,7 +17,10 @@
 
 use crate::error::{Error, Result};
 use crate::sprint_parser::types::{TaskId, AgentType, Task};
+use crate::verification::CriteriaScore;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::time::{Duration, Instant};
 
 /**
@@ -32,7 +35,8 @@ use std::time::{Duration, Instant};
  * 3. Running: Assigned to agent, execution in progress
  * 4. Completed: Execution successful, dependents can proceed
  * 5. Failed: Execution failed, blocks dependents
- * 6. Result: Clear state machine with explicit transitions
+ * 6. PartiallyComplete: Finished with some acceptance criteria unmet, blocks dependents
+ * 7. Result: Clear state machine with explicit transitions
  */
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskStatus {
@@ -46,6 +50,9 @@ pub enum TaskStatus {
     Completed { duration: Duration },
     /// Execution failed
     Failed { error: String },
+    /// Execution finished but not every acceptance criterion verified
+    /// (completion rounded to a whole percent)
+    PartiallyComplete { completion_percentage: u8, remaining: Vec<String> },
 }
 
 /**
@@ -123,6 +130,8 @@ pub struct ExecutionState {
     completed: HashSet<TaskId>,
     /// Set of failed task IDs
     failed: HashSet<TaskId>,
+    /// Set of partially complete task IDs
+    partial: HashSet<TaskId>,
     /// Sprint start time
     started_at: Option<Instant>,
 }
@@ -160,6 +169,7 @@ impl ExecutionState {
             agents,
             completed: HashSet::new(),
             failed: HashSet::new(),
+            partial: HashSet::new(),
             started_at: None,
         }
     }
@@ -302,6 +312,50 @@ impl ExecutionState {
         Ok(())
     }
 
+    /**
+     * Finish task according to its acceptance criteria score
+     *
+     * DESIGN DECISION: All criteria met → Completed, otherwise PartiallyComplete
+     * WHY: A task verified against 2 of 3 criteria is neither done nor failed;
+     * keeping the remaining criteria on the status tells the next agent what's left
+     *
+     * Partially complete tasks release their agent but do not unblock dependents.
+     */
+    pub fn complete_with_criteria(&mut self, task_id: &TaskId, score: &CriteriaScore) -> Result<()> {
+        if score.is_complete() {
+            return self.complete_task(task_id).map(|_| ());
+        }
+
+        let status = self.tasks.get_mut(task_id)
+            .ok_or_else(|| Error::Configuration(format!("Task not found: {}", task_id)))?;
+
+        let agent_type = match status {
+            TaskStatus::Running { agent, .. } => {
+                let agent_type = agent.clone();
+                *status = TaskStatus::PartiallyComplete {
+                    completion_percentage: score.completion_percentage.round().clamp(0.0, 100.0) as u8,
+                    remaining: score.unmet.clone(),
+                };
+                agent_type
+            }
+            _ => {
+                return Err(Error::Configuration(format!(
+                    "Task {} cannot partially complete from status {:?}",
+                    task_id, status
+                )));
+            }
+        };
+
+        // Release agent
+        if let Some(agent) = self.agents.get_mut(&agent_type) {
+            agent.complete();
+        }
+
+        self.partial.insert(task_id.clone());
+
+        Ok(())
+    }
+
     /**
      * Get task status
      */
@@ -350,6 +404,26 @@ impl ExecutionState {
         &self.failed
     }
 
+    /**
+     * Get list of partially complete tasks
+     */
+    pub fn partial_tasks(&self) -> &HashSet<TaskId> {
+        &self.partial
+    }
+
+    /**
+     * Unmet acceptance criteria of each partially complete task
+     */
+    pub fn remaining_criteria(&self) -> HashMap<TaskId, Vec<String>> {
+        self.tasks
+            .iter()
+            .filter_map(|(id, status)| match status {
+                TaskStatus::PartiallyComplete { remaining, .. } => Some((id.clone(), remaining.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
     /**
      * Find available agent of given type
      */
@@ -358,11 +432,14 @@ impl ExecutionState {
     }
 
     /**
-     * Check if all tasks complete (success or failure)
+     * Check if all tasks complete (success, failure or partial completion)
      */
     pub fn is_complete(&self) -> bool {
         self.tasks.iter().all(|(_, status)| {
-            matches!(status, TaskStatus::Completed { .. } | TaskStatus::Failed { .. })
+            matches!(
+                status,
+                TaskStatus::Completed { .. } | TaskStatus::Failed { .. } | TaskStatus::PartiallyComplete { .. }
+            )
         })
     }
 
@@ -374,6 +451,7 @@ impl ExecutionState {
             total_tasks: self.tasks.len(),
             completed: self.completed.len(),
             failed: self.failed.len(),
+            partially_complete: self.partial.len(),
             running: 0,
             pending: 0,
             total_duration: Duration::ZERO,
@@ -386,7 +464,7 @@ impl ExecutionState {
                 TaskStatus::Completed { duration } => {
                     stats.total_duration += *duration;
                 }
-                TaskStatus::Failed { .. } => {}
+                TaskStatus::Failed { .. } | TaskStatus::PartiallyComplete { .. } => {}
             }
         }
 
@@ -394,6 +472,109 @@ impl ExecutionState {
     }
 }
 
+/**
+ * Finished task outcome recorded in a checkpoint
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TaskOutcome {
+    Completed { duration: Duration },
+    Failed { error: String },
+    PartiallyComplete { completion_percentage: u8, remaining: Vec<String> },
+}
+
+/**
+ * Resumable snapshot of sprint execution
+ *
+ * DESIGN DECISION: Only finished outcomes are checkpointed; running tasks restart
+ * WHY: Running status holds a process-local Instant and an agent that no longer
+ * exists after a restart, so an interrupted task must run again anyway
+ *
+ * Saved as JSON or compact binary by file extension (crate::serialization).
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionCheckpoint {
+    /// Task ID → outcome, for every finished task
+    pub outcomes: HashMap<TaskId, TaskOutcome>,
+    /// Sprint wall-clock time elapsed when the checkpoint was taken
+    pub elapsed: Duration,
+}
+
+impl ExecutionCheckpoint {
+    /// Save (".bin" = compact binary, otherwise JSON)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        crate::serialization::write_file(path, self)
+    }
+
+    /// Load a checkpoint written by `save`
+    pub fn load(path: &Path) -> Result<Self> {
+        crate::serialization::read_file(path)
+    }
+}
+
+impl ExecutionState {
+    /// Snapshot finished task outcomes for resuming later
+    pub fn checkpoint(&self) -> ExecutionCheckpoint {
+        let outcomes = self.tasks
+            .iter()
+            .filter_map(|(id, status)| {
+                let outcome = match status {
+                    TaskStatus::Completed { duration } => TaskOutcome::Completed { duration: *duration },
+                    TaskStatus::Failed { error } => TaskOutcome::Failed { error: error.clone() },
+                    TaskStatus::PartiallyComplete { completion_percentage, remaining } => {
+                        TaskOutcome::PartiallyComplete {
+                            completion_percentage: *completion_percentage,
+                            remaining: remaining.clone(),
+                        }
+                    }
+                    TaskStatus::Pending | TaskStatus::Ready | TaskStatus::Running { .. } => return None,
+                };
+                Some((id.clone(), outcome))
+            })
+            .collect();
+
+        ExecutionCheckpoint {
+            outcomes,
+            elapsed: self.elapsed().unwrap_or(Duration::ZERO),
+        }
+    }
+
+    /**
+     * Rebuild execution state from a checkpoint
+     *
+     * Tasks without an outcome start Pending (the scheduler re-derives Ready from
+     * the dependency graph). Elapsed time carries over into elapsed().
+     */
+    pub fn restore(tasks: &HashMap<TaskId, Task>, checkpoint: &ExecutionCheckpoint) -> Result<Self> {
+        let mut state = Self::new(tasks);
+
+        for (task_id, outcome) in &checkpoint.outcomes {
+            let status = state.tasks.get_mut(task_id)
+                .ok_or_else(|| Error::Configuration(format!("Checkpoint task not in sprint: {}", task_id)))?;
+
+            *status = match outcome.clone() {
+                TaskOutcome::Completed { duration } => {
+                    state.completed.insert(task_id.clone());
+                    TaskStatus::Completed { duration }
+                }
+                TaskOutcome::Failed { error } => {
+                    state.failed.insert(task_id.clone());
+                    TaskStatus::Failed { error }
+                }
+                TaskOutcome::PartiallyComplete { completion_percentage, remaining } => {
+                    state.partial.insert(task_id.clone());
+                    TaskStatus::PartiallyComplete { completion_percentage, remaining }
+                }
+            };
+        }
+
+        if !checkpoint.elapsed.is_zero() {
+            state.started_at = Instant::now().checked_sub(checkpoint.elapsed);
+        }
+
+        Ok(state)
+    }
+}
+
 /**
  * Execution statistics
  */
@@ -402,6 +583,7 @@ pub struct ExecutionStatistics {
     pub total_tasks: usize,
     pub completed: usize,
     pub failed: usize,
+    pub partially_complete: usize,
     pub running: usize,
     pub pending: usize,
     pub total_duration: Duration,
@@ -424,6 +606,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
         tasks
@@ -466,6 +649,34 @@ mod tests {
         assert_eq!(state.running_tasks().len(), 0);
     }
 
+    #[test]
+    fn test_partial_completion_names_remaining_criteria() {
+        let tasks = create_test_tasks();
+        let mut state = ExecutionState::new(&tasks);
+        let task_id = "DB-001".to_string();
+        state.start_task(&task_id, AgentType::Database).unwrap();
+
+        let score = CriteriaScore {
+            completion_percentage: 200.0 / 3.0,
+            verified: vec!["Table exists".to_string(), "Tests pass".to_string()],
+            unmet: vec!["Coverage above 80%".to_string()],
+        };
+        state.complete_with_criteria(&task_id, &score).unwrap();
+
+        match state.get_status(&task_id) {
+            Some(TaskStatus::PartiallyComplete { completion_percentage, remaining }) => {
+                assert_eq!(*completion_percentage, 67);
+                assert_eq!(remaining, &vec!["Coverage above 80%".to_string()]);
+            }
+            other => panic!("Expected PartiallyComplete, got {:?}", other),
+        }
+        assert!(!state.is_completed(&task_id));
+        assert!(state.partial_tasks().contains(&task_id));
+        assert!(state.find_idle_agent(&AgentType::Database).is_some());
+        assert!(state.is_complete());
+        assert_eq!(state.statistics().partially_complete, 1);
+    }
+
     #[test]
     fn test_agent_assignment() {
         let tasks = create_test_tasks();
@@ -488,4 +699,64 @@ mod tests {
         // Agent idle again
         assert!(state.find_idle_agent(&AgentType::Database).is_some());
     }
+
+    /**
+     * Test: Checkpoint of a 300-task sprint round-trips identically through JSON and binary
+     */
+    #[test]
+    fn test_checkpoint_binary_matches_json() {
+        let tasks: HashMap<TaskId, Task> = (0..300)
+            .map(|i| {
+                let id = format!("API-{:03}", i);
+                (id.clone(), Task {
+                    id,
+                    title: format!("Endpoint {}", i),
+                    agent: AgentType::Api,
+                    duration: "1 hour".to_string(),
+                    dependencies: vec![],
+                    acceptance_criteria: vec![],
+                    files: vec![],
+                    patterns: vec![],
+                    priority: None,
+                })
+            })
+            .collect();
+
+        let mut state = ExecutionState::new(&tasks);
+        state.start_sprint();
+        for i in 0..250 {
+            let id = format!("API-{:03}", i);
+            state.start_task(&id, AgentType::Api).unwrap();
+            match i % 10 {
+                0 => state.fail_task(&id, format!("Endpoint {} returned 500", i)).unwrap(),
+                _ => {
+                    state.complete_task(&id).unwrap();
+                }
+            }
+        }
+        state.start_task(&"API-250".to_string(), AgentType::Api).unwrap();
+
+        let checkpoint = state.checkpoint();
+        assert_eq!(checkpoint.outcomes.len(), 250); // running API-250 is not checkpointed
+
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("checkpoint.json");
+        let binary_path = dir.path().join("checkpoint.bin");
+        checkpoint.save(&json_path).unwrap();
+        checkpoint.save(&binary_path).unwrap();
+        let from_json = ExecutionCheckpoint::load(&json_path).unwrap();
+        let from_binary = ExecutionCheckpoint::load(&binary_path).unwrap();
+        assert_eq!(from_json, checkpoint);
+        assert_eq!(from_binary, from_json);
+
+        let json_size = std::fs::metadata(&json_path).unwrap().len();
+        let binary_size = std::fs::metadata(&binary_path).unwrap().len();
+        assert!(binary_size * 2 < json_size, "json {} vs binary {}", json_size, binary_size);
+
+        let restored = ExecutionState::restore(&tasks, &from_binary).unwrap();
+        assert_eq!(restored.completed_tasks().len(), 225);
+        assert_eq!(restored.failed_tasks().len(), 25);
+        assert_eq!(restored.get_status(&"API-250".to_string()), Some(&TaskStatus::Pending));
+        assert!(restored.elapsed().is_some());
+    }
 }
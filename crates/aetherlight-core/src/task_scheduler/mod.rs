@@ -20,7 +20,7 @@
  *
  * - `scheduler.rs`: Core scheduling logic (assign tasks, monitor execution)
  * - `executor.rs`: Execution state management (running, completed, failed)
- * - `monitor.rs`: Progress tracking and metrics collection
+ * - `monitor.rs`: Progress tracking and metrics collection (incl. per-task progress reports)
  *
  * # Usage Example
  *
@@ -63,9 +63,12 @@ pub mod executor;
 pub mod monitor;
 
 // Re-export primary types for ergonomic imports
-pub use scheduler::TaskScheduler;
-pub use executor::{ExecutionState, TaskStatus, AgentAssignment};
-pub use monitor::{ProgressMonitor, SprintMetrics, SprintResult};
+pub use scheduler::{TaskScheduler, ProgressListener};
+pub use executor::{ExecutionState, TaskStatus, AgentAssignment, ExecutionCheckpoint, TaskOutcome};
+pub use monitor::{
+    ProgressMonitor, SprintMetrics, SprintResult, LevelConcurrency, TaskInterval,
+    ProgressReporter, ProgressUpdate, ProgressEvent,
+};
 
 #[cfg(test)]
 mod tests {
@@ -100,6 +103,7 @@ mod tests {
                 acceptance_criteria: vec!["Schema created".to_string()],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -113,6 +117,9 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec!["DB-001".to_string()],
+            owner: None,
+            labels: vec![],
+            priority: None,
         }
     }
 }
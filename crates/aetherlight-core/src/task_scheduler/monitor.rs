@@ -15,9 +15,11 @@
  * PERFORMANCE: <5ms metrics calculation, minimal overhead
  */
 
-use crate::sprint_parser::types::TaskId;
+use crate::sprint_parser::types::{ExecutableSprintPlan, TaskId};
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{self, Receiver, Sender};
 
 /**
  * Sprint execution result
@@ -30,9 +32,11 @@ use std::collections::HashMap;
  * 2. Failure metrics: Tasks failed, error analysis
  * 3. Efficiency metrics: Actual vs theoretical time, bottlenecks
  * 4. Historical data: Feed into planning agent for better estimates
- * 5. Result: Data-driven sprint optimization
+ * 5. Critical path + per-level concurrency show where parallelism was lost
+ * 6. Partially complete tasks list their unmet criteria; their dependents are blocked
+ * 7. Result: Data-driven sprint optimization (serializable for CLI/UI retrospectives)
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SprintResult {
     /// Total sprint duration (wall-clock time)
     pub total_time: Duration,
@@ -40,18 +44,58 @@ pub struct SprintResult {
     pub tasks_completed: usize,
     /// Number of tasks failed
     pub tasks_failed: usize,
-    /// Parallel efficiency (0.0 to 1.0)
-    /// 1.0 = perfect parallel execution
+    /// Parallel efficiency (0.0 to 1.0): share of possible savings vs sequential realized
+    /// 1.0 = finished at theoretical_min_time
     /// 0.0 = completely sequential
     pub parallel_efficiency: f64,
-    /// Theoretical minimum time (if all tasks parallel)
+    /// Theoretical minimum time: longest single task, or critical_path_time once
+    /// `with_schedule` supplies the dependency graph
     pub theoretical_min_time: Duration,
     /// Actual time saved vs sequential execution
     pub time_saved: Duration,
     /// Individual task durations
     pub task_durations: HashMap<TaskId, Duration>,
+    /// Tasks in the order they were started
+    pub start_order: Vec<TaskId>,
+    /// Sum of task durations (sequential baseline)
+    pub sequential_baseline: Duration,
+    /// Sequential baseline / wall-clock time: mean number of tasks running at once
+    /// (1.0 = sequential). Not a measured speedup: no sequential run is timed
+    pub average_concurrency: f64,
+    /// Longest dependency chain by task duration (start → finish)
+    pub critical_path: Vec<TaskId>,
+    /// Total duration of the critical path (lower bound on wall-clock time)
+    pub critical_path_time: Duration,
+    /// Concurrency achieved at each dependency level
+    pub level_concurrency: Vec<LevelConcurrency>,
+    /// Unmet acceptance criteria of each partially complete task
+    #[serde(default)]
+    pub remaining_criteria: HashMap<TaskId, Vec<String>>,
+    /// Tasks never started because a dependency is only partially complete (sorted)
+    #[serde(default)]
+    pub blocked_tasks: Vec<TaskId>,
 }
 
+/**
+ * Concurrency achieved within one dependency level
+ *
+ * DESIGN DECISION: Level = longest dependency chain from a root (roots are level 0)
+ * WHY: Tasks on the same level are the ones that *could* overlap; comparing
+ * `tasks` with `max_concurrent` shows where parallelism was left unused
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelConcurrency {
+    /// Dependency level (0 = no dependencies)
+    pub level: usize,
+    /// Number of tasks on this level
+    pub tasks: usize,
+    /// Most tasks from this level running at the same instant
+    pub max_concurrent: usize,
+}
+
+/// Task execution window relative to sprint start: (started, finished)
+pub type TaskInterval = (Duration, Duration);
+
 impl SprintResult {
     /**
      * Calculate parallel efficiency
@@ -82,21 +126,17 @@ impl SprintResult {
         let sum_of_durations: Duration = task_durations.values().sum();
         let longest_task = task_durations.values().max().copied().unwrap_or(Duration::ZERO);
 
-        // Calculate parallel efficiency
-        let efficiency = if sum_of_durations > longest_task {
-            let saved = sum_of_durations.saturating_sub(total_time);
-            let possible_savings = sum_of_durations.saturating_sub(longest_task);
-            if possible_savings > Duration::ZERO {
-                saved.as_secs_f64() / possible_savings.as_secs_f64()
-            } else {
-                1.0 // Only one task, no parallelism possible
-            }
-        } else {
-            0.0
-        };
+        // Calculate parallel efficiency (no dependency info yet: longest task is the bound)
+        let efficiency = Self::efficiency(sum_of_durations, total_time, longest_task);
 
         let time_saved = sum_of_durations.saturating_sub(total_time);
 
+        let average_concurrency = if total_time > Duration::ZERO {
+            sum_of_durations.as_secs_f64() / total_time.as_secs_f64()
+        } else {
+            1.0
+        };
+
         Self {
             total_time,
             tasks_completed,
@@ -105,9 +145,170 @@ impl SprintResult {
             theoretical_min_time: longest_task,
             time_saved,
             task_durations,
+            start_order: Vec::new(),
+            sequential_baseline: sum_of_durations,
+            average_concurrency,
+            critical_path: Vec::new(),
+            critical_path_time: Duration::ZERO,
+            level_concurrency: Vec::new(),
+            remaining_criteria: HashMap::new(),
+            blocked_tasks: Vec::new(),
         }
     }
 
+    /// (baseline - actual) / (baseline - min), clamped to 0.0..=1.0
+    fn efficiency(baseline: Duration, actual: Duration, min: Duration) -> f64 {
+        if baseline <= min {
+            return 0.0; // Single task/chain: no parallelism possible
+        }
+        let saved = baseline.saturating_sub(actual);
+        let possible_savings = baseline - min;
+        (saved.as_secs_f64() / possible_savings.as_secs_f64()).clamp(0.0, 1.0)
+    }
+
+    /**
+     * Add plan-dependent metrics: critical path and per-level concurrency
+     *
+     * DESIGN DECISION: Separate step from new()
+     * WHY: new() only knows durations; critical path needs the dependency graph,
+     * level concurrency needs actual start/finish windows
+     *
+     * REASONING CHAIN:
+     * 1. Level of a task = 1 + max level of its dependencies (roots = 0)
+     * 2. Finish(task) = duration(task) + max finish of its dependencies
+     * 3. Critical path = follow the latest-finishing dependency back from the
+     *    latest-finishing task (ties broken by task ID for stable output)
+     * 4. theoretical_min_time/parallel_efficiency rebased on critical_path_time:
+     *    dependent tasks can't overlap, so the longest task alone overstates what
+     *    was achievable and understates efficiency
+     * 5. Per level: sweep start/finish events to find peak overlap
+     *
+     * Dependency cycles (invalid plans) are cut at the back edge rather than recursing forever.
+     *
+     * PERFORMANCE: O(V + E) for path/levels, O(V log V) for overlap sweep
+     */
+    pub fn with_schedule(
+        mut self,
+        plan: &ExecutableSprintPlan,
+        intervals: &HashMap<TaskId, TaskInterval>,
+    ) -> Self {
+        let mut task_ids: Vec<&TaskId> = plan.tasks.keys().collect();
+        task_ids.sort();
+
+        let mut finish: HashMap<TaskId, Duration> = HashMap::new();
+        let mut levels: HashMap<TaskId, usize> = HashMap::new();
+        let mut visiting: HashSet<TaskId> = HashSet::new();
+        for task_id in &task_ids {
+            self.chain_finish(plan, task_id, &mut finish, &mut levels, &mut visiting);
+        }
+
+        // Critical path: walk back from the latest-finishing task
+        let mut current = task_ids
+            .iter()
+            .max_by(|a, b| finish[**a].cmp(&finish[**b]).then_with(|| b.cmp(a)))
+            .map(|id| (*id).clone());
+        self.critical_path_time = current.as_ref().map(|id| finish[id]).unwrap_or_default();
+
+        let mut path = Vec::new();
+        let mut on_path: HashSet<TaskId> = HashSet::new();
+        while let Some(task_id) = current {
+            on_path.insert(task_id.clone());
+            current = plan
+                .dependencies
+                .get(&task_id)
+                .into_iter()
+                .flatten()
+                .filter(|dep| finish.contains_key(*dep) && !on_path.contains(*dep))
+                .max_by(|a, b| finish[*a].cmp(&finish[*b]).then_with(|| b.cmp(a)))
+                .cloned();
+            path.push(task_id);
+        }
+        path.reverse();
+        self.critical_path = path;
+
+        if !self.critical_path.is_empty() {
+            self.theoretical_min_time = self.critical_path_time;
+            self.parallel_efficiency =
+                Self::efficiency(self.sequential_baseline, self.total_time, self.critical_path_time);
+        }
+
+        // Per-level concurrency from actual execution windows
+        let mut by_level: HashMap<usize, Vec<&TaskId>> = HashMap::new();
+        for (task_id, level) in &levels {
+            by_level.entry(*level).or_default().push(task_id);
+        }
+        let mut level_concurrency: Vec<LevelConcurrency> = by_level
+            .into_iter()
+            .map(|(level, tasks)| LevelConcurrency {
+                level,
+                tasks: tasks.len(),
+                max_concurrent: Self::peak_overlap(tasks.iter().filter_map(|id| intervals.get(*id))),
+            })
+            .collect();
+        level_concurrency.sort_by_key(|l| l.level);
+        self.level_concurrency = level_concurrency;
+
+        self
+    }
+
+    /// Memoized (finish time, level) of the longest dependency chain ending at task_id
+    ///
+    /// `visiting` holds the current recursion stack; a dependency already on it is a
+    /// cycle and is ignored.
+    fn chain_finish(
+        &self,
+        plan: &ExecutableSprintPlan,
+        task_id: &TaskId,
+        finish: &mut HashMap<TaskId, Duration>,
+        levels: &mut HashMap<TaskId, usize>,
+        visiting: &mut HashSet<TaskId>,
+    ) -> (Duration, usize) {
+        if let (Some(done), Some(level)) = (finish.get(task_id), levels.get(task_id)) {
+            return (*done, *level);
+        }
+
+        visiting.insert(task_id.clone());
+        let mut start = Duration::ZERO;
+        let mut level = 0;
+        for dep in plan.dependencies.get(task_id).into_iter().flatten() {
+            if !plan.tasks.contains_key(dep) || visiting.contains(dep) {
+                continue;
+            }
+            let (dep_finish, dep_level) = self.chain_finish(plan, dep, finish, levels, visiting);
+            start = start.max(dep_finish);
+            level = level.max(dep_level + 1);
+        }
+        visiting.remove(task_id);
+
+        let duration = self.task_durations.get(task_id).copied().unwrap_or_else(|| {
+            plan.tasks
+                .get(task_id)
+                .map(|task| ExecutableSprintPlan::parse_duration(&task.duration))
+                .unwrap_or_default()
+        });
+
+        finish.insert(task_id.clone(), start + duration);
+        levels.insert(task_id.clone(), level);
+        (start + duration, level)
+    }
+
+    /// Maximum number of intervals open at the same instant
+    fn peak_overlap<'a>(intervals: impl Iterator<Item = &'a TaskInterval>) -> usize {
+        // Finish (-1) sorts before start (+1) at equal times: back-to-back ≠ overlap
+        let mut events: Vec<(Duration, i32)> = intervals
+            .flat_map(|(start, end)| [(*start, 1), (*end, -1)])
+            .collect();
+        events.sort();
+
+        let mut open = 0i32;
+        let mut peak = 0i32;
+        for (_, delta) in events {
+            open += delta;
+            peak = peak.max(open);
+        }
+        peak as usize
+    }
+
     /**
      * Format result as human-readable summary
      */
@@ -118,14 +319,19 @@ impl SprintResult {
              - Tasks completed: {}\n\
              - Tasks failed: {}\n\
              - Parallel efficiency: {:.1}%\n\
+             - Average concurrency: {:.2} tasks\n\
              - Time saved: {:.1}h\n\
-             - Theoretical min: {:.1}h",
+             - Theoretical min: {:.1}h\n\
+             - Critical path: {} ({:.1}h)",
             self.total_time.as_secs_f64() / 3600.0,
             self.tasks_completed,
             self.tasks_failed,
             self.parallel_efficiency * 100.0,
+            self.average_concurrency,
             self.time_saved.as_secs_f64() / 3600.0,
             self.theoretical_min_time.as_secs_f64() / 3600.0,
+            self.critical_path.join(" → "),
+            self.critical_path_time.as_secs_f64() / 3600.0,
         )
     }
 }
@@ -150,6 +356,77 @@ pub struct SprintMetrics {
     pub remaining: usize,
     /// Estimated time to completion (based on current progress)
     pub estimated_remaining: Option<Duration>,
+    /// Overall sprint progress (0.0 to 1.0), including partial progress of running tasks
+    pub progress: f64,
+}
+
+/**
+ * Progress update sent by an agent while its task runs
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressUpdate {
+    /// Task reporting progress
+    pub task_id: TaskId,
+    /// Task progress (0.0 to 1.0)
+    pub fraction: f64,
+    /// What the agent is doing now
+    pub message: String,
+}
+
+/**
+ * Progress update after it was applied to the sprint
+ *
+ * DESIGN DECISION: Carries the sprint total alongside the task update
+ * WHY: Listeners (CLI progress bar, IPC progress signal) render both without
+ * needing access to the monitor
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressEvent {
+    /// Task that reported progress
+    pub task_id: TaskId,
+    /// Task progress (0.0 to 1.0)
+    pub fraction: f64,
+    /// Agent's message
+    pub message: String,
+    /// Overall sprint progress (0.0 to 1.0) after this update
+    pub sprint_progress: f64,
+}
+
+/**
+ * Handle an executor uses to report progress on its task
+ *
+ * DESIGN DECISION: Channel sender, not a reference to the monitor
+ * WHY: Agents report from their own thread; the scheduler owns the monitor and
+ * applies each update as it arrives (ProgressMonitor::report_progress)
+ *
+ * Fractions are clamped to 0.0..=1.0. Reporting after the scheduler is gone is a no-op.
+ */
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    task_id: TaskId,
+    sender: Sender<ProgressUpdate>,
+}
+
+impl ProgressReporter {
+    /// Reporter sending `task_id`'s updates to `sender`
+    pub(crate) fn new(task_id: TaskId, sender: Sender<ProgressUpdate>) -> Self {
+        Self { task_id, sender }
+    }
+
+    /// Task this reporter reports for
+    pub fn task_id(&self) -> &TaskId {
+        &self.task_id
+    }
+
+    /// Report `fraction` (0.0 to 1.0) of the task done, with a status message
+    pub fn report(&self, fraction: f64, message: impl Into<String>) {
+        let fraction = if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) };
+        let _ = self.sender.send(ProgressUpdate {
+            task_id: self.task_id.clone(),
+            fraction,
+            message: message.into(),
+        });
+    }
 }
 
 /**
@@ -173,16 +450,32 @@ pub struct ProgressMonitor {
     task_starts: HashMap<TaskId, Instant>,
     /// Task ID → Duration
     task_durations: HashMap<TaskId, Duration>,
+    /// Task IDs in start order
+    start_order: Vec<TaskId>,
+    /// Task ID → (start, finish) relative to sprint start
+    task_intervals: HashMap<TaskId, TaskInterval>,
+    /// Task ID → last reported progress (running tasks only)
+    task_progress: HashMap<TaskId, f64>,
+    /// Sending side handed to reporters
+    progress_sender: Sender<ProgressUpdate>,
+    /// Queued updates not yet applied
+    progress_receiver: Receiver<ProgressUpdate>,
     /// Total number of tasks
     total_tasks: usize,
 }
 
 impl ProgressMonitor {
     pub fn new(total_tasks: usize) -> Self {
+        let (progress_sender, progress_receiver) = mpsc::channel();
         Self {
             started_at: None,
             task_starts: HashMap::new(),
             task_durations: HashMap::new(),
+            start_order: Vec::new(),
+            task_intervals: HashMap::new(),
+            task_progress: HashMap::new(),
+            progress_sender,
+            progress_receiver,
             total_tasks,
         }
     }
@@ -198,6 +491,7 @@ impl ProgressMonitor {
      * Record task start
      */
     pub fn start_task(&mut self, task_id: TaskId) {
+        self.start_order.push(task_id.clone());
         self.task_starts.insert(task_id, Instant::now());
     }
 
@@ -205,10 +499,81 @@ impl ProgressMonitor {
      * Record task completion
      */
     pub fn complete_task(&mut self, task_id: &TaskId) {
+        self.task_progress.remove(task_id);
         if let Some(start_time) = self.task_starts.remove(task_id) {
             let duration = start_time.elapsed();
             self.task_durations.insert(task_id.clone(), duration);
+
+            if let Some(sprint_start) = self.started_at {
+                let started = start_time.saturating_duration_since(sprint_start);
+                self.task_intervals.insert(task_id.clone(), (started, started + duration));
+            }
+        }
+    }
+
+    /**
+     * Create a reporter for a task's executor
+     */
+    pub fn reporter(&self, task_id: TaskId) -> ProgressReporter {
+        ProgressReporter::new(task_id, self.progress_sender.clone())
+    }
+
+    /**
+     * Record progress on a running task
+     *
+     * Returns None (update ignored) if the task is not running: late updates
+     * must not pull a completed task back below 100%.
+     */
+    pub fn report_progress(&mut self, update: ProgressUpdate) -> Option<ProgressEvent> {
+        if !self.task_starts.contains_key(&update.task_id) {
+            return None;
+        }
+        self.task_progress.insert(update.task_id.clone(), update.fraction);
+
+        Some(ProgressEvent {
+            task_id: update.task_id,
+            fraction: update.fraction,
+            message: update.message,
+            sprint_progress: self.sprint_progress(),
+        })
+    }
+
+    /**
+     * Apply all updates queued by reporters, in the order they were sent
+     */
+    pub fn drain_progress(&mut self) -> Vec<ProgressEvent> {
+        let updates: Vec<ProgressUpdate> = self.progress_receiver.try_iter().collect();
+        updates
+            .into_iter()
+            .filter_map(|update| self.report_progress(update))
+            .collect()
+    }
+
+    /**
+     * Last reported progress of a task (1.0 once completed, None if not started or silent)
+     */
+    pub fn task_progress(&self, task_id: &TaskId) -> Option<f64> {
+        if self.task_durations.contains_key(task_id) {
+            return Some(1.0);
+        }
+        self.task_progress.get(task_id).copied()
+    }
+
+    /**
+     * Overall sprint progress (0.0 to 1.0)
+     *
+     * DESIGN DECISION: Every task weighs the same
+     * WHY: Plan durations are estimates; equal weights keep the figure stable
+     * and match the completed/remaining counts shown next to it
+     *
+     * FORMULA: (completed + Σ progress of running tasks) / total tasks
+     */
+    pub fn sprint_progress(&self) -> f64 {
+        if self.total_tasks == 0 {
+            return 1.0;
         }
+        let done = self.task_durations.len() as f64 + self.task_progress.values().sum::<f64>();
+        (done / self.total_tasks as f64).clamp(0.0, 1.0)
     }
 
     /**
@@ -235,6 +600,7 @@ impl ProgressMonitor {
             running: running_count,
             remaining,
             estimated_remaining,
+            progress: self.sprint_progress(),
         })
     }
 
@@ -243,26 +609,154 @@ impl ProgressMonitor {
      */
     pub fn finalize(
         self,
+        plan: &ExecutableSprintPlan,
         tasks_completed: usize,
         tasks_failed: usize,
     ) -> Option<SprintResult> {
         let started_at = self.started_at?;
         let total_time = started_at.elapsed();
 
-        Some(SprintResult::new(
+        let mut result = SprintResult::new(
             total_time,
             tasks_completed,
             tasks_failed,
             self.task_durations,
-        ))
+        )
+        .with_schedule(plan, &self.task_intervals);
+        result.start_order = self.start_order;
+
+        Some(result)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sprint_parser::types::{AgentType, Task};
     use std::thread;
 
+    fn hours(h: f64) -> Duration {
+        Duration::from_secs_f64(h * 3600.0)
+    }
+
+    /// Diamond: A → {B, C} → D
+    fn diamond_plan() -> ExecutableSprintPlan {
+        let task = |id: &str, deps: &[&str]| Task {
+            id: id.to_string(),
+            title: id.to_string(),
+            agent: AgentType::Api,
+            duration: "1 hour".to_string(),
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            acceptance_criteria: vec![],
+            files: vec![],
+            patterns: vec![],
+            priority: None,
+        };
+        let tasks: HashMap<TaskId, Task> = [
+            task("A", &[]),
+            task("B", &["A"]),
+            task("C", &["A"]),
+            task("D", &["B", "C"]),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+        let dependencies = tasks
+            .values()
+            .filter(|t| !t.dependencies.is_empty())
+            .map(|t| (t.id.clone(), t.dependencies.clone()))
+            .collect();
+
+        ExecutableSprintPlan {
+            name: "Diamond".to_string(),
+            duration: "1 day".to_string(),
+            goals: vec![],
+            tasks,
+            dependencies,
+            dependents: HashMap::new(),
+            approval_gates: vec![],
+            parallel_groups: vec![],
+            execution_order: vec![],
+            owner: None,
+            labels: vec![],
+            priority: None,
+        }
+    }
+
+    /**
+     * Test: Diamond graph critical path, concurrency bounds, per-level concurrency
+     */
+    #[test]
+    fn test_diamond_critical_path_and_concurrency() {
+        let plan = diamond_plan();
+
+        // A=1h, B=3h, C=1h, D=2h → critical path A→B→D = 6h, sequential = 7h
+        let durations: HashMap<TaskId, Duration> = [("A", 1.0), ("B", 3.0), ("C", 1.0), ("D", 2.0)]
+            .into_iter()
+            .map(|(id, h)| (id.to_string(), hours(h)))
+            .collect();
+        let intervals: HashMap<TaskId, TaskInterval> = [
+            ("A", 0.0, 1.0),
+            ("B", 1.0, 4.0),
+            ("C", 1.0, 2.0),
+            ("D", 4.5, 6.5),
+        ]
+        .into_iter()
+        .map(|(id, start, end)| (id.to_string(), (hours(start), hours(end))))
+        .collect();
+
+        let result = SprintResult::new(hours(6.5), 4, 0, durations).with_schedule(&plan, &intervals);
+
+        assert_eq!(result.critical_path, vec!["A", "B", "D"]);
+        assert_eq!(result.critical_path_time, hours(6.0));
+        assert_eq!(result.sequential_baseline, hours(7.0));
+
+        // Efficiency measured against the critical path, not the longest task (3h)
+        assert_eq!(result.theoretical_min_time, hours(6.0));
+        assert!((result.parallel_efficiency - 0.5).abs() < 0.01); // (7 - 6.5) / (7 - 6)
+
+        // Between fully sequential (1.0) and ideal parallel (baseline / critical path)
+        let ideal = result.sequential_baseline.as_secs_f64() / result.critical_path_time.as_secs_f64();
+        assert!(result.average_concurrency > 1.0);
+        assert!(result.average_concurrency < ideal);
+
+        assert_eq!(
+            result.level_concurrency,
+            vec![
+                LevelConcurrency { level: 0, tasks: 1, max_concurrent: 1 },
+                LevelConcurrency { level: 1, tasks: 2, max_concurrent: 2 },
+                LevelConcurrency { level: 2, tasks: 1, max_concurrent: 1 },
+            ]
+        );
+
+        // Serializable for CLI/UI
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: SprintResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.critical_path, result.critical_path);
+    }
+
+    /**
+     * Test: Dependency cycle doesn't overflow the stack or loop the path walk
+     */
+    #[test]
+    fn test_cyclic_dependencies_terminate() {
+        let mut plan = diamond_plan();
+        // A now also depends on D: A → B → D → A
+        plan.dependencies.insert("A".to_string(), vec!["D".to_string()]);
+
+        let durations: HashMap<TaskId, Duration> = [("A", 1.0), ("B", 3.0), ("C", 1.0), ("D", 2.0)]
+            .into_iter()
+            .map(|(id, h)| (id.to_string(), hours(h)))
+            .collect();
+
+        let result = SprintResult::new(hours(7.0), 4, 0, durations).with_schedule(&plan, &HashMap::new());
+
+        let unique: HashSet<&TaskId> = result.critical_path.iter().collect();
+        assert_eq!(unique.len(), result.critical_path.len());
+        assert!(!result.critical_path.is_empty());
+        assert!(result.critical_path_time <= hours(7.0));
+    }
+
     #[test]
     fn test_sprint_result_calculation() {
         let mut task_durations = HashMap::new();
@@ -302,6 +796,35 @@ mod tests {
         assert!(metrics.estimated_remaining.is_some());
     }
 
+    /**
+     * Test: Partial task progress counts toward the sprint total
+     */
+    #[test]
+    fn test_reported_progress_in_sprint_total() {
+        let mut monitor = ProgressMonitor::new(2);
+        monitor.start_sprint();
+        monitor.start_task("DB-001".to_string());
+
+        monitor.reporter("DB-001".to_string()).report(0.5, "Schema drafted");
+        // Not started: ignored
+        monitor.reporter("API-001".to_string()).report(0.9, "Too early");
+
+        let events = monitor.drain_progress();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].message, "Schema drafted");
+        assert!((events[0].sprint_progress - 0.25).abs() < 1e-9);
+        assert_eq!(monitor.task_progress(&"DB-001".to_string()), Some(0.5));
+        assert!((monitor.metrics(1).unwrap().progress - 0.25).abs() < 1e-9);
+
+        monitor.complete_task(&"DB-001".to_string());
+        assert!((monitor.sprint_progress() - 0.5).abs() < 1e-9);
+
+        // Late update after completion doesn't lower the total
+        monitor.reporter("DB-001".to_string()).report(0.1, "Late");
+        assert!(monitor.drain_progress().is_empty());
+        assert_eq!(monitor.task_progress(&"DB-001".to_string()), Some(1.0));
+    }
+
     #[test]
     fn test_perfect_parallel_efficiency() {
         let mut task_durations = HashMap::new();
@@ -16,11 +16,21 @@
  */
 
 use crate::error::{Error, Result};
-use crate::sprint_parser::types::{ExecutableSprintPlan, TaskId, AgentType};
+use crate::sprint_parser::types::{ExecutableSprintPlan, Task, TaskId, AgentType};
 use crate::sprint_parser::dependency_graph::DependencyGraph as SprintDependencyGraph;
 use crate::task_scheduler::executor::{ExecutionState, TaskStatus};
-use crate::task_scheduler::monitor::{ProgressMonitor, SprintResult};
+use crate::task_scheduler::monitor::{ProgressEvent, ProgressMonitor, ProgressReporter, SprintResult};
+use crate::ipc::{ProgressSignal, SignalWriter};
+use crate::verification::CriteriaScore;
 use std::collections::HashMap;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+/// How often a silent executor is checked for having finished
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Receives every applied progress update (CLI progress bar, IPC progress signal)
+pub type ProgressListener = Box<dyn FnMut(&ProgressEvent)>;
 
 /**
  * Task scheduler for sprint execution
@@ -50,6 +60,12 @@ pub struct TaskScheduler {
     monitor: Option<ProgressMonitor>,
     /// Sprint plan being executed
     plan: Option<ExecutableSprintPlan>,
+    /// Maximum tasks running at once (None = limited only by idle agents)
+    max_concurrency: Option<usize>,
+    /// Notified of task progress during execution
+    progress_listener: Option<ProgressListener>,
+    /// Writes a progress signal per update (IDE/desktop watchers)
+    progress_signals: Option<SignalWriter>,
 }
 
 impl TaskScheduler {
@@ -62,9 +78,49 @@ impl TaskScheduler {
             state: None,
             monitor: None,
             plan: None,
+            max_concurrency: None,
+            progress_listener: None,
+            progress_signals: None,
         }
     }
 
+    /**
+     * Limit how many tasks run at once
+     *
+     * DESIGN DECISION: Highest-priority ready tasks win the available slots
+     * WHY: When agents/terminals are scarce, critical work should not wait behind
+     * low-priority tasks that happened to become ready at the same time
+     *
+     * Values below 1 are treated as 1.
+     */
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency.max(1));
+        self
+    }
+
+    /**
+     * Notify `listener` of every progress update reported by task executors
+     *
+     * Each event carries the task's fraction and message plus the overall sprint
+     * progress at that moment, so the listener can drive a progress bar directly.
+     */
+    pub fn with_progress_listener(mut self, listener: impl FnMut(&ProgressEvent) + 'static) -> Self {
+        self.progress_listener = Some(Box::new(listener));
+        self
+    }
+
+    /**
+     * Write every progress update as a ProgressSignal (`<task_id>.progress.json`)
+     *
+     * DESIGN DECISION: Signals go through the workflow directory, like completion signals
+     * WHY: VS Code and the desktop app already watch `.lumina/workflow`; a failed write
+     * is logged and never aborts the sprint
+     */
+    pub fn with_progress_signals(mut self, writer: SignalWriter) -> Self {
+        self.progress_signals = Some(writer);
+        self
+    }
+
     /**
      * Check if scheduler is idle (not executing)
      */
@@ -88,6 +144,53 @@ impl TaskScheduler {
      * FUTURE: This will become `async fn execute_sprint()` in AS-004
      */
     pub fn execute_sprint_sync(&mut self, plan: ExecutableSprintPlan) -> Result<SprintResult> {
+        self.execute_sprint_with(plan, |_, _| Ok(()))
+    }
+
+    /**
+     * Execute sprint plan, running each task through `executor`
+     *
+     * DESIGN DECISION: Executor receives a ProgressReporter for its task
+     * WHY: Long tasks otherwise jump from 0% to 100%; intermediate
+     * `(fraction, message)` reports feed ProgressMonitor's sprint total
+     *
+     * REASONING CHAIN:
+     * 1. Task assigned → executor(task, reporter) runs on a scoped thread
+     * 2. Meanwhile the scheduler applies each report as it arrives and forwards it
+     *    to the progress listener and progress signal writer
+     * 3. Executor returns → its reporter is dropped, the channel closes
+     * 4. Task completed → counts as 1.0 regardless of its last report
+     *
+     * An executor error aborts the sprint and is returned as-is; a panic is re-raised.
+     */
+    pub fn execute_sprint_with<F>(&mut self, plan: ExecutableSprintPlan, mut executor: F) -> Result<SprintResult>
+    where
+        F: FnMut(&Task, &ProgressReporter) -> Result<()> + Send,
+    {
+        self.execute_sprint_with_criteria(plan, move |task, reporter| {
+            executor(task, reporter).map(|()| CriteriaScore::score(&[], &[]))
+        })
+    }
+
+    /**
+     * Execute sprint plan, scoring each task against its acceptance criteria
+     *
+     * DESIGN DECISION: Executor returns the task's CriteriaScore (e.g. from
+     * Verifier::verify_criteria); a score with unmet criteria partially completes the task
+     * WHY: A task verified against 2 of 3 criteria must not unblock work that builds on it,
+     * but the rest of the sprint can still run
+     *
+     * REASONING CHAIN:
+     * 1. All criteria met → Completed, dependents become ready
+     * 2. Some unmet → PartiallyComplete, agent released, dependents stay Pending
+     * 3. Nothing left to assign and nothing running → sprint ends (no deadlock error
+     *    when partially complete tasks explain the leftovers)
+     * 4. SprintResult lists the remaining criteria per task and the blocked tasks
+     */
+    pub fn execute_sprint_with_criteria<F>(&mut self, plan: ExecutableSprintPlan, mut executor: F) -> Result<SprintResult>
+    where
+        F: FnMut(&Task, &ProgressReporter) -> Result<CriteriaScore> + Send,
+    {
         // Initialize components
         let mut graph = SprintDependencyGraph::build(&plan)?;
         let mut state = ExecutionState::new(&plan.tasks);
@@ -102,8 +205,11 @@ impl TaskScheduler {
 
         // Main scheduling loop
         while !state.is_complete() {
-            // Find tasks ready to execute (dependencies satisfied)
-            let ready_tasks = graph.ready_tasks();
+            // Find tasks ready to execute (dependencies satisfied), highest priority first;
+            // partially complete tasks stay "ready" in the graph but have already run
+            let mut ready_tasks = graph.ready_tasks();
+            ready_tasks.retain(|task_id| !state.partial_tasks().contains(task_id));
+            plan.order_by_priority(&mut ready_tasks);
 
             // Mark tasks as ready in state
             for task_id in &ready_tasks {
@@ -112,9 +218,12 @@ impl TaskScheduler {
                 }
             }
 
-            // Assign tasks to available agents
+            // Assign tasks to available agents (up to the free concurrency slots)
+            let slots = self.max_concurrency
+                .map(|limit| limit.saturating_sub(state.running_tasks().len()))
+                .unwrap_or(usize::MAX);
             let mut assigned = false;
-            for task_id in ready_tasks {
+            for task_id in ready_tasks.into_iter().take(slots) {
                 // Get task info
                 let task = plan.tasks.get(&task_id)
                     .ok_or_else(|| Error::Configuration(format!("Task not found: {}", task_id)))?;
@@ -126,11 +235,21 @@ impl TaskScheduler {
                     monitor.start_task(task_id.clone());
                     assigned = true;
 
-                    // Simulate task execution (will be real in AS-004)
-                    // For now, immediately complete the task
-                    let duration = state.complete_task(&task_id)?;
+                    // Run task (one at a time until AS-004), forwarding its progress live
+                    let score = self.run_task(task, &mut executor, &mut monitor)?;
+
+                    state.complete_with_criteria(&task_id, &score)?;
                     monitor.complete_task(&task_id);
-                    graph.mark_complete(task_id)?;
+                    if score.is_complete() {
+                        graph.mark_complete(task_id)?;
+                    } else {
+                        tracing::warn!(
+                            task = %task_id,
+                            remaining = ?score.unmet,
+                            "Task partially complete ({:.0}%), dependents blocked",
+                            score.completion_percentage
+                        );
+                    }
                 }
             }
 
@@ -139,6 +258,10 @@ impl TaskScheduler {
                 // Check for deadlock
                 let running = state.running_tasks();
                 if running.is_empty() {
+                    // Leftovers wait on partially complete tasks: sprint ends here
+                    if !state.partial_tasks().is_empty() {
+                        break;
+                    }
                     return Err(Error::Configuration(
                         "Deadlock detected: No tasks running but sprint not complete".to_string()
                     ));
@@ -148,8 +271,14 @@ impl TaskScheduler {
 
         // Generate result
         let stats = state.statistics();
-        let result = monitor.finalize(stats.completed, stats.failed)
+        let mut result = monitor.finalize(&plan, stats.completed, stats.failed)
             .ok_or_else(|| Error::Configuration("Failed to generate sprint result".to_string()))?;
+        result.remaining_criteria = state.remaining_criteria();
+        result.blocked_tasks = plan.tasks.keys()
+            .filter(|id| matches!(state.get_status(id), Some(TaskStatus::Pending | TaskStatus::Ready)))
+            .cloned()
+            .collect();
+        result.blocked_tasks.sort();
 
         // Clear state
         self.plan = None;
@@ -160,6 +289,62 @@ impl TaskScheduler {
         Ok(result)
     }
 
+    /**
+     * Run one task, forwarding its progress reports while it runs
+     *
+     * The receive loop ends when the executor's reporter is dropped; a reporter
+     * kept alive past the executor (e.g., moved into a detached thread) is
+     * caught by the is_finished check instead.
+     */
+    fn run_task<F, T>(&mut self, task: &Task, executor: &mut F, monitor: &mut ProgressMonitor) -> Result<T>
+    where
+        F: FnMut(&Task, &ProgressReporter) -> Result<T> + Send,
+        T: Send,
+    {
+        let (sender, updates) = mpsc::channel();
+        let reporter = ProgressReporter::new(task.id.clone(), sender);
+
+        std::thread::scope(|scope| {
+            let running = scope.spawn(move || executor(task, &reporter));
+
+            loop {
+                match updates.recv_timeout(PROGRESS_POLL_INTERVAL) {
+                    Ok(update) => {
+                        if let Some(event) = monitor.report_progress(update) {
+                            self.notify_progress(&event);
+                        }
+                    }
+                    Err(RecvTimeoutError::Timeout) if !running.is_finished() => {}
+                    Err(_) => break,
+                }
+            }
+
+            // Reports sent just before the executor finished
+            for update in updates.try_iter() {
+                if let Some(event) = monitor.report_progress(update) {
+                    self.notify_progress(&event);
+                }
+            }
+
+            running.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    }
+
+    /// Forward a progress event to the listener and the progress signal file
+    fn notify_progress(&mut self, event: &ProgressEvent) {
+        if let Some(listener) = self.progress_listener.as_mut() {
+            listener(event);
+        }
+
+        if let Some(writer) = &self.progress_signals {
+            let signal = ProgressSignal::new(event.task_id.clone(), event.fraction, event.message.clone())
+                .with_sprint_progress(event.sprint_progress);
+            if let Err(e) = writer.write_progress(&signal) {
+                tracing::warn!("Failed to write progress signal for {}: {:#}", event.task_id, e);
+            }
+        }
+    }
+
     /**
      * Get current sprint progress (if executing)
      */
@@ -176,11 +361,13 @@ impl TaskScheduler {
              - Completed: {}\n\
              - Running: {}\n\
              - Remaining: {}\n\
+             - Progress: {:.0}%\n\
              - Est. remaining: {:.1}m",
             metrics.elapsed.as_secs_f64() / 60.0,
             metrics.completed,
             metrics.running,
             metrics.remaining,
+            metrics.progress * 100.0,
             metrics.estimated_remaining.unwrap_or_default().as_secs_f64() / 60.0,
         ))
     }
@@ -211,6 +398,7 @@ mod tests {
                 acceptance_criteria: vec!["Schema created".to_string()],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -224,6 +412,9 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec!["DB-001".to_string()],
+            owner: None,
+            labels: vec![],
+            priority: None,
         }
     }
 
@@ -242,6 +433,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
         tasks.insert(
@@ -255,6 +447,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -270,6 +463,7 @@ mod tests {
                 acceptance_criteria: vec![],
                 files: vec![],
                 patterns: vec![],
+                priority: None,
             },
         );
 
@@ -289,9 +483,155 @@ mod tests {
             approval_gates: vec![],
             parallel_groups: vec![],
             execution_order: vec!["DB-001".to_string(), "UI-001".to_string(), "API-001".to_string()],
+            owner: None,
+            labels: vec![],
+            priority: None,
         }
     }
 
+    /**
+     * Test: Under max_concurrency, higher-priority ready tasks start first
+     */
+    #[test]
+    fn test_priority_orders_ready_tasks_under_max_concurrency() {
+        use crate::sprint_parser::types::Priority;
+
+        let mut plan = create_parallel_plan();
+        plan.tasks.get_mut("DB-001").unwrap().priority = Some(Priority::Low);
+        plan.tasks.get_mut("UI-001").unwrap().priority = Some(Priority::Critical);
+        plan.tasks.get_mut("API-001").unwrap().priority = Some(Priority::High);
+
+        let mut scheduler = TaskScheduler::new().with_max_concurrency(1);
+        let result = scheduler.execute_sprint_sync(plan).unwrap();
+
+        // UI (critical) before DB (low); API only becomes ready after DB
+        assert_eq!(result.tasks_completed, 3);
+        assert_eq!(result.start_order, vec!["UI-001", "DB-001", "API-001"]);
+
+        // Without priorities UI would lose the tie to DB (ID order)
+        let mut scheduler = TaskScheduler::new().with_max_concurrency(1);
+        let result = scheduler.execute_sprint_sync(create_parallel_plan()).unwrap();
+        assert_eq!(result.start_order, vec!["DB-001", "API-001", "UI-001"]);
+    }
+
+    /**
+     * Test: Executor progress reaches the listener with the sprint total
+     */
+    #[test]
+    fn test_executor_progress_reported() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&events);
+        let mut scheduler = TaskScheduler::new()
+            .with_progress_listener(move |event| sink.borrow_mut().push(event.clone()));
+
+        let result = scheduler
+            .execute_sprint_with(create_simple_plan(), |task, reporter| {
+                assert_eq!(reporter.task_id(), &task.id);
+                reporter.report(0.5, "Halfway");
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(result.tasks_completed, 1);
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].task_id, "DB-001");
+        assert_eq!(events[0].message, "Halfway");
+        assert!((events[0].sprint_progress - 0.5).abs() < 1e-9);
+    }
+
+    /**
+     * Test: Progress reaches the listener while the executor is still running
+     */
+    #[test]
+    fn test_progress_forwarded_before_task_finishes() {
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        let (seen_tx, seen_rx) = mpsc::channel();
+        let mut scheduler = TaskScheduler::new()
+            .with_progress_listener(move |event| seen_tx.send(event.fraction).unwrap());
+
+        scheduler
+            .execute_sprint_with(create_simple_plan(), move |_, reporter| {
+                reporter.report(0.5, "Halfway");
+                let fraction = seen_rx.recv_timeout(Duration::from_secs(5))
+                    .map_err(|_| Error::Configuration("progress not forwarded live".to_string()))?;
+                assert_eq!(fraction, 0.5);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /**
+     * Test: Progress updates are written as progress signals
+     */
+    #[test]
+    fn test_progress_signals_written() {
+        use crate::ipc::SignalReader;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut scheduler = TaskScheduler::new()
+            .with_progress_signals(SignalWriter::new(temp_dir.path()).unwrap());
+
+        scheduler
+            .execute_sprint_with(create_simple_plan(), |_, reporter| {
+                reporter.report(0.5, "Halfway");
+                Ok(())
+            })
+            .unwrap();
+
+        let signal = SignalReader::new(temp_dir.path()).unwrap().read_progress("DB-001").unwrap().unwrap();
+        assert_eq!(signal.fraction, 0.5);
+        assert_eq!(signal.message, "Halfway");
+        assert_eq!(signal.sprint_progress, Some(0.5));
+    }
+
+    /**
+     * Test: Executor error aborts the sprint
+     */
+    #[test]
+    fn test_executor_error_aborts_sprint() {
+        let mut scheduler = TaskScheduler::new();
+        let result = scheduler.execute_sprint_with(create_simple_plan(), |_, _| {
+            Err(Error::Configuration("agent crashed".to_string()))
+        });
+        assert_eq!(result.unwrap_err(), Error::Configuration("agent crashed".to_string()));
+    }
+
+    /**
+     * Test: Partially complete task names its unmet criteria and blocks its dependents
+     */
+    #[test]
+    fn test_partial_criteria_block_dependents() {
+        let mut scheduler = TaskScheduler::new();
+        let result = scheduler
+            .execute_sprint_with_criteria(create_parallel_plan(), |task, _| {
+                Ok(if task.id == "DB-001" {
+                    CriteriaScore {
+                        completion_percentage: 200.0 / 3.0,
+                        verified: vec!["Schema created".to_string(), "Migrations run".to_string()],
+                        unmet: vec!["Indexes added".to_string()],
+                    }
+                } else {
+                    CriteriaScore::score(&[], &[])
+                })
+            })
+            .unwrap();
+
+        assert_eq!(result.tasks_completed, 1); // UI-001
+        assert_eq!(
+            result.remaining_criteria,
+            HashMap::from([("DB-001".to_string(), vec!["Indexes added".to_string()])])
+        );
+        assert_eq!(result.blocked_tasks, vec!["API-001"]);
+        assert!(!result.start_order.contains(&"API-001".to_string()));
+        assert!(scheduler.is_idle());
+    }
+
     #[test]
     fn test_scheduler_initialization() {
         let scheduler = TaskScheduler::new();
@@ -0,0 +1,53 @@
+/**
+ * Structured Logging (tracing)
+ *
+ * DESIGN DECISION: Library emits `tracing` spans/events; hosts install the subscriber
+ * WHY: println!/eprintln! in library code pollutes stdout of NAPI/Tauri hosts and
+ * cannot be filtered by level or module
+ *
+ * REASONING CHAIN:
+ * 1. Core modules emit leveled events (warn!/info!/debug!) and spans (#[instrument])
+ * 2. Without a subscriber installed, events are discarded at near-zero cost
+ * 3. Hosts (desktop app, CLI) call init_tracing() once at startup
+ * 4. RUST_LOG overrides the host default (e.g. RUST_LOG=aetherlight_core=debug)
+ * 5. Result: One filterable log stream across core and host, with search latency per span
+ *
+ * PATTERN: Pattern-OBSERVABILITY-001 (Structured Logging)
+ * RELATED: pattern_index.rs (search_by_intent span), lumina-desktop ipc_server
+ * PERFORMANCE: Disabled spans cost one atomic load per callsite
+ */
+
+use crate::{Error, Result};
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable consulted for the log filter
+pub const LOG_ENV_VAR: &str = "RUST_LOG";
+
+/**
+ * Install a global fmt subscriber filtered by RUST_LOG
+ *
+ * DESIGN DECISION: Fall back to `default_filter` when RUST_LOG unset or invalid
+ * WHY: Hosts ship a sensible level (e.g. "info") but users can raise it without rebuilding
+ *
+ * Returns `Error::Configuration` if the filter is invalid or a global subscriber
+ * is already installed.
+ *
+ * # Examples
+ *
+ * ```rust,no_run
+ * aetherlight_core::init_tracing("info,aetherlight_core=debug").unwrap();
+ * ```
+ */
+pub fn init_tracing(default_filter: &str) -> Result<()> {
+    let filter = match EnvFilter::try_from_env(LOG_ENV_VAR) {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::try_new(default_filter)
+            .map_err(|e| Error::Configuration(format!("Invalid log filter '{}': {}", default_filter, e)))?,
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(true)
+        .try_init()
+        .map_err(|e| Error::Configuration(format!("Failed to install tracing subscriber: {}", e)))
+}
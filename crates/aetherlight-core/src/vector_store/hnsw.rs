@@ -0,0 +1,380 @@
+/**
+ * HNSW Index - Approximate nearest neighbours over stored embeddings
+ *
+ * DESIGN DECISION: Small in-crate Hierarchical Navigable Small World graph
+ * WHY: Brute-force search is O(n) and misses the <10ms target past ~50k vectors;
+ * HNSW answers in roughly O(log n) with tunable recall. Kept in-crate (no extra
+ * dependency) so the desktop and node builds stay offline-buildable
+ *
+ * REASONING CHAIN:
+ * 1. Each vector gets a random top layer (exponential distribution, mult = 1/ln(M))
+ * 2. Insert: greedy descent through the layers above the node's top layer, then a
+ *    beam search (ef_construction) on each of its layers; link to the M most similar
+ *    and prune neighbour lists back to M (2M on layer 0), keeping the most similar
+ * 3. Search: greedy descent to layer 0, beam search with `ef` (larger = higher recall)
+ * 4. Similarity is the store's SimilarityMetric (higher = closer), so ANN results
+ *    carry exactly the scores exact search would give them
+ * 5. Removed or replaced vectors are tombstoned: still traversed, never returned
+ * 6. Levels come from a fixed-seed generator: same inserts → same graph
+ *
+ * TRADEOFF: Tombstones are never unlinked; after heavy churn rebuild the index
+ *
+ * PATTERN: Pattern-VECTOR-001 (Local Vector Storage)
+ * RELATED: SqliteVectorStore::build_ann_index, SearchMode::Approximate
+ * PERFORMANCE: O(ef · M · log n) similarity evaluations per query
+ */
+
+use super::SimilarityMetric;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Default beam width for approximate search (SearchMode::Approximate)
+pub const DEFAULT_EF_SEARCH: usize = 64;
+
+/// Seed of the level generator (fixed so builds are reproducible)
+const LEVEL_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Graph construction parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HnswParams {
+    /// Links per node on upper layers (layer 0 keeps 2 × m)
+    pub m: usize,
+
+    /// Beam width while inserting (higher = better graph, slower build)
+    pub ef_construction: usize,
+}
+
+impl Default for HnswParams {
+    fn default() -> Self {
+        Self { m: 16, ef_construction: 200 }
+    }
+}
+
+/// Node similarity to the current query; ordered by score, ties by lower node index
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scored {
+    score: f32,
+    node: usize,
+}
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.total_cmp(&other.score).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Node {
+    id: String,
+    vector: Vec<f32>,
+    /// Neighbour lists, one per layer 0..=top layer of this node
+    links: Vec<Vec<usize>>,
+    deleted: bool,
+}
+
+/// In-memory HNSW graph keyed by vector id
+pub struct HnswIndex {
+    metric: SimilarityMetric,
+    params: HnswParams,
+    level_mult: f64,
+    nodes: Vec<Node>,
+    live: HashMap<String, usize>,
+    entry: Option<usize>,
+    rng: u64,
+}
+
+impl HnswIndex {
+    /// Empty index scoring with `metric` (m is raised to at least 2)
+    pub fn new(metric: SimilarityMetric, params: HnswParams) -> Self {
+        let params = HnswParams { m: params.m.max(2), ..params };
+        Self {
+            metric,
+            params,
+            level_mult: 1.0 / (params.m as f64).ln(),
+            nodes: Vec::new(),
+            live: HashMap::new(),
+            entry: None,
+            rng: LEVEL_SEED,
+        }
+    }
+
+    /// Construction parameters in effect
+    pub fn params(&self) -> HnswParams {
+        self.params
+    }
+
+    /// Number of searchable (non-removed) vectors
+    pub fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.live.is_empty()
+    }
+
+    /// Add a vector; an existing vector with the same id is replaced
+    pub fn insert(&mut self, id: &str, vector: Vec<f32>) {
+        self.remove(id);
+
+        let level = self.random_level();
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            id: id.to_string(),
+            vector,
+            links: vec![Vec::new(); level + 1],
+            deleted: false,
+        });
+        self.live.insert(id.to_string(), node);
+
+        let Some(entry) = self.entry else {
+            self.entry = Some(node);
+            return;
+        };
+
+        let top = self.nodes[entry].links.len() - 1;
+        let query = self.nodes[node].vector.clone();
+        let mut nearest = Scored { score: self.similarity(&query, entry), node: entry };
+        for layer in (level + 1..=top).rev() {
+            nearest = self.greedy(&query, nearest, layer);
+        }
+
+        let mut entry_points = vec![nearest];
+        for layer in (0..=level.min(top)).rev() {
+            let candidates = self.search_layer(&query, &entry_points, self.params.ef_construction, layer);
+            let neighbours: Vec<usize> = candidates.iter().take(self.params.m).map(|c| c.node).collect();
+
+            let cap = self.max_links(layer);
+            for &neighbour in &neighbours {
+                self.nodes[neighbour].links[layer].push(node);
+                if self.nodes[neighbour].links[layer].len() > cap {
+                    self.prune(neighbour, layer, cap);
+                }
+            }
+            self.nodes[node].links[layer] = neighbours;
+            entry_points = candidates;
+        }
+
+        if level > top {
+            self.entry = Some(node);
+        }
+    }
+
+    /// Tombstone a vector; true if it was present
+    pub fn remove(&mut self, id: &str) -> bool {
+        match self.live.remove(id) {
+            Some(node) => {
+                self.nodes[node].deleted = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /**
+     * Approximate top `k` for `query` as (id, score), best first
+     *
+     * Ties are ordered by id, like exact search. `ef` below `k` is raised to `k`.
+     */
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Vec<(&str, f32)> {
+        let Some(entry) = self.entry else {
+            return Vec::new();
+        };
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let top = self.nodes[entry].links.len() - 1;
+        let mut nearest = Scored { score: self.similarity(query, entry), node: entry };
+        for layer in (1..=top).rev() {
+            nearest = self.greedy(query, nearest, layer);
+        }
+
+        let mut results: Vec<(&str, f32)> = self
+            .search_layer(query, &[nearest], ef.max(k), 0)
+            .into_iter()
+            .filter(|c| !self.nodes[c.node].deleted)
+            .map(|c| (self.nodes[c.node].id.as_str(), c.score))
+            .collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        results.truncate(k);
+        results
+    }
+
+    fn similarity(&self, query: &[f32], node: usize) -> f32 {
+        self.metric.score(query, &self.nodes[node].vector)
+    }
+
+    fn max_links(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.params.m * 2
+        } else {
+            self.params.m
+        }
+    }
+
+    /// Follow the most similar neighbour on `layer` until none improves
+    fn greedy(&self, query: &[f32], start: Scored, layer: usize) -> Scored {
+        let mut best = start;
+        loop {
+            let current = best.node;
+            for &neighbour in &self.nodes[current].links[layer] {
+                let score = self.similarity(query, neighbour);
+                if score > best.score {
+                    best = Scored { score, node: neighbour };
+                }
+            }
+            if best.node == current {
+                return best;
+            }
+        }
+    }
+
+    /// Beam search on one layer → up to `ef` nodes, most similar first
+    fn search_layer(&self, query: &[f32], entry_points: &[Scored], ef: usize, layer: usize) -> Vec<Scored> {
+        let mut visited: HashSet<usize> = entry_points.iter().map(|e| e.node).collect();
+        let mut candidates: BinaryHeap<Scored> = entry_points.iter().copied().collect();
+        let mut results: BinaryHeap<Reverse<Scored>> = entry_points.iter().copied().map(Reverse).collect();
+        while results.len() > ef {
+            results.pop();
+        }
+
+        while let Some(current) = candidates.pop() {
+            let worst = results.peek().map_or(f32::NEG_INFINITY, |r| r.0.score);
+            if results.len() >= ef && current.score < worst {
+                break;
+            }
+
+            for &neighbour in &self.nodes[current.node].links[layer] {
+                if !visited.insert(neighbour) {
+                    continue;
+                }
+                let scored = Scored { score: self.similarity(query, neighbour), node: neighbour };
+                let worst = results.peek().map_or(f32::NEG_INFINITY, |r| r.0.score);
+                if results.len() < ef || scored.score > worst {
+                    candidates.push(scored);
+                    results.push(Reverse(scored));
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+
+        let mut found: Vec<Scored> = results.into_iter().map(|r| r.0).collect();
+        found.sort_by(|a, b| b.cmp(a));
+        found
+    }
+
+    /// Keep the `cap` neighbours most similar to `node` on `layer`
+    fn prune(&mut self, node: usize, layer: usize, cap: usize) {
+        let vector = &self.nodes[node].vector;
+        let mut scored: Vec<Scored> = self.nodes[node].links[layer]
+            .iter()
+            .map(|&link| Scored { score: self.metric.score(vector, &self.nodes[link].vector), node: link })
+            .collect();
+        scored.sort_by(|a, b| b.cmp(a));
+        scored.truncate(cap);
+        self.nodes[node].links[layer] = scored.into_iter().map(|s| s.node).collect();
+    }
+
+    /// floor(-ln(U) · level_mult), U uniform in (0, 1] (xorshift64*)
+    fn random_level(&mut self) -> usize {
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        let bits = self.rng.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11;
+        let uniform = (bits as f64 + 1.0) / (1u64 << 53) as f64;
+        (-uniform.ln() * self.level_mult).floor() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random vectors in [-1, 1)
+    fn vectors(count: usize, dims: usize, seed: u64) -> Vec<Vec<f32>> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+        };
+        (0..count).map(|_| (0..dims).map(|_| next()).collect()).collect()
+    }
+
+    fn exact_top(data: &[Vec<f32>], query: &[f32], k: usize, metric: SimilarityMetric) -> Vec<String> {
+        let mut scored: Vec<(String, f32)> = data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("v{}", i), metric.score(query, v)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scored.into_iter().take(k).map(|(id, _)| id).collect()
+    }
+
+    /**
+     * Test: Recall@10 against brute force is high and grows with ef
+     */
+    #[test]
+    fn test_recall_against_brute_force() {
+        let data = vectors(2_000, 16, 7);
+        let queries = vectors(50, 16, 99);
+        let metric = SimilarityMetric::Cosine;
+
+        let mut index = HnswIndex::new(metric, HnswParams::default());
+        for (i, vector) in data.iter().enumerate() {
+            index.insert(&format!("v{}", i), vector.clone());
+        }
+        assert_eq!(index.len(), data.len());
+
+        let recall = |ef: usize| {
+            let hits: usize = queries
+                .iter()
+                .map(|query| {
+                    let exact = exact_top(&data, query, 10, metric);
+                    index.search(query, 10, ef).iter().filter(|(id, _)| exact.iter().any(|e| e == id)).count()
+                })
+                .sum();
+            hits as f64 / (queries.len() * 10) as f64
+        };
+
+        let narrow = recall(10);
+        let wide = recall(DEFAULT_EF_SEARCH);
+        assert!(wide >= 0.95, "recall@10 with ef={} was {}", DEFAULT_EF_SEARCH, wide);
+        assert!(wide >= narrow);
+    }
+
+    /**
+     * Test: Removed and replaced vectors are never returned; scores match the metric
+     */
+    #[test]
+    fn test_remove_and_replace() {
+        let mut index = HnswIndex::new(SimilarityMetric::Euclidean, HnswParams::default());
+        index.insert("a", vec![0.0, 0.0]);
+        index.insert("b", vec![1.0, 0.0]);
+        index.insert("c", vec![5.0, 5.0]);
+
+        let query = [0.1, 0.0];
+        let ids = |index: &HnswIndex| index.search(&query, 3, 16).into_iter().map(|(id, _)| id.to_string()).collect::<Vec<_>>();
+        assert_eq!(ids(&index), ["a", "b", "c"]);
+        assert_eq!(index.search(&query, 1, 16)[0].1, SimilarityMetric::Euclidean.score(&query, &[0.0, 0.0]));
+
+        assert!(index.remove("a"));
+        assert!(!index.remove("a"));
+        assert_eq!(ids(&index), ["b", "c"]);
+
+        // Re-inserting "c" next to the query moves it to the front
+        index.insert("c", vec![0.1, 0.0]);
+        assert_eq!(ids(&index), ["c", "b"]);
+        assert_eq!(index.len(), 2);
+    }
+}
@@ -1,7 +1,7 @@
 /**
  * Vector Store Module
  *
- * DESIGN DECISION: SQLite-backed vector storage with cosine similarity search
+ * DESIGN DECISION: SQLite-backed vector storage with configurable similarity search
  * WHY: No mature ChromaDB Rust client, SQLite provides equivalent persistent storage
  *
  * REASONING CHAIN:
@@ -20,16 +20,126 @@
  * FUTURE: Add ChromaDB HTTP client when cloud sync needed
  */
 
+pub mod hnsw;
 pub mod sqlite;
 
+pub use hnsw::{HnswIndex, HnswParams, DEFAULT_EF_SEARCH};
 pub use sqlite::SqliteVectorStore;
 
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use std::fmt;
+use std::str::FromStr;
 
 /// Search result with similarity score
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub id: String,
+    /// Similarity under the store's SimilarityMetric, always higher = more similar:
+    /// - Cosine: cos(angle) in [-1, 1] (0 for a zero vector)
+    /// - DotProduct: raw a·b, unbounded (equals cosine for unit vectors)
+    /// - Euclidean: 1 / (1 + L2 distance) in (0, 1]; 1 = identical vectors
     pub score: f32,
     pub metadata: JsonValue,
 }
+
+/**
+ * How a search finds its candidates
+ *
+ * DESIGN DECISION: Exact by default, approximate only when asked for
+ * WHY: Brute force is exact and fast enough below ~50k vectors; the HNSW index trades
+ * a little recall for sub-10ms queries on larger stores
+ *
+ * Approximate falls back to exact when the store has no ANN index built.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Score every stored vector
+    #[default]
+    Exact,
+
+    /// Walk the HNSW index; larger `ef` = higher recall, slower (see DEFAULT_EF_SEARCH)
+    Approximate { ef: usize },
+}
+
+/**
+ * Similarity metric used to score stored vectors against a query
+ *
+ * DESIGN DECISION: Chosen when a store is created and persisted with it
+ * WHY: Some embedding models are trained for dot product or L2 distance; scoring them
+ * with cosine misranks. Persisting the metric keeps later queries consistent with it
+ *
+ * REASONING CHAIN:
+ * 1. Cosine: angle only, magnitude ignored. Default, and the right choice for
+ *    all-MiniLM-L6-v2 (the bundled model), which is trained for cosine similarity
+ * 2. DotProduct: rewards magnitude too; for L2-normalized embeddings (MiniLM output,
+ *    KeywordEmbedder) it ranks exactly like cosine
+ * 3. Euclidean: L2 distance mapped to 1 / (1 + d) so higher is still more similar
+ * 4. Every score is "higher = more similar", so search sorting is metric-independent
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimilarityMetric {
+    #[default]
+    Cosine,
+    DotProduct,
+    Euclidean,
+}
+
+impl SimilarityMetric {
+    /// Score `b` against query `a` (higher = more similar)
+    pub fn score(self, a: &[f32], b: &[f32]) -> f32 {
+        debug_assert_eq!(a.len(), b.len(), "Embeddings must have same dimensions");
+
+        match self {
+            SimilarityMetric::Cosine => {
+                let magnitudes = magnitude(a) * magnitude(b);
+                if magnitudes == 0.0 {
+                    0.0
+                } else {
+                    dot(a, b) / magnitudes
+                }
+            }
+            SimilarityMetric::DotProduct => dot(a, b),
+            SimilarityMetric::Euclidean => {
+                let distance = a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f32>().sqrt();
+                1.0 / (1.0 + distance)
+            }
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SimilarityMetric::Cosine => "cosine",
+            SimilarityMetric::DotProduct => "dot_product",
+            SimilarityMetric::Euclidean => "euclidean",
+        }
+    }
+}
+
+impl fmt::Display for SimilarityMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SimilarityMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "cosine" => Ok(SimilarityMetric::Cosine),
+            "dot_product" => Ok(SimilarityMetric::DotProduct),
+            "euclidean" => Ok(SimilarityMetric::Euclidean),
+            other => Err(format!("Unknown similarity metric: {}", other)),
+        }
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn magnitude(v: &[f32]) -> f32 {
+    v.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
@@ -1,7 +1,7 @@
 /**
  * SQLite Vector Store Implementation
  *
- * DESIGN DECISION: SQLite with JSON for vectors + metadata, Rust-based cosine similarity
+ * DESIGN DECISION: SQLite with JSON for vectors + metadata, Rust-based similarity scoring
  * WHY: Embedded, persistent, zero configuration, works offline
  *
  * REASONING CHAIN:
@@ -9,17 +9,18 @@
  * 2. SQLite: battle-tested, embedded, ACID compliant, cross-platform
  * 3. Store vectors as JSON (SQLite has good JSON support since 3.38)
  * 4. Metadata as JSON (flexible schema for different pattern types)
- * 5. Cosine similarity in Rust (load all, filter in memory for <10k vectors)
- * 6. For >10k vectors: could add HNSW index or switch to specialized vector DB
+ * 5. Similarity (cosine by default, see SimilarityMetric) in Rust (load all, filter in memory for <10k vectors)
+ * 6. For >50k vectors: optional in-memory HNSW index (build_ann_index, SearchMode::Approximate)
  * 7. Trade-off: Simplicity vs specialized vector DB performance
  *
  * PATTERN: Pattern-VECTOR-001 (Local Vector Storage)
  * PERFORMANCE: <10ms for 10k patterns (brute-force cosine similarity acceptable)
  * RELATED: LocalEmbeddings, PatternMatcher
- * FUTURE: Add HNSW index for >100k patterns, or ChromaDB HTTP client
+ * FUTURE: ChromaDB HTTP client
  */
 
-use super::SearchResult;
+use super::hnsw::{HnswIndex, HnswParams};
+use super::{SearchMode, SearchResult, SimilarityMetric};
 
 // Re-enabled after embeddings module restored
 // TEMPORARILY DISABLED: embeddings module disabled (Windows SDK required)
@@ -27,8 +28,8 @@ use super::SearchResult;
 // Local type alias for Embedding while embeddings module is disabled
 pub type Embedding = Vec<f32>;
 
-use crate::error::Result;
-use rusqlite::{params, Connection};
+use crate::error::{Error, Result};
+use rusqlite::{params, Connection, OptionalExtension};
 use serde_json::Value as JsonValue;
 use std::path::Path;
 
@@ -43,11 +44,22 @@ use std::path::Path;
  * - embedding: TEXT (JSON array of f32)
  * - metadata: TEXT (JSON object with pattern data)
  * - created_at: INTEGER (Unix timestamp)
+ *
+ * store_meta (key TEXT PRIMARY KEY, value TEXT) holds the similarity metric
+ * ("similarity_metric"); stores created before it existed are read as cosine
+ *
+ * `ann` is the optional HNSW index (memory only, rebuilt with build_ann_index after
+ * opening); once built, insert/delete/clear keep it in sync with the table
  */
 pub struct SqliteVectorStore {
     conn: Connection,
+    metric: SimilarityMetric,
+    ann: Option<HnswIndex>,
 }
 
+/// store_meta key of the persisted SimilarityMetric
+const METRIC_KEY: &str = "similarity_metric";
+
 impl SqliteVectorStore {
     /**
      * DESIGN DECISION: Create database file with schema initialization
@@ -61,12 +73,46 @@ impl SqliteVectorStore {
      * 5. No index on embedding (brute-force search acceptable for <10k)
      */
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let conn = Connection::open(path)?;
+        Self::open(Connection::open(path)?, None)
+    }
+
+    /**
+     * Open/create a file store scored with `metric`
+     *
+     * A new store records the metric; an existing store must have been created with
+     * the same one (switching metrics means rebuilding the store)
+     */
+    pub fn with_metric<P: AsRef<Path>>(path: P, metric: SimilarityMetric) -> Result<Self> {
+        Self::open(Connection::open(path)?, Some(metric))
+    }
+
+    /**
+     * DESIGN DECISION: In-memory database for testing
+     * WHY: Fast, no file cleanup needed, isolated tests
+     */
+    pub fn new_in_memory() -> Result<Self> {
+        Self::new_in_memory_with_metric(SimilarityMetric::default())
+    }
 
+    pub fn new_in_memory_with_metric(metric: SimilarityMetric) -> Result<Self> {
+        Self::init(Connection::open_in_memory()?, Some(metric))
+    }
+
+    /// Metric this store scores searches with
+    pub fn metric(&self) -> SimilarityMetric {
+        self.metric
+    }
+
+    fn open(conn: Connection, requested: Option<SimilarityMetric>) -> Result<Self> {
         // Enable WAL mode for better concurrency
         // NOTE: PRAGMA returns results, so use query_row instead of execute
         conn.query_row("PRAGMA journal_mode=WAL", [], |_| Ok(()))?;
 
+        Self::init(conn, requested)
+    }
+
+    /// Create tables, then resolve the metric against the one stored (if any)
+    fn init(conn: Connection, requested: Option<SimilarityMetric>) -> Result<Self> {
         // Create vectors table
         conn.execute(
             "CREATE TABLE IF NOT EXISTS vectors (
@@ -77,62 +123,116 @@ impl SqliteVectorStore {
             )",
             [],
         )?;
-
-        Ok(Self { conn })
-    }
-
-    /**
-     * DESIGN DECISION: In-memory database for testing
-     * WHY: Fast, no file cleanup needed, isolated tests
-     */
-    pub fn new_in_memory() -> Result<Self> {
-        let conn = Connection::open_in_memory()?;
-
         conn.execute(
-            "CREATE TABLE vectors (
-                id TEXT PRIMARY KEY,
-                embedding TEXT NOT NULL,
-                metadata TEXT NOT NULL,
-                created_at INTEGER NOT NULL
-            )",
+            "CREATE TABLE IF NOT EXISTS store_meta (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
             [],
         )?;
 
-        Ok(Self { conn })
+        let stored: Option<String> = conn
+            .query_row("SELECT value FROM store_meta WHERE key = ?", params![METRIC_KEY], |row| row.get(0))
+            .optional()?;
+
+        let metric = match (stored, requested) {
+            (Some(stored), requested) => {
+                let stored: SimilarityMetric = stored.parse().map_err(Error::ValidationError)?;
+                if let Some(requested) = requested.filter(|&r| r != stored) {
+                    return Err(Error::ValidationError(format!(
+                        "Vector store uses {} similarity, cannot open it with {} (rebuild the store to switch metrics)",
+                        stored, requested
+                    )));
+                }
+                stored
+            }
+            (None, requested) => {
+                let metric = requested.unwrap_or_default();
+                conn.execute(
+                    "INSERT INTO store_meta (key, value) VALUES (?, ?)",
+                    params![METRIC_KEY, metric.as_str()],
+                )?;
+                metric
+            }
+        };
+
+        Ok(Self { conn, metric, ann: None })
     }
 
     /**
      * DESIGN DECISION: Insert or replace vector
      * WHY: Allows updating existing patterns
      *
-     * REASONING CHAIN:
-     * 1. Serialize embedding to JSON (TEXT in SQLite)
-     * 2. Serialize metadata to JSON
-     * 3. INSERT OR REPLACE (upsert semantics)
-     * 4. Store Unix timestamp for created_at
+     * Delegates to insert_batch (single-item batch).
      */
     pub fn insert(&mut self, id: &str, embedding: &Embedding, metadata: &JsonValue) -> Result<()> {
-        let embedding_json = serde_json::to_string(embedding)?;
-        let metadata_json = serde_json::to_string(metadata)?;
+        self.insert_batch([(id, embedding, metadata)]).map(|_| ())
+    }
+
+    /**
+     * DESIGN DECISION: Insert many vectors in one transaction with one prepared statement
+     * WHY: Autocommit inserts pay a journal sync per row; seeding 10k embeddings
+     * during PatternIndex::rebuild was dominated by commit overhead
+     *
+     * REASONING CHAIN:
+     * 1. Open a transaction (all-or-nothing: any failure rolls back the whole batch)
+     * 2. Prepare INSERT OR REPLACE once, reuse for every row
+     * 3. Reject non-finite embeddings (NaN serializes as null and can't be read back)
+     * 4. Serialize embedding + metadata to JSON per row (same format as before)
+     * 5. Commit once
+     *
+     * PERFORMANCE: One commit per batch instead of per row
+     * RELATED: PatternIndex::import_patterns (batches embeddings per import)
+     */
+    pub fn insert_batch<'a, I>(&mut self, items: I) -> Result<usize>
+    where
+        I: IntoIterator<Item = (&'a str, &'a Embedding, &'a JsonValue)>,
+    {
         let timestamp = chrono::Utc::now().timestamp();
+        let tx = self.conn.transaction()?;
+        let mut inserted = 0;
+        let mut indexed: Vec<(&str, &Embedding)> = Vec::new();
+
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT OR REPLACE INTO vectors (id, embedding, metadata, created_at) VALUES (?, ?, ?, ?)",
+            )?;
+
+            for (id, embedding, metadata) in items {
+                if embedding.iter().any(|value| !value.is_finite()) {
+                    return Err(Error::ValidationError(format!(
+                        "Embedding for '{}' contains non-finite values",
+                        id
+                    )));
+                }
+
+                let embedding_json = serde_json::to_string(embedding)?;
+                let metadata_json = serde_json::to_string(metadata)?;
+                stmt.execute(params![id, embedding_json, metadata_json, timestamp])?;
+                inserted += 1;
+                if self.ann.is_some() {
+                    indexed.push((id, embedding));
+                }
+            }
+        }
 
-        self.conn.execute(
-            "INSERT OR REPLACE INTO vectors (id, embedding, metadata, created_at) VALUES (?, ?, ?, ?)",
-            params![id, embedding_json, metadata_json, timestamp],
-        )?;
+        tx.commit()?;
 
-        Ok(())
+        // Index only after commit, so a rolled-back batch never reaches the ANN index
+        if let Some(ann) = self.ann.as_mut() {
+            for (id, embedding) in indexed {
+                ann.insert(id, embedding.clone());
+            }
+        }
+        Ok(inserted)
     }
 
     /**
-     * DESIGN DECISION: Brute-force cosine similarity search
+     * DESIGN DECISION: Brute-force similarity search (store's SimilarityMetric)
      * WHY: Simple, fast enough for <10k vectors (<10ms target)
      *
      * REASONING CHAIN:
      * 1. Load all vectors from SQLite (or apply filters first if needed)
      * 2. Deserialize embeddings from JSON
-     * 3. Calculate cosine similarity for each vector
-     * 4. Sort by score (descending)
+     * 3. Score each vector with the store's metric (higher = more similar)
+     * 4. Sort by score (descending), ties by id (ascending) so order is reproducible
      * 5. Return top N results
      *
      * PERFORMANCE:
@@ -140,9 +240,91 @@ impl SqliteVectorStore {
      * - Modern CPU: ~1ns per operation = ~8ms for brute force
      * - Meets <10ms target for 10k patterns
      *
-     * ALTERNATIVE: For >100k patterns, add HNSW index or use ChromaDB
+     * ALTERNATIVE: For >50k patterns, search_with(SearchMode::Approximate) after build_ann_index
      */
     pub fn search(&self, query_embedding: &Embedding, limit: usize) -> Result<Vec<SearchResult>> {
+        self.search_paginated(query_embedding, limit, 0)
+    }
+
+    /**
+     * DESIGN DECISION: Search with an explicit mode (exact or HNSW)
+     * WHY: Callers with large stores opt into approximate search per query, while
+     * anything that needs exact top-K (tests, dedup) keeps calling search
+     *
+     * REASONING CHAIN:
+     * 1. Exact → search (brute force)
+     * 2. Approximate without a built index → exact fallback (never an error)
+     * 3. Approximate → top `limit` ids from the HNSW walk with beam width `ef`
+     * 4. Metadata read by id; scores are the same metric scores exact search gives
+     *
+     * PERFORMANCE: O(ef · log n) vector comparisons instead of O(n)
+     */
+    pub fn search_with(&self, query_embedding: &Embedding, limit: usize, mode: SearchMode) -> Result<Vec<SearchResult>> {
+        let (ann, ef) = match (mode, self.ann.as_ref()) {
+            (SearchMode::Approximate { ef }, Some(ann)) => (ann, ef),
+            _ => return self.search(query_embedding, limit),
+        };
+
+        let mut stmt = self.conn.prepare_cached("SELECT metadata FROM vectors WHERE id = ?")?;
+        let mut results = Vec::new();
+        for (id, score) in ann.search(query_embedding, limit, ef) {
+            let metadata_json: Option<String> = stmt.query_row(params![id], |row| row.get(0)).optional()?;
+            if let Some(metadata_json) = metadata_json {
+                let metadata: JsonValue = serde_json::from_str(&metadata_json)?;
+                results.push(SearchResult { id: id.to_string(), score, metadata });
+            }
+        }
+        Ok(results)
+    }
+
+    /**
+     * DESIGN DECISION: Build the HNSW index from every stored embedding on request
+     * WHY: The index lives in memory only; building costs a full scan plus graph
+     * construction, so stores that never search approximately never pay for it
+     *
+     * Replaces any existing index. Returns number of vectors indexed.
+     */
+    pub fn build_ann_index(&mut self) -> Result<usize> {
+        self.build_ann_index_with(HnswParams::default())
+    }
+
+    /// build_ann_index with explicit graph parameters (M, ef_construction)
+    pub fn build_ann_index_with(&mut self, params: HnswParams) -> Result<usize> {
+        let mut index = HnswIndex::new(self.metric, params);
+        {
+            let mut stmt = self.conn.prepare("SELECT id, embedding FROM vectors ORDER BY id")?;
+            let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+            for row in rows {
+                let (id, embedding_json) = row?;
+                let embedding: Embedding = serde_json::from_str(&embedding_json)?;
+                index.insert(&id, embedding);
+            }
+        }
+
+        let indexed = index.len();
+        self.ann = Some(index);
+        Ok(indexed)
+    }
+
+    /// Whether an ANN index is built (SearchMode::Approximate uses it)
+    pub fn has_ann_index(&self) -> bool {
+        self.ann.is_some()
+    }
+
+    /// Drop the ANN index (approximate searches fall back to exact)
+    pub fn drop_ann_index(&mut self) {
+        self.ann = None;
+    }
+
+    /**
+     * DESIGN DECISION: Page through search results by offset
+     * WHY: The pattern browser pages semantic results; top-K alone can't reach page 2
+     *
+     * Same ranking as search (score descending, ties broken by id), so pages
+     * [0, 10) and [10, 20) together equal one [0, 20) query: no duplicates, no gaps.
+     * Each page still scores every vector (brute force, see search).
+     */
+    pub fn search_paginated(&self, query_embedding: &Embedding, limit: usize, offset: usize) -> Result<Vec<SearchResult>> {
         // Load all vectors (could optimize with WHERE clause for metadata filtering)
         let mut stmt = self.conn.prepare("SELECT id, embedding, metadata FROM vectors")?;
 
@@ -154,7 +336,7 @@ impl SqliteVectorStore {
             ))
         })?;
 
-        // Calculate cosine similarity for each vector
+        // Score each vector
         let mut results: Vec<SearchResult> = Vec::new();
 
         for row in rows {
@@ -163,8 +345,7 @@ impl SqliteVectorStore {
             // Deserialize embedding
             let embedding: Embedding = serde_json::from_str(&embedding_json)?;
 
-            // Calculate cosine similarity
-            let score = cosine_similarity(query_embedding, &embedding);
+            let score = self.metric.score(query_embedding, &embedding);
 
             // Deserialize metadata
             let metadata: JsonValue = serde_json::from_str(&metadata_json)?;
@@ -172,13 +353,11 @@ impl SqliteVectorStore {
             results.push(SearchResult { id, score, metadata });
         }
 
-        // Sort by score (descending)
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        // Sort by score (descending), tied scores by id for stable pages
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap().then_with(|| a.id.cmp(&b.id)));
 
-        // Return top N
-        results.truncate(limit);
-
-        Ok(results)
+        // Return N results after the first `offset`
+        Ok(results.into_iter().skip(offset).take(limit).collect())
     }
 
     /**
@@ -187,9 +366,82 @@ impl SqliteVectorStore {
      */
     pub fn delete(&mut self, id: &str) -> Result<()> {
         self.conn.execute("DELETE FROM vectors WHERE id = ?", params![id])?;
+        if let Some(ann) = self.ann.as_mut() {
+            ann.remove(id);
+        }
         Ok(())
     }
 
+    /**
+     * DESIGN DECISION: Delete every vector whose metadata matches a filter, in one statement
+     * WHY: Removing a whole domain or source file otherwise means searching for ids first
+     *
+     * REASONING CHAIN:
+     * 1. Filter is a JSON object; each key must equal the same key in the row's metadata
+     * 2. Keys become json_extract paths, values bound as JSON and compared with IS
+     *    (so `null` matches rows where the key is null or missing)
+     * 3. Empty or non-object filters rejected (would otherwise delete everything)
+     * 4. Returns number of rows deleted
+     *
+     * RELATED: PatternIndex incremental maintenance (remove vectors for a deleted source)
+     */
+    pub fn delete_where(&mut self, filter: &JsonValue) -> Result<usize> {
+        let conditions = match filter.as_object() {
+            Some(conditions) if !conditions.is_empty() => conditions,
+            _ => {
+                return Err(Error::ValidationError(format!(
+                    "delete_where filter must be a non-empty JSON object, got {}",
+                    filter
+                )))
+            }
+        };
+
+        let mut clauses = Vec::with_capacity(conditions.len());
+        let mut values: Vec<String> = Vec::with_capacity(conditions.len() * 2);
+        for (key, value) in conditions {
+            clauses.push("json_extract(metadata, ?) IS json_extract(?, '$')");
+            values.push(format!("$.\"{}\"", key.replace('"', "\"\"")));
+            values.push(serde_json::to_string(value)?);
+        }
+
+        let condition = clauses.join(" AND ");
+
+        // Ids first, so the ANN index can drop the same rows
+        if self.ann.is_some() {
+            let sql = format!("SELECT id FROM vectors WHERE {}", condition);
+            let mut stmt = self.conn.prepare(&sql)?;
+            let ids = stmt
+                .query_map(rusqlite::params_from_iter(&values), |row| row.get::<_, String>(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            if let Some(ann) = self.ann.as_mut() {
+                for id in &ids {
+                    ann.remove(id);
+                }
+            }
+        }
+
+        let sql = format!("DELETE FROM vectors WHERE {}", condition);
+        let deleted = self.conn.execute(&sql, rusqlite::params_from_iter(values))?;
+        Ok(deleted)
+    }
+
+    /**
+     * DESIGN DECISION: Read every row back as (id, embedding, metadata), ordered by id
+     * WHY: Callers keeping an in-memory view of the store (PatternIndex) restore it on open
+     */
+    pub fn entries(&self) -> Result<Vec<(String, Embedding, JsonValue)>> {
+        let mut stmt = self.conn.prepare("SELECT id, embedding, metadata FROM vectors ORDER BY id")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?;
+
+        rows.map(|row| {
+            let (id, embedding, metadata) = row?;
+            Ok((id, serde_json::from_str(&embedding)?, serde_json::from_str(&metadata)?))
+        })
+        .collect()
+    }
+
     /**
      * DESIGN DECISION: Count vectors
      * WHY: Useful for debugging and UI display
@@ -199,51 +451,50 @@ impl SqliteVectorStore {
         Ok(count as usize)
     }
 
+    /**
+     * DESIGN DECISION: Dimension read from a stored vector
+     * WHY: Every vector in a store comes from one embedder; callers compare this with
+     * their embedder's dimension before inserting or searching (scoring vectors of
+     * different length silently truncates)
+     *
+     * None for an empty store.
+     */
+    pub fn dimension(&self) -> Result<Option<usize>> {
+        let dimension: Option<i64> = self
+            .conn
+            .query_row("SELECT json_array_length(embedding) FROM vectors LIMIT 1", [], |row| row.get(0))
+            .optional()?;
+        Ok(dimension.map(|d| d as usize))
+    }
+
+    /**
+     * DESIGN DECISION: Compact on request (VACUUM + ANALYZE), see crate::compaction
+     * WHY: Rebuilds and delete_where leave free pages; the file never shrinks on its own
+     *
+     * Returns bytes reclaimed.
+     */
+    pub fn compact(&self) -> Result<u64> {
+        crate::compaction::compact_sqlite(&self.conn)
+    }
+
+    /// Whether enough of the file is free pages to be worth compact()
+    pub fn needs_compaction(&self) -> Result<bool> {
+        crate::compaction::needs_compaction(&self.conn)
+    }
+
     /**
      * DESIGN DECISION: Clear all vectors
      * WHY: Useful for testing and reset functionality
      */
     pub fn clear(&mut self) -> Result<()> {
         self.conn.execute("DELETE FROM vectors", [])?;
+        if let Some(ann) = self.ann.as_mut() {
+            *ann = HnswIndex::new(self.metric, ann.params());
+        }
         Ok(())
     }
 }
 
-/**
- * Cosine Similarity Calculation
- *
- * DESIGN DECISION: Standard cosine similarity formula
- * WHY: Industry standard for vector similarity, range [-1, 1], 1 = identical
- *
- * FORMULA:
- * similarity = (A · B) / (||A|| * ||B||)
- *
- * WHERE:
- * - A · B = dot product (sum of element-wise multiplication)
- * - ||A|| = magnitude of A (sqrt of sum of squares)
- *
- * OPTIMIZATION: Embeddings already L2-normalized (||A|| = ||B|| = 1.0)
- * Therefore: similarity = A · B (just dot product)
- */
-fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
-    debug_assert_eq!(
-        a.len(),
-        b.len(),
-        "Embeddings must have same dimensions for cosine similarity"
-    );
-
-    // Dot product
-    let dot_product: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
-
-    // Note: If embeddings are NOT L2-normalized, use this instead:
-    // let magnitude_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
-    // let magnitude_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
-    // dot_product / (magnitude_a * magnitude_b)
-
-    // Since our embeddings ARE L2-normalized (magnitude = 1.0):
-    dot_product
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,17 +502,154 @@ mod tests {
 
     #[test]
     fn test_cosine_similarity() {
+        let cosine = SimilarityMetric::Cosine;
+
         // Identical vectors (normalized)
         let a = vec![0.5, 0.5, 0.5, 0.5];
         let b = vec![0.5, 0.5, 0.5, 0.5];
-        let sim = cosine_similarity(&a, &b);
+        let sim = cosine.score(&a, &b);
         assert!((sim - 1.0).abs() < 0.01, "Identical vectors should have similarity ~1.0");
 
         // Orthogonal vectors
         let a = vec![1.0, 0.0];
         let b = vec![0.0, 1.0];
-        let sim = cosine_similarity(&a, &b);
+        let sim = cosine.score(&a, &b);
         assert!((sim - 0.0).abs() < 0.01, "Orthogonal vectors should have similarity ~0.0");
+
+        // Magnitude ignored; zero vector scores 0 instead of NaN
+        assert!((cosine.score(&[1.0, 0.0], &[5.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert_eq!(cosine.score(&[1.0, 0.0], &[0.0, 0.0]), 0.0);
+    }
+
+    /**
+     * Test: Each metric ranks the same hand-constructed set differently
+     *
+     * query [1, 0] vs
+     * - aligned [0.5, 0]: same direction, short   → cosine 1.0,   dot 0.5, distance 0.5
+     * - long    [3, 1]:   off-axis, long          → cosine 0.949, dot 3.0, distance 2.236
+     * - near    [1, 0.6]: closest point after aligned → cosine 0.857, dot 1.0, distance 0.6
+     */
+    #[test]
+    fn test_similarity_metrics_ranking() {
+        let ranking = |metric: SimilarityMetric| {
+            let mut store = SqliteVectorStore::new_in_memory_with_metric(metric).unwrap();
+            store.insert("aligned", &vec![0.5, 0.0], &json!({})).unwrap();
+            store.insert("long", &vec![3.0, 1.0], &json!({})).unwrap();
+            store.insert("near", &vec![1.0, 0.6], &json!({})).unwrap();
+
+            let results = store.search(&vec![1.0, 0.0], 3).unwrap();
+            assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+            results.into_iter().map(|r| r.id).collect::<Vec<_>>()
+        };
+
+        assert_eq!(ranking(SimilarityMetric::Cosine), vec!["aligned", "long", "near"]);
+        assert_eq!(ranking(SimilarityMetric::DotProduct), vec!["long", "near", "aligned"]);
+        assert_eq!(ranking(SimilarityMetric::Euclidean), vec!["aligned", "near", "long"]);
+    }
+
+    /**
+     * Test: Search order equals a brute-force f64 reference for every metric
+     *
+     * Reference sorts by cosine / dot descending and by raw L2 distance ascending,
+     * so it also checks that the 1 / (1 + d) mapping preserves distance order.
+     */
+    #[test]
+    fn test_metric_rankings_match_brute_force() {
+        let dataset: [(&str, [f32; 3]); 8] = [
+            ("a", [1.0, 0.0, 0.0]),
+            ("b", [0.9, 0.1, 0.0]),
+            ("c", [2.0, 2.0, 0.0]),
+            ("d", [0.0, 1.0, 0.0]),
+            ("e", [-1.0, 0.0, 0.5]),
+            ("f", [0.3, 0.3, 0.3]),
+            ("g", [5.0, -1.0, 2.0]),
+            ("h", [0.6, 0.0, 0.8]),
+        ];
+        let query = [0.8f32, 0.2, 0.1];
+
+        let as_f64 = |v: &[f32]| v.iter().map(|&x| x as f64).collect::<Vec<f64>>();
+        let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+        let q = as_f64(&query);
+
+        for metric in [SimilarityMetric::Cosine, SimilarityMetric::DotProduct, SimilarityMetric::Euclidean] {
+            let mut store = SqliteVectorStore::new_in_memory_with_metric(metric).unwrap();
+            for (id, vector) in &dataset {
+                store.insert(id, &vector.to_vec(), &json!({})).unwrap();
+            }
+            let ranked: Vec<String> = store.search(&query.to_vec(), dataset.len()).unwrap().into_iter().map(|r| r.id).collect();
+
+            let mut reference: Vec<(&str, f64)> = dataset
+                .iter()
+                .map(|(id, vector)| {
+                    let v = as_f64(vector);
+                    let key = match metric {
+                        SimilarityMetric::Cosine => -dot(&q, &v) / (dot(&q, &q).sqrt() * dot(&v, &v).sqrt()),
+                        SimilarityMetric::DotProduct => -dot(&q, &v),
+                        SimilarityMetric::Euclidean => q.iter().zip(&v).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt(),
+                    };
+                    (*id, key)
+                })
+                .collect();
+            reference.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            let expected: Vec<&str> = reference.iter().map(|(id, _)| *id).collect();
+
+            assert_eq!(ranked, expected, "{} ranking", metric);
+        }
+    }
+
+    /**
+     * Test: Consecutive pages equal one larger query; tied scores ordered by id
+     */
+    #[test]
+    fn test_search_paginated() {
+        let mut store = SqliteVectorStore::new_in_memory().unwrap();
+        for i in 0..25 {
+            let angle = i as f32 * 0.05;
+            store.insert(&format!("p{:02}", i), &vec![angle.cos(), angle.sin()], &json!({})).unwrap();
+        }
+        // Same vector as p03 → tied score, ordered by id
+        let angle = 3.0f32 * 0.05;
+        store.insert("p03-copy", &vec![angle.cos(), angle.sin()], &json!({})).unwrap();
+
+        let query = vec![1.0, 0.0];
+        let ids = |results: Vec<SearchResult>| results.into_iter().map(|r| r.id).collect::<Vec<_>>();
+        let all = ids(store.search(&query, 20).unwrap());
+        let mut paged = ids(store.search_paginated(&query, 10, 0).unwrap());
+        paged.extend(ids(store.search_paginated(&query, 10, 10).unwrap()));
+
+        assert_eq!(paged, all);
+        assert_eq!(&all[3..5], ["p03", "p03-copy"]);
+        assert_eq!(store.search_paginated(&query, 10, 20).unwrap().len(), 6);
+        assert!(store.search_paginated(&query, 10, 30).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_metric_persisted_with_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("store.sqlite");
+
+        let store = SqliteVectorStore::with_metric(&path, SimilarityMetric::DotProduct).unwrap();
+        assert_eq!(store.metric(), SimilarityMetric::DotProduct);
+        drop(store);
+
+        // Reopening without a preference keeps the stored metric
+        assert_eq!(SqliteVectorStore::new(&path).unwrap().metric(), SimilarityMetric::DotProduct);
+        assert!(SqliteVectorStore::with_metric(&path, SimilarityMetric::DotProduct).is_ok());
+
+        // Asking for a different metric is refused instead of silently mixing
+        let err = SqliteVectorStore::with_metric(&path, SimilarityMetric::Cosine).err().unwrap();
+        assert!(err.to_string().contains("dot_product"), "{}", err);
+
+        // Stores created before metrics were recorded are cosine
+        let legacy = dir.path().join("legacy.sqlite");
+        Connection::open(&legacy)
+            .unwrap()
+            .execute(
+                "CREATE TABLE vectors (id TEXT PRIMARY KEY, embedding TEXT NOT NULL, metadata TEXT NOT NULL, created_at INTEGER NOT NULL)",
+                [],
+            )
+            .unwrap();
+        assert_eq!(SqliteVectorStore::new(&legacy).unwrap().metric(), SimilarityMetric::Cosine);
     }
 
     #[test]
@@ -301,6 +689,18 @@ mod tests {
         assert_eq!(store.count().unwrap(), 2);
     }
 
+    #[test]
+    fn test_vector_store_dimension() {
+        let mut store = SqliteVectorStore::new_in_memory().unwrap();
+        assert_eq!(store.dimension().unwrap(), None);
+
+        store.insert("1", &vec![0.1, 0.2, 0.3], &json!({})).unwrap();
+        assert_eq!(store.dimension().unwrap(), Some(3));
+
+        store.clear().unwrap();
+        assert_eq!(store.dimension().unwrap(), None);
+    }
+
     #[test]
     fn test_vector_store_delete() {
         let mut store = SqliteVectorStore::new_in_memory().unwrap();
@@ -314,6 +714,30 @@ mod tests {
         assert_eq!(store.count().unwrap(), 1);
     }
 
+    #[test]
+    fn test_vector_store_delete_where() {
+        let mut store = SqliteVectorStore::new_in_memory().unwrap();
+
+        store.insert("1", &vec![0.1], &json!({"domain": "rust", "source": "a.md"})).unwrap();
+        store.insert("2", &vec![0.2], &json!({"domain": "rust", "source": "b.md"})).unwrap();
+        store.insert("3", &vec![0.3], &json!({"domain": "typescript", "source": "c.md"})).unwrap();
+
+        let deleted = store.delete_where(&json!({"domain": "rust"})).unwrap();
+        assert_eq!(deleted, 2);
+
+        let remaining = store.search(&vec![1.0], 10).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].metadata["domain"], "typescript");
+
+        // All keys must match
+        assert_eq!(store.delete_where(&json!({"domain": "typescript", "source": "a.md"})).unwrap(), 0);
+
+        // Refuse filters that would match everything
+        assert!(store.delete_where(&json!({})).is_err());
+        assert!(store.delete_where(&json!("rust")).is_err());
+        assert_eq!(store.count().unwrap(), 1);
+    }
+
     #[test]
     fn test_vector_store_clear() {
         let mut store = SqliteVectorStore::new_in_memory().unwrap();
@@ -327,6 +751,37 @@ mod tests {
         assert_eq!(store.count().unwrap(), 0);
     }
 
+    /**
+     * Test: 10k-vector batch stores every row; failed batch rolls back
+     *
+     * Speed vs per-row inserts is measured in benches/vector_store_batch.rs.
+     */
+    #[test]
+    fn test_vector_store_insert_batch() {
+        let dir = tempfile::tempdir().unwrap();
+        let embedding = vec![0.1_f32; 8];
+        let metadata = json!({"domain": "rust"});
+        let ids: Vec<String> = (0..10_000).map(|i| format!("pattern-{}", i)).collect();
+
+        let mut batched = SqliteVectorStore::new(dir.path().join("batched.sqlite")).unwrap();
+        let inserted = batched
+            .insert_batch(ids.iter().map(|id| (id.as_str(), &embedding, &metadata)))
+            .unwrap();
+
+        assert_eq!(inserted, ids.len());
+        assert_eq!(batched.count().unwrap(), ids.len());
+
+        // Failure mid-batch leaves the store unchanged
+        let poisoned = vec![f32::NAN; 8];
+        let result = batched.insert_batch(vec![
+            ("new-1", &embedding, &metadata),
+            ("new-2", &poisoned, &metadata),
+            ("new-3", &embedding, &metadata),
+        ]);
+        assert!(result.is_err());
+        assert_eq!(batched.count().unwrap(), ids.len());
+    }
+
     #[test]
     fn test_vector_store_upsert() {
         let mut store = SqliteVectorStore::new_in_memory().unwrap();
@@ -343,4 +798,40 @@ mod tests {
         let results = store.search(&vec![0.2], 1).unwrap();
         assert_eq!(results[0].metadata["version"], 2);
     }
+
+    /**
+     * Test: Approximate search falls back to exact without an index and tracks
+     * inserts, deletes and clears once the index is built
+     */
+    #[test]
+    fn test_ann_index_search() {
+        let mut store = SqliteVectorStore::new_in_memory().unwrap();
+        let approximate = SearchMode::Approximate { ef: crate::vector_store::DEFAULT_EF_SEARCH };
+        for i in 0..50 {
+            let angle = i as f32 * 0.05;
+            store.insert(&format!("p{:02}", i), &vec![angle.cos(), angle.sin()], &json!({"i": i})).unwrap();
+        }
+        let query = vec![1.0, 0.0];
+
+        // No index yet: same results as exact search
+        assert!(!store.has_ann_index());
+        let exact: Vec<String> = store.search(&query, 5).unwrap().into_iter().map(|r| r.id).collect();
+        let fallback: Vec<String> = store.search_with(&query, 5, approximate).unwrap().into_iter().map(|r| r.id).collect();
+        assert_eq!(fallback, exact);
+
+        assert_eq!(store.build_ann_index().unwrap(), 50);
+        let results = store.search_with(&query, 5, approximate).unwrap();
+        assert_eq!(results.iter().map(|r| r.id.clone()).collect::<Vec<_>>(), exact);
+        assert_eq!(results[0].metadata["i"], 0);
+
+        // Index follows writes
+        store.delete("p00").unwrap();
+        store.delete_where(&json!({"i": 1})).unwrap();
+        store.insert("fresh", &vec![1.0, 0.0], &json!({"i": -1})).unwrap();
+        let ids: Vec<String> = store.search_with(&query, 2, approximate).unwrap().into_iter().map(|r| r.id).collect();
+        assert_eq!(ids, ["fresh", "p02"]);
+
+        store.clear().unwrap();
+        assert!(store.search_with(&query, 5, approximate).unwrap().is_empty());
+    }
 }
@@ -146,6 +146,112 @@ impl VerificationResult {
     }
 }
 
+/// Acceptance criterion and the claims that prove it
+///
+/// DESIGN DECISION: A criterion is met only if every one of its claims verifies
+/// WHY: "API returns 200 and tests pass" is not half-met when only tests pass;
+/// partial credit belongs at the task level (CriteriaScore), not per criterion
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriterionClaims {
+    /// Criterion text, as in the task's `acceptance_criteria`
+    pub criterion: String,
+
+    /// Claims that must all verify for the criterion to count as met
+    pub claims: Vec<AgentClaim>,
+
+    /// Relative weight in the completion percentage (default 1.0)
+    pub weight: f64,
+}
+
+impl CriterionClaims {
+    /// Criterion with weight 1.0
+    pub fn new(criterion: impl Into<String>, claims: Vec<AgentClaim>) -> Self {
+        Self {
+            criterion: criterion.into(),
+            claims,
+            weight: 1.0,
+        }
+    }
+
+    /// Set relative weight (negative weights count as 0)
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        self.weight = weight.max(0.0);
+        self
+    }
+}
+
+/// Task completion scored against its acceptance criteria
+///
+/// DESIGN DECISION: Weighted share of met criteria instead of all-or-nothing
+/// WHY: A task with 2 of 3 criteria verified is "partially complete", and the
+/// scheduler needs to know which criteria remain
+///
+/// REASONING CHAIN:
+/// 1. Each criterion maps to one or more claims (CriterionClaims)
+/// 2. Criterion met = every claim has a verified result (no claims = unprovable = unmet)
+/// 3. completion_percentage = met weight / total weight × 100 (equal weights: met / total)
+/// 4. unmet lists the remaining criteria in plan order
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriteriaScore {
+    /// Weighted share of criteria met (0.0 to 100.0)
+    pub completion_percentage: f64,
+
+    /// Criteria whose claims all verified
+    pub verified: Vec<String>,
+
+    /// Criteria still unmet
+    pub unmet: Vec<String>,
+}
+
+impl CriteriaScore {
+    /// Score criteria against verification results
+    ///
+    /// Results are matched to claims by equality; a claim without a result is unverified.
+    /// No criteria (or zero total weight) scores 100%.
+    pub fn score(criteria: &[CriterionClaims], results: &[VerificationResult]) -> Self {
+        let mut verified = Vec::new();
+        let mut unmet = Vec::new();
+        let mut met_weight = 0.0;
+        let mut total_weight = 0.0;
+
+        for criterion in criteria {
+            let weight = criterion.weight.max(0.0);
+            total_weight += weight;
+
+            let met = !criterion.claims.is_empty()
+                && criterion.claims.iter().all(|claim| {
+                    results.iter().any(|result| result.verified && &result.claim == claim)
+                });
+
+            if met {
+                met_weight += weight;
+                verified.push(criterion.criterion.clone());
+            } else {
+                unmet.push(criterion.criterion.clone());
+            }
+        }
+
+        let completion_percentage = if total_weight > 0.0 {
+            (met_weight / total_weight) * 100.0
+        } else if unmet.is_empty() {
+            100.0
+        } else {
+            0.0
+        };
+
+        Self {
+            completion_percentage,
+            verified,
+            unmet,
+        }
+    }
+
+    /// All criteria met
+    pub fn is_complete(&self) -> bool {
+        self.unmet.is_empty()
+    }
+}
+
 /// Verifier trait
 ///
 /// DESIGN DECISION: Async trait for verification operations
@@ -235,6 +341,20 @@ impl VerificationSystem {
     pub fn with_defaults(root: PathBuf) -> Self {
         Self::new(root, VerificationConfig::default())
     }
+
+    /// Verify every criterion's claims and score the task
+    ///
+    /// A claim whose verifier returns Err counts as unverified.
+    pub async fn verify_criteria(&self, criteria: &[CriterionClaims]) -> CriteriaScore {
+        let mut results = Vec::new();
+        for claim in criteria.iter().flat_map(|c| &c.claims) {
+            if let Ok(result) = self.verify(claim).await {
+                results.push(result);
+            }
+        }
+
+        CriteriaScore::score(criteria, &results)
+    }
 }
 
 #[async_trait]
@@ -295,9 +415,10 @@ impl Verifier for VerificationSystem {
 
         // Warn if verification took too long
         if duration > self.config.timeout_ms {
-            eprintln!(
-                "⚠️  Verification took {}ms (target: {}ms)",
-                duration, self.config.timeout_ms
+            tracing::warn!(
+                duration_ms = duration,
+                target_ms = self.config.timeout_ms,
+                "Verification exceeded time budget"
             );
         }
 
@@ -418,6 +539,46 @@ mod tests {
         assert!(result.error.is_none());
     }
 
+    #[test]
+    fn test_criteria_score_partial() {
+        let table = AgentClaim::FileReference { file: PathBuf::from("migrations/001.sql"), line: None };
+        let tests = AgentClaim::TestsPassing { count: 12, total: 12 };
+        let coverage = AgentClaim::TestCoverage { percentage: 85.0 };
+        let criteria = vec![
+            CriterionClaims::new("Table exists", vec![table.clone()]),
+            CriterionClaims::new("Tests pass", vec![tests.clone()]),
+            CriterionClaims::new("Coverage above 80%", vec![coverage.clone()]),
+        ];
+        let results = vec![
+            VerificationResult::success(table, 10),
+            VerificationResult::success(tests, 10),
+            VerificationResult::failed(coverage, "72%".to_string(), 10),
+        ];
+
+        let score = CriteriaScore::score(&criteria, &results);
+
+        assert!((score.completion_percentage - 66.67).abs() < 0.01);
+        assert_eq!(score.verified, vec!["Table exists", "Tests pass"]);
+        assert_eq!(score.unmet, vec!["Coverage above 80%"]);
+        assert!(!score.is_complete());
+    }
+
+    #[test]
+    fn test_criteria_score_weights() {
+        let tests = AgentClaim::TestsPassing { count: 3, total: 3 };
+        let criteria = vec![
+            CriterionClaims::new("Tests pass", vec![tests.clone()]).with_weight(3.0),
+            // No claims: can't be proven
+            CriterionClaims::new("Docs updated", vec![]),
+        ];
+
+        let score = CriteriaScore::score(&criteria, &[VerificationResult::success(tests, 5)]);
+        assert!((score.completion_percentage - 75.0).abs() < 0.01);
+        assert_eq!(score.unmet, vec!["Docs updated"]);
+
+        assert_eq!(CriteriaScore::score(&[], &[]).completion_percentage, 100.0);
+    }
+
     #[test]
     fn test_verification_stats() {
         let mut stats = VerificationStats::new();
@@ -49,12 +49,12 @@ impl FunctionVerifier {
                 Ok(json) => match serde_json::from_str::<CodeMap>(&json) {
                     Ok(map) => Some(map),
                     Err(e) => {
-                        eprintln!("⚠️  Failed to parse code map: {}", e);
+                        tracing::warn!(error = %e, "Failed to parse code map");
                         None
                     }
                 },
                 Err(e) => {
-                    eprintln!("⚠️  Failed to read code map: {}", e);
+                    tracing::warn!(error = %e, "Failed to read code map");
                     None
                 }
             }
@@ -1,7 +1,12 @@
 use crate::error::Result;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
+/// Pending invitations expire after this many days (see expire_stale)
+pub const INVITATION_TTL_DAYS: i64 = 30;
+
 /**
  * Viral Invitation Manager
  *
@@ -19,7 +24,11 @@ use uuid::Uuid;
  * PATTERN: Pattern-VIRAL-001 (Storage-Based Viral Growth Mechanics)
  * SECURITY: Referral codes are UUIDs (unguessable, no enumeration attacks)
  * PRIVACY: Email addresses only stored with explicit consent
- * ANTI-ABUSE: Invitation caps prevent spam, email verification required
+ * ANTI-ABUSE: Invitation caps prevent spam, email verification required; a user can't
+ * accept their own code, and an invitee email earns a bonus at most once (compared
+ * trimmed and case-insensitive)
+ * LIFECYCLE: Pending → Accepted → Converted; Pending → Expired via expire_stale
+ * (only Pending ever expires, so a sweep can't take back an earned bonus)
  */
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +36,9 @@ pub struct InvitationManager {
     // Placeholder: Full implementation uses SQLite + DHT sync
     user_id: String,
     tier: UserTier,
+    /// Invitations held in memory until the Phase 4 invitations table exists
+    #[serde(default)]
+    invitations: Vec<Invitation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -45,6 +57,9 @@ pub struct Invitation {
     pub status: InvitationStatus,
     pub created_at: String,  // ISO 8601
     pub accepted_at: Option<String>,
+    /// Last reminder nudge (ISO 8601); one reminder per pending invitation
+    #[serde(default)]
+    pub reminded_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -68,6 +83,15 @@ pub enum InvitationError {
 
     #[error("Database error: {0}")]
     DatabaseError(String),
+
+    #[error("Invalid timestamp on invitation {id}: {value}")]
+    InvalidTimestamp { id: String, value: String },
+
+    #[error("Cannot accept your own referral code")]
+    SelfReferral,
+
+    #[error("Invitee {0} already accepted an invitation from this user")]
+    DuplicateInvitee(String),
 }
 
 impl InvitationManager {
@@ -82,7 +106,26 @@ impl InvitationManager {
      * - Initialize SQLite connection for invitation tracking
      */
     pub fn new(user_id: String, tier: UserTier) -> Self {
-        Self { user_id, tier }
+        Self { user_id, tier, invitations: Vec::new() }
+    }
+
+    /**
+     * Record a new pending invitation with a fresh referral code
+     *
+     * PLACEHOLDER: Kept in memory (Phase 4: INSERT INTO invitations)
+     */
+    pub fn create_invitation(&mut self, invitee_email: Option<String>, now: DateTime<Utc>) -> Result<Invitation> {
+        let invitation = Invitation {
+            id: Uuid::new_v4().to_string(),
+            referral_code: self.generate_referral_code()?,
+            invitee_email,
+            status: InvitationStatus::Pending,
+            created_at: now.to_rfc3339(),
+            accepted_at: None,
+            reminded_at: None,
+        };
+        self.invitations.push(invitation.clone());
+        Ok(invitation)
     }
 
     /**
@@ -114,7 +157,7 @@ impl InvitationManager {
     /**
      * Get all invitations created by this user
      *
-     * PLACEHOLDER: Returns empty vector
+     * PLACEHOLDER: Returns invitations created through this manager, newest first
      *
      * FULL IMPLEMENTATION (Phase 4):
      * 1. Query SQLite: SELECT * FROM invitations WHERE referrer_id = ?
@@ -124,13 +167,86 @@ impl InvitationManager {
      */
     pub fn get_my_invitations(&self) -> Result<Vec<Invitation>> {
         // TODO (Phase 4): Query SQLite for invitations
-        Ok(vec![])
+        let mut invitations = self.invitations.clone();
+        invitations.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(invitations)
+    }
+
+    /**
+     * Expire pending invitations older than `ttl`
+     *
+     * DESIGN DECISION: Sweep driven by the caller's clock (now passed in)
+     * WHY: Nothing moved Pending to Expired; an explicit `now` keeps the sweep
+     * deterministic in tests and lets a scheduler run it at any interval
+     *
+     * REASONING CHAIN:
+     * 1. Only Pending invitations are candidates; Accepted/Converted/Expired never change
+     * 2. Timestamps are parsed before anything changes (bad data → error, nothing expired)
+     * 3. Expired when created_at + ttl < now (an invitation exactly ttl old survives)
+     * 4. Returns ids of the invitations expired by this sweep
+     */
+    pub fn expire_stale(&mut self, now: DateTime<Utc>, ttl: Duration) -> std::result::Result<Vec<String>, InvitationError> {
+        let stale = self.pending_older_than(now, ttl)?;
+
+        for invitation in &mut self.invitations {
+            if stale.contains(&invitation.id) {
+                invitation.status = InvitationStatus::Expired;
+            }
+        }
+
+        Ok(stale)
+    }
+
+    /**
+     * Pending invitations due a reminder nudge
+     *
+     * Eligible: still Pending, at least `reminder_after` old, not reminded yet
+     * (mark_reminded records the nudge). Run expire_stale first so invitations past
+     * their TTL aren't nudged.
+     */
+    pub fn pending_needing_reminder(
+        &self,
+        now: DateTime<Utc>,
+        reminder_after: Duration,
+    ) -> std::result::Result<Vec<Invitation>, InvitationError> {
+        let mut due = Vec::new();
+        for invitation in self.invitations.iter().filter(|i| i.status == InvitationStatus::Pending) {
+            if invitation.reminded_at.is_none() && created_at(invitation)? + reminder_after <= now {
+                due.push(invitation.clone());
+            }
+        }
+        Ok(due)
+    }
+
+    /// Record that a reminder was sent for an invitation
+    pub fn mark_reminded(&mut self, invitation_id: &str, now: DateTime<Utc>) -> std::result::Result<(), InvitationError> {
+        let invitation = self
+            .invitations
+            .iter_mut()
+            .find(|i| i.id == invitation_id)
+            .ok_or_else(|| InvitationError::NotFound(invitation_id.to_string()))?;
+        invitation.reminded_at = Some(now.to_rfc3339());
+        Ok(())
+    }
+
+    fn pending_older_than(&self, now: DateTime<Utc>, age: Duration) -> std::result::Result<Vec<String>, InvitationError> {
+        let mut ids = Vec::new();
+        for invitation in self.invitations.iter().filter(|i| i.status == InvitationStatus::Pending) {
+            if created_at(invitation)? + age < now {
+                ids.push(invitation.id.clone());
+            }
+        }
+        Ok(ids)
     }
 
     /**
      * Calculate storage bonus from accepted invitations
      *
-     * PLACEHOLDER: Returns 0 MB
+     * PLACEHOLDER: Counts invitations held by this manager
+     *
+     * DEDUPLICATION: Accepted invitations count once per distinct invitee email, so a
+     * re-invited address never adds a second bonus (invitations without an email
+     * count individually)
      *
      * FULL IMPLEMENTATION (Phase 4):
      * 1. Count accepted invitations (status = 'accepted' OR 'converted')
@@ -147,10 +263,12 @@ impl InvitationManager {
      * PATTERN: Pattern-VIRAL-001
      */
     pub fn calculate_storage_bonus(&self) -> Result<u64> {
-        let invitations = self.get_my_invitations()?;
-        let accepted_count = invitations
+        let mut emails = HashSet::new();
+        let accepted_count = self
+            .invitations
             .iter()
             .filter(|i| i.status == InvitationStatus::Accepted || i.status == InvitationStatus::Converted)
+            .filter(|i| i.invitee_email.as_deref().is_none_or(|email| emails.insert(normalize_email(email))))
             .count() as u64;
 
         let bonus_per_invite = match self.tier {
@@ -174,7 +292,7 @@ impl InvitationManager {
     /**
      * Accept invitation (called when new user signs up with referral code)
      *
-     * PLACEHOLDER: Returns Ok without doing anything
+     * PLACEHOLDER: Validates and marks the in-memory invitation accepted
      *
      * FULL IMPLEMENTATION (Phase 4):
      * 1. Validate referral code exists and not expired
@@ -184,15 +302,62 @@ impl InvitationManager {
      * 5. Send notification to referrer ("John accepted your invitation!")
      * 6. Track K-factor metrics for viral growth analysis
      *
+     * REJECTED (nothing changes):
+     * - Unknown code, or invitation no longer Pending → InvalidReferralCode
+     * - invitee_user_id is the referrer → SelfReferral
+     * - Email already accepted another of this user's invitations → DuplicateInvitee
+     *
      * SECURITY: Email verification required before bonus granted
      * ANTI-ABUSE: Rate limiting (max 10 signups per referral code per day)
      */
-    pub fn accept_invitation(&mut self, _referral_code: String, _invitee_email: String) -> Result<()> {
-        // TODO (Phase 4): Validate, create user, grant bonus
+    pub fn accept_invitation(
+        &mut self,
+        referral_code: String,
+        invitee_user_id: String,
+        invitee_email: String,
+    ) -> std::result::Result<(), InvitationError> {
+        if invitee_user_id == self.user_id {
+            return Err(InvitationError::SelfReferral);
+        }
+
+        let email = normalize_email(&invitee_email);
+        let already_accepted = self.invitations.iter().any(|i| {
+            matches!(i.status, InvitationStatus::Accepted | InvitationStatus::Converted)
+                && i.invitee_email.as_deref().map(normalize_email).as_deref() == Some(email.as_str())
+        });
+        if already_accepted {
+            return Err(InvitationError::DuplicateInvitee(email));
+        }
+
+        let invitation = self
+            .invitations
+            .iter_mut()
+            .find(|i| i.referral_code == referral_code && i.status == InvitationStatus::Pending)
+            .ok_or(InvitationError::InvalidReferralCode(referral_code))?;
+
+        // TODO (Phase 4): Create user, notify referrer
+        invitation.status = InvitationStatus::Accepted;
+        invitation.invitee_email = Some(email);
+        invitation.accepted_at = Some(Utc::now().to_rfc3339());
         Ok(())
     }
 }
 
+/// Comparison form of an email address (trimmed, lowercase)
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// created_at of an invitation as UTC
+fn created_at(invitation: &Invitation) -> std::result::Result<DateTime<Utc>, InvitationError> {
+    DateTime::parse_from_rfc3339(&invitation.created_at)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| InvitationError::InvalidTimestamp {
+            id: invitation.id.clone(),
+            value: invitation.created_at.clone(),
+        })
+}
+
 // ============================================================================
 // FULL IMPLEMENTATION ROADMAP (Phase 4)
 // ============================================================================
@@ -304,6 +469,81 @@ mod tests {
         // - 200 invites = +10000 MB (cap reached)
     }
 
+    #[test]
+    fn test_expire_stale_only_touches_pending() {
+        let mut manager = InvitationManager::new("user123".to_string(), UserTier::Pro);
+        let start = Utc::now();
+        let ttl = Duration::days(INVITATION_TTL_DAYS);
+
+        let old = manager.create_invitation(Some("old@example.com".to_string()), start).unwrap();
+        let converted = manager.create_invitation(None, start).unwrap();
+        let fresh = manager.create_invitation(None, start + Duration::days(20)).unwrap();
+        manager.invitations.iter_mut().find(|i| i.id == converted.id).unwrap().status = InvitationStatus::Converted;
+
+        // Exactly ttl old: not yet expired
+        assert!(manager.expire_stale(start + ttl, ttl).unwrap().is_empty());
+
+        let expired = manager.expire_stale(start + ttl + Duration::seconds(1), ttl).unwrap();
+        assert_eq!(expired, vec![old.id.clone()]);
+
+        let status = |id: &str| manager.invitations.iter().find(|i| i.id == id).unwrap().status.clone();
+        assert_eq!(status(&old.id), InvitationStatus::Expired);
+        assert_eq!(status(&converted.id), InvitationStatus::Converted);
+        assert_eq!(status(&fresh.id), InvitationStatus::Pending);
+
+        // Second sweep reports nothing new
+        assert!(manager.expire_stale(start + ttl + Duration::seconds(1), ttl).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pending_needing_reminder() {
+        let mut manager = InvitationManager::new("user123".to_string(), UserTier::Pro);
+        let start = Utc::now();
+        let week = Duration::days(7);
+
+        let due = manager.create_invitation(None, start).unwrap();
+        manager.create_invitation(None, start + Duration::days(5)).unwrap();
+
+        let reminders = manager.pending_needing_reminder(start + week, week).unwrap();
+        assert_eq!(reminders.iter().map(|i| i.id.clone()).collect::<Vec<_>>(), vec![due.id.clone()]);
+
+        // One nudge per invitation
+        manager.mark_reminded(&due.id, start + week).unwrap();
+        assert!(manager.pending_needing_reminder(start + week, week).unwrap().is_empty());
+        assert!(matches!(manager.mark_reminded("missing", start), Err(InvitationError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_self_referral_rejected() {
+        let mut manager = InvitationManager::new("user123".to_string(), UserTier::Pro);
+        let invitation = manager.create_invitation(None, Utc::now()).unwrap();
+
+        let result = manager.accept_invitation(invitation.referral_code.clone(), "user123".to_string(), "me@example.com".to_string());
+        assert!(matches!(result, Err(InvitationError::SelfReferral)));
+        assert_eq!(manager.invitations[0].status, InvitationStatus::Pending);
+        assert_eq!(manager.calculate_storage_bonus().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_duplicate_invitee_bonus_granted_once() {
+        let mut manager = InvitationManager::new("user123".to_string(), UserTier::Pro);
+        let first = manager.create_invitation(None, Utc::now()).unwrap();
+        let second = manager.create_invitation(None, Utc::now()).unwrap();
+
+        manager.accept_invitation(first.referral_code, "friend".to_string(), "Friend@Example.com".to_string()).unwrap();
+        assert_eq!(manager.calculate_storage_bonus().unwrap(), 20);
+
+        // Same address (different case/whitespace) on a second code
+        let result = manager.accept_invitation(second.referral_code.clone(), "friend-alt".to_string(), " friend@example.com".to_string());
+        assert!(matches!(result, Err(InvitationError::DuplicateInvitee(email)) if email == "friend@example.com"));
+
+        // Even if a duplicate slipped in (e.g., imported data), the bonus counts it once
+        let duplicate = manager.invitations.iter_mut().find(|i| i.id == second.id).unwrap();
+        duplicate.status = InvitationStatus::Converted;
+        duplicate.invitee_email = Some("FRIEND@example.com".to_string());
+        assert_eq!(manager.calculate_storage_bonus().unwrap(), 20);
+    }
+
     #[test]
     fn test_get_my_invitations_empty() {
         let manager = InvitationManager::new("user123".to_string(), UserTier::Pro);
@@ -21,8 +21,8 @@ pub mod invitation;
 pub mod storage_quota;
 
 pub use invitation::{
-    InvitationManager, Invitation, InvitationStatus, InvitationError, UserTier
+    InvitationManager, Invitation, InvitationStatus, InvitationError, UserTier, INVITATION_TTL_DAYS
 };
 pub use storage_quota::{
-    StorageQuotaManager, StorageStats, QuotaError
+    StorageQuotaManager, StorageStats, QuotaError, BYTES_PER_MB
 };
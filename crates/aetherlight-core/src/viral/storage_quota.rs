@@ -1,5 +1,5 @@
 use crate::error::{Error, Result};
-use crate::viral::invitation::UserTier;
+use crate::viral::invitation::{InvitationManager, UserTier};
 use serde::{Deserialize, Serialize};
 
 /**
@@ -16,14 +16,23 @@ use serde::{Deserialize, Serialize};
  * 5. Cap prevents abuse: Pro user with 100 invites = 2GB base + 1GB bonus (capped) = 3GB total
  * 6. Result: Fair system that rewards active users without breaking storage limits
  *
+ * ENFORCEMENT: Callers report bytes already stored (with_used_bytes) and each write
+ * (record_stored); check_can_store rejects a write that would pass the total.
+ * Usage is tracked in bytes, quotas are in MB (1 MB = 1,000,000 bytes)
+ *
  * PATTERN: Pattern-VIRAL-001 (Storage-Based Viral Growth Mechanics)
  * PERFORMANCE: O(1) quota calculations (simple arithmetic, no database queries)
  */
 
+/// Bytes per quota MB (decimal, so 2000 MB = 2 GB)
+pub const BYTES_PER_MB: u64 = 1_000_000;
+
 #[derive(Debug, Clone)]
 pub struct StorageQuotaManager {
     _user_id: String, // TODO: Use for Phase 4 authentication and quota tracking
     tier: UserTier,
+    used_bytes: u64,
+    bonus_mb: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,7 +68,72 @@ impl StorageQuotaManager {
      * - Initialize SQLite connection for quota tracking
      */
     pub fn new(user_id: String, tier: UserTier) -> Self {
-        Self { _user_id: user_id, tier }
+        Self { _user_id: user_id, tier, used_bytes: 0, bonus_mb: 0 }
+    }
+
+    /// Start from `bytes` already stored (e.g., size of the pattern database)
+    pub fn with_used_bytes(mut self, bytes: u64) -> Self {
+        self.used_bytes = bytes;
+        self
+    }
+
+    /// Viral bonus earned so far (capped per tier when the total is computed)
+    pub fn with_bonus_mb(mut self, bonus_mb: u64) -> Self {
+        self.bonus_mb = bonus_mb;
+        self
+    }
+
+    /**
+     * Viral bonus taken from the user's invitations
+     *
+     * Prefer this over with_bonus_mb: calculate_storage_bonus counts each invitee
+     * email once, so re-inviting an address never raises the quota twice.
+     */
+    pub fn with_invitation_bonus(self, invitations: &InvitationManager) -> Result<Self> {
+        Ok(self.with_bonus_mb(invitations.calculate_storage_bonus()?))
+    }
+
+    /// Bytes currently counted against the quota
+    pub fn used_bytes(&self) -> u64 {
+        self.used_bytes
+    }
+
+    /// Count a completed write against the quota
+    pub fn record_stored(&mut self, bytes: u64) {
+        self.used_bytes = self.used_bytes.saturating_add(bytes);
+    }
+
+    /// Total quota in MB: base + bonus capped for the tier
+    pub fn total_mb(&self) -> u64 {
+        self.get_base_storage() + self.bonus_mb.min(self.get_bonus_cap())
+    }
+
+    /// Whole MB still free (0 once the quota is full)
+    pub fn remaining_mb(&self) -> u64 {
+        (self.total_mb() * BYTES_PER_MB).saturating_sub(self.used_bytes) / BYTES_PER_MB
+    }
+
+    /**
+     * Enforce the quota before a write
+     *
+     * DESIGN DECISION: Reject only when the write would pass the total
+     * WHY: Filling the quota exactly (used == total) is allowed; one byte more is not
+     *
+     * Exceeded reports the usage the write would reach (MB, rounded up) and the total.
+     * Does not record the write: call record_stored once it succeeds.
+     */
+    pub fn check_can_store(&self, additional_bytes: u64) -> std::result::Result<(), QuotaError> {
+        let after_write = self.used_bytes.saturating_add(additional_bytes);
+        let total_mb = self.total_mb();
+
+        if after_write > total_mb * BYTES_PER_MB {
+            Err(QuotaError::QuotaExceeded {
+                used: after_write.div_ceil(BYTES_PER_MB),
+                total: total_mb,
+            })
+        } else {
+            Ok(())
+        }
     }
 
     /**
@@ -107,21 +181,18 @@ impl StorageQuotaManager {
     /**
      * Get current storage statistics
      *
-     * PLACEHOLDER: Returns hardcoded stats (0 MB used)
-     *
-     * FULL IMPLEMENTATION (Phase 4):
-     * 1. Query SQLite: SELECT SUM(size_bytes) FROM patterns WHERE user_id = ?
-     * 2. Query invitations: Calculate viral bonus from accepted invitations
-     * 3. Calculate: total = base + min(bonus, cap)
-     * 4. Calculate: percentage_used = (used / total) * 100
-     * 5. Return StorageStats struct
+     * Same inputs as quota enforcement (check_can_store), so the numbers shown are
+     * the numbers enforced:
+     * - used_mb: usage reported to this manager (with_used_bytes, record_stored),
+     *   rounded up to whole MB
+     * - bonus_mb: bonus set with with_bonus_mb / with_invitation_bonus, capped for the tier
+     * - total_mb: base + capped bonus (total_mb())
      */
-    pub fn get_storage_stats(&self, bonus_mb: u64) -> Result<StorageStats> {
-        let used_mb = 0; // TODO (Phase 4): Query actual usage from SQLite
+    pub fn get_storage_stats(&self) -> Result<StorageStats> {
+        let used_mb = self.used_bytes.div_ceil(BYTES_PER_MB);
         let base_mb = self.get_base_storage();
-        let bonus_cap = self.get_bonus_cap();
-        let capped_bonus = bonus_mb.min(bonus_cap);
-        let total_mb = base_mb + capped_bonus;
+        let capped_bonus = self.bonus_mb.min(self.get_bonus_cap());
+        let total_mb = self.total_mb();
         let percentage_used = if total_mb > 0 {
             (used_mb as f64 / total_mb as f64) * 100.0
         } else {
@@ -138,9 +209,7 @@ impl StorageQuotaManager {
     }
 
     /**
-     * Check if user can store additional data
-     *
-     * PLACEHOLDER: Always returns true (no quota enforcement)
+     * Check if user can store additional data (whole MB, bonus as set on this manager)
      *
      * FULL IMPLEMENTATION (Phase 4):
      * 1. Get current usage: used_mb
@@ -156,8 +225,8 @@ impl StorageQuotaManager {
      *
      * PATTERN: Pattern-VIRAL-001 (Storage incentivizes invitations)
      */
-    pub fn can_store(&self, size_mb: u64, current_bonus_mb: u64) -> Result<bool> {
-        let stats = self.get_storage_stats(current_bonus_mb)?;
+    pub fn can_store(&self, size_mb: u64) -> Result<bool> {
+        let stats = self.get_storage_stats()?;
 
         if stats.used_mb + size_mb > stats.total_mb {
             Err(Error::Internal(format!(
@@ -183,8 +252,8 @@ impl StorageQuotaManager {
  *    - storage_quotas table: (user_id, used_mb, base_mb, bonus_mb, updated_at)
  *
  * 2. Quota Enforcement:
- *    - Hook into pattern storage: Check can_store() before inserting
- *    - Update used_mb on pattern insert/delete
+ *    - Hook into pattern storage: check_can_store() before inserting (done for initial sync)
+ *    - Update used bytes on pattern insert/delete (record_stored covers inserts only)
  *    - Trigger quota recalculation on invitation acceptance
  *
  * 3. UI Integration (products/lumina-desktop/src/components/StorageQuotaBar.tsx):
@@ -230,7 +299,7 @@ mod tests {
     #[test]
     fn test_storage_stats_with_no_bonus() {
         let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro);
-        let stats = manager.get_storage_stats(0).unwrap();
+        let stats = manager.get_storage_stats().unwrap();
 
         assert_eq!(stats.used_mb, 0); // Placeholder: no actual usage yet
         assert_eq!(stats.base_mb, 2000); // Pro tier base
@@ -241,8 +310,9 @@ mod tests {
 
     #[test]
     fn test_storage_stats_with_bonus_under_cap() {
-        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro);
-        let stats = manager.get_storage_stats(200).unwrap(); // 10 invites × 20MB
+        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro)
+            .with_bonus_mb(200); // 10 invites × 20MB
+        let stats = manager.get_storage_stats().unwrap();
 
         assert_eq!(stats.base_mb, 2000);
         assert_eq!(stats.bonus_mb, 200); // Under cap (1000 MB)
@@ -251,8 +321,9 @@ mod tests {
 
     #[test]
     fn test_storage_stats_with_bonus_exceeding_cap() {
-        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro);
-        let stats = manager.get_storage_stats(1500).unwrap(); // 75 invites × 20MB = 1500MB
+        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro)
+            .with_bonus_mb(1500); // 75 invites × 20MB = 1500MB
+        let stats = manager.get_storage_stats().unwrap();
 
         assert_eq!(stats.base_mb, 2000);
         assert_eq!(stats.bonus_mb, 1000); // Capped at 1000 MB
@@ -261,8 +332,9 @@ mod tests {
 
     #[test]
     fn test_can_store_within_quota() {
-        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro);
-        let result = manager.can_store(500, 200); // Storing 500MB with 200MB bonus
+        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro)
+            .with_bonus_mb(200);
+        let result = manager.can_store(500); // Storing 500MB with 200MB bonus
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), true);
@@ -271,8 +343,57 @@ mod tests {
     #[test]
     fn test_can_store_exceeding_quota() {
         let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Free);
-        let result = manager.can_store(200, 0); // Storing 200MB with 100MB quota
+        let result = manager.can_store(200); // Storing 200MB with 100MB quota
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_check_can_store_exact_boundary() {
+        // Free tier: 100 MB, 60 MB already used
+        let mut manager = StorageQuotaManager::new("user123".to_string(), UserTier::Free)
+            .with_used_bytes(60 * BYTES_PER_MB);
+        assert_eq!(manager.remaining_mb(), 40);
+
+        // Write that lands exactly on the total is allowed
+        assert!(manager.check_can_store(40 * BYTES_PER_MB).is_ok());
+        manager.record_stored(40 * BYTES_PER_MB);
+        assert_eq!(manager.used_bytes(), manager.total_mb() * BYTES_PER_MB);
+        assert_eq!(manager.remaining_mb(), 0);
+
+        // used == total: nothing more fits, not even one byte
+        assert!(manager.check_can_store(0).is_ok());
+        match manager.check_can_store(1) {
+            Err(QuotaError::QuotaExceeded { used, total }) => assert_eq!((used, total), (101, 100)),
+            other => panic!("expected QuotaExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invitation_bonus_not_double_counted() {
+        let mut invitations = InvitationManager::new("user123".to_string(), UserTier::Pro);
+        let first = invitations.create_invitation(None, chrono::Utc::now()).unwrap();
+        let again = invitations.create_invitation(None, chrono::Utc::now()).unwrap();
+        invitations.accept_invitation(first.referral_code, "friend".to_string(), "friend@example.com".to_string()).unwrap();
+        assert!(invitations
+            .accept_invitation(again.referral_code, "friend".to_string(), "friend@example.com".to_string())
+            .is_err());
+
+        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Pro)
+            .with_invitation_bonus(&invitations)
+            .unwrap();
+        assert_eq!(manager.total_mb(), 2020); // 2000 base + one 20 MB bonus
+    }
+
+    #[test]
+    fn test_remaining_mb_includes_capped_bonus() {
+        let manager = StorageQuotaManager::new("user123".to_string(), UserTier::Network)
+            .with_bonus_mb(400) // capped at 250
+            .with_used_bytes(BYTES_PER_MB / 2);
+
+        assert_eq!(manager.total_mb(), 750);
+        assert_eq!(manager.remaining_mb(), 749); // partial MB used is not free
+        let stats = manager.get_storage_stats().unwrap();
+        assert_eq!((stats.used_mb, stats.bonus_mb, stats.total_mb), (1, 250, 750));
+    }
 }
@@ -149,31 +149,31 @@ async fn test_multi_agent_collaboration() -> Result<()> {
     network.register_agent(Box::new(InfrastructureAgent::new(
         create_test_patterns(Domain::Infrastructure),
         create_test_embeddings(Domain::Infrastructure),
-    )));
+    )))?;
     network.register_agent(Box::new(QualityAgent::new(
         create_test_patterns(Domain::Quality),
         create_test_embeddings(Domain::Quality),
-    )));
+    )))?;
     network.register_agent(Box::new(ScalabilityAgent::new(
         create_test_patterns(Domain::Scalability),
         create_test_embeddings(Domain::Scalability),
-    )));
+    )))?;
     network.register_agent(Box::new(KnowledgeAgent::new(
         create_test_patterns(Domain::Knowledge),
         create_test_embeddings(Domain::Knowledge),
-    )));
+    )))?;
     network.register_agent(Box::new(InnovationAgent::new(
         create_test_patterns(Domain::Innovation),
         create_test_embeddings(Domain::Innovation),
-    )));
+    )))?;
     network.register_agent(Box::new(DeploymentAgent::new(
         create_test_patterns(Domain::Deployment),
         create_test_embeddings(Domain::Deployment),
-    )));
+    )))?;
     network.register_agent(Box::new(EthicsAgent::new(
         create_test_patterns(Domain::Ethics),
         create_test_embeddings(Domain::Ethics),
-    )));
+    )))?;
 
     // DESIGN DECISION: Query about CI/CD pipeline testing (cross-domain problem)
     // WHY: Deployment + Quality domains both relevant (tests cross-agent collaboration)
@@ -419,31 +419,31 @@ async fn test_concurrent_queries() -> Result<()> {
     network.register_agent(Box::new(InfrastructureAgent::new(
         create_test_patterns(Domain::Infrastructure),
         create_test_embeddings(Domain::Infrastructure),
-    )));
+    )))?;
     network.register_agent(Box::new(QualityAgent::new(
         create_test_patterns(Domain::Quality),
         create_test_embeddings(Domain::Quality),
-    )));
+    )))?;
     network.register_agent(Box::new(ScalabilityAgent::new(
         create_test_patterns(Domain::Scalability),
         create_test_embeddings(Domain::Scalability),
-    )));
+    )))?;
     network.register_agent(Box::new(KnowledgeAgent::new(
         create_test_patterns(Domain::Knowledge),
         create_test_embeddings(Domain::Knowledge),
-    )));
+    )))?;
     network.register_agent(Box::new(InnovationAgent::new(
         create_test_patterns(Domain::Innovation),
         create_test_embeddings(Domain::Innovation),
-    )));
+    )))?;
     network.register_agent(Box::new(DeploymentAgent::new(
         create_test_patterns(Domain::Deployment),
         create_test_embeddings(Domain::Deployment),
-    )));
+    )))?;
     network.register_agent(Box::new(EthicsAgent::new(
         create_test_patterns(Domain::Ethics),
         create_test_embeddings(Domain::Ethics),
-    )));
+    )))?;
 
     let network = std::sync::Arc::new(tokio::sync::Mutex::new(network));
 
@@ -513,31 +513,31 @@ async fn test_agent_network_registration() -> Result<()> {
     network.register_agent(Box::new(InfrastructureAgent::new(
         create_test_patterns(Domain::Infrastructure),
         create_test_embeddings(Domain::Infrastructure),
-    )));
+    )))?;
     network.register_agent(Box::new(QualityAgent::new(
         create_test_patterns(Domain::Quality),
         create_test_embeddings(Domain::Quality),
-    )));
+    )))?;
     network.register_agent(Box::new(ScalabilityAgent::new(
         create_test_patterns(Domain::Scalability),
         create_test_embeddings(Domain::Scalability),
-    )));
+    )))?;
     network.register_agent(Box::new(KnowledgeAgent::new(
         create_test_patterns(Domain::Knowledge),
         create_test_embeddings(Domain::Knowledge),
-    )));
+    )))?;
     network.register_agent(Box::new(InnovationAgent::new(
         create_test_patterns(Domain::Innovation),
         create_test_embeddings(Domain::Innovation),
-    )));
+    )))?;
     network.register_agent(Box::new(DeploymentAgent::new(
         create_test_patterns(Domain::Deployment),
         create_test_embeddings(Domain::Deployment),
-    )));
+    )))?;
     network.register_agent(Box::new(EthicsAgent::new(
         create_test_patterns(Domain::Ethics),
         create_test_embeddings(Domain::Ethics),
-    )));
+    )))?;
 
     // VALIDATION: All 7 agents should be retrievable
     assert!(network.get_agent(Domain::Infrastructure).is_some());
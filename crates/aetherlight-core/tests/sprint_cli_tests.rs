@@ -0,0 +1,104 @@
+/**
+ * Integration tests for the sprint CLI (stdin input)
+ *
+ * DESIGN DECISION: Run the real aetherlight binary with a piped plan
+ * WHY: Pipelines depend on the exit code and output, not just the library result
+ *
+ * PATTERN: Pattern-CLI-001 (Command-Line Interface)
+ * RELATED: cli/sprint.rs, bin/aetherlight.rs
+ */
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const VALID_PLAN: &str = r#"
+sprint:
+  name: "Piped Sprint"
+  duration: "1 week"
+  goals: ["Ship"]
+  tasks:
+    - id: "DB-001"
+      title: "Create table"
+      agent: "database"
+      duration: "2 hours"
+      dependencies: []
+      acceptance_criteria: ["Table exists"]
+"#;
+
+/// Run `aetherlight <args>` with `stdin` piped in
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aetherlight"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to start aetherlight");
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_validate_plan_from_stdin() {
+    let output = run(&["sprint", "validate", "-"], VALID_PLAN);
+
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Sprint plan 'Piped Sprint' is valid (1 tasks"), "{}", stdout);
+}
+
+#[test]
+fn test_validate_invalid_plan_from_stdin() {
+    let output = run(&["sprint", "validate", "-"], "sprint:\n  name: \"No tasks\"\n");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Sprint plan validation failed"), "{}", stderr);
+}
+
+#[test]
+fn test_validate_toml_from_stdin_with_format() {
+    let plan = r#"
+[sprint]
+name = "Piped TOML"
+duration = "1 week"
+goals = []
+
+[[sprint.tasks]]
+id = "DB-001"
+title = "Create table"
+agent = "database"
+duration = "2 hours"
+acceptance_criteria = ["Table exists"]
+"#;
+
+    let output = run(&["sprint", "validate", "-", "--format", "toml"], plan);
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let output = run(&["sprint", "validate", "-", "--format", "json"], plan);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_simulate_prints_progress_bar() {
+    let output = run(&["sprint", "simulate", "-"], VALID_PLAN);
+
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[####################] 100% DB-001"), "{}", stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Tasks completed: 1"), "{}", stdout);
+}
+
+#[test]
+fn test_simulate_writes_progress_signals() {
+    let workflow_dir = tempfile::TempDir::new().unwrap();
+    let dir = workflow_dir.path().to_str().unwrap();
+
+    let output = run(&["sprint", "simulate", "-", "--workflow-dir", dir], VALID_PLAN);
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(workflow_dir.path().join("DB-001.progress.json").exists());
+
+    let output = run(&["sprint", "order", "-", "--workflow-dir", dir], VALID_PLAN);
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,185 @@
+/**
+ * DESIGN DECISION: Optional on-disk cache of parsed files keyed by path (--cache <file>)
+ * WHY: Watch-mode tooling re-runs the parser over the whole workspace; re-parsing
+ * unchanged files wastes most of the <3s budget
+ *
+ * REASONING CHAIN:
+ * 1. Each entry records the file's mtime, size, SHA-256 of its content and parsed result
+ * 2. mtime + size unchanged → reuse without reading the file
+ * 3. Stat changed → read and hash; same content → reuse (entry gets the new mtime)
+ * 4. Content changed → re-parse
+ * 5. Only files visited this run are written back (deleted files drop out);
+ *    parse errors are never cached, so broken files are retried every run
+ * 6. Missing, corrupt or old-version cache files (or a --metrics mismatch) just start empty
+ *
+ * PATTERN: Pattern-ANALYZER-001 (AST-Based Code Analysis)
+ * RELATED: main.rs (parse_directory)
+ */
+
+use crate::{parse_rust_source, RustFileError, RustParsedFile};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+/// Bump when RustParsedFile's shape changes so stale caches are discarded
+const CACHE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    /// Results were produced with --metrics (a run with the other setting starts empty)
+    #[serde(default)]
+    metrics: bool,
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CacheEntry {
+    mtime_ns: u64,
+    size: u64,
+    hash: String,
+    file: RustParsedFile,
+}
+
+pub struct ParseCache {
+    metrics: bool,
+    previous: HashMap<String, CacheEntry>,
+    current: BTreeMap<String, CacheEntry>,
+    /// Files served from the cache this run
+    pub hits: usize,
+    /// Files parsed this run
+    pub misses: usize,
+}
+
+impl ParseCache {
+    pub fn load(path: &Path, metrics: bool) -> Self {
+        let previous = fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|cache| cache.version == CACHE_VERSION && cache.metrics == metrics)
+            .map(|cache| cache.entries.into_iter().collect())
+            .unwrap_or_default();
+
+        ParseCache {
+            metrics,
+            previous,
+            current: BTreeMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let cache = CacheFile {
+            version: CACHE_VERSION,
+            metrics: self.metrics,
+            entries: self.current.clone(),
+        };
+        fs::write(path, serde_json::to_string(&cache)?)
+    }
+
+    /// Parse `path`, reusing the cached result when the file is unchanged
+    pub fn parse(&mut self, path: &Path) -> Result<RustParsedFile, RustFileError> {
+        let path_str = path.to_str().unwrap_or("").to_string();
+
+        let metadata = fs::metadata(path).map_err(|e| RustFileError::io(&path_str, e))?;
+        let size = metadata.len();
+        let mtime_ns = metadata
+            .modified()
+            .ok()
+            .and_then(|mtime| mtime.duration_since(UNIX_EPOCH).ok())
+            .map(|since_epoch| since_epoch.as_nanos() as u64)
+            .unwrap_or(0);
+
+        let mut cached = self.previous.remove(&path_str);
+        if let Some(entry) = cached.take_if(|e| e.mtime_ns == mtime_ns && e.size == size) {
+            return Ok(self.reuse(path_str, entry));
+        }
+
+        let content = fs::read_to_string(path).map_err(|e| RustFileError::io(&path_str, e))?;
+        let hash = format!("{:x}", Sha256::digest(content.as_bytes()));
+
+        if let Some(entry) = cached.filter(|e| e.hash == hash) {
+            return Ok(self.reuse(path_str, CacheEntry { mtime_ns, size, ..entry }));
+        }
+
+        self.misses += 1;
+        let file = parse_rust_source(path_str.clone(), &content, self.metrics)?;
+        self.current.insert(
+            path_str,
+            CacheEntry {
+                mtime_ns,
+                size,
+                hash,
+                file: file.clone(),
+            },
+        );
+        Ok(file)
+    }
+
+    fn reuse(&mut self, path_str: String, entry: CacheEntry) -> RustParsedFile {
+        self.hits += 1;
+        let file = entry.file.clone();
+        self.current.insert(path_str, entry);
+        file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_directory;
+
+    fn item_names(file: &RustParsedFile) -> Vec<&str> {
+        file.items.iter().map(|item| item.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_unchanged_tree_reuses_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("parse-cache.json");
+        let src = dir.path().join("src");
+        fs::create_dir(&src).unwrap();
+        fs::write(src.join("lib.rs"), "pub fn alpha() {}\n").unwrap();
+        fs::write(src.join("util.rs"), "pub struct Helper;\n").unwrap();
+
+        // First run: nothing cached
+        let mut cache = ParseCache::load(&cache_path, false);
+        let first = parse_directory(&src, Some(&mut cache), false);
+        assert_eq!((cache.hits, cache.misses), (0, 2));
+        cache.save(&cache_path).unwrap();
+
+        // Second run over the unchanged tree: everything reused
+        let mut cache = ParseCache::load(&cache_path, false);
+        let second = parse_directory(&src, Some(&mut cache), false);
+        assert_eq!((cache.hits, cache.misses), (2, 0));
+        assert_eq!(second.files.len(), first.files.len());
+        cache.save(&cache_path).unwrap();
+
+        // Edited file is re-parsed, the other one still reused
+        fs::write(src.join("lib.rs"), "pub fn alpha() {}\npub fn beta() {}\n").unwrap();
+        let mut cache = ParseCache::load(&cache_path, false);
+        let third = parse_directory(&src, Some(&mut cache), false);
+        assert_eq!((cache.hits, cache.misses), (1, 1));
+
+        let lib = third.files.iter().find(|f| f.path.ends_with("lib.rs")).unwrap();
+        assert_eq!(item_names(lib), vec!["alpha", "beta"]);
+    }
+
+    #[test]
+    fn test_corrupt_cache_starts_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("parse-cache.json");
+        fs::write(&cache_path, "{ not json").unwrap();
+        fs::write(dir.path().join("lib.rs"), "pub fn alpha() {}\n").unwrap();
+
+        let mut cache = ParseCache::load(&cache_path, false);
+        let output = parse_directory(dir.path(), Some(&mut cache), false);
+
+        assert_eq!(output.files.len(), 1);
+        assert_eq!((cache.hits, cache.misses), (0, 1));
+    }
+}
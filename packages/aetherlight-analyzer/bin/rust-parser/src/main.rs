@@ -14,6 +14,10 @@
  * PERFORMANCE: Target <3s for 30k LOC Rust code
  */
 
+mod cache;
+mod metrics;
+
+use cache::ParseCache;
 use clap::Parser as ClapParser;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -28,21 +32,55 @@ use walkdir::WalkDir;
 #[command(name = "rust-parser")]
 #[command(about = "Parse Rust code and output JSON AST")]
 struct Cli {
-    /// Directory to analyze
-    directory: PathBuf,
+    /// Directory to analyze, or a single .rs file (editor re-parse on save)
+    path: PathBuf,
 
     /// Output JSON format
     #[arg(long)]
     json: bool,
+
+    /// Reuse results for unchanged files across runs (directory mode only)
+    #[arg(long)]
+    cache: Option<PathBuf>,
+
+    /// Add complexity estimates and field counts (slower)
+    #[arg(long)]
+    metrics: bool,
 }
 
 #[derive(Serialize, Deserialize)]
 struct RustParseOutput {
     files: Vec<RustParsedFile>,
-    errors: Vec<String>,
+    errors: Vec<RustFileError>,
 }
 
-#[derive(Serialize, Deserialize)]
+/**
+ * File that couldn't be analyzed
+ *
+ * DESIGN DECISION: Structured entries instead of "path: message" strings
+ * WHY: Analyzer UI needs to tell unreadable files from syntax errors and point at the line
+ */
+#[derive(Serialize, Deserialize, Debug)]
+struct RustFileError {
+    path: String,
+    kind: String, // "io" | "parse"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>, // 1-based; parse errors only
+    message: String,
+}
+
+impl RustFileError {
+    fn io(path: &str, err: std::io::Error) -> Self {
+        RustFileError {
+            path: path.to_string(),
+            kind: "io".to_string(),
+            line: None,
+            message: err.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 struct RustParsedFile {
     path: String,
     items: Vec<RustItem>,
@@ -50,7 +88,7 @@ struct RustParsedFile {
     loc: usize,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct RustItem {
     kind: String, // "struct" | "trait" | "impl" | "fn" | "mod" | "enum" | "type"
     name: String,
@@ -64,9 +102,14 @@ struct RustItem {
     return_type: Option<String>,
     impl_trait: Option<String>,
     impl_target: Option<String>,
+    // --metrics only (omitted from the default output)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    complexity: Option<u32>, // fn: cyclomatic complexity estimate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field_count: Option<usize>, // struct
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct RustField {
     name: String,
     #[serde(rename = "type")]
@@ -75,7 +118,7 @@ struct RustField {
     location: Location,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct RustMethod {
     name: String,
     visibility: String,
@@ -83,22 +126,24 @@ struct RustMethod {
     return_type: Option<String>,
     is_async: bool,
     location: Location,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    complexity: Option<u32>, // --metrics only; None for trait methods without a body
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct RustParam {
     name: String,
     #[serde(rename = "type")]
     param_type: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct RustUse {
     path: String,
     items: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct Location {
     line: usize,
     column: usize,
@@ -107,13 +152,63 @@ struct Location {
 fn main() {
     let cli = Cli::parse();
 
+    let output = if cli.path.is_file() {
+        parse_single_file(&cli.path, cli.metrics)
+    } else if let Some(cache_path) = &cli.cache {
+        let mut cache = ParseCache::load(cache_path, cli.metrics);
+        let output = parse_directory(&cli.path, Some(&mut cache), cli.metrics);
+        if let Err(err) = cache.save(cache_path) {
+            eprintln!("Failed to write parse cache {}: {}", cache_path.display(), err);
+        }
+        output
+    } else {
+        parse_directory(&cli.path, None, cli.metrics)
+    };
+
+    // Output JSON
+    if cli.json || true {
+        // Always output JSON for now
+        match serde_json::to_string_pretty(&output) {
+            Ok(json) => println!("{}", json),
+            Err(err) => eprintln!("Failed to serialize JSON: {}", err),
+        }
+    }
+}
+
+/**
+ * Parse one explicitly requested file (no extension/target filtering)
+ */
+fn parse_single_file(path: &std::path::Path, metrics: bool) -> RustParseOutput {
+    let mut output = RustParseOutput {
+        files: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    match parse_rust_file(path, metrics) {
+        Ok(parsed_file) => output.files.push(parsed_file),
+        Err(err) => output.errors.push(err),
+    }
+
+    output
+}
+
+/**
+ * Parse every .rs file under a directory (full scan)
+ *
+ * With a cache, unchanged files reuse their previous result instead of being re-parsed
+ */
+fn parse_directory(
+    directory: &std::path::Path,
+    mut cache: Option<&mut ParseCache>,
+    metrics: bool,
+) -> RustParseOutput {
     let mut output = RustParseOutput {
         files: Vec::new(),
         errors: Vec::new(),
     };
 
     // Walk directory tree
-    for entry in WalkDir::new(&cli.directory)
+    for entry in WalkDir::new(directory)
         .into_iter()
         .filter_map(|e| e.ok())
     {
@@ -129,22 +224,17 @@ fn main() {
             continue;
         }
 
-        match parse_rust_file(path) {
+        let result = match cache.as_deref_mut() {
+            Some(cache) => cache.parse(path),
+            None => parse_rust_file(path, metrics),
+        };
+        match result {
             Ok(parsed_file) => output.files.push(parsed_file),
-            Err(err) => output
-                .errors
-                .push(format!("{}: {}", path.display(), err)),
+            Err(err) => output.errors.push(err),
         }
     }
 
-    // Output JSON
-    if cli.json || true {
-        // Always output JSON for now
-        match serde_json::to_string_pretty(&output) {
-            Ok(json) => println!("{}", json),
-            Err(err) => eprintln!("Failed to serialize JSON: {}", err),
-        }
-    }
+    output
 }
 
 /**
@@ -153,12 +243,32 @@ fn main() {
  * DESIGN DECISION: Use syn::parse_file for full AST parsing
  * WHY: syn is the standard Rust parser, used by rustc, rust-analyzer, rustfmt
  */
-fn parse_rust_file(path: &std::path::Path) -> Result<RustParsedFile, String> {
-    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+fn parse_rust_file(path: &std::path::Path, metrics: bool) -> Result<RustParsedFile, RustFileError> {
+    let path_str = path.to_str().unwrap_or("").to_string();
+
+    let content = fs::read_to_string(path).map_err(|e| RustFileError::io(&path_str, e))?;
 
-    let syntax_tree = syn::parse_file(&content).map_err(|e| e.to_string())?;
+    parse_rust_source(path_str, &content, metrics)
+}
 
-    let mut visitor = RustVisitor::new(path.to_str().unwrap_or("").to_string());
+/**
+ * Parse already-read source (parse errors carry syn's span line)
+ *
+ * NOTE: proc-macro2 "span-locations" is required; without it every span reports line 0
+ */
+fn parse_rust_source(
+    path_str: String,
+    content: &str,
+    metrics: bool,
+) -> Result<RustParsedFile, RustFileError> {
+    let syntax_tree = syn::parse_file(content).map_err(|e| RustFileError {
+        path: path_str.clone(),
+        kind: "parse".to_string(),
+        line: Some(e.span().start().line).filter(|&line| line > 0),
+        message: e.to_string(),
+    })?;
+
+    let mut visitor = RustVisitor::new(path_str.clone(), metrics);
     visitor.visit_file(&syntax_tree);
 
     // Calculate LOC (exclude blank lines and comments)
@@ -171,7 +281,7 @@ fn parse_rust_file(path: &std::path::Path) -> Result<RustParsedFile, String> {
         .count();
 
     Ok(RustParsedFile {
-        path: path.to_str().unwrap_or("").to_string(),
+        path: path_str,
         items: visitor.items,
         uses: visitor.uses,
         loc,
@@ -189,19 +299,26 @@ fn parse_rust_file(path: &std::path::Path) -> Result<RustParsedFile, String> {
  */
 struct RustVisitor {
     file_path: String,
+    metrics: bool,
     items: Vec<RustItem>,
     uses: Vec<RustUse>,
 }
 
 impl RustVisitor {
-    fn new(file_path: String) -> Self {
+    fn new(file_path: String, metrics: bool) -> Self {
         Self {
             file_path,
+            metrics,
             items: Vec::new(),
             uses: Vec::new(),
         }
     }
 
+    /// Complexity of a fn body when --metrics is on
+    fn complexity(&self, body: &syn::Block) -> Option<u32> {
+        self.metrics.then(|| metrics::cyclomatic_complexity(body))
+    }
+
     fn visibility_to_string(vis: &Visibility) -> String {
         match vis {
             Visibility::Public(_) => "pub".to_string(),
@@ -284,7 +401,7 @@ impl<'ast> Visit<'ast> for RustVisitor {
                 visibility: Self::visibility_to_string(&field.vis),
                 location: Self::placeholder_location(),
             })
-            .collect();
+            .collect::<Vec<_>>();
 
         self.items.push(RustItem {
             kind: "struct".to_string(),
@@ -293,12 +410,14 @@ impl<'ast> Visit<'ast> for RustVisitor {
             location: Self::placeholder_location(),
             documentation: Self::extract_documentation(&s.attrs),
             attrs: Some(Self::extract_attributes(&s.attrs)),
+            field_count: self.metrics.then_some(fields.len()),
             fields: Some(fields),
             methods: None,
             params: None,
             return_type: None,
             impl_trait: None,
             impl_target: None,
+            complexity: None,
         });
     }
 
@@ -334,6 +453,7 @@ impl<'ast> Visit<'ast> for RustVisitor {
                         },
                         is_async: method.sig.asyncness.is_some(),
                         location: Self::placeholder_location(),
+                        complexity: method.default.as_ref().and_then(|body| self.complexity(body)),
                     })
                 } else {
                     None
@@ -354,6 +474,8 @@ impl<'ast> Visit<'ast> for RustVisitor {
             return_type: None,
             impl_trait: None,
             impl_target: None,
+            complexity: None,
+            field_count: None,
         });
     }
 
@@ -389,6 +511,7 @@ impl<'ast> Visit<'ast> for RustVisitor {
                         },
                         is_async: method.sig.asyncness.is_some(),
                         location: Self::placeholder_location(),
+                        complexity: self.complexity(&method.block),
                     })
                 } else {
                     None
@@ -420,6 +543,8 @@ impl<'ast> Visit<'ast> for RustVisitor {
             return_type: None,
             impl_trait,
             impl_target: Some(impl_target),
+            complexity: None,
+            field_count: None,
         });
     }
 
@@ -462,6 +587,8 @@ impl<'ast> Visit<'ast> for RustVisitor {
             },
             impl_trait: None,
             impl_target: None,
+            complexity: self.complexity(&f.block),
+            field_count: None,
         });
     }
 
@@ -533,3 +660,30 @@ fn extract_use_items_with_prefix(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syntax_error_reports_line() {
+        let source = "pub struct Valid;\n\nfn broken() {\n    let x = ;\n}\n";
+
+        let err = parse_rust_source("src/broken.rs".to_string(), source, false).err().unwrap();
+
+        assert_eq!(err.path, "src/broken.rs");
+        assert_eq!(err.kind, "parse");
+        assert_eq!(err.line, Some(4));
+        assert!(!err.message.is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_file_is_io_error() {
+        let err = parse_rust_file(std::path::Path::new("/nonexistent/lib.rs"), false).err().unwrap();
+
+        assert_eq!(err.kind, "io");
+        assert_eq!(err.line, None);
+        let json = serde_json::to_value(&err).unwrap();
+        assert!(json.get("line").is_none());
+    }
+}
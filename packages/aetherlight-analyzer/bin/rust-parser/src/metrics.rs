@@ -0,0 +1,84 @@
+/**
+ * DESIGN DECISION: Cyclomatic complexity estimate from the syn AST (--metrics only)
+ * WHY: Code-map and impact analysis need a cheap "refactor candidate" signal;
+ * computing it on every run would slow the default path
+ *
+ * REASONING CHAIN:
+ * 1. Complexity = 1 (base) + decision points, same rule as typescript-parser.ts
+ * 2. Decision points: if (incl. else-if / if let), while, for, loop, && and ||
+ * 3. match with N arms adds N - 1 (first arm is the fall-through, like `default`)
+ * 4. Closures count toward the enclosing fn; nested items (fn, impl) do not
+ *
+ * PATTERN: Pattern-ANALYZER-001 (AST-Based Code Analysis)
+ * RELATED: main.rs (RustVisitor), typescript-parser.ts (calculateComplexity)
+ */
+
+use syn::visit::{self, Visit};
+use syn::{BinOp, Block, Expr, Item};
+
+/// Cyclomatic complexity of a function body
+pub fn cyclomatic_complexity(body: &Block) -> u32 {
+    let mut counter = DecisionCounter { decisions: 0 };
+    counter.visit_block(body);
+    1 + counter.decisions
+}
+
+struct DecisionCounter {
+    decisions: u32,
+}
+
+impl<'ast> Visit<'ast> for DecisionCounter {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::If(_) | Expr::While(_) | Expr::ForLoop(_) | Expr::Loop(_) => self.decisions += 1,
+            Expr::Match(m) => self.decisions += m.arms.len().saturating_sub(1) as u32,
+            Expr::Binary(b) if matches!(b.op, BinOp::And(_) | BinOp::Or(_)) => self.decisions += 1,
+            _ => {}
+        }
+        visit::visit_expr(self, expr);
+    }
+
+    // Nested items are measured on their own (or not at all), not as part of this body
+    fn visit_item(&mut self, _item: &'ast Item) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complexity_of(source: &str) -> u32 {
+        let f: syn::ItemFn = syn::parse_str(source).unwrap();
+        cyclomatic_complexity(&f.block)
+    }
+
+    #[test]
+    fn test_straight_line_fn_is_one() {
+        assert_eq!(complexity_of("fn f(a: u32) -> u32 { let b = a + 1; b * 2 }"), 1);
+    }
+
+    #[test]
+    fn test_known_branching() {
+        let source = r#"
+            fn classify(values: &[i32], strict: bool) -> &'static str {
+                for v in values {                       // +1
+                    if *v < 0 && strict {               // +1 if, +1 &&
+                        return "negative";
+                    } else if *v == 0 {                 // +1
+                        continue;
+                    }
+                }
+                match values.len() {                    // +2 (3 arms)
+                    0 => "empty",
+                    1 => "single",
+                    _ => {
+                        let check = |x: &i32| *x > 10 || *x < -10; // +1 (closure counts)
+                        fn nested(x: bool) -> bool { if x { !x } else { x } } // not counted
+                        if values.iter().any(check) && nested(strict) { "wide" } else { "many" } // +1 if, +1 &&
+                    }
+                }
+            }
+        "#;
+
+        assert_eq!(complexity_of(source), 1 + 1 + 2 + 1 + 2 + 1 + 2);
+    }
+}
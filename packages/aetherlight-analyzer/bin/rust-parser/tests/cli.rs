@@ -0,0 +1,56 @@
+/**
+ * CLI tests - run the rust-parser binary the way rust-parser.ts spawns it
+ */
+
+use std::path::Path;
+use std::process::Command;
+
+fn run(path: &Path) -> serde_json::Value {
+    run_with(path, &[])
+}
+
+fn run_with(path: &Path, flags: &[&str]) -> serde_json::Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-parser"))
+        .arg(path)
+        .arg("--json")
+        .args(flags)
+        .output()
+        .expect("failed to run rust-parser");
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("rust-parser printed invalid JSON")
+}
+
+#[test]
+fn test_single_file_mode() {
+    let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/main.rs");
+
+    let output = run(&file);
+
+    let files = output["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["path"], file.to_str().unwrap());
+    assert!(files[0]["items"].as_array().unwrap().iter().any(|item| item["name"] == "parse_rust_file"));
+    assert!(output["errors"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_metrics_flag() {
+    let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/main.rs");
+    let find = |output: &serde_json::Value, name: &str| {
+        output["files"][0]["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["name"] == name)
+            .cloned()
+            .unwrap()
+    };
+
+    // Default output stays lean
+    let plain = run(&file);
+    assert!(find(&plain, "main").get("complexity").is_none());
+
+    let output = run_with(&file, &["--metrics"]);
+    assert!(find(&output, "main")["complexity"].as_u64().unwrap() > 1);
+    assert_eq!(find(&output, "RustFileError")["field_count"], 4);
+}
@@ -267,7 +267,10 @@ pub struct BrokenStruct {
 
       // Parser should return errors but not crash
       expect(result.files).toBeDefined();
-      // May have parse errors depending on syn's error handling
+      const error = result.parseErrors.find((e) => e.location.filePath === testFile);
+      expect(error).toBeDefined();
+      expect(error!.kind).toBe('parse');
+      expect(error!.location.line).toBe(2); // Unclosed brace opens on line 2
     });
   });
 });
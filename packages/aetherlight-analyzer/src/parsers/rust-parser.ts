@@ -38,7 +38,14 @@ import {
 
 interface RustParseOutput {
   files: RustParsedFile[];
-  errors: string[];
+  errors: RustFileError[];
+}
+
+interface RustFileError {
+  path: string;
+  kind: 'io' | 'parse';
+  line?: number; // Parse errors only (syn span)
+  message: string;
 }
 
 interface RustParsedFile {
@@ -61,6 +68,8 @@ interface RustItem {
   return_type?: string;
   impl_trait?: string;
   impl_target?: string;
+  complexity?: number; // fn items, rust-parser --metrics only
+  field_count?: number; // struct items, rust-parser --metrics only
 }
 
 interface RustField {
@@ -77,6 +86,7 @@ interface RustMethod {
   return_type?: string;
   is_async: boolean;
   location: { line: number; column: number };
+  complexity?: number; // rust-parser --metrics only
 }
 
 interface RustParam {
@@ -113,7 +123,7 @@ export class RustParser {
    * DESIGN DECISION: Call Rust CLI tool via subprocess, parse JSON output
    * WHY: Only accurate way to parse Rust is using Rust's syn crate
    *
-   * @param directoryPath - Root directory to scan
+   * @param directoryPath - Root directory to scan (or a single .rs file to re-parse)
    * @returns ParseResult with all parsed files and dependencies
    */
   public async parse(directoryPath: string): Promise<ParseResult> {
@@ -146,10 +156,11 @@ export class RustParser {
         files: parsedFiles,
         totalFiles: parsedFiles.length,
         totalLinesOfCode: totalLoc,
-        parseErrors: output.errors.map((msg) => ({
-          message: msg,
-          location: { filePath: directoryPath, line: 1, column: 1 },
+        parseErrors: output.errors.map((err) => ({
+          message: err.message,
+          location: { filePath: err.path, line: err.line ?? 1, column: 1 },
           severity: 'warning' as const,
+          kind: err.kind,
         })),
         parseDurationMs: Date.now() - startTime,
       };
@@ -245,6 +256,7 @@ export class RustParser {
           documentation: item.documentation,
           metadata: {
             derives: item.attrs?.filter((a) => a.startsWith('derive')),
+            fieldCount: item.field_count,
           },
           fields: (item.fields || []).map((f) => this.transformField(f, file.path)),
           isPublic: item.visibility === 'pub',
@@ -304,6 +316,7 @@ export class RustParser {
           returnType: item.return_type,
           isAsync: item.attrs?.includes('async') || false,
           isExported: item.visibility === 'pub',
+          complexity: item.complexity,
         };
         elements.push(funcElement);
       });
@@ -360,7 +373,7 @@ export class RustParser {
       visibility: method.visibility === 'pub' ? 'public' : 'private',
       isStatic: false, // TODO: Detect from params (no &self)
       isAbstract: false,
-      complexity: 1, // TODO: Calculate complexity in Rust parser
+      complexity: method.complexity ?? 1, // Estimated only with rust-parser --metrics
     };
   }
 
@@ -120,6 +120,8 @@ export interface ParseError {
   message: string;
   location: SourceLocation;
   severity: 'error' | 'warning';
+  /** Unreadable file vs. syntax error (set by parsers that can tell them apart) */
+  kind?: 'io' | 'parse';
 }
 
 export interface ParseResult {
@@ -26,6 +26,8 @@ export interface PatternMetadata {
   framework?: string | null;
   /** Domain context (e.g., "error-handling", "async", "testing") */
   domain?: string | null;
+  /** Minutes a match against this pattern saves (analytics default when unset) */
+  estimatedTimeSavedMinutes?: number | null;
 }
 
 /**
@@ -97,6 +99,14 @@ export class ConfidenceScore {
    * ```
    */
   meetsThreshold(threshold: number): boolean;
+
+  /**
+   * Serialize compact score for logging/telemetry
+   *
+   * @returns JSON string with total_score and the top-3 dimensions
+   *          ordered by weighted contribution (descending)
+   */
+  toCompactJSON(): string;
 }
 
 /**
@@ -136,8 +146,15 @@ export class Pattern {
   /** Tags for keyword matching */
   get tags(): string[];
 
+  /**
+   * Pattern metadata as JSON string (kept for compatibility)
+   *
+   * Prefer `metadataObject`; this string parses to the same fields.
+   */
+  get metadata(): string;
+
   /** Pattern metadata (context for multi-dimensional matching) */
-  get metadata(): PatternMetadata;
+  get metadataObject(): PatternMetadata;
 
   /** Creation timestamp (ISO 8601 string) */
   get createdAt(): string;
@@ -173,6 +190,55 @@ export class Pattern {
   static fromJSON(json: string): Pattern;
 }
 
+/**
+ * Chainable builder for patterns with metadata
+ *
+ * The `Pattern` constructor only takes title, content and tags; use the builder
+ * to set language, framework and domain. Unset fields are `null` in `metadata`.
+ *
+ * @example
+ * ```typescript
+ * const pattern = new PatternBuilder()
+ *   .title("Rust error handling")
+ *   .content("Use Result<T, E> for fallible operations")
+ *   .addTag("rust")
+ *   .language("rust")
+ *   .build();
+ * ```
+ */
+export class PatternBuilder {
+  /** Create an empty builder */
+  constructor();
+
+  /** Set pattern title (required) */
+  title(title: string): this;
+
+  /** Set pattern content (required) */
+  content(content: string): this;
+
+  /** Add a single tag */
+  addTag(tag: string): this;
+
+  /** Set programming language metadata */
+  language(language: string): this;
+
+  /** Set framework metadata */
+  framework(framework: string): this;
+
+  /** Set domain metadata */
+  domain(domain: string): this;
+
+  /** Set minutes saved per match (analytics credit) */
+  estimatedTimeSaved(minutes: number): this;
+
+  /**
+   * Build a validated pattern (the builder stays reusable)
+   *
+   * @throws Error if title or content is missing or empty, or a tag is invalid
+   */
+  build(): Pattern;
+}
+
 /**
  * Match result with pattern and confidence score
  */
@@ -268,6 +334,35 @@ export class PatternMatcher {
    */
   isEmpty(): boolean;
 
+  /**
+   * Persist the whole pattern library to a file
+   *
+   * Patterns keep their ids, metadata and timestamps; reload with `PatternMatcher.loadFromFile`.
+   *
+   * @param path - Destination file (replaced atomically)
+   * @throws Error if the file cannot be written
+   *
+   * @example
+   * ```typescript
+   * matcher.saveToFile("patterns.bin");
+   * ```
+   */
+  saveToFile(path: string): void;
+
+  /**
+   * Load a matcher written by `saveToFile`
+   *
+   * @param path - File written by saveToFile
+   * @returns Matcher with the saved library
+   * @throws Error if the file is unreadable or was written by an incompatible version
+   *
+   * @example
+   * ```typescript
+   * const matcher = PatternMatcher.loadFromFile("patterns.bin");
+   * ```
+   */
+  static loadFromFile(path: string): PatternMatcher;
+
   /**
    * Find matching patterns for a user query
    *
@@ -282,6 +377,7 @@ export class PatternMatcher {
    *
    * @param query - User query string
    * @param maxResults - Maximum number of results to return
+   * @param minConfidence - Confidence floor for this call (default: configured min_confidence)
    * @returns Array of match results sorted by confidence
    * @throws Error if query is empty or library is empty
    *
@@ -293,7 +389,110 @@ export class PatternMatcher {
    * }
    * ```
    */
-  findMatches(query: string, maxResults: number): MatchResult[];
+  findMatches(query: string, maxResults: number, minConfidence?: number): MatchResult[];
+
+  /**
+   * Find matching patterns without blocking the event loop
+   *
+   * Same arguments and results as `findMatches`, computed on a worker thread.
+   * Use for very large libraries (100k+ patterns).
+   *
+   * @param query - User query string
+   * @param maxResults - Maximum number of results to return
+   * @param minConfidence - Confidence floor for this call (default: configured min_confidence)
+   * @returns Promise of match results sorted by confidence
+   * @throws Rejects with the same errors `findMatches` throws
+   *
+   * @example
+   * ```typescript
+   * const results = await matcher.findMatchesAsync("How do I handle errors in Rust?", 5);
+   * ```
+   */
+  findMatchesAsync(query: string, maxResults: number, minConfidence?: number): Promise<MatchResult[]>;
+
+  /**
+   * Find matches for many queries in one native call
+   *
+   * Queries are scored in parallel; results[i] belongs to queries[i].
+   * Blank queries yield an empty array instead of throwing.
+   *
+   * @param queries - User query strings
+   * @param maxResults - Maximum number of results per query
+   * @returns One result array per query, in input order
+   * @throws Error if library is empty
+   *
+   * @example
+   * ```typescript
+   * const [errors, io] = matcher.findMatchesBatch(["handle errors in rust", "async io"], 5);
+   * ```
+   */
+  findMatchesBatch(queries: string[], maxResults: number): MatchResult[][];
+
+  /**
+   * Find matches and explain near-misses when nothing qualifies
+   *
+   * Empty library is reported via `reason` instead of throwing.
+   *
+   * @param query - User query string
+   * @param maxResults - Maximum number of matches to return
+   * @param options - Threshold, required tags, near-miss limit
+   * @returns Matches plus best rejected candidates and the reason
+   * @throws Error if query is empty
+   *
+   * @example
+   * ```typescript
+   * const result = matcher.findMatchesWithDiagnostics("handle errors", 5, { minConfidence: 0.8 });
+   * if (result.matches.length === 0) {
+   *   for (const miss of result.nearMisses) {
+   *     console.log(`${miss.pattern.title}: ${miss.reason}`);
+   *   }
+   * }
+   * ```
+   */
+  findMatchesWithDiagnostics(
+    query: string,
+    maxResults: number,
+    options?: DiagnosticOptions
+  ): MatchDiagnostics;
+}
+
+/**
+ * Options for findMatchesWithDiagnostics (all optional)
+ */
+export interface DiagnosticOptions {
+  /** Minimum total confidence for a match (default 0.5) */
+  minConfidence?: number;
+  /** Tags a pattern must carry, case-insensitive */
+  requiredTags?: string[];
+  /** Maximum near-miss candidates returned (default 3) */
+  maxNearMisses?: number;
+}
+
+/** Why a candidate (or the whole query) produced no match */
+export type MissReason = "empty_library" | "below_threshold" | "filtered_by_tag";
+
+/**
+ * Candidate that was scored but did not qualify
+ */
+export interface NearMiss {
+  /** Rejected candidate pattern */
+  pattern: Pattern;
+  /** Candidate's confidence score */
+  confidence: ConfidenceScore;
+  /** Why the candidate didn't qualify */
+  reason: MissReason;
+}
+
+/**
+ * Matches plus near-miss diagnostics
+ */
+export interface MatchDiagnostics {
+  /** Qualifying matches sorted by confidence */
+  matches: MatchResult[];
+  /** Best rejected candidates sorted by confidence */
+  nearMisses: NearMiss[];
+  /** Why nothing matched (undefined when matches is non-empty) */
+  reason?: MissReason;
 }
 
 /**
@@ -113,6 +113,7 @@ try {
  * Exports:
  * - PatternMatcher class
  * - Pattern class
+ * - PatternBuilder class
  * - ConfidenceScore class
  * - MatchResult interface
  * - ConfidenceBreakdown interface
@@ -123,5 +124,6 @@ module.exports = nativeBinding;
 // ES6 named exports for modern JavaScript
 module.exports.PatternMatcher = nativeBinding.PatternMatcher;
 module.exports.Pattern = nativeBinding.Pattern;
+module.exports.PatternBuilder = nativeBinding.PatternBuilder;
 module.exports.ConfidenceScore = nativeBinding.ConfidenceScore;
 module.exports.version = nativeBinding.version;
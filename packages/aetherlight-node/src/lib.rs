@@ -17,7 +17,6 @@
  * PATTERN: Pattern-001 (Rust Core + Language Bindings)
  * RELATED: aetherlight-core (core library), VS Code extension (P1-009)
  * PERFORMANCE: <5ms FFI latency, <3MB binary size
- * FUTURE: Add async pattern matching with worker threads (P1-011)
  *
  * # FFI Architecture
  *
@@ -54,9 +53,11 @@
  */
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use aetherlight_core::pattern::PatternBuilder as CorePatternBuilder;
 use aetherlight_core::{
     PatternMatcher as CoreMatcher,
     Pattern as CorePattern,
+    PatternMetadata as CorePatternMetadata,
     MatchResult as CoreMatchResult,
     ConfidenceScore as CoreConfidenceScore,
     ConfidenceBreakdown as CoreConfidenceBreakdown,
@@ -65,10 +66,87 @@ use aetherlight_core::{
     PatternMatch as CorePatternMatch,
     SearchContext as CoreSearchContext,
     PatternIndexStatistics as CoreStatistics,
+    UsageEntry as CoreUsageEntry,
+    UsageOutcome as CoreUsageOutcome,
+    DiagnosticOptions as CoreDiagnosticOptions,
+    MatchDiagnostics as CoreMatchDiagnostics,
+    NearMiss as CoreNearMiss,
 };
+use napi::{Env, Task};
 use uuid::Uuid;
 use std::path::PathBuf;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/**
+ * Error classes exposed to JavaScript
+ *
+ * DESIGN DECISION: Every thrown error carries a napi status (err.code) and a stable
+ * code prefix in the message ("NOT_FOUND: Pattern not found: ...")
+ * WHY: napi::Status has no not-found/conflict status, and JS callers need to tell
+ * argument mistakes from internal failures without matching on prose
+ *
+ * | Class          | err.code       | Message prefix     |
+ * |----------------|----------------|--------------------|
+ * | InvalidArgument| InvalidArg     | INVALID_ARGUMENT:  |
+ * | NotFound       | GenericFailure | NOT_FOUND:         |
+ * | AlreadyExists  | InvalidArg     | ALREADY_EXISTS:    |
+ * | Internal       | GenericFailure | INTERNAL:          |
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorClass {
+    InvalidArgument,
+    NotFound,
+    AlreadyExists,
+    Internal,
+}
+
+impl ErrorClass {
+    /// Stable code JS can branch on (never reworded)
+    fn code(self) -> &'static str {
+        match self {
+            ErrorClass::InvalidArgument => "INVALID_ARGUMENT",
+            ErrorClass::NotFound => "NOT_FOUND",
+            ErrorClass::AlreadyExists => "ALREADY_EXISTS",
+            ErrorClass::Internal => "INTERNAL",
+        }
+    }
+
+    fn status(self) -> napi::Status {
+        match self {
+            ErrorClass::InvalidArgument | ErrorClass::AlreadyExists => napi::Status::InvalidArg,
+            ErrorClass::NotFound | ErrorClass::Internal => napi::Status::GenericFailure,
+        }
+    }
+
+    /// Class of a core error (exhaustive: new variants must be classified here)
+    fn of(err: &CoreError) -> Self {
+        match err {
+            CoreError::InvalidArgument(_)
+            | CoreError::InvalidPatternId(_)
+            | CoreError::PatternValidation(_)
+            | CoreError::InvalidConfidenceScore(_)
+            | CoreError::MissingConfidenceDimension(_)
+            | CoreError::InvalidConfidenceWeights(_)
+            | CoreError::InvalidQuery(_)
+            | CoreError::Parse(_)
+            | CoreError::Configuration(_)
+            | CoreError::SprintParse(_)
+            | CoreError::DuplicateTaskId(_)
+            | CoreError::Shamir(_)
+            | CoreError::ValidationError(_) => ErrorClass::InvalidArgument,
+            CoreError::PatternNotFound(_)
+            | CoreError::EmptyLibrary
+            | CoreError::AgentNotAvailable(_) => ErrorClass::NotFound,
+            CoreError::DuplicatePattern(_) | CoreError::AgentAlreadyRegistered(_) => ErrorClass::AlreadyExists,
+            CoreError::MatchingFailed(_)
+            | CoreError::LockError(_)
+            | CoreError::Io(_)
+            | CoreError::Serialization(_)
+            | CoreError::Internal(_) => ErrorClass::Internal,
+        }
+    }
+}
 
 /**
  * Convert Rust core errors to NAPI errors
@@ -81,14 +159,21 @@ use std::collections::HashMap;
  * 2. All Rust errors returned as Result (no panics in library code)
  * 3. NAPI-RS converts Result::Err to JavaScript Error automatically
  * 4. Error messages preserved from Rust (user-facing, no internal details)
- * 5. JavaScript consumers can catch errors with try/catch
+ * 5. Status and code prefix chosen from the CoreError variant (see ErrorClass)
  * 6. Helper function pattern avoids orphan rule violation (cannot impl foreign trait on foreign type)
  *
  * PATTERN: Pattern-007 (Language Bindings via NAPI)
  * RELATED: aetherlight_core::Error
  */
 fn convert_error(err: CoreError) -> napi::Error {
-    napi::Error::new(napi::Status::GenericFailure, err.to_string())
+    let class = ErrorClass::of(&err);
+    napi::Error::new(class.status(), format!("{}: {}", class.code(), err))
+}
+
+/// Parse a UUID argument (INVALID_ARGUMENT naming the argument on failure)
+fn parse_uuid(name: &str, value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value)
+        .map_err(|e| convert_error(CoreError::InvalidArgument(format!("{}: invalid UUID '{}': {}", name, value, e))))
 }
 
 /**
@@ -105,8 +190,7 @@ fn convert_error(err: CoreError) -> napi::Error {
  * 5. Auto-serializable to JSON via NAPI-RS
  *
  * PATTERN: Pattern-007 (Language Bindings via NAPI)
- * RELATED: aetherlight_core::Pattern
- * FUTURE: Add builder pattern for JavaScript (P1-010)
+ * RELATED: aetherlight_core::Pattern, PatternBuilder (metadata from JavaScript)
  */
 #[napi]
 pub struct Pattern {
@@ -183,8 +267,7 @@ impl Pattern {
      * 1. NAPI-RS cannot serialize serde_json::Value directly (no NapiRaw impl)
      * 2. String is universally compatible across FFI boundary
      * 3. JavaScript consumers can JSON.parse() the string
-     * 4. Alternative: Define separate PatternMetadata struct with #[napi(object)]
-     * 5. JSON string simpler for optional fields (language?, framework?, domain?)
+     * 4. Kept for compatibility; new code should use metadataObject (typed PatternMetadata)
      *
      * # JavaScript Example
      *
@@ -205,6 +288,23 @@ impl Pattern {
             .map_err(|e| convert_error(CoreError::from(e)))
     }
 
+    /**
+     * Get pattern metadata as a typed object
+     *
+     * DESIGN DECISION: #[napi(object)] PatternMetadata instead of a JSON string
+     * WHY: TypeScript consumers get real types and autocomplete, no JSON.parse
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const { language, framework, domain } = pattern.metadataObject;
+     * ```
+     */
+    #[napi(getter, js_name = "metadataObject")]
+    pub fn metadata_object(&self) -> PatternMetadata {
+        PatternMetadata::from(self.inner.metadata().clone())
+    }
+
     /**
      * Get creation timestamp as ISO 8601 string
      *
@@ -265,11 +365,162 @@ impl Pattern {
     #[napi(factory, js_name = "fromJSON")]
     pub fn from_json(json: String) -> Result<Self> {
         let inner: CorePattern = serde_json::from_str(&json)
-            .map_err(|e| convert_error(CoreError::from(e)))?;
+            .map_err(|e| convert_error(CoreError::InvalidArgument(format!("pattern JSON: {}", e))))?;
         Ok(Self { inner })
     }
 }
 
+/**
+ * FFI wrapper for Pattern::builder() (P1-010)
+ *
+ * DESIGN DECISION: Chainable setters returning `this`, validation in build()
+ * WHY: The Pattern constructor only takes (title, content, tags); metadata
+ * (language, framework, domain) had no way in from JavaScript
+ *
+ * REASONING CHAIN:
+ * 1. Setters mutate the wrapped core builder and return the same JS object
+ * 2. build() validates like the core builder (title/content required, tags normalized)
+ * 3. build() does not consume the builder: one builder can stamp out several patterns
+ *    (each gets its own id and timestamps)
+ * 4. Unset optional fields stay None → null in Pattern.metadata
+ *
+ * PATTERN: Pattern-007 (Language Bindings via NAPI)
+ * RELATED: aetherlight_core::pattern::PatternBuilder
+ *
+ * # JavaScript Example
+ *
+ * ```javascript
+ * const pattern = new PatternBuilder()
+ *   .title("Rust error handling")
+ *   .content("Use Result<T, E> for fallible operations")
+ *   .addTag("rust")
+ *   .language("rust")
+ *   .build();
+ * ```
+ */
+#[napi]
+pub struct PatternBuilder {
+    inner: CorePatternBuilder,
+}
+
+impl Default for PatternBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[napi]
+impl PatternBuilder {
+    /// Create an empty builder
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: CorePattern::builder(),
+        }
+    }
+
+    /// Set pattern title (required)
+    #[napi]
+    pub fn title(&mut self, this: This, title: String) -> This {
+        self.update(|builder| builder.title(title));
+        this
+    }
+
+    /// Set pattern content (required)
+    #[napi]
+    pub fn content(&mut self, this: This, content: String) -> This {
+        self.update(|builder| builder.content(content));
+        this
+    }
+
+    /// Add a single tag
+    #[napi(js_name = "addTag")]
+    pub fn add_tag(&mut self, this: This, tag: String) -> This {
+        self.update(|builder| builder.tag(tag));
+        this
+    }
+
+    /// Set programming language metadata
+    #[napi]
+    pub fn language(&mut self, this: This, language: String) -> This {
+        self.update(|builder| builder.language(language));
+        this
+    }
+
+    /// Set framework metadata
+    #[napi]
+    pub fn framework(&mut self, this: This, framework: String) -> This {
+        self.update(|builder| builder.framework(framework));
+        this
+    }
+
+    /// Set domain metadata
+    #[napi]
+    pub fn domain(&mut self, this: This, domain: String) -> This {
+        self.update(|builder| builder.domain(domain));
+        this
+    }
+
+    /// Set minutes saved per match (analytics credit)
+    #[napi(js_name = "estimatedTimeSaved")]
+    pub fn estimated_time_saved(&mut self, this: This, minutes: u32) -> This {
+        self.update(|builder| builder.estimated_time_saved(minutes));
+        this
+    }
+
+    /**
+     * Build a validated Pattern
+     *
+     * Throws INVALID_ARGUMENT-class errors when title or content is missing or
+     * empty, or a tag is invalid (same rules as the core builder).
+     */
+    #[napi]
+    pub fn build(&self) -> Result<Pattern> {
+        let inner = self.inner.clone().build()
+            .map_err(convert_error)?;
+        Ok(Pattern { inner })
+    }
+
+    fn update(&mut self, set: impl FnOnce(CorePatternBuilder) -> CorePatternBuilder) {
+        self.inner = set(std::mem::take(&mut self.inner));
+    }
+}
+
+/**
+ * FFI wrapper for PatternMetadata
+ *
+ * DESIGN DECISION: Plain JavaScript object with optional fields
+ * WHY: Metadata is data-only; unset fields arrive as undefined (null in JSON string form)
+ *
+ * PATTERN: Pattern-007 (Language Bindings via NAPI)
+ * RELATED: aetherlight_core::PatternMetadata, Pattern.metadataObject
+ */
+#[napi(object)]
+pub struct PatternMetadata {
+    /// Programming language (e.g., "rust", "typescript", "python")
+    pub language: Option<String>,
+
+    /// Framework/library (e.g., "tokio", "react", "flutter")
+    pub framework: Option<String>,
+
+    /// Domain context (e.g., "error-handling", "async", "testing")
+    pub domain: Option<String>,
+
+    /// Minutes a match against this pattern saves (analytics default when unset)
+    pub estimated_time_saved_minutes: Option<u32>,
+}
+
+impl From<CorePatternMetadata> for PatternMetadata {
+    fn from(core: CorePatternMetadata) -> Self {
+        Self {
+            language: core.language,
+            framework: core.framework,
+            domain: core.domain,
+            estimated_time_saved_minutes: core.estimated_time_saved_minutes,
+        }
+    }
+}
+
 /**
  * FFI wrapper for ConfidenceBreakdown
  *
@@ -360,6 +611,25 @@ impl ConfidenceScore {
     pub fn meets_threshold(&self, threshold: f64) -> bool {
         self.inner.meets_threshold(threshold)
     }
+
+    /**
+     * Serialize compact score (total + top-3 dimensions) to JSON
+     *
+     * DESIGN DECISION: JSON string for telemetry payloads
+     * WHY: Analytics logs the string as-is; full breakdown stays on `breakdown`
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * telemetry.log(score.toCompactJSON());
+     * // {"total_score":0.82,"top_dimensions":[{"name":"semantic_similarity",...}]}
+     * ```
+     */
+    #[napi(js_name = "toCompactJSON")]
+    pub fn to_compact_json(&self) -> Result<String> {
+        serde_json::to_string(&self.inner.to_compact())
+            .map_err(|e| convert_error(CoreError::from(e)))
+    }
 }
 
 /**
@@ -438,6 +708,8 @@ impl From<CoreMatchResult> for MatchResult {
  * 3. NAPI-RS handles thread safety (matcher instance owned by JavaScript)
  * 4. Errors converted to JavaScript exceptions automatically
  * 5. Results cloned for FFI boundary (acceptable cost vs latency)
+ * 6. Core matcher behind Arc: findMatchesAsync hands a reference to a worker
+ *    thread; add/remove copy on write only while such a search is in flight
  *
  * PATTERN: Pattern-007 (Language Bindings via NAPI)
  * PATTERN: Pattern-005 (Multi-Dimensional Matching)
@@ -446,7 +718,7 @@ impl From<CoreMatchResult> for MatchResult {
  */
 #[napi]
 pub struct PatternMatcher {
-    inner: CoreMatcher,
+    inner: Arc<CoreMatcher>,
 }
 
 impl Default for PatternMatcher {
@@ -469,7 +741,7 @@ impl PatternMatcher {
     #[napi(constructor)]
     pub fn new() -> Self {
         Self {
-            inner: CoreMatcher::new(),
+            inner: Arc::new(CoreMatcher::new()),
         }
     }
 
@@ -496,7 +768,7 @@ impl PatternMatcher {
      */
     #[napi(js_name = "addPattern")]
     pub fn add_pattern(&mut self, pattern: &Pattern) -> Result<()> {
-        self.inner.add_pattern(pattern.inner.clone())
+        Arc::make_mut(&mut self.inner).add_pattern(pattern.inner.clone())
             .map_err(convert_error)
     }
 
@@ -514,9 +786,8 @@ impl PatternMatcher {
      */
     #[napi(js_name = "removePattern")]
     pub fn remove_pattern(&mut self, id: String) -> Result<()> {
-        let uuid = Uuid::parse_str(&id)
-            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, format!("Invalid UUID: {}", e)))?;
-        self.inner.remove_pattern(&uuid)
+        let uuid = parse_uuid("id", &id)?;
+        Arc::make_mut(&mut self.inner).remove_pattern(&uuid)
             .map_err(convert_error)
     }
 
@@ -535,8 +806,7 @@ impl PatternMatcher {
      */
     #[napi(js_name = "getPattern")]
     pub fn get_pattern(&self, id: String) -> Result<Pattern> {
-        let uuid = Uuid::parse_str(&id)
-            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, format!("Invalid UUID: {}", e)))?;
+        let uuid = parse_uuid("id", &id)?;
         let core_pattern = self.inner.get_pattern(&uuid)
             .map_err(convert_error)?;
         Ok(Pattern { inner: core_pattern.clone() })
@@ -572,6 +842,43 @@ impl PatternMatcher {
         self.inner.is_empty()
     }
 
+    /**
+     * Persist the whole library (ids, content, tags, metadata, timestamps) to a file
+     *
+     * DESIGN DECISION: Versioned matcher snapshot, reloaded with loadFromFile
+     * WHY: Replaying addPattern for thousands of patterns on every process start
+     * re-tokenizes the whole library; the snapshot carries precomputed tokens
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * matcher.saveToFile("patterns.bin");
+     * ```
+     */
+    #[napi(js_name = "saveToFile")]
+    pub fn save_to_file(&self, path: String) -> Result<()> {
+        self.inner.save_to_file(&path)
+            .map_err(convert_error)
+    }
+
+    /**
+     * Load a matcher written by saveToFile
+     *
+     * Throws if the file was written by an incompatible version.
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const matcher = PatternMatcher.loadFromFile("patterns.bin");
+     * ```
+     */
+    #[napi(factory, js_name = "loadFromFile")]
+    pub fn load_from_file(path: String) -> Result<Self> {
+        let inner = CoreMatcher::load_from_file(&path)
+            .map_err(convert_error)?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
     /**
      * Find matching patterns for a user query
      *
@@ -582,12 +889,11 @@ impl PatternMatcher {
      * 1. <50ms target makes async overhead unnecessary (no blocking)
      * 2. Synchronous API simpler for consumers (no await/Promises)
      * 3. Node.js event loop not blocked (<50ms acceptable)
-     * 4. Future: Add async version for >100k pattern libraries (P1-011)
+     * 4. >100k pattern libraries: use findMatchesAsync (worker thread)
      * 5. Results cloned for FFI boundary (acceptable cost)
      *
      * PATTERN: Pattern-005 (Multi-Dimensional Matching)
      * PERFORMANCE: <50ms for 10k patterns, <5ms FFI overhead
-     * FUTURE: Add async findMatchesAsync for large libraries (P1-011)
      *
      * # JavaScript Example
      *
@@ -596,15 +902,276 @@ impl PatternMatcher {
      * for (const result of results) {
      *   console.log(`${result.pattern.title}: ${result.confidence.totalScore * 100}%`);
      * }
+     *
+     * // Override the configured confidence floor for this call
+     * const strict = matcher.findMatches("How do I handle errors in Rust?", 5, 0.8);
      * ```
      */
     #[napi(js_name = "findMatches")]
-    pub fn find_matches(&self, query: String, max_results: i64) -> Result<Vec<MatchResult>> {
-        let results = self.inner.find_matches(&query, max_results as usize)
-            .map_err(convert_error)?;
+    pub fn find_matches(&self, query: String, max_results: i64, min_confidence: Option<f64>) -> Result<Vec<MatchResult>> {
+        let results = match min_confidence {
+            Some(min_confidence) => self.inner.find_matches_above(&query, max_results as usize, min_confidence),
+            None => self.inner.find_matches(&query, max_results as usize),
+        }
+        .map_err(convert_error)?;
 
         Ok(results.into_iter().map(|r| r.into()).collect())
     }
+
+    /**
+     * Find matching patterns on a worker thread (Promise)
+     *
+     * DESIGN DECISION: AsyncTask on the libuv thread pool, same arguments as findMatches
+     * WHY: A 100k-pattern search takes long enough to stall Node's event loop;
+     * P1-011 (async matching for large libraries)
+     *
+     * REASONING CHAIN:
+     * 1. Matcher shared with the task by Arc clone (no library copy)
+     * 2. compute() runs find_matches off the JS thread
+     * 3. Errors go through convert_error, so the promise rejects with the exact
+     *    message findMatches would throw
+     * 4. Patterns added while the search runs are not seen by it (copy on write)
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const results = await matcher.findMatchesAsync("How do I handle errors in Rust?", 5);
+     * ```
+     */
+    #[napi(js_name = "findMatchesAsync")]
+    pub fn find_matches_async(
+        &self,
+        query: String,
+        max_results: i64,
+        min_confidence: Option<f64>,
+    ) -> AsyncTask<FindMatchesTask> {
+        AsyncTask::new(FindMatchesTask {
+            matcher: Arc::clone(&self.inner),
+            query,
+            max_results: max_results as usize,
+            min_confidence,
+        })
+    }
+
+    /**
+     * Find matches for many queries in one FFI call
+     *
+     * DESIGN DECISION: One call returning one result array per query
+     * WHY: N findMatches calls cost N FFI round-trips and match on one core;
+     * the core batch API shares pattern-side work and scores queries in parallel
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const [errors, io] = matcher.findMatchesBatch(["handle errors in rust", "async io"], 5);
+     * ```
+     */
+    #[napi(js_name = "findMatchesBatch")]
+    pub fn find_matches_batch(&self, queries: Vec<String>, max_results: i64) -> Result<Vec<Vec<MatchResult>>> {
+        let queries: Vec<&str> = queries.iter().map(String::as_str).collect();
+        let results = self.inner
+            .find_matches_batch(&queries, max_results as usize)
+            .map_err(convert_error)?;
+
+        Ok(results
+            .into_iter()
+            .map(|matches| matches.into_iter().map(|r| r.into()).collect())
+            .collect())
+    }
+
+    /**
+     * Find matches with near-miss diagnostics
+     *
+     * DESIGN DECISION: Separate method, findMatches unchanged
+     * WHY: Extension shows "closest patterns and why they didn't qualify"
+     * instead of an empty list; empty library reported as a reason, not thrown
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const result = matcher.findMatchesWithDiagnostics("handle errors", 5, { minConfidence: 0.8 });
+     * if (result.matches.length === 0) {
+     *   console.log(`No matches (${result.reason})`);
+     *   for (const miss of result.nearMisses) {
+     *     console.log(`${miss.pattern.title}: ${miss.confidence.totalScore} (${miss.reason})`);
+     *   }
+     * }
+     * ```
+     */
+    #[napi(js_name = "findMatchesWithDiagnostics")]
+    pub fn find_matches_with_diagnostics(
+        &self,
+        query: String,
+        max_results: i64,
+        options: Option<DiagnosticOptions>,
+    ) -> Result<MatchDiagnostics> {
+        let options = options.map(CoreDiagnosticOptions::from).unwrap_or_default();
+        let diagnostics = self.inner
+            .find_matches_with_diagnostics(&query, max_results as usize, &options)
+            .map_err(convert_error)?;
+
+        Ok(diagnostics.into())
+    }
+}
+
+/// Background search behind PatternMatcher.findMatchesAsync
+pub struct FindMatchesTask {
+    matcher: Arc<CoreMatcher>,
+    query: String,
+    max_results: usize,
+    min_confidence: Option<f64>,
+}
+
+impl Task for FindMatchesTask {
+    type Output = Vec<CoreMatchResult>;
+    type JsValue = Vec<MatchResult>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        match self.min_confidence {
+            Some(min_confidence) => self.matcher.find_matches_above(&self.query, self.max_results, min_confidence),
+            None => self.matcher.find_matches(&self.query, self.max_results),
+        }
+        .map_err(convert_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+/**
+ * FFI wrapper for DiagnosticOptions
+ *
+ * DESIGN DECISION: Plain JavaScript object, every field optional
+ * WHY: Callers override only what they filter on; core defaults fill the rest
+ */
+#[napi(object)]
+pub struct DiagnosticOptions {
+    /// Minimum total confidence for a match (default 0.5)
+    pub min_confidence: Option<f64>,
+
+    /// Tags a pattern must carry (case-insensitive)
+    pub required_tags: Option<Vec<String>>,
+
+    /// Maximum near-miss candidates returned (default 3)
+    pub max_near_misses: Option<i64>,
+}
+
+impl From<DiagnosticOptions> for CoreDiagnosticOptions {
+    fn from(js: DiagnosticOptions) -> Self {
+        let defaults = CoreDiagnosticOptions::default();
+        Self {
+            min_confidence: js.min_confidence.unwrap_or(defaults.min_confidence),
+            required_tags: js.required_tags.unwrap_or(defaults.required_tags),
+            max_near_misses: js.max_near_misses
+                .map(|n| n.max(0) as usize)
+                .unwrap_or(defaults.max_near_misses),
+        }
+    }
+}
+
+/**
+ * FFI wrapper for NearMiss
+ *
+ * DESIGN DECISION: JavaScript class with getters (like MatchResult)
+ * WHY: Contains Pattern and ConfidenceScore classes
+ */
+#[napi]
+pub struct NearMiss {
+    pattern: Pattern,
+    confidence: ConfidenceScore,
+    reason: String,
+}
+
+#[napi]
+impl NearMiss {
+    /**
+     * Get the rejected candidate pattern
+     */
+    #[napi(getter)]
+    pub fn pattern(&self) -> Pattern {
+        Pattern {
+            inner: self.pattern.inner.clone(),
+        }
+    }
+
+    /**
+     * Get the candidate's confidence score
+     */
+    #[napi(getter)]
+    pub fn confidence(&self) -> ConfidenceScore {
+        ConfidenceScore {
+            inner: self.confidence.inner.clone(),
+        }
+    }
+
+    /**
+     * Get why the candidate didn't qualify ("below_threshold" | "filtered_by_tag")
+     */
+    #[napi(getter)]
+    pub fn reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+impl From<CoreNearMiss> for NearMiss {
+    fn from(core: CoreNearMiss) -> Self {
+        Self {
+            pattern: Pattern { inner: core.pattern },
+            confidence: ConfidenceScore { inner: core.confidence },
+            reason: core.reason.as_str().to_string(),
+        }
+    }
+}
+
+/**
+ * FFI wrapper for MatchDiagnostics
+ *
+ * DESIGN DECISION: JavaScript class with getters (like MatchResult)
+ * WHY: Holds MatchResult/NearMiss classes, cannot use #[napi(object)]
+ */
+#[napi]
+pub struct MatchDiagnostics {
+    matches: Vec<CoreMatchResult>,
+    near_misses: Vec<CoreNearMiss>,
+    reason: Option<String>,
+}
+
+#[napi]
+impl MatchDiagnostics {
+    /**
+     * Get qualifying matches (sorted by confidence)
+     */
+    #[napi(getter)]
+    pub fn matches(&self) -> Vec<MatchResult> {
+        self.matches.iter().cloned().map(MatchResult::from).collect()
+    }
+
+    /**
+     * Get best rejected candidates (sorted by confidence)
+     */
+    #[napi(getter, js_name = "nearMisses")]
+    pub fn near_misses(&self) -> Vec<NearMiss> {
+        self.near_misses.iter().cloned().map(NearMiss::from).collect()
+    }
+
+    /**
+     * Get why nothing matched ("empty_library" | "below_threshold" | "filtered_by_tag")
+     */
+    #[napi(getter)]
+    pub fn reason(&self) -> Option<String> {
+        self.reason.clone()
+    }
+}
+
+impl From<CoreMatchDiagnostics> for MatchDiagnostics {
+    fn from(core: CoreMatchDiagnostics) -> Self {
+        Self {
+            matches: core.matches,
+            near_misses: core.near_misses,
+            reason: core.reason.map(|r| r.as_str().to_string()),
+        }
+    }
 }
 
 /**
@@ -696,8 +1263,9 @@ impl From<CorePatternMatch> for PatternMatch {
 /**
  * FFI wrapper for SearchContext
  *
- * DESIGN DECISION: Plain JavaScript object for optional context
- * WHY: Context is data-only, enables context-aware ranking
+ * DESIGN DECISION: Plain JavaScript object for optional context, every field optional
+ * WHY: Context is data-only, enables context-aware ranking; callers that only know
+ * the domain pass `{ domain }` and the rest default to empty (no boost)
  *
  * PATTERN: Pattern-INDEX-001 (Semantic Pattern Search)
  * RELATED: PatternIndex.searchByIntent()
@@ -708,10 +1276,14 @@ impl From<CorePatternMatch> for PatternMatch {
  * const context = {
  *   domain: "authentication",
  *   framework: "actix-web",
+ *   selectedDomains: ["rust", "devops"],
  *   recentPatterns: ["Pattern-OAUTH2-001"],
  *   userPreferences: { "Pattern-OAUTH2-001": 0.95 }
  * };
  * const matches = await index.searchByIntent("secure login", context);
+ *
+ * // Minimal context: omitted fields default to empty
+ * const byDomain = await index.searchByIntent("secure login", { domain: "authentication" });
  * ```
  */
 #[napi(object)]
@@ -722,20 +1294,24 @@ pub struct SearchContext {
     /// Current framework (e.g., "actix-web", "react", "flutter")
     pub framework: Option<String>,
 
-    /// Recent patterns used (boost related patterns)
-    pub recent_patterns: Vec<String>,
+    /// Domains the user selected (soft boost, optional)
+    pub selected_domains: Option<Vec<String>>,
+
+    /// Recent patterns used (boost related patterns, optional)
+    pub recent_patterns: Option<Vec<String>>,
 
-    /// User preference (boost patterns user likes)
-    pub user_preferences: HashMap<String, f64>,
+    /// User preference (boost patterns user likes, optional)
+    pub user_preferences: Option<HashMap<String, f64>>,
 }
 
 impl From<SearchContext> for CoreSearchContext {
     fn from(js: SearchContext) -> Self {
         Self {
             domain: js.domain,
+            selected_domains: js.selected_domains.unwrap_or_default(),
             framework: js.framework,
-            recent_patterns: js.recent_patterns,
-            user_preferences: js.user_preferences,
+            recent_patterns: js.recent_patterns.unwrap_or_default(),
+            user_preferences: js.user_preferences.unwrap_or_default(),
         }
     }
 }
@@ -765,6 +1341,37 @@ impl From<CoreStatistics> for PatternIndexStatistics {
     }
 }
 
+/**
+ * FFI wrapper for one recordUsageBatch entry
+ *
+ * DESIGN DECISION: outcome as string ("accepted" | "rejected")
+ * WHY: Plain object fields must be primitives; an unknown value is reported
+ * per entry instead of rejecting the whole batch
+ */
+#[napi(object)]
+pub struct UsageEntry {
+    pub pattern_id: String,
+    pub confidence: f64,
+    /// "accepted" (default) or "rejected"
+    pub outcome: Option<String>,
+}
+
+/// Batch entry that could not be recorded
+#[napi(object)]
+pub struct UsageBatchError {
+    /// Position of the entry in the submitted batch
+    pub index: u32,
+    pub pattern_id: String,
+    pub reason: String,
+}
+
+/// Result of recordUsageBatch
+#[napi(object)]
+pub struct UsageBatchReport {
+    pub recorded: u32,
+    pub errors: Vec<UsageBatchError>,
+}
+
 /**
  * FFI wrapper for PatternIndex
  *
@@ -830,6 +1437,54 @@ impl PatternIndex {
         Ok(Self { inner: core })
     }
 
+    /**
+     * Whether a complete index exists on disk (a rebuild has finished)
+     *
+     * DESIGN DECISION: Separate from an empty search result
+     * WHY: Lets the extension prompt "build index?" on first use
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * if (!index.isInitialized() || index.needsRebuild()) {
+     *   await index.rebuild();
+     * }
+     * ```
+     */
+    #[napi(js_name = "isInitialized")]
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+
+    /**
+     * Whether the index was never built or pattern files changed since the last rebuild
+     */
+    #[napi(js_name = "needsRebuild")]
+    pub fn needs_rebuild(&self) -> Result<bool> {
+        self.inner.needs_rebuild().map_err(convert_error)
+    }
+
+    /**
+     * Preload embeddings model and vector store
+     *
+     * DESIGN DECISION: Explicit warm-up instead of loading in constructor
+     * WHY: Constructor stays cheap; callers pay model load at startup/idle time
+     * so subsequent searchByIntent calls hit warm paths
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const index = new PatternIndex('./docs/patterns', './data');
+     * await index.warmUp(); // First search no longer pays model load
+     * ```
+     */
+    #[napi(js_name = "warmUp")]
+    pub async fn warm_up(&self) -> Result<()> {
+        self.inner.warm_up()
+            .await
+            .map_err(convert_error)
+    }
+
     /**
      * Search patterns by intent (semantic search)
      *
@@ -852,12 +1507,10 @@ impl PatternIndex {
      * // Without context
      * const matches = await index.searchByIntent("OAuth2 with PKCE");
      *
-     * // With context (boosts relevance)
+     * // With context (boosts relevance; all fields optional)
      * const context = {
      *   domain: "authentication",
-     *   framework: "actix-web",
-     *   recentPatterns: [],
-     *   userPreferences: {}
+     *   framework: "actix-web"
      * };
      * const matches = await index.searchByIntent("OAuth2 with PKCE", context);
      *
@@ -908,6 +1561,7 @@ impl PatternIndex {
     pub async fn add_pattern(&mut self, pattern: &Pattern) -> Result<()> {
         self.inner.add_pattern(pattern.inner.clone())
             .await
+            .map(|_| ())
             .map_err(convert_error)
     }
 
@@ -952,6 +1606,66 @@ impl PatternIndex {
             .map_err(convert_error)
     }
 
+    /**
+     * Record usage of several patterns in one call
+     *
+     * DESIGN DECISION: One FFI round-trip and one index write for the whole batch
+     * WHY: Applying several suggestions at once paid a round-trip per pattern
+     *
+     * Malformed entries (bad confidence, unknown pattern, unknown outcome) are
+     * reported in `errors` by position; the other entries are still recorded.
+     *
+     * # JavaScript Example
+     *
+     * ```javascript
+     * const report = await index.recordUsageBatch([
+     *   { patternId: a.id, confidence: 0.87 },
+     *   { patternId: b.id, confidence: 0.4, outcome: "rejected" },
+     * ]);
+     * console.log(report.recorded, report.errors);
+     * ```
+     */
+    #[napi(js_name = "recordUsageBatch")]
+    pub async fn record_usage_batch(&self, entries: Vec<UsageEntry>) -> UsageBatchReport {
+        let mut errors = Vec::new();
+        let mut positions = Vec::with_capacity(entries.len());
+        let mut batch = Vec::with_capacity(entries.len());
+
+        for (index, entry) in entries.into_iter().enumerate() {
+            let outcome = match entry.outcome.as_deref() {
+                None | Some("accepted") => CoreUsageOutcome::Accepted,
+                Some("rejected") => CoreUsageOutcome::Rejected,
+                Some(other) => {
+                    errors.push(UsageBatchError {
+                        index: index as u32,
+                        pattern_id: entry.pattern_id,
+                        reason: format!("unknown outcome '{}'", other),
+                    });
+                    continue;
+                }
+            };
+            positions.push(index);
+            batch.push(CoreUsageEntry {
+                pattern_id: entry.pattern_id,
+                confidence: entry.confidence,
+                outcome: Some(outcome),
+            });
+        }
+
+        let report = self.inner.record_usage_batch(&batch).await;
+        errors.extend(report.errors.into_iter().map(|e| UsageBatchError {
+            index: positions[e.index] as u32,
+            pattern_id: e.pattern_id,
+            reason: e.reason,
+        }));
+        errors.sort_by_key(|e| e.index);
+
+        UsageBatchReport {
+            recorded: report.recorded as u32,
+            errors,
+        }
+    }
+
     /**
      * Get index statistics
      *
@@ -1024,6 +1738,50 @@ mod tests {
         assert_eq!(matcher.count(), 0);
     }
 
+    /**
+     * Test: Each error class maps to its napi status and stable code
+     */
+    #[test]
+    fn test_error_classes() {
+        let cases = [
+            (CoreError::InvalidArgument("x".to_string()), napi::Status::InvalidArg, "INVALID_ARGUMENT: "),
+            (CoreError::InvalidQuery("empty".to_string()), napi::Status::InvalidArg, "INVALID_ARGUMENT: "),
+            (CoreError::InvalidConfidenceScore(1.5), napi::Status::InvalidArg, "INVALID_ARGUMENT: "),
+            (CoreError::PatternNotFound("id".to_string()), napi::Status::GenericFailure, "NOT_FOUND: "),
+            (CoreError::EmptyLibrary, napi::Status::GenericFailure, "NOT_FOUND: "),
+            (CoreError::DuplicatePattern("id".to_string()), napi::Status::InvalidArg, "ALREADY_EXISTS: "),
+            (CoreError::Io("disk".to_string()), napi::Status::GenericFailure, "INTERNAL: "),
+            (CoreError::Internal("bug".to_string()), napi::Status::GenericFailure, "INTERNAL: "),
+        ];
+
+        for (core, status, prefix) in cases {
+            let message = core.to_string();
+            let err = convert_error(core);
+            assert_eq!(err.status, status, "{}", message);
+            assert_eq!(err.reason, format!("{}{}", prefix, message));
+        }
+    }
+
+    /**
+     * Test: Malformed UUID and JSON arguments are INVALID_ARGUMENT; unknown ids are NOT_FOUND
+     */
+    #[test]
+    fn test_argument_errors() {
+        let mut matcher = PatternMatcher::new();
+
+        let err = matcher.remove_pattern("not-a-uuid".to_string()).unwrap_err();
+        assert_eq!(err.status, napi::Status::InvalidArg);
+        assert!(err.reason.starts_with("INVALID_ARGUMENT: "), "{}", err.reason);
+
+        let err = Pattern::from_json("{".to_string()).err().unwrap();
+        assert_eq!(err.status, napi::Status::InvalidArg);
+        assert!(err.reason.starts_with("INVALID_ARGUMENT: "), "{}", err.reason);
+
+        let err = matcher.get_pattern(Uuid::new_v4().to_string()).err().unwrap();
+        assert_eq!(err.status, napi::Status::GenericFailure);
+        assert!(err.reason.starts_with("NOT_FOUND: "), "{}", err.reason);
+    }
+
     #[test]
     fn test_ffi_version() {
         let ver = version();
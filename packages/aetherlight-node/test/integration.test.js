@@ -33,12 +33,13 @@ const assert = require('node:assert');
  * DESIGN DECISION: Graceful degradation for pre-build phase
  * WHY: Enable test file validation without Rust toolchain installed
  */
-let PatternMatcher, Pattern, ConfidenceScore, version;
+let PatternMatcher, Pattern, PatternBuilder, ConfidenceScore, version;
 
 try {
   const bindings = require('../index.js');
   PatternMatcher = bindings.PatternMatcher;
   Pattern = bindings.Pattern;
+  PatternBuilder = bindings.PatternBuilder;
   ConfidenceScore = bindings.ConfidenceScore;
   version = bindings.version;
 } catch (err) {
@@ -48,6 +49,7 @@ try {
   // Mock implementations for syntax checking
   PatternMatcher = class { constructor() {} };
   Pattern = class { constructor() {} };
+  PatternBuilder = class { constructor() {} };
   ConfidenceScore = class {};
   version = () => '0.0.0-mock';
 }
@@ -168,6 +170,72 @@ describe('Pattern class', () => {
     assert.strictEqual(deserialized.content, original.content);
     assert.deepStrictEqual(deserialized.tags, original.tags);
   });
+
+  /**
+   * Test: metadataObject matches the parsed metadata string
+   *
+   * DESIGN DECISION: Typed getter and JSON string getter stay in sync
+   * WHY: String getter kept for compatibility; both must describe the same metadata
+   */
+  test('metadataObject matches parsed metadata string', () => {
+    if (!nativeAddonAvailable()) return;
+
+    const stored = JSON.parse(new Pattern('Title', 'Content', []).toJSON());
+    stored.metadata = { language: 'rust', framework: 'tokio', domain: null };
+    const pattern = Pattern.fromJSON(JSON.stringify(stored));
+
+    const parsed = JSON.parse(pattern.metadata);
+    const object = pattern.metadataObject;
+    assert.strictEqual(object.language, parsed.language);
+    assert.strictEqual(object.framework, parsed.framework);
+    assert.strictEqual(object.language, 'rust');
+    assert.strictEqual(object.framework, 'tokio');
+    // Unset fields: null in the string form, absent (undefined) on the object
+    assert.strictEqual(parsed.domain, null);
+    assert.strictEqual(object.domain ?? null, null);
+  });
+});
+
+describe('PatternBuilder class', () => {
+  /**
+   * Test: Builder sets metadata the constructor cannot
+   *
+   * DESIGN DECISION: Chained setters return the builder itself
+   * WHY: Metadata was readable from JavaScript but not writable
+   */
+  test('builds pattern with metadata', () => {
+    if (!nativeAddonAvailable()) return;
+
+    const builder = new PatternBuilder();
+    const pattern = builder
+      .title('Retry with backoff')
+      .content('Retry failed requests with exponential backoff')
+      .addTag('retry')
+      .addTag('network')
+      .language('rust')
+      .framework('tokio')
+      .build();
+
+    assert.strictEqual(pattern.title, 'Retry with backoff');
+    assert.deepStrictEqual(pattern.tags, ['retry', 'network']);
+    assert.deepStrictEqual(JSON.parse(pattern.metadata), {
+      language: 'rust',
+      framework: 'tokio',
+      domain: null, // unset → null
+    });
+
+    // Builder is reusable; each build is a new pattern
+    assert.notStrictEqual(builder.build().id, pattern.id);
+  });
+
+  /**
+   * Test: build() validates like the core builder
+   */
+  test('build without content throws', () => {
+    if (!nativeAddonAvailable()) return;
+
+    assert.throws(() => new PatternBuilder().title('Title only').build(), /content is required/);
+  });
 });
 
 describe('PatternMatcher class', () => {
@@ -386,6 +454,75 @@ describe('Performance (smoke test)', () => {
   });
 });
 
+/**
+ * PatternIndex search context
+ *
+ * DESIGN DECISION: Minimal context objects ({ domain }) are valid
+ * WHY: recentPatterns/userPreferences used to be required, so callers that only
+ * knew the domain had to pad the object with empty values
+ *
+ * NOTE: Needs the embeddings model; skipped when the index can't embed patterns
+ */
+describe('PatternIndex search context', () => {
+  test('minimal { domain } context ranks like the fully specified context', async (t) => {
+    if (!nativeAddonAvailable()) return;
+
+    const fs = require('node:fs');
+    const os = require('node:os');
+    const path = require('node:path');
+    const { PatternIndex } = require('../index.js');
+
+    const patternDir = fs.mkdtempSync(path.join(os.tmpdir(), 'aetherlight-patterns-'));
+    const dataDir = fs.mkdtempSync(path.join(os.tmpdir(), 'aetherlight-data-'));
+    const index = new PatternIndex(patternDir, dataDir);
+
+    // Built with domain metadata; neither pattern is used, so no recency/usage boost
+    const authPattern = new PatternBuilder()
+      .title('Session token validation')
+      .content('Validate the auth session token on every request before touching user data')
+      .addTag('auth')
+      .addTag('security')
+      .domain('auth')
+      .build();
+    const retryPattern = new PatternBuilder()
+      .title('Retry with backoff')
+      .content('Retry failed network calls with exponential backoff')
+      .addTag('resilience')
+      .domain('resilience')
+      .build();
+    try {
+      await index.addPattern(authPattern);
+      await index.addPattern(retryPattern);
+    } catch (err) {
+      t.skip(`embeddings model unavailable: ${err.message}`);
+      return;
+    }
+
+    const query = 'validate the login session';
+    const summarize = (matches) =>
+      matches.map((m) => [m.pattern.id, m.relevance, m.contextBoost ?? null]);
+    const boostOf = (matches, pattern) =>
+      matches.find((m) => m.pattern.id === pattern.id)?.contextBoost ?? null;
+
+    const minimal = await index.searchByIntent(query, { domain: 'auth' });
+    const full = await index.searchByIntent(query, {
+      domain: 'auth',
+      selectedDomains: [],
+      recentPatterns: [],
+      userPreferences: {},
+    });
+
+    assert.deepStrictEqual(summarize(minimal), summarize(full));
+
+    // Domain boost alone: only the pattern in a selected domain is boosted
+    const selected = await index.searchByIntent(query, { selectedDomains: ['auth'] });
+    assert.ok(selected.some((m) => m.pattern.id === authPattern.id), 'auth pattern should match');
+    assert.ok(boostOf(selected, authPattern) > 0, 'auth pattern should carry the selected-domain boost');
+    assert.strictEqual(boostOf(selected, retryPattern), null);
+    assert.strictEqual(boostOf(await index.searchByIntent(query, {}), authPattern), null);
+  });
+});
+
 /**
  * Print test summary
  */
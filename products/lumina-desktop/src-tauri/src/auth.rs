@@ -78,6 +78,14 @@ pub fn generate_device_fingerprint() -> Result<String> {
     Ok(fingerprint)
 }
 
+/// License key layout: four dash-separated groups of four A-Z/0-9 characters,
+/// optionally prefixed with AL- (AL-XXXX-XXXX-XXXX-XXXX, as issued since BUG-002C)
+const LICENSE_KEY_GROUPS: usize = 4;
+const LICENSE_KEY_GROUP_LEN: usize = 4;
+const LICENSE_KEY_PREFIX: &str = "AL-";
+/// Legacy keys issued before the four-group format: AL-XXXX-XXXX
+const LEGACY_LICENSE_KEY_GROUPS: usize = 2;
+
 /// License validation API request payload
 #[derive(Debug, Serialize)]
 struct LicenseValidationRequest {
@@ -98,12 +106,111 @@ pub struct LicenseValidationResponse {
     pub message: String,
 }
 
+/// License validation failures callers handle specifically (returned inside anyhow::Error)
+///
+/// DESIGN DECISION: Typed error only for cases with their own UI, everything else stays anyhow
+/// WHY: Activation dialog can offer "server timed out, retrying" instead of a generic error
+#[derive(Debug)]
+pub enum AuthError {
+    /// Server did not respond within the HTTP timeouts (settings)
+    Timeout { message: String },
+    /// Key is malformed; rejected locally without contacting the server
+    InvalidFormat { reason: String },
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Timeout { message } => write!(f, "License server timed out: {}. Please try again.", message),
+            Self::InvalidFormat { reason } => write!(
+                f,
+                "Invalid license key format: {}. Expected AL-XXXX-XXXX-XXXX-XXXX or XXXX-XXXX-XXXX-XXXX (letters and digits).",
+                reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// Normalize a user-entered license key to [AL-]XXXX-XXXX-XXXX-XXXX (or legacy AL-XXXX-XXXX)
+///
+/// DESIGN DECISION: Reject obviously malformed keys locally, leave validity to the server
+/// WHY: Pasted keys often carry whitespace/newlines or lowercase letters; sending them
+/// costs a round-trip and comes back as an opaque 400/404
+///
+/// Normalization: whitespace removed anywhere (line-wrapped pastes), letters uppercased,
+/// dashes inserted if the key was entered as 16 bare characters. The AL- prefix is kept
+/// (the server stores keys with it); only prefixed keys may use the legacy two-group
+/// shape. Keys have no checksum, so a well-formed key may still be unknown to the server.
+pub fn normalize_license_key(raw: &str) -> std::result::Result<String, AuthError> {
+    let invalid = |reason: String| AuthError::InvalidFormat { reason };
+
+    let compact: String = raw
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if compact.is_empty() {
+        return Err(invalid("license key is empty".to_string()));
+    }
+
+    if let Some(bad) = compact.chars().find(|c| *c != '-' && !c.is_ascii_alphanumeric()) {
+        return Err(invalid(format!("unexpected character '{}'", bad)));
+    }
+
+    let (prefix, key) = match compact.strip_prefix(LICENSE_KEY_PREFIX) {
+        Some(key) => (LICENSE_KEY_PREFIX, key),
+        None => ("", compact.as_str()),
+    };
+
+    let groups: Vec<&str> = if key.contains('-') {
+        key.split('-').collect()
+    } else if key.len() == LICENSE_KEY_GROUPS * LICENSE_KEY_GROUP_LEN {
+        (0..LICENSE_KEY_GROUPS)
+            .map(|i| &key[i * LICENSE_KEY_GROUP_LEN..(i + 1) * LICENSE_KEY_GROUP_LEN])
+            .collect()
+    } else {
+        return Err(invalid(format!(
+            "expected {} characters, got {}",
+            LICENSE_KEY_GROUPS * LICENSE_KEY_GROUP_LEN,
+            key.len()
+        )));
+    };
+
+    let legacy = !prefix.is_empty() && groups.len() == LEGACY_LICENSE_KEY_GROUPS;
+    if groups.len() != LICENSE_KEY_GROUPS && !legacy {
+        return Err(invalid(if prefix.is_empty() {
+            format!("expected {} groups, got {}", LICENSE_KEY_GROUPS, groups.len())
+        } else {
+            format!(
+                "expected {} groups after {} ({} for legacy keys), got {}",
+                LICENSE_KEY_GROUPS,
+                LICENSE_KEY_PREFIX,
+                LEGACY_LICENSE_KEY_GROUPS,
+                groups.len()
+            )
+        }));
+    }
+    if let Some(group) = groups.iter().find(|g| g.len() != LICENSE_KEY_GROUP_LEN) {
+        return Err(invalid(format!(
+            "group '{}' has {} characters, expected {}",
+            group,
+            group.len(),
+            LICENSE_KEY_GROUP_LEN
+        )));
+    }
+
+    Ok(format!("{}{}", prefix, groups.join("-")))
+}
+
 /// Validate license key with server API
 ///
 /// Calls POST /api/license/validate with license_key + device_fingerprint
 ///
 /// # Arguments
-/// - `license_key`: License key from dashboard (format: XXXX-XXXX-XXXX-XXXX)
+/// - `client`: Shared HTTP client (http::HttpClient)
+/// - `license_key`: License key from dashboard (format: [AL-]XXXX-XXXX-XXXX-XXXX, normalized first)
 /// - `api_url`: API base URL (e.g., "https://www.aetherlight.ai")
 ///
 /// # Returns
@@ -115,22 +222,22 @@ pub struct LicenseValidationResponse {
 /// - 404: Invalid license key (not found in database)
 /// - 403: License already activated on another device
 /// - 500: Server error (database or network issue)
-/// - Network errors: Connection timeout, DNS resolution failure
+/// - Malformed key: `AuthError::InvalidFormat` (no request sent)
+/// - Timeout: `AuthError::Timeout` (downcast the returned error)
+/// - Network errors: DNS resolution failure, connection refused
 ///
 /// # Example
 /// ```no_run
-/// let response = validate_license_key("CD7W-AJDK-RLQT-LUFA", "https://www.aetherlight.ai").await?;
+/// let response = validate_license_key(&client, "CD7W-AJDK-RLQT-LUFA", "https://www.aetherlight.ai").await?;
 /// println!("Activated! User: {}, Tier: {}", response.user_name, response.tier);
 /// ```
 pub async fn validate_license_key(
+    client: &reqwest::Client,
     license_key: &str,
     api_url: &str,
 ) -> Result<LicenseValidationResponse> {
-    // Validate license key format: trim whitespace
-    let license_key = license_key.trim();
-    if license_key.is_empty() {
-        anyhow::bail!("License key is empty");
-    }
+    // Validate license key format locally (server remains the source of truth)
+    let license_key = normalize_license_key(license_key)?;
 
     // Generate device fingerprint
     let device_fingerprint = generate_device_fingerprint()
@@ -141,20 +248,26 @@ pub async fn validate_license_key(
 
     // Build request payload
     let payload = LicenseValidationRequest {
-        license_key: license_key.to_string(),
+        license_key: license_key.clone(),
         device_fingerprint,
     };
 
-    println!("🔑 Validating license key: {}... at {}", &license_key[..4], endpoint);
+    tracing::info!("Validating license key: {}... at {}", &license_key[..4], endpoint);
 
-    // Send POST request
-    let client = reqwest::Client::new();
+    // Send POST request (paced with other server calls)
+    crate::rate_limit::server().acquire().await;
     let response = client
         .post(&endpoint)
         .json(&payload)
         .send()
         .await
-        .context("Failed to send license validation request - check internet connection")?;
+        .map_err(|e| {
+            if e.is_timeout() {
+                anyhow::Error::new(AuthError::Timeout { message: e.to_string() })
+            } else {
+                anyhow::Error::new(e).context("Failed to send license validation request - check internet connection")
+            }
+        })?;
 
     // Check status code
     let status = response.status();
@@ -166,11 +279,11 @@ pub async fn validate_license_key(
             .await
             .context("Failed to parse license validation response")?;
 
-        println!("✅ License validation successful!");
-        println!("   User: {}", validation_response.user_name);
-        println!("   Tier: {}", validation_response.tier);
-        println!("   User ID: {}", validation_response.user_id);
-        println!("   Device ID: {}", validation_response.device_id);
+        tracing::info!("License validation successful!");
+        tracing::info!("User: {}", validation_response.user_name);
+        tracing::info!("Tier: {}", validation_response.tier);
+        tracing::info!("User ID: {}", validation_response.user_id);
+        tracing::info!("Device ID: {}", validation_response.device_id);
 
         Ok(validation_response)
     } else {
@@ -179,7 +292,7 @@ pub async fn validate_license_key(
 
         // Return user-friendly error messages based on status code
         match status.as_u16() {
-            400 => anyhow::bail!("Invalid request: {}. Please check license key format (XXXX-XXXX-XXXX-XXXX).", error_text),
+            400 => anyhow::bail!("Invalid request: {}. Please check license key format (AL-XXXX-XXXX-XXXX-XXXX).", error_text),
             404 => anyhow::bail!("Invalid license key: Key not found in database. Please check your license key and try again. Get your license key from https://aetherlight.ai/dashboard"),
             403 => anyhow::bail!("License already activated on another device. Each license can only be used on one device at a time. Please deactivate your other device from the dashboard or contact support."),
             500 => anyhow::bail!("Server error: {}. Please try again later or contact support if the problem persists.", error_text),
@@ -191,6 +304,7 @@ pub async fn validate_license_key(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::http::HttpClient;
 
     /// Test device fingerprint consistency
     /// Same device should produce same fingerprint across multiple calls
@@ -216,13 +330,32 @@ mod tests {
         assert!(!fp.is_empty(), "Fingerprint should not be empty");
     }
 
+    /// A hung license server yields AuthError::Timeout within the configured bound
+    #[tokio::test]
+    async fn test_validation_times_out_on_slow_server() {
+        use crate::http::test_server::slow_server;
+        use std::time::{Duration, Instant};
+
+        let api_url = slow_server(Duration::from_secs(10)).await;
+        let timeout = Duration::from_millis(300);
+        let http = HttpClient::with_timeouts(Duration::from_secs(1), timeout).unwrap();
+
+        let start = Instant::now();
+        let err = validate_license_key(&http.client(), "CD7W-AJDK-RLQT-LUFA", &api_url)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.downcast_ref::<AuthError>(), Some(AuthError::Timeout { .. })), "{:?}", err);
+        assert!(start.elapsed() < timeout + Duration::from_secs(1), "{:?}", start.elapsed());
+    }
+
     /// Test empty license key validation
     #[tokio::test]
     async fn test_empty_license_key() {
         let license_key = "";
         let api_url = "https://www.aetherlight.ai";
 
-        let result = validate_license_key(license_key, api_url).await;
+        let result = validate_license_key(&HttpClient::new().unwrap().client(), license_key, api_url).await;
         assert!(result.is_err(), "Empty license key should fail validation");
 
         let error_msg = result.unwrap_err().to_string();
@@ -235,10 +368,73 @@ mod tests {
         let license_key = "   ";
         let api_url = "https://www.aetherlight.ai";
 
-        let result = validate_license_key(license_key, api_url).await;
+        let result = validate_license_key(&HttpClient::new().unwrap().client(), license_key, api_url).await;
         assert!(result.is_err(), "Whitespace-only license key should fail validation");
     }
 
+    /// Pasted keys are trimmed, uppercased and dashed before the request
+    #[test]
+    fn test_normalize_well_formed_key() {
+        assert_eq!(normalize_license_key("CD7W-AJDK-RLQT-LUFA").unwrap(), "CD7W-AJDK-RLQT-LUFA");
+        assert_eq!(normalize_license_key("  cd7w-ajdk-rlqt-lufa\n").unwrap(), "CD7W-AJDK-RLQT-LUFA");
+        assert_eq!(normalize_license_key("CD7WAJDKRLQTLUFA").unwrap(), "CD7W-AJDK-RLQT-LUFA");
+        assert_eq!(normalize_license_key("CD7W-AJDK-\r\nRLQT-LUFA").unwrap(), "CD7W-AJDK-RLQT-LUFA");
+    }
+
+    /// Documented AL- keys keep their prefix
+    #[test]
+    fn test_normalize_prefixed_key() {
+        assert_eq!(normalize_license_key("AL-CD7W-AJDK-RLQT-LUFA").unwrap(), "AL-CD7W-AJDK-RLQT-LUFA");
+        assert_eq!(normalize_license_key(" al-cd7w-ajdk-rlqt-lufa ").unwrap(), "AL-CD7W-AJDK-RLQT-LUFA");
+        assert_eq!(normalize_license_key("AL-CD7WAJDKRLQTLUFA").unwrap(), "AL-CD7W-AJDK-RLQT-LUFA");
+    }
+
+    /// Legacy AL-XXXX-XXXX keys still validate; the short shape needs the prefix
+    #[test]
+    fn test_normalize_legacy_key() {
+        assert_eq!(normalize_license_key("AL-CD7W-AJDK").unwrap(), "AL-CD7W-AJDK");
+        assert_eq!(normalize_license_key("al-cd7w-ajdk\n").unwrap(), "AL-CD7W-AJDK");
+
+        for (key, expected) in [
+            ("CD7W-AJDK", "expected 4 groups, got 2"),
+            ("AL-CD7W-AJDK-RLQT", "expected 4 groups after AL- (2 for legacy keys), got 3"),
+            ("AL-CD7W-AJD", "group 'AJD'"),
+        ] {
+            let err = normalize_license_key(key).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", key, err);
+        }
+    }
+
+    #[test]
+    fn test_normalize_rejects_malformed_keys() {
+        for (key, expected) in [
+            ("CD7W-AJDK-RLQT", "expected 4 groups"),
+            ("CD7W-AJDK-RLQT-LUFA-0000", "expected 4 groups"),
+            ("CD7W-AJDK-RLQ-LUFAX", "group 'RLQ'"),
+            ("CD7W--AJDK-RLQT", "group ''"),
+            ("CD7W-AJDK-RLQT-LUF!", "unexpected character '!'"),
+            ("CD7W_AJDK_RLQT_LUFA", "unexpected character '_'"),
+            ("CD7WAJDK", "expected 16 characters, got 8"),
+        ] {
+            let err = normalize_license_key(key).unwrap_err();
+            assert!(matches!(err, AuthError::InvalidFormat { .. }), "{}", key);
+            assert!(err.to_string().contains(expected), "{}: {}", key, err);
+        }
+    }
+
+    /// Malformed keys fail before any connection is opened
+    #[tokio::test]
+    async fn test_malformed_key_rejected_before_request() {
+        let (api_url, connections) = crate::http::test_server::counting_server().await;
+
+        let err = validate_license_key(&HttpClient::new().unwrap().client(), "CD7W-AJDK", &api_url)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.downcast_ref::<AuthError>(), Some(AuthError::InvalidFormat { .. })), "{:?}", err);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
     // Integration tests with live API (marked as #[ignore] - run manually)
 
     /// Test valid license key activation (free tier)
@@ -249,7 +445,7 @@ mod tests {
         let license_key = "CD7W-AJDK-RLQT-LUFA"; // Free tier test key
         let api_url = "https://www.aetherlight.ai";
 
-        let result = validate_license_key(license_key, api_url).await;
+        let result = validate_license_key(&HttpClient::new().unwrap().client(), license_key, api_url).await;
 
         // May fail if already activated - that's expected behavior (403)
         if result.is_ok() {
@@ -268,10 +464,10 @@ mod tests {
     #[tokio::test]
     #[ignore] // Requires live API
     async fn test_invalid_license_key() {
-        let license_key = "INVALID-0000-0000-0000";
+        let license_key = "ZZZZ-0000-0000-0000"; // Well-formed but unissued
         let api_url = "https://www.aetherlight.ai";
 
-        let result = validate_license_key(license_key, api_url).await;
+        let result = validate_license_key(&HttpClient::new().unwrap().client(), license_key, api_url).await;
         assert!(result.is_err(), "Invalid license key should fail validation");
 
         let error_msg = result.unwrap_err().to_string();
@@ -0,0 +1,312 @@
+/**
+ * First-Run Setup - Ordered, resumable installation wizard steps (Installer-001)
+ *
+ * DESIGN DECISION: One orchestrating command; progress persisted after every step
+ * WHY: The wizard invoked four commands independently. A failure at sync left storage
+ * provisioned and settings.json written with no record of what had run, so a retry
+ * started over and is_first_run() already reported setup as done
+ *
+ * REASONING CHAIN:
+ * 1. Steps run in SetupStep order: provision storage → metadata → network → sync
+ * 2. setup_progress.json is written before the first step and after every step,
+ *    recording the config and the last step that completed
+ * 3. A failure records failed_step + error, then returns the error
+ * 4. Retry with the same config skips through last_completed; a different config
+ *    starts over (every step depends on storage_mb/domains)
+ * 5. Success removes the file; while it exists is_first_run() keeps showing the wizard
+ * 6. reset_setup() undoes every step the last attempt touched (newest first) and
+ *    leaves an empty progress file, so the next run starts from the first step
+ *
+ * PATTERN: Pattern-INSTALLER-001 (First-Run Wizard)
+ * RELATED: main.rs (run_first_run_setup, reset_setup, WizardSteps), InstallationWizard.tsx,
+ *          pattern_sync.rs (sync step resumes its own partial progress)
+ */
+
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Emitted as each step starts, is skipped (already done) or completes
+pub const STEP_EVENT: &str = "setup-step";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupStep {
+    ProvisionStorage,
+    SetupMetadata,
+    ConfigureNetwork,
+    SyncPatterns,
+}
+
+impl SetupStep {
+    /// Execution order
+    pub const ALL: [SetupStep; 4] = [
+        SetupStep::ProvisionStorage,
+        SetupStep::SetupMetadata,
+        SetupStep::ConfigureNetwork,
+        SetupStep::SyncPatterns,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepStatus {
+    Started,
+    Skipped,
+    Completed,
+}
+
+/// Payload of `setup-step`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetupStepEvent {
+    pub step: SetupStep,
+    pub status: StepStatus,
+}
+
+/// Wizard choices the steps run with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetupConfig {
+    pub storage_mb: u64,
+    pub domains: Vec<String>,
+}
+
+/// Contents of setup_progress.json
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SetupProgress {
+    pub config: Option<SetupConfig>,
+    /// Last step that finished (every step before it finished too)
+    pub last_completed: Option<SetupStep>,
+    pub failed_step: Option<SetupStep>,
+    pub error: Option<String>,
+}
+
+impl SetupProgress {
+    /// Saved progress (None if missing or unreadable)
+    pub fn load(path: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(path).ok()?;
+        match serde_json::from_str(&json) {
+            Ok(progress) => Some(progress),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable setup progress {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create setup progress directory: {}", e))?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize setup progress: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to save setup progress: {}", e))
+    }
+
+    /// Steps that ran (completed or failed part-way), in execution order
+    fn touched(&self) -> Vec<SetupStep> {
+        let last = self.failed_step.max(self.last_completed);
+        SetupStep::ALL.into_iter().filter(|step| Some(*step) <= last).collect()
+    }
+}
+
+/**
+ * The wizard steps (Tauri commands in the app)
+ *
+ * DESIGN DECISION: Boxed futures instead of async-trait dependency
+ * WHY: Same convention as ipc_server::VoiceCaptureBackend
+ */
+pub trait SetupSteps: Send + Sync {
+    fn run<'a>(&'a self, step: SetupStep, config: &'a SetupConfig) -> BoxFuture<'a, Result<(), String>>;
+
+    /// Clean up what `step` left behind (reset_setup)
+    fn undo(&self, step: SetupStep) -> Result<(), String>;
+}
+
+/**
+ * Run the setup steps not yet completed for `config`
+ */
+pub async fn run_setup<F>(
+    steps: &dyn SetupSteps,
+    progress_path: &Path,
+    config: SetupConfig,
+    mut on_step: F,
+) -> Result<(), String>
+where
+    F: FnMut(SetupStepEvent),
+{
+    let mut progress = match SetupProgress::load(progress_path) {
+        Some(saved) if saved.config.as_ref() == Some(&config) => {
+            if let Some(step) = saved.failed_step {
+                tracing::info!("[Installer-001] Resuming setup at {:?} (last error: {:?})", step, saved.error);
+            }
+            saved
+        }
+        _ => SetupProgress { config: Some(config.clone()), ..Default::default() },
+    };
+    progress.save(progress_path)?;
+
+    for step in SetupStep::ALL {
+        if Some(step) <= progress.last_completed {
+            on_step(SetupStepEvent { step, status: StepStatus::Skipped });
+            continue;
+        }
+
+        on_step(SetupStepEvent { step, status: StepStatus::Started });
+        if let Err(e) = steps.run(step, &config).await {
+            tracing::error!("[Installer-001] Setup step {:?} failed: {}", step, e);
+            progress.failed_step = Some(step);
+            progress.error = Some(e.clone());
+            if let Err(save_err) = progress.save(progress_path) {
+                tracing::warn!("{}", save_err);
+            }
+            return Err(e);
+        }
+
+        progress.last_completed = Some(step);
+        progress.failed_step = None;
+        progress.error = None;
+        progress.save(progress_path)?;
+        on_step(SetupStepEvent { step, status: StepStatus::Completed });
+    }
+
+    std::fs::remove_file(progress_path)
+        .map_err(|e| format!("Failed to remove setup progress: {}", e))?;
+    tracing::info!("[Installer-001] First-run setup complete");
+    Ok(())
+}
+
+/**
+ * Undo a partial setup so the next run starts from the first step
+ */
+pub fn reset_setup(steps: &dyn SetupSteps, progress_path: &Path) -> Result<(), String> {
+    let touched = SetupProgress::load(progress_path).map(|p| p.touched()).unwrap_or_default();
+
+    for step in touched.into_iter().rev() {
+        steps.undo(step)?;
+    }
+
+    // Keep the (now empty) file: setup is still unfinished
+    SetupProgress::default().save(progress_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Records calls; fails `fail_at` until it is cleared
+    #[derive(Default)]
+    struct FakeSteps {
+        fail_at: Mutex<Option<SetupStep>>,
+        ran: Mutex<Vec<SetupStep>>,
+        undone: Mutex<Vec<SetupStep>>,
+    }
+
+    impl FakeSteps {
+        fn failing_at(step: SetupStep) -> Self {
+            Self { fail_at: Mutex::new(Some(step)), ..Default::default() }
+        }
+
+        fn take_ran(&self) -> Vec<SetupStep> {
+            std::mem::take(&mut *self.ran.lock().unwrap())
+        }
+    }
+
+    impl SetupSteps for FakeSteps {
+        fn run<'a>(&'a self, step: SetupStep, _config: &'a SetupConfig) -> BoxFuture<'a, Result<(), String>> {
+            Box::pin(async move {
+                self.ran.lock().unwrap().push(step);
+                if *self.fail_at.lock().unwrap() == Some(step) {
+                    return Err(format!("{:?} failed", step));
+                }
+                Ok(())
+            })
+        }
+
+        fn undo(&self, step: SetupStep) -> Result<(), String> {
+            self.undone.lock().unwrap().push(step);
+            Ok(())
+        }
+    }
+
+    fn config() -> SetupConfig {
+        SetupConfig { storage_mb: 500, domains: vec!["legal".to_string()] }
+    }
+
+    /**
+     * Test: Failure at sync records the last good step; retry runs only sync
+     */
+    #[tokio::test]
+    async fn test_retry_resumes_after_failed_sync() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("setup_progress.json");
+        let steps = FakeSteps::failing_at(SetupStep::SyncPatterns);
+
+        let err = run_setup(&steps, &path, config(), |_| {}).await.unwrap_err();
+        assert_eq!(err, "SyncPatterns failed");
+        assert_eq!(steps.take_ran(), SetupStep::ALL.to_vec());
+
+        let saved = SetupProgress::load(&path).unwrap();
+        assert_eq!(saved.last_completed, Some(SetupStep::ConfigureNetwork));
+        assert_eq!(saved.failed_step, Some(SetupStep::SyncPatterns));
+        assert_eq!(saved.error.as_deref(), Some("SyncPatterns failed"));
+
+        // Network comes back: only the failed step runs again
+        *steps.fail_at.lock().unwrap() = None;
+        let mut events = Vec::new();
+        run_setup(&steps, &path, config(), |event| events.push(event)).await.unwrap();
+
+        assert_eq!(steps.take_ran(), vec![SetupStep::SyncPatterns]);
+        let skipped: Vec<SetupStep> = events
+            .iter()
+            .filter(|e| e.status == StepStatus::Skipped)
+            .map(|e| e.step)
+            .collect();
+        assert_eq!(skipped, SetupStep::ALL[..3].to_vec());
+        assert_eq!(
+            events.last(),
+            Some(&SetupStepEvent { step: SetupStep::SyncPatterns, status: StepStatus::Completed })
+        );
+        assert!(!path.exists(), "progress file removed once setup completes");
+    }
+
+    /// Different wizard choices invalidate completed steps
+    #[tokio::test]
+    async fn test_changed_config_starts_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("setup_progress.json");
+        let steps = FakeSteps::failing_at(SetupStep::ConfigureNetwork);
+        run_setup(&steps, &path, config(), |_| {}).await.unwrap_err();
+        steps.take_ran();
+
+        *steps.fail_at.lock().unwrap() = None;
+        let bigger = SetupConfig { storage_mb: 1000, ..config() };
+        run_setup(&steps, &path, bigger, |_| {}).await.unwrap();
+
+        assert_eq!(steps.take_ran(), SetupStep::ALL.to_vec());
+    }
+
+    /**
+     * Test: reset undoes the touched steps newest-first; next run starts from the top
+     */
+    #[tokio::test]
+    async fn test_reset_cleans_partial_setup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("setup_progress.json");
+        let steps = FakeSteps::failing_at(SetupStep::ConfigureNetwork);
+        run_setup(&steps, &path, config(), |_| {}).await.unwrap_err();
+        steps.take_ran();
+
+        reset_setup(&steps, &path).unwrap();
+        assert_eq!(
+            *steps.undone.lock().unwrap(),
+            vec![SetupStep::ConfigureNetwork, SetupStep::SetupMetadata, SetupStep::ProvisionStorage]
+        );
+        assert_eq!(SetupProgress::load(&path), Some(SetupProgress::default()));
+
+        *steps.fail_at.lock().unwrap() = None;
+        run_setup(&steps, &path, config(), |_| {}).await.unwrap();
+        assert_eq!(steps.take_ran(), SetupStep::ALL.to_vec());
+    }
+}
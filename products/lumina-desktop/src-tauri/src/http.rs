@@ -0,0 +1,300 @@
+/**
+ * Shared HTTP Client for ÆtherLight Server Calls
+ *
+ * DESIGN DECISION: One reqwest::Client in Tauri managed state, passed to every HTTP function
+ * WHY: Building a Client per call threw away its connection pool and TLS sessions,
+ * adding a handshake to every balance check/transcription and churning file descriptors
+ *
+ * REASONING CHAIN:
+ * 1. reqwest::Client is an Arc around a connection pool: cloning is cheap and shares it
+ * 2. HttpClient is built once at startup and registered with `.manage()` (a build error is
+ *    logged and reqwest's default client used instead; see new_or_default)
+ * 3. Commands take `tauri::State<HttpClient>`; services (ServerTranscriber, ...) hold a clone
+ * 4. Every request carries the lumina-desktop user-agent and the client's timeouts
+ * 5. Timeouts come from settings (http_connect_timeout_secs, http_request_timeout_secs);
+ *    configure() rebuilds the client so changes apply to the next request
+ * 6. Result: Keep-alive reuse across calls, one place to configure HTTP behaviour
+ *
+ * PATTERN: Pattern-HTTP-001 (Shared Connection Pool)
+ * RELATED: transcription.rs, auth.rs, rate_limit.rs, main.rs (sync_initial_patterns)
+ */
+
+use anyhow::{Context, Result};
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// Sent with every server request (server logs/analytics can tell desktop versions apart)
+pub const USER_AGENT: &str = concat!("lumina-desktop/", env!("CARGO_PKG_VERSION"));
+
+/// Time allowed to establish a TCP/TLS connection
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed for a whole request, including uploading audio and server-side transcription
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Shared HTTP client (Tauri managed state)
+#[derive(Debug)]
+pub struct HttpClient {
+    inner: RwLock<Configured>,
+}
+
+#[derive(Debug, Clone)]
+struct Configured {
+    client: reqwest::Client,
+    connect_timeout: Duration,
+    request_timeout: Duration,
+}
+
+impl Configured {
+    fn build(connect_timeout: Duration, request_timeout: Duration) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout)
+            .build()
+            .context("Failed to build HTTP client")?;
+
+        Ok(Self {
+            client,
+            connect_timeout,
+            request_timeout,
+        })
+    }
+}
+
+impl HttpClient {
+    /// Client with the default connect and request timeouts
+    pub fn new() -> Result<Self> {
+        Self::with_timeouts(DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT)
+    }
+
+    /**
+     * Client with the default timeouts, or reqwest's default client if that fails to build
+     *
+     * DESIGN DECISION: Log the build error and keep starting instead of panicking
+     * WHY: A broken TLS backend or proxy config must not crash the app at launch;
+     * server calls still go out, and local features don't need HTTP at all
+     *
+     * The fallback records zero timeouts, so the next configure() retries the full build.
+     */
+    pub fn new_or_default() -> Self {
+        Self::new().unwrap_or_else(|e| {
+            tracing::error!("{:#}; falling back to the default HTTP client", e);
+            Self::fallback()
+        })
+    }
+
+    /// reqwest's default client: no user-agent, no timeouts
+    fn fallback() -> Self {
+        Self {
+            inner: RwLock::new(Configured {
+                client: reqwest::Client::default(),
+                connect_timeout: Duration::ZERO,
+                request_timeout: Duration::ZERO,
+            }),
+        }
+    }
+
+    /// Client with explicit timeouts
+    pub fn with_timeouts(connect_timeout: Duration, request_timeout: Duration) -> Result<Self> {
+        Ok(Self {
+            inner: RwLock::new(Configured::build(connect_timeout, request_timeout)?),
+        })
+    }
+
+    /// Apply new timeouts (no-op when unchanged, so idle connections are kept)
+    ///
+    /// Requests already in flight finish under the old timeouts.
+    pub fn configure(&self, connect_timeout: Duration, request_timeout: Duration) -> Result<()> {
+        {
+            let current = self.inner.read().unwrap_or_else(|e| e.into_inner());
+            if current.connect_timeout == connect_timeout && current.request_timeout == request_timeout {
+                return Ok(());
+            }
+        }
+
+        let rebuilt = Configured::build(connect_timeout, request_timeout)?;
+        *self.inner.write().unwrap_or_else(|e| e.into_inner()) = rebuilt;
+        tracing::info!(
+            "HTTP timeouts set: connect {:?}, request {:?}",
+            connect_timeout,
+            request_timeout
+        );
+        Ok(())
+    }
+
+    /// Client for the next request (clones share the connection pool)
+    pub fn client(&self) -> reqwest::Client {
+        self.read().client
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        self.read().connect_timeout
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        self.read().request_timeout
+    }
+
+    fn read(&self) -> Configured {
+        self.inner.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// Local mock servers for HTTP tests (also used by transcription.rs, auth.rs and pattern_sync.rs)
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Keep-alive HTTP server answering `{}` to every request; counts TCP connections
+    pub(crate) async fn counting_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    let mut request = Vec::new();
+                    loop {
+                        let n = match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => n,
+                        };
+                        request.extend_from_slice(&buf[..n]);
+                        // Bodiless GETs: one request per blank line
+                        while let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                            request.drain(..end + 4);
+                            let response = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}";
+                            if socket.write_all(response.as_bytes()).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        (format!("http://{}", addr), connections)
+    }
+
+    /// Server answering every request with `body` (200, application/json), one request per connection
+    pub(crate) async fn json_server(body: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let body = body.clone();
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await;
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    /// Server that accepts connections but only responds after `delay` (simulates a hung server)
+    pub(crate) async fn slow_server(delay: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await;
+                    tokio::time::sleep(delay).await;
+                    let _ = socket
+                        .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}")
+                        .await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::{counting_server, slow_server};
+    use super::*;
+    use std::sync::atomic::Ordering;
+    use std::time::Instant;
+
+    /// Clones share one pool: repeated calls reuse the same connection
+    #[tokio::test]
+    async fn test_shared_client_reuses_connection() {
+        let (url, connections) = counting_server().await;
+        let http = HttpClient::new().unwrap();
+
+        for _ in 0..3 {
+            let client = http.client(); // as handed to services/commands
+            let response = client.get(&url).send().await.unwrap();
+            assert!(response.status().is_success());
+            response.bytes().await.unwrap();
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    /// A hung server fails the request at the configured bound instead of hanging
+    #[tokio::test]
+    async fn test_request_timeout_bounds_slow_server() {
+        let url = slow_server(Duration::from_secs(10)).await;
+        let http = HttpClient::with_timeouts(DEFAULT_CONNECT_TIMEOUT, Duration::from_millis(200)).unwrap();
+
+        let start = Instant::now();
+        let err = http.client().get(&url).send().await.unwrap_err();
+
+        assert!(err.is_timeout(), "{:?}", err);
+        assert!(start.elapsed() < Duration::from_secs(2), "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn test_client_carries_configured_timeouts() {
+        let http = HttpClient::new().unwrap();
+        assert_eq!(http.connect_timeout(), DEFAULT_CONNECT_TIMEOUT);
+        assert_eq!(http.request_timeout(), DEFAULT_REQUEST_TIMEOUT);
+
+        let http = HttpClient::with_timeouts(Duration::from_secs(2), Duration::from_secs(5)).unwrap();
+        assert_eq!(http.request_timeout(), Duration::from_secs(5));
+        assert!(USER_AGENT.starts_with("lumina-desktop/"));
+
+        http.configure(Duration::from_secs(3), Duration::from_secs(30)).unwrap();
+        assert_eq!(http.connect_timeout(), Duration::from_secs(3));
+        assert_eq!(http.request_timeout(), Duration::from_secs(30));
+    }
+
+    /**
+     * Test: Fallback client is replaced by a configured one on the next configure()
+     */
+    #[test]
+    fn test_fallback_rebuilt_by_configure() {
+        let http = HttpClient::fallback();
+        assert_eq!(http.request_timeout(), Duration::ZERO);
+
+        http.configure(DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT).unwrap();
+        assert_eq!(http.connect_timeout(), DEFAULT_CONNECT_TIMEOUT);
+        assert_eq!(http.request_timeout(), DEFAULT_REQUEST_TIMEOUT);
+    }
+}
@@ -0,0 +1,431 @@
+/**
+ * Pattern Index Rebuild - User-triggered semantic index rebuild with progress
+ *
+ * DESIGN DECISION: One long-lived PatternIndex owned by a worker thread (Tauri managed state)
+ * WHY: Rebuilding after bulk edits can take seconds; users had no trigger and no feedback,
+ * and a rebuilt index is only useful if later searches hit the same index
+ *
+ * REASONING CHAIN:
+ * 1. PatternIndexService is managed state; its worker thread owns the PatternIndex
+ *    (PatternIndex holds a SQLite connection, so it and its futures are not Send)
+ * 2. Commands send jobs (rebuild, search, compact) over a channel, replies come back on oneshots
+ * 3. Rebuild reads the library from patterns.db (the table Pattern Manager edits and sync fills)
+ * 4. PatternIndex::rebuild_from_patterns reports (processed, total) per pattern,
+ *    forwarded by main.rs as `index-rebuild-progress` events
+ * 5. Final PatternIndexStatistics sent as `index-rebuild-complete`
+ * 6. RebuildGuard rejects a second rebuild while one is queued or running
+ *
+ * PATTERN: Pattern-INDEX-001 (Pattern Index Rebuild)
+ * RELATED: aetherlight_core::PatternIndex::rebuild_from_patterns, main.rs (get_pattern_db,
+ * rebuild_pattern_index, search_pattern_index), storage::PostgresStorage
+ */
+
+use aetherlight_core::{Embedder, EmbeddingConfig, Pattern, PatternIndex, PatternIndexStatistics, SearchContext};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+/// Emitted after each pattern is processed
+pub const PROGRESS_EVENT: &str = "index-rebuild-progress";
+
+/// Emitted once with final PatternIndexStatistics
+pub const COMPLETE_EVENT: &str = "index-rebuild-complete";
+
+/// Emitted with an error message if the rebuild fails
+pub const FAILED_EVENT: &str = "index-rebuild-failed";
+
+/// Payload of `index-rebuild-progress`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RebuildProgress {
+    pub processed: usize,
+    pub total: usize,
+}
+
+/// Semantic search hit, identified by its patterns.db id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexMatch {
+    pub id: String,
+    pub name: String,
+    pub relevance: f64,
+    pub reasoning: String,
+}
+
+/**
+ * RebuildGuard - Prevents concurrent rebuilds (Tauri managed state)
+ */
+#[derive(Debug, Default)]
+pub struct RebuildGuard {
+    running: Arc<AtomicBool>,
+}
+
+/// Held for the duration of a rebuild; releases the guard on drop
+#[derive(Debug)]
+pub struct RebuildTicket {
+    running: Arc<AtomicBool>,
+}
+
+impl RebuildGuard {
+    /// Claim the guard (None if a rebuild is already running)
+    pub fn try_start(&self) -> Option<RebuildTicket> {
+        self.running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| RebuildTicket { running: Arc::clone(&self.running) })
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Acquire)
+    }
+}
+
+impl Drop for RebuildTicket {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Release);
+    }
+}
+
+/**
+ * Default index location: ~/.lumina/index (vector store and embedding models)
+ *
+ * Created by the PatternIndexService worker on start.
+ */
+pub fn default_data_dir() -> PathBuf {
+    let mut data_dir = dirs::home_dir().expect("Failed to get home directory");
+    data_dir.push(".lumina");
+    data_dir.push("index");
+    data_dir
+}
+
+/**
+ * Library to index: every row of the patterns table, paired with its store id
+ *
+ * DESIGN DECISION: Select only the columns get_pattern_db and PostgresStorage share
+ * WHY: Both create patterns.db's patterns table (with different extra columns)
+ *
+ * Rows that fail Pattern validation (empty name/description) are skipped, not fatal.
+ */
+pub fn load_library(conn: &Connection) -> Result<Vec<(String, Pattern)>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, name, description, domain, tags FROM patterns ORDER BY id")
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            let tags: Option<String> = row.get(4)?;
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                tags.map(|tags| tags.split(',').map(|t| t.trim().to_string()).filter(|t| !t.is_empty()).collect())
+                    .unwrap_or_default(),
+            ))
+        })
+        .map_err(|e| format!("Failed to read pattern library: {}", e))?;
+
+    let mut library = Vec::new();
+    for row in rows {
+        let (id, name, description, domain, tags): (String, String, String, Option<String>, Vec<String>) =
+            row.map_err(|e| format!("Failed to parse pattern: {}", e))?;
+
+        let mut builder = Pattern::builder().title(name).content(description).tags(tags);
+        if let Some(domain) = domain {
+            builder = builder.domain(domain);
+        }
+        match builder.build() {
+            Ok(pattern) => library.push((id, pattern)),
+            Err(e) => tracing::warn!(pattern_id = %id, error = %e, "Skipping pattern in index rebuild"),
+        }
+    }
+
+    Ok(library)
+}
+
+type Reply<T> = oneshot::Sender<Result<T, String>>;
+
+enum Job {
+    Rebuild {
+        library: Vec<(String, Pattern)>,
+        embedding: EmbeddingConfig,
+        on_progress: Box<dyn FnMut(RebuildProgress) + Send>,
+        reply: Reply<PatternIndexStatistics>,
+    },
+    Search {
+        intent: String,
+        context: Option<SearchContext>,
+        reply: Reply<Vec<IndexMatch>>,
+    },
+    Compact {
+        reply: Reply<u64>,
+    },
+}
+
+/**
+ * PatternIndexService - Handle to the worker-owned PatternIndex (Tauri managed state)
+ *
+ * DESIGN DECISION: Channel to a dedicated thread instead of Mutex<PatternIndex>
+ * WHY: PatternIndex is not Send; its worker drives a current-thread runtime, so
+ * async commands only await a oneshot reply
+ */
+#[derive(Clone)]
+pub struct PatternIndexService {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl PatternIndexService {
+    /**
+     * Start the worker for the index stored in `data_dir`
+     *
+     * `embedder` replaces the configured provider when given (tests, hosts without the model).
+     */
+    pub fn spawn(data_dir: PathBuf, embedder: Option<Box<dyn Embedder>>) -> Self {
+        let (jobs, receiver) = mpsc::channel();
+        std::thread::spawn(move || run_worker(data_dir, embedder, receiver));
+        Self { jobs }
+    }
+
+    /// Replace the indexed library; resolves once the rebuild finished
+    pub async fn rebuild<F>(
+        &self,
+        library: Vec<(String, Pattern)>,
+        embedding: EmbeddingConfig,
+        on_progress: F,
+    ) -> Result<PatternIndexStatistics, String>
+    where
+        F: FnMut(RebuildProgress) + Send + 'static,
+    {
+        let (reply, result) = oneshot::channel();
+        self.send(Job::Rebuild { library, embedding, on_progress: Box::new(on_progress), reply })?;
+        result.await.map_err(|_| "Pattern index worker stopped".to_string())?
+    }
+
+    /// Semantic search over the last rebuilt library (context boosts, never filters)
+    pub async fn search(&self, intent: &str, context: Option<SearchContext>) -> Result<Vec<IndexMatch>, String> {
+        let (reply, result) = oneshot::channel();
+        self.send(Job::Search { intent: intent.to_string(), context, reply })?;
+        result.await.map_err(|_| "Pattern index worker stopped".to_string())?
+    }
+
+    /// Compact the index's vector store; resolves with bytes reclaimed
+    pub async fn compact(&self) -> Result<u64, String> {
+        let (reply, result) = oneshot::channel();
+        self.send(Job::Compact { reply })?;
+        result.await.map_err(|_| "Pattern index worker stopped".to_string())?
+    }
+
+    fn send(&self, job: Job) -> Result<(), String> {
+        self.jobs
+            .send(job)
+            .map_err(|_| "Pattern index worker stopped".to_string())
+    }
+}
+
+fn run_worker(data_dir: PathBuf, embedder: Option<Box<dyn Embedder>>, jobs: mpsc::Receiver<Job>) {
+    let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to start pattern index runtime");
+            return;
+        }
+    };
+
+    // Library comes from patterns.db, so pattern_dir (markdown library) is never read
+    let mut index = std::fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create {}: {}", data_dir.display(), e))
+        .and_then(|_| {
+            PatternIndex::new(data_dir.clone(), data_dir)
+                .map_err(|e| format!("Failed to open pattern index: {}", e))
+        });
+    if let (Ok(index), Some(embedder)) = (index.as_mut(), embedder) {
+        index.set_embedder(embedder);
+    }
+
+    // PatternIndex assigns fresh UUIDs; map them back to patterns.db ids for results
+    let mut store_ids: HashMap<Uuid, String> = HashMap::new();
+
+    for job in jobs {
+        match job {
+            Job::Rebuild { library, embedding, mut on_progress, reply } => {
+                let result = match index.as_mut() {
+                    Ok(index) => runtime.block_on(async {
+                        index.set_embedding_config(embedding);
+                        store_ids.clear();
+
+                        let mut patterns = Vec::with_capacity(library.len());
+                        for (store_id, pattern) in library {
+                            store_ids.insert(*pattern.id(), store_id);
+                            patterns.push(pattern);
+                        }
+
+                        index
+                            .rebuild_from_patterns(patterns, |processed, total| {
+                                on_progress(RebuildProgress { processed, total })
+                            })
+                            .await
+                            .map_err(|e| format!("Pattern index rebuild failed: {}", e))?;
+
+                        Ok(index.get_statistics().await)
+                    }),
+                    Err(e) => Err(e.clone()),
+                };
+                let _ = reply.send(result);
+            }
+            Job::Search { intent, context, reply } => {
+                let result = match index.as_ref() {
+                    Ok(index) => runtime.block_on(index.search_by_intent(&intent, context.as_ref()))
+                        .map(|matches| {
+                            matches
+                                .into_iter()
+                                .map(|m| IndexMatch {
+                                    id: store_ids
+                                        .get(m.pattern.id())
+                                        .cloned()
+                                        .unwrap_or_else(|| m.pattern.id().to_string()),
+                                    name: m.pattern.title().to_string(),
+                                    relevance: m.relevance,
+                                    reasoning: m.reasoning,
+                                })
+                                .collect()
+                        })
+                        .map_err(|e| format!("Pattern index search failed: {}", e)),
+                    Err(e) => Err(e.clone()),
+                };
+                let _ = reply.send(result);
+            }
+            Job::Compact { reply } => {
+                let result = match index.as_ref() {
+                    Ok(index) => runtime.block_on(index.compact())
+                        .map_err(|e| format!("Pattern index compaction failed: {}", e)),
+                    Err(e) => Err(e.clone()),
+                };
+                let _ = reply.send(result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aetherlight_core::{EmbeddingResult, EMBEDDING_DIM};
+
+    /// Deterministic bag-of-words embedder (no ONNX model needed)
+    struct WordHashEmbedder;
+
+    impl Embedder for WordHashEmbedder {
+        fn dimension(&self) -> usize {
+            EMBEDDING_DIM
+        }
+
+        fn embed(&self, text: &str) -> aetherlight_core::Result<EmbeddingResult> {
+            let mut embedding = vec![0.0f32; EMBEDDING_DIM];
+            for word in text.split_whitespace() {
+                let bucket = word.to_lowercase().bytes().fold(0usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+                embedding[bucket % EMBEDDING_DIM] += 1.0;
+            }
+            Ok(EmbeddingResult {
+                embedding,
+                text: text.to_string(),
+                duration_ms: 0,
+                token_count: text.split_whitespace().count(),
+            })
+        }
+    }
+
+    /// patterns table as created by get_pattern_db (main.rs)
+    fn pattern_db(rows: &[(&str, &str, &str)]) -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE patterns (
+                id TEXT PRIMARY KEY, name TEXT NOT NULL, description TEXT NOT NULL, domain TEXT,
+                confidence_score REAL, tags TEXT, created_at TEXT, updated_at TEXT
+            )",
+            [],
+        ).unwrap();
+        for (id, name, description) in rows {
+            conn.execute(
+                "INSERT INTO patterns (id, name, description, domain, tags) VALUES (?1, ?2, ?3, 'rust', 'resilience, auth')",
+                rusqlite::params![id, name, description],
+            ).unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    fn test_guard_rejects_concurrent_rebuild() {
+        let guard = RebuildGuard::default();
+
+        let ticket = guard.try_start().expect("first rebuild should start");
+        assert!(guard.is_running());
+        assert!(guard.try_start().is_none());
+
+        drop(ticket);
+        assert!(!guard.is_running());
+        assert!(guard.try_start().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_empty_library() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let service = PatternIndexService::spawn(data_dir.path().to_path_buf(), None);
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let stats = service
+            .rebuild(load_library(&pattern_db(&[])).unwrap(), EmbeddingConfig::default(), move |progress| {
+                sink.lock().unwrap().push(progress)
+            })
+            .await
+            .unwrap();
+
+        assert!(events.lock().unwrap().is_empty());
+        assert_eq!(stats.total_patterns, 0);
+    }
+
+    /**
+     * Test: Rebuild from patterns.db reports per-pattern progress and the index
+     * stays loaded for later searches
+     */
+    #[tokio::test]
+    async fn test_rebuild_reports_progress() {
+        let conn = pattern_db(&[
+            ("pattern-001", "Retry With Backoff", "Exponential backoff with jitter"),
+            ("pattern-002", "OAuth2 PKCE", "Use PKCE for public clients"),
+            ("pattern-003", "   ", "Invalid: empty name"),
+        ]);
+
+        let data_dir = tempfile::tempdir().unwrap();
+        let service = PatternIndexService::spawn(data_dir.path().to_path_buf(), Some(Box::new(WordHashEmbedder)));
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let stats = service
+            .rebuild(load_library(&conn).unwrap(), EmbeddingConfig::default(), move |progress| {
+                sink.lock().unwrap().push(progress)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                RebuildProgress { processed: 1, total: 2 },
+                RebuildProgress { processed: 2, total: 2 },
+            ]
+        );
+        assert_eq!(stats.total_patterns, 2);
+
+        let matches = service.search("PKCE for public clients", None).await.unwrap();
+        assert_eq!(matches[0].id, "pattern-002");
+        assert_eq!(matches[0].name, "OAuth2 PKCE");
+
+        // Compaction keeps the index usable
+        service.compact().await.unwrap();
+        assert_eq!(service.search("PKCE for public clients", None).await.unwrap()[0].id, "pattern-002");
+    }
+}
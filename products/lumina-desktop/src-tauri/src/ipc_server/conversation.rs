@@ -0,0 +1,131 @@
+/**
+ * IPC Conversation - Multi-turn function calls surfaced to the IDE
+ *
+ * DESIGN DECISION: ConversationManager behind a backend trait, follow-ups as protocol messages
+ * WHY: The conversation manager asked follow-up questions nobody could see; the IDE
+ * needs to render "What client name would you like?" and send the answer back
+ *
+ * REASONING CHAIN:
+ * 1. captureVoice transcript → ConversationBackend::start (session per command)
+ * 2. Follow-up (missing param, clarification, confirmation) → `clarificationNeeded` push
+ * 3. IDE replies `clarificationAnswer` with the session id → ConversationBackend::answer
+ * 4. Next follow-up → `clarificationNeeded`, completed call → `conversationComplete`
+ * 5. Transcripts that match no function are plain dictation: nothing is pushed
+ * 6. Trait keeps IpcServer testable without the embedding model
+ * 7. main.rs builds the ConversationManager over the local function registry at startup
+ *    (local_conversation_backend); without the model, transcripts stay plain dictation
+ *
+ * PATTERN: Pattern-IPC-002 (Unified IPC Protocol), Pattern-CONVERSATION-001
+ * RELATED: aetherlight_core::conversation_manager, mod.rs (route_message)
+ */
+
+use super::unified_protocol::UnifiedIpcMessage;
+use aetherlight_core::conversation_manager::{ConversationManager, SystemResponse};
+use aetherlight_core::{FunctionCallGenerator, FunctionRegistry};
+use std::path::Path;
+use std::sync::Arc;
+
+/// User id for sessions started from IDE voice capture (single local user)
+const IPC_USER_ID: &str = "ide";
+
+/// Function registry vector store (inside the desktop data dir)
+const FUNCTION_REGISTRY_FILE: &str = "function_registry.sqlite";
+
+/// Options offered for a confirmation question
+const CONFIRM_OPTIONS: [&str; 2] = ["Yes, go ahead", "No, cancel"];
+
+/**
+ * Multi-turn function call sessions for IDE requests
+ *
+ * Blocking: implementations may run embedding inference; callers use spawn_blocking.
+ */
+pub trait ConversationBackend: Send + Sync {
+    /// Start a session for a transcribed command → (session id, first response)
+    fn start(&self, query: &str) -> Result<(String, SystemResponse), String>;
+
+    /// Feed the user's answer into an existing session
+    fn answer(&self, session_id: &str, answer: &str) -> Result<SystemResponse, String>;
+}
+
+impl ConversationBackend for ConversationManager {
+    fn start(&self, query: &str) -> Result<(String, SystemResponse), String> {
+        self.start_session(IPC_USER_ID.to_string(), query.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    fn answer(&self, session_id: &str, answer: &str) -> Result<SystemResponse, String> {
+        self.process_turn(session_id, answer.to_string())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/**
+ * ConversationManager over the function registry in `data_dir`
+ *
+ * DESIGN DECISION: None (logged) when the embedding model or registry can't be opened
+ * WHY: No transcript can match a function without embeddings; the IPC server still
+ * serves dictation instead of failing to start
+ *
+ * Blocking: loads the ONNX model; call from spawn_blocking.
+ */
+pub fn local_conversation_backend(data_dir: &Path) -> Option<Arc<dyn ConversationBackend>> {
+    let (model_path, tokenizer_path) = aetherlight_core::model_paths(data_dir.join("models"));
+    let registry = match FunctionRegistry::new(model_path, tokenizer_path, data_dir.join(FUNCTION_REGISTRY_FILE)) {
+        Ok(registry) => Arc::new(registry),
+        Err(e) => {
+            tracing::warn!(error = %e, "Function registry unavailable, voice commands stay dictation");
+            return None;
+        }
+    };
+
+    let generator = Arc::new(FunctionCallGenerator::new(Arc::clone(&registry)));
+    Some(Arc::new(ConversationManager::new(generator, registry)))
+}
+
+/**
+ * Protocol message for a conversation response
+ *
+ * `id` is the request id when replying to `clarificationAnswer`, a fresh id for pushes.
+ */
+pub fn conversation_message(id: String, session_id: String, response: SystemResponse) -> UnifiedIpcMessage {
+    match response {
+        SystemResponse::AskForMissingParam { prompt, examples, .. } => {
+            UnifiedIpcMessage::ClarificationNeeded { id, session_id, question: prompt, options: examples }
+        }
+        SystemResponse::Clarification { question, options } => {
+            UnifiedIpcMessage::ClarificationNeeded { id, session_id, question, options }
+        }
+        SystemResponse::Confirmation { summary, .. } => UnifiedIpcMessage::ClarificationNeeded {
+            id,
+            session_id,
+            question: summary,
+            options: CONFIRM_OPTIONS.iter().map(|o| o.to_string()).collect(),
+        },
+        SystemResponse::Execution { function_id, result } => {
+            UnifiedIpcMessage::ConversationComplete { id, session_id, function_id, result }
+        }
+        SystemResponse::Error { message, .. } => UnifiedIpcMessage::error(id, message),
+    }
+}
+
+/**
+ * Push for the first turn of a voice command, if it is a function call
+ *
+ * None when the transcript matched no function (or the backend failed): the
+ * transcript is ordinary dictation and the IDE already has it in captureVoiceResponse.
+ */
+pub fn start_conversation(backend: &dyn ConversationBackend, transcript: &str) -> Option<UnifiedIpcMessage> {
+    match backend.start(transcript) {
+        Ok((_, SystemResponse::Error { message, .. })) => {
+            tracing::debug!(%message, "Transcript is not a function call");
+            None
+        }
+        Ok((session_id, response)) => {
+            Some(conversation_message(uuid::Uuid::new_v4().to_string(), session_id, response))
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to start conversation");
+            None
+        }
+    }
+}
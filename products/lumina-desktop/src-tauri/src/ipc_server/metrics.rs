@@ -0,0 +1,91 @@
+/**
+ * IPC Server Metrics - Connection and message counters
+ *
+ * DESIGN DECISION: Lock-free atomic counters + per-type map, snapshot on demand
+ * WHY: Operators can't see active connections, routed message mix, or error rates from logs alone
+ *
+ * REASONING CHAIN:
+ * 1. Every accept/disconnect/route/parse/send touches a counter (hot path)
+ * 2. Atomics keep scalar counters lock-free across client tasks
+ * 3. Per-message-type counts keyed by protocol type name ("captureVoice", "ping", ...)
+ * 4. snapshot() copies everything into a serializable struct
+ * 5. Snapshot exposed via `stats` message so diagnostics tools can query it locally
+ *
+ * PATTERN: Pattern-IPC-001 (WebSocket IPC Server)
+ * RELATED: ipc_server/mod.rs, aetherlight_core::realtime_sync::ServerStats
+ * PERFORMANCE: One relaxed atomic add per event; one short mutex hold per routed message
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/**
+ * Live counters shared by all client tasks
+ */
+#[derive(Debug, Default)]
+pub struct IpcMetrics {
+    connections_opened: AtomicU64,
+    connections_closed: AtomicU64,
+    parse_errors: AtomicU64,
+    send_failures: AtomicU64,
+    messages_by_type: Mutex<HashMap<String, u64>>,
+}
+
+/**
+ * Point-in-time copy of IpcMetrics (sent in `statsResponse`)
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IpcMetricsSnapshot {
+    pub connections_opened: u64,
+    pub connections_closed: u64,
+    /// Opened minus closed
+    pub active_connections: u64,
+    /// Routed messages keyed by protocol type name
+    pub messages_by_type: HashMap<String, u64>,
+    pub parse_errors: u64,
+    pub send_failures: u64,
+}
+
+impl IpcMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_connection_opened(&self) {
+        self.connections_opened.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_connection_closed(&self) {
+        self.connections_closed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_parse_error(&self) {
+        self.parse_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_send_failure(&self) {
+        self.send_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a routed message by its protocol type name
+    pub fn record_message(&self, message_type: &str) {
+        let mut counts = self.messages_by_type.lock().unwrap();
+        *counts.entry(message_type.to_string()).or_insert(0) += 1;
+    }
+
+    pub fn snapshot(&self) -> IpcMetricsSnapshot {
+        let connections_opened = self.connections_opened.load(Ordering::Relaxed);
+        let connections_closed = self.connections_closed.load(Ordering::Relaxed);
+
+        IpcMetricsSnapshot {
+            connections_opened,
+            connections_closed,
+            active_connections: connections_opened.saturating_sub(connections_closed),
+            messages_by_type: self.messages_by_type.lock().unwrap().clone(),
+            parse_errors: self.parse_errors.load(Ordering::Relaxed),
+            send_failures: self.send_failures.load(Ordering::Relaxed),
+        }
+    }
+}
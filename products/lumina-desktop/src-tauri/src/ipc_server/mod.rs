@@ -18,20 +18,45 @@
  */
 
 use tokio::net::{TcpListener, TcpStream};
-use tokio_tungstenite::{accept_async, tungstenite::Message};
+use tokio_tungstenite::accept_async;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
 use futures_util::{StreamExt, SinkExt};
 use tokio::sync::broadcast;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use anyhow::Result;
 use uuid::Uuid;
 
 use crate::system_context::types::ContextUpdate;
 
+pub mod conversation;
+pub mod metrics;
+#[cfg(test)]
+pub(crate) mod replay;
 pub mod types;
 pub mod unified_protocol;
+pub mod voice_capture;
 
+pub use conversation::*;
+pub use metrics::*;
 pub use types::*;
 pub use unified_protocol::*;
+pub use voice_capture::*;
+
+/**
+ * Broadcast a recorded analytics event to IPC clients subscribed with analytics: true
+ *
+ * No-op when no client is connected (broadcast send without receivers is not an error here)
+ */
+pub fn notify_event_recorded(
+    sender: &broadcast::Sender<ContextUpdate>,
+    event_type: aetherlight_core::analytics::EventType,
+) {
+    let _ = sender.send(ContextUpdate::EventRecorded(event_type.into()));
+}
 
 /**
  * IpcServer - WebSocket server for context sharing
@@ -49,6 +74,44 @@ pub use unified_protocol::*;
 pub struct IpcServer {
     /// Broadcast channel for context updates
     update_rx: Arc<broadcast::Sender<ContextUpdate>>,
+    /// Shared state handed to every client task
+    route: RouteContext,
+}
+
+/**
+ * State needed to route client messages (cloned into each client task)
+ */
+#[derive(Clone, Default)]
+struct RouteContext {
+    /// Connection/message counters (queried via `stats` message)
+    metrics: Arc<IpcMetrics>,
+    /// Recording + transcription for captureVoice (None = voice capture unavailable)
+    voice: Option<Arc<dyn VoiceCaptureBackend>>,
+    /// Multi-turn function calls for captured commands (None = transcripts only)
+    conversation: Option<Arc<dyn ConversationBackend>>,
+    /// Push subscriptions of the client this context serves (fresh per connection)
+    subscriptions: Arc<Subscriptions>,
+    /// Write-task queue of the client this context serves (replies from spawned handlers)
+    outbound: Option<tokio::sync::mpsc::UnboundedSender<Message>>,
+    /// Shared settings.json writer (None = settings sync unavailable)
+    settings: Option<crate::SettingsStore>,
+}
+
+/**
+ * Per-client opt-in push streams (set by `subscribe`)
+ *
+ * DESIGN DECISION: Only analytics pushes are gated
+ * WHY: Voice/focus updates predate `subscribe` and existing IDEs rely on receiving them
+ */
+#[derive(Debug, Default)]
+struct Subscriptions {
+    analytics: AtomicBool,
+}
+
+impl Subscriptions {
+    fn analytics(&self) -> bool {
+        self.analytics.load(Ordering::Relaxed)
+    }
 }
 
 impl IpcServer {
@@ -60,9 +123,33 @@ impl IpcServer {
     pub fn new(update_rx: broadcast::Sender<ContextUpdate>) -> Self {
         Self {
             update_rx: Arc::new(update_rx),
+            route: RouteContext::default(),
         }
     }
 
+    /// Serve captureVoice requests with the given recording/transcription backend
+    pub fn with_voice_backend(mut self, backend: Arc<dyn VoiceCaptureBackend>) -> Self {
+        self.route.voice = Some(backend);
+        self
+    }
+
+    /// Turn captured commands into function call conversations (clarificationNeeded pushes)
+    pub fn with_conversation_backend(mut self, backend: Arc<dyn ConversationBackend>) -> Self {
+        self.route.conversation = Some(backend);
+        self
+    }
+
+    /// Persist syncSettings through the app's settings store (serialized with other settings writers)
+    pub fn with_settings_store(mut self, store: crate::SettingsStore) -> Self {
+        self.route.settings = Some(store);
+        self
+    }
+
+    /// Snapshot of connection/message counters
+    pub fn metrics(&self) -> IpcMetricsSnapshot {
+        self.route.metrics.snapshot()
+    }
+
     /**
      * Start WebSocket server (spawns background task)
      *
@@ -78,35 +165,43 @@ impl IpcServer {
      * 6. Forward context updates to all clients
      *
      * @param addr - Address to bind (default: "127.0.0.1:43215")
+     * @returns Bound address (resolves port 0 to the assigned port)
      */
-    pub async fn start(&self, addr: &str) -> Result<()> {
+    pub async fn start(&self, addr: &str) -> Result<SocketAddr> {
         let listener = TcpListener::bind(addr).await?;
-        println!("🌐 IPC Server listening on {}", addr);
+        let local_addr = listener.local_addr()?;
+        tracing::info!(addr = %local_addr, "IPC server listening");
 
         let update_rx = self.update_rx.clone();
+        let route = self.route.clone();
 
         tokio::spawn(async move {
             loop {
                 match listener.accept().await {
                     Ok((stream, addr)) => {
-                        println!("✅ New IDE connection from: {}", addr);
+                        tracing::info!(peer = %addr, "IDE connected");
+                        route.metrics.record_connection_opened();
 
                         let update_rx_clone = update_rx.clone();
+                        let route_clone = route.clone();
 
                         tokio::spawn(async move {
-                            if let Err(e) = Self::handle_client(stream, update_rx_clone).await {
-                                eprintln!("❌ Client error ({}): {}", addr, e);
+                            let metrics = route_clone.metrics.clone();
+                            if let Err(e) = Self::handle_client(stream, update_rx_clone, route_clone).await {
+                                tracing::warn!(peer = %addr, error = %e, "IDE client error");
                             }
+                            metrics.record_connection_closed();
+                            tracing::info!(peer = %addr, "IDE disconnected");
                         });
                     }
                     Err(e) => {
-                        eprintln!("❌ Failed to accept connection: {}", e);
+                        tracing::error!(error = %e, "Failed to accept IPC connection");
                     }
                 }
             }
         });
 
-        Ok(())
+        Ok(local_addr)
     }
 
     /**
@@ -120,13 +215,16 @@ impl IpcServer {
      * 2. Subscribe to broadcast channel
      * 3. Loop: receive context update → serialize to JSON → send to client
      * 4. Handle client messages (ping/pong, queries)
-     * 5. Close on disconnect
+     * 5. Close on disconnect: read loop fires `shutdown`, write task flushes queued
+     *    replies and exits (the broadcast branch never ends on its own, so dropping
+     *    the mpsc sender alone would leave the task - and the connection count - alive)
      *
      * PERFORMANCE: <5ms update delivery (localhost WebSocket)
      */
     async fn handle_client(
         stream: TcpStream,
         update_rx: Arc<broadcast::Sender<ContextUpdate>>,
+        route: RouteContext,
     ) -> Result<()> {
         // Upgrade to WebSocket
         let ws_stream = accept_async(stream).await?;
@@ -135,52 +233,67 @@ impl IpcServer {
         // Create channel for sending messages to the write task
         let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Message>();
 
+        // Subscriptions and reply queue belong to this connection only
+        let route = RouteContext {
+            subscriptions: Arc::new(Subscriptions::default()),
+            outbound: Some(tx.clone()),
+            ..route
+        };
+
+        // Read loop signals the write task to stop when the client goes away
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
         // Subscribe to context updates
         let mut update_rx_sub = update_rx.subscribe();
 
         // Spawn task to handle all writes (both updates and responses)
+        let write_metrics = route.metrics.clone();
+        let subscriptions = route.subscriptions.clone();
         let write_handle = tokio::spawn(async move {
             let mut write = write;
 
             loop {
                 tokio::select! {
-                    // Handle context updates
-                    Ok(update) = update_rx_sub.recv() => {
-                        // Convert ContextUpdate to IpcResponse format
-                        let ipc_response = match update {
-                            ContextUpdate::VoiceRecording(recording_state) => {
-                                types::IpcResponse::ContextUpdate {
-                                    id: uuid::Uuid::new_v4().to_string(),
-                                    update_type: "VoiceRecording".to_string(),
-                                    recording_state: Some(recording_state),
-                                }
-                            }
-                            ContextUpdate::FocusVoicePanel => {
-                                types::IpcResponse::FocusVoicePanel
+                    // Read loop ended: flush replies already queued, then stop
+                    _ = &mut shutdown_rx => {
+                        while let Ok(msg) = rx.try_recv() {
+                            if write.send(msg).await.is_err() {
+                                write_metrics.record_send_failure();
+                                break;
                             }
-                            _ => {
-                                // GitChanged, FileChanged, DocChanged will be added later
+                        }
+                        break;
+                    }
+                    // Handle context updates
+                    update = update_rx_sub.recv() => {
+                        let update = match update {
+                            Ok(update) => update,
+                            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                                tracing::warn!(skipped, "IDE client lagging, dropped context updates");
                                 continue;
                             }
+                            Err(broadcast::error::RecvError::Closed) => break,
                         };
-
-                        let json = match serde_json::to_string(&ipc_response) {
-                            Ok(json) => json,
-                            Err(e) => {
-                                eprintln!("❌ Failed to serialize update: {}", e);
+                        let json = match Self::push_json(update, &subscriptions) {
+                            Some(Ok(json)) => json,
+                            Some(Err(e)) => {
+                                tracing::error!(error = %e, "Failed to serialize context update");
                                 continue;
                             }
+                            None => continue,
                         };
 
                         if let Err(e) = write.send(Message::Text(json)).await {
-                            eprintln!("❌ Failed to send update to client: {}", e);
+                            tracing::warn!(error = %e, "Failed to send context update to client");
+                            write_metrics.record_send_failure();
                             break;
                         }
                     }
                     // Handle outgoing messages from main loop
                     Some(msg) = rx.recv() => {
                         if let Err(e) = write.send(msg).await {
-                            eprintln!("❌ Failed to send message to client: {}", e);
+                            tracing::warn!(error = %e, "Failed to send message to client");
+                            write_metrics.record_send_failure();
                             break;
                         }
                     }
@@ -193,29 +306,42 @@ impl IpcServer {
         while let Some(msg) = read.next().await {
             match msg {
                 Ok(Message::Text(text)) => {
-                    println!("📩 Received from client: {}", text);
+                    tracing::trace!(message = %text, "Received from client");
 
                     // Parse unified protocol message
                     match serde_json::from_str::<UnifiedIpcMessage>(&text) {
                         Ok(parsed_msg) => {
                             // Route message to appropriate handler
-                            let response = Self::route_message(parsed_msg).await;
+                            let response = Self::route_message(parsed_msg, &route).await;
 
                             // Send response back to client via channel
                             if let Some(resp) = response {
                                 let json = match serde_json::to_string(&resp) {
                                     Ok(json) => json,
                                     Err(e) => {
-                                        eprintln!("❌ Failed to serialize response: {}", e);
+                                        tracing::error!(error = %e, "Failed to serialize response");
                                         continue;
                                     }
                                 };
 
                                 let _ = tx.send(Message::Text(json));
+
+                                // Version mismatch: client can't talk to us. Queue a close frame
+                                // behind the rejection; leaving the loop stops the write task,
+                                // which flushes both before the socket is dropped
+                                if let UnifiedIpcMessage::UnsupportedVersion { requested, .. } = resp {
+                                    tracing::warn!(requested, supported = ?SUPPORTED_PROTOCOL_VERSIONS, "Rejected client protocol version");
+                                    let _ = tx.send(Message::Close(Some(CloseFrame {
+                                        code: CloseCode::Protocol,
+                                        reason: "unsupported protocol version".into(),
+                                    })));
+                                    break;
+                                }
                             }
                         }
                         Err(e) => {
-                            eprintln!("❌ Failed to parse message: {}", e);
+                            tracing::warn!(error = %e, "Failed to parse IPC message");
+                            route.metrics.record_parse_error();
                             let error = UnifiedIpcMessage::error(
                                 "unknown".to_string(),
                                 format!("Invalid message format: {}", e)
@@ -231,24 +357,51 @@ impl IpcServer {
                     let _ = tx.send(Message::Pong(ping));
                 }
                 Ok(Message::Close(_)) => {
-                    println!("👋 Client disconnected");
+                    tracing::debug!("Client sent close frame");
                     break;
                 }
                 Err(e) => {
-                    eprintln!("❌ WebSocket error: {}", e);
+                    tracing::warn!(error = %e, "WebSocket error");
                     break;
                 }
                 _ => {}
             }
         }
 
-        // Clean up write task
+        // Stop the write task (flushes pending replies) before reporting disconnect
+        let _ = shutdown_tx.send(());
         drop(tx);
         let _ = write_handle.await;
 
         Ok(())
     }
 
+    /**
+     * Serialize a broadcast update for one client (None = not forwarded to this client)
+     *
+     * DESIGN DECISION: Voice/focus keep the legacy IpcResponse format; analytics use
+     * the unified protocol and require an `analytics: true` subscription
+     */
+    fn push_json(update: ContextUpdate, subscriptions: &Subscriptions) -> Option<serde_json::Result<String>> {
+        match update {
+            ContextUpdate::VoiceRecording(recording_state) => {
+                Some(serde_json::to_string(&types::IpcResponse::ContextUpdate {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    update_type: "VoiceRecording".to_string(),
+                    recording_state: Some(recording_state),
+                }))
+            }
+            ContextUpdate::FocusVoicePanel => {
+                Some(serde_json::to_string(&types::IpcResponse::FocusVoicePanel))
+            }
+            ContextUpdate::EventRecorded(event) if subscriptions.analytics() => {
+                Some(serde_json::to_string(&UnifiedIpcMessage::event_recorded(event)))
+            }
+            // GitChanged, FileChanged, DocChanged will be added later
+            _ => None,
+        }
+    }
+
     /**
      * Route message to appropriate handler
      *
@@ -261,30 +414,76 @@ impl IpcServer {
      * 3. Call appropriate handler function
      * 4. Return response (or None for one-way messages)
      */
-    async fn route_message(msg: UnifiedIpcMessage) -> Option<UnifiedIpcMessage> {
+    async fn route_message(msg: UnifiedIpcMessage, route: &RouteContext) -> Option<UnifiedIpcMessage> {
+        route.metrics.record_message(msg.message_type());
+
         match msg {
+            // ==================== Handshake ====================
+            UnifiedIpcMessage::Hello { id, protocol_version } => {
+                Some(UnifiedIpcMessage::negotiate_version(id, protocol_version))
+            }
+
             // ==================== Voice Capture ====================
             UnifiedIpcMessage::CaptureVoiceRequest { id, context } => {
-                println!("🎙️  Processing voice capture request (id: {})", id);
-                Some(Self::handle_voice_capture(id, context).await)
+                tracing::debug!(id = %id, "Processing voice capture request");
+                match (route.voice.clone(), route.outbound.clone()) {
+                    // Capture records for seconds: run it off the read loop so pings and
+                    // other requests keep flowing; reply goes straight to the write task
+                    (Some(backend), Some(outbound)) => {
+                        let conversation = route.conversation.clone();
+                        tokio::spawn(async move {
+                            let response = Self::handle_voice_capture(id, context, Some(&*backend)).await;
+                            let transcript = match &response {
+                                UnifiedIpcMessage::CaptureVoiceResponse { success: true, text, .. } => Some(text.clone()),
+                                _ => None,
+                            };
+                            Self::send_outbound(&outbound, &response);
+
+                            // Follow-up question for commands goes out after the transcript
+                            if let (Some(conversation), Some(transcript)) = (conversation, transcript) {
+                                let push = tokio::task::spawn_blocking(move || {
+                                    start_conversation(&*conversation, &transcript)
+                                })
+                                .await;
+                                if let Ok(Some(push)) = push {
+                                    Self::send_outbound(&outbound, &push);
+                                }
+                            }
+                        });
+                        None
+                    }
+                    (backend, _) => Some(Self::handle_voice_capture(id, context, backend.as_deref()).await),
+                }
+            }
+
+            // ==================== Conversation ====================
+            UnifiedIpcMessage::ClarificationAnswer { id, session_id, answer } => {
+                tracing::debug!(id = %id, session_id = %session_id, "Processing clarification answer");
+                Some(Self::handle_clarification_answer(id, session_id, answer, route.conversation.clone()).await)
             }
 
             // ==================== System Context ====================
             UnifiedIpcMessage::GetFullContext { id } => {
-                println!("📋 Processing get full context request (id: {})", id);
+                tracing::debug!(id = %id, "Processing get full context request");
                 Some(Self::handle_get_full_context(id).await)
             }
 
-            UnifiedIpcMessage::Subscribe { id, git, files, docs } => {
-                println!("🔔 Processing subscribe request (id: {}, git: {}, files: {}, docs: {})", id, git, files, docs);
-                // Subscribe requests don't have a response (subscription is implicit)
+            UnifiedIpcMessage::Subscribe { id, git, files, docs, analytics } => {
+                tracing::debug!(id = %id, git, files, docs, analytics, "Processing subscribe request");
+                route.subscriptions.analytics.store(analytics, Ordering::Relaxed);
+                // Acknowledged with pong (git/files/docs updates are not yet gated)
                 Some(UnifiedIpcMessage::pong(id))
             }
 
             // ==================== Settings Synchronization ====================
             UnifiedIpcMessage::SyncSettings { id, settings } => {
-                println!("⚙️  Processing settings sync from VS Code (id: {})", id);
-                Some(Self::handle_sync_settings(id, settings).await)
+                tracing::debug!(id = %id, "Processing settings sync from VS Code");
+                Some(Self::handle_sync_settings(id, settings, route.settings.as_ref()).await)
+            }
+
+            // ==================== Diagnostics ====================
+            UnifiedIpcMessage::Stats { id } => {
+                Some(UnifiedIpcMessage::StatsResponse { id, stats: route.metrics.snapshot() })
             }
 
             // ==================== Connection Management ====================
@@ -294,7 +493,7 @@ impl IpcServer {
 
             // Other messages (responses, updates) don't need routing (they're outbound only)
             _ => {
-                println!("⚠️  Received outbound-only message type (ignoring)");
+                tracing::debug!("Received outbound-only message type (ignoring)");
                 None
             }
         }
@@ -303,35 +502,86 @@ impl IpcServer {
     /**
      * Handle voice capture request
      *
-     * DESIGN DECISION: Placeholder implementation for now
-     * WHY: Need IPC working first, then integrate real voice capture
+     * DESIGN DECISION: Delegate to VoiceCaptureBackend (real recording + transcription)
+     * WHY: Same recording state and transcription path as the hotkey flow
      *
-     * TODO: Integrate with actual voice capture system
-     * - Start audio recording with cpal
-     * - Transcribe with Whisper.cpp or OpenAI API
-     * - Match against pattern library
-     * - Return real transcription + confidence
+     * REASONING CHAIN:
+     * 1. No backend configured → structured error (voice capture unavailable)
+     * 2. Backend records, transcribes, matches pattern
+     * 3. Recording already active (hotkey) → RECORDING_IN_PROGRESS error
+     * 4. Map result/failure onto captureVoiceResponse fields
      */
-    async fn handle_voice_capture(id: String, context: CodeContext) -> UnifiedIpcMessage {
-        println!("🎤 Voice capture for file: {} (language: {})", context.current_file, context.language);
+    async fn handle_voice_capture(
+        id: String,
+        context: CodeContext,
+        backend: Option<&dyn VoiceCaptureBackend>,
+    ) -> UnifiedIpcMessage {
+        tracing::info!(file = %context.current_file, language = %context.language, "Voice capture requested");
 
-        // Placeholder: Simulate voice capture
-        // TODO: Replace with real voice capture implementation
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        let result = match backend {
+            Some(backend) => backend.capture(&context).await,
+            None => Err(VoiceCaptureFailure::new(
+                ErrorCode::Unknown,
+                "Voice capture not available",
+            )),
+        };
 
-        UnifiedIpcMessage::CaptureVoiceResponse {
-            id,
-            success: true,
-            text: "[Placeholder] Add authentication with OAuth2".to_string(),
-            confidence: 0.85,
-            pattern: Some(PatternMatch {
-                id: "Pattern-AUTH-001".to_string(),
-                name: "OAuth2 Authentication".to_string(),
-                reasoning: "User mentioned authentication, matched OAuth2 pattern".to_string(),
-                confidence: 0.85,
-            }),
-            error: None,
-            error_code: None,
+        match result {
+            Ok(captured) => UnifiedIpcMessage::CaptureVoiceResponse {
+                id,
+                success: true,
+                text: captured.text,
+                confidence: captured.confidence,
+                pattern: captured.pattern,
+                error: None,
+                error_code: None,
+            },
+            Err(failure) => {
+                tracing::warn!(error = %failure.message, "Voice capture failed");
+                UnifiedIpcMessage::CaptureVoiceResponse {
+                    id,
+                    success: false,
+                    text: String::new(),
+                    confidence: 0.0,
+                    pattern: None,
+                    error: Some(failure.message),
+                    error_code: Some(failure.code),
+                }
+            }
+        }
+    }
+
+    /**
+     * Handle clarificationAnswer: next turn of the function call conversation
+     *
+     * Turn processing may run embedding inference, so it runs on the blocking pool.
+     */
+    async fn handle_clarification_answer(
+        id: String,
+        session_id: String,
+        answer: String,
+        conversation: Option<Arc<dyn ConversationBackend>>,
+    ) -> UnifiedIpcMessage {
+        let Some(conversation) = conversation else {
+            return UnifiedIpcMessage::error(id, "Conversations not available".to_string());
+        };
+
+        let turn_session = session_id.clone();
+        let turn = tokio::task::spawn_blocking(move || conversation.answer(&turn_session, &answer)).await;
+        match turn {
+            Ok(Ok(response)) => conversation_message(id, session_id, response),
+            Ok(Err(e)) => UnifiedIpcMessage::error(id, e),
+            Err(e) => UnifiedIpcMessage::error(id, format!("Conversation turn failed: {}", e)),
+        }
+    }
+
+    /// Queue a message on a client's write task (serialization failures are logged)
+    fn send_outbound(outbound: &tokio::sync::mpsc::UnboundedSender<Message>, message: &UnifiedIpcMessage) {
+        match serde_json::to_string(message) {
+            Ok(json) => {
+                let _ = outbound.send(Message::Text(json));
+            }
+            Err(e) => tracing::error!(error = %e, message_type = message.message_type(), "Failed to serialize outbound message"),
         }
     }
 
@@ -341,7 +591,7 @@ impl IpcServer {
      * TODO: Integrate with SystemContextProvider
      */
     async fn handle_get_full_context(id: String) -> UnifiedIpcMessage {
-        println!("📋 Getting full system context");
+        tracing::debug!("Getting full system context");
 
         // Placeholder: Return error (system context not yet integrated)
         UnifiedIpcMessage::error(
@@ -366,73 +616,37 @@ impl IpcServer {
      *
      * PATTERN: Pattern-SETTINGS-SYNC-001 (VS Code → Desktop Settings Sync)
      */
-    async fn handle_sync_settings(id: String, settings: SettingsSync) -> UnifiedIpcMessage {
-        println!("⚙️  Syncing settings from VS Code:");
-        println!("   - API Key: {}", if settings.openai_api_key.is_empty() { "❌ Empty" } else { "✅ Set" });
-        println!("   - Whisper Model: {}", settings.whisper_model);
-        println!("   - Offline Mode: {}", settings.offline_mode);
-
-        // Load current settings path (returns PathBuf directly, not Result)
-        let settings_path = crate::get_settings_path();
-
-        // Load current settings
-        let mut current_settings = match crate::get_settings() {
-            Ok(settings) => settings,
-            Err(e) => {
-                let error_msg = format!("Failed to load current settings: {}", e);
-                eprintln!("❌ {}", error_msg);
-                return UnifiedIpcMessage::SyncSettingsResponse {
-                    id,
-                    success: false,
-                    error: Some(error_msg),
-                };
-            }
-        };
+    async fn handle_sync_settings(
+        id: String,
+        settings: SettingsSync,
+        store: Option<&crate::SettingsStore>,
+    ) -> UnifiedIpcMessage {
+        tracing::info!(
+            api_key_set = !settings.openai_api_key.is_empty(),
+            whisper_model = %settings.whisper_model,
+            offline_mode = settings.offline_mode,
+            "Syncing settings from VS Code"
+        );
 
         // Merge settings: VS Code overrides desktop defaults
-        current_settings.openai_api_key = settings.openai_api_key;
         // Note: whisper_model and offline_mode fields removed per user feedback (Pattern-UI-007)
+        // Shared read-modify-write serializes with other settings writers and replaces the file atomically
+        let result = match store {
+            Some(store) => store.update(|current| current.openai_api_key = settings.openai_api_key),
+            None => Err("Settings store not available".to_string()),
+        };
 
-        // Save merged settings directly to file (no hotkey re-registration needed for sync)
-        // Create directory if it doesn't exist
-        if let Some(parent) = settings_path.parent() {
-            if let Err(e) = std::fs::create_dir_all(parent) {
-                let error_msg = format!("Failed to create settings directory: {}", e);
-                eprintln!("❌ {}", error_msg);
-                return UnifiedIpcMessage::SyncSettingsResponse {
+        match result {
+            Ok(_) => {
+                tracing::info!(path = %crate::get_settings_path().display(), "Settings synced and saved");
+                UnifiedIpcMessage::SyncSettingsResponse {
                     id,
-                    success: false,
-                    error: Some(error_msg),
-                };
-            }
-        }
-
-        // Serialize and write to file
-        match serde_json::to_string_pretty(&current_settings) {
-            Ok(json) => {
-                match std::fs::write(&settings_path, json) {
-                    Ok(_) => {
-                        println!("✅ Settings synced and saved to: {}", settings_path.display());
-                        UnifiedIpcMessage::SyncSettingsResponse {
-                            id,
-                            success: true,
-                            error: None,
-                        }
-                    }
-                    Err(e) => {
-                        let error_msg = format!("Failed to write settings file: {}", e);
-                        eprintln!("❌ {}", error_msg);
-                        UnifiedIpcMessage::SyncSettingsResponse {
-                            id,
-                            success: false,
-                            error: Some(error_msg),
-                        }
-                    }
+                    success: true,
+                    error: None,
                 }
             }
-            Err(e) => {
-                let error_msg = format!("Failed to serialize settings: {}", e);
-                eprintln!("❌ {}", error_msg);
+            Err(error_msg) => {
+                tracing::error!("{}", error_msg);
                 UnifiedIpcMessage::SyncSettingsResponse {
                     id,
                     success: false,
@@ -468,4 +682,426 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    /**
+     * Test: Routing messages increments per-type counters; stats reports them
+     */
+    #[tokio::test]
+    async fn test_route_message_updates_metrics() {
+        let route = RouteContext::default();
+
+        IpcServer::route_message(UnifiedIpcMessage::Ping { id: "1".to_string() }, &route).await;
+        IpcServer::route_message(UnifiedIpcMessage::Ping { id: "2".to_string() }, &route).await;
+        IpcServer::route_message(UnifiedIpcMessage::GetFullContext { id: "3".to_string() }, &route).await;
+        route.metrics.record_parse_error();
+
+        let response = IpcServer::route_message(UnifiedIpcMessage::Stats { id: "4".to_string() }, &route).await;
+
+        match response {
+            Some(UnifiedIpcMessage::StatsResponse { id, stats }) => {
+                assert_eq!(id, "4");
+                assert_eq!(stats.messages_by_type.get("ping"), Some(&2));
+                assert_eq!(stats.messages_by_type.get("getFullContext"), Some(&1));
+                assert_eq!(stats.messages_by_type.get("stats"), Some(&1));
+                assert_eq!(stats.parse_errors, 1);
+                assert_eq!(stats.send_failures, 0);
+            }
+            other => panic!("Expected statsResponse, got {:?}", other),
+        }
+    }
+
+    /**
+     * Test: Client speaking a supported protocol version is accepted
+     */
+    #[tokio::test]
+    async fn test_hello_matching_version_accepted() {
+        let route = RouteContext::default();
+        let hello: UnifiedIpcMessage = serde_json::from_str(
+            &format!(r#"{{"type":"hello","id":"h1","protocol_version":{}}}"#, PROTOCOL_VERSION)
+        ).unwrap();
+
+        match IpcServer::route_message(hello, &route).await {
+            Some(UnifiedIpcMessage::HelloAck { id, protocol_version }) => {
+                assert_eq!(id, "h1");
+                assert_eq!(protocol_version, PROTOCOL_VERSION);
+            }
+            other => panic!("Expected helloAck, got {:?}", other),
+        }
+    }
+
+    /**
+     * Test: Too-old client is rejected with the supported version list
+     */
+    #[tokio::test]
+    async fn test_hello_old_version_rejected() {
+        let route = RouteContext::default();
+        let hello = UnifiedIpcMessage::Hello { id: "h2".to_string(), protocol_version: 0 };
+
+        let response = IpcServer::route_message(hello, &route).await.unwrap();
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json["type"], "unsupportedVersion");
+        assert_eq!(json["requested"], 0);
+        assert_eq!(json["supported"], serde_json::json!(SUPPORTED_PROTOCOL_VERSIONS));
+    }
+
+    type TestClient = tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<TcpStream>>;
+
+    async fn send_json(client: &mut TestClient, json: &str) {
+        client.send(Message::Text(json.to_string())).await.unwrap();
+    }
+
+    async fn next_json(client: &mut TestClient) -> serde_json::Value {
+        loop {
+            let msg = timeout(Duration::from_secs(2), client.next())
+                .await
+                .expect("timed out waiting for IPC message")
+                .unwrap()
+                .unwrap();
+            if let Message::Text(text) = msg {
+                return serde_json::from_str(&text).unwrap();
+            }
+        }
+    }
+
+    /**
+     * Test: Recording an event pushes eventRecorded to analytics subscribers only
+     */
+    #[tokio::test]
+    async fn test_event_recorded_pushed_to_analytics_subscriber() {
+        let (tx, _rx) = broadcast::channel(100);
+        let server = IpcServer::new(tx.clone());
+        let addr = server.start("127.0.0.1:0").await.unwrap();
+
+        let (mut subscriber, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr)).await.unwrap();
+        let (mut bystander, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr)).await.unwrap();
+
+        send_json(&mut subscriber, r#"{"type":"subscribe","id":"s1","git":false,"files":false,"docs":false,"analytics":true}"#).await;
+        assert_eq!(next_json(&mut subscriber).await["type"], "pong");
+        // Older clients omit the analytics flag
+        send_json(&mut bystander, r#"{"type":"subscribe","id":"s2","git":true,"files":true,"docs":true}"#).await;
+        assert_eq!(next_json(&mut bystander).await["type"], "pong");
+
+        notify_event_recorded(&tx, aetherlight_core::analytics::EventType::PatternMatch);
+
+        let pushed = next_json(&mut subscriber).await;
+        assert_eq!(pushed["type"], "eventRecorded");
+        assert_eq!(pushed["event_type"], "pattern_match");
+        assert_eq!(pushed["time_saved_minutes"], 10);
+
+        // Bystander's next message is the reply to its ping, not the analytics push
+        send_json(&mut bystander, r#"{"type":"ping","id":"p1"}"#).await;
+        let reply = next_json(&mut bystander).await;
+        assert_eq!(reply["type"], "pong");
+        assert_eq!(reply["id"], "p1");
+    }
+
+    /**
+     * Test: Rejected client gets unsupportedVersion, then a protocol close frame, then EOF
+     */
+    #[tokio::test]
+    async fn test_unsupported_version_closes_socket() {
+        let (tx, _rx) = broadcast::channel(100);
+        let server = IpcServer::new(tx);
+        let addr = server.start("127.0.0.1:0").await.unwrap();
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr)).await.unwrap();
+        send_json(&mut client, r#"{"type":"hello","id":"h1","protocol_version":0}"#).await;
+        assert_eq!(next_json(&mut client).await["type"], "unsupportedVersion");
+
+        let close = timeout(Duration::from_secs(2), client.next())
+            .await
+            .expect("timed out waiting for close frame")
+            .unwrap()
+            .unwrap();
+        match close {
+            Message::Close(Some(frame)) => assert_eq!(frame.code, CloseCode::Protocol),
+            other => panic!("Expected close frame, got {:?}", other),
+        }
+
+        // Server side is gone: stream ends instead of hanging
+        let end = timeout(Duration::from_secs(2), client.next()).await.expect("socket left open");
+        assert!(matches!(end, None | Some(Err(_))));
+    }
+
+    /**
+     * Test: Disconnecting a client ends its tasks and drops active_connections
+     */
+    #[tokio::test]
+    async fn test_disconnect_decrements_active_connections() {
+        let (tx, _rx) = broadcast::channel(100);
+        let server = IpcServer::new(tx);
+        let addr = server.start("127.0.0.1:0").await.unwrap();
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr)).await.unwrap();
+        send_json(&mut client, r#"{"type":"ping","id":"p1"}"#).await;
+        assert_eq!(next_json(&mut client).await["type"], "pong");
+        assert_eq!(server.metrics().active_connections, 1);
+
+        client.close(None).await.unwrap();
+        drop(client);
+
+        timeout(Duration::from_secs(2), async {
+            while server.metrics().active_connections != 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("connection still counted as active after disconnect");
+        assert_eq!(server.metrics().connections_closed, 1);
+    }
+
+    /// Backend returning a fixed transcript (no microphone / network)
+    struct MockTranscriber {
+        text: String,
+    }
+
+    impl VoiceCaptureBackend for MockTranscriber {
+        fn capture<'a>(
+            &'a self,
+            _context: &'a CodeContext,
+        ) -> futures_util::future::BoxFuture<'a, std::result::Result<CapturedVoice, VoiceCaptureFailure>> {
+            Box::pin(async move {
+                Ok(CapturedVoice {
+                    text: self.text.clone(),
+                    confidence: 0.9,
+                    pattern: None,
+                })
+            })
+        }
+    }
+
+    /// Backend that blocks until released (simulates the recording window)
+    struct GatedTranscriber {
+        release: Arc<tokio::sync::Notify>,
+    }
+
+    impl VoiceCaptureBackend for GatedTranscriber {
+        fn capture<'a>(
+            &'a self,
+            _context: &'a CodeContext,
+        ) -> futures_util::future::BoxFuture<'a, std::result::Result<CapturedVoice, VoiceCaptureFailure>> {
+            Box::pin(async move {
+                self.release.notified().await;
+                Ok(CapturedVoice { text: "done".to_string(), confidence: 0.9, pattern: None })
+            })
+        }
+    }
+
+    fn capture_request(id: &str) -> UnifiedIpcMessage {
+        UnifiedIpcMessage::CaptureVoiceRequest {
+            id: id.to_string(),
+            context: CodeContext {
+                language: "rust".to_string(),
+                current_file: "src/main.rs".to_string(),
+                cursor_position: CursorPosition { line: 1, character: 0 },
+                surrounding_code: String::new(),
+            },
+        }
+    }
+
+    async fn next_outbound(rx: &mut tokio::sync::mpsc::UnboundedReceiver<Message>) -> UnifiedIpcMessage {
+        match timeout(Duration::from_secs(2), rx.recv()).await.expect("timed out waiting for reply") {
+            Some(Message::Text(json)) => serde_json::from_str(&json).unwrap(),
+            other => panic!("Expected text reply, got {:?}", other),
+        }
+    }
+
+    /**
+     * Test: captureVoice response carries backend transcript, not the old placeholder
+     */
+    #[tokio::test]
+    async fn test_voice_capture_uses_backend_transcript() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let route = RouteContext {
+            voice: Some(Arc::new(MockTranscriber { text: "add retry logic to the client".to_string() })),
+            outbound: Some(tx),
+            ..Default::default()
+        };
+
+        assert!(IpcServer::route_message(capture_request("v1"), &route).await.is_none());
+
+        match next_outbound(&mut rx).await {
+            UnifiedIpcMessage::CaptureVoiceResponse { id, success, text, .. } => {
+                assert_eq!(id, "v1");
+                assert!(success);
+                assert_eq!(text, "add retry logic to the client");
+                assert!(!text.contains("[Placeholder]"));
+            }
+            other => panic!("Expected captureVoiceResponse, got {:?}", other),
+        }
+    }
+
+    /**
+     * Conversation for a function with one required parameter (clientName)
+     *
+     * Mirrors ConversationManager: missing param → AskForMissingParam, answer → Execution.
+     */
+    #[derive(Default)]
+    struct MissingParamConversation {
+        sessions: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ConversationBackend for MissingParamConversation {
+        fn start(&self, query: &str) -> std::result::Result<(String, aetherlight_core::conversation_manager::SystemResponse), String> {
+            use aetherlight_core::conversation_manager::SystemResponse;
+
+            if !query.contains("cases") {
+                return Ok((
+                    "s0".to_string(),
+                    SystemResponse::Error { message: "I didn't understand that command".to_string(), recoverable: true },
+                ));
+            }
+            let mut sessions = self.sessions.lock().unwrap();
+            let session_id = format!("s{}", sessions.len() + 1);
+            sessions.push(session_id.clone());
+            Ok((session_id, SystemResponse::AskForMissingParam {
+                param_name: "clientName".to_string(),
+                param_type: "string".to_string(),
+                prompt: "What client name would you like?".to_string(),
+                examples: vec!["John Doe".to_string()],
+            }))
+        }
+
+        fn answer(&self, session_id: &str, answer: &str) -> std::result::Result<aetherlight_core::conversation_manager::SystemResponse, String> {
+            if !self.sessions.lock().unwrap().iter().any(|s| s == session_id) {
+                return Err(format!("Session not found: {}", session_id));
+            }
+            Ok(aetherlight_core::conversation_manager::SystemResponse::Execution {
+                function_id: "legal.searchCases".to_string(),
+                result: serde_json::json!({ "status": "ready_to_execute", "parameters": { "clientName": answer } }),
+            })
+        }
+    }
+
+    /**
+     * Test: Command missing a parameter pushes clarificationNeeded; answering completes the call
+     */
+    #[tokio::test]
+    async fn test_missing_param_clarification_round_trip() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let route = RouteContext {
+            voice: Some(Arc::new(MockTranscriber { text: "find open cases".to_string() })),
+            conversation: Some(Arc::new(MissingParamConversation::default())),
+            outbound: Some(tx),
+            ..Default::default()
+        };
+
+        assert!(IpcServer::route_message(capture_request("v3"), &route).await.is_none());
+        assert!(matches!(next_outbound(&mut rx).await, UnifiedIpcMessage::CaptureVoiceResponse { .. }));
+
+        let session_id = match next_outbound(&mut rx).await {
+            UnifiedIpcMessage::ClarificationNeeded { session_id, question, options, .. } => {
+                assert_eq!(question, "What client name would you like?");
+                assert_eq!(options, vec!["John Doe"]);
+                session_id
+            }
+            other => panic!("Expected clarificationNeeded, got {:?}", other),
+        };
+
+        let answer = UnifiedIpcMessage::ClarificationAnswer {
+            id: "a1".to_string(),
+            session_id: session_id.clone(),
+            answer: "Jane Smith".to_string(),
+        };
+        match IpcServer::route_message(answer, &route).await {
+            Some(UnifiedIpcMessage::ConversationComplete { id, session_id: done, function_id, result }) => {
+                assert_eq!(id, "a1");
+                assert_eq!(done, session_id);
+                assert_eq!(function_id, "legal.searchCases");
+                assert_eq!(result["parameters"]["clientName"], "Jane Smith");
+            }
+            other => panic!("Expected conversationComplete, got {:?}", other),
+        }
+    }
+
+    /**
+     * Test: Clarification round trip over a real WebSocket connection
+     */
+    #[tokio::test]
+    async fn test_clarification_round_trip_over_websocket() {
+        let (tx, _rx) = broadcast::channel(100);
+        let server = IpcServer::new(tx)
+            .with_voice_backend(Arc::new(MockTranscriber { text: "find open cases".to_string() }))
+            .with_conversation_backend(Arc::new(MissingParamConversation::default()));
+        let addr = server.start("127.0.0.1:0").await.unwrap();
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr)).await.unwrap();
+
+        send_json(&mut client, &serde_json::to_string(&capture_request("v5")).unwrap()).await;
+        let response = next_json(&mut client).await;
+        assert_eq!(response["type"], "captureVoiceResponse");
+        assert_eq!(response["text"], "find open cases");
+
+        let question = next_json(&mut client).await;
+        assert_eq!(question["type"], "clarificationNeeded");
+        assert_eq!(question["question"], "What client name would you like?");
+        let session_id = question["session_id"].as_str().unwrap().to_string();
+
+        let answer = serde_json::json!({
+            "type": "clarificationAnswer",
+            "id": "a2",
+            "session_id": session_id,
+            "answer": "Jane Smith",
+        });
+        send_json(&mut client, &answer.to_string()).await;
+
+        let complete = next_json(&mut client).await;
+        assert_eq!(complete["type"], "conversationComplete");
+        assert_eq!(complete["id"], "a2");
+        assert_eq!(complete["session_id"], session_id.as_str());
+        assert_eq!(complete["function_id"], "legal.searchCases");
+        assert_eq!(complete["result"]["parameters"]["clientName"], "Jane Smith");
+    }
+
+    /**
+     * Test: Dictation that is not a function call gets no follow-up push
+     */
+    #[tokio::test]
+    async fn test_dictation_pushes_no_clarification() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let route = RouteContext {
+            voice: Some(Arc::new(MockTranscriber { text: "add retry logic".to_string() })),
+            conversation: Some(Arc::new(MissingParamConversation::default())),
+            outbound: Some(tx),
+            ..Default::default()
+        };
+
+        assert!(IpcServer::route_message(capture_request("v4"), &route).await.is_none());
+        assert!(matches!(next_outbound(&mut rx).await, UnifiedIpcMessage::CaptureVoiceResponse { .. }));
+        assert!(timeout(Duration::from_millis(200), rx.recv()).await.is_err());
+    }
+
+    /**
+     * Test: In-flight capture doesn't block routing of other messages
+     */
+    #[tokio::test]
+    async fn test_voice_capture_runs_off_read_loop() {
+        let release = Arc::new(tokio::sync::Notify::new());
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let route = RouteContext {
+            voice: Some(Arc::new(GatedTranscriber { release: release.clone() })),
+            outbound: Some(tx),
+            ..Default::default()
+        };
+
+        assert!(IpcServer::route_message(capture_request("v2"), &route).await.is_none());
+
+        // Still recording, yet ping is answered immediately
+        let pong = timeout(
+            Duration::from_millis(500),
+            IpcServer::route_message(UnifiedIpcMessage::Ping { id: "p1".to_string() }, &route),
+        )
+        .await
+        .expect("ping blocked behind voice capture");
+        assert!(matches!(pong, Some(UnifiedIpcMessage::Pong { .. })));
+
+        release.notify_one();
+        match next_outbound(&mut rx).await {
+            UnifiedIpcMessage::CaptureVoiceResponse { id, text, .. } => {
+                assert_eq!(id, "v2");
+                assert_eq!(text, "done");
+            }
+            other => panic!("Expected captureVoiceResponse, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,259 @@
+/**
+ * IPC Replay - Record and replay protocol sessions against a live IpcServer
+ *
+ * DESIGN DECISION: Sessions are JSON files of (sent frame, expected replies) exchanges
+ * WHY: Client/server interop was only checked by hand; a recorded session replayed in
+ * cargo test catches protocol drift (renamed fields, changed replies) as messages evolve
+ *
+ * REASONING CHAIN:
+ * 1. Each exchange sends one frame: a UnifiedIpcMessage, or raw text for malformed input
+ * 2. capture() records whatever the server answers until it goes quiet
+ * 3. Recording files are hand-editable: expected replies match as JSON subsets, so
+ *    volatile fields (serde error text, generated ids) are simply left out
+ * 4. replay() sends each frame and waits for exactly the expected number of replies
+ * 5. First mismatch fails with the exchange index, expected and actual JSON
+ *
+ * PATTERN: Pattern-IPC-002 (Unified IPC Protocol)
+ * RELATED: mod.rs (IpcServer), unified_protocol.rs, tests/fixtures/ipc/
+ */
+
+use anyhow::{anyhow, bail, Context, Result};
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use super::unified_protocol::UnifiedIpcMessage;
+
+/// Longest wait for an expected reply during replay
+const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Silence after which capture() considers the server done answering a frame
+const CAPTURE_IDLE: Duration = Duration::from_millis(200);
+
+type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Frame sent by the client (untagged: `{"raw": ...}` for text that isn't a valid message)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Frame {
+    Message(Box<UnifiedIpcMessage>),
+    Raw { raw: String },
+}
+
+impl Frame {
+    fn to_text(&self) -> Result<String> {
+        match self {
+            Frame::Message(message) => Ok(serde_json::to_string(message)?),
+            Frame::Raw { raw } => Ok(raw.clone()),
+        }
+    }
+}
+
+/// One request and the replies it must produce, in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Exchange {
+    pub send: Frame,
+    #[serde(default)]
+    pub expect: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Recording {
+    pub exchanges: Vec<Exchange>,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read recording {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Invalid recording {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Failed to write recording {}", path.display()))
+    }
+}
+
+/**
+ * Record a session: send each frame and keep every reply the server makes to it
+ */
+pub(crate) async fn capture(addr: SocketAddr, frames: Vec<Frame>) -> Result<Recording> {
+    let mut client = connect(addr).await?;
+    let mut exchanges = Vec::with_capacity(frames.len());
+
+    for send in frames {
+        client.send(Message::Text(send.to_text()?)).await?;
+
+        let mut expect = Vec::new();
+        while let Ok(reply) = timeout(CAPTURE_IDLE, next_json(&mut client)).await {
+            expect.push(reply?);
+        }
+        exchanges.push(Exchange { send, expect });
+    }
+
+    let _ = client.close(None).await;
+    Ok(Recording { exchanges })
+}
+
+/**
+ * Replay a session, failing on the first reply that doesn't match the recording
+ */
+pub(crate) async fn replay(addr: SocketAddr, recording: &Recording) -> Result<()> {
+    let mut client = connect(addr).await?;
+
+    for (index, exchange) in recording.exchanges.iter().enumerate() {
+        client.send(Message::Text(exchange.send.to_text()?)).await?;
+
+        for expected in &exchange.expect {
+            let actual = timeout(REPLY_TIMEOUT, next_json(&mut client))
+                .await
+                .map_err(|_| anyhow!("Exchange {}: timed out waiting for {}", index, expected))??;
+            if !json_matches(&actual, expected) {
+                bail!("Exchange {}: expected {}, got {}", index, expected, actual);
+            }
+        }
+    }
+
+    let _ = client.close(None).await;
+    Ok(())
+}
+
+/// Objects match when every expected key matches (extra actual keys are ignored)
+fn json_matches(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => expected
+            .iter()
+            .all(|(key, value)| actual.get(key).is_some_and(|a| json_matches(a, value))),
+        (Value::Array(actual), Value::Array(expected)) => {
+            actual.len() == expected.len()
+                && actual.iter().zip(expected).all(|(a, e)| json_matches(a, e))
+        }
+        _ => actual == expected,
+    }
+}
+
+async fn connect(addr: SocketAddr) -> Result<Client> {
+    let (client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr)).await?;
+    Ok(client)
+}
+
+/// Next text frame as JSON (control frames are skipped)
+async fn next_json(client: &mut Client) -> Result<Value> {
+    loop {
+        match client.next().await {
+            Some(Ok(Message::Text(text))) => return Ok(serde_json::from_str(&text)?),
+            Some(Ok(Message::Close(_))) | None => bail!("Server closed the connection"),
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc_server::{CapturedVoice, CodeContext, IpcServer, VoiceCaptureBackend, VoiceCaptureFailure};
+    use futures_util::future::BoxFuture;
+    use serde_json::json;
+    use std::sync::Arc;
+    use tokio::sync::broadcast;
+
+    /// Same backend the fixture was recorded against (fixed transcript)
+    struct FixtureTranscriber;
+
+    impl VoiceCaptureBackend for FixtureTranscriber {
+        fn capture<'a>(
+            &'a self,
+            _context: &'a CodeContext,
+        ) -> BoxFuture<'a, std::result::Result<CapturedVoice, VoiceCaptureFailure>> {
+            Box::pin(async {
+                Ok(CapturedVoice { text: "add retry logic".to_string(), confidence: 0.9, pattern: None })
+            })
+        }
+    }
+
+    async fn start_server() -> SocketAddr {
+        let (tx, _rx) = broadcast::channel(100);
+        IpcServer::new(tx)
+            .with_voice_backend(Arc::new(FixtureTranscriber))
+            .start("127.0.0.1:0")
+            .await
+            .unwrap()
+    }
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ipc").join(name)
+    }
+
+    #[test]
+    fn test_json_matches_subset() {
+        let actual = json!({"type": "error", "id": "unknown", "message": "Invalid message format: eof"});
+
+        assert!(json_matches(&actual, &json!({"type": "error", "id": "unknown"})));
+        assert!(!json_matches(&actual, &json!({"type": "pong"})));
+        assert!(!json_matches(&actual, &json!({"type": "error", "code": 1})));
+        assert!(!json_matches(&json!([1, 2]), &json!([1])));
+    }
+
+    /**
+     * Test: Recorded session (ping, subscribe, captureVoice, malformed input) replays cleanly
+     */
+    #[tokio::test]
+    async fn test_replay_basic_session_fixture() {
+        let recording = Recording::load(&fixture("basic_session.json")).unwrap();
+        let sent: Vec<&str> = recording
+            .exchanges
+            .iter()
+            .map(|e| match &e.send {
+                Frame::Message(message) => message.message_type(),
+                Frame::Raw { .. } => "raw",
+            })
+            .collect();
+        assert_eq!(sent, vec!["hello", "ping", "subscribe", "captureVoice", "raw", "raw"]);
+
+        replay(start_server().await, &recording).await.unwrap();
+    }
+
+    /**
+     * Test: Changed reply fails replay with the exchange index
+     */
+    #[tokio::test]
+    async fn test_replay_reports_mismatch() {
+        let mut recording = Recording::load(&fixture("basic_session.json")).unwrap();
+        recording.exchanges[1].expect = vec![json!({"type": "pong", "id": "someone-else"})];
+
+        let error = replay(start_server().await, &recording).await.unwrap_err();
+        assert!(error.to_string().starts_with("Exchange 1:"), "{}", error);
+    }
+
+    /**
+     * Test: capture → save → load round-trips into a recording that replays
+     */
+    #[tokio::test]
+    async fn test_capture_round_trip() {
+        let addr = start_server().await;
+        let frames = vec![
+            Frame::Message(Box::new(UnifiedIpcMessage::pong("unused".to_string()))), // outbound-only: no reply
+            Frame::Message(Box::new(UnifiedIpcMessage::Ping { id: "p1".to_string() })),
+            Frame::Raw { raw: "{".to_string() },
+        ];
+
+        let recording = capture(addr, frames).await.unwrap();
+        assert!(recording.exchanges[0].expect.is_empty());
+        assert_eq!(recording.exchanges[1].expect, vec![json!({"type": "pong", "id": "p1"})]);
+        assert_eq!(recording.exchanges[2].expect[0]["type"], "error");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.json");
+        recording.save(&path).unwrap();
+        replay(addr, &Recording::load(&path).unwrap()).await.unwrap();
+    }
+}
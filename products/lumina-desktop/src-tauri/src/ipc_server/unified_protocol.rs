@@ -18,6 +18,24 @@
 
 use serde::{Deserialize, Serialize};
 
+/**
+ * Protocol versioning
+ *
+ * DESIGN DECISION: Integer protocol version negotiated by `hello` handshake
+ * WHY: Desktop/extension version skew otherwise surfaces as confusing parse errors
+ *
+ * REASONING CHAIN:
+ * 1. Client sends `hello` with the protocol_version it speaks
+ * 2. Server accepts if listed in SUPPORTED_PROTOCOL_VERSIONS → `helloAck`
+ * 3. Otherwise server replies `unsupportedVersion` with the supported list and closes
+ * 4. Bump PROTOCOL_VERSION on breaking message changes; drop old versions from the list
+ *    once no shipped extension speaks them
+ */
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Protocol versions this server can speak (ascending)
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[u32] = &[PROTOCOL_VERSION];
+
 /**
  * Code context extracted from IDE editor
  *
@@ -99,6 +117,8 @@ pub enum ErrorCode {
     DesktopNotRunning,
     ConnectionError,
     InvalidMessage,
+    RecordingInProgress,
+    InsufficientTokens,
     Unknown,
 }
 
@@ -118,6 +138,29 @@ pub enum ErrorCode {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(tag = "type")]
 pub enum UnifiedIpcMessage {
+    // ==================== Handshake ====================
+    /// Request: Extension → Desktop - Announce protocol version (first message)
+    #[serde(rename = "hello")]
+    Hello {
+        id: String,
+        protocol_version: u32,
+    },
+
+    /// Response: Desktop → Extension - Version accepted
+    #[serde(rename = "helloAck")]
+    HelloAck {
+        id: String,
+        protocol_version: u32,
+    },
+
+    /// Response: Desktop → Extension - Version rejected (connection closed after send)
+    #[serde(rename = "unsupportedVersion")]
+    UnsupportedVersion {
+        id: String,
+        requested: u32,
+        supported: Vec<u32>,
+    },
+
     // ==================== Voice Capture Messages ====================
     /// Request: Extension → Desktop - Capture voice with code context
     #[serde(rename = "captureVoice")]
@@ -153,6 +196,34 @@ pub enum UnifiedIpcMessage {
         request_id: String,
     },
 
+    // ==================== Conversation (multi-turn function calls) ====================
+    /// Push: Desktop → Extension - Follow-up question for a voice command
+    #[serde(rename = "clarificationNeeded")]
+    ClarificationNeeded {
+        id: String,
+        session_id: String,
+        question: String,
+        /// Suggested answers (may be empty for free-form answers)
+        options: Vec<String>,
+    },
+
+    /// Request: Extension → Desktop - User's answer to a clarificationNeeded question
+    #[serde(rename = "clarificationAnswer")]
+    ClarificationAnswer {
+        id: String,
+        session_id: String,
+        answer: String,
+    },
+
+    /// Response: Desktop → Extension - Function call complete, ready to execute
+    #[serde(rename = "conversationComplete")]
+    ConversationComplete {
+        id: String,
+        session_id: String,
+        function_id: String,
+        result: serde_json::Value,
+    },
+
     // ==================== System Context Messages ====================
     /// Request: Extension → Desktop - Get full system context snapshot
     #[serde(rename = "getFullContext")]
@@ -165,6 +236,9 @@ pub enum UnifiedIpcMessage {
         git: bool,
         files: bool,
         docs: bool,
+        /// Receive `eventRecorded` pushes (absent in older clients = false)
+        #[serde(default)]
+        analytics: bool,
     },
 
     /// Response: Desktop → Extension - Full context snapshot
@@ -181,6 +255,16 @@ pub enum UnifiedIpcMessage {
         update: crate::system_context::types::ContextUpdate,
     },
 
+    // ==================== Analytics ====================
+    /// Update: Desktop → Extension - Analytics event recorded (analytics subscribers only)
+    #[serde(rename = "eventRecorded")]
+    EventRecorded {
+        id: String,
+        event_type: String,
+        time_saved_minutes: i32,
+        recorded_at: chrono::DateTime<chrono::Utc>,
+    },
+
     // ==================== Settings Synchronization ====================
     /// Request: Extension → Desktop - Sync settings from VS Code to desktop app
     #[serde(rename = "syncSettings")]
@@ -198,6 +282,18 @@ pub enum UnifiedIpcMessage {
         error: Option<String>,
     },
 
+    // ==================== Diagnostics ====================
+    /// Request: Diagnostics tool → Desktop - Snapshot IPC server counters
+    #[serde(rename = "stats")]
+    Stats { id: String },
+
+    /// Response: Desktop → Diagnostics tool - IPC server counters
+    #[serde(rename = "statsResponse")]
+    StatsResponse {
+        id: String,
+        stats: super::metrics::IpcMetricsSnapshot,
+    },
+
     // ==================== Connection Management ====================
     /// Ping to keep connection alive
     #[serde(rename = "ping")]
@@ -239,4 +335,59 @@ impl UnifiedIpcMessage {
     pub fn pong(id: String) -> Self {
         UnifiedIpcMessage::Pong { id }
     }
+
+    /// Push message for a recorded analytics event (fresh message id)
+    pub fn event_recorded(event: crate::system_context::types::RecordedEvent) -> Self {
+        UnifiedIpcMessage::EventRecorded {
+            id: uuid::Uuid::new_v4().to_string(),
+            event_type: event.event_type,
+            time_saved_minutes: event.time_saved_minutes,
+            recorded_at: event.recorded_at,
+        }
+    }
+
+    /**
+     * Negotiate protocol version for a `hello` handshake
+     *
+     * Returns `HelloAck` if the requested version is supported, otherwise
+     * `UnsupportedVersion` listing SUPPORTED_PROTOCOL_VERSIONS.
+     */
+    pub fn negotiate_version(id: String, requested: u32) -> Self {
+        if SUPPORTED_PROTOCOL_VERSIONS.contains(&requested) {
+            UnifiedIpcMessage::HelloAck { id, protocol_version: requested }
+        } else {
+            UnifiedIpcMessage::UnsupportedVersion {
+                id,
+                requested,
+                supported: SUPPORTED_PROTOCOL_VERSIONS.to_vec(),
+            }
+        }
+    }
+
+    /// Protocol type name (matches serde "type" tag), used as metrics key
+    pub fn message_type(&self) -> &'static str {
+        match self {
+            UnifiedIpcMessage::Hello { .. } => "hello",
+            UnifiedIpcMessage::HelloAck { .. } => "helloAck",
+            UnifiedIpcMessage::UnsupportedVersion { .. } => "unsupportedVersion",
+            UnifiedIpcMessage::CaptureVoiceRequest { .. } => "captureVoice",
+            UnifiedIpcMessage::CaptureVoiceResponse { .. } => "captureVoiceResponse",
+            UnifiedIpcMessage::VoiceStatus { .. } => "voiceStatus",
+            UnifiedIpcMessage::ClarificationNeeded { .. } => "clarificationNeeded",
+            UnifiedIpcMessage::ClarificationAnswer { .. } => "clarificationAnswer",
+            UnifiedIpcMessage::ConversationComplete { .. } => "conversationComplete",
+            UnifiedIpcMessage::GetFullContext { .. } => "getFullContext",
+            UnifiedIpcMessage::Subscribe { .. } => "subscribe",
+            UnifiedIpcMessage::FullContext { .. } => "fullContext",
+            UnifiedIpcMessage::ContextUpdate { .. } => "contextUpdate",
+            UnifiedIpcMessage::EventRecorded { .. } => "eventRecorded",
+            UnifiedIpcMessage::SyncSettings { .. } => "syncSettings",
+            UnifiedIpcMessage::SyncSettingsResponse { .. } => "syncSettingsResponse",
+            UnifiedIpcMessage::Stats { .. } => "stats",
+            UnifiedIpcMessage::StatsResponse { .. } => "statsResponse",
+            UnifiedIpcMessage::Ping { .. } => "ping",
+            UnifiedIpcMessage::Pong { .. } => "pong",
+            UnifiedIpcMessage::Error { .. } => "error",
+        }
+    }
 }
@@ -0,0 +1,315 @@
+/**
+ * IPC Voice Capture - Real recording + transcription for IDE-triggered capture
+ *
+ * DESIGN DECISION: Voice capture behind a backend trait, desktop implementation shares hotkey path
+ * WHY: `captureVoice` returned a hardcoded placeholder; IDE users got fake transcripts
+ *
+ * REASONING CHAIN:
+ * 1. IDE sends captureVoice → IpcServer routes to VoiceCaptureBackend
+ * 2. DesktopVoiceBackend claims the shared RecordingState via IpcCaptureGuard
+ *    (rejects if hotkey recording active; hotkey is refused while the guard lives)
+ * 3. Pre-flight token balance check, same threshold as the hotkey flow
+ * 4. Records for a fixed capture window via voice::start/stop_recording_global
+ * 5. Transcribes with transcription::transcribe_audio (same server proxy as hotkeys)
+ * 6. Matches transcript against the local pattern library (aetherlight_core::PatternMatcher)
+ * 7. Guard drop releases RecordingState on every exit path (error, panic, cancellation)
+ * 8. Trait keeps IpcServer testable with a mock transcriber
+ *
+ * PATTERN: Pattern-IPC-002 (Unified IPC Protocol)
+ * RELATED: main.rs (toggle_recording hotkey flow), voice.rs, transcription.rs
+ * FUTURE: Stop on silence (VAD) instead of fixed window; stream voiceStatus updates
+ */
+
+use super::unified_protocol::{CodeContext, ErrorCode, PatternMatch};
+use aetherlight_core::{MatchingConfig, Pattern, PatternMatcher};
+use futures_util::future::BoxFuture;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Recording length for IDE-triggered capture (no stop signal in protocol yet)
+pub const IPC_CAPTURE_WINDOW: Duration = Duration::from_secs(8);
+
+/// Minimum matcher confidence for attaching a pattern to the response
+const PATTERN_MATCH_THRESHOLD: f64 = 0.5;
+
+/// Successful voice capture
+#[derive(Debug, Clone)]
+pub struct CapturedVoice {
+    pub text: String,
+    pub confidence: f32,
+    pub pattern: Option<PatternMatch>,
+}
+
+/// Failed voice capture (mapped to captureVoiceResponse error fields)
+#[derive(Debug, Clone)]
+pub struct VoiceCaptureFailure {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl VoiceCaptureFailure {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+}
+
+/**
+ * Records and transcribes voice for an IDE request
+ *
+ * DESIGN DECISION: Boxed future instead of async-trait dependency
+ * WHY: Single async method; trait object stored in IpcServer
+ */
+pub trait VoiceCaptureBackend: Send + Sync {
+    fn capture<'a>(
+        &'a self,
+        context: &'a CodeContext,
+    ) -> BoxFuture<'a, Result<CapturedVoice, VoiceCaptureFailure>>;
+}
+
+/**
+ * Desktop backend sharing RecordingState and audio buffer with the hotkey flow
+ */
+pub struct DesktopVoiceBackend {
+    app: AppHandle,
+    audio_buffer: Arc<Mutex<Vec<f32>>>,
+    capture_window: Duration,
+}
+
+impl DesktopVoiceBackend {
+    pub fn new(app: AppHandle, audio_buffer: Arc<Mutex<Vec<f32>>>) -> Self {
+        Self {
+            app,
+            audio_buffer,
+            capture_window: IPC_CAPTURE_WINDOW,
+        }
+    }
+
+    /// Claim shared recording state; fails if hotkey (or another IDE) is recording
+    fn begin_recording(&self) -> Result<IpcCaptureGuard, VoiceCaptureFailure> {
+        let state = self.app.state::<Mutex<crate::RecordingState>>();
+        let mut recording = state.lock()
+            .map_err(|e| VoiceCaptureFailure::new(ErrorCode::Unknown, format!("Lock error: {}", e)))?;
+
+        if recording.is_recording {
+            return Err(VoiceCaptureFailure::new(
+                ErrorCode::RecordingInProgress,
+                "Recording already in progress",
+            ));
+        }
+
+        recording.is_recording = true;
+        recording.ipc_capture = true;
+        recording.start_time = Some(std::time::Instant::now());
+        Ok(IpcCaptureGuard { app: self.app.clone() })
+    }
+
+    /**
+     * Refuse to record when the balance can't pay for a minute of transcription
+     *
+     * DESIGN DECISION: Same rules as toggle_recording's pre-flight check
+     * WHY: IDE users shouldn't record 8 seconds only to get a 402 from transcription
+     *
+     * Balance lookup failures (network) don't block capture, matching the hotkey flow.
+     */
+    async fn check_balance(&self, settings: &crate::AppSettings) -> Result<(), VoiceCaptureFailure> {
+        if settings.license_key.is_empty() {
+            return Ok(()); // transcription reports the missing license
+        }
+
+        let http = self.app.state::<crate::http::HttpClient>();
+        match crate::transcription::check_token_balance(
+            &http.client(),
+            &settings.license_key,
+            &settings.global_network_api_endpoint,
+        )
+        .await
+        {
+            Ok(balance) if balance.tokens_balance < crate::transcription::MIN_RECORDING_TOKENS => {
+                let _ = self.app.emit("insufficient-tokens", balance.clone());
+                Err(VoiceCaptureFailure::new(
+                    ErrorCode::InsufficientTokens,
+                    format!(
+                        "Insufficient tokens: {} tokens remaining. Need at least {} tokens for 1 minute.",
+                        balance.tokens_balance,
+                        crate::transcription::MIN_RECORDING_TOKENS
+                    ),
+                ))
+            }
+            Ok(_) => Ok(()),
+            Err(e) => {
+                tracing::warn!(error = %e, "Balance check failed, proceeding with IPC voice capture");
+                Ok(())
+            }
+        }
+    }
+
+    /**
+     * Record for the capture window on one blocking thread
+     *
+     * WHY: voice.rs keeps the cpal stream in thread-local storage, so start and
+     * stop must run on the same OS thread (async tasks may migrate between awaits)
+     */
+    async fn record(&self) -> Result<(Vec<f32>, u32), VoiceCaptureFailure> {
+        let buffer = Arc::clone(&self.audio_buffer);
+        let app = self.app.clone();
+        let window = self.capture_window;
+
+        tokio::task::spawn_blocking(move || {
+            crate::voice::start_recording_global(buffer, app)
+                .map_err(|e| VoiceCaptureFailure::new(ErrorCode::MicrophoneNotFound, format!("Failed to start recording: {}", e)))?;
+            std::thread::sleep(window);
+            Ok(crate::voice::stop_recording_global())
+        })
+        .await
+        .map_err(|e| VoiceCaptureFailure::new(ErrorCode::Unknown, format!("Recording task failed: {}", e)))?
+    }
+
+    async fn capture_inner(&self) -> Result<CapturedVoice, VoiceCaptureFailure> {
+        let settings = crate::get_settings()
+            .map_err(|e| VoiceCaptureFailure::new(ErrorCode::Unknown, format!("Failed to load settings: {}", e)))?;
+        self.check_balance(&settings).await?;
+
+        let (samples, sample_rate) = self.record().await?;
+        tracing::debug!(samples = samples.len(), sample_rate, "IPC voice capture recorded");
+
+        let http = self.app.state::<crate::http::HttpClient>();
+        let text = crate::transcription::transcribe_audio(
+            &http.client(),
+            &samples,
+            sample_rate,
+            &settings.license_key,
+            &settings.global_network_api_endpoint,
+        )
+        .await
+        .map_err(|e| VoiceCaptureFailure::new(ErrorCode::TranscriptionFailed, e.to_string()))?;
+
+        let pattern = match match_pattern(&text).await {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                tracing::warn!(error = %e, "Pattern matching failed for IPC voice capture");
+                None
+            }
+        };
+
+        Ok(CapturedVoice {
+            text,
+            confidence: 0.95, // OpenAI Whisper is highly accurate (same as hotkey flow)
+            pattern,
+        })
+    }
+}
+
+impl VoiceCaptureBackend for DesktopVoiceBackend {
+    fn capture<'a>(
+        &'a self,
+        _context: &'a CodeContext,
+    ) -> BoxFuture<'a, Result<CapturedVoice, VoiceCaptureFailure>> {
+        Box::pin(async move {
+            let _guard = self.begin_recording()?;
+            self.capture_inner().await
+        })
+    }
+}
+
+/**
+ * Ownership of RecordingState by one IPC capture
+ *
+ * DESIGN DECISION: RAII guard instead of paired begin/end calls
+ * WHY: An early return, panic or dropped future would otherwise leave
+ * is_recording stuck and lock out the hotkey until restart
+ */
+struct IpcCaptureGuard {
+    app: AppHandle,
+}
+
+impl Drop for IpcCaptureGuard {
+    fn drop(&mut self) {
+        let state = self.app.state::<Mutex<crate::RecordingState>>();
+        let mut recording = match state.lock() {
+            Ok(recording) => recording,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        recording.is_recording = false;
+        recording.ipc_capture = false;
+        recording.start_time = None;
+    }
+}
+
+/// Tokenized library cache next to patterns.db (~/.lumina/pattern_matcher.bin)
+fn matcher_snapshot_path() -> PathBuf {
+    let mut path = dirs::home_dir().expect("Failed to get home directory");
+    path.push(".lumina");
+    path.push("pattern_matcher.bin");
+    path
+}
+
+/**
+ * Matcher for `patterns`, reusing the on-disk snapshot's precomputed tokens
+ *
+ * DESIGN DECISION: Snapshot is a cache; failing to read or write it never fails matching
+ * WHY: PatternMatcher::load already rebuilds on a stale or unreadable snapshot; a
+ * read-only or full disk should only cost the tokenization time
+ *
+ * Rewritten (temp file + rename) whenever the library diverged from it.
+ */
+fn load_matcher(snapshot_path: &Path, patterns: Vec<Pattern>) -> Result<PatternMatcher, String> {
+    let snapshot = std::fs::read(snapshot_path).ok();
+    let load = PatternMatcher::load(snapshot.as_deref(), patterns, MatchingConfig::default())
+        .map_err(|e| e.to_string())?;
+
+    if !load.restored {
+        tracing::debug!(tokenized = load.tokenized, "Pattern matcher snapshot stale, rewriting");
+        if let Err(e) = load.matcher.save_to_file(snapshot_path) {
+            tracing::warn!(error = %e, "Failed to write pattern matcher snapshot");
+        }
+    }
+
+    Ok(load.matcher)
+}
+
+/**
+ * Best pattern from the local library for a transcript (None below threshold)
+ */
+async fn match_pattern(text: &str) -> Result<Option<PatternMatch>, String> {
+    let library = crate::get_all_patterns().await?;
+    let mut patterns = Vec::with_capacity(library.len());
+    let mut ids = std::collections::HashMap::new();
+
+    for stored in library {
+        let pattern = Pattern::builder()
+            .title(stored.name.clone())
+            .content(stored.description.clone())
+            .tags(stored.tags.clone())
+            .domain(stored.domain.clone())
+            .build()
+            .map_err(|e| e.to_string())?;
+        ids.insert(*pattern.id(), stored.id);
+        patterns.push(pattern);
+    }
+
+    let matcher = load_matcher(&matcher_snapshot_path(), patterns)?;
+
+    if matcher.is_empty() {
+        return Ok(None);
+    }
+
+    let best = matcher.find_matches(text, 1).map_err(|e| e.to_string())?.into_iter().next();
+
+    Ok(best
+        .filter(|m| m.confidence.total_score() >= PATTERN_MATCH_THRESHOLD)
+        .map(|m| {
+            let score = m.confidence.total_score();
+            PatternMatch {
+                id: ids.remove(m.pattern.id()).unwrap_or_else(|| m.pattern.id().to_string()),
+                name: m.pattern.title().to_string(),
+                reasoning: format!(
+                    "Transcript matched '{}' with {:.0}% confidence",
+                    m.pattern.title(),
+                    score * 100.0
+                ),
+                confidence: score as f32,
+            }
+        }))
+}
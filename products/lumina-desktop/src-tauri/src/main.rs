@@ -33,7 +33,8 @@ use tauri::{
     tray::{TrayIconBuilder, TrayIconEvent},
 };
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
-use aetherlight_core::analytics::{UsageTracker, UsageMetrics, MetricsPeriod, EventType};
+use aetherlight_core::analytics::{Durability, UsageTracker, UsageMetrics, MetricsPeriod, DomainMetrics, EventType};
+use aetherlight_core::{normalize_tags, EmbeddingConfig, EmbeddingPrecision};
 // Pattern from aetherlight-core not used directly - see DatabasePattern struct
 use std::collections::HashMap;
 use rusqlite::{Connection, params};
@@ -46,6 +47,16 @@ mod storage;
 mod voice;
 mod transcription;
 mod auth;  // BUG-002: License validation and device fingerprinting
+mod index_rebuild;
+mod recording_flow;
+mod resample;
+mod overlay;
+mod pattern_sync;
+mod first_run;
+mod rate_limit;
+mod http;
+
+use recording_flow::{PressError, Pressed, RecordingState, StartError};
 
 /**
  * DESIGN DECISION: IPC sender type alias for managed state
@@ -101,6 +112,68 @@ struct AppSettings {
     global_network_api_endpoint: String,  // ÆtherLight API (Vercel)
     hosted_node_url: Option<String>,      // User's own Supabase/Postgres (optional)
     selected_domains: Vec<String>,
+    #[serde(default = "default_analytics_retention_days")]
+    analytics_retention_days: u32,    // Raw analytics events older than this are rolled up
+    #[serde(default)]
+    analytics_durability: Durability, // "safe" fsyncs every event, "fast" trades power-loss safety for latency
+    #[serde(default = "default_pattern_history_limit")]
+    pattern_history_limit: usize,     // Prior versions kept per pattern (oldest pruned)
+    #[serde(default)]
+    embedding_precision: EmbeddingPrecision, // Pattern index model; rebuild index after changing
+    #[serde(default = "default_server_requests_per_second")]
+    server_requests_per_second: f64,  // Pacing for all ÆtherLight server calls (rate_limit.rs)
+    #[serde(default = "default_http_connect_timeout_secs")]
+    http_connect_timeout_secs: u64,   // Time to reach the server before a Timeout error (http.rs)
+    #[serde(default = "default_http_request_timeout_secs")]
+    http_request_timeout_secs: u64,   // Time for a whole request, incl. transcription (http.rs)
+    #[serde(default)]
+    file_watcher_ignore_globs: Vec<String>, // Extra gitignore-style globs; applied at next launch
+    #[serde(default = "default_transcription_sample_rate")]
+    transcription_sample_rate: u32,   // Rate recordings are resampled to before upload (resample.rs)
+}
+
+fn default_analytics_retention_days() -> u32 {
+    aetherlight_core::analytics::DEFAULT_RETENTION_DAYS
+}
+
+fn default_pattern_history_limit() -> usize {
+    storage::DEFAULT_MAX_PATTERN_VERSIONS
+}
+
+fn default_server_requests_per_second() -> f64 {
+    rate_limit::DEFAULT_SERVER_REQUESTS_PER_SECOND
+}
+
+fn default_http_connect_timeout_secs() -> u64 {
+    http::DEFAULT_CONNECT_TIMEOUT.as_secs()
+}
+
+fn default_http_request_timeout_secs() -> u64 {
+    http::DEFAULT_REQUEST_TIMEOUT.as_secs()
+}
+
+fn default_transcription_sample_rate() -> u32 {
+    resample::WHISPER_SAMPLE_RATE
+}
+
+impl AppSettings {
+    /// Apply HTTP timeouts to the shared client (zero is treated as 1 second)
+    fn apply_http_timeouts(&self, http: &http::HttpClient) -> anyhow::Result<()> {
+        http.configure(
+            std::time::Duration::from_secs(self.http_connect_timeout_secs.max(1)),
+            std::time::Duration::from_secs(self.http_request_timeout_secs.max(1)),
+        )
+    }
+
+    /// Quota tier of the activated license (Free before activation or for an unknown tier)
+    fn user_tier(&self) -> UserTier {
+        match self.tier.as_deref().map(|tier| tier.trim().to_ascii_lowercase()).as_deref() {
+            Some("network") => UserTier::Network,
+            Some("pro") => UserTier::Pro,
+            Some("enterprise") => UserTier::Enterprise,
+            _ => UserTier::Free,
+        }
+    }
 }
 
 impl Default for AppSettings {
@@ -117,6 +190,15 @@ impl Default for AppSettings {
             global_network_api_endpoint: "https://www.aetherlight.ai".to_string(), // ÆtherLight global network
             hosted_node_url: None,  // Optional: user's own cloud backup
             selected_domains: vec![], // User selects in Settings UI
+            analytics_retention_days: default_analytics_retention_days(),
+            analytics_durability: Durability::default(),
+            pattern_history_limit: default_pattern_history_limit(),
+            embedding_precision: EmbeddingPrecision::default(),
+            server_requests_per_second: default_server_requests_per_second(),
+            http_connect_timeout_secs: default_http_connect_timeout_secs(),
+            http_request_timeout_secs: default_http_request_timeout_secs(),
+            file_watcher_ignore_globs: vec![], // Defaults + workspace .gitignore already apply
+            transcription_sample_rate: default_transcription_sample_rate(),
         }
     }
 }
@@ -142,6 +224,70 @@ struct DatabasePattern {
     domain: String,
     confidence_score: Option<f64>,
     tags: Vec<String>,
+    #[serde(default = "default_pattern_version")]
+    version: u32, // Incremented on every edit (see storage::PatternHistory)
+    #[serde(default)]
+    estimated_time_saved_minutes: Option<u32>, // Credited per pattern_match event (None = analytics default)
+}
+
+fn default_pattern_version() -> u32 {
+    aetherlight_core::INITIAL_PATTERN_VERSION
+}
+
+impl From<storage::PatternRecord> for DatabasePattern {
+    fn from(record: storage::PatternRecord) -> Self {
+        Self {
+            id: record.id,
+            name: record.name,
+            description: record.description,
+            domain: record.domain.unwrap_or_default(),
+            confidence_score: record.confidence_score,
+            tags: record.tags,
+            version: record.version,
+            estimated_time_saved_minutes: record.estimated_time_saved_minutes,
+        }
+    }
+}
+
+/**
+ * DESIGN DECISION: Partial pattern update for update_pattern_fields
+ * WHY: update_pattern writes every column, so an editor saving only the description
+ * clobbered a concurrent tag edit with its stale copy
+ *
+ * Fields left out (None) keep their stored value. Nullable columns take Option<Option<T>>:
+ * an explicit JSON null (Some(None)) clears the value. name/description/domain/tags
+ * can't be null (clear tags with an empty list).
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct PatternPatch {
+    name: Option<String>,
+    description: Option<String>,
+    domain: Option<String>,
+    #[serde(default, deserialize_with = "present_or_null", skip_serializing_if = "Option::is_none")]
+    confidence_score: Option<Option<f64>>,
+    tags: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "present_or_null", skip_serializing_if = "Option::is_none")]
+    estimated_time_saved_minutes: Option<Option<u32>>,
+}
+
+/// Double-option field: missing → None (via serde default), null → Some(None), value → Some(Some(v))
+fn present_or_null<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl PatternPatch {
+    fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.domain.is_none()
+            && self.confidence_score.is_none()
+            && self.tags.is_none()
+            && self.estimated_time_saved_minutes.is_none()
+    }
 }
 
 /**
@@ -164,6 +310,7 @@ struct SerializableMetrics {
     searches: i64,
     insertions: i64,
     pattern_matches: i64,
+    by_domain: HashMap<String, DomainMetrics>, // Empty when events carry no domain metadata
 }
 
 impl From<aetherlight_core::analytics::Metrics> for SerializableMetrics {
@@ -184,6 +331,7 @@ impl From<aetherlight_core::analytics::Metrics> for SerializableMetrics {
             searches: metrics.searches,
             insertions: metrics.insertions,
             pattern_matches: metrics.pattern_matches,
+            by_domain: metrics.by_domain,
         }
     }
 }
@@ -198,24 +346,6 @@ struct DailyTimeSaved {
     minutes_saved: i64,
 }
 
-/**
- * DESIGN DECISION: Recording state with Mutex for thread-safe access
- * WHY: Global hotkeys run on separate thread, need shared mutable state
- */
-struct RecordingState {
-    is_recording: bool,
-    start_time: Option<std::time::Instant>,
-}
-
-impl Default for RecordingState {
-    fn default() -> Self {
-        Self {
-            is_recording: false,
-            start_time: None,
-        }
-    }
-}
-
 /**
  * DESIGN DECISION: Get analytics database path in user's home directory
  * WHY: Analytics data must persist across app restarts, per-user isolation
@@ -238,6 +368,22 @@ fn get_storage_path() -> std::path::PathBuf {
     path
 }
 
+/// Pattern metadata database (outcomes, calibration, sync_state)
+fn get_metadata_path() -> std::path::PathBuf {
+    let mut path = dirs::home_dir().expect("Failed to get home directory");
+    path.push(".lumina");
+    path.push("metadata.db");
+    path
+}
+
+/// Unfinished first-run setup (see first_run.rs); absent once setup completes
+fn get_setup_progress_path() -> std::path::PathBuf {
+    let mut path = dirs::home_dir().expect("Failed to get home directory");
+    path.push(".lumina");
+    path.push("setup_progress.json");
+    path
+}
+
 /**
  * DESIGN DECISION: Lazy initialization of analytics tracker
  * WHY: Don't want to fail app startup if analytics DB can't be created
@@ -256,7 +402,12 @@ fn get_usage_tracker() -> Result<UsageTracker, String> {
             .map_err(|e| format!("Failed to create analytics directory: {}", e))?;
     }
 
+    let durability = get_settings()
+        .map(|settings| settings.analytics_durability)
+        .unwrap_or_default();
+
     UsageTracker::new(db_path.to_str().unwrap())
+        .and_then(|tracker| tracker.with_durability(durability))
         .map_err(|e| format!("Failed to initialize analytics tracker: {}", e))
 }
 
@@ -298,18 +449,30 @@ fn get_usage_metrics(period: String) -> Result<SerializableMetrics, String> {
  *
  * REASONING CHAIN:
  * 1. Frontend requests last N days of data
- * 2. Query analytics database grouped by date
+ * 2. Query analytics database grouped by the user's local date
+ *    - utc_offset_minutes from the frontend (e.g. -new Date().getTimezoneOffset())
+ *    - Omitted → the system's current UTC offset
  * 3. Return array of {date, minutes_saved} objects
  * 4. Frontend renders as line chart
  *
  * PERFORMANCE: <50ms for 30 days of data (typical case)
  */
 #[tauri::command]
-fn get_time_saved_history(days: u32) -> Result<Vec<DailyTimeSaved>, String> {
+fn get_time_saved_history(
+    days: u32,
+    utc_offset_minutes: Option<i32>,
+) -> Result<Vec<DailyTimeSaved>, String> {
+    use chrono::Offset;
+
     let tracker = get_usage_tracker()?;
 
-    // Use UsageTracker's public get_daily_time_saved method
-    let history_tuples = tracker.get_daily_time_saved(days)
+    let utc_offset = match utc_offset_minutes {
+        Some(minutes) => utc_offset_from_minutes(minutes)?,
+        None => chrono::Local::now().offset().fix(),
+    };
+
+    // Bucket by the user's local day, not the UTC day
+    let history_tuples = tracker.get_daily_time_saved_local(days, utc_offset)
         .map_err(|e| format!("Failed to get daily time saved: {}", e))?;
 
     // Convert (String, i64) tuples to DailyTimeSaved structs
@@ -324,6 +487,14 @@ fn get_time_saved_history(days: u32) -> Result<Vec<DailyTimeSaved>, String> {
     Ok(history)
 }
 
+/// Frontend UTC offset in minutes → FixedOffset (out-of-range values are an error, not an overflow)
+fn utc_offset_from_minutes(minutes: i32) -> Result<chrono::FixedOffset, String> {
+    minutes
+        .checked_mul(60)
+        .and_then(chrono::FixedOffset::east_opt)
+        .ok_or_else(|| format!("Invalid UTC offset: {} minutes", minutes))
+}
+
 /**
  * DESIGN DECISION: Tauri command to record analytics events
  * WHY: Frontend needs to track actions for dashboard metrics
@@ -333,14 +504,20 @@ fn get_time_saved_history(days: u32) -> Result<Vec<DailyTimeSaved>, String> {
  * 2. Convert event type string to EventType enum
  * 3. Insert into analytics database
  * 4. Dashboard auto-refreshes to show updated metrics
+ * 5. Broadcast eventRecorded to IDEs subscribed to analytics (unified IPC protocol)
  *
  * EXAMPLES:
  * - Voice capture completed → record_event("voice_capture")
  * - Code search executed → record_event("search")
- * - Pattern matched → record_event("pattern_match")
+ * - Pattern matched → record_event("pattern_match", '{"pattern_id": "..."}')
+ *   credits the pattern's estimated_time_saved_minutes (default 10 when unset)
  */
 #[tauri::command]
-fn record_event(event_type: String, metadata: Option<String>) -> Result<(), String> {
+fn record_event(
+    event_type: String,
+    metadata: Option<String>,
+    ipc_sender: tauri::State<'_, Arc<Mutex<Option<IpcSender>>>>,
+) -> Result<(), String> {
     let tracker = get_usage_tracker()?;
 
     // Use UsageTracker's public methods based on event type
@@ -348,12 +525,27 @@ fn record_event(event_type: String, metadata: Option<String>) -> Result<(), Stri
         "voice_capture" => tracker.record_voice_capture(metadata.as_deref()),
         "search" => tracker.record_search(metadata.as_deref()),
         "insertion" => tracker.record_insertion(metadata.as_deref()),
-        "pattern_match" => tracker.record_pattern_match(metadata.as_deref()),
+        "pattern_match" => match get_pattern_db() {
+            Ok(patterns) => tracker
+                .with_time_saved_lookup(move |pattern_id| pattern_time_saved(&patterns, pattern_id))
+                .record_pattern_match(metadata.as_deref()),
+            Err(e) => {
+                tracing::warn!("Pattern estimates unavailable, using default: {}", e);
+                tracker.record_pattern_match(metadata.as_deref())
+            }
+        },
         _ => return Err(format!("Invalid event type: {}", event_type)),
     };
 
     result.map_err(|e| format!("Failed to record event: {}", e))?;
 
+    // 5. Push to IDEs subscribed to analytics (eventRecorded)
+    if let (Some(event), Ok(sender)) = (EventType::from_str(&event_type), ipc_sender.lock()) {
+        if let Some(sender) = sender.as_ref() {
+            ipc_server::notify_event_recorded(sender, event);
+        }
+    }
+
     Ok(())
 }
 
@@ -369,7 +561,9 @@ fn record_event(event_type: String, metadata: Option<String>) -> Result<(), Stri
  * 5. Result: User sees available balance before recording
  */
 #[tauri::command]
-async fn get_token_balance() -> Result<transcription::TokenBalanceResponse, String> {
+async fn get_token_balance(
+    http: tauri::State<'_, http::HttpClient>,
+) -> Result<transcription::TokenBalanceResponse, String> {
     // Load settings to get license key and API URL
     let settings = get_settings().map_err(|e| format!("Failed to load settings: {}", e))?;
 
@@ -379,6 +573,7 @@ async fn get_token_balance() -> Result<transcription::TokenBalanceResponse, Stri
 
     // Check balance via server API
     transcription::check_token_balance(
+        &http.client(),
         &settings.license_key,
         &settings.global_network_api_endpoint,
     )
@@ -405,230 +600,277 @@ async fn get_token_balance() -> Result<transcription::TokenBalanceResponse, Stri
 async fn toggle_recording(
     state: tauri::State<'_, Mutex<RecordingState>>,
     audio_buffer: tauri::State<'_, Arc<Mutex<Vec<f32>>>>,
+    indicator: tauri::State<'_, overlay::AudioIndicator>,
     app: AppHandle,
 ) -> Result<bool, String> {
-    // Read current recording state and determine action
-    let (is_starting_recording, duration) = {
-        let mut recording = state.lock().map_err(|e| format!("Lock error: {}", e))?;
-        recording.is_recording = !recording.is_recording;
-
-        if recording.is_recording {
-            recording.start_time = Some(std::time::Instant::now());
-            (true, 0u64)
-        } else {
-            let dur = recording
-                .start_time
-                .map(|start| start.elapsed().as_millis() as u64)
-                .unwrap_or(0);
-            (false, dur)
+    let audio = voice::GlobalRecorder::new(Arc::clone(&audio_buffer), app.clone());
+    let settings = get_settings().map_err(|e| format!("Failed to load settings: {}", e))?;
+    let hooks = DesktopPressHooks::new(&app, indicator.inner(), &settings);
+
+    let pressed = recording_flow::press(state.inner(), &audio, &transcription::KeyboardSink, &hooks).await;
+    match pressed {
+        Ok(Pressed::Started) => {
+            // Emit event to frontend for UI updates
+            app.emit("recording-started", ()).map_err(|e| e.to_string())?;
+            Ok(true)
         }
-        // MutexGuard dropped here
-    };
+        // Escape during the balance check: cancel_recording already updated the UI
+        Ok(Pressed::Cancelled) => Ok(false),
+        Ok(Pressed::Finished { transcript, duration_ms }) => {
+            let result = VoiceCaptureResult {
+                text: transcript.clone(),
+                confidence: 0.95, // OpenAI Whisper is highly accurate
+                duration_ms,
+            };
 
-    if is_starting_recording {
-        println!("🎤 Recording started...");
-
-        // DESIGN DECISION: Pre-flight token balance check before recording
-        // WHY: Prevents wasting user's time recording if they don't have enough tokens
-        //
-        // REASONING CHAIN:
-        // 1. Load settings to get license key
-        // 2. Check token balance via server API
-        // 3. Calculate minimum required tokens (375 = 1 minute)
-        // 4. If balance < 375 → Show upgrade prompt, don't start recording
-        // 5. If balance >= 375 → Proceed with recording
-        // 6. Result: User only records when they can afford transcription
-        let settings = get_settings().map_err(|e| format!("Failed to load settings: {}", e))?;
-
-        if !settings.license_key.is_empty() {
-            match transcription::check_token_balance(
-                &settings.license_key,
-                &settings.global_network_api_endpoint,
-            ).await {
-                Ok(balance) => {
-                    const MIN_TOKENS: u64 = 375; // 1 minute of transcription
-                    if balance.tokens_balance < MIN_TOKENS {
-                        println!("⚠️ Insufficient tokens: {} < {} required", balance.tokens_balance, MIN_TOKENS);
-                        // Emit event to show upgrade prompt in frontend
-                        app.emit("insufficient-tokens", balance.clone()).map_err(|e| e.to_string())?;
-                        // Revert recording state
-                        let mut recording = state.lock().map_err(|e| format!("Lock error: {}", e))?;
-                        recording.is_recording = false;
-                        recording.start_time = None;
-                        return Err(format!(
-                            "Insufficient tokens: {} tokens remaining. Need at least {} tokens for 1 minute.",
-                            balance.tokens_balance,
-                            MIN_TOKENS
-                        ));
-                    }
-                    println!("✅ Pre-flight check passed: {} tokens available",
-                             balance.tokens_balance);
+            // Emit event to frontend with result
+            app.emit("recording-stopped", &result).map_err(|e| e.to_string())?;
+
+            tracing::debug!("Voice capture complete: {}", transcript);
+
+            Ok(false)
+        }
+        Err(PressError::Refused(e)) => Err(e),
+        Err(PressError::Start(StartError::InsufficientTokens(balance))) => {
+            // Emit event to show upgrade prompt in frontend
+            app.emit("insufficient-tokens", balance.clone()).map_err(|e| e.to_string())?;
+            Err(format!(
+                "Insufficient tokens: {} tokens remaining. Need at least {} tokens for 1 minute.",
+                balance.tokens_balance,
+                transcription::MIN_RECORDING_TOKENS
+            ))
+        }
+        Err(PressError::Start(StartError::Audio(e))) => Err(format!("Failed to start recording: {}", e)),
+        Err(PressError::Start(StartError::Cancelled)) => Ok(false),
+        Err(PressError::NoTranscriber(message)) => {
+            tracing::warn!("{}", message);
+            Err(message)
+        }
+        Err(PressError::Finish(recording_flow::FinishError::Typing(e))) => {
+            Err(format!("Failed to type transcript: {}", e))
+        }
+        Err(PressError::Finish(recording_flow::FinishError::Transcription(e))) => {
+            // Handle structured errors with frontend event emission (BUG-004)
+            use transcription::TranscriptionError;
+
+            match &e {
+                TranscriptionError::Unauthorized { message } => {
+                    // Emit event to show license activation dialog
+                    let _ = app.emit("show-license-activation", message.clone());
+                    Err(format!("License invalid: {}. Please re-activate your device.", message))
                 }
-                Err(e) => {
-                    eprintln!("⚠️ Balance check failed: {}. Proceeding with recording anyway.", e);
-                    // Don't block recording if balance check fails (network issues, etc.)
+                TranscriptionError::PaymentRequired { message, balance_tokens, required_tokens } => {
+                    // Emit event to show token purchase dialog with balance
+                    let payload = serde_json::json!({
+                        "message": message,
+                        "balance": balance_tokens,
+                        "required": required_tokens,
+                    });
+                    let _ = app.emit("show-token-purchase", payload);
+                    Err(format!("Insufficient tokens: {}. You have {} tokens, need {} tokens.",
+                        message, balance_tokens, required_tokens))
+                }
+                TranscriptionError::Forbidden { message } => {
+                    // Emit event to show device activation dialog
+                    let _ = app.emit("show-device-activation", message.clone());
+                    Err(format!("Device not active: {}. Please activate your device.", message))
+                }
+                TranscriptionError::Timeout { message } => {
+                    // Same retry dialog, but say the server timed out rather than "network error"
+                    let _ = app.emit("show-retry-dialog", format!("Server timed out, retrying may help: {}", message));
+                    Err(format!("Server timed out: {}. Please try again.", message))
+                }
+                TranscriptionError::ServerError { message } |
+                TranscriptionError::NetworkError { message } => {
+                    // Emit event to show retry dialog
+                    let _ = app.emit("show-retry-dialog", message.clone());
+                    Err(format!("Temporary error: {}. Please try again.", message))
+                }
+                _ => {
+                    // Generic error handling for NotFound, ParseError
+                    Err(format!("Transcription failed: {}", e))
                 }
             }
         }
+    }
+}
+
+/**
+ * Hotkey recording services and UI for recording_flow::press
+ *
+ * DESIGN DECISION: Server services built from settings once per press
+ * WHY: No license key → no balance check, and a stopped recording reports the missing
+ * license (or the deprecated BYOK key) instead of transcribing
+ *
+ * Builds with the `mock-transcription` feature use MockBalanceChecker / MockTranscriber
+ * instead, so the hotkey flow runs without a license key or network.
+ */
+struct DesktopPressHooks<'a> {
+    app: &'a AppHandle,
+    indicator: &'a overlay::AudioIndicator,
+    balance: Option<Box<dyn recording_flow::BalanceChecker>>,
+    transcriber: Result<Box<dyn recording_flow::Transcriber>, String>,
+}
+
+/// Transcript typed by `mock-transcription` builds
+#[cfg(feature = "mock-transcription")]
+const MOCK_TRANSCRIPT: &str = "mock transcription";
+
+impl<'a> DesktopPressHooks<'a> {
+    #[cfg(not(feature = "mock-transcription"))]
+    fn new(app: &'a AppHandle, indicator: &'a overlay::AudioIndicator, settings: &AppSettings) -> Self {
+        let http = app.state::<http::HttpClient>();
 
-        // Start audio capture with thread-local storage
-        let buffer_clone = Arc::clone(&audio_buffer);
-        voice::start_recording_global(buffer_clone, app.clone())
-            .map_err(|e| format!("Failed to start recording: {}", e))?;
-
-        // Create and show overlay window at top of screen
-        // DESIGN DECISION: Full-width, non-interactive indicator bar at top of screen
-        // WHY: User needs to see visual feedback that recording is active WITHOUT blocking transcription
-        //
-        // REASONING CHAIN:
-        // 1. Original 384px width too narrow (only 20% of 1920px screen)
-        // 2. User reported: "recording bar has disappeared even when I turn on the recording"
-        // 3. Code was creating window but too small to notice
-        // 4. Solution: Make it full screen width (3840px covers dual monitor setups)
-        // 5. Centered at top, 8px tall (more visible for debugging)
-        // 6. **CRITICAL FIX #1:** User reported transcription blocked when indicator visible
-        //    - Root cause: Indicator window stealing focus from target app
-        //    - enigo types wherever cursor/focus is currently
-        //    - If indicator has focus, typing goes nowhere
-        // 7. **CRITICAL FIX #2:** focusable(false) made window invisible on Windows
-        //    - Windows requires focusable windows to be visible
-        //    - Solution: visible_on_all_workspaces + accept_first_mouse(false)
-        //    - Window visible but doesn't intercept clicks/focus
-        // 8. **CRITICAL FIX #3:** Window not appearing on subsequent recordings
-        //    - Root cause: Window label "audio-indicator" already exists (not fully closed)
-        //    - Solution: Check if window exists first, show it if it does
-        //    - Result: Reuse existing window instead of creating duplicate
-        // 9. Result: Clear visual feedback during recording + transcription works
-        //
-        // PATTERN: Pattern-UI-008 (Non-Interactive Overlay Windows)
-
-        // Check if window already exists (from previous recording)
-        if let Some(existing_overlay) = app.get_webview_window("audio-indicator") {
-            // Window exists, just show it (but DON'T set focus - that breaks transcription!)
-            let _ = existing_overlay.show();
-            println!("📊 Audio indicator overlay shown (existing window)");
+        // Check for license key (new monetization model)
+        let transcriber: Result<Box<dyn recording_flow::Transcriber>, String> = if !settings.license_key.is_empty() {
+            // Transcribe audio via server API (proxies to OpenAI with credit tracking)
+            Ok(Box::new(
+                transcription::ServerTranscriber::new(http.client(), &settings.license_key, &settings.global_network_api_endpoint)
+                    .with_sample_rate(settings.transcription_sample_rate),
+            ))
+        } else if !settings.openai_api_key.is_empty() {
+            // Fallback: legacy OpenAI API key (BYOK model - migration period)
+            Err("BYOK model deprecated. Please activate device to get license key. Visit dashboard to activate.".to_string())
         } else {
-            // Create new window
-            if let Ok(overlay) = tauri::WebviewWindowBuilder::new(
-                &app,
-                "audio-indicator",
-                tauri::WebviewUrl::App("indicator.html".into())
-            )
-            .title("Audio Indicator")
-            .inner_size(3840.0, 8.0) // Full width (covers dual monitors), 8px tall for better visibility
-            .position(0.0, 0.0) // Top of screen
-            .decorations(false) // No title bar
-            .always_on_top(true) // Stay above all windows
-            .skip_taskbar(true) // Don't appear in taskbar
-            // REMOVED .transparent(true) - Tauri 2.0: transparency now configured in tauri.conf.json
-            .visible_on_all_workspaces(true) // Show on all virtual desktops
-            .accept_first_mouse(false) // Don't intercept first click
-            .build() {
-                println!("📊 Audio indicator overlay shown (new window)");
-            }
+            Err("License key not configured. Please activate device first. Visit dashboard to activate.".to_string())
+        };
+
+        // Pre-flight balance check (skipped without a license key)
+        let balance = (!settings.license_key.is_empty()).then(|| {
+            Box::new(transcription::ServerBalanceChecker::new(http.client(), &settings.license_key, &settings.global_network_api_endpoint))
+                as Box<dyn recording_flow::BalanceChecker>
+        });
+
+        Self { app, indicator, balance, transcriber }
+    }
+
+    #[cfg(feature = "mock-transcription")]
+    fn new(app: &'a AppHandle, indicator: &'a overlay::AudioIndicator, _settings: &AppSettings) -> Self {
+        tracing::debug!("mock-transcription build: using MockBalanceChecker / MockTranscriber");
+        Self {
+            app,
+            indicator,
+            balance: Some(Box::new(recording_flow::MockBalanceChecker { tokens_balance: 10_000 })),
+            transcriber: Ok(Box::new(recording_flow::MockTranscriber { transcript: MOCK_TRANSCRIPT.to_string() })),
         }
+    }
+}
 
-        // Emit event to frontend for UI updates
-        app.emit("recording-started", ()).map_err(|e| e.to_string())?;
+impl recording_flow::PressHooks for DesktopPressHooks<'_> {
+    fn balance_checker(&self) -> Option<&dyn recording_flow::BalanceChecker> {
+        self.balance.as_deref()
+    }
 
-        Ok(true)
-    } else {
-        println!("⏹️  Recording stopped. Duration: {}ms", duration);
+    fn transcriber(&self) -> Result<&dyn recording_flow::Transcriber, String> {
+        match &self.transcriber {
+            Ok(transcriber) => Ok(&**transcriber),
+            Err(message) => Err(message.clone()),
+        }
+    }
 
-        // Stop audio capture and get samples with native sample rate
-        let (audio_samples, sample_rate) = voice::stop_recording_global();
-        println!("📊 Captured {} audio samples at {}Hz", audio_samples.len(), sample_rate);
+    fn on_started(&self) {
+        // Show audio indicator bar at top of screen (one window, never focused)
+        if let Err(e) = self.indicator.show(|| overlay::build_audio_indicator(self.app)) {
+            tracing::warn!("Audio indicator not shown: {}", e);
+        }
+        sync_cancel_hotkey(self.app);
+    }
 
+    fn on_stopped(&self) {
         // Hide overlay window IMMEDIATELY (user gets instant feedback)
-        if let Some(overlay) = app.get_webview_window("audio-indicator") {
-            let _ = overlay.hide();
-            println!("📊 Audio indicator overlay hidden");
+        if let Err(e) = self.indicator.hide() {
+            tracing::warn!("Audio indicator not hidden: {}", e);
         }
+        sync_cancel_hotkey(self.app);
+    }
+}
+
+/**
+ * DESIGN DECISION: Cancel discards the recording instead of transcribing it
+ * WHY: A recording started by mistake could only be stopped, which transcribes and bills
+ *
+ * REASONING CHAIN:
+ * 1. recording_flow::cancel stops capture, clears the buffer, resets RecordingState
+ * 2. No transcription request is made, so no tokens are charged
+ * 3. Hide overlay, drop the Escape hotkey, emit "recording-cancelled"
+ * 4. Returns false (and emits nothing) when no recording was in progress
+ */
+#[tauri::command]
+fn cancel_recording(
+    state: tauri::State<'_, Mutex<RecordingState>>,
+    audio_buffer: tauri::State<'_, Arc<Mutex<Vec<f32>>>>,
+    indicator: tauri::State<'_, overlay::AudioIndicator>,
+    app: AppHandle,
+) -> Result<bool, String> {
+    let audio = voice::GlobalRecorder::new(Arc::clone(&audio_buffer), app.clone());
+    if !recording_flow::cancel(state.inner(), &audio)? {
+        return Ok(false);
+    }
 
-        // Load settings to get license key and API URL
-        let settings = get_settings().map_err(|e| format!("Failed to load settings: {}", e))?;
+    if let Err(e) = indicator.hide() {
+        tracing::warn!("Audio indicator not hidden: {}", e);
+    }
+    sync_cancel_hotkey(&app);
+    app.emit("recording-cancelled", ()).map_err(|e| e.to_string())?;
 
-        // Check for license key (new monetization model)
-        if settings.license_key.is_empty() {
-            // Fallback: Check for legacy OpenAI API key (BYOK model - migration period)
-            if !settings.openai_api_key.is_empty() {
-                println!("⚠️  BYOK model deprecated. Please activate device to get license key.");
-                return Err("BYOK model deprecated. Please activate device to get license key. Visit dashboard to activate.".to_string());
-            }
+    Ok(true)
+}
 
-            println!("⚠️  License key not configured. Please activate device first.");
-            return Err("License key not configured. Please activate device first. Visit dashboard to activate.".to_string());
-        }
+/// Hotkey that cancels the current recording
+const CANCEL_HOTKEY: &str = "Escape";
 
-        // Transcribe audio via server API (proxies to OpenAI with credit tracking)
-        println!("🔄 Transcribing audio via server API...");
-        let transcript = match transcription::transcribe_audio(
-            &audio_samples,
-            sample_rate, // Use native sample rate
-            &settings.license_key,
-            &settings.global_network_api_endpoint,
-        )
-        .await
-        {
-            Ok(text) => text,
-            Err(e) => {
-                // Handle structured errors with frontend event emission (BUG-004)
-                use transcription::TranscriptionError;
-
-                match &e {
-                    TranscriptionError::Unauthorized { message } => {
-                        // Emit event to show license activation dialog
-                        let _ = app.emit("show-license-activation", message.clone());
-                        return Err(format!("License invalid: {}. Please re-activate your device.", message));
-                    }
-                    TranscriptionError::PaymentRequired { message, balance_tokens, required_tokens } => {
-                        // Emit event to show token purchase dialog with balance
-                        let payload = serde_json::json!({
-                            "message": message,
-                            "balance": balance_tokens,
-                            "required": required_tokens,
-                        });
-                        let _ = app.emit("show-token-purchase", payload);
-                        return Err(format!("Insufficient tokens: {}. You have {} tokens, need {} tokens.",
-                            message, balance_tokens, required_tokens));
-                    }
-                    TranscriptionError::Forbidden { message } => {
-                        // Emit event to show device activation dialog
-                        let _ = app.emit("show-device-activation", message.clone());
-                        return Err(format!("Device not active: {}. Please activate your device.", message));
-                    }
-                    TranscriptionError::ServerError { message } |
-                    TranscriptionError::NetworkError { message } => {
-                        // Emit event to show retry dialog
-                        let _ = app.emit("show-retry-dialog", message.clone());
-                        return Err(format!("Temporary error: {}. Please try again.", message));
-                    }
-                    _ => {
-                        // Generic error handling for NotFound, ParseError
-                        return Err(format!("Transcription failed: {}", e));
-                    }
-                }
+/**
+ * DESIGN DECISION: Escape registered only while recording, (un)registered off the handler
+ * WHY: A permanent global Escape binding would swallow Escape in every other app, and the
+ * global-shortcut plugin holds its lock while a shortcut handler runs, so registering from
+ * the recording hotkey (or unregistering from the Escape handler) deadlocks
+ *
+ * REASONING CHAIN:
+ * 1. Spawned task waits for the plugin lock (handler finished)
+ * 2. It reads RecordingState when it runs, so quick start → stop presses leave the
+ *    registration matching the final state
+ * 3. Recording and not registered → register; idle and registered → unregister
+ */
+fn sync_cancel_hotkey(app: &AppHandle) {
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let is_recording = app
+            .state::<Mutex<RecordingState>>()
+            .lock()
+            .map(|recording| recording.is_recording)
+            .unwrap_or(false);
+        let is_registered = app.global_shortcut().is_registered(CANCEL_HOTKEY);
+
+        if is_recording && !is_registered {
+            register_cancel_hotkey(&app);
+        } else if !is_recording && is_registered {
+            if let Err(e) = app.global_shortcut().unregister(CANCEL_HOTKEY) {
+                tracing::debug!("Cancel hotkey not unregistered: {}", e);
             }
-        };
-
-        println!("✅ Transcription received: {}", transcript);
+        }
+    });
+}
 
-        // Type transcript at cursor position via OS-level keyboard simulation
-        transcription::type_transcript(&transcript)
-            .map_err(|e| format!("Failed to type transcript: {}", e))?;
+fn register_cancel_hotkey(app: &AppHandle) {
+    let app_handle = app.clone();
 
-        let result = VoiceCaptureResult {
-            text: transcript.clone(),
-            confidence: 0.95, // OpenAI Whisper is highly accurate
-            duration_ms: duration,
-        };
+    let result = app.global_shortcut().on_shortcut(CANCEL_HOTKEY, move |_app, _shortcut, event| {
+        if event.state == ShortcutState::Pressed {
+            tracing::info!("Escape pressed - cancelling recording");
 
-        // Emit event to frontend with result
-        app.emit("recording-stopped", &result).map_err(|e| e.to_string())?;
+            let state = app_handle.state::<Mutex<RecordingState>>();
+            let audio_buffer = app_handle.state::<Arc<Mutex<Vec<f32>>>>();
+            let indicator = app_handle.state::<overlay::AudioIndicator>();
 
-        println!("✅ Voice capture complete: {}", transcript);
+            if let Err(e) = cancel_recording(state, audio_buffer, indicator, app_handle.clone()) {
+                tracing::error!("Error cancelling recording: {}", e);
+            }
+        }
+    });
 
-        Ok(false)
+    if let Err(e) = result {
+        tracing::warn!("Failed to register cancel hotkey: {}", e);
     }
 }
 
@@ -640,10 +882,14 @@ async fn toggle_recording(
 fn start_capture(state: tauri::State<Mutex<RecordingState>>) -> Result<(), String> {
     let mut recording = state.lock().map_err(|e| format!("Lock error: {}", e))?;
 
+    if recording.ipc_capture {
+        return Err("Voice capture for IDE in progress".to_string());
+    }
+
     if !recording.is_recording {
         recording.is_recording = true;
         recording.start_time = Some(std::time::Instant::now());
-        println!("🎤 Recording started (frontend)...");
+        tracing::info!("Recording started (frontend)...");
     }
 
     Ok(())
@@ -653,6 +899,10 @@ fn start_capture(state: tauri::State<Mutex<RecordingState>>) -> Result<(), Strin
 fn stop_capture(state: tauri::State<Mutex<RecordingState>>) -> Result<VoiceCaptureResult, String> {
     let mut recording = state.lock().map_err(|e| format!("Lock error: {}", e))?;
 
+    if recording.ipc_capture {
+        return Err("Voice capture for IDE in progress".to_string());
+    }
+
     if recording.is_recording {
         let duration = recording
             .start_time
@@ -662,7 +912,7 @@ fn stop_capture(state: tauri::State<Mutex<RecordingState>>) -> Result<VoiceCaptu
         recording.is_recording = false;
         recording.start_time = None;
 
-        println!("⏹️  Recording stopped (frontend). Duration: {}ms", duration);
+        tracing::info!("Recording stopped (frontend). Duration: {}ms", duration);
 
         // TODO (Phase 3): Real transcription
         Ok(VoiceCaptureResult {
@@ -675,6 +925,19 @@ fn stop_capture(state: tauri::State<Mutex<RecordingState>>) -> Result<VoiceCaptu
     }
 }
 
+/**
+ * Audio format the next recording will use (Settings shows it next to the rate picker)
+ *
+ * Capture rate is the default microphone's native rate; transcription rate is
+ * settings.transcription_sample_rate, the rate ServerTranscriber asks recording_flow for.
+ */
+#[tauri::command]
+fn get_audio_format() -> Result<resample::AudioFormat, String> {
+    let capture_sample_rate = voice::VoiceCapture::default_input_sample_rate().map_err(|e| e.to_string())?;
+    let settings = read_settings(&get_settings_path());
+    Ok(resample::negotiate(capture_sample_rate, Some(settings.transcription_sample_rate)))
+}
+
 #[tauri::command]
 fn list_audio_devices() -> Result<Vec<String>, String> {
     // TODO (Phase 3): Real device enumeration with cpal
@@ -707,61 +970,172 @@ fn get_settings_path() -> std::path::PathBuf {
  */
 #[tauri::command]
 fn get_settings() -> Result<AppSettings, String> {
-    let settings_path = get_settings_path();
+    Ok(read_settings(&get_settings_path()))
+}
 
+fn read_settings(settings_path: &std::path::Path) -> AppSettings {
     if !settings_path.exists() {
-        println!("ℹ️ No settings file found, using defaults");
-        return Ok(AppSettings::default());
+        tracing::info!("No settings file found, using defaults");
+        return AppSettings::default();
     }
 
-    match std::fs::read_to_string(&settings_path) {
+    match std::fs::read_to_string(settings_path) {
         Ok(json) => {
             match serde_json::from_str(&json) {
                 Ok(settings) => {
-                    println!("📖 Settings loaded: {:?}", settings);
-                    Ok(settings)
+                    tracing::debug!("Settings loaded: {:?}", settings);
+                    settings
                 }
                 Err(e) => {
-                    eprintln!("⚠️ Failed to parse settings: {}", e);
-                    Ok(AppSettings::default())
+                    tracing::warn!("Failed to parse settings: {}", e);
+                    AppSettings::default()
                 }
             }
         }
         Err(e) => {
-            eprintln!("⚠️ Failed to read settings: {}", e);
-            Ok(AppSettings::default())
+            tracing::warn!("Failed to read settings: {}", e);
+            AppSettings::default()
         }
     }
 }
 
 /**
- * DESIGN DECISION: Save settings and re-register hotkeys
- * WHY: Settings must persist AND take effect immediately
+ * DESIGN DECISION: SettingsStore (Tauri managed state) owns every settings.json write
+ * WHY: save_settings, activate_license, configure_pattern_network, and IPC settings sync
+ *      all change settings; a writer that loads, edits, and saves without holding a lock
+ *      across all three steps silently discards a concurrent writer's change
  *
  * REASONING CHAIN:
- * 1. Serialize settings to JSON
- * 2. Write to file in user's home directory
- * 3. Re-register hotkeys with new settings
- * 4. Return success/error to frontend
+ * 1. One SettingsStore is managed by Tauri and cloned into the IPC server at startup
+ * 2. Partial changes go through update(|settings| ...), full replacements through persist_settings
+ * 3. The store's lock is held across load → modify → save
+ * 4. JSON is written to a sibling temp file, then renamed over settings.json (atomic)
+ * 5. A settings file that exists but won't parse is never silently replaced with defaults:
+ *    update() refuses to write, persist_settings() moves it to settings.json.corrupt first
+ * 6. Result: No lost updates, no truncated files, no user settings wiped by a bad edit
+ *
+ * Callers must finish any slow work (network calls) before update():
+ * the closure runs with the lock held.
+ *
+ * PATTERN: Pattern-SETTINGS-SYNC-001 (VS Code → Desktop Settings Sync)
  */
-#[tauri::command]
-fn save_settings(settings: AppSettings, app: AppHandle) -> Result<(), String> {
-    let settings_path = get_settings_path();
+#[derive(Clone)]
+struct SettingsStore {
+    path: std::path::PathBuf,
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl SettingsStore {
+    fn new(path: std::path::PathBuf) -> Self {
+        Self {
+            path,
+            write_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Replace settings.json with `settings`, backing up an unparseable existing file
+    fn persist_settings(&self, settings: &AppSettings) -> Result<(), String> {
+        let _guard = self.lock();
+
+        if let Err(e) = load_existing_settings(&self.path) {
+            backup_corrupt_settings(&self.path, &e)?;
+        }
+        write_settings_atomic(&self.path, settings)
+    }
+
+    /// Apply `modify` to the stored settings; errors if the existing file can't be loaded
+    fn update<F>(&self, modify: F) -> Result<AppSettings, String>
+    where
+        F: FnOnce(&mut AppSettings),
+    {
+        let _guard = self.lock();
+
+        let mut settings = load_existing_settings(&self.path)?;
+        modify(&mut settings);
+        write_settings_atomic(&self.path, &settings)?;
+
+        Ok(settings)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ()> {
+        // A panicked writer never leaves a partial file behind, so a poisoned lock is safe to reuse
+        self.write_lock.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Strict load for writers: defaults only when no file exists yet
+fn load_existing_settings(settings_path: &std::path::Path) -> Result<AppSettings, String> {
+    if !settings_path.exists() {
+        return Ok(AppSettings::default());
+    }
+
+    let json = std::fs::read_to_string(settings_path)
+        .map_err(|e| format!("Failed to read settings: {}", e))?;
+
+    serde_json::from_str(&json).map_err(|e| {
+        format!(
+            "Settings file {} is invalid ({}); refusing to overwrite it",
+            settings_path.display(),
+            e
+        )
+    })
+}
 
+/// Caller must hold the store's lock
+fn backup_corrupt_settings(settings_path: &std::path::Path, reason: &str) -> Result<(), String> {
+    let backup_path = settings_path.with_extension("json.corrupt");
+    std::fs::rename(settings_path, &backup_path)
+        .map_err(|e| format!("Failed to back up invalid settings file: {}", e))?;
+
+    tracing::warn!("{}; backed up to {}", reason, backup_path.display());
+    Ok(())
+}
+
+/// Caller must hold the store's lock
+fn write_settings_atomic(settings_path: &std::path::Path, settings: &AppSettings) -> Result<(), String> {
     // Create directory if it doesn't exist
     if let Some(parent) = settings_path.parent() {
         std::fs::create_dir_all(parent)
             .map_err(|e| format!("Failed to create settings directory: {}", e))?;
     }
 
-    // Serialize and write to file
-    let json = serde_json::to_string_pretty(&settings)
+    let json = serde_json::to_string_pretty(settings)
         .map_err(|e| format!("Failed to serialize settings: {}", e))?;
 
-    std::fs::write(&settings_path, json)
+    let tmp_path = settings_path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, json)
         .map_err(|e| format!("Failed to write settings: {}", e))?;
 
-    println!("💾 Settings saved: {:?}", settings);
+    std::fs::rename(&tmp_path, settings_path)
+        .map_err(|e| format!("Failed to replace settings file: {}", e))?;
+
+    Ok(())
+}
+
+/**
+ * DESIGN DECISION: Save settings and re-register hotkeys
+ * WHY: Settings must persist AND take effect immediately
+ *
+ * REASONING CHAIN:
+ * 1. Reject invalid file watcher ignore globs or transcription rate (nothing saved)
+ * 2. Serialize settings to JSON
+ * 3. Write to file in user's home directory
+ * 4. Re-register hotkeys with new settings
+ * 5. Return success/error to frontend
+ */
+#[tauri::command]
+fn save_settings(settings: AppSettings, app: AppHandle) -> Result<(), String> {
+    for glob in &settings.file_watcher_ignore_globs {
+        system_context::file_watcher::validate_ignore_glob(glob).map_err(|e| format!("{:#}", e))?;
+    }
+    resample::validate_transcription_rate(settings.transcription_sample_rate)?;
+    app.state::<SettingsStore>().persist_settings(&settings)?;
+
+    tracing::debug!("Settings saved: {:?}", settings);
+    rate_limit::server().set_rate(settings.server_requests_per_second);
+    settings
+        .apply_http_timeouts(&app.state::<http::HttpClient>())
+        .map_err(|e| e.to_string())?;
 
     // Re-register hotkeys with new settings
     // Get IPC sender from managed state
@@ -794,44 +1168,37 @@ fn save_settings(settings: AppSettings, app: AppHandle) -> Result<(), String> {
  * Returns success message with user_name and tier or error message
  */
 #[tauri::command]
-async fn activate_license(license_key: String) -> Result<String, String> {
-    println!("🔑 Activating license key: {}...", &license_key[..std::cmp::min(4, license_key.len())]);
-
-    // Load current settings
-    let mut settings = get_settings()
-        .map_err(|e| format!("Failed to load settings: {}", e))?;
+async fn activate_license(
+    license_key: String,
+    http: tauri::State<'_, http::HttpClient>,
+    settings_store: tauri::State<'_, SettingsStore>,
+) -> Result<String, String> {
+    // Reject malformed keys before touching settings or the network
+    let license_key = auth::normalize_license_key(&license_key).map_err(|e| e.to_string())?;
+    tracing::info!("Activating license key: {}...", &license_key[..4]);
 
     // Get API URL from settings
-    let api_url = settings.global_network_api_endpoint.clone();
+    let api_url = get_settings()
+        .map_err(|e| format!("Failed to load settings: {}", e))?
+        .global_network_api_endpoint;
 
     // Validate license key with server
-    let validation_response = auth::validate_license_key(&license_key, &api_url)
+    let validation_response = auth::validate_license_key(&http.client(), &license_key, &api_url)
         .await
         .map_err(|e| format!("{}", e))?; // Convert anyhow::Error to String
 
-    // Store license key and validation response in settings
-    settings.license_key = license_key.trim().to_string();
-    settings.user_id = Some(validation_response.user_id.clone());
-    settings.device_id = Some(validation_response.device_id.clone());
-    settings.tier = Some(validation_response.tier.clone());
-
-    // Save settings
-    let settings_path = get_settings_path();
-    if let Some(parent) = settings_path.parent() {
-        std::fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
-    }
-
-    let json = serde_json::to_string_pretty(&settings)
-        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
-
-    std::fs::write(&settings_path, json)
-        .map_err(|e| format!("Failed to save settings: {}", e))?;
+    // Store license key and validation response in settings (re-read after the network call)
+    settings_store.update(|settings| {
+        settings.license_key = license_key.clone();
+        settings.user_id = Some(validation_response.user_id.clone());
+        settings.device_id = Some(validation_response.device_id.clone());
+        settings.tier = Some(validation_response.tier.clone());
+    })?;
 
-    println!("✅ License activated successfully");
-    println!("   User ID: {}", validation_response.user_id);
-    println!("   Device ID: {}", validation_response.device_id);
-    println!("   Tier: {}", validation_response.tier);
+    tracing::info!("License activated successfully");
+    tracing::info!("User ID: {}", validation_response.user_id);
+    tracing::info!("Device ID: {}", validation_response.device_id);
+    tracing::info!("Tier: {}", validation_response.tier);
 
     Ok(format!("Device activated successfully! Welcome, {}. Your {} tier license is now active.",
         validation_response.user_name,
@@ -870,20 +1237,20 @@ async fn activate_license(license_key: String) -> Result<String, String> {
 fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<IpcSender>) -> Result<(), String> {
     // Unregister all existing shortcuts
     if let Err(e) = app.global_shortcut().unregister_all() {
-        eprintln!("⚠️ Failed to unregister existing hotkeys: {}", e);
+        tracing::warn!("Failed to unregister existing hotkeys: {}", e);
     }
 
     // Register recording hotkey if configured
     if let Some(hotkey) = &settings.recording_hotkey {
         // Check if hotkey is a mouse button (contains "Click")
         if hotkey.contains("Click") {
-            println!("⚠️ Mouse button hotkeys not yet supported (recording hotkey: {}). Keyboard hotkeys only for now.", hotkey);
-            println!("💡 Please set a keyboard hotkey (e.g., F13, CommandOrControl+Shift+R, etc.)");
+            tracing::warn!("Mouse button hotkeys not yet supported (recording hotkey: {}). Keyboard hotkeys only for now.", hotkey);
+            tracing::info!("Please set a keyboard hotkey (e.g., F13, CommandOrControl+Shift+R, etc.)");
             // Skip mouse button registration but continue to register other hotkeys
         } else {
             // If hotkey is "Backquote", register both backtick and tilde
             if hotkey == "Backquote" {
-                println!("🔥 Registering both backtick (`) and tilde (~) hotkeys");
+                tracing::info!("Registering both backtick (`) and tilde (~) hotkeys");
 
                 // Register backtick (`) - focuses Voice panel in VS Code
                 let app_handle = app.clone();
@@ -891,7 +1258,7 @@ fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<I
 
                 match app.global_shortcut().on_shortcut("Backquote", move |_app, _shortcut, event| {
                     if event.state == ShortcutState::Pressed {
-                        println!("🔥 Backtick (`) pressed - will focus Voice panel");
+                        tracing::info!("Backtick (`) pressed - will focus Voice panel");
 
                         let app_clone = app_handle.clone();
                         let ipc_sender_for_async = ipc_sender_clone.clone();
@@ -899,26 +1266,27 @@ fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<I
                         // Get state and audio buffer
                         let state = app_clone.state::<Mutex<RecordingState>>();
                         let audio_buffer = app_clone.state::<Arc<Mutex<Vec<f32>>>>();
+                        let indicator = app_clone.state::<overlay::AudioIndicator>();
                         let app_for_async = app_clone.clone();
 
                         // Spawn blocking task to run async toggle_recording
                         tauri::async_runtime::block_on(async move {
-                            match toggle_recording(state, audio_buffer, app_for_async).await {
+                            match toggle_recording(state, audio_buffer, indicator, app_for_async).await {
                                 Ok(is_recording) => {
-                                    println!("✅ Recording state: {}", is_recording);
+                                    tracing::info!("Recording state: {}", is_recording);
 
                                     // If we just started recording, send IPC message to focus Voice panel
                                     if is_recording {
                                         if let Some(sender) = ipc_sender_for_async {
-                                            println!("📡 Sending IPC message to focus Voice panel");
+                                            tracing::info!("Sending IPC message to focus Voice panel");
                                             if let Err(e) = sender.send(system_context::types::ContextUpdate::FocusVoicePanel) {
-                                                eprintln!("⚠️ Failed to send FocusVoicePanel IPC message: {}", e);
+                                                tracing::warn!("Failed to send FocusVoicePanel IPC message: {}", e);
                                             }
                                         }
                                     }
                                 }
                                 Err(e) => {
-                                    eprintln!("❌ Error toggling recording: {}", e);
+                                    tracing::error!("Error toggling recording: {}", e);
                                 }
                             }
                         });
@@ -926,15 +1294,15 @@ fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<I
                 }) {
                     Ok(_) => {
                         match app.global_shortcut().register("Backquote") {
-                            Ok(_) => println!("✅ Backtick (`) hotkey registered"),
+                            Ok(_) => tracing::info!("Backtick (`) hotkey registered"),
                             Err(e) => {
-                                eprintln!("⚠️ Failed to register backtick hotkey: {}", e);
+                                tracing::warn!("Failed to register backtick hotkey: {}", e);
                                 return Err("Failed to register backtick hotkey".to_string());
                             }
                         }
                     }
                     Err(e) => {
-                        eprintln!("⚠️ Failed to setup backtick hotkey listener: {}", e);
+                        tracing::warn!("Failed to setup backtick hotkey listener: {}", e);
                         return Err(format!("Failed to setup hotkey listener: {}", e));
                     }
                 }
@@ -944,24 +1312,25 @@ fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<I
 
                 match app.global_shortcut().on_shortcut("Shift+Backquote", move |_app, _shortcut, event| {
                     if event.state == ShortcutState::Pressed {
-                        println!("🔥 Tilde (~) pressed - no focus change");
+                        tracing::info!("Tilde (~) pressed - no focus change");
 
                         let app_clone = app_handle.clone();
 
                         // Get state and audio buffer
                         let state = app_clone.state::<Mutex<RecordingState>>();
                         let audio_buffer = app_clone.state::<Arc<Mutex<Vec<f32>>>>();
+                        let indicator = app_clone.state::<overlay::AudioIndicator>();
                         let app_for_async = app_clone.clone();
 
                         // Spawn blocking task to run async toggle_recording
                         tauri::async_runtime::block_on(async move {
-                            match toggle_recording(state, audio_buffer, app_for_async).await {
+                            match toggle_recording(state, audio_buffer, indicator, app_for_async).await {
                                 Ok(is_recording) => {
-                                    println!("✅ Recording state: {}", is_recording);
+                                    tracing::info!("Recording state: {}", is_recording);
                                     // Note: No IPC message sent for tilde - just record
                                 }
                                 Err(e) => {
-                                    eprintln!("❌ Error toggling recording: {}", e);
+                                    tracing::error!("Error toggling recording: {}", e);
                                 }
                             }
                         });
@@ -969,44 +1338,45 @@ fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<I
                 }) {
                     Ok(_) => {
                         match app.global_shortcut().register("Shift+Backquote") {
-                            Ok(_) => println!("✅ Tilde (~) hotkey registered"),
+                            Ok(_) => tracing::info!("Tilde (~) hotkey registered"),
                             Err(e) => {
-                                eprintln!("⚠️ Failed to register tilde hotkey: {}", e);
+                                tracing::warn!("Failed to register tilde hotkey: {}", e);
                                 return Err("Failed to register tilde hotkey".to_string());
                             }
                         }
                     }
                     Err(e) => {
-                        eprintln!("⚠️ Failed to setup tilde hotkey listener: {}", e);
+                        tracing::warn!("Failed to setup tilde hotkey listener: {}", e);
                         return Err(format!("Failed to setup hotkey listener: {}", e));
                     }
                 }
             } else {
                 // For other hotkeys, register as before (no IPC message)
-                println!("🔥 Attempting to register recording hotkey: {}", hotkey);
+                tracing::info!("Attempting to register recording hotkey: {}", hotkey);
 
                 let app_handle = app.clone();
                 let hotkey_str = hotkey.clone();
 
                 match app.global_shortcut().on_shortcut(hotkey.as_str(), move |_app, _shortcut, event| {
                     if event.state == ShortcutState::Pressed {
-                        println!("🔥 Recording hotkey pressed: {}", hotkey_str);
+                        tracing::info!("Recording hotkey pressed: {}", hotkey_str);
 
                         let app_clone = app_handle.clone();
 
                         // Get state and audio buffer
                         let state = app_clone.state::<Mutex<RecordingState>>();
                         let audio_buffer = app_clone.state::<Arc<Mutex<Vec<f32>>>>();
+                        let indicator = app_clone.state::<overlay::AudioIndicator>();
                         let app_for_async = app_clone.clone();
 
                         // Spawn blocking task to run async toggle_recording
                         tauri::async_runtime::block_on(async move {
-                            match toggle_recording(state, audio_buffer, app_for_async).await {
+                            match toggle_recording(state, audio_buffer, indicator, app_for_async).await {
                                 Ok(is_recording) => {
-                                    println!("✅ Recording state: {}", is_recording);
+                                    tracing::info!("Recording state: {}", is_recording);
                                 }
                                 Err(e) => {
-                                    eprintln!("❌ Error toggling recording: {}", e);
+                                    tracing::error!("Error toggling recording: {}", e);
                                 }
                             }
                         });
@@ -1014,15 +1384,15 @@ fn register_hotkeys(app: AppHandle, settings: &AppSettings, ipc_sender: Option<I
                 }) {
                     Ok(_) => {
                         match app.global_shortcut().register(hotkey.as_str()) {
-                            Ok(_) => println!("✅ Recording hotkey registered: {}", hotkey),
+                            Ok(_) => tracing::info!("Recording hotkey registered: {}", hotkey),
                             Err(e) => {
-                                eprintln!("⚠️ Failed to register recording hotkey: {}", e);
+                                tracing::warn!("Failed to register recording hotkey: {}", e);
                                 return Err(format!("Failed to register hotkey '{}'. Try a different combination.", hotkey));
                             }
                         }
                     }
                     Err(e) => {
-                        eprintln!("⚠️ Failed to setup hotkey listener: {}", e);
+                        tracing::warn!("Failed to setup hotkey listener: {}", e);
                         return Err(format!("Failed to setup hotkey listener: {}", e));
                     }
                 }
@@ -1090,15 +1460,48 @@ fn get_pattern_db() -> Result<Connection, String> {
         [],
     ).map_err(|e| format!("Failed to create patterns table: {}", e))?;
 
+    migrate_time_saved_column(&conn)
+        .map_err(|e| format!("Failed to migrate patterns table: {}", e))?;
+
+    // Adds patterns.version and pattern_history on first use
+    storage::PatternHistory::new(&conn, default_pattern_history_limit())
+        .map_err(|e| format!("Failed to initialize pattern history: {}", e))?;
+
     Ok(conn)
 }
 
+/// Adds patterns.estimated_time_saved_minutes to databases created before it existed
+fn migrate_time_saved_column(conn: &Connection) -> rusqlite::Result<()> {
+    storage::postgres::add_column_if_missing(conn, "estimated_time_saved_minutes", "INTEGER")
+}
+
+/// Per-pattern time-saved estimate (None if unset or the pattern doesn't exist)
+fn pattern_time_saved(conn: &Connection, pattern_id: &str) -> Option<u32> {
+    conn.query_row(
+        "SELECT estimated_time_saved_minutes FROM patterns WHERE id = ?1",
+        params![pattern_id],
+        |row| row.get::<_, Option<u32>>(0),
+    )
+    .ok()
+    .flatten()
+}
+
+/// Pattern history bounded by the user's pattern_history_limit setting
+fn pattern_history(conn: &Connection) -> Result<storage::PatternHistory<'_>, String> {
+    let limit = get_settings()
+        .map(|settings| settings.pattern_history_limit)
+        .unwrap_or_else(|_| default_pattern_history_limit());
+
+    storage::PatternHistory::new(conn, limit)
+        .map_err(|e| format!("Failed to initialize pattern history: {}", e))
+}
+
 #[tauri::command]
 async fn get_all_patterns() -> Result<Vec<DatabasePattern>, String> {
     let conn = get_pattern_db()?;
 
     let mut stmt = conn.prepare(
-        "SELECT id, name, description, domain, confidence_score, tags FROM patterns"
+        "SELECT id, name, description, domain, confidence_score, tags, version, estimated_time_saved_minutes FROM patterns"
     ).map_err(|e| format!("Failed to prepare query: {}", e))?;
 
     let patterns = stmt.query_map([], |row| {
@@ -1116,6 +1519,8 @@ async fn get_all_patterns() -> Result<Vec<DatabasePattern>, String> {
             domain: row.get(3)?,
             confidence_score: row.get(4)?,
             tags,
+            version: row.get(6)?,
+            estimated_time_saved_minutes: row.get(7)?,
         })
     }).map_err(|e| format!("Failed to query patterns: {}", e))?;
 
@@ -1134,6 +1539,8 @@ async fn get_all_patterns() -> Result<Vec<DatabasePattern>, String> {
                 domain: "rust".to_string(),
                 confidence_score: Some(0.92),
                 tags: vec!["async".to_string(), "error".to_string(), "rust".to_string()],
+                version: default_pattern_version(),
+                estimated_time_saved_minutes: None,
             },
             DatabasePattern {
                 id: "pattern-002".to_string(),
@@ -1142,6 +1549,8 @@ async fn get_all_patterns() -> Result<Vec<DatabasePattern>, String> {
                 domain: "typescript".to_string(),
                 confidence_score: Some(0.88),
                 tags: vec!["react".to_string(), "hooks".to_string(), "state".to_string()],
+                version: default_pattern_version(),
+                estimated_time_saved_minutes: None,
             },
         ];
 
@@ -1161,7 +1570,7 @@ async fn get_all_patterns() -> Result<Vec<DatabasePattern>, String> {
             ).map_err(|e| format!("Failed to insert seed pattern: {}", e))?;
         }
 
-        println!("✅ Seeded {} patterns to database", seed_patterns.len());
+        tracing::info!("Seeded {} patterns to database", seed_patterns.len());
         return Ok(seed_patterns);
     }
 
@@ -1169,33 +1578,20 @@ async fn get_all_patterns() -> Result<Vec<DatabasePattern>, String> {
 }
 
 #[tauri::command]
-async fn get_pattern(pattern_id: String) -> Result<DatabasePattern, String> {
-    let conn = get_pattern_db()?;
-
-    let mut stmt = conn.prepare(
-        "SELECT id, name, description, domain, confidence_score, tags FROM patterns WHERE id = ?1"
-    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+async fn get_pattern(
+    pattern_id: String,
+    patterns: tauri::State<'_, storage::PatternStoreState>,
+) -> Result<DatabasePattern, String> {
+    patterns.with_store(|store| find_pattern(store, &pattern_id))
+}
 
-    let pattern = stmt.query_row(params![&pattern_id], |row| {
-        let tags_str: String = row.get(5).unwrap_or_default();
-        let tags: Vec<String> = if tags_str.is_empty() {
-            vec![]
-        } else {
-            tags_str.split(',').map(|s| s.trim().to_string()).collect()
-        };
-
-        Ok(DatabasePattern {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            description: row.get(2)?,
-            domain: row.get(3)?,
-            confidence_score: row.get(4)?,
-            tags,
-        })
-    }).map_err(|e| format!("Pattern not found: {}", e))?;
-
-    Ok(pattern)
-}
+/// get_pattern against the store (an alias id resolves to its canonical pattern)
+fn find_pattern(store: &dyn storage::PatternStore, pattern_id: &str) -> Result<DatabasePattern, String> {
+    store
+        .get(pattern_id)?
+        .map(DatabasePattern::from)
+        .ok_or_else(|| format!("Pattern not found: {}", pattern_id))
+}
 
 #[tauri::command]
 async fn update_pattern(pattern: DatabasePattern) -> Result<(), String> {
@@ -1207,8 +1603,8 @@ async fn update_pattern(pattern: DatabasePattern) -> Result<(), String> {
      * 1. Check required fields (name, description)
      * 2. Validate confidence score (0.0-1.0)
      * 3. Sanitize Chain of Thought (remove malicious content)
-     * 4. Update database
-     * 5. Optional: Create git commit (if version control enabled)
+     * 4. Snapshot current row into pattern history, bump version
+     * 5. Update database
      */
 
     // Basic validation
@@ -1226,34 +1622,187 @@ async fn update_pattern(pattern: DatabasePattern) -> Result<(), String> {
         }
     }
 
-    // Update pattern in database
+    // Tags are stored comma-joined; normalize and reject embedded commas
+    let tags = normalize_tags(&pattern.tags).map_err(|e| e.to_string())?;
+
+    // Update pattern in database (prior version kept in history)
     let conn = get_pattern_db()?;
-    let tags_str = pattern.tags.join(",");
+    let history = pattern_history(&conn)?;
+    let content = storage::PatternContent {
+        name: pattern.name,
+        description: pattern.description,
+        domain: Some(pattern.domain),
+        confidence_score: pattern.confidence_score,
+        tags,
+        estimated_time_saved_minutes: pattern.estimated_time_saved_minutes,
+    };
+
+    history.edit(&pattern.id, &content)
+        .map_err(|e| format!("Failed to update pattern: {}", e))?
+        .ok_or_else(|| format!("Pattern not found: {}", pattern.id))?;
 
+    Ok(())
+}
+
+#[tauri::command]
+async fn update_pattern_fields(pattern_id: String, changes: PatternPatch) -> Result<u32, String> {
+    /**
+     * DESIGN DECISION: Only the provided fields are written, in one UPDATE
+     * WHY: No read-modify-write round trip through the UI, so concurrent edits to
+     * other fields survive; payload is just what changed
+     *
+     * Returns the pattern's new version number.
+     */
+    let conn = get_pattern_db()?;
+    let history = pattern_history(&conn)?;
+    patch_pattern(&conn, &history, &pattern_id, &changes)
+}
+
+/**
+ * Apply a PatternPatch with update_pattern's validation and history snapshot
+ *
+ * Empty patch: nothing written, current version returned (still errors if the
+ * pattern doesn't exist).
+ */
+fn patch_pattern(
+    conn: &Connection,
+    history: &storage::PatternHistory,
+    pattern_id: &str,
+    patch: &PatternPatch,
+) -> Result<u32, String> {
+    if patch.name.as_ref().is_some_and(|name| name.trim().is_empty()) {
+        return Err("Pattern name cannot be empty".to_string());
+    }
+
+    if patch.description.as_ref().is_some_and(|description| description.trim().is_empty()) {
+        return Err("Pattern description cannot be empty".to_string());
+    }
+
+    if patch.confidence_score.flatten().is_some_and(|conf| !(0.0..=1.0).contains(&conf)) {
+        return Err("Confidence score must be between 0.0 and 1.0".to_string());
+    }
+
+    let tags_str = match &patch.tags {
+        Some(tags) => Some(normalize_tags(tags).map_err(|e| e.to_string())?.join(",")),
+        None => None,
+    };
+
+    if patch.is_empty() {
+        return history.current_version(pattern_id)
+            .map_err(|e| format!("Failed to query pattern: {}", e))?
+            .ok_or_else(|| format!("Pattern not found: {}", pattern_id));
+    }
+
+    let tx = conn.unchecked_transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    history.snapshot(pattern_id)
+        .map_err(|e| format!("Failed to record pattern history: {}", e))?
+        .ok_or_else(|| format!("Pattern not found: {}", pattern_id))?;
+
+    // COALESCE keeps the stored column wherever the patch passes NULL (field not provided);
+    // nullable columns are written whenever their flag is set, so Some(None) stores NULL
     conn.execute(
         "UPDATE patterns SET
-            name = ?1,
-            description = ?2,
-            domain = ?3,
-            confidence_score = ?4,
-            tags = ?5,
+            name = COALESCE(?1, name),
+            description = COALESCE(?2, description),
+            domain = COALESCE(?3, domain),
+            confidence_score = CASE WHEN ?4 THEN ?5 ELSE confidence_score END,
+            tags = COALESCE(?6, tags),
+            estimated_time_saved_minutes = CASE WHEN ?7 THEN ?8 ELSE estimated_time_saved_minutes END,
+            version = version + 1,
             updated_at = CURRENT_TIMESTAMP
-         WHERE id = ?6",
+         WHERE id = ?9",
         params![
-            &pattern.name,
-            &pattern.description,
-            &pattern.domain,
-            &pattern.confidence_score,
+            &patch.name,
+            &patch.description,
+            &patch.domain,
+            patch.confidence_score.is_some(),
+            patch.confidence_score.flatten(),
             &tags_str,
-            &pattern.id
+            patch.estimated_time_saved_minutes.is_some(),
+            patch.estimated_time_saved_minutes.flatten(),
+            pattern_id
         ],
     ).map_err(|e| format!("Failed to update pattern: {}", e))?;
 
-    Ok(())
+    tx.commit().map_err(|e| format!("Failed to update pattern: {}", e))?;
+
+    history.current_version(pattern_id)
+        .map_err(|e| format!("Failed to query pattern: {}", e))?
+        .ok_or_else(|| format!("Pattern not found: {}", pattern_id))
 }
 
 #[tauri::command]
-async fn delete_pattern(pattern_id: String) -> Result<(), String> {
+async fn get_pattern_history(pattern_id: String) -> Result<Vec<storage::PatternVersion>, String> {
+    /**
+     * DESIGN DECISION: Return prior versions newest first
+     * WHY: Pattern editor shows "what changed recently" above older snapshots
+     */
+    let conn = get_pattern_db()?;
+    let history = pattern_history(&conn)?;
+
+    if history.current_version(&pattern_id)
+        .map_err(|e| format!("Failed to query pattern: {}", e))?
+        .is_none()
+    {
+        return Err(format!("Pattern not found: {}", pattern_id));
+    }
+
+    history.versions(&pattern_id)
+        .map_err(|e| format!("Failed to query pattern history: {}", e))
+}
+
+#[tauri::command]
+async fn revert_pattern(pattern_id: String, version: u32) -> Result<u32, String> {
+    /**
+     * DESIGN DECISION: Revert creates a new version with the old content
+     * WHY: Reverted-away content stays in history, so a bad revert can be undone
+     *
+     * Returns the pattern's new version number.
+     */
+    let conn = get_pattern_db()?;
+    let history = pattern_history(&conn)?;
+
+    history.revert(&pattern_id, version)
+        .map_err(|e| format!("Failed to revert pattern: {}", e))?
+        .ok_or_else(|| format!("Version {} of pattern {} not found in history", version, pattern_id))
+}
+
+#[tauri::command]
+async fn get_pattern_stats(top_n: Option<usize>) -> Result<storage::PatternLibraryStats, String> {
+    /**
+     * DESIGN DECISION: Library overview from the patterns table + pattern_match analytics
+     * WHY: Overview panel shows totals, domains, confidence spread and most-used patterns
+     *
+     * Usage is best-effort: without analytics.db, most_used is empty rather than failing.
+     */
+    let conn = get_pattern_db()?;
+
+    let usage = match get_usage_tracker() {
+        Ok(tracker) => {
+            let from = chrono::DateTime::<chrono::Utc>::UNIX_EPOCH;
+            let to = chrono::Utc::now() + chrono::Duration::minutes(1);
+            let events = tracker
+                .query_events(from, to, Some(&[EventType::PatternMatch]))
+                .map_err(|e| format!("Failed to query pattern usage: {}", e))?;
+            storage::stats::usage_counts(&events)
+        }
+        Err(e) => {
+            tracing::warn!("Pattern usage unavailable: {}", e);
+            HashMap::new()
+        }
+    };
+
+    storage::stats::pattern_stats(&conn, &usage, top_n.unwrap_or(storage::DEFAULT_TOP_PATTERNS))
+        .map_err(|e| format!("Failed to aggregate pattern statistics: {}", e))
+}
+
+#[tauri::command]
+async fn delete_pattern(
+    pattern_id: String,
+    patterns: tauri::State<'_, storage::PatternStoreState>,
+) -> Result<(), String> {
     /**
      * DESIGN DECISION: Soft delete with confirmation
      * WHY: Prevent accidental data loss, enable rollback
@@ -1264,21 +1813,19 @@ async fn delete_pattern(pattern_id: String) -> Result<(), String> {
      * 3. If referenced → warn user (hard delete breaks references)
      * 4. Soft delete: Mark as deleted, keep in database
      * 5. Hard delete: Optional, requires explicit confirmation
+     *
+     * The store removes the pattern with its aliases and version history.
      */
+    patterns.with_store(|store| delete_from_store(store, &pattern_id))
+}
 
-    // Delete pattern from database
-    let conn = get_pattern_db()?;
-
-    let rows_deleted = conn.execute(
-        "DELETE FROM patterns WHERE id = ?1",
-        params![&pattern_id],
-    ).map_err(|e| format!("Failed to delete pattern: {}", e))?;
-
-    if rows_deleted == 0 {
-        return Err(format!("Pattern not found: {}", pattern_id));
+/// delete_pattern against the store; unknown ids are an error
+fn delete_from_store(store: &dyn storage::PatternStore, pattern_id: &str) -> Result<(), String> {
+    if store.delete(pattern_id)? {
+        Ok(())
+    } else {
+        Err(format!("Pattern not found: {}", pattern_id))
     }
-
-    Ok(())
 }
 
 #[tauri::command]
@@ -1310,20 +1857,24 @@ async fn create_pattern(pattern: DatabasePattern) -> Result<String, String> {
         pattern.id.clone()
     };
 
+    // Tags are stored comma-joined; normalize and reject embedded commas
+    let tags = normalize_tags(&pattern.tags).map_err(|e| e.to_string())?;
+
     // Insert pattern into database
     let conn = get_pattern_db()?;
-    let tags_str = pattern.tags.join(",");
+    let tags_str = tags.join(",");
 
     conn.execute(
-        "INSERT INTO patterns (id, name, description, domain, confidence_score, tags)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        "INSERT INTO patterns (id, name, description, domain, confidence_score, tags, estimated_time_saved_minutes)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
         params![
             &pattern_id,
             &pattern.name,
             &pattern.description,
             &pattern.domain,
             &pattern.confidence_score,
-            &tags_str
+            &tags_str,
+            &pattern.estimated_time_saved_minutes
         ],
     ).map_err(|e| format!("Failed to create pattern: {}", e))?;
 
@@ -1331,53 +1882,153 @@ async fn create_pattern(pattern: DatabasePattern) -> Result<String, String> {
 }
 
 #[tauri::command]
-async fn search_patterns(query: String) -> Result<Vec<DatabasePattern>, String> {
+async fn search_patterns(
+    query: String,
+    patterns: tauri::State<'_, storage::PatternStoreState>,
+) -> Result<Vec<DatabasePattern>, String> {
     /**
-     * DESIGN DECISION: Search across name, description, tags, Chain of Thought
+     * DESIGN DECISION: Search across name, description, domain and tags
      * WHY: User searches with natural language ("how to handle async errors")
      *
      * REASONING CHAIN:
-     * 1. Full-text search across all pattern fields
-     * 2. Generate embeddings for query (semantic search)
-     * 3. Score patterns by relevance (cosine similarity)
-     * 4. Return top N patterns sorted by score
+     * 1. Case-insensitive substring match through the pattern store
+     * 2. Highest confidence first (store order)
+     * 3. Patterns in the user's selected domains first (soft boost, others still returned)
+     * 4. Semantic matches go through search_pattern_index instead
      * 5. Performance: <100ms for 10k patterns
      */
+    let selected_domains = get_settings()?.selected_domains;
+    patterns.with_store(|store| search_store(store, &query, &selected_domains))
+}
 
-    // Simple full-text search using SQLite LIKE
-    // FUTURE: Implement semantic search with vector_store for better results
-    let conn = get_pattern_db()?;
-    let search_term = format!("%{}%", query.to_lowercase());
+/// search_patterns against the store
+fn search_store(
+    store: &dyn storage::PatternStore,
+    query: &str,
+    selected_domains: &[String],
+) -> Result<Vec<DatabasePattern>, String> {
+    let query = storage::PatternQuery { text: Some(query.to_string()), ..Default::default() };
+    let mut patterns: Vec<DatabasePattern> = store
+        .search(&query)?
+        .into_iter()
+        .map(DatabasePattern::from)
+        .collect();
 
-    let mut stmt = conn.prepare(
-        "SELECT id, name, description, domain, confidence_score, tags FROM patterns
-         WHERE LOWER(name) LIKE ?1
-            OR LOWER(description) LIKE ?1
-            OR LOWER(domain) LIKE ?1
-            OR LOWER(tags) LIKE ?1"
-    ).map_err(|e| format!("Failed to prepare search query: {}", e))?;
-
-    let patterns = stmt.query_map(params![&search_term], |row| {
-        let tags_str: String = row.get(5).unwrap_or_default();
-        let tags: Vec<String> = if tags_str.is_empty() {
-            vec![]
-        } else {
-            tags_str.split(',').map(|s| s.trim().to_string()).collect()
-        };
+    boost_selected_domains(&mut patterns, selected_domains);
+    Ok(patterns)
+}
 
-        Ok(DatabasePattern {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            description: row.get(2)?,
-            domain: row.get(3)?,
-            confidence_score: row.get(4)?,
-            tags,
-        })
-    }).map_err(|e| format!("Failed to search patterns: {}", e))?
-      .collect::<Result<Vec<_>, _>>()
-      .map_err(|e| format!("Failed to collect search results: {}", e))?;
+/// Move patterns in a selected domain ahead of the rest (stable; nothing removed)
+fn boost_selected_domains(patterns: &mut [DatabasePattern], selected_domains: &[String]) {
+    patterns.sort_by_key(|pattern| {
+        !selected_domains
+            .iter()
+            .any(|domain| domain.eq_ignore_ascii_case(pattern.domain.trim()))
+    });
+}
 
-    Ok(patterns)
+/**
+ * DESIGN DECISION: Rebuild runs in the background, progress streamed as events
+ * WHY: Semantic index rebuild after bulk edits can take seconds; the UI needs feedback
+ *
+ * REASONING CHAIN:
+ * 1. Claim RebuildGuard → error if a rebuild is already running
+ * 2. Read the library from patterns.db (same store pattern sync writes)
+ * 3. Hand it to the managed PatternIndexService (index outlives the command)
+ * 4. Emit `index-rebuild-progress` { processed, total } per pattern
+ * 5. Emit `index-rebuild-complete` with PatternIndexStatistics (or `index-rebuild-failed`)
+ * 6. Command returns immediately once the rebuild has started
+ *
+ * PATTERN: Pattern-INDEX-001 (Pattern Index Rebuild)
+ * RELATED: index_rebuild.rs, PatternIndex::rebuild_from_patterns, search_pattern_index
+ */
+#[tauri::command]
+fn rebuild_pattern_index(
+    app: AppHandle,
+    guard: tauri::State<'_, index_rebuild::RebuildGuard>,
+    service: tauri::State<'_, index_rebuild::PatternIndexService>,
+) -> Result<(), String> {
+    let ticket = guard.try_start()
+        .ok_or_else(|| "Pattern index rebuild already in progress".to_string())?;
+    let library = index_rebuild::load_library(&get_pattern_db()?)?;
+    let embedding = EmbeddingConfig { precision: get_settings()?.embedding_precision, ..Default::default() };
+
+    let service = service.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let _ticket = ticket;
+        let progress_app = app.clone();
+        let result = service.rebuild(library, embedding, move |progress| {
+            let _ = progress_app.emit(index_rebuild::PROGRESS_EVENT, progress);
+        }).await;
+
+        match result {
+            Ok(stats) => {
+                tracing::info!(total_patterns = stats.total_patterns, "Pattern index rebuilt");
+                let _ = app.emit(index_rebuild::COMPLETE_EVENT, stats);
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Pattern index rebuild failed");
+                let _ = app.emit(index_rebuild::FAILED_EVENT, e);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/**
+ * Semantic search over the last rebuilt pattern index
+ *
+ * Empty until rebuild_pattern_index has run in this session.
+ * Patterns in the user's selected domains rank higher (boost, not filter).
+ */
+#[tauri::command]
+async fn search_pattern_index(
+    service: tauri::State<'_, index_rebuild::PatternIndexService>,
+    query: String,
+) -> Result<Vec<index_rebuild::IndexMatch>, String> {
+    // No selected domains → no context, so search ranks exactly as without one
+    let selected_domains = get_settings()?.selected_domains;
+    let context = (!selected_domains.is_empty()).then(|| aetherlight_core::SearchContext {
+        selected_domains,
+        ..Default::default()
+    });
+    service.search(&query, context).await
+}
+
+/**
+ * DESIGN DECISION: Manual compaction for the settings maintenance panel
+ * WHY: Deletes leave free pages in every database and SQLite never shrinks the files;
+ * startup and rebuilds only compact past the free-page threshold
+ *
+ * Compacts analytics.db, patterns.db, metadata.db and the pattern index vector store,
+ * reporting bytes reclaimed per database (databases not created yet are skipped).
+ */
+#[tauri::command]
+async fn compact_databases(
+    index: tauri::State<'_, index_rebuild::PatternIndexService>,
+) -> Result<Vec<storage::CompactionReport>, String> {
+    let mut reports = vec![storage::CompactionReport {
+        database: "analytics".to_string(),
+        reclaimed_bytes: get_usage_tracker()?
+            .compact()
+            .map_err(|e| format!("Failed to compact analytics database: {}", e))?,
+    }];
+
+    for (database, path) in [("patterns", get_storage_path()), ("metadata", get_metadata_path())] {
+        reports.extend(storage::compact_database(database, &path, false)?);
+    }
+
+    reports.push(storage::CompactionReport {
+        database: "pattern_index".to_string(),
+        reclaimed_bytes: index.compact().await?,
+    });
+
+    tracing::info!(
+        reclaimed_bytes = reports.iter().map(|r| r.reclaimed_bytes).sum::<u64>(),
+        "Compacted databases"
+    );
+    Ok(reports)
 }
 
 /**
@@ -1410,16 +2061,20 @@ use aetherlight_core::viral::{InvitationManager, StorageQuotaManager, UserTier};
  * RELATED: Storage-001, Storage-002, Storage-003, Storage-004
  */
 
-/// Check if this is the first run (no settings file exists)
+/// Check if this is the first run (no settings file, or setup never finished)
 #[tauri::command]
 async fn is_first_run() -> Result<bool, String> {
-    let settings_path = get_settings_path();
-    Ok(!settings_path.exists())
+    // configure_pattern_network writes settings.json, so a setup that failed at sync
+    // must be detected through its progress file
+    Ok(!get_settings_path().exists() || get_setup_progress_path().exists())
 }
 
 /// Provision PostgreSQL database with pgvector extension (Storage-001)
 #[tauri::command]
-async fn provision_postgresql(storage_mb: u64) -> Result<(), String> {
+async fn provision_postgresql(
+    storage_mb: u64,
+    patterns: tauri::State<'_, storage::PatternStoreState>,
+) -> Result<(), String> {
     /**
      * DESIGN DECISION: Use SQLite as v1.0 backend (PostgreSQL migration later)
      * WHY: SQLite bundled (zero dependencies), PostgreSQL requires external server
@@ -1430,14 +2085,16 @@ async fn provision_postgresql(storage_mb: u64) -> Result<(), String> {
      * 3. Solution: Use SQLite with JSON embeddings for v1.0
      * 4. Future: Migrate to PostgreSQL when user upgrades to Pro tier
      * 5. Result: Zero-dependency installation, upgrade path exists
+     * 6. The opened store becomes the managed PatternStoreState (backend chosen in
+     *    storage::open_pattern_store, so this command survives the PostgreSQL swap)
      *
      * PATTERN: Pattern-STORAGE-002 (SQLite-First, PostgreSQL-Later)
      * RELATED: Storage-003 (SQLite metadata), Installer-001
      * PERFORMANCE: <200ms semantic search with SQLite JSON (acceptable for v1.0)
      */
-    use storage::{PostgresStorage, StorageConfig};
+    use storage::StorageConfig;
 
-    println!("📦 [Storage-001] Provisioning pattern storage with {} MB...", storage_mb);
+    tracing::info!("[Storage-001] Provisioning pattern storage with {} MB...", storage_mb);
 
     let config = StorageConfig {
         storage_mb,
@@ -1446,10 +2103,10 @@ async fn provision_postgresql(storage_mb: u64) -> Result<(), String> {
         sqlite_path: get_storage_path().to_string_lossy().to_string(),
     };
 
-    PostgresStorage::new(config)
-        .map_err(|e| format!("Failed to provision storage: {}", e))?;
+    let store = storage::open_pattern_store(config)?;
+    patterns.install(store)?;
 
-    println!("✅ Pattern storage provisioned ({} MB, ~{} patterns max)", storage_mb, storage_mb / 5);
+    tracing::info!("Pattern storage provisioned ({} MB, ~{} patterns max)", storage_mb, storage_mb / 5);
     Ok(())
 }
 
@@ -1473,22 +2130,21 @@ async fn setup_sqlite_metadata() -> Result<(), String> {
      */
     use storage::SqliteMetadata;
 
-    println!("📦 [Storage-003] Setting up SQLite metadata tables...");
-
-    let mut path = dirs::home_dir().expect("Failed to get home directory");
-    path.push(".lumina");
-    path.push("metadata.db");
+    tracing::info!("[Storage-003] Setting up SQLite metadata tables...");
 
-    SqliteMetadata::new(path.to_str().unwrap())
+    SqliteMetadata::new(get_metadata_path().to_str().unwrap())
         .map_err(|e| format!("Failed to initialize metadata storage: {}", e))?;
 
-    println!("✅ SQLite metadata tables created (outcomes, calibration, sync_state)");
+    tracing::info!("SQLite metadata tables created (outcomes, calibration, sync_state)");
     Ok(())
 }
 
 /// Configure Code.NET pattern network endpoint (Storage-002)
 #[tauri::command]
-async fn configure_pattern_network(domains: Vec<String>) -> Result<(), String> {
+async fn configure_pattern_network(
+    domains: Vec<String>,
+    settings_store: tauri::State<'_, SettingsStore>,
+) -> Result<(), String> {
     /**
      * DESIGN DECISION: Save Code.NET configuration to settings, test connection
      * WHY: User selects domains in wizard → saved for pattern sync filtering
@@ -1504,46 +2160,35 @@ async fn configure_pattern_network(domains: Vec<String>) -> Result<(), String> {
      * RELATED: Storage-004 (sync_initial_patterns uses this config)
      * PERFORMANCE: <500ms (HTTP test connection + file write)
      */
-    println!("📦 [Storage-002] Configuring Code.NET for domains: {:?}", domains);
+    tracing::info!("[Storage-002] Configuring Code.NET for domains: {:?}", domains);
 
     // Load current settings
-    let settings_path = get_settings_path();
-    let mut settings = if settings_path.exists() {
-        get_settings()?
-    } else {
-        AppSettings::default()
-    };
-
-    // Update selected domains
-    settings.selected_domains = domains.clone();
+    let settings = get_settings()?;
 
     // Test connection to Global Network (placeholder - real HTTP request in production)
     // TODO: Implement actual HTTP client test connection
-    println!("🔗 Testing connection to {}...", settings.global_network_api_endpoint);
+    tracing::info!("Testing connection to {}...", settings.global_network_api_endpoint);
     tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
 
     // Connection test passed (placeholder)
-    println!("✅ Code.NET connection successful");
+    tracing::info!("Code.NET connection successful");
 
-    // Save updated settings
-    if let Some(parent) = settings_path.parent() {
-        std::fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
-    }
+    // Update selected domains (re-read after the connection test)
+    settings_store.update(|settings| settings.selected_domains = domains.clone())?;
 
-    let json = serde_json::to_string_pretty(&settings)
-        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
-
-    std::fs::write(&settings_path, json)
-        .map_err(|e| format!("Failed to write settings: {}", e))?;
-
-    println!("✅ Code.NET configured with {} domains", domains.len());
+    tracing::info!("Code.NET configured with {} domains", domains.len());
     Ok(())
 }
 
 /// Sync initial patterns from Code.NET (Storage-004)
 #[tauri::command]
-async fn sync_initial_patterns(domains: Vec<String>, storage_mb: u64) -> Result<(), String> {
+async fn sync_initial_patterns(
+    domains: Vec<String>,
+    storage_mb: u64,
+    app: AppHandle,
+    http: tauri::State<'_, http::HttpClient>,
+    patterns: tauri::State<'_, storage::PatternStoreState>,
+) -> Result<pattern_sync::SyncSummary, String> {
     /**
      * DESIGN DECISION: Download patterns from Global Network, filter by domains, store locally
      * WHY: First-run wizard needs to populate pattern library for semantic search
@@ -1551,28 +2196,32 @@ async fn sync_initial_patterns(domains: Vec<String>, storage_mb: u64) -> Result<
      * REASONING CHAIN:
      * 1. Load settings to get global_network_api_endpoint
      * 2. Calculate pattern limit from storage allocation (~5MB per pattern)
-     * 3. Fetch pattern list from Global Network API (Vercel → Supabase)
-     * 4. Filter patterns by selected domains
-     * 5. Download pattern embeddings (1024-dim Voyage-3-large vectors)
-     * 6. Insert patterns into PostgresStorage (patterns.db)
-     * 7. Update sync_state in SqliteMetadata (metadata.db)
+     * 3. Open the pattern store (patterns.db) and SqliteMetadata (metadata.db)
+     * 4. pattern_sync::sync_patterns fetches, filters and inserts, checkpointing sync_state
+     * 5. Progress forwarded as `sync-progress` events, summary as `sync-complete`
+     * 6. Patterns that failed to insert come back in summary.failed_ids (sync_state "partial")
+     * 6a. Each insert is checked against the storage quota (storage_quota: license tier,
+     *     invitation bonus, patterns.db size counts as used); the sync stops before
+     *     overfilling, sets summary.quota_exceeded and leaves sync_state "partial"
+     * 7. The store (opened with this sync's limit) becomes the managed PatternStoreState;
+     *    it's installed after the sync so the state lock isn't held across awaits
      * 8. Result: User has local pattern library ready for semantic search
      *
      * PATTERN: Pattern-STORAGE-005 (First-Run Pattern Sync)
-     * RELATED: Storage-001 (PostgresStorage), Storage-002 (Global Network config), Storage-003 (SqliteMetadata)
+     * RELATED: pattern_sync.rs, Storage-001 (PatternStore), Storage-002 (Global Network config),
+     *          Storage-003 (SqliteMetadata)
      * PERFORMANCE: <30s for 200 patterns (network limited)
      */
-    use storage::{PostgresStorage, SqliteMetadata, StorageConfig, PatternRecord, SyncState};
-    use chrono::Utc;
+    use storage::{SqliteMetadata, StorageConfig};
 
     let pattern_limit = ((storage_mb / 5).min(5000)) as usize; // ~5MB per pattern, max 5000
-    println!("📦 [Storage-004] Starting pattern sync...");
-    println!("   Storage allocation: {} MB (~{} patterns max)", storage_mb, pattern_limit);
-    println!("   Selected domains: {:?}", domains);
+    tracing::info!("[Storage-004] Starting pattern sync...");
+    tracing::info!("Storage allocation: {} MB (~{} patterns max)", storage_mb, pattern_limit);
+    tracing::info!("Selected domains: {:?}", domains);
 
     // Load settings to get global_network_api_endpoint
     let settings = get_settings()?;
-    println!("🔗 Global Network endpoint: {}", settings.global_network_api_endpoint);
+    tracing::info!("Global Network endpoint: {}", settings.global_network_api_endpoint);
 
     // Initialize storage instances
     let storage_config = StorageConfig {
@@ -1582,154 +2231,114 @@ async fn sync_initial_patterns(domains: Vec<String>, storage_mb: u64) -> Result<
         sqlite_path: get_storage_path().to_string_lossy().to_string(),
     };
 
-    let storage = PostgresStorage::new(storage_config.clone())
-        .map_err(|e| format!("Failed to initialize pattern storage: {}", e))?;
+    let store = storage::open_pattern_store(storage_config)?;
 
-    let mut metadata_path = dirs::home_dir().expect("Failed to get home directory");
-    metadata_path.push(".lumina");
-    metadata_path.push("metadata.db");
-
-    let metadata = SqliteMetadata::new(metadata_path.to_str().unwrap())
+    let metadata = SqliteMetadata::new(get_metadata_path().to_str().unwrap())
         .map_err(|e| format!("Failed to initialize metadata storage: {}", e))?;
 
-    // Update sync_state to "syncing"
-    let sync_state = SyncState {
-        last_sync_timestamp: Utc::now().to_rfc3339(),
-        patterns_synced: 0,
-        domains_synced: domains.clone(),
-        sync_status: "syncing".to_string(),
-        error_message: None,
-    };
-    metadata.update_sync_state(&sync_state)
-        .map_err(|e| format!("Failed to update sync state: {}", e))?;
+    let mut quota = storage_quota(&settings)?;
+    tracing::info!("Storage quota: {} MB remaining", quota.remaining_mb());
 
-    /**
-     * DESIGN DECISION: Fetch real patterns from Supabase Node 1 via Edge Functions
-     * WHY: Replace mock data with actual pattern library from Supabase
-     *
-     * REASONING CHAIN:
-     * 1. HTTP GET to patterns-list edge function (deployed on Node 1)
-     * 2. Parse JSON response containing pattern metadata
-     * 3. Filter patterns by selected domains (if user chose specific domains)
-     * 4. Map Supabase pattern format to PatternRecord format
-     * 5. Insert patterns into local PostgresStorage
-     * 6. Result: User has real ÆtherLight patterns locally for semantic search
-     *
-     * PATTERN: Pattern-SUPABASE-002 (Edge Function Pattern Sync)
-     * PERFORMANCE: <5s for 42 patterns (network + insertion)
-     */
-    println!("📥 Fetching patterns from Global Network...");
-
-    // Build API URL with limit (using global network API endpoint from settings)
-    let api_url = format!(
-        "{}/patterns-list?limit={}",
-        settings.global_network_api_endpoint,
-        pattern_limit
-    );
-
-    // Fetch patterns from Supabase edge function
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&api_url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch patterns from Supabase: {}", e))?;
+    let summary = pattern_sync::sync_patterns(
+        &http.client(),
+        &settings.global_network_api_endpoint,
+        &domains,
+        pattern_limit,
+        store.as_ref(),
+        &metadata,
+        Some(&mut quota),
+        |progress| {
+            let _ = app.emit(pattern_sync::PROGRESS_EVENT, progress);
+        },
+    )
+    .await?;
+    patterns.install(store)?;
 
-    if !response.status().is_success() {
-        return Err(format!(
-            "Supabase API returned error: {} {}",
-            response.status(),
-            response.text().await.unwrap_or_default()
-        ));
-    }
+    let _ = app.emit(pattern_sync::COMPLETE_EVENT, &summary);
+    Ok(summary)
+}
 
-    // Parse JSON response
-    #[derive(Deserialize)]
-    struct PatternsListResponse {
-        patterns: Vec<SupabasePattern>,
-    }
+/**
+ * First-run steps backed by the individual wizard commands above
+ *
+ * DESIGN DECISION: Undo only clears sync/network state, never patterns.db
+ * WHY: patterns.db also holds the user's own patterns (Pattern Manager); synced rows
+ * are deduplicated on the next sync, so leaving them is harmless
+ */
+struct WizardSteps {
+    app: AppHandle,
+}
 
-    #[derive(Deserialize)]
-    struct SupabasePattern {
-        pattern_id: String,
-        name: String,
-        domain: Option<String>,
-        description: Option<String>,
-        created_at: String,
-        updated_at: String,
+impl first_run::SetupSteps for WizardSteps {
+    fn run<'a>(
+        &'a self,
+        step: first_run::SetupStep,
+        config: &'a first_run::SetupConfig,
+    ) -> futures_util::future::BoxFuture<'a, Result<(), String>> {
+        use first_run::SetupStep;
+
+        Box::pin(async move {
+            match step {
+                SetupStep::ProvisionStorage => provision_postgresql(config.storage_mb, self.app.state()).await,
+                SetupStep::SetupMetadata => setup_sqlite_metadata().await,
+                SetupStep::ConfigureNetwork => configure_pattern_network(config.domains.clone(), self.app.state()).await,
+                SetupStep::SyncPatterns => sync_initial_patterns(
+                    config.domains.clone(),
+                    config.storage_mb,
+                    self.app.clone(),
+                    self.app.state(),
+                    self.app.state(),
+                )
+                .await
+                .map(|_| ()),
+            }
+        })
     }
 
-    let response_data: PatternsListResponse = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse Supabase response: {}", e))?;
-
-    println!("📦 Downloaded {} patterns from Supabase", response_data.patterns.len());
-
-    // Filter patterns by selected domains (if specific domains chosen)
-    let filtered_patterns: Vec<_> = if domains.is_empty() {
-        // No domain filter - take all patterns
-        response_data.patterns
-    } else {
-        // Filter by selected domains
-        response_data.patterns
-            .into_iter()
-            .filter(|p| {
-                p.domain
-                    .as_ref()
-                    .map(|d| domains.iter().any(|selected| selected == d))
-                    .unwrap_or(false)
-            })
-            .collect()
-    };
-
-    println!("🎯 Filtered to {} patterns matching selected domains", filtered_patterns.len());
-
-    // Insert patterns into local storage
-    let mut patterns_synced = 0;
-    for pattern in filtered_patterns {
-        // Map Supabase pattern to PatternRecord
-        let pattern_record = PatternRecord {
-            id: pattern.pattern_id.clone(),
-            name: pattern.name,
-            description: pattern.description.unwrap_or_default(),
-            domain: pattern.domain,
-            tags: vec![], // Tags not included in list endpoint (optimization)
-            confidence_score: Some(0.90), // Default confidence for curated patterns
-            embedding: None, // Embeddings stored in Supabase, not synced to desktop (large payload)
-            created_at: pattern.created_at,
-            updated_at: pattern.updated_at,
-        };
-
-        // Insert pattern into storage
-        storage
-            .insert_pattern(&pattern_record)
-            .map_err(|e| format!("Failed to insert pattern {}: {}", pattern_record.id, e))?;
-
-        patterns_synced += 1;
-
-        // Progress feedback every 10 patterns
-        if patterns_synced % 10 == 0 {
-            println!("   Synced {} patterns...", patterns_synced);
+    fn undo(&self, step: first_run::SetupStep) -> Result<(), String> {
+        use first_run::SetupStep;
+
+        match step {
+            // Idempotent, and the tables may hold data from outside the wizard
+            SetupStep::ProvisionStorage | SetupStep::SetupMetadata => Ok(()),
+            SetupStep::ConfigureNetwork => {
+                self.app
+                    .state::<SettingsStore>()
+                    .update(|settings| settings.selected_domains.clear())
+                    .map(|_| ())
+            }
+            SetupStep::SyncPatterns => {
+                // Forget the interrupted run so the next sync doesn't resume its count
+                let metadata = storage::SqliteMetadata::new(get_metadata_path().to_str().unwrap())
+                    .map_err(|e| format!("Failed to initialize metadata storage: {}", e))?;
+                metadata
+                    .update_sync_state(&storage::SyncState {
+                        last_sync_timestamp: chrono::Utc::now().to_rfc3339(),
+                        patterns_synced: 0,
+                        domains_synced: vec![],
+                        sync_status: "idle".to_string(),
+                        error_message: None,
+                    })
+                    .map_err(|e| format!("Failed to reset sync state: {}", e))
+            }
         }
     }
+}
 
-    println!("✅ Pattern sync complete: {} patterns stored", patterns_synced);
-
-    // Update sync_state to "complete"
-    let final_sync_state = SyncState {
-        last_sync_timestamp: Utc::now().to_rfc3339(),
-        patterns_synced: patterns_synced as u64,
-        domains_synced: domains,
-        sync_status: "complete".to_string(),
-        error_message: None,
-    };
-    metadata.update_sync_state(&final_sync_state)
-        .map_err(|e| format!("Failed to update final sync state: {}", e))?;
-
-    println!("📊 Sync state updated: {} patterns, status: complete", patterns_synced);
+/// Run the remaining first-run steps, resuming after the last completed one (Installer-001)
+#[tauri::command]
+async fn run_first_run_setup(config: first_run::SetupConfig, app: AppHandle) -> Result<(), String> {
+    let steps = WizardSteps { app: app.clone() };
+    first_run::run_setup(&steps, &get_setup_progress_path(), config, |event| {
+        let _ = app.emit(first_run::STEP_EVENT, event);
+    })
+    .await
+}
 
-    Ok(())
+/// Undo a partially completed first-run setup so it can start over (Installer-001)
+#[tauri::command]
+fn reset_setup(app: AppHandle) -> Result<(), String> {
+    first_run::reset_setup(&WizardSteps { app }, &get_setup_progress_path())
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1764,22 +2373,36 @@ async fn generate_referral_code() -> Result<String, String> {
         .map_err(|e| format!("Failed to generate referral code: {:?}", e))
 }
 
+/**
+ * DESIGN DECISION: One quota manager for enforcement (pattern sync) and display (storage stats)
+ * WHY: Both must agree on tier, bonus and usage, or the UI shows room the sync won't use
+ *
+ * - Tier and user id from the activated license in settings (Free until activation)
+ * - Bonus from the user's invitations (with_invitation_bonus)
+ * - Used bytes: current patterns.db size
+ */
+fn storage_quota(settings: &AppSettings) -> Result<StorageQuotaManager, String> {
+    // TODO (Phase 4): invitations persisted per user (InvitationManager keeps them in memory)
+    let user_id = settings.user_id.clone().unwrap_or_else(|| "demo-user".to_string());
+    let tier = settings.user_tier();
+    let invitations = InvitationManager::new(user_id.clone(), tier.clone());
+
+    let used_bytes = std::fs::metadata(get_storage_path()).map_or(0, |m| m.len());
+    StorageQuotaManager::new(user_id, tier)
+        .with_used_bytes(used_bytes)
+        .with_invitation_bonus(&invitations)
+        .map_err(|e| format!("Failed to load storage bonus: {}", e))
+}
+
 /**
  * DESIGN DECISION: Return storage stats with viral bonus breakdown
  * WHY: User sees "500MB base + 200MB bonus = 700MB total" - clear value prop
  */
 #[tauri::command]
 async fn get_storage_stats() -> Result<ViralStorageStats, String> {
-    // TODO (Phase 4): Load user_id from authenticated session
-    let user_id = "demo-user".to_string();
-    let tier = UserTier::Pro; // TODO: Load from subscription
-
-    let manager = StorageQuotaManager::new(user_id, tier);
+    let manager = storage_quota(&get_settings()?)?;
 
-    // TODO: Get actual usage from filesystem
-    let used_mb = 150u64; // Placeholder
-
-    let stats = manager.get_storage_stats(used_mb)
+    let stats = manager.get_storage_stats()
         .map_err(|e| format!("Failed to get storage stats: {:?}", e))?;
 
     Ok(ViralStorageStats {
@@ -1819,13 +2442,84 @@ async fn get_my_invitations() -> Result<Vec<ViralInvitation>, String> {
     }).collect())
 }
 
+/// Installation wizard's default allocation, assumed until provision/sync reopen the store
+const DEFAULT_STORAGE_MB: u64 = 500;
+
+/**
+ * DESIGN DECISION: Open the pattern store at startup, not only in the wizard
+ * WHY: get_pattern/search_patterns/delete_pattern go through PatternStoreState,
+ * which would otherwise stay empty after every restart
+ *
+ * provision_postgresql and sync_initial_patterns replace it with a store opened
+ * for the allocation the user picked.
+ */
+fn open_pattern_store_at_startup(app: &tauri::App) {
+    let config = storage::StorageConfig {
+        storage_mb: DEFAULT_STORAGE_MB,
+        max_patterns: DEFAULT_STORAGE_MB / 5, // ~5MB per pattern
+        postgres_url: String::new(),
+        sqlite_path: get_storage_path().to_string_lossy().to_string(),
+    };
+
+    let installed = storage::open_pattern_store(config)
+        .and_then(|store| app.state::<storage::PatternStoreState>().install(store));
+    if let Err(e) = installed {
+        tracing::warn!("Pattern store not opened at startup: {}", e);
+    }
+}
+
+/// Emitted with each ProgressSignal an agent writes for the workspace's sprint
+const SPRINT_PROGRESS_EVENT: &str = "sprint-progress";
+
+/**
+ * DESIGN DECISION: Forward workspace progress signals as a Tauri event
+ * WHY: The scheduler writes `<task>.progress.json` per update; the desktop UI shows
+ * the same live sprint progress as VS Code without polling
+ *
+ * Only Lumina workspaces (with a .lumina directory) are watched, so launching the
+ * app from another directory never creates .lumina/workflow there.
+ */
+fn watch_sprint_progress(app: &tauri::App, workspace_path: &str) {
+    let workspace = std::path::Path::new(workspace_path);
+    if !workspace.join(".lumina").is_dir() {
+        tracing::info!("No .lumina directory in {}, sprint progress not watched", workspace_path);
+        return;
+    }
+
+    let progress_app = app.handle().clone();
+    let watcher = aetherlight_core::SignalReader::new(workspace.join(aetherlight_core::WORKFLOW_DIR))
+        .and_then(|reader| {
+            reader.watch_progress(move |signal| {
+                let _ = progress_app.emit(SPRINT_PROGRESS_EVENT, signal);
+            })
+        });
+    match watcher {
+        // Managed state keeps the watcher alive for the app's lifetime
+        Ok(watcher) => {
+            app.manage(watcher);
+        }
+        Err(e) => tracing::warn!("Sprint progress not watched: {:#}", e),
+    }
+}
+
 fn main() {
+    // Structured logging for core + desktop (RUST_LOG overrides, e.g. RUST_LOG=debug)
+    if let Err(e) = aetherlight_core::init_tracing("info") {
+        tracing::warn!("Failed to initialize logging: {}", e);
+    }
+
     tauri::Builder::default()
         // BUG-006: Initialize updater plugin for automatic updates
         .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(Mutex::new(RecordingState::default()))
+        .manage(http::HttpClient::new_or_default()) // Shared connection pool for server calls
+        .manage(SettingsStore::new(get_settings_path())) // Serialized settings.json writes (Pattern-SETTINGS-SYNC-001)
+        .manage(overlay::AudioIndicator::default()) // Audio indicator window (created on first recording)
         .manage(Arc::new(Mutex::new(Vec::<f32>::new()))) // Audio buffer for voice capture
         .manage(Arc::new(Mutex::new(Option::<IpcSender>::None))) // IPC sender for focus messages
+        .manage(storage::PatternStoreState::default()) // Pattern store, opened by provision/sync
+        .manage(index_rebuild::RebuildGuard::default()) // One pattern index rebuild at a time
+        .manage(index_rebuild::PatternIndexService::spawn(index_rebuild::default_data_dir(), None)) // Semantic pattern index (rebuild + search)
         .on_window_event(|window, event| {
             // Hide settings window instead of closing (keeps app running in tray)
             if let WindowEvent::CloseRequested { api, .. } = event {
@@ -1861,6 +2555,10 @@ fn main() {
                             show_settings_window(&app);
                         }
                         "quit" => {
+                            // process::exit skips destructors: checkpoint analytics.db first
+                            if let Err(e) = get_usage_tracker().and_then(|tracker| tracker.flush().map_err(|e| e.to_string())) {
+                                tracing::warn!(error = %e, "Failed to flush analytics before quit");
+                            }
                             std::process::exit(0);
                         }
                         _ => {}
@@ -1874,7 +2572,7 @@ fn main() {
                 })
                 .build(app)?;
 
-            println!("✅ System tray created with Settings and Quit menu");
+            tracing::info!("System tray created with Settings and Quit menu");
 
             /**
              * DESIGN DECISION: Initialize system context provider (Desktop-001)
@@ -1900,10 +2598,19 @@ fn main() {
                 .unwrap()
                 .to_string();
 
+            watch_sprint_progress(app, &workspace_path);
+            open_pattern_store_at_startup(app);
+
             // Initialize context provider and IPC server (async block in setup)
             let app_handle_clone = app.handle().clone();
+            let file_ignore_globs = get_settings()
+                .map(|settings| settings.file_watcher_ignore_globs)
+                .unwrap_or_default();
             tauri::async_runtime::spawn(async move {
-                match system_context::SystemContextProvider::new(&workspace_path).await {
+                let provider = system_context::SystemContextProvider::new(&workspace_path)
+                    .await
+                    .map(|provider| provider.with_file_ignore_globs(&file_ignore_globs));
+                match provider {
                     Ok(provider) => {
                         /**
                          * DESIGN DECISION: Connect SystemContextProvider → IPC Server via broadcast channel
@@ -1919,43 +2626,60 @@ fn main() {
                          * RELATED: Desktop-001, IDE extensions (VS Code, Cursor)
                          */
                         let (ipc_tx, _ipc_rx) = tokio::sync::broadcast::channel(100);
-                        let ipc_server = ipc_server::IpcServer::new(ipc_tx.clone());
+                        // IDE captureVoice shares recording state + audio buffer with hotkey flow
+                        let audio_buffer = app_handle_clone.state::<Arc<Mutex<Vec<f32>>>>().inner().clone();
+                        let voice_backend = ipc_server::DesktopVoiceBackend::new(app_handle_clone.clone(), audio_buffer);
+                        let mut ipc_server = ipc_server::IpcServer::new(ipc_tx.clone())
+                            .with_voice_backend(Arc::new(voice_backend))
+                            .with_settings_store(app_handle_clone.state::<SettingsStore>().inner().clone());
+
+                        // Voice commands matching a registered function become clarificationNeeded conversations
+                        let data_dir = index_rebuild::default_data_dir();
+                        let conversation = tokio::task::spawn_blocking(move || {
+                            ipc_server::local_conversation_backend(&data_dir)
+                        })
+                        .await
+                        .ok()
+                        .flatten();
+                        if let Some(conversation) = conversation {
+                            ipc_server = ipc_server.with_conversation_backend(conversation);
+                        }
 
                         // Store IPC sender in managed state for hotkey access (Pattern-IPC-004)
                         {
                             let ipc_sender_state = app_handle.state::<Arc<Mutex<Option<IpcSender>>>>();
                             let mut sender = ipc_sender_state.lock().unwrap();
                             *sender = Some(ipc_tx.clone());
-                            println!("📡 IPC sender stored in managed state");
+                            tracing::info!("IPC sender stored in managed state");
                         }
 
                         // Start IPC server on localhost:43215
                         if let Err(e) = ipc_server.start("127.0.0.1:43215").await {
-                            eprintln!("⚠️ Failed to start IPC server: {}", e);
+                            tracing::warn!("Failed to start IPC server: {}", e);
                         } else {
-                            println!("✅ IPC server started on ws://localhost:43215");
+                            tracing::info!("IPC server started on ws://localhost:43215");
                         }
 
                         // Start system context monitors
                         if let Err(e) = provider.start().await {
-                            eprintln!("⚠️ Failed to start system context monitors: {}", e);
+                            tracing::warn!("Failed to start system context monitors: {}", e);
                         } else {
-                            println!("✅ System context provider started (workspace: {})", workspace_path);
+                            tracing::info!("System context provider started (workspace: {})", workspace_path);
 
                             // Subscribe to context updates and forward to IPC
                             let mut update_rx = provider.subscribe();
                             tokio::spawn(async move {
                                 while let Ok(update) = update_rx.recv().await {
                                     if let Err(e) = ipc_tx.send(update) {
-                                        eprintln!("⚠️ Failed to forward update to IPC: {}", e);
+                                        tracing::warn!("Failed to forward update to IPC: {}", e);
                                     }
                                 }
                             });
                         }
                     }
                     Err(e) => {
-                        eprintln!("⚠️ Failed to initialize system context provider: {}", e);
-                        eprintln!("   This is non-fatal - app will continue without context monitoring");
+                        tracing::warn!("Failed to initialize system context provider: {}", e);
+                        tracing::warn!("This is non-fatal - app will continue without context monitoring");
                     }
                 }
             });
@@ -1975,6 +2699,10 @@ fn main() {
 
             // Load settings and register hotkeys at startup
             let settings = get_settings()?;
+            rate_limit::server().set_rate(settings.server_requests_per_second);
+            if let Err(e) = settings.apply_http_timeouts(&app.state::<http::HttpClient>()) {
+                tracing::error!("HTTP timeouts not applied, keeping the current client: {:#}", e);
+            }
 
             /**
              * DESIGN DECISION: Check for empty license_key on startup (BUG-002)
@@ -1995,16 +2723,16 @@ fn main() {
              * RELATED: activate_license() command, LicenseActivationDialog.tsx
              */
             if settings.license_key.is_empty() {
-                println!("⚠️  First launch detected: License key not configured");
-                println!("   User will be prompted to activate device in frontend");
-                println!("   Get license key from: https://aetherlight.ai/dashboard");
+                tracing::warn!("First launch detected: License key not configured");
+                tracing::info!("User will be prompted to activate device in frontend");
+                tracing::info!("Get license key from: https://aetherlight.ai/dashboard");
             } else {
-                println!("✅ License key configured: {}...", &settings.license_key[..std::cmp::min(4, settings.license_key.len())]);
+                tracing::info!("License key configured: {}...", &settings.license_key[..std::cmp::min(4, settings.license_key.len())]);
                 if let Some(tier) = &settings.tier {
-                    println!("   Tier: {}", tier);
+                    tracing::info!("Tier: {}", tier);
                 }
                 if let Some(user_id) = &settings.user_id {
-                    println!("   User ID: {}", user_id);
+                    tracing::info!("User ID: {}", user_id);
                 }
             }
 
@@ -2016,7 +2744,29 @@ fn main() {
             };
 
             if let Err(e) = register_hotkeys(app.handle().clone(), &settings, ipc_sender) {
-                eprintln!("⚠️ Failed to register hotkeys at startup: {}", e);
+                tracing::warn!("Failed to register hotkeys at startup: {}", e);
+            }
+
+            /**
+             * DESIGN DECISION: Apply analytics retention on startup (analytics_retention_days)
+             * WHY: Analytics DB would grow forever; old events fold into daily rollups
+             * so dashboard totals stay accurate (non-fatal if it fails)
+             */
+            if let Err(e) = get_usage_tracker().and_then(|tracker| {
+                tracker
+                    .with_retention_days(settings.analytics_retention_days)
+                    .map_err(|e| e.to_string())
+            }) {
+                tracing::warn!(error = %e, "Failed to apply analytics retention");
+            }
+
+            // Pattern deletes leave free pages behind; compact only past the threshold
+            for (database, path) in [("patterns", get_storage_path()), ("metadata", get_metadata_path())] {
+                match storage::compact_database(database, &path, true) {
+                    Ok(Some(report)) => tracing::info!(database, reclaimed_bytes = report.reclaimed_bytes, "Compacted database"),
+                    Ok(None) => {}
+                    Err(e) => tracing::warn!(database, error = %e, "Failed to compact database"),
+                }
             }
 
             /**
@@ -2042,40 +2792,42 @@ fn main() {
                     Ok(updater) => {
                         match updater.check().await {
                             Ok(Some(update)) => {
-                                println!("🆕 Update available: v{}", update.version);
-                                println!("   Current version: v{}", update.current_version);
+                                tracing::info!("Update available: v{}", update.version);
+                                tracing::info!("Current version: v{}", update.current_version);
                                 if let Some(date) = update.date {
-                                    println!("   Release date: {}", date);
+                                    tracing::info!("Release date: {}", date);
                                 }
-                                println!("   Download size: {} bytes", update.body.as_ref().map_or(0, |b| b.len()));
+                                tracing::info!("Download size: {} bytes", update.body.as_ref().map_or(0, |b| b.len()));
                                 // TODO BUG-006: Future enhancement - show update notification UI
                                 // For now, updates are manual - user can download from https://aetherlight.ai/download
                             }
                             Ok(None) => {
-                                println!("✅ Desktop app is up to date");
+                                tracing::info!("Desktop app is up to date");
                             }
                             Err(e) => {
-                                eprintln!("⚠️ Update check failed: {}", e);
+                                tracing::warn!("Update check failed: {}", e);
                                 // Non-fatal: Continue app launch
                             }
                         }
                     }
                     Err(e) => {
-                        eprintln!("⚠️ Failed to initialize updater: {}", e);
+                        tracing::warn!("Failed to initialize updater: {}", e);
                         // Non-fatal: Continue app launch
                     }
                 }
             });
 
-            println!("🚀 Lumina running in system tray.");
+            tracing::info!("Lumina running in system tray.");
 
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             toggle_recording,
+            cancel_recording,
             start_capture,
             stop_capture,
             list_audio_devices,
+            get_audio_format,
             get_settings,
             save_settings,
             activate_license,  // BUG-002: License validation on first launch
@@ -2086,9 +2838,16 @@ fn main() {
             get_all_patterns,
             get_pattern,
             update_pattern,
+            update_pattern_fields,
             delete_pattern,
             create_pattern,
             search_patterns,
+            get_pattern_history,
+            revert_pattern,
+            get_pattern_stats,
+            rebuild_pattern_index,
+            search_pattern_index,
+            compact_databases,
             generate_referral_code,
             get_storage_stats,
             get_my_invitations,
@@ -2096,8 +2855,283 @@ fn main() {
             provision_postgresql,
             setup_sqlite_metadata,
             configure_pattern_network,
-            sync_initial_patterns
+            sync_initial_patterns,
+            run_first_run_setup,
+            reset_setup
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Selected domains move to the front; cross-domain results keep their order and stay
+    #[test]
+    fn test_boost_selected_domains() {
+        let pattern = |id: &str, domain: &str| DatabasePattern {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            domain: domain.to_string(),
+            confidence_score: None,
+            tags: vec![],
+            version: 1,
+            estimated_time_saved_minutes: None,
+        };
+        let mut patterns = vec![
+            pattern("a", "typescript"),
+            pattern("b", "devops"),
+            pattern("c", "frontend"),
+            pattern("d", "Rust"),
+        ];
+
+        boost_selected_domains(&mut patterns, &["rust".to_string(), "devops".to_string()]);
+
+        let ids: Vec<&str> = patterns.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "d", "a", "c"]);
+    }
+
+    /// get_pattern/search_patterns/delete_pattern logic against an in-memory store
+    #[test]
+    fn test_utc_offset_from_minutes() {
+        assert_eq!(utc_offset_from_minutes(330).unwrap().local_minus_utc(), 5 * 3600 + 1800);
+        assert_eq!(utc_offset_from_minutes(-300).unwrap().local_minus_utc(), -5 * 3600);
+        assert!(utc_offset_from_minutes(24 * 60).is_err());
+        assert_eq!(utc_offset_from_minutes(i32::MAX).unwrap_err(), format!("Invalid UTC offset: {} minutes", i32::MAX));
+    }
+
+    #[test]
+    fn test_pattern_commands_against_memory_store() {
+        use storage::{MemoryStore, PatternRecord, PatternStore};
+
+        let record = |id: &str, name: &str, domain: &str, confidence: f64| PatternRecord {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: format!("{} description", name),
+            domain: Some(domain.to_string()),
+            tags: vec!["resilience".to_string()],
+            confidence_score: Some(confidence),
+            embedding: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            version: 3,
+            estimated_time_saved_minutes: Some(20),
+        };
+        let store = MemoryStore::default();
+        store.insert_pattern(&record("pattern-001", "Retry With Backoff", "rust", 0.7)).unwrap();
+        store.insert_pattern(&record("pattern-002", "Circuit Breaker", "typescript", 0.9)).unwrap();
+        store.insert_pattern(&PatternRecord { id: "pattern-042".to_string(), ..record("", "Retry With Backoff", "rust", 0.7) }).unwrap();
+
+        // Alias resolves; Pattern Manager fields come through
+        let pattern = find_pattern(&store, "pattern-042").unwrap();
+        assert_eq!(pattern.id, "pattern-001");
+        assert_eq!(pattern.version, 3);
+        assert_eq!(pattern.estimated_time_saved_minutes, Some(20));
+        assert_eq!(find_pattern(&store, "missing").unwrap_err(), "Pattern not found: missing");
+
+        // Tag match; selected domain ahead of higher confidence
+        let ids: Vec<String> = search_store(&store, "RESILIENCE", &["rust".to_string()])
+            .unwrap()
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(ids, vec!["pattern-001", "pattern-002"]);
+
+        delete_from_store(&store, "pattern-001").unwrap();
+        assert!(find_pattern(&store, "pattern-042").is_err());
+        assert_eq!(delete_from_store(&store, "pattern-001").unwrap_err(), "Pattern not found: pattern-001");
+    }
+
+    /// Pattern matches credit the pattern's stored estimate; patterns without one get the default
+    #[test]
+    fn test_pattern_match_credits_stored_estimate() {
+        let patterns = Connection::open_in_memory().unwrap();
+        patterns.execute(
+            "CREATE TABLE patterns (id TEXT PRIMARY KEY, name TEXT NOT NULL, description TEXT NOT NULL)",
+            [],
+        ).unwrap();
+        migrate_time_saved_column(&patterns).unwrap();
+        migrate_time_saved_column(&patterns).unwrap(); // idempotent
+        patterns.execute(
+            "INSERT INTO patterns (id, name, description, estimated_time_saved_minutes)
+             VALUES ('saga', 'Saga', 'Compensating transactions', 45), ('guard', 'Guard', 'Early return', NULL)",
+            [],
+        ).unwrap();
+
+        let tracker = UsageTracker::new(":memory:")
+            .unwrap()
+            .with_time_saved_lookup(move |pattern_id| pattern_time_saved(&patterns, pattern_id));
+
+        tracker.record_pattern_match(Some(r#"{"pattern_id": "saga"}"#)).unwrap();
+        assert_eq!(tracker.time_saved_by_type(EventType::PatternMatch).unwrap(), 45);
+
+        tracker.record_pattern_match(Some(r#"{"pattern_id": "guard"}"#)).unwrap();
+        let default = i64::from(EventType::PatternMatch.time_saved_minutes());
+        assert_eq!(tracker.time_saved_by_type(EventType::PatternMatch).unwrap(), 45 + default);
+    }
+
+    /// patterns table as get_pattern_db creates it, with one row
+    fn patch_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE patterns (
+                id TEXT PRIMARY KEY, name TEXT NOT NULL, description TEXT NOT NULL, domain TEXT,
+                confidence_score REAL, tags TEXT,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP, updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+            )",
+            [],
+        ).unwrap();
+        migrate_time_saved_column(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO patterns (id, name, description, domain, confidence_score, tags, updated_at)
+             VALUES ('retry', 'Retry', 'Retry with backoff', 'rust', 0.9, 'async,retry', '2020-01-01 00:00:00')",
+            [],
+        ).unwrap();
+        conn
+    }
+
+    /// Patching only tags leaves every other column as stored, bumps version and updated_at
+    #[test]
+    fn test_patch_tags_keeps_description() {
+        let conn = patch_db();
+        let history = storage::PatternHistory::new(&conn, storage::DEFAULT_MAX_PATTERN_VERSIONS).unwrap();
+        let patch = PatternPatch {
+            tags: Some(vec!["Async".to_string(), "backoff".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(patch_pattern(&conn, &history, "retry", &patch).unwrap(), 2);
+
+        let (name, description, confidence, tags, updated_at): (String, String, f64, String, String) = conn
+            .query_row(
+                "SELECT name, description, confidence_score, tags, updated_at FROM patterns WHERE id = 'retry'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+            )
+            .unwrap();
+        assert_eq!(name, "Retry");
+        assert_eq!(description, "Retry with backoff");
+        assert_eq!(confidence, 0.9);
+        assert_eq!(tags, "async,backoff");
+        assert_ne!(updated_at, "2020-01-01 00:00:00");
+        assert_eq!(history.versions("retry").unwrap()[0].tags, vec!["async", "retry"]);
+    }
+
+    /// Explicit null clears a nullable column; a missing field keeps it
+    #[test]
+    fn test_patch_null_clears_field() {
+        let conn = patch_db();
+        let history = storage::PatternHistory::new(&conn, storage::DEFAULT_MAX_PATTERN_VERSIONS).unwrap();
+        let stored = |conn: &Connection| -> (Option<f64>, Option<u32>) {
+            conn.query_row(
+                "SELECT confidence_score, estimated_time_saved_minutes FROM patterns WHERE id = 'retry'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+        };
+
+        let set: PatternPatch = serde_json::from_str(r#"{"estimated_time_saved_minutes": 25}"#).unwrap();
+        patch_pattern(&conn, &history, "retry", &set).unwrap();
+        assert_eq!(stored(&conn), (Some(0.9), Some(25)));
+
+        let clear: PatternPatch = serde_json::from_str(r#"{"estimated_time_saved_minutes": null}"#).unwrap();
+        assert_eq!(clear.estimated_time_saved_minutes, Some(None));
+        patch_pattern(&conn, &history, "retry", &clear).unwrap();
+        assert_eq!(stored(&conn), (Some(0.9), None));
+
+        let clear_confidence: PatternPatch = serde_json::from_str(r#"{"confidence_score": null, "name": "Retry"}"#).unwrap();
+        patch_pattern(&conn, &history, "retry", &clear_confidence).unwrap();
+        assert_eq!(stored(&conn), (None, None));
+
+        let untouched: PatternPatch = serde_json::from_str(r#"{"description": "Retry, then give up"}"#).unwrap();
+        assert!(untouched.confidence_score.is_none());
+        assert!(patch_pattern(&conn, &history, "retry", &PatternPatch {
+            confidence_score: Some(Some(1.5)),
+            ..Default::default()
+        }).is_err());
+    }
+
+    #[test]
+    fn test_patch_missing_pattern_errors() {
+        let conn = patch_db();
+        let history = storage::PatternHistory::new(&conn, storage::DEFAULT_MAX_PATTERN_VERSIONS).unwrap();
+        let patch = PatternPatch { description: Some("Retry forever".to_string()), ..Default::default() };
+
+        let err = patch_pattern(&conn, &history, "missing", &patch).unwrap_err();
+        assert!(err.contains("Pattern not found"), "{}", err);
+        assert!(patch_pattern(&conn, &history, "missing", &PatternPatch::default()).is_err());
+
+        let blank = PatternPatch { name: Some("  ".to_string()), ..Default::default() };
+        assert!(patch_pattern(&conn, &history, "retry", &blank).is_err());
+        assert_eq!(history.current_version("retry").unwrap(), Some(1));
+    }
+
+    /// Test concurrent settings writers never corrupt settings.json or lose updates
+    /// File must always parse, and every writer's change must survive
+    #[test]
+    fn test_concurrent_settings_updates() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings_path = dir.path().join("settings.json");
+        let store = SettingsStore::new(settings_path.clone());
+
+        store.update(|settings| settings.selected_domains.clear()).unwrap();
+
+        let writers: Vec<_> = ["rust", "typescript"]
+            .into_iter()
+            .map(|domain| {
+                let store = store.clone();
+                std::thread::spawn(move || {
+                    for i in 0..50 {
+                        store.update(|settings| {
+                            settings.selected_domains.push(format!("{}-{}", domain, i));
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        // Read while writers are running: every snapshot must be a complete file
+        for _ in 0..50 {
+            let json = std::fs::read_to_string(&settings_path).unwrap();
+            let _: AppSettings = serde_json::from_str(&json)
+                .expect("Settings file should always parse during concurrent writes");
+        }
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let loaded = read_settings(&settings_path);
+        assert_eq!(loaded.selected_domains.len(), 100, "No update should be lost");
+        assert!(!settings_path.with_extension("json.tmp").exists(), "Temp file should not linger");
+    }
+
+    /// Test an unparseable settings.json is never silently replaced with defaults
+    /// update() must refuse to write; persist_settings() must back the file up first
+    #[test]
+    fn test_invalid_settings_file_not_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings_path = dir.path().join("settings.json");
+        let store = SettingsStore::new(settings_path.clone());
+        let corrupt = "{ \"license_key\": \"LUMINA-KEY\", ";
+        std::fs::write(&settings_path, corrupt).unwrap();
+
+        let err = store
+            .update(|settings| settings.selected_domains.push("rust".to_string()))
+            .unwrap_err();
+        assert!(err.contains("refusing to overwrite"), "{}", err);
+        assert_eq!(std::fs::read_to_string(&settings_path).unwrap(), corrupt);
+
+        let mut replacement = AppSettings::default();
+        replacement.selected_domains.push("rust".to_string());
+        store.persist_settings(&replacement).unwrap();
+
+        let backup_path = settings_path.with_extension("json.corrupt");
+        assert_eq!(std::fs::read_to_string(&backup_path).unwrap(), corrupt);
+        assert_eq!(read_settings(&settings_path).selected_domains, vec!["rust".to_string()]);
+    }
+}
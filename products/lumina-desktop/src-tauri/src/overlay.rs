@@ -0,0 +1,287 @@
+/**
+ * Overlay Controller - Single audio indicator window with explicit state
+ *
+ * DESIGN DECISION: Controller in managed state owns the overlay window handle and its state
+ * WHY: toggle_recording queried get_webview_window("audio-indicator") on every press and
+ * accreted fixes for a window that did not reappear, stole focus, or was created twice
+ * under the same label
+ *
+ * REASONING CHAIN:
+ * 1. State is explicit (Hidden / Visible), not inferred from window lookups
+ * 2. The window handle is created once and kept; later shows reuse it
+ * 3. One Mutex serializes show/hide, so two presses cannot both create a window
+ * 4. show() while Visible and hide() while Hidden are no-ops
+ * 5. A handle that fails to show is destroyed, then recreated (its label would otherwise
+ *    still be registered and the rebuild fails with a duplicate-label error)
+ * 6. Never focuses the window: enigo types wherever focus is (CRITICAL FIX #1)
+ *
+ * PATTERN: Pattern-UI-008 (Non-Interactive Overlay Windows)
+ * RELATED: main.rs (toggle_recording)
+ */
+
+use std::sync::Mutex;
+
+/// Window label of the audio indicator
+pub const AUDIO_INDICATOR_LABEL: &str = "audio-indicator";
+
+/// Whether the overlay is on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayState {
+    Hidden,
+    Visible,
+}
+
+/// Window operations the controller needs (tauri::WebviewWindow in the app)
+pub trait OverlayWindow: Send {
+    fn show(&self) -> Result<(), String>;
+    fn hide(&self) -> Result<(), String>;
+    /// Close the window without close-request handlers, releasing its label
+    fn destroy(&self) -> Result<(), String>;
+}
+
+struct Inner<W> {
+    state: OverlayState,
+    window: Option<W>,
+}
+
+/// Owns one overlay window and its show/hide state
+pub struct OverlayController<W> {
+    inner: Mutex<Inner<W>>,
+}
+
+/// The audio indicator shown while recording
+pub type AudioIndicator = OverlayController<tauri::WebviewWindow>;
+
+impl<W: OverlayWindow> Default for OverlayController<W> {
+    fn default() -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                state: OverlayState::Hidden,
+                window: None,
+            }),
+        }
+    }
+}
+
+impl<W: OverlayWindow> OverlayController<W> {
+    /// Current state
+    pub fn state(&self) -> OverlayState {
+        self.lock().state
+    }
+
+    /**
+     * Show the overlay, creating the window on first use
+     *
+     * `create` builds a visible window; it is only called when no usable handle exists.
+     */
+    pub fn show(&self, create: impl FnOnce() -> Result<W, String>) -> Result<(), String> {
+        let mut inner = self.lock();
+        if inner.state == OverlayState::Visible {
+            return Ok(());
+        }
+
+        if let Some(window) = &inner.window {
+            match window.show() {
+                Ok(()) => {
+                    inner.state = OverlayState::Visible;
+                    return Ok(());
+                }
+                Err(e) => {
+                    tracing::warn!("Overlay window unusable ({}), recreating", e);
+                    if let Some(stale) = inner.window.take() {
+                        if let Err(e) = stale.destroy() {
+                            tracing::warn!("Stale overlay window not destroyed: {}", e);
+                        }
+                    }
+                }
+            }
+        }
+
+        inner.window = Some(create()?);
+        inner.state = OverlayState::Visible;
+        Ok(())
+    }
+
+    /// Hide the overlay (window kept for the next show)
+    pub fn hide(&self) -> Result<(), String> {
+        let mut inner = self.lock();
+        if inner.state == OverlayState::Hidden {
+            return Ok(());
+        }
+
+        // Hidden even if the window is gone: nothing left on screen to hide
+        inner.state = OverlayState::Hidden;
+        match &inner.window {
+            Some(window) => window.hide(),
+            None => Ok(()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner<W>> {
+        // Overlay state stays usable after a panic elsewhere
+        match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl OverlayWindow for tauri::WebviewWindow {
+    fn show(&self) -> Result<(), String> {
+        // DON'T set focus - that breaks transcription
+        tauri::WebviewWindow::show(self).map_err(|e| e.to_string())
+    }
+
+    fn hide(&self) -> Result<(), String> {
+        tauri::WebviewWindow::hide(self).map_err(|e| e.to_string())
+    }
+
+    fn destroy(&self) -> Result<(), String> {
+        tauri::WebviewWindow::destroy(self).map_err(|e| e.to_string())
+    }
+}
+
+/**
+ * Build the audio indicator window
+ *
+ * DESIGN DECISION: Full-width, non-interactive indicator bar at top of screen
+ * WHY: User needs to see visual feedback that recording is active WITHOUT blocking transcription
+ *
+ * REASONING CHAIN:
+ * 1. Original 384px width too narrow (only 20% of 1920px screen)
+ * 2. Full screen width (3840px covers dual monitor setups), 8px tall at top
+ * 3. focusable(false) made window invisible on Windows (CRITICAL FIX #2)
+ *    - Solution: visible_on_all_workspaces + accept_first_mouse(false)
+ *    - Window visible but doesn't intercept clicks/focus
+ * 4. Built once per app run by AudioIndicator (CRITICAL FIX #3: no duplicate labels)
+ */
+pub fn build_audio_indicator(app: &tauri::AppHandle) -> Result<tauri::WebviewWindow, String> {
+    tauri::WebviewWindowBuilder::new(
+        app,
+        AUDIO_INDICATOR_LABEL,
+        tauri::WebviewUrl::App("indicator.html".into()),
+    )
+    .title("Audio Indicator")
+    .inner_size(3840.0, 8.0) // Full width (covers dual monitors), 8px tall for better visibility
+    .position(0.0, 0.0) // Top of screen
+    .decorations(false) // No title bar
+    .always_on_top(true) // Stay above all windows
+    .skip_taskbar(true) // Don't appear in taskbar
+    // Tauri 2.0: transparency configured in tauri.conf.json
+    .visible_on_all_workspaces(true) // Show on all virtual desktops
+    .accept_first_mouse(false) // Don't intercept first click
+    .build()
+    .map_err(|e| format!("Failed to create audio indicator: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Counts calls; fails show() when `broken` is set
+    #[derive(Default)]
+    struct Calls {
+        shows: AtomicUsize,
+        hides: AtomicUsize,
+        destroys: AtomicUsize,
+    }
+
+    struct FakeWindow {
+        calls: Arc<Calls>,
+        broken: bool,
+    }
+
+    impl OverlayWindow for FakeWindow {
+        fn show(&self) -> Result<(), String> {
+            if self.broken {
+                return Err("window destroyed".to_string());
+            }
+            self.calls.shows.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn hide(&self) -> Result<(), String> {
+            self.calls.hides.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn destroy(&self) -> Result<(), String> {
+            self.calls.destroys.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /**
+     * Test: Hidden → Visible → Hidden → Visible creates one window and reuses it
+     */
+    #[test]
+    fn test_transitions_reuse_single_window() {
+        let controller = OverlayController::<FakeWindow>::default();
+        let calls = Arc::new(Calls::default());
+        let created = AtomicUsize::new(0);
+        let create = || {
+            created.fetch_add(1, Ordering::SeqCst);
+            Ok(FakeWindow { calls: calls.clone(), broken: false })
+        };
+
+        assert_eq!(controller.state(), OverlayState::Hidden);
+        controller.show(create).unwrap();
+        assert_eq!(controller.state(), OverlayState::Visible);
+
+        controller.hide().unwrap();
+        assert_eq!(controller.state(), OverlayState::Hidden);
+
+        controller.show(create).unwrap();
+        assert_eq!(controller.state(), OverlayState::Visible);
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+        assert_eq!(calls.shows.load(Ordering::SeqCst), 1);
+        assert_eq!(calls.hides.load(Ordering::SeqCst), 1);
+    }
+
+    /**
+     * Test: Repeated show/hide are no-ops
+     */
+    #[test]
+    fn test_repeated_transitions_are_noops() {
+        let controller = OverlayController::<FakeWindow>::default();
+        let calls = Arc::new(Calls::default());
+
+        controller.hide().unwrap();
+        controller.show(|| Ok(FakeWindow { calls: calls.clone(), broken: false })).unwrap();
+        controller.show(|| panic!("window must not be created twice")).unwrap();
+        controller.hide().unwrap();
+        controller.hide().unwrap();
+
+        assert_eq!(controller.state(), OverlayState::Hidden);
+        assert_eq!(calls.hides.load(Ordering::SeqCst), 1);
+    }
+
+    /**
+     * Test: Unusable window is destroyed and recreated; failed creation leaves state Hidden
+     */
+    #[test]
+    fn test_broken_window_recreated() {
+        let controller = OverlayController::<FakeWindow>::default();
+        let calls = Arc::new(Calls::default());
+
+        assert!(controller.show(|| Err("no display".to_string())).is_err());
+        assert_eq!(controller.state(), OverlayState::Hidden);
+
+        controller.show(|| Ok(FakeWindow { calls: calls.clone(), broken: true })).unwrap();
+        controller.hide().unwrap();
+        controller.show(|| {
+            // Stale window released its label before the rebuild
+            assert_eq!(calls.destroys.load(Ordering::SeqCst), 1);
+            Ok(FakeWindow { calls: calls.clone(), broken: false })
+        })
+        .unwrap();
+
+        assert_eq!(controller.state(), OverlayState::Visible);
+        controller.hide().unwrap();
+        controller.show(|| panic!("working window must be reused")).unwrap();
+        assert_eq!(calls.shows.load(Ordering::SeqCst), 1);
+        assert_eq!(calls.destroys.load(Ordering::SeqCst), 1);
+    }
+}
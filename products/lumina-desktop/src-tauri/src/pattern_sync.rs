@@ -0,0 +1,616 @@
+/**
+ * Initial Pattern Sync - Download, filter and store Global Network patterns with progress
+ *
+ * DESIGN DECISION: Sync logic takes a progress callback; main.rs forwards it as Tauri events
+ * WHY: First-run sync can take ~30s and the wizard only showed a spinner; a callback keeps
+ * this module free of AppHandle so tests can drive it against a mock server
+ *
+ * REASONING CHAIN:
+ * 1. Phases: fetching (GET patterns-list) → filtering (by selected domains) → inserting
+ * 2. `sync-progress` carries { synced, total, phase }; synced counts patterns processed
+ *    in the current phase, so a progress bar can fill during inserting
+ * 3. SyncState is written as "syncing" every CHECKPOINT_INTERVAL stored patterns
+ * 4. A crashed sync leaves "syncing" behind; the next run carries its count forward
+ *    (patterns it stored come back as AlreadyStored, so nothing is inserted twice)
+ * 5. A pattern that fails to insert is recorded and skipped; the sync finishes as
+ *    "partial" with the failed ids in error_message (retrying re-inserts only those,
+ *    everything else comes back AlreadyStored)
+ * 6. A known id whose upstream content changed comes back Updated (new version), so
+ *    upstream edits don't fail the sync
+ * 7. Fetch/parse/state errors are recorded as sync_status "error" before being returned
+ * 8. `sync-complete` carries the SyncSummary once SyncState is "complete" or "partial"
+ * 9. With a StorageQuotaManager, each pattern (serialized size) is checked before it is
+ *    inserted; the first one that doesn't fit ends the sync with summary.quota_exceeded,
+ *    also recorded as "partial" (error_message says the quota was reached)
+ *
+ * PATTERN: Pattern-STORAGE-005 (First-Run Pattern Sync)
+ * RELATED: main.rs (sync_initial_patterns), storage::PatternStore, storage::SqliteMetadata,
+ *          index_rebuild.rs (same progress-event shape)
+ * PERFORMANCE: <30s for 200 patterns (network limited)
+ */
+
+use crate::storage::{InsertOutcome, PatternRecord, PatternStore, SqliteMetadata, SyncState};
+use aetherlight_core::viral::StorageQuotaManager;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Emitted at each phase change and after each pattern is inserted
+pub const PROGRESS_EVENT: &str = "sync-progress";
+
+/// Emitted once with the final SyncSummary
+pub const COMPLETE_EVENT: &str = "sync-complete";
+
+/// Stored patterns between SyncState checkpoints
+const CHECKPOINT_INTERVAL: usize = 10;
+
+/// Failed ids listed in SyncState.error_message (the rest are summarized as a count)
+const MAX_LISTED_FAILURES: usize = 20;
+
+/// Default confidence for curated Global Network patterns
+const CURATED_CONFIDENCE: f64 = 0.90;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncPhase {
+    Fetching,
+    Filtering,
+    Inserting,
+}
+
+/// Payload of `sync-progress`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SyncProgress {
+    pub synced: usize,
+    pub total: usize,
+    pub phase: SyncPhase,
+}
+
+/// Payload of `sync-complete`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncSummary {
+    pub downloaded: usize,
+    pub matched_domains: usize,
+    /// Patterns stored by this sync (including ones carried over from an interrupted run)
+    pub stored: u64,
+    pub duplicates_collapsed: usize,
+    pub already_stored: usize,
+    /// Known patterns whose upstream content changed (stored as a new version)
+    pub updated: usize,
+    /// Patterns that failed to insert (sync_status "partial" when non-empty)
+    pub failed_ids: Vec<String>,
+    pub resumed: bool,
+    /// Sync stopped early: the next pattern would have exceeded the storage quota
+    /// (sync_status "partial")
+    pub quota_exceeded: bool,
+}
+
+impl SyncSummary {
+    /// Some matched patterns weren't stored (insert failures or storage quota)
+    pub fn is_partial(&self) -> bool {
+        !self.failed_ids.is_empty() || self.quota_exceeded
+    }
+}
+
+#[derive(Deserialize)]
+struct PatternsListResponse {
+    patterns: Vec<SupabasePattern>,
+}
+
+#[derive(Deserialize)]
+struct SupabasePattern {
+    pattern_id: String,
+    name: String,
+    domain: Option<String>,
+    description: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+/// Sync up to `pattern_limit` patterns for `domains` (empty = all) into `storage`
+pub async fn sync_patterns<F>(
+    client: &reqwest::Client,
+    api_endpoint: &str,
+    domains: &[String],
+    pattern_limit: usize,
+    storage: &dyn PatternStore,
+    metadata: &SqliteMetadata,
+    quota: Option<&mut StorageQuotaManager>,
+    on_progress: F,
+) -> Result<SyncSummary, String>
+where
+    F: FnMut(SyncProgress),
+{
+    let carried_over = interrupted_count(metadata, domains);
+    if carried_over > 0 {
+        tracing::info!("Resuming interrupted sync ({} patterns already stored)", carried_over);
+    }
+    checkpoint(metadata, domains, carried_over, "syncing", None)?;
+
+    let result = run(client, api_endpoint, domains, pattern_limit, storage, metadata, quota, carried_over, on_progress).await;
+
+    match &result {
+        Ok(summary) if summary.is_partial() => {
+            checkpoint(metadata, domains, summary.stored, "partial", Some(partial_message(summary)))?;
+            tracing::warn!(
+                "Sync state updated: {} patterns, status: partial ({} failed, quota exceeded: {})",
+                summary.stored,
+                summary.failed_ids.len(),
+                summary.quota_exceeded
+            );
+        }
+        Ok(summary) => {
+            checkpoint(metadata, domains, summary.stored, "complete", None)?;
+            tracing::info!("Sync state updated: {} patterns, status: complete", summary.stored);
+        }
+        Err(e) => {
+            // Keep the count so the retry resumes instead of starting over
+            let stored = metadata
+                .get_sync_state()
+                .ok()
+                .flatten()
+                .map_or(carried_over, |state| state.patterns_synced);
+            if let Err(state_err) = checkpoint(metadata, domains, stored, "error", Some(e.clone())) {
+                tracing::warn!("Failed to record sync error: {}", state_err);
+            }
+        }
+    }
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run<F>(
+    client: &reqwest::Client,
+    api_endpoint: &str,
+    domains: &[String],
+    pattern_limit: usize,
+    storage: &dyn PatternStore,
+    metadata: &SqliteMetadata,
+    mut quota: Option<&mut StorageQuotaManager>,
+    carried_over: u64,
+    mut on_progress: F,
+) -> Result<SyncSummary, String>
+where
+    F: FnMut(SyncProgress),
+{
+    on_progress(SyncProgress { synced: 0, total: 0, phase: SyncPhase::Fetching });
+    tracing::info!("Fetching patterns from Global Network...");
+
+    let api_url = format!("{}/patterns-list?limit={}", api_endpoint, pattern_limit);
+
+    // Paced with other server calls
+    crate::rate_limit::server().acquire().await;
+    let response = client
+        .get(&api_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch patterns from Supabase: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Supabase API returned error: {} {}",
+            response.status(),
+            response.text().await.unwrap_or_default()
+        ));
+    }
+
+    let response_data: PatternsListResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Supabase response: {}", e))?;
+
+    let downloaded = response_data.patterns.len();
+    tracing::info!("Downloaded {} patterns from Supabase", downloaded);
+    on_progress(SyncProgress { synced: 0, total: downloaded, phase: SyncPhase::Filtering });
+
+    let filtered_patterns: Vec<_> = if domains.is_empty() {
+        response_data.patterns
+    } else {
+        response_data
+            .patterns
+            .into_iter()
+            .filter(|p| {
+                p.domain
+                    .as_ref()
+                    .map(|d| domains.iter().any(|selected| selected == d))
+                    .unwrap_or(false)
+            })
+            .collect()
+    };
+
+    let total = filtered_patterns.len();
+    tracing::info!("Filtered to {} patterns matching selected domains", total);
+    on_progress(SyncProgress { synced: 0, total, phase: SyncPhase::Inserting });
+
+    // Identical content collapsed into one record
+    let mut stored = carried_over;
+    let mut inserted = 0;
+    let mut duplicates_collapsed = 0;
+    let mut already_stored = 0;
+    let mut updated = 0;
+    let mut failed_ids = Vec::new();
+    let mut quota_exceeded = false;
+
+    for (processed, pattern) in filtered_patterns.into_iter().enumerate() {
+        let pattern_record = PatternRecord {
+            id: pattern.pattern_id,
+            name: pattern.name,
+            description: pattern.description.unwrap_or_default(),
+            domain: pattern.domain,
+            tags: vec![], // Tags not included in list endpoint (optimization)
+            confidence_score: Some(CURATED_CONFIDENCE),
+            embedding: None, // Embeddings stored in Supabase, not synced to desktop (large payload)
+            created_at: pattern.created_at,
+            updated_at: pattern.updated_at,
+            version: aetherlight_core::INITIAL_PATTERN_VERSION,
+            estimated_time_saved_minutes: None,
+        };
+
+        // Serialized size approximates the bytes the record adds to the store
+        let size = serde_json::to_vec(&pattern_record).map_or(0, |bytes| bytes.len() as u64);
+        if let Some(quota) = quota.as_deref() {
+            if let Err(e) = quota.check_can_store(size) {
+                tracing::warn!("Stopping sync before {}: {}", pattern_record.id, e);
+                quota_exceeded = true;
+                break;
+            }
+        }
+
+        match storage.insert_pattern(&pattern_record) {
+            Ok(InsertOutcome::Inserted) => {
+                stored += 1;
+                inserted += 1;
+                if let Some(quota) = quota.as_deref_mut() {
+                    quota.record_stored(size);
+                }
+                if inserted % CHECKPOINT_INTERVAL == 0 {
+                    tracing::info!("Synced {} patterns...", stored);
+                    checkpoint(metadata, domains, stored, "syncing", None)?;
+                }
+            }
+            Ok(InsertOutcome::Duplicate { canonical_id }) => {
+                tracing::debug!(alias = %pattern_record.id, canonical = %canonical_id, "Duplicate pattern content collapsed");
+                duplicates_collapsed += 1;
+            }
+            Ok(InsertOutcome::AlreadyStored) => already_stored += 1,
+            Ok(InsertOutcome::Updated) => updated += 1,
+            Err(e) => {
+                // One bad record shouldn't discard the rest of the sync
+                tracing::warn!("Failed to insert pattern {}: {}", pattern_record.id, e);
+                failed_ids.push(pattern_record.id);
+            }
+        }
+
+        on_progress(SyncProgress { synced: processed + 1, total, phase: SyncPhase::Inserting });
+    }
+
+    tracing::info!(stored, duplicates_collapsed, already_stored, updated, failed = failed_ids.len(), quota_exceeded, "Pattern sync complete");
+
+    Ok(SyncSummary {
+        downloaded,
+        matched_domains: total,
+        stored,
+        duplicates_collapsed,
+        already_stored,
+        updated,
+        failed_ids,
+        resumed: carried_over > 0,
+        quota_exceeded,
+    })
+}
+
+/// Patterns stored by an interrupted sync of the same domains (0 if none)
+fn interrupted_count(metadata: &SqliteMetadata, domains: &[String]) -> u64 {
+    match metadata.get_sync_state() {
+        Ok(Some(state))
+            if matches!(state.sync_status.as_str(), "syncing" | "partial" | "error") && state.domains_synced == domains =>
+        {
+            state.patterns_synced
+        }
+        _ => 0,
+    }
+}
+
+/// Why a partial sync stopped short: quota reached and/or insert failures
+fn partial_message(summary: &SyncSummary) -> String {
+    let mut reasons = Vec::new();
+    if summary.quota_exceeded {
+        reasons.push(format!("Storage quota reached after {} pattern(s)", summary.stored));
+    }
+    if !summary.failed_ids.is_empty() {
+        reasons.push(failure_message(&summary.failed_ids));
+    }
+    reasons.join("; ")
+}
+
+/// "Failed to insert N pattern(s): a, b, ... (+k more)"
+fn failure_message(failed_ids: &[String]) -> String {
+    let listed = failed_ids.iter().take(MAX_LISTED_FAILURES).cloned().collect::<Vec<_>>().join(", ");
+    let unlisted = failed_ids.len().saturating_sub(MAX_LISTED_FAILURES);
+    let mut message = format!("Failed to insert {} pattern(s): {}", failed_ids.len(), listed);
+    if unlisted > 0 {
+        message.push_str(&format!(" (+{} more)", unlisted));
+    }
+    message
+}
+
+fn checkpoint(
+    metadata: &SqliteMetadata,
+    domains: &[String],
+    patterns_synced: u64,
+    sync_status: &str,
+    error_message: Option<String>,
+) -> Result<(), String> {
+    metadata
+        .update_sync_state(&SyncState {
+            last_sync_timestamp: Utc::now().to_rfc3339(),
+            patterns_synced,
+            domains_synced: domains.to_vec(),
+            sync_status: sync_status.to_string(),
+            error_message,
+        })
+        .map_err(|e| format!("Failed to update sync state: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::json_server;
+    use crate::http::HttpClient;
+    use crate::storage::{MemoryStore, PatternQuery, PostgresStorage, StorageConfig};
+
+    fn storage() -> PostgresStorage {
+        PostgresStorage::new(StorageConfig {
+            storage_mb: 200,
+            max_patterns: 200,
+            postgres_url: String::new(),
+            sqlite_path: ":memory:".to_string(),
+        })
+        .unwrap()
+    }
+
+    fn patterns_body(count: usize) -> String {
+        let patterns: Vec<_> = (0..count)
+            .map(|i| {
+                serde_json::json!({
+                    "pattern_id": format!("pattern-{:03}", i),
+                    "name": format!("Pattern {}", i),
+                    "domain": if i % 3 == 0 { "legal" } else { "engineering" },
+                    "description": format!("Distinct description {}", i),
+                    "created_at": "2025-01-01T00:00:00Z",
+                    "updated_at": "2025-01-01T00:00:00Z",
+                })
+            })
+            .collect();
+        serde_json::json!({ "patterns": patterns }).to_string()
+    }
+
+    /**
+     * Test: Progress goes through every phase with increasing insert counts,
+     * and SyncState ends "complete" with the stored count
+     */
+    #[tokio::test]
+    async fn test_sync_reports_progress() {
+        let api_url = json_server(patterns_body(30)).await;
+        let storage = storage();
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+        let domains = vec!["engineering".to_string()];
+
+        let mut events = Vec::new();
+        let summary = sync_patterns(
+            &HttpClient::new().unwrap().client(),
+            &api_url,
+            &domains,
+            100,
+            &storage,
+            &metadata,
+            None,
+            |progress| events.push(progress),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.downloaded, 30);
+        assert_eq!(summary.matched_domains, 20);
+        assert_eq!(summary.stored, 20);
+        assert!(!summary.resumed);
+
+        assert_eq!(events[0].phase, SyncPhase::Fetching);
+        assert_eq!(events[1], SyncProgress { synced: 0, total: 30, phase: SyncPhase::Filtering });
+        let inserting: Vec<usize> = events
+            .iter()
+            .filter(|e| e.phase == SyncPhase::Inserting)
+            .map(|e| e.synced)
+            .collect();
+        assert_eq!(inserting, (0..=20).collect::<Vec<_>>());
+        assert!(events.iter().all(|e| e.synced <= e.total));
+
+        let state = metadata.get_sync_state().unwrap().unwrap();
+        assert_eq!(state.sync_status, "complete");
+        assert_eq!(state.patterns_synced, 20);
+    }
+
+    /// An interrupted sync's count is carried forward; its patterns are not inserted twice
+    #[tokio::test]
+    async fn test_interrupted_sync_resumes() {
+        let api_url = json_server(patterns_body(12)).await;
+        let storage = storage();
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+        let client = HttpClient::new().unwrap().client();
+
+        sync_patterns(&client, &api_url, &[], 100, &storage, &metadata, None, |_| {}).await.unwrap();
+        // Simulate a crash after the first checkpoint
+        checkpoint(&metadata, &[], 10, "syncing", None).unwrap();
+
+        let summary = sync_patterns(&client, &api_url, &[], 100, &storage, &metadata, None, |_| {}).await.unwrap();
+
+        assert!(summary.resumed);
+        assert_eq!(summary.already_stored, 12);
+        assert_eq!(summary.stored, 10);
+        assert_eq!(metadata.get_sync_state().unwrap().unwrap().sync_status, "complete");
+    }
+
+    /// MemoryStore that rejects one pattern id until `failing` is cleared
+    #[derive(Default)]
+    struct FailingStore {
+        inner: MemoryStore,
+        failing: std::sync::Mutex<Option<String>>,
+    }
+
+    impl PatternStore for FailingStore {
+        fn insert_pattern(&self, pattern: &PatternRecord) -> Result<InsertOutcome, String> {
+            if self.failing.lock().unwrap().as_deref() == Some(pattern.id.as_str()) {
+                return Err("disk I/O error".to_string());
+            }
+            self.inner.insert_pattern(pattern)
+        }
+
+        fn get(&self, id: &str) -> Result<Option<PatternRecord>, String> {
+            self.inner.get(id)
+        }
+
+        fn search(&self, query: &PatternQuery) -> Result<Vec<PatternRecord>, String> {
+            self.inner.search(query)
+        }
+
+        fn delete(&self, id: &str) -> Result<bool, String> {
+            self.inner.delete(id)
+        }
+    }
+
+    /// A failing insert is skipped: the rest persist and the state records the partial failure;
+    /// retrying stores only the failed pattern and completes
+    #[tokio::test]
+    async fn test_insert_failure_finishes_partial() {
+        let api_url = json_server(patterns_body(5)).await;
+        let store = FailingStore { failing: std::sync::Mutex::new(Some("pattern-001".to_string())), ..Default::default() };
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+        let client = HttpClient::new().unwrap().client();
+
+        let summary = sync_patterns(&client, &api_url, &[], 100, &store, &metadata, None, |_| {}).await.unwrap();
+
+        assert!(summary.is_partial());
+        assert_eq!(summary.failed_ids, vec!["pattern-001".to_string()]);
+        assert_eq!(summary.stored, 4);
+
+        let state = metadata.get_sync_state().unwrap().unwrap();
+        assert_eq!(state.sync_status, "partial");
+        assert_eq!(state.patterns_synced, 4);
+        assert_eq!(state.error_message.as_deref(), Some("Failed to insert 1 pattern(s): pattern-001"));
+
+        *store.failing.lock().unwrap() = None;
+        let retry = sync_patterns(&client, &api_url, &[], 100, &store, &metadata, None, |_| {}).await.unwrap();
+
+        assert!(!retry.is_partial());
+        assert_eq!(retry.already_stored, 4);
+        assert_eq!(retry.stored, 5);
+        assert_eq!(metadata.get_sync_state().unwrap().unwrap().sync_status, "complete");
+    }
+
+    /// Upstream edits to a synced pattern update it instead of failing the sync on every retry
+    #[tokio::test]
+    async fn test_changed_upstream_pattern_updates() {
+        let storage = storage();
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+        let client = HttpClient::new().unwrap().client();
+
+        let api_url = json_server(patterns_body(5)).await;
+        sync_patterns(&client, &api_url, &[], 100, &storage, &metadata, None, |_| {}).await.unwrap();
+
+        let mut body: serde_json::Value = serde_json::from_str(&patterns_body(5)).unwrap();
+        body["patterns"][1]["name"] = "Renamed upstream".into();
+        let api_url = json_server(body.to_string()).await;
+
+        for expected_updates in [1, 0] {
+            let summary = sync_patterns(&client, &api_url, &[], 100, &storage, &metadata, None, |_| {}).await.unwrap();
+            assert!(!summary.is_partial());
+            assert_eq!(summary.updated, expected_updates);
+            assert_eq!(metadata.get_sync_state().unwrap().unwrap().sync_status, "complete");
+        }
+
+        let renamed = storage.get_pattern("pattern-001").unwrap().unwrap();
+        assert_eq!(renamed.name, "Renamed upstream");
+        assert_eq!(renamed.version, 2);
+        assert_eq!(storage.get_storage_stats().unwrap().0, 5);
+    }
+
+    /// Sync logic only needs a PatternStore: same outcome against the in-memory backend
+    #[tokio::test]
+    async fn test_sync_into_memory_store() {
+        let mut body: serde_json::Value = serde_json::from_str(&patterns_body(6)).unwrap();
+        // Same content as pattern-001 under a new id
+        let mut duplicate = body["patterns"][1].clone();
+        duplicate["pattern_id"] = "pattern-101".into();
+        body["patterns"].as_array_mut().unwrap().push(duplicate);
+
+        let api_url = json_server(body.to_string()).await;
+        let store = MemoryStore::default();
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+
+        let summary = sync_patterns(&HttpClient::new().unwrap().client(), &api_url, &[], 100, &store, &metadata, None, |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(summary.stored, 6);
+        assert_eq!(summary.duplicates_collapsed, 1);
+        let legal = store
+            .search(&PatternQuery { domain: Some("legal".to_string()), ..Default::default() })
+            .unwrap();
+        assert_eq!(legal.len(), 2);
+        assert_eq!(store.get("pattern-101").unwrap().unwrap().id, "pattern-001");
+    }
+
+    /// A full quota stops the sync before the first pattern that doesn't fit
+    #[tokio::test]
+    async fn test_sync_stops_at_storage_quota() {
+        use aetherlight_core::viral::{UserTier, BYTES_PER_MB};
+
+        let api_url = json_server(patterns_body(10)).await;
+        let storage = storage();
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+
+        // Free tier (100 MB) with room for a few small records only
+        let mut quota = StorageQuotaManager::new("user".to_string(), UserTier::Free).with_used_bytes(100 * BYTES_PER_MB - 800);
+
+        let summary = sync_patterns(&HttpClient::new().unwrap().client(), &api_url, &[], 100, &storage, &metadata, Some(&mut quota), |_| {})
+            .await
+            .unwrap();
+
+        assert!(summary.quota_exceeded);
+        assert!(summary.is_partial());
+        assert!(summary.stored > 0 && summary.stored < 10, "stored {}", summary.stored);
+        assert_eq!(storage.get_storage_stats().unwrap().0, summary.stored);
+        assert!(quota.used_bytes() <= 100 * BYTES_PER_MB);
+
+        // Recorded as partial, not complete: the rest didn't fit
+        let state = metadata.get_sync_state().unwrap().unwrap();
+        assert_eq!(state.sync_status, "partial");
+        assert_eq!(state.patterns_synced, summary.stored);
+        assert_eq!(
+            state.error_message,
+            Some(format!("Storage quota reached after {} pattern(s)", summary.stored))
+        );
+    }
+
+    #[test]
+    fn test_failure_message_caps_listed_ids() {
+        let ids: Vec<String> = (0..MAX_LISTED_FAILURES + 3).map(|i| format!("p{}", i)).collect();
+        let message = failure_message(&ids);
+        assert!(message.starts_with(&format!("Failed to insert {} pattern(s): p0, p1", ids.len())));
+        assert!(message.ends_with("(+3 more)"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_failed_sync_recorded_as_error() {
+        let api_url = json_server("not json".to_string()).await;
+        let metadata = SqliteMetadata::new(":memory:").unwrap();
+
+        let err = sync_patterns(&HttpClient::new().unwrap().client(), &api_url, &[], 100, &storage(), &metadata, None, |_| {})
+            .await
+            .unwrap_err();
+
+        let state = metadata.get_sync_state().unwrap().unwrap();
+        assert_eq!(state.sync_status, "error");
+        assert_eq!(state.error_message, Some(err));
+    }
+}
@@ -0,0 +1,185 @@
+/**
+ * Outbound Server Rate Limiter
+ *
+ * DESIGN DECISION: One token bucket shared by every call to the ÆtherLight server
+ * WHY: Balance checks, transcription, license validation and pattern sync fire
+ * independently; a burst (e.g. rapid record/stop) can trip the server's rate limit
+ * and surface as a confusing 429 or network failure
+ *
+ * REASONING CHAIN:
+ * 1. Bucket refills at `requests_per_second`, holds at most `burst` tokens
+ * 2. acquire() takes a token; when the bucket is empty it reserves the next one
+ *    (tokens go negative) and sleeps until it is due, instead of erroring
+ * 3. Reservations are handed out in lock order, so concurrent callers are paced
+ *    one interval apart rather than all waking at once
+ * 4. The lock is never held across an await (std Mutex is fine)
+ * 5. Rate comes from settings (server_requests_per_second) and can change live
+ *
+ * PATTERN: Pattern-RATELIMIT-001 (Token Bucket)
+ * RELATED: transcription.rs, auth.rs, main.rs (sync_initial_patterns)
+ * PERFORMANCE: O(1) per acquire, no background task
+ */
+
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default pacing for server calls (settings: server_requests_per_second)
+pub const DEFAULT_SERVER_REQUESTS_PER_SECOND: f64 = 5.0;
+
+/// Calls allowed back-to-back before pacing starts
+const DEFAULT_BURST: u32 = 2;
+
+/// Limiter shared by all outbound server calls
+static SERVER_LIMITER: LazyLock<RateLimiter> =
+    LazyLock::new(|| RateLimiter::new(DEFAULT_SERVER_REQUESTS_PER_SECOND, DEFAULT_BURST));
+
+/// Shared limiter for ÆtherLight server calls
+pub fn server() -> &'static RateLimiter {
+    &SERVER_LIMITER
+}
+
+/// Token bucket rate limiter (safe to share across tasks)
+#[derive(Debug)]
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative = reservations waiting for refill
+    tokens: f64,
+    /// Maximum stored tokens
+    capacity: f64,
+    /// Refill rate (tokens per second)
+    rate: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+impl RateLimiter {
+    /// Limiter allowing `requests_per_second` on average and `burst` back-to-back
+    ///
+    /// Non-positive rates are clamped to 0.1/s, burst to at least 1.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        let capacity = f64::from(burst.max(1));
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                capacity,
+                rate: clamp_rate(requests_per_second),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Change the refill rate (tokens already stored or reserved are kept)
+    pub fn set_rate(&self, requests_per_second: f64) {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        bucket.refill(Instant::now());
+        bucket.rate = clamp_rate(requests_per_second);
+    }
+
+    /// Current refill rate (requests per second)
+    pub fn rate(&self) -> f64 {
+        self.bucket.lock().unwrap_or_else(|e| e.into_inner()).rate
+    }
+
+    /// Wait until a request may be sent
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+        if !wait.is_zero() {
+            tracing::debug!("Rate limited: waiting {:?} before server call", wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token, returning how long the caller must wait for it
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        bucket.refill(now);
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / bucket.rate)
+        }
+    }
+}
+
+fn clamp_rate(requests_per_second: f64) -> f64 {
+    if requests_per_second.is_finite() {
+        requests_per_second.max(0.1)
+    } else {
+        DEFAULT_SERVER_REQUESTS_PER_SECOND
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Burst passes immediately, the rest is paced at the configured rate
+    #[tokio::test]
+    async fn test_burst_is_paced_to_rate() {
+        let limiter = RateLimiter::new(20.0, 1); // one call per 50ms
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+
+        // First call free, 4 more at 50ms each
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(190), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(600), "{:?}", elapsed);
+    }
+
+    /// Concurrent callers share one bucket instead of each getting their own budget
+    #[tokio::test]
+    async fn test_concurrent_callers_share_bucket() {
+        let limiter = Arc::new(RateLimiter::new(20.0, 2));
+        let start = Instant::now();
+
+        let calls: Vec<_> = (0..6)
+            .map(|_| {
+                let limiter = Arc::clone(&limiter);
+                tokio::spawn(async move {
+                    limiter.acquire().await;
+                    start.elapsed()
+                })
+            })
+            .collect();
+
+        let mut finished = Vec::new();
+        for call in calls {
+            finished.push(call.await.unwrap());
+        }
+        finished.sort();
+
+        // Two burst tokens immediately, then one per 50ms: last at ~200ms
+        assert!(finished[1] < Duration::from_millis(40), "{:?}", finished);
+        assert!(finished[5] >= Duration::from_millis(190), "{:?}", finished);
+    }
+
+    #[test]
+    fn test_reserve_waits_for_deficit() {
+        let limiter = RateLimiter::new(10.0, 1);
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::from_millis(100));
+        assert_eq!(limiter.reserve(now), Duration::from_millis(200));
+
+        limiter.set_rate(0.0);
+        assert_eq!(limiter.rate(), 0.1);
+    }
+}
@@ -0,0 +1,641 @@
+/**
+ * Recording Flow - Hotkey recording state machine behind injectable services
+ *
+ * DESIGN DECISION: Network, microphone and keyboard behind traits; toggle_recording keeps the UI
+ * WHY: toggle_recording hard-depended on a license key, the balance endpoint and the
+ * transcription API, so start → balance check → record → transcribe → type could not be tested
+ *
+ * REASONING CHAIN:
+ * 1. toggle() flips RecordingState (refused while an IDE capture owns the microphone)
+ * 2. start(): BalanceChecker pre-flight → revert to idle if tokens are insufficient → AudioSource::start
+ * 3. Stop: AudioSource::stop returns samples at the native sample rate
+ * 4. transcribe_and_type(): resample to the Transcriber's rate → Transcriber → TranscriptSink
+ *    (OS keyboard in production)
+ * 5. cancel(): stop and discard the capture, back to idle, no transcription (no billing)
+ * 6. press() runs one hotkey press end to end; main.rs supplies the services and the
+ *    side effects users see (overlay window, cancel hotkey) through PressHooks
+ * 7. MockBalanceChecker / MockTranscriber replace the server in tests and in
+ *    builds with the `mock-transcription` feature
+ *
+ * PATTERN: Pattern-MONETIZATION-001 (Server-Side Key Management)
+ * RELATED: main.rs (toggle_recording), transcription.rs (server implementations), voice.rs (GlobalRecorder)
+ */
+
+use crate::resample::{self, AudioFormat};
+use crate::transcription::{TokenBalanceResponse, TranscriptionError, MIN_RECORDING_TOKENS};
+use futures_util::future::BoxFuture;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/**
+ * DESIGN DECISION: Recording state with Mutex for thread-safe access
+ * WHY: Global hotkeys run on separate thread, need shared mutable state
+ */
+#[derive(Default)]
+pub struct RecordingState {
+    pub is_recording: bool,
+    pub start_time: Option<Instant>,
+    /// Recording owned by an IDE captureVoice request (hotkey/frontend must not toggle it)
+    pub ipc_capture: bool,
+}
+
+/// What toggle() did to the recording state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Toggle {
+    /// Idle → recording: call start()
+    Started,
+    /// Recording → idle: stop the AudioSource, then transcribe_and_type()
+    Stopped { duration_ms: u64 },
+}
+
+/// Why a recording did not start (state is idle in all cases)
+#[derive(Debug)]
+pub enum StartError {
+    /// Balance below MIN_RECORDING_TOKENS
+    InsufficientTokens(TokenBalanceResponse),
+    /// Microphone could not be opened
+    Audio(String),
+    /// cancel() ran during the balance check: the microphone was never opened
+    Cancelled,
+}
+
+/// Why a finished recording produced no typed text
+#[derive(Debug)]
+pub enum FinishError {
+    Transcription(TranscriptionError),
+    Typing(String),
+}
+
+/**
+ * Token balance lookup for the pre-flight check
+ *
+ * DESIGN DECISION: Boxed futures instead of async-trait dependency
+ * WHY: Same convention as ipc_server::VoiceCaptureBackend
+ */
+pub trait BalanceChecker: Send + Sync {
+    fn check_balance(&self) -> BoxFuture<'_, anyhow::Result<TokenBalanceResponse>>;
+}
+
+/// Speech-to-text for recorded samples (mono f32 at `sample_rate`)
+pub trait Transcriber: Send + Sync {
+    /// Sample rate the backend needs (None = accepts any rate)
+    fn required_sample_rate(&self) -> Option<u32> {
+        None
+    }
+
+    fn transcribe<'a>(
+        &'a self,
+        samples: &'a [f32],
+        sample_rate: u32,
+    ) -> BoxFuture<'a, Result<String, TranscriptionError>>;
+}
+
+/// Microphone capture
+pub trait AudioSource: Send + Sync {
+    fn start(&self) -> Result<(), String>;
+    /// Stop capture, returning (samples, native sample rate)
+    fn stop(&self) -> (Vec<f32>, u32);
+
+    /// Stop capture and drop everything recorded
+    fn discard(&self) {
+        let _ = self.stop();
+    }
+}
+
+/// Where the transcript goes (typed at the cursor in production)
+pub trait TranscriptSink: Send + Sync {
+    fn type_text(&self, text: &str) -> Result<(), String>;
+}
+
+/**
+ * Flip recording state (hotkey press)
+ *
+ * Refused while an IDE capture owns the recording.
+ */
+pub fn toggle(state: &Mutex<RecordingState>) -> Result<Toggle, String> {
+    let mut recording = state.lock().map_err(|e| format!("Lock error: {}", e))?;
+    // IDE capture owns the microphone until its guard drops
+    if recording.ipc_capture {
+        return Err("Voice capture for IDE in progress".to_string());
+    }
+    recording.is_recording = !recording.is_recording;
+
+    if recording.is_recording {
+        recording.start_time = Some(Instant::now());
+        Ok(Toggle::Started)
+    } else {
+        let duration_ms = recording
+            .start_time
+            .take()
+            .map(|start| start.elapsed().as_millis() as u64)
+            .unwrap_or(0);
+        Ok(Toggle::Stopped { duration_ms })
+    }
+}
+
+/**
+ * Start recording after toggle() returned Started
+ *
+ * DESIGN DECISION: Pre-flight token balance check before recording
+ * WHY: Prevents wasting user's time recording if they don't have enough tokens
+ *
+ * REASONING CHAIN:
+ * 1. No checker (no license key) → skip, transcription reports the missing license
+ * 2. Balance < MIN_RECORDING_TOKENS (375 = 1 minute) → revert to idle, don't record
+ * 3. Balance lookup failed (network, etc.) → record anyway
+ * 4. Cancelled (or cancelled and restarted) during the check → don't open the microphone
+ * 5. Start the microphone under the state lock, so cancel() sees either no capture yet
+ *    or a running one; revert to idle if it can't be opened
+ */
+pub async fn start(
+    state: &Mutex<RecordingState>,
+    balance: Option<&dyn BalanceChecker>,
+    audio: &dyn AudioSource,
+) -> Result<(), StartError> {
+    // Identifies this recording: toggle() stamped it, cancel() clears it
+    let started_at = lock(state).start_time;
+
+    if let Some(checker) = balance {
+        match checker.check_balance().await {
+            Ok(balance) if balance.tokens_balance < MIN_RECORDING_TOKENS => {
+                tracing::warn!("Insufficient tokens: {} < {} required", balance.tokens_balance, MIN_RECORDING_TOKENS);
+                revert(state, started_at);
+                return Err(StartError::InsufficientTokens(balance));
+            }
+            Ok(balance) => {
+                tracing::info!("Pre-flight check passed: {} tokens available", balance.tokens_balance);
+            }
+            Err(e) => {
+                tracing::warn!("Balance check failed: {}. Proceeding with recording anyway.", e);
+            }
+        }
+    }
+
+    let mut recording = lock(state);
+    if !recording.is_recording || recording.start_time != started_at {
+        tracing::info!("Recording cancelled before the microphone was opened");
+        return Err(StartError::Cancelled);
+    }
+    audio.start().map_err(|e| {
+        recording.is_recording = false;
+        recording.start_time = None;
+        StartError::Audio(e)
+    })
+}
+
+/// Format the transcriber will receive for audio captured at `capture_sample_rate`
+pub fn negotiate_format(capture_sample_rate: u32, transcriber: &dyn Transcriber) -> AudioFormat {
+    resample::negotiate(capture_sample_rate, transcriber.required_sample_rate())
+}
+
+/**
+ * Transcribe a finished recording and type the transcript
+ *
+ * Samples are resampled to the transcriber's required rate first (see negotiate_format).
+ */
+pub async fn transcribe_and_type(
+    samples: &[f32],
+    sample_rate: u32,
+    transcriber: &dyn Transcriber,
+    sink: &dyn TranscriptSink,
+) -> Result<String, FinishError> {
+    let format = negotiate_format(sample_rate, transcriber);
+    let resampled;
+    let samples = if format.needs_resampling() {
+        tracing::info!(
+            "Resampling {} samples {}Hz → {}Hz",
+            samples.len(),
+            format.capture_sample_rate,
+            format.transcription_sample_rate
+        );
+        resampled = resample::resample(samples, format.capture_sample_rate, format.transcription_sample_rate)
+            .map_err(|message| FinishError::Transcription(TranscriptionError::ParseError { message }))?;
+        &resampled[..]
+    } else {
+        samples
+    };
+
+    let transcript = transcriber
+        .transcribe(samples, format.transcription_sample_rate)
+        .await
+        .map_err(FinishError::Transcription)?;
+
+    tracing::debug!("Transcription received: {}", transcript);
+
+    sink.type_text(&transcript).map_err(FinishError::Typing)?;
+    Ok(transcript)
+}
+
+/**
+ * Cancel an in-progress recording
+ *
+ * DESIGN DECISION: Discard audio instead of transcribing it
+ * WHY: A recording started by mistake must not cost tokens
+ *
+ * Returns false when nothing was recording. Refused while an IDE capture owns the recording.
+ */
+pub fn cancel(state: &Mutex<RecordingState>, audio: &dyn AudioSource) -> Result<bool, String> {
+    {
+        let mut recording = state.lock().map_err(|e| format!("Lock error: {}", e))?;
+        if recording.ipc_capture {
+            return Err("Voice capture for IDE in progress".to_string());
+        }
+        if !recording.is_recording {
+            return Ok(false);
+        }
+        // Idle under the same lock start() opens the microphone with (see start)
+        recording.is_recording = false;
+        recording.start_time = None;
+    }
+
+    audio.discard();
+    tracing::info!("Recording cancelled (audio discarded, not transcribed)");
+    Ok(true)
+}
+
+/// What one hotkey press did (see press)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pressed {
+    /// Microphone open, PressHooks::on_started ran
+    Started,
+    /// Cancelled during the balance check: the microphone was never opened
+    Cancelled,
+    /// Recording stopped, transcribed and typed
+    Finished { transcript: String, duration_ms: u64 },
+}
+
+/// Why a hotkey press failed (state is idle afterwards, except for Refused)
+#[derive(Debug)]
+pub enum PressError {
+    /// toggle() refused (IDE capture in progress, lock error)
+    Refused(String),
+    Start(StartError),
+    /// No transcription service (message for the user); the recording is dropped
+    NoTranscriber(String),
+    Finish(FinishError),
+}
+
+/**
+ * Services and UI side effects for press(), supplied per press by main.rs
+ *
+ * DESIGN DECISION: One hooks object instead of more press() parameters
+ * WHY: The transcriber is only needed (and only valid) once a recording stops, and the
+ * overlay/cancel hotkey must follow the recording state the same way in every caller
+ */
+pub trait PressHooks: Send + Sync {
+    /// Pre-flight balance check (None skips it)
+    fn balance_checker(&self) -> Option<&dyn BalanceChecker>;
+
+    /// Transcription service for a stopped recording (Err = not configured)
+    fn transcriber(&self) -> Result<&dyn Transcriber, String>;
+
+    /// Microphone opened
+    fn on_started(&self) {}
+
+    /// Microphone stopped, before transcription
+    fn on_stopped(&self) {}
+}
+
+/**
+ * Run one hotkey press: start a recording, or stop, transcribe and type the current one
+ *
+ * REASONING CHAIN:
+ * 1. toggle() → Started: start() (balance check, microphone) → on_started
+ * 2. cancel() during the balance check → Pressed::Cancelled, microphone never opened
+ * 3. toggle() → Stopped: stop the microphone → on_stopped → transcriber → transcribe_and_type()
+ */
+pub async fn press(
+    state: &Mutex<RecordingState>,
+    audio: &dyn AudioSource,
+    sink: &dyn TranscriptSink,
+    hooks: &dyn PressHooks,
+) -> Result<Pressed, PressError> {
+    match toggle(state).map_err(PressError::Refused)? {
+        Toggle::Started => {
+            tracing::info!("Recording started...");
+            match start(state, hooks.balance_checker(), audio).await {
+                Ok(()) => {
+                    hooks.on_started();
+                    Ok(Pressed::Started)
+                }
+                Err(StartError::Cancelled) => Ok(Pressed::Cancelled),
+                Err(e) => Err(PressError::Start(e)),
+            }
+        }
+        Toggle::Stopped { duration_ms } => {
+            tracing::info!("Recording stopped. Duration: {}ms", duration_ms);
+
+            // Stop audio capture and get samples with native sample rate
+            let (samples, sample_rate) = audio.stop();
+            tracing::info!("Captured {} audio samples at {}Hz", samples.len(), sample_rate);
+            hooks.on_stopped();
+
+            let transcriber = hooks.transcriber().map_err(PressError::NoTranscriber)?;
+            tracing::info!("Transcribing audio...");
+            let transcript = transcribe_and_type(&samples, sample_rate, transcriber, sink)
+                .await
+                .map_err(PressError::Finish)?;
+            Ok(Pressed::Finished { transcript, duration_ms })
+        }
+    }
+}
+
+/// Recording state, recovering a poisoned lock (the hotkey must not stay stuck)
+fn lock(state: &Mutex<RecordingState>) -> std::sync::MutexGuard<'_, RecordingState> {
+    match state.lock() {
+        Ok(recording) => recording,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Back to idle, unless the recording started at `started_at` was already cancelled or replaced
+fn revert(state: &Mutex<RecordingState>, started_at: Option<Instant>) {
+    let mut recording = lock(state);
+    if recording.start_time == started_at {
+        recording.is_recording = false;
+        recording.start_time = None;
+    }
+}
+
+/**
+ * Fixed balance, no network (tests and `mock-transcription` builds)
+ */
+#[cfg(any(test, feature = "mock-transcription"))]
+pub struct MockBalanceChecker {
+    pub tokens_balance: u64,
+}
+
+#[cfg(any(test, feature = "mock-transcription"))]
+impl BalanceChecker for MockBalanceChecker {
+    fn check_balance(&self) -> BoxFuture<'_, anyhow::Result<TokenBalanceResponse>> {
+        Box::pin(async move {
+            Ok(TokenBalanceResponse {
+                success: true,
+                tokens_balance: self.tokens_balance,
+                tokens_used_this_month: 0,
+                subscription_tier: "mock".to_string(),
+                minutes_remaining: self.tokens_balance / MIN_RECORDING_TOKENS,
+                warnings: vec![],
+            })
+        })
+    }
+}
+
+/**
+ * Fixed transcript, no network (tests and `mock-transcription` builds)
+ */
+#[cfg(any(test, feature = "mock-transcription"))]
+pub struct MockTranscriber {
+    pub transcript: String,
+}
+
+#[cfg(any(test, feature = "mock-transcription"))]
+impl Transcriber for MockTranscriber {
+    fn transcribe<'a>(
+        &'a self,
+        _samples: &'a [f32],
+        _sample_rate: u32,
+    ) -> BoxFuture<'a, Result<String, TranscriptionError>> {
+        Box::pin(async move { Ok(self.transcript.clone()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts microphone starts/stops; start() records one second of silence
+    #[derive(Default)]
+    struct FakeAudio {
+        starts: AtomicUsize,
+        stops: AtomicUsize,
+        buffer: Mutex<Vec<f32>>,
+    }
+
+    impl AudioSource for FakeAudio {
+        fn start(&self) -> Result<(), String> {
+            self.starts.fetch_add(1, Ordering::SeqCst);
+            *self.buffer.lock().unwrap() = vec![0.0; 16_000];
+            Ok(())
+        }
+
+        fn stop(&self) -> (Vec<f32>, u32) {
+            self.stops.fetch_add(1, Ordering::SeqCst);
+            (std::mem::take(&mut *self.buffer.lock().unwrap()), 16_000)
+        }
+    }
+
+    /// Counts transcription calls
+    #[derive(Default)]
+    struct CountingTranscriber(AtomicUsize);
+
+    impl Transcriber for CountingTranscriber {
+        fn transcribe<'a>(
+            &'a self,
+            _samples: &'a [f32],
+            _sample_rate: u32,
+        ) -> BoxFuture<'a, Result<String, TranscriptionError>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Ok(String::new()) })
+        }
+    }
+
+    /// Collects typed transcripts instead of simulating keystrokes
+    #[derive(Default)]
+    struct CollectingSink(Mutex<Vec<String>>);
+
+    impl TranscriptSink for CollectingSink {
+        fn type_text(&self, text: &str) -> Result<(), String> {
+            self.0.lock().unwrap().push(text.to_string());
+            Ok(())
+        }
+    }
+
+    /**
+     * Test: Insufficient balance reverts to idle without opening the microphone
+     */
+    #[tokio::test]
+    async fn test_insufficient_tokens_reverts_state() {
+        let state = Mutex::new(RecordingState::default());
+        let audio = FakeAudio::default();
+        let checker = MockBalanceChecker { tokens_balance: MIN_RECORDING_TOKENS - 1 };
+
+        assert_eq!(toggle(&state).unwrap(), Toggle::Started);
+        let result = start(&state, Some(&checker), &audio).await;
+
+        match result {
+            Err(StartError::InsufficientTokens(balance)) => assert_eq!(balance.tokens_balance, MIN_RECORDING_TOKENS - 1),
+            other => panic!("expected InsufficientTokens, got {:?}", other),
+        }
+        let recording = state.lock().unwrap();
+        assert!(!recording.is_recording);
+        assert!(recording.start_time.is_none());
+        assert_eq!(audio.starts.load(Ordering::SeqCst), 0);
+    }
+
+    /**
+     * Test: start → balance check → record → transcribe → type with mock services
+     */
+    #[tokio::test]
+    async fn test_happy_path_types_mock_transcript() {
+        let state = Mutex::new(RecordingState::default());
+        let audio = FakeAudio::default();
+        let sink = CollectingSink::default();
+        let checker = MockBalanceChecker { tokens_balance: 10_000 };
+        let transcriber = MockTranscriber { transcript: "add error handling".to_string() };
+
+        assert_eq!(toggle(&state).unwrap(), Toggle::Started);
+        start(&state, Some(&checker), &audio).await.unwrap();
+        assert!(state.lock().unwrap().is_recording);
+        assert_eq!(audio.starts.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(toggle(&state).unwrap(), Toggle::Stopped { .. }));
+        let (samples, sample_rate) = audio.stop();
+        let transcript = transcribe_and_type(&samples, sample_rate, &transcriber, &sink).await.unwrap();
+
+        assert_eq!(transcript, "add error handling");
+        assert_eq!(*sink.0.lock().unwrap(), vec!["add error handling".to_string()]);
+        assert!(!state.lock().unwrap().is_recording);
+    }
+
+    /// Requires 16kHz, records what it received
+    #[derive(Default)]
+    struct RateCheckingTranscriber(Mutex<Option<(usize, u32)>>);
+
+    impl Transcriber for RateCheckingTranscriber {
+        fn required_sample_rate(&self) -> Option<u32> {
+            Some(16_000)
+        }
+
+        fn transcribe<'a>(
+            &'a self,
+            samples: &'a [f32],
+            sample_rate: u32,
+        ) -> BoxFuture<'a, Result<String, TranscriptionError>> {
+            *self.0.lock().unwrap() = Some((samples.len(), sample_rate));
+            Box::pin(async move { Ok("resampled".to_string()) })
+        }
+    }
+
+    /**
+     * Test: 48kHz capture reaches a 16kHz transcriber resampled
+     */
+    #[tokio::test]
+    async fn test_samples_resampled_to_required_rate() {
+        let transcriber = RateCheckingTranscriber::default();
+        let sink = CollectingSink::default();
+        let samples = vec![0.0; 48_000];
+
+        assert_eq!(
+            negotiate_format(48_000, &transcriber),
+            AudioFormat { capture_sample_rate: 48_000, transcription_sample_rate: 16_000 }
+        );
+        transcribe_and_type(&samples, 48_000, &transcriber, &sink).await.unwrap();
+
+        assert_eq!(*transcriber.0.lock().unwrap(), Some((16_000, 16_000)));
+    }
+
+    /// Hotkey services for press(): fixed balance, counting transcriber
+    struct TestHooks {
+        balance: MockBalanceChecker,
+        transcriber: CountingTranscriber,
+    }
+
+    impl TestHooks {
+        fn new() -> Self {
+            Self {
+                balance: MockBalanceChecker { tokens_balance: 10_000 },
+                transcriber: CountingTranscriber::default(),
+            }
+        }
+
+        fn transcriptions(&self) -> usize {
+            self.transcriber.0.load(Ordering::SeqCst)
+        }
+    }
+
+    impl PressHooks for TestHooks {
+        fn balance_checker(&self) -> Option<&dyn BalanceChecker> {
+            Some(&self.balance)
+        }
+
+        fn transcriber(&self) -> Result<&dyn Transcriber, String> {
+            Ok(&self.transcriber)
+        }
+    }
+
+    /**
+     * Test: Cancel between two hotkey presses discards the capture and never transcribes
+     */
+    #[tokio::test]
+    async fn test_cancel_discards_without_transcribing() {
+        let state = Mutex::new(RecordingState::default());
+        let audio = FakeAudio::default();
+        let sink = CollectingSink::default();
+        let hooks = TestHooks::new();
+
+        assert!(!cancel(&state, &audio).unwrap());
+
+        assert_eq!(press(&state, &audio, &sink, &hooks).await.unwrap(), Pressed::Started);
+        assert!(cancel(&state, &audio).unwrap());
+
+        assert!(audio.buffer.lock().unwrap().is_empty());
+        assert_eq!(audio.stops.load(Ordering::SeqCst), 1);
+        {
+            let recording = state.lock().unwrap();
+            assert!(!recording.is_recording);
+            assert!(recording.start_time.is_none());
+        }
+
+        // Next press starts a fresh recording instead of finishing the cancelled one
+        assert_eq!(press(&state, &audio, &sink, &hooks).await.unwrap(), Pressed::Started);
+        assert_eq!(hooks.transcriptions(), 0);
+
+        // The press after that finishes it, so the counter is reachable on this path
+        assert!(matches!(press(&state, &audio, &sink, &hooks).await.unwrap(), Pressed::Finished { .. }));
+        assert_eq!(hooks.transcriptions(), 1);
+    }
+
+    /// Balance check during which the user presses Escape
+    struct CancellingChecker<'a> {
+        state: &'a Mutex<RecordingState>,
+        audio: &'a FakeAudio,
+    }
+
+    impl BalanceChecker for CancellingChecker<'_> {
+        fn check_balance(&self) -> BoxFuture<'_, anyhow::Result<TokenBalanceResponse>> {
+            assert!(cancel(self.state, self.audio).unwrap());
+            Box::pin(async move { MockBalanceChecker { tokens_balance: 10_000 }.check_balance().await })
+        }
+    }
+
+    /**
+     * Test: Cancel during the balance check keeps the microphone closed
+     */
+    #[tokio::test]
+    async fn test_cancel_during_start_does_not_open_microphone() {
+        let state = Mutex::new(RecordingState::default());
+        let audio = FakeAudio::default();
+        let checker = CancellingChecker { state: &state, audio: &audio };
+
+        assert_eq!(toggle(&state).unwrap(), Toggle::Started);
+        let result = start(&state, Some(&checker), &audio).await;
+
+        assert!(matches!(result, Err(StartError::Cancelled)), "got {:?}", result);
+        assert_eq!(audio.starts.load(Ordering::SeqCst), 0);
+        assert!(!state.lock().unwrap().is_recording);
+    }
+
+    /**
+     * Test: Hotkey is refused while an IDE capture owns the recording
+     */
+    #[test]
+    fn test_toggle_refused_during_ipc_capture() {
+        let state = Mutex::new(RecordingState { is_recording: true, start_time: None, ipc_capture: true });
+
+        assert!(toggle(&state).is_err());
+        assert!(cancel(&state, &FakeAudio::default()).is_err());
+        assert!(state.lock().unwrap().is_recording);
+    }
+}
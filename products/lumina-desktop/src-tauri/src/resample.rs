@@ -0,0 +1,178 @@
+/**
+ * Audio Resampling - Capture rate → transcription backend rate
+ *
+ * DESIGN DECISION: Resample at the voice/transcription boundary with rubato
+ * WHY: Microphones capture at their native rate (usually 44.1/48kHz) while Whisper
+ * is trained on 16kHz; leaving the conversion to the backend degraded accuracy
+ *
+ * REASONING CHAIN:
+ * 1. voice.rs captures at the device's native rate (setting cpal's rate only changes metadata)
+ * 2. The Transcriber reports the rate it needs (Transcriber::required_sample_rate)
+ * 3. negotiate() pairs capture rate and backend rate into an AudioFormat
+ *    (settings.transcription_sample_rate overrides the server backend's 16kHz; see get_audio_format)
+ * 4. resample() converts mono f32 samples with an FFT resampler (band-limited, no aliasing)
+ * 5. Resampler delay is trimmed so output length = input length × to / from
+ *
+ * PATTERN: Pattern-AUDIO-002 (Correct WAV Header Metadata)
+ * RELATED: recording_flow.rs (transcribe_and_type), voice.rs (native-rate capture)
+ */
+
+use rubato::{FftFixedIn, Resampler};
+use serde::Serialize;
+
+/// Sample rate Whisper is trained on
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
+/// Transcription rates settings may choose (telephone quality up to studio)
+pub const TRANSCRIPTION_SAMPLE_RATES: std::ops::RangeInclusive<u32> = 8_000..=48_000;
+
+/// Input frames per resampler call
+const CHUNK_FRAMES: usize = 1024;
+
+/// Audio format agreed between capture and transcription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AudioFormat {
+    /// Rate the microphone captured at
+    pub capture_sample_rate: u32,
+    /// Rate sent to the transcription backend
+    pub transcription_sample_rate: u32,
+}
+
+impl AudioFormat {
+    /// Whether samples must be converted before transcription
+    pub fn needs_resampling(&self) -> bool {
+        self.capture_sample_rate != self.transcription_sample_rate
+    }
+}
+
+/**
+ * Pair a capture rate with the backend's required rate
+ *
+ * Backends without a requirement (None) receive audio at the capture rate.
+ */
+pub fn negotiate(capture_sample_rate: u32, required_sample_rate: Option<u32>) -> AudioFormat {
+    AudioFormat {
+        capture_sample_rate,
+        transcription_sample_rate: required_sample_rate.unwrap_or(capture_sample_rate),
+    }
+}
+
+/// Reject a transcription rate outside TRANSCRIPTION_SAMPLE_RATES
+pub fn validate_transcription_rate(sample_rate: u32) -> Result<(), String> {
+    if TRANSCRIPTION_SAMPLE_RATES.contains(&sample_rate) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Transcription sample rate {}Hz is outside {}-{}Hz",
+            sample_rate,
+            TRANSCRIPTION_SAMPLE_RATES.start(),
+            TRANSCRIPTION_SAMPLE_RATES.end()
+        ))
+    }
+}
+
+/**
+ * Resample mono f32 samples from one rate to another
+ *
+ * Output length is `samples.len() * to_rate / from_rate` (rounded down).
+ */
+pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Result<Vec<f32>, String> {
+    if from_rate == to_rate || samples.is_empty() {
+        return Ok(samples.to_vec());
+    }
+
+    let expected = (samples.len() as u64 * to_rate as u64 / from_rate as u64) as usize;
+    let mut resampler = FftFixedIn::<f32>::new(from_rate as usize, to_rate as usize, CHUNK_FRAMES, 2, 1)
+        .map_err(|e| format!("Failed to create resampler: {}", e))?;
+    let delay = resampler.output_delay();
+    let mut output = Vec::with_capacity(expected + delay + resampler.output_frames_max());
+
+    let mut position = 0;
+    while samples.len() - position >= resampler.input_frames_next() {
+        let frames = resampler.input_frames_next();
+        let chunk = resampler
+            .process(&[&samples[position..position + frames]], None)
+            .map_err(|e| format!("Resampling failed: {}", e))?;
+        output.extend_from_slice(&chunk[0]);
+        position += frames;
+    }
+
+    // Last partial chunk (zero-padded), then flush the delayed frames
+    if position < samples.len() {
+        let chunk = resampler
+            .process_partial(Some(&[&samples[position..]]), None)
+            .map_err(|e| format!("Resampling failed: {}", e))?;
+        output.extend_from_slice(&chunk[0]);
+    }
+    while output.len() < delay + expected {
+        let chunk = resampler
+            .process_partial(None::<&[&[f32]]>, None)
+            .map_err(|e| format!("Resampling failed: {}", e))?;
+        output.extend_from_slice(&chunk[0]);
+    }
+
+    Ok(output[delay..delay + expected].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn sine(frequency: f32, sample_rate: u32, seconds: f32) -> Vec<f32> {
+        (0..(sample_rate as f32 * seconds) as usize)
+            .map(|i| (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin() * 0.5)
+            .collect()
+    }
+
+    /**
+     * Test: 48kHz buffer downsampled to 16kHz keeps length ratio and waveform
+     */
+    #[test]
+    fn test_downsample_48k_to_16k() {
+        let input = sine(440.0, 48_000, 1.0);
+        let output = resample(&input, 48_000, WHISPER_SAMPLE_RATE).unwrap();
+
+        assert_eq!(output.len(), 16_000);
+
+        // Away from the edges the output is a 440Hz sine sampled at 16kHz
+        // (normalized correlation; tolerates the resampler's sub-sample delay)
+        let reference = sine(440.0, WHISPER_SAMPLE_RATE, 1.0);
+        let (a, b) = (&output[1_000..15_000], &reference[1_000..15_000]);
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let correlation = dot / (norm(a) * norm(b));
+        assert!(correlation > 0.99, "correlation {}", correlation);
+    }
+
+    /**
+     * Test: 44.1kHz (non-integer ratio) and equal rates
+     */
+    #[test]
+    fn test_resample_lengths() {
+        let input = sine(440.0, 44_100, 0.5);
+        assert_eq!(resample(&input, 44_100, WHISPER_SAMPLE_RATE).unwrap().len(), 8_000);
+        assert_eq!(resample(&input, 44_100, 44_100).unwrap(), input);
+        assert!(resample(&[], 48_000, WHISPER_SAMPLE_RATE).unwrap().is_empty());
+    }
+
+    /**
+     * Test: Negotiation uses backend rate when required, capture rate otherwise
+     */
+    #[test]
+    fn test_negotiate() {
+        let format = negotiate(48_000, Some(WHISPER_SAMPLE_RATE));
+        assert_eq!(format.transcription_sample_rate, WHISPER_SAMPLE_RATE);
+        assert!(format.needs_resampling());
+
+        assert!(!negotiate(48_000, None).needs_resampling());
+    }
+
+    #[test]
+    fn test_validate_transcription_rate() {
+        assert!(validate_transcription_rate(WHISPER_SAMPLE_RATE).is_ok());
+        assert!(validate_transcription_rate(48_000).is_ok());
+        assert!(validate_transcription_rate(0).is_err());
+        assert!(validate_transcription_rate(96_000).is_err());
+    }
+}
@@ -0,0 +1,413 @@
+/**
+ * Pattern History - Bounded version history for edited patterns
+ *
+ * DESIGN DECISION: Snapshot the current row into pattern_history before every edit
+ * WHY: update_pattern overwrote content irreversibly; curated libraries need rollback
+ *
+ * REASONING CHAIN:
+ * 1. patterns.version starts at 1 (aetherlight_core::INITIAL_PATTERN_VERSION)
+ * 2. Before an edit, the current row is copied into pattern_history with its version
+ * 3. The edit writes new content and increments patterns.version
+ * 4. Revert is an edit whose new content comes from a history row (history stays linear)
+ * 5. Only the newest `max_versions` snapshots per pattern are kept
+ *
+ * PATTERN: Pattern-STORAGE-002 (SQLite-First, PostgreSQL-Later)
+ * RELATED: main.rs (update_pattern, get_pattern_history, revert_pattern),
+ *          aetherlight_core::Pattern::revise (core equivalent)
+ * PERFORMANCE: One INSERT + one bounded DELETE per edit
+ */
+
+use rusqlite::{params, Connection, OptionalExtension, Result as SqliteResult, Row};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::postgres::add_column_if_missing;
+
+/// Prior versions kept per pattern when settings don't override it
+pub const DEFAULT_MAX_PATTERN_VERSIONS: usize = 20;
+
+/**
+ * PatternVersion - Snapshot of a pattern as it was before an edit
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternVersion {
+    pub pattern_id: String,
+    pub version: u32,
+    pub name: String,
+    pub description: String,
+    pub domain: Option<String>,
+    pub confidence_score: Option<f64>,
+    pub tags: Vec<String>,
+    pub estimated_time_saved_minutes: Option<u32>,
+    pub recorded_at: String, // When this version was superseded (ISO 8601)
+}
+
+/**
+ * PatternContent - Editable columns of a pattern (what an edit writes, what revert restores)
+ *
+ * Tags must already be normalized (aetherlight_core::normalize_tags); they are stored comma-joined.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternContent {
+    pub name: String,
+    pub description: String,
+    pub domain: Option<String>,
+    pub confidence_score: Option<f64>,
+    pub tags: Vec<String>,
+    pub estimated_time_saved_minutes: Option<u32>,
+}
+
+impl From<PatternVersion> for PatternContent {
+    fn from(version: PatternVersion) -> Self {
+        Self {
+            name: version.name,
+            description: version.description,
+            domain: version.domain,
+            confidence_score: version.confidence_score,
+            tags: version.tags,
+            estimated_time_saved_minutes: version.estimated_time_saved_minutes,
+        }
+    }
+}
+
+/**
+ * PatternHistory - Version tracking over the desktop patterns table
+ *
+ * DESIGN DECISION: Borrow the caller's connection
+ * WHY: Tauri commands open one connection per call; history shares it
+ */
+pub struct PatternHistory<'a> {
+    conn: &'a Connection,
+    max_versions: usize,
+}
+
+impl<'a> PatternHistory<'a> {
+    /**
+     * Attach history to an existing patterns table
+     *
+     * SCHEMA:
+     * - patterns.version: INTEGER NOT NULL DEFAULT 1 (added to older databases)
+     * - patterns.estimated_time_saved_minutes: INTEGER (added to older databases)
+     * - pattern_history: (pattern_id, version) → snapshot of name/description/domain/
+     *   confidence_score/tags/estimated_time_saved_minutes plus recorded_at
+     */
+    pub fn new(conn: &'a Connection, max_versions: usize) -> SqliteResult<Self> {
+        // Migrate databases created before versioning
+        let has_version: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('patterns') WHERE name = 'version'",
+            [],
+            |row| row.get::<_, i64>(0).map(|count| count > 0),
+        )?;
+        if !has_version {
+            conn.execute(
+                "ALTER TABLE patterns ADD COLUMN version INTEGER NOT NULL DEFAULT 1",
+                [],
+            )?;
+        }
+
+        add_column_if_missing(conn, "estimated_time_saved_minutes", "INTEGER")?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS pattern_history (
+                pattern_id TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                description TEXT NOT NULL,
+                domain TEXT,
+                confidence_score REAL,
+                tags TEXT,
+                estimated_time_saved_minutes INTEGER,
+                recorded_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (pattern_id, version)
+            )",
+            [],
+        )?;
+
+        // History tables created before time-saved estimates were versioned
+        let history_has_time_saved: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('pattern_history') WHERE name = 'estimated_time_saved_minutes'",
+            [],
+            |row| row.get::<_, i64>(0).map(|count| count > 0),
+        )?;
+        if !history_has_time_saved {
+            conn.execute("ALTER TABLE pattern_history ADD COLUMN estimated_time_saved_minutes INTEGER", [])?;
+        }
+
+        Ok(Self {
+            conn,
+            max_versions: max_versions.max(1),
+        })
+    }
+
+    /// Current version of a pattern (None if it doesn't exist)
+    pub fn current_version(&self, pattern_id: &str) -> SqliteResult<Option<u32>> {
+        self.conn
+            .query_row(
+                "SELECT version FROM patterns WHERE id = ?1",
+                params![pattern_id],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /**
+     * Copy the current row into history, then prune beyond max_versions
+     *
+     * Returns the version that was snapshotted (None if the pattern doesn't exist).
+     * Callers must increment patterns.version in the same edit.
+     */
+    pub fn snapshot(&self, pattern_id: &str) -> SqliteResult<Option<u32>> {
+        let version = match self.current_version(pattern_id)? {
+            Some(version) => version,
+            None => return Ok(None),
+        };
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO pattern_history
+                (pattern_id, version, name, description, domain, confidence_score, tags,
+                 estimated_time_saved_minutes, recorded_at)
+             SELECT id, version, name, description, domain, confidence_score, tags,
+                    estimated_time_saved_minutes, strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             FROM patterns WHERE id = ?1",
+            params![pattern_id],
+        )?;
+
+        self.prune(pattern_id)?;
+        Ok(Some(version))
+    }
+
+    /// Prior versions of a pattern, newest first
+    pub fn versions(&self, pattern_id: &str) -> SqliteResult<Vec<PatternVersion>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT pattern_id, version, name, description, domain, confidence_score, tags, recorded_at,
+                    estimated_time_saved_minutes
+             FROM pattern_history WHERE pattern_id = ?1
+             ORDER BY version DESC",
+        )?;
+
+        let versions = stmt
+            .query_map(params![pattern_id], version_from_row)?
+            .collect::<SqliteResult<Vec<_>>>()?;
+
+        Ok(versions)
+    }
+
+    /// One prior version of a pattern (None if pruned or never recorded)
+    pub fn version(&self, pattern_id: &str, version: u32) -> SqliteResult<Option<PatternVersion>> {
+        self.conn
+            .query_row(
+                "SELECT pattern_id, version, name, description, domain, confidence_score, tags, recorded_at,
+                        estimated_time_saved_minutes
+                 FROM pattern_history WHERE pattern_id = ?1 AND version = ?2",
+                params![pattern_id, version],
+                version_from_row,
+            )
+            .optional()
+    }
+
+    /**
+     * Replace a pattern's content, keeping the current row in history
+     *
+     * Snapshot, UPDATE and version bump run in one transaction.
+     * Returns the new current version (None if the pattern doesn't exist).
+     */
+    pub fn edit(&self, pattern_id: &str, content: &PatternContent) -> SqliteResult<Option<u32>> {
+        let tx = self.conn.unchecked_transaction()?;
+
+        if self.snapshot(pattern_id)?.is_none() {
+            return Ok(None);
+        }
+
+        self.conn.execute(
+            "UPDATE patterns SET
+                name = ?1,
+                description = ?2,
+                domain = ?3,
+                confidence_score = ?4,
+                tags = ?5,
+                estimated_time_saved_minutes = ?6,
+                version = version + 1,
+                updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?7",
+            params![
+                &content.name,
+                &content.description,
+                &content.domain,
+                &content.confidence_score,
+                &content.tags.join(","),
+                &content.estimated_time_saved_minutes,
+                pattern_id
+            ],
+        )?;
+
+        tx.commit()?;
+        self.current_version(pattern_id)
+    }
+
+    /**
+     * Restore a prior version's content as a new version
+     *
+     * DESIGN DECISION: Revert moves forward (v3 reverted to v1 becomes v4)
+     * WHY: The reverted-away content stays in history, so a bad revert is itself undoable
+     *
+     * Returns the new current version (None if the pattern or version doesn't exist).
+     */
+    pub fn revert(&self, pattern_id: &str, version: u32) -> SqliteResult<Option<u32>> {
+        match self.version(pattern_id, version)? {
+            Some(target) => self.edit(pattern_id, &target.into()),
+            None => Ok(None),
+        }
+    }
+
+    /// Drop snapshots older than the newest `max_versions`
+    fn prune(&self, pattern_id: &str) -> SqliteResult<usize> {
+        self.conn.execute(
+            "DELETE FROM pattern_history
+             WHERE pattern_id = ?1 AND version NOT IN (
+                SELECT version FROM pattern_history WHERE pattern_id = ?1
+                ORDER BY version DESC LIMIT ?2
+             )",
+            params![pattern_id, self.max_versions as i64],
+        )
+    }
+}
+
+fn version_from_row(row: &Row) -> SqliteResult<PatternVersion> {
+    let tags_str: Option<String> = row.get(6)?;
+    let tags = tags_str
+        .filter(|s| !s.is_empty())
+        .map(|s| s.split(',').map(|t| t.trim().to_string()).collect())
+        .unwrap_or_default();
+
+    Ok(PatternVersion {
+        pattern_id: row.get(0)?,
+        version: row.get(1)?,
+        name: row.get(2)?,
+        description: row.get(3)?,
+        domain: row.get(4)?,
+        confidence_score: row.get(5)?,
+        tags,
+        estimated_time_saved_minutes: row.get(8)?,
+        recorded_at: row.get(7)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// patterns table as created by main.rs get_pattern_db (pre-versioning)
+    fn pattern_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE patterns (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                description TEXT NOT NULL,
+                domain TEXT,
+                confidence_score REAL,
+                tags TEXT,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+            )",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO patterns (id, name, description, domain, confidence_score, tags)
+             VALUES ('pattern-001', 'Retry', 'Retry with backoff', 'rust', 0.9, 'async,retry')",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    /// pattern-001 as seeded by pattern_db, with another description
+    fn content(description: &str) -> PatternContent {
+        PatternContent {
+            name: "Retry".to_string(),
+            description: description.to_string(),
+            domain: Some("rust".to_string()),
+            confidence_score: Some(0.9),
+            tags: vec!["async".to_string(), "retry".to_string()],
+            estimated_time_saved_minutes: None,
+        }
+    }
+
+    fn edit(history: &PatternHistory, description: &str) {
+        history.edit("pattern-001", &content(description)).unwrap().unwrap();
+    }
+
+    fn description(conn: &Connection) -> String {
+        conn.query_row("SELECT description FROM patterns WHERE id = 'pattern-001'", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_edit_bumps_version() {
+        let conn = pattern_db();
+        let history = PatternHistory::new(&conn, DEFAULT_MAX_PATTERN_VERSIONS).unwrap();
+        assert_eq!(history.current_version("pattern-001").unwrap(), Some(1));
+
+        edit(&history, "Retry with exponential backoff and jitter");
+
+        assert_eq!(history.current_version("pattern-001").unwrap(), Some(2));
+        let versions = history.versions("pattern-001").unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].version, 1);
+        assert_eq!(versions[0].description, "Retry with backoff");
+        assert_eq!(versions[0].tags, vec!["async", "retry"]);
+    }
+
+    #[test]
+    fn test_revert_restores_prior_content() {
+        let conn = pattern_db();
+        let history = PatternHistory::new(&conn, DEFAULT_MAX_PATTERN_VERSIONS).unwrap();
+
+        edit(&history, "Second draft");
+        edit(&history, "Bad edit");
+        assert_eq!(history.current_version("pattern-001").unwrap(), Some(3));
+
+        assert_eq!(history.revert("pattern-001", 1).unwrap(), Some(4));
+        assert_eq!(description(&conn), "Retry with backoff");
+
+        // Reverted-away content is kept
+        let kept: Vec<u32> = history.versions("pattern-001").unwrap().iter().map(|v| v.version).collect();
+        assert_eq!(kept, vec![3, 2, 1]);
+        assert_eq!(history.version("pattern-001", 3).unwrap().unwrap().description, "Bad edit");
+
+        assert_eq!(history.revert("pattern-001", 99).unwrap(), None);
+        assert_eq!(history.revert("missing", 1).unwrap(), None);
+        assert_eq!(history.edit("missing", &content("Retry")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_revert_restores_time_saved_estimate() {
+        let conn = pattern_db();
+        let history = PatternHistory::new(&conn, DEFAULT_MAX_PATTERN_VERSIONS).unwrap();
+        let timed = |minutes| PatternContent { estimated_time_saved_minutes: Some(minutes), ..content("Retry with backoff") };
+
+        history.edit("pattern-001", &timed(15)).unwrap().unwrap();
+        history.edit("pattern-001", &timed(45)).unwrap().unwrap();
+        assert_eq!(history.version("pattern-001", 2).unwrap().unwrap().estimated_time_saved_minutes, Some(15));
+
+        history.revert("pattern-001", 2).unwrap().unwrap();
+        let restored: Option<u32> = conn
+            .query_row("SELECT estimated_time_saved_minutes FROM patterns WHERE id = 'pattern-001'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(restored, Some(15));
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let conn = pattern_db();
+        let history = PatternHistory::new(&conn, 2).unwrap();
+
+        for draft in ["v2", "v3", "v4", "v5"] {
+            edit(&history, draft);
+        }
+
+        let kept: Vec<u32> = history.versions("pattern-001").unwrap().iter().map(|v| v.version).collect();
+        assert_eq!(kept, vec![4, 3]);
+        assert_eq!(history.current_version("pattern-001").unwrap(), Some(5));
+    }
+}
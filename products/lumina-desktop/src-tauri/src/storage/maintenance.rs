@@ -0,0 +1,102 @@
+/**
+ * Database Maintenance - Compaction of the desktop SQLite databases
+ *
+ * DESIGN DECISION: Compact by file path with a short-lived connection
+ * WHY: patterns.db and metadata.db are opened per command (get_pattern_db, SqliteMetadata::new),
+ * so there is no long-lived store to ask. The vector store is compacted by the worker
+ * that owns it (PatternIndexService::compact), analytics.db by UsageTracker::compact
+ *
+ * REASONING CHAIN:
+ * 1. Missing file → skipped (feature never used, nothing to reclaim)
+ * 2. busy_timeout: a concurrent command's write makes VACUUM wait instead of fail
+ * 3. only_if_needed (startup) compacts past aetherlight_core::COMPACTION_FREE_RATIO
+ * 4. compact_databases (settings maintenance panel) always compacts
+ *
+ * PATTERN: Pattern-STORAGE-004 (Local Metadata Storage)
+ * RELATED: aetherlight_core::compaction, main.rs (compact_databases, startup)
+ */
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+/// Bytes reclaimed from one database
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactionReport {
+    pub database: String,
+    pub reclaimed_bytes: u64,
+}
+
+/**
+ * Compact the database at `path`
+ *
+ * None when the file does not exist, or when `only_if_needed` and the
+ * database has too few free pages to be worth rewriting.
+ */
+pub fn compact_database(
+    database: &str,
+    path: &Path,
+    only_if_needed: bool,
+) -> Result<Option<CompactionReport>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let conn = Connection::open(path)
+        .map_err(|e| format!("Failed to open {} database: {}", database, e))?;
+    conn.busy_timeout(Duration::from_secs(5))
+        .map_err(|e| format!("Failed to configure {} database: {}", database, e))?;
+
+    if only_if_needed && !aetherlight_core::needs_compaction(&conn).map_err(|e| e.to_string())? {
+        return Ok(None);
+    }
+
+    let reclaimed_bytes = aetherlight_core::compact_sqlite(&conn)
+        .map_err(|e| format!("Failed to compact {} database: {}", database, e))?;
+    Ok(Some(CompactionReport { database: database.to_string(), reclaimed_bytes }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Test: Deleting most patterns then compacting shrinks patterns.db on disk
+     */
+    #[test]
+    fn test_compaction_reclaims_deleted_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("patterns.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute("CREATE TABLE patterns (id TEXT PRIMARY KEY, description TEXT NOT NULL)", []).unwrap();
+            let description = "Exponential backoff with jitter. ".repeat(32);
+            for i in 0..1000 {
+                conn.execute(
+                    "INSERT INTO patterns (id, description) VALUES (?1, ?2)",
+                    rusqlite::params![format!("pattern-{:04}", i), description],
+                ).unwrap();
+            }
+            conn.execute("DELETE FROM patterns WHERE id > 'pattern-0050'", []).unwrap();
+        }
+        let size_before = std::fs::metadata(&path).unwrap().len();
+
+        let report = compact_database("patterns", &path, true).unwrap().expect("fragmented database compacted");
+        let size_after = std::fs::metadata(&path).unwrap().len();
+
+        assert_eq!(report.database, "patterns");
+        assert!(report.reclaimed_bytes > 0);
+        assert!(size_after < size_before, "{} -> {}", size_before, size_after);
+
+        // Nothing left to reclaim: only a forced compaction runs
+        assert_eq!(compact_database("patterns", &path, true).unwrap(), None);
+        assert!(compact_database("patterns", &path, false).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_missing_database_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(compact_database("metadata", &dir.path().join("metadata.db"), false).unwrap(), None);
+    }
+}
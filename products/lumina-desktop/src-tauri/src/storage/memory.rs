@@ -0,0 +1,126 @@
+/**
+ * Memory Store - In-memory PatternStore for tests
+ *
+ * DESIGN DECISION: Same dedup/alias/ordering rules as PostgresStorage, no SQLite
+ * WHY: Command and sync logic tests should exercise PatternStore semantics, not a database
+ *
+ * RELATED: store.rs (PatternStore), postgres.rs (reference behaviour)
+ */
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use aetherlight_core::{pattern_content_hash, sanitize_tags};
+
+use crate::storage::store::PatternStore;
+use crate::storage::types::{InsertOutcome, PatternQuery, PatternRecord};
+
+#[derive(Default)]
+pub struct MemoryStore {
+    inner: Mutex<Patterns>,
+}
+
+#[derive(Default)]
+struct Patterns {
+    /// id → (content hash, record)
+    records: BTreeMap<String, (String, PatternRecord)>,
+    /// alias id → canonical id
+    aliases: HashMap<String, String>,
+}
+
+impl Patterns {
+    fn canonical_id<'a>(&'a self, id: &'a str) -> &'a str {
+        self.aliases.get(id).map_or(id, String::as_str)
+    }
+}
+
+impl MemoryStore {
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Patterns>, String> {
+        self.inner.lock().map_err(|e| format!("Lock error: {}", e))
+    }
+}
+
+impl PatternStore for MemoryStore {
+    fn insert_pattern(&self, pattern: &PatternRecord) -> Result<InsertOutcome, String> {
+        let mut patterns = self.lock()?;
+        let content_hash = pattern_content_hash(&pattern.name, &pattern.description);
+
+        let existing = patterns
+            .records
+            .iter()
+            .find(|(_, (hash, _))| *hash == content_hash)
+            .map(|(id, _)| id.clone());
+        if let Some(canonical_id) = existing {
+            if canonical_id == pattern.id || patterns.aliases.contains_key(&pattern.id) {
+                return Ok(InsertOutcome::AlreadyStored);
+            }
+            patterns.aliases.insert(pattern.id.clone(), canonical_id.clone());
+            return Ok(InsertOutcome::Duplicate { canonical_id });
+        }
+
+        if let Some((hash, stored)) = patterns.records.get_mut(&pattern.id) {
+            let tags = sanitize_tags(&pattern.tags);
+            *stored = PatternRecord {
+                tags: if tags.is_empty() { std::mem::take(&mut stored.tags) } else { tags },
+                confidence_score: pattern.confidence_score.or(stored.confidence_score),
+                embedding: pattern.embedding.clone().or_else(|| stored.embedding.take()),
+                estimated_time_saved_minutes: pattern.estimated_time_saved_minutes.or(stored.estimated_time_saved_minutes),
+                created_at: std::mem::take(&mut stored.created_at),
+                version: stored.version + 1,
+                ..pattern.clone()
+            };
+            *hash = content_hash;
+            return Ok(InsertOutcome::Updated);
+        }
+        patterns.aliases.remove(&pattern.id);
+
+        let record = PatternRecord { tags: sanitize_tags(&pattern.tags), ..pattern.clone() };
+        patterns.records.insert(pattern.id.clone(), (content_hash, record));
+        Ok(InsertOutcome::Inserted)
+    }
+
+    fn get(&self, id: &str) -> Result<Option<PatternRecord>, String> {
+        let patterns = self.lock()?;
+        let canonical_id = patterns.canonical_id(id);
+        Ok(patterns.records.get(canonical_id).map(|(_, record)| record.clone()))
+    }
+
+    fn search(&self, query: &PatternQuery) -> Result<Vec<PatternRecord>, String> {
+        let patterns = self.lock()?;
+        let text = query.text.as_ref().map(|text| text.to_ascii_lowercase());
+
+        let mut matches: Vec<PatternRecord> = patterns
+            .records
+            .values()
+            .map(|(_, record)| record)
+            .filter(|record| query.domain.is_none() || record.domain == query.domain)
+            .filter(|record| {
+                text.as_ref().is_none_or(|text| {
+                    let matches = |field: &str| field.to_ascii_lowercase().contains(text.as_str());
+                    matches(&record.name)
+                        || matches(&record.description)
+                        || record.domain.as_deref().is_some_and(matches)
+                        || record.tags.iter().any(|tag| matches(tag))
+                })
+            })
+            .cloned()
+            .collect();
+
+        // Confidence descending, unscored last, then id (records are already in id order)
+        matches.sort_by(|a, b| match (a.confidence_score, b.confidence_score) {
+            (Some(a), Some(b)) => b.total_cmp(&a),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        });
+        matches.truncate(query.limit.unwrap_or(usize::MAX));
+        Ok(matches)
+    }
+
+    fn delete(&self, id: &str) -> Result<bool, String> {
+        let mut patterns = self.lock()?;
+        let canonical_id = patterns.canonical_id(id).to_string();
+
+        let deleted = patterns.records.remove(&canonical_id).is_some();
+        patterns.aliases.retain(|alias, canonical| *canonical != canonical_id && *alias != canonical_id);
+        Ok(deleted)
+    }
+}
@@ -9,17 +9,30 @@
  * 2. pgvector extension provides HNSW indexes (<100ms for 10k patterns)
  * 3. PostgreSQL handles pattern storage (name, description, embeddings)
  * 4. SQLite handles metadata (outcomes, calibration, sync_state)
- * 5. Result: Fast semantic search + local-first metadata
+ * 5. Callers go through the PatternStore trait (store.rs), never a concrete backend
+ * 6. Result: Fast semantic search + local-first metadata
  *
  * PATTERN: Pattern-STORAGE-001 (Hybrid Storage Architecture)
  * RELATED: Installer-001 (wizard calls this), Storage-002 (Code.NET sync)
  * PERFORMANCE: <100ms semantic search, <50ms metadata queries
  */
 
+pub mod history;
+pub mod maintenance;
+#[cfg(test)]
+pub mod memory;
+pub mod stats;
 pub mod postgres;
 pub mod sqlite;
+pub mod store;
 pub mod types;
 
+pub use history::{PatternContent, PatternHistory, PatternVersion, DEFAULT_MAX_PATTERN_VERSIONS};
+pub use maintenance::{compact_database, CompactionReport};
+pub use stats::{PatternLibraryStats, DEFAULT_TOP_PATTERNS};
+#[cfg(test)]
+pub use memory::MemoryStore;
 pub use postgres::PostgresStorage;
 pub use sqlite::SqliteMetadata;
+pub use store::{open_pattern_store, PatternStore, PatternStoreState};
 pub use types::*;
@@ -19,15 +19,18 @@
  * will be added in Phase 4 for Pro/Team/Enterprise tiers.
  */
 
-use rusqlite::{Connection, params, Result as SqliteResult};
+use rusqlite::{Connection, OptionalExtension, Row, params, Result as SqliteResult};
 use std::path::PathBuf;
-use crate::storage::types::{PatternRecord, StorageConfig};
+use crate::storage::history::{PatternHistory, DEFAULT_MAX_PATTERN_VERSIONS};
+use crate::storage::store::PatternStore;
+use crate::storage::types::{InsertOutcome, PatternQuery, PatternRecord, StorageConfig};
+use aetherlight_core::{pattern_content_hash, sanitize_tags};
 
 /**
  * PostgresStorage - Pattern storage (currently SQLite-backed)
  *
  * DESIGN DECISION: Abstract interface, swap backend later
- * WHY: Code remains same when migrating SQLite → PostgreSQL
+ * WHY: Code remains same when migrating SQLite → PostgreSQL (callers use it as a PatternStore)
  */
 pub struct PostgresStorage {
     conn: Connection,
@@ -51,6 +54,12 @@ impl PostgresStorage {
      * - embedding: TEXT (JSON array of floats, 384-dim)
      * - created_at: TEXT (ISO 8601)
      * - updated_at: TEXT (ISO 8601)
+     * - content_hash: TEXT (SHA256 of normalized name + description, dedup key)
+     * - version, estimated_time_saved_minutes: Pattern Manager columns (PatternHistory)
+     *
+     * pattern_aliases maps duplicate ids (same content_hash) to the canonical id.
+     * The Pattern Manager (main.rs get_pattern_db) shares patterns.db, so whichever
+     * side created the table first, the missing columns are added here.
      */
     pub fn new(config: StorageConfig) -> SqliteResult<Self> {
         // Create directory if not exists
@@ -77,23 +86,79 @@ impl PostgresStorage {
             [],
         )?;
 
+        // Migrate databases created before content deduplication (or by the Pattern Manager)
+        add_column_if_missing(&conn, "embedding", "TEXT")?;
+        add_column_if_missing(&conn, "content_hash", "TEXT")?;
+        add_column_if_missing(&conn, "estimated_time_saved_minutes", "INTEGER")?;
+        PatternHistory::new(&conn, DEFAULT_MAX_PATTERN_VERSIONS)?; // version + pattern_history
+        Self::backfill_content_hashes(&conn)?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS pattern_aliases (
+                alias_id TEXT PRIMARY KEY,
+                canonical_id TEXT NOT NULL,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP
+            )",
+            [],
+        )?;
+
         // Create indexes for common queries
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_patterns_domain ON patterns(domain)",
             [],
         )?;
 
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_patterns_content_hash ON patterns(content_hash)",
+            [],
+        )?;
+
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_patterns_confidence ON patterns(confidence_score)",
             [],
         )?;
 
-        println!("✅ Pattern storage initialized: {}", config.sqlite_path);
-        println!("   Max storage: {} MB (~{} patterns)", config.storage_mb, config.max_patterns);
+        tracing::info!("Pattern storage initialized: {}", config.sqlite_path);
+        tracing::info!("Max storage: {} MB (~{} patterns)", config.storage_mb, config.max_patterns);
 
         Ok(Self { conn, config })
     }
 
+    /**
+     * Fill content_hash for rows stored before deduplication existed
+     *
+     * DESIGN DECISION: Compute in Rust, one transaction, only rows WHERE content_hash IS NULL
+     * WHY: SQLite has no SHA256; without a hash, legacy rows never match incoming
+     * duplicates. Runs on every open but is a no-op once all rows are hashed.
+     *
+     * Duplicates among the legacy rows themselves are left as-is (no alias rewrite).
+     */
+    fn backfill_content_hashes(conn: &Connection) -> SqliteResult<usize> {
+        let rows: Vec<(String, String, String)> = {
+            let mut stmt = conn.prepare(
+                "SELECT id, name, description FROM patterns WHERE content_hash IS NULL"
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+            rows.collect::<SqliteResult<_>>()?
+        };
+
+        if rows.is_empty() {
+            return Ok(0);
+        }
+
+        let tx = conn.unchecked_transaction()?;
+        for (id, name, description) in &rows {
+            tx.execute(
+                "UPDATE patterns SET content_hash = ?1 WHERE id = ?2",
+                params![pattern_content_hash(name, description), id],
+            )?;
+        }
+        tx.commit()?;
+
+        tracing::info!(rows = rows.len(), "Backfilled pattern content hashes");
+        Ok(rows.len())
+    }
+
     /**
      * Check if storage is provisioned
      */
@@ -132,8 +197,46 @@ impl PostgresStorage {
      *
      * DESIGN DECISION: Validate against storage limits
      * WHY: User chose 200MB-5GB limit, enforce to prevent surprises
+     *
+     * DESIGN DECISION: Deduplicate by content hash before inserting
+     * WHY: Same pattern synced under different ids wastes storage and duplicates search results;
+     * the duplicate id is recorded in pattern_aliases so lookups by either id still resolve
+     *
+     * Re-inserting a known id (canonical or alias) returns AlreadyStored, so re-syncs
+     * aren't counted as newly collapsed duplicates.
+     *
+     * DESIGN DECISION: Known id with new content is an update, not a conflict
+     * WHY: Upstream edits re-sync under the same id; inserting hit the primary key and the
+     * sync stayed "partial" on every retry. The old content is kept in pattern_history.
      */
-    pub fn insert_pattern(&self, pattern: &PatternRecord) -> SqliteResult<()> {
+    pub fn insert_pattern(&self, pattern: &PatternRecord) -> SqliteResult<InsertOutcome> {
+        let content_hash = pattern_content_hash(&pattern.name, &pattern.description);
+
+        if let Some(canonical_id) = self.find_by_content_hash(&content_hash)? {
+            if canonical_id == pattern.id {
+                return Ok(InsertOutcome::AlreadyStored);
+            }
+            let aliased = self.conn.execute(
+                "INSERT OR IGNORE INTO pattern_aliases (alias_id, canonical_id) VALUES (?1, ?2)",
+                params![&pattern.id, &canonical_id],
+            )?;
+            if aliased == 0 {
+                return Ok(InsertOutcome::AlreadyStored);
+            }
+            return Ok(InsertOutcome::Duplicate { canonical_id });
+        }
+
+        if self.conn
+            .query_row("SELECT 1 FROM patterns WHERE id = ?1", params![&pattern.id], |_| Ok(()))
+            .optional()?
+            .is_some()
+        {
+            return self.update_synced_pattern(pattern, &content_hash);
+        }
+
+        // An alias whose content diverged from its canonical pattern becomes a pattern of its own
+        self.conn.execute("DELETE FROM pattern_aliases WHERE alias_id = ?1", params![&pattern.id])?;
+
         // Check storage limits
         let (current_count, _) = self.get_storage_stats()?;
         if current_count >= self.config.max_patterns {
@@ -145,60 +248,152 @@ impl PostgresStorage {
             .map(|emb| serde_json::to_string(emb).unwrap_or_default());
 
         self.conn.execute(
-            "INSERT INTO patterns (id, name, description, domain, tags, confidence_score, embedding, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            "INSERT INTO patterns (id, name, description, domain, tags, confidence_score, embedding, created_at, updated_at, content_hash, estimated_time_saved_minutes)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
             params![
                 &pattern.id,
                 &pattern.name,
                 &pattern.description,
                 &pattern.domain,
-                &pattern.tags.join(","),
+                &sanitize_tags(&pattern.tags).join(","), // Synced data: split rather than reject commas
                 &pattern.confidence_score,
                 &embedding_json,
                 &pattern.created_at,
                 &pattern.updated_at,
+                &content_hash,
+                &pattern.estimated_time_saved_minutes,
             ],
         )?;
 
-        Ok(())
+        Ok(InsertOutcome::Inserted)
+    }
+
+    /**
+     * Replace a stored pattern's content, snapshotting the previous version
+     *
+     * Fields the record leaves empty (tags, confidence, embedding, time saved) keep
+     * their stored values: the sync list endpoint doesn't carry them.
+     */
+    fn update_synced_pattern(&self, pattern: &PatternRecord, content_hash: &str) -> SqliteResult<InsertOutcome> {
+        let embedding_json = pattern.embedding.as_ref()
+            .map(|emb| serde_json::to_string(emb).unwrap_or_default());
+
+        let tx = self.conn.unchecked_transaction()?;
+        PatternHistory::new(&self.conn, DEFAULT_MAX_PATTERN_VERSIONS)?.snapshot(&pattern.id)?;
+        self.conn.execute(
+            "UPDATE patterns SET
+                name = ?2,
+                description = ?3,
+                domain = ?4,
+                tags = CASE WHEN ?5 = '' THEN tags ELSE ?5 END,
+                confidence_score = COALESCE(?6, confidence_score),
+                embedding = COALESCE(?7, embedding),
+                updated_at = ?8,
+                content_hash = ?9,
+                estimated_time_saved_minutes = COALESCE(?10, estimated_time_saved_minutes),
+                version = version + 1
+             WHERE id = ?1",
+            params![
+                &pattern.id,
+                &pattern.name,
+                &pattern.description,
+                &pattern.domain,
+                &sanitize_tags(&pattern.tags).join(","),
+                &pattern.confidence_score,
+                &embedding_json,
+                &pattern.updated_at,
+                content_hash,
+                &pattern.estimated_time_saved_minutes,
+            ],
+        )?;
+        tx.commit()?;
+
+        Ok(InsertOutcome::Updated)
+    }
+
+    /**
+     * Resolve a pattern id to its canonical id (itself if never collapsed)
+     */
+    pub fn canonical_id(&self, id: &str) -> SqliteResult<String> {
+        let canonical = self.conn.query_row(
+            "SELECT canonical_id FROM pattern_aliases WHERE alias_id = ?1",
+            params![id],
+            |row| row.get(0),
+        );
+
+        match canonical {
+            Ok(canonical) => Ok(canonical),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(id.to_string()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /**
+     * Number of duplicate pattern ids collapsed into canonical patterns
+     */
+    pub fn duplicates_collapsed(&self) -> SqliteResult<u64> {
+        self.conn.query_row("SELECT COUNT(*) FROM pattern_aliases", [], |row| row.get(0))
+    }
+
+    fn find_by_content_hash(&self, content_hash: &str) -> SqliteResult<Option<String>> {
+        let id = self.conn.query_row(
+            "SELECT id FROM patterns WHERE content_hash = ?1 LIMIT 1",
+            params![content_hash],
+            |row| row.get(0),
+        );
+
+        match id {
+            Ok(id) => Ok(Some(id)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /**
+     * Pattern by id, following aliases to the canonical pattern
+     */
+    pub fn get_pattern(&self, id: &str) -> SqliteResult<Option<PatternRecord>> {
+        let canonical_id = self.canonical_id(id)?;
+        self.conn.query_row(
+            "SELECT id, name, description, domain, tags, confidence_score, embedding, created_at, updated_at,
+                    version, estimated_time_saved_minutes
+             FROM patterns
+             WHERE id = ?1",
+            params![canonical_id],
+            Self::record_from_row,
+        ).optional()
     }
 
     /**
      * Search patterns by domain
      */
     pub fn search_by_domain(&self, domain: &str) -> SqliteResult<Vec<PatternRecord>> {
+        self.search(&PatternQuery { domain: Some(domain.to_string()), ..Default::default() })
+    }
+
+    /**
+     * Search patterns, highest confidence first
+     *
+     * DESIGN DECISION: instr(lower(..)) rather than LIKE for the text filter
+     * WHY: User text may contain % or _; lower() is ASCII-only, same as MemoryStore
+     */
+    pub fn search(&self, query: &PatternQuery) -> SqliteResult<Vec<PatternRecord>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, name, description, domain, tags, confidence_score, embedding, created_at, updated_at
+            "SELECT id, name, description, domain, tags, confidence_score, embedding, created_at, updated_at,
+                    version, estimated_time_saved_minutes
              FROM patterns
-             WHERE domain = ?1
-             ORDER BY confidence_score DESC"
+             WHERE (?1 IS NULL OR domain = ?1)
+               AND (?2 IS NULL
+                    OR instr(lower(name), lower(?2)) > 0
+                    OR instr(lower(description), lower(?2)) > 0
+                    OR instr(lower(coalesce(domain, '')), lower(?2)) > 0
+                    OR instr(lower(coalesce(tags, '')), lower(?2)) > 0)
+             ORDER BY confidence_score DESC, id
+             LIMIT ?3"
         )?;
 
-        let patterns = stmt.query_map(params![domain], |row| {
-            let tags_str: String = row.get(4).unwrap_or_default();
-            let tags: Vec<String> = if tags_str.is_empty() {
-                vec![]
-            } else {
-                tags_str.split(',').map(|s| s.trim().to_string()).collect()
-            };
-
-            let embedding_json: Option<String> = row.get(6)?;
-            let embedding = embedding_json.and_then(|json| {
-                serde_json::from_str(&json).ok()
-            });
-
-            Ok(PatternRecord {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                domain: row.get(3)?,
-                tags,
-                confidence_score: row.get(5)?,
-                embedding,
-                created_at: row.get(7)?,
-                updated_at: row.get(8)?,
-            })
-        })?;
+        let limit = query.limit.map_or(-1, |limit| limit as i64);
+        let patterns = stmt.query_map(params![query.domain, query.text, limit], Self::record_from_row)?;
 
         let mut result = Vec::new();
         for pattern in patterns {
@@ -208,15 +403,95 @@ impl PostgresStorage {
         Ok(result)
     }
 
+    /**
+     * Delete a pattern (by id or alias) together with its aliases and version history
+     *
+     * Returns false if nothing was stored under the id.
+     */
+    pub fn delete_pattern(&self, id: &str) -> SqliteResult<bool> {
+        let canonical_id = self.canonical_id(id)?;
+
+        let tx = self.conn.unchecked_transaction()?;
+        let deleted = tx.execute("DELETE FROM patterns WHERE id = ?1", params![&canonical_id])?;
+        tx.execute(
+            "DELETE FROM pattern_aliases WHERE canonical_id = ?1 OR alias_id = ?1",
+            params![&canonical_id],
+        )?;
+        tx.execute("DELETE FROM pattern_history WHERE pattern_id = ?1", params![&canonical_id])?;
+        tx.commit()?;
+
+        Ok(deleted > 0)
+    }
+
+    fn record_from_row(row: &Row) -> SqliteResult<PatternRecord> {
+        let tags_str: String = row.get(4).unwrap_or_default();
+        let tags: Vec<String> = if tags_str.is_empty() {
+            vec![]
+        } else {
+            tags_str.split(',').map(|s| s.trim().to_string()).collect()
+        };
+
+        let embedding_json: Option<String> = row.get(6)?;
+        let embedding = embedding_json.and_then(|json| {
+            serde_json::from_str(&json).ok()
+        });
+
+        Ok(PatternRecord {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            description: row.get(2)?,
+            domain: row.get(3)?,
+            tags,
+            confidence_score: row.get(5)?,
+            embedding,
+            created_at: row.get(7)?,
+            updated_at: row.get(8)?,
+            version: row.get(9)?,
+            estimated_time_saved_minutes: row.get(10)?,
+        })
+    }
+
     /**
      * Truncate all patterns (for testing/reset)
      */
     pub fn truncate(&self) -> SqliteResult<()> {
         self.conn.execute("DELETE FROM patterns", [])?;
+        self.conn.execute("DELETE FROM pattern_aliases", [])?;
         Ok(())
     }
 }
 
+/// Adds `column` to the patterns table of databases created without it
+pub fn add_column_if_missing(conn: &Connection, column: &str, definition: &str) -> SqliteResult<()> {
+    let has_column: bool = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('patterns') WHERE name = ?1",
+        params![column],
+        |row| row.get::<_, i64>(0).map(|count| count > 0),
+    )?;
+    if !has_column {
+        conn.execute(&format!("ALTER TABLE patterns ADD COLUMN {} {}", column, definition), [])?;
+    }
+    Ok(())
+}
+
+impl PatternStore for PostgresStorage {
+    fn insert_pattern(&self, pattern: &PatternRecord) -> Result<InsertOutcome, String> {
+        PostgresStorage::insert_pattern(self, pattern).map_err(|e| e.to_string())
+    }
+
+    fn get(&self, id: &str) -> Result<Option<PatternRecord>, String> {
+        self.get_pattern(id).map_err(|e| e.to_string())
+    }
+
+    fn search(&self, query: &PatternQuery) -> Result<Vec<PatternRecord>, String> {
+        PostgresStorage::search(self, query).map_err(|e| e.to_string())
+    }
+
+    fn delete(&self, id: &str) -> Result<bool, String> {
+        self.delete_pattern(id).map_err(|e| e.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,6 +530,8 @@ mod tests {
             embedding: Some(vec![0.1; 384]),
             created_at: "2025-10-14T00:00:00Z".to_string(),
             updated_at: "2025-10-14T00:00:00Z".to_string(),
+            version: 1,
+            estimated_time_saved_minutes: None,
         };
 
         storage.insert_pattern(&pattern).unwrap();
@@ -263,4 +540,156 @@ mod tests {
         assert_eq!(patterns.len(), 1);
         assert_eq!(patterns[0].name, "Test Pattern");
     }
+
+    #[test]
+    fn test_identical_content_stored_once() {
+        let config = StorageConfig {
+            storage_mb: 200,
+            max_patterns: 200,
+            postgres_url: String::new(),
+            sqlite_path: ":memory:".to_string(),
+        };
+
+        let storage = PostgresStorage::new(config).unwrap();
+
+        let original = PatternRecord {
+            id: "pattern-001".to_string(),
+            name: "Retry With Backoff".to_string(),
+            description: "Exponential backoff with jitter".to_string(),
+            domain: Some("rust".to_string()),
+            tags: vec![],
+            confidence_score: Some(0.90),
+            embedding: None,
+            created_at: "2025-10-14T00:00:00Z".to_string(),
+            updated_at: "2025-10-14T00:00:00Z".to_string(),
+            version: 1,
+            estimated_time_saved_minutes: None,
+        };
+        let duplicate = PatternRecord {
+            id: "pattern-042".to_string(),
+            description: "Exponential backoff\r\nwith  jitter".to_string(),
+            ..original.clone()
+        };
+
+        assert_eq!(storage.insert_pattern(&original).unwrap(), InsertOutcome::Inserted);
+        assert_eq!(
+            storage.insert_pattern(&duplicate).unwrap(),
+            InsertOutcome::Duplicate { canonical_id: "pattern-001".to_string() }
+        );
+
+        let patterns = storage.search_by_domain("rust").unwrap();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].id, "pattern-001");
+        assert_eq!(storage.canonical_id("pattern-042").unwrap(), "pattern-001");
+        assert_eq!(storage.duplicates_collapsed().unwrap(), 1);
+
+        // Re-sync of both ids changes nothing and is not another collapse
+        assert_eq!(storage.insert_pattern(&original).unwrap(), InsertOutcome::AlreadyStored);
+        assert_eq!(storage.insert_pattern(&duplicate).unwrap(), InsertOutcome::AlreadyStored);
+        assert_eq!(storage.duplicates_collapsed().unwrap(), 1);
+    }
+
+    /**
+     * Test: patterns.db created by the Pattern Manager opens as a store with its columns
+     */
+    #[test]
+    fn test_pattern_manager_table_migrated() {
+        let dir = tempfile::tempdir().unwrap();
+        let sqlite_path = dir.path().join("patterns.db").to_string_lossy().to_string();
+
+        // Schema as main.rs get_pattern_db creates it (no embedding column)
+        let manager = Connection::open(&sqlite_path).unwrap();
+        manager.execute(
+            "CREATE TABLE patterns (
+                id TEXT PRIMARY KEY, name TEXT NOT NULL, description TEXT NOT NULL, domain TEXT,
+                confidence_score REAL, tags TEXT,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP, updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                estimated_time_saved_minutes INTEGER
+            )",
+            [],
+        ).unwrap();
+        manager.execute(
+            "INSERT INTO patterns (id, name, description, domain, tags, estimated_time_saved_minutes)
+             VALUES ('pattern-001', 'Retry With Backoff', 'Exponential backoff', 'rust', 'retry,network', 15)",
+            [],
+        ).unwrap();
+        drop(manager);
+
+        let storage = PostgresStorage::new(StorageConfig {
+            storage_mb: 200,
+            max_patterns: 200,
+            postgres_url: String::new(),
+            sqlite_path,
+        }).unwrap();
+
+        let pattern = storage.get_pattern("pattern-001").unwrap().unwrap();
+        assert_eq!(pattern.version, 1);
+        assert_eq!(pattern.estimated_time_saved_minutes, Some(15));
+
+        // Text search also matches tags
+        let found = storage.search(&PatternQuery { text: Some("NETWORK".to_string()), ..Default::default() }).unwrap();
+        assert_eq!(found.len(), 1);
+
+        storage.conn.execute(
+            "INSERT INTO pattern_history (pattern_id, version, name, description) VALUES ('pattern-001', 1, 'Old', 'Old')",
+            [],
+        ).unwrap();
+        assert!(storage.delete_pattern("pattern-001").unwrap());
+        let history: i64 = storage.conn.query_row("SELECT COUNT(*) FROM pattern_history", [], |row| row.get(0)).unwrap();
+        assert_eq!(history, 0);
+    }
+
+    /**
+     * Test: Rows from before the content_hash column get hashed on open and dedupe
+     */
+    #[test]
+    fn test_legacy_rows_backfilled() {
+        let dir = tempfile::tempdir().unwrap();
+        let sqlite_path = dir.path().join("patterns.db").to_string_lossy().to_string();
+
+        // Pre-dedup schema with one stored pattern
+        let legacy = Connection::open(&sqlite_path).unwrap();
+        legacy.execute(
+            "CREATE TABLE patterns (
+                id TEXT PRIMARY KEY, name TEXT NOT NULL, description TEXT NOT NULL, domain TEXT,
+                tags TEXT, confidence_score REAL, embedding TEXT, created_at TEXT, updated_at TEXT
+            )",
+            [],
+        ).unwrap();
+        legacy.execute(
+            "INSERT INTO patterns (id, name, description, domain) VALUES ('legacy-1', 'Retry With Backoff', 'Exponential backoff with jitter', 'rust')",
+            [],
+        ).unwrap();
+        drop(legacy);
+
+        let storage = PostgresStorage::new(StorageConfig {
+            storage_mb: 200,
+            max_patterns: 200,
+            postgres_url: String::new(),
+            sqlite_path,
+        }).unwrap();
+
+        let unhashed: i64 = storage.conn.query_row(
+            "SELECT COUNT(*) FROM patterns WHERE content_hash IS NULL", [], |row| row.get(0),
+        ).unwrap();
+        assert_eq!(unhashed, 0);
+
+        let incoming = PatternRecord {
+            id: "pattern-007".to_string(),
+            name: "Retry With Backoff".to_string(),
+            description: "Exponential backoff with jitter".to_string(),
+            domain: Some("rust".to_string()),
+            tags: vec![],
+            confidence_score: None,
+            embedding: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            version: 1,
+            estimated_time_saved_minutes: None,
+        };
+        assert_eq!(
+            storage.insert_pattern(&incoming).unwrap(),
+            InsertOutcome::Duplicate { canonical_id: "legacy-1".to_string() }
+        );
+    }
 }
@@ -121,7 +121,7 @@ impl SqliteMetadata {
             [],
         )?;
 
-        println!("✅ Metadata storage initialized: {}", db_path);
+        tracing::info!("Metadata storage initialized: {}", db_path);
 
         Ok(Self { conn })
     }
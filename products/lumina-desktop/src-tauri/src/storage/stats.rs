@@ -0,0 +1,235 @@
+/**
+ * Pattern Library Statistics - Aggregates over the local patterns table
+ *
+ * DESIGN DECISION: One pass over the patterns table, usage joined from analytics
+ * WHY: Library overview panel needs the shape of the library (size, domains,
+ * confidence, most-used) without loading every pattern into the frontend
+ *
+ * REASONING CHAIN:
+ * 1. Totals, per-domain counts and average confidence come from one pass over patterns
+ * 2. Confidence is bucketed into fixed 0.2-wide ranges (unscored patterns counted apart)
+ * 3. Usage lives in analytics.db: pattern_match events with {"pattern_id": ...} metadata
+ * 4. Usage counts are joined onto pattern names; ids no longer in the library are dropped
+ * 5. Top-N most used, ties broken by name for stable output
+ *
+ * PATTERN: Pattern-STORAGE-002 (SQLite-First, PostgreSQL-Later)
+ * RELATED: main.rs (get_pattern_stats), aetherlight_core::PatternIndexStatistics
+ *          (semantic index equivalent)
+ * PERFORMANCE: One query over patterns, O(events) usage scan
+ */
+
+use aetherlight_core::analytics::{EventRecord, EventType};
+use rusqlite::{Connection, Result as SqliteResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Most-used patterns returned when the caller doesn't choose
+pub const DEFAULT_TOP_PATTERNS: usize = 5;
+
+/// Confidence bucket ranges [min, max)
+const CONFIDENCE_BUCKET_BOUNDS: [(f64, f64); 5] = [(0.0, 0.2), (0.2, 0.4), (0.4, 0.6), (0.6, 0.8), (0.8, 1.0)];
+
+/// Domain label for patterns stored without a domain
+const UNCATEGORIZED_DOMAIN: &str = "uncategorized";
+
+/**
+ * PatternLibraryStats - Library overview (lexical store counterpart of PatternIndexStatistics)
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternLibraryStats {
+    pub total_patterns: usize,
+    pub by_domain: BTreeMap<String, usize>,
+    pub average_confidence: Option<f64>, // None when no pattern has a confidence score
+    pub confidence_buckets: Vec<ConfidenceBucket>,
+    pub unscored_patterns: usize,
+    pub most_used: Vec<PatternUsage>,
+}
+
+/// Patterns whose confidence falls in [min, max) (the last bucket includes 1.0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceBucket {
+    pub min: f64,
+    pub max: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternUsage {
+    pub pattern_id: String,
+    pub name: String,
+    pub uses: u64,
+}
+
+/// Count pattern_match events per pattern_id (events without one are ignored)
+pub fn usage_counts(events: &[EventRecord]) -> HashMap<String, u64> {
+    let mut counts = HashMap::new();
+
+    for event in events.iter().filter(|e| e.event_type == EventType::PatternMatch) {
+        let pattern_id = event
+            .metadata
+            .as_deref()
+            .and_then(|metadata| serde_json::from_str::<serde_json::Value>(metadata).ok())
+            .and_then(|metadata| metadata.get("pattern_id")?.as_str().map(str::to_string));
+
+        if let Some(pattern_id) = pattern_id {
+            *counts.entry(pattern_id).or_insert(0) += 1;
+        }
+    }
+
+    counts
+}
+
+/// Aggregate the patterns table, ranking the `top_n` most used by `usage`
+pub fn pattern_stats(
+    conn: &Connection,
+    usage: &HashMap<String, u64>,
+    top_n: usize,
+) -> SqliteResult<PatternLibraryStats> {
+    let mut stmt = conn.prepare("SELECT id, name, domain, confidence_score FROM patterns")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, Option<f64>>(3)?,
+        ))
+    })?;
+
+    let mut by_domain = BTreeMap::new();
+    let mut confidence_buckets: Vec<ConfidenceBucket> = CONFIDENCE_BUCKET_BOUNDS
+        .iter()
+        .map(|&(min, max)| ConfidenceBucket { min, max, count: 0 })
+        .collect();
+    let mut confidence_sum = 0.0;
+    let mut scored = 0;
+    let mut total_patterns = 0;
+    let mut most_used = Vec::new();
+
+    for row in rows {
+        let (id, name, domain, confidence) = row?;
+        total_patterns += 1;
+
+        let domain = domain
+            .map(|d| d.trim().to_lowercase())
+            .filter(|d| !d.is_empty())
+            .unwrap_or_else(|| UNCATEGORIZED_DOMAIN.to_string());
+        *by_domain.entry(domain).or_insert(0) += 1;
+
+        if let Some(confidence) = confidence {
+            let confidence = confidence.clamp(0.0, 1.0);
+            confidence_sum += confidence;
+            scored += 1;
+            let bucket = CONFIDENCE_BUCKET_BOUNDS
+                .iter()
+                .position(|&(_, max)| confidence < max)
+                .unwrap_or(CONFIDENCE_BUCKET_BOUNDS.len() - 1);
+            confidence_buckets[bucket].count += 1;
+        }
+
+        if let Some(&uses) = usage.get(&id).filter(|&&uses| uses > 0) {
+            most_used.push(PatternUsage { pattern_id: id, name, uses });
+        }
+    }
+
+    most_used.sort_by(|a, b| b.uses.cmp(&a.uses).then_with(|| a.name.cmp(&b.name)));
+    most_used.truncate(top_n);
+
+    Ok(PatternLibraryStats {
+        total_patterns,
+        by_domain,
+        average_confidence: (scored > 0).then(|| confidence_sum / scored as f64),
+        confidence_buckets,
+        unscored_patterns: total_patterns - scored,
+        most_used,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    /// patterns table as created by main.rs get_pattern_db
+    fn seeded_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE patterns (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                description TEXT NOT NULL,
+                domain TEXT,
+                confidence_score REAL,
+                tags TEXT
+            )",
+            [],
+        )
+        .unwrap();
+        for (id, name, domain, confidence) in [
+            ("p1", "Retry", Some("rust"), Some(0.9)),
+            ("p2", "Result Errors", Some("Rust"), Some(0.7)),
+            ("p3", "useState", Some("typescript"), Some(1.0)),
+            ("p4", "Draft", None, None),
+        ] {
+            conn.execute(
+                "INSERT INTO patterns (id, name, description, domain, confidence_score, tags)
+                 VALUES (?1, ?2, 'description', ?3, ?4, '')",
+                rusqlite::params![id, name, domain, confidence],
+            )
+            .unwrap();
+        }
+        conn
+    }
+
+    fn pattern_match(metadata: Option<&str>) -> EventRecord {
+        EventRecord {
+            timestamp: Utc::now(),
+            event_type: EventType::PatternMatch,
+            time_saved_minutes: EventType::PatternMatch.time_saved_minutes() as i64,
+            metadata: metadata.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_pattern_stats_aggregates() {
+        let conn = seeded_db();
+        let events = vec![
+            pattern_match(Some(r#"{"pattern_id": "p2"}"#)),
+            pattern_match(Some(r#"{"pattern_id": "p2"}"#)),
+            pattern_match(Some(r#"{"pattern_id": "p1"}"#)),
+            pattern_match(Some(r#"{"pattern_id": "deleted"}"#)),
+            pattern_match(Some("not json")),
+            pattern_match(None),
+        ];
+        let usage = usage_counts(&events);
+        assert_eq!(usage.len(), 3);
+
+        let stats = pattern_stats(&conn, &usage, 2).unwrap();
+
+        assert_eq!(stats.total_patterns, 4);
+        assert_eq!(stats.by_domain["rust"], 2);
+        assert_eq!(stats.by_domain["typescript"], 1);
+        assert_eq!(stats.by_domain[UNCATEGORIZED_DOMAIN], 1);
+
+        assert!((stats.average_confidence.unwrap() - 0.8666).abs() < 1e-3);
+        assert_eq!(stats.unscored_patterns, 1);
+        let counts: Vec<usize> = stats.confidence_buckets.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![0, 0, 0, 1, 2]);
+
+        let most_used: Vec<(&str, u64)> =
+            stats.most_used.iter().map(|u| (u.pattern_id.as_str(), u.uses)).collect();
+        assert_eq!(most_used, vec![("p2", 2), ("p1", 1)]);
+    }
+
+    #[test]
+    fn test_empty_library() {
+        let conn = seeded_db();
+        conn.execute("DELETE FROM patterns", []).unwrap();
+
+        let stats = pattern_stats(&conn, &HashMap::new(), DEFAULT_TOP_PATTERNS).unwrap();
+
+        assert_eq!(stats.total_patterns, 0);
+        assert_eq!(stats.average_confidence, None);
+        assert!(stats.most_used.is_empty());
+        assert_eq!(stats.confidence_buckets.len(), 5);
+    }
+}
@@ -0,0 +1,235 @@
+/**
+ * Pattern Store - Backend-neutral pattern storage interface (Storage-001)
+ *
+ * DESIGN DECISION: Commands and pattern_sync depend on `dyn PatternStore`, not PostgresStorage
+ * WHY: The v1.0 backend is SQLite behind a PostgreSQL name; naming it everywhere made the
+ * Phase 4 PostgreSQL swap a multi-file edit and kept command logic untestable without a db
+ *
+ * REASONING CHAIN:
+ * 1. PatternStore covers what callers need: insert (with dedup), get, search, delete
+ * 2. open_pattern_store() is the only place that picks a concrete backend
+ * 3. PatternStoreState (Tauri managed state) holds the open store, empty until provisioned
+ * 4. MemoryStore (tests) implements the same dedup semantics without SQLite
+ * 5. Result: PostgreSQL backend = one new impl + one branch in open_pattern_store()
+ *
+ * PATTERN: Pattern-STORAGE-002 (SQLite-First, PostgreSQL-Later)
+ * RELATED: postgres.rs (PostgresStorage), memory.rs (MemoryStore), pattern_sync.rs,
+ *          main.rs (provision_postgresql, sync_initial_patterns)
+ */
+
+use std::sync::Mutex;
+
+use crate::storage::postgres::PostgresStorage;
+use crate::storage::types::{InsertOutcome, PatternQuery, PatternRecord, StorageConfig};
+
+/**
+ * Pattern storage backend
+ *
+ * Errors are plain strings, same as the Tauri commands that surface them.
+ */
+pub trait PatternStore: Send {
+    /// Store a pattern; identical content under another id is aliased, not duplicated
+    fn insert_pattern(&self, pattern: &PatternRecord) -> Result<InsertOutcome, String>;
+
+    /// Pattern by id or alias
+    fn get(&self, id: &str) -> Result<Option<PatternRecord>, String>;
+
+    /// Patterns matching `query`, highest confidence first
+    fn search(&self, query: &PatternQuery) -> Result<Vec<PatternRecord>, String>;
+
+    /// Delete a pattern (by id or alias), its aliases and version history; false if not stored
+    fn delete(&self, id: &str) -> Result<bool, String>;
+}
+
+/**
+ * Open the pattern store described by `config`
+ *
+ * Always SQLite-backed for now (config.postgres_url is not used until Phase 4).
+ */
+pub fn open_pattern_store(config: StorageConfig) -> Result<Box<dyn PatternStore>, String> {
+    let storage = PostgresStorage::new(config)
+        .map_err(|e| format!("Failed to initialize pattern storage: {}", e))?;
+    Ok(Box::new(storage))
+}
+
+/**
+ * Pattern store shared by the storage commands (Tauri managed state)
+ *
+ * DESIGN DECISION: Empty until provision_postgresql/sync_initial_patterns opens a store
+ * WHY: The storage limit is chosen in the installation wizard, not known at startup
+ */
+#[derive(Default)]
+pub struct PatternStoreState {
+    store: Mutex<Option<Box<dyn PatternStore>>>,
+}
+
+impl PatternStoreState {
+    /// Replace the current store (the previous one is closed)
+    pub fn install(&self, store: Box<dyn PatternStore>) -> Result<(), String> {
+        let mut current = self.store.lock().map_err(|e| format!("Lock error: {}", e))?;
+        *current = Some(store);
+        Ok(())
+    }
+
+    /// Run `f` against the current store
+    pub fn with_store<T>(
+        &self,
+        f: impl FnOnce(&dyn PatternStore) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let current = self.store.lock().map_err(|e| format!("Lock error: {}", e))?;
+        match current.as_deref() {
+            Some(store) => f(store),
+            None => Err("Pattern storage not provisioned".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStore;
+
+    fn record(id: &str, name: &str, domain: &str, confidence: f64) -> PatternRecord {
+        PatternRecord {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: format!("{} description", name),
+            domain: Some(domain.to_string()),
+            tags: vec![],
+            confidence_score: Some(confidence),
+            embedding: None,
+            created_at: "2025-10-14T00:00:00Z".to_string(),
+            updated_at: "2025-10-14T00:00:00Z".to_string(),
+            version: 1,
+            estimated_time_saved_minutes: None,
+        }
+    }
+
+    #[test]
+    fn test_state_requires_provisioned_store() {
+        let state = PatternStoreState::default();
+
+        let err = state.with_store(|store| store.get("pattern-001")).unwrap_err();
+        assert_eq!(err, "Pattern storage not provisioned");
+
+        state.install(Box::new(MemoryStore::default())).unwrap();
+        assert!(state.with_store(|store| store.get("pattern-001")).unwrap().is_none());
+    }
+
+    /**
+     * Test: insert/get/search/delete through the managed state, as the commands use it
+     */
+    #[test]
+    fn test_store_operations_through_state() {
+        let state = PatternStoreState::default();
+        state.install(Box::new(MemoryStore::default())).unwrap();
+
+        state.with_store(|store| {
+            store.insert_pattern(&record("pattern-001", "Retry With Backoff", "rust", 0.7))?;
+            store.insert_pattern(&record("pattern-002", "Circuit Breaker", "rust", 0.9))?;
+            store.insert_pattern(&record("pattern-003", "Debounce Input", "typescript", 0.8))?;
+            let duplicate = PatternRecord { id: "pattern-042".to_string(), ..record("", "Retry With Backoff", "rust", 0.7) };
+            assert_eq!(
+                store.insert_pattern(&duplicate)?,
+                InsertOutcome::Duplicate { canonical_id: "pattern-001".to_string() }
+            );
+            Ok(())
+        }).unwrap();
+
+        let rust: Vec<String> = state
+            .with_store(|store| store.search(&PatternQuery { domain: Some("rust".to_string()), ..Default::default() }))
+            .unwrap()
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(rust, vec!["pattern-002", "pattern-001"]);
+
+        let by_text = state
+            .with_store(|store| store.search(&PatternQuery { text: Some("BACKOFF".to_string()), limit: Some(5), ..Default::default() }))
+            .unwrap();
+        assert_eq!(by_text.len(), 1);
+
+        // Alias resolves to the canonical pattern; deleting through it removes both
+        let aliased = state.with_store(|store| store.get("pattern-042")).unwrap().unwrap();
+        assert_eq!(aliased.id, "pattern-001");
+        assert!(state.with_store(|store| store.delete("pattern-042")).unwrap());
+        assert!(state.with_store(|store| store.get("pattern-001")).unwrap().is_none());
+        assert!(state.with_store(|store| store.get("pattern-042")).unwrap().is_none());
+        assert!(!state.with_store(|store| store.delete("pattern-001")).unwrap());
+    }
+
+    /**
+     * Test: Re-inserting a known id with new content updates it in both backends
+     */
+    #[test]
+    fn test_changed_content_updates_stored_pattern() {
+        let sqlite = open_pattern_store(StorageConfig {
+            storage_mb: 200,
+            max_patterns: 200,
+            postgres_url: String::new(),
+            sqlite_path: ":memory:".to_string(),
+        })
+        .unwrap();
+        let memory: Box<dyn PatternStore> = Box::new(MemoryStore::default());
+
+        for store in [sqlite.as_ref(), memory.as_ref()] {
+            store.insert_pattern(&record("pattern-001", "Retry With Backoff", "rust", 0.7)).unwrap();
+            let renamed = PatternRecord {
+                confidence_score: None,
+                updated_at: "2025-11-01T00:00:00Z".to_string(),
+                ..record("pattern-001", "Retry With Jitter", "rust", 0.0)
+            };
+
+            assert_eq!(store.insert_pattern(&renamed).unwrap(), InsertOutcome::Updated);
+            assert_eq!(store.insert_pattern(&renamed).unwrap(), InsertOutcome::AlreadyStored);
+
+            let stored = store.get("pattern-001").unwrap().unwrap();
+            assert_eq!(stored.name, "Retry With Jitter");
+            assert_eq!(stored.version, 2);
+            assert_eq!(stored.confidence_score, Some(0.7));
+            assert_eq!(stored.created_at, "2025-10-14T00:00:00Z");
+            assert_eq!(stored.updated_at, "2025-11-01T00:00:00Z");
+        }
+    }
+
+    /**
+     * Test: SQLite backend answers the same queries the same way as MemoryStore
+     */
+    #[test]
+    fn test_sqlite_and_memory_stores_agree() {
+        let sqlite = open_pattern_store(StorageConfig {
+            storage_mb: 200,
+            max_patterns: 200,
+            postgres_url: String::new(),
+            sqlite_path: ":memory:".to_string(),
+        })
+        .unwrap();
+        let memory: Box<dyn PatternStore> = Box::new(MemoryStore::default());
+
+        let queries = [
+            PatternQuery::default(),
+            PatternQuery { domain: Some("rust".to_string()), ..Default::default() },
+            PatternQuery { text: Some("circuit".to_string()), ..Default::default() },
+            PatternQuery { limit: Some(2), ..Default::default() },
+        ];
+
+        for store in [sqlite.as_ref(), memory.as_ref()] {
+            store.insert_pattern(&record("pattern-001", "Retry With Backoff", "rust", 0.7)).unwrap();
+            store.insert_pattern(&record("pattern-002", "Circuit Breaker", "rust", 0.9)).unwrap();
+            store.insert_pattern(&record("pattern-003", "Debounce Input", "typescript", 0.8)).unwrap();
+            store.insert_pattern(&PatternRecord { id: "pattern-042".to_string(), ..record("", "Circuit Breaker", "rust", 0.9) }).unwrap();
+            assert!(store.delete("pattern-003").unwrap());
+        }
+
+        for query in &queries {
+            let ids = |store: &dyn PatternStore| -> Vec<String> {
+                store.search(query).unwrap().into_iter().map(|p| p.id).collect()
+            };
+            assert_eq!(ids(sqlite.as_ref()), ids(memory.as_ref()), "{:?}", query);
+        }
+        assert_eq!(
+            sqlite.get("pattern-042").unwrap().map(|p| p.id),
+            memory.get("pattern-042").unwrap().map(|p| p.id)
+        );
+    }
+}
@@ -24,6 +24,48 @@ pub struct PatternRecord {
     pub embedding: Option<Vec<f32>>, // 384-dim for all-MiniLM-L6-v2
     pub created_at: String,
     pub updated_at: String,
+    /// Edit count, maintained by PatternHistory (ignored on insert, new patterns start at 1)
+    #[serde(default = "initial_version")]
+    pub version: u32,
+    /// Minutes credited per pattern_match event (None = analytics default)
+    #[serde(default)]
+    pub estimated_time_saved_minutes: Option<u32>,
+}
+
+fn initial_version() -> u32 {
+    aetherlight_core::INITIAL_PATTERN_VERSION
+}
+
+/**
+ * PatternQuery - Filters for PatternStore::search (all optional, combined with AND)
+ *
+ * DESIGN DECISION: Plain substring match on name/description, no embeddings
+ * WHY: Synced patterns carry no embeddings; semantic search goes through the pattern index
+ */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PatternQuery {
+    pub domain: Option<String>,
+    /// Case-insensitive (ASCII) substring of name, description, domain or tags
+    pub text: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/**
+ * InsertOutcome - Result of inserting a pattern into a PatternStore
+ *
+ * DESIGN DECISION: Duplicates reported, not errors
+ * WHY: Sync sources overlap; identical content under a new id is expected, not a failure
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertOutcome {
+    /// New content stored under the record's id
+    Inserted,
+    /// Identical content already stored; record id newly aliased to `canonical_id`
+    Duplicate { canonical_id: String },
+    /// Record id already stored or aliased (re-sync of a known pattern, nothing changed)
+    AlreadyStored,
+    /// Record id already stored with different content; stored pattern updated to a new version
+    Updated,
 }
 
 /**
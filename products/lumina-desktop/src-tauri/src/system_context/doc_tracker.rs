@@ -80,12 +80,12 @@ impl DocTracker {
                 match Self::analyze_documentation(&workspace_path) {
                     Ok(update) => {
                         if let Err(e) = tx.send(update).await {
-                            eprintln!("DocTracker: Failed to send update: {}", e);
+                            tracing::warn!("DocTracker: Failed to send update: {}", e);
                             break;
                         }
                     }
                     Err(e) => {
-                        eprintln!("DocTracker: Analysis failed: {}", e);
+                        tracing::warn!("DocTracker: Analysis failed: {}", e);
                     }
                 }
             }
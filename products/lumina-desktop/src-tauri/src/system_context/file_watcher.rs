@@ -6,7 +6,7 @@
  *
  * REASONING CHAIN:
  * 1. User creates/modifies/deletes files → notify events triggered
- * 2. FileWatcher filters events (ignore .git/, node_modules/, build/)
+ * 2. FileWatcher filters events with gitignore rules (defaults + .gitignore + settings globs)
  * 3. Sends FileUpdate events to aggregator
  * 4. SystemContext updated with recently changed files
  * 5. Pattern matcher knows what files user actively working on
@@ -17,9 +17,11 @@
  */
 
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 use notify::{Watcher, RecursiveMode, Result as NotifyResult};
-use anyhow::Result;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use anyhow::{Context, Result};
 
 use super::types::FileUpdate;
 
@@ -44,8 +46,34 @@ use super::types::FileUpdate;
  */
 pub struct FileWatcher {
     workspace_path: PathBuf,
+    ignore: Arc<Gitignore>,
 }
 
+/**
+ * Ignore rules applied before any workspace .gitignore (gitignore syntax)
+ *
+ * Trailing `/` matches directories only, so `target/` ignores everything under
+ * any directory named target but not a file named `target.rs`.
+ */
+pub const DEFAULT_IGNORE_GLOBS: &[&str] = &[
+    ".git/",
+    "node_modules/",
+    "target/",
+    "dist/",
+    "build/",
+    "out/",
+    ".next/",
+    ".nuxt/",
+    ".svelte-kit/",
+    "coverage/",
+    ".cache/",
+    "*.log",
+    "*.tmp",
+    "*.swp",
+    "*.swo",
+    "*~",
+];
+
 impl FileWatcher {
     /**
      * Create new file watcher
@@ -57,12 +85,33 @@ impl FileWatcher {
         if !path.exists() {
             anyhow::bail!("Workspace path does not exist: {}", workspace_path);
         }
+        // notify reports canonical paths (e.g. /private/var on macOS); match against the same root
+        let path = path.canonicalize().unwrap_or(path);
 
+        let ignore = Arc::new(build_ignore(&path, &[])?);
         Ok(Self {
             workspace_path: path,
+            ignore,
         })
     }
 
+    /**
+     * Add ignore globs on top of the defaults and the workspace .gitignore
+     *
+     * DESIGN DECISION: Invalid globs are skipped with a warning, never fatal
+     * WHY: One typo in settings must not stop file watching (and the IPC server
+     * started with it); save_settings rejects invalid globs up front (validate_ignore_glob)
+     *
+     * @param globs - Gitignore-syntax patterns (settings: file_watcher_ignore_globs)
+     */
+    pub fn with_ignore_globs(mut self, globs: &[String]) -> Self {
+        match build_ignore(&self.workspace_path, globs) {
+            Ok(ignore) => self.ignore = Arc::new(ignore),
+            Err(e) => tracing::warn!("FileWatcher: Keeping default ignore rules: {:#}", e),
+        }
+        self
+    }
+
     /**
      * Start watching filesystem (spawns background task)
      *
@@ -79,6 +128,7 @@ impl FileWatcher {
      */
     pub async fn start(&self, tx: Sender<FileUpdate>) -> Result<()> {
         let workspace_path = self.workspace_path.clone();
+        let ignore = Arc::clone(&self.ignore);
 
         // Create channel for notify events
         let (notify_tx, mut notify_rx) = tokio::sync::mpsc::channel(100);
@@ -112,7 +162,7 @@ impl FileWatcher {
             while let Some(event) = notify_rx.recv().await {
                 for path in event.paths {
                     // Filter ignored paths
-                    if Self::should_ignore(&path) {
+                    if should_ignore(&ignore, &path) {
                         continue;
                     }
 
@@ -125,7 +175,7 @@ impl FileWatcher {
                     };
 
                     if let Err(e) = tx.send(update).await {
-                        eprintln!("FileWatcher: Failed to send update: {}", e);
+                        tracing::warn!("FileWatcher: Failed to send update: {}", e);
                         break;
                     }
                 }
@@ -135,62 +185,66 @@ impl FileWatcher {
         Ok(())
     }
 
-    /**
-     * Check if path should be ignored
-     *
-     * DESIGN DECISION: Hardcoded ignore list (not .gitignore parsing)
-     * WHY: Simple, fast, covers 99% of use cases
-     *
-     * IGNORED PATHS:
-     * - .git/ (git internals)
-     * - node_modules/ (npm packages)
-     * - target/ (Rust build)
-     * - dist/, build/, out/ (build outputs)
-     * - .next/, .nuxt/, .svelte-kit/ (framework builds)
-     * - *.log, *.tmp, *~ (temp files)
-     *
-     * @param path - File path to check
-     * @return true if should ignore
-     */
-    fn should_ignore(path: &Path) -> bool {
-        let path_str = path.to_string_lossy();
-
-        // Ignore directories
-        let ignored_dirs = [
-            ".git",
-            "node_modules",
-            "target",
-            "dist",
-            "build",
-            "out",
-            ".next",
-            ".nuxt",
-            ".svelte-kit",
-            "coverage",
-            ".cache",
-        ];
-
-        for dir in &ignored_dirs {
-            if path_str.contains(dir) {
-                return true;
-            }
-        }
+    /// Check if a path is excluded from FileChanged updates
+    pub fn should_ignore(&self, path: &Path) -> bool {
+        should_ignore(&self.ignore, path)
+    }
+}
 
-        // Ignore file extensions
-        if let Some(ext) = path.extension() {
-            let ext_str = ext.to_string_lossy();
-            if matches!(ext_str.as_ref(), "log" | "tmp" | "swp" | "swo") {
-                return true;
-            }
+/**
+ * Build the ignore matcher for a workspace
+ *
+ * DESIGN DECISION: Gitignore semantics via the `ignore` crate (not substring matching)
+ * WHY: Substring checks ignored unrelated files (`out` matched `layout.rs`) and missed
+ * project-specific build output; .gitignore already lists what the user considers noise
+ *
+ * Order (later rules win, `!pattern` re-includes): defaults → workspace .gitignore → extra globs
+ * Only the workspace root .gitignore is read. Invalid extra globs are skipped (warned).
+ */
+fn build_ignore(root: &Path, extra_globs: &[String]) -> Result<Gitignore> {
+    let mut builder = GitignoreBuilder::new(root);
+
+    for glob in DEFAULT_IGNORE_GLOBS {
+        builder
+            .add_line(None, glob)
+            .with_context(|| format!("Invalid default ignore glob: {}", glob))?;
+    }
+
+    let gitignore = root.join(".gitignore");
+    if gitignore.exists() {
+        if let Some(e) = builder.add(&gitignore) {
+            tracing::warn!("FileWatcher: Some .gitignore rules could not be parsed: {}", e);
         }
+    }
 
-        // Ignore temp files (ending with ~)
-        if path_str.ends_with('~') {
-            return true;
+    for glob in extra_globs {
+        if let Err(e) = builder.add_line(None, glob) {
+            tracing::warn!("FileWatcher: Skipping invalid ignore glob '{}': {}", glob, e);
         }
+    }
 
-        false
+    builder.build().context("Failed to build file watcher ignore rules")
+}
+
+/// Check one settings ignore glob (gitignore syntax) before it is saved
+pub fn validate_ignore_glob(glob: &str) -> Result<()> {
+    GitignoreBuilder::new("")
+        .add_line(None, glob)
+        .with_context(|| format!("Invalid file watcher ignore glob '{}'", glob))?;
+    Ok(())
+}
+
+/// Check a path (absolute under the workspace, or workspace-relative) against the rules
+fn should_ignore(ignore: &Gitignore, path: &Path) -> bool {
+    let relative = path.strip_prefix(ignore.path()).unwrap_or(path);
+    if relative.has_root() {
+        // Outside the workspace: not ours to filter
+        return false;
     }
+
+    ignore
+        .matched_path_or_any_parents(relative, path.is_dir())
+        .is_ignore()
 }
 
 #[cfg(test)]
@@ -199,16 +253,85 @@ mod tests {
 
     #[test]
     fn test_should_ignore() {
-        assert!(FileWatcher::should_ignore(Path::new(".git/HEAD")));
-        assert!(FileWatcher::should_ignore(Path::new("node_modules/foo")));
-        assert!(FileWatcher::should_ignore(Path::new("target/debug/main")));
-        assert!(FileWatcher::should_ignore(Path::new("test.log")));
-        assert!(FileWatcher::should_ignore(Path::new("test.tmp")));
-        assert!(FileWatcher::should_ignore(Path::new("file~")));
-
-        assert!(!FileWatcher::should_ignore(Path::new("src/main.rs")));
-        assert!(!FileWatcher::should_ignore(Path::new("README.md")));
-        assert!(!FileWatcher::should_ignore(Path::new("package.json")));
+        let temp_dir = tempfile::tempdir().unwrap();
+        let watcher = FileWatcher::new(temp_dir.path().to_str().unwrap()).unwrap();
+
+        assert!(watcher.should_ignore(Path::new(".git/HEAD")));
+        assert!(watcher.should_ignore(Path::new("node_modules/foo")));
+        assert!(watcher.should_ignore(Path::new("target/debug/main")));
+        assert!(watcher.should_ignore(Path::new("crates/core/target/debug/main")));
+        assert!(watcher.should_ignore(Path::new("test.log")));
+        assert!(watcher.should_ignore(Path::new("test.tmp")));
+        assert!(watcher.should_ignore(Path::new("file~")));
+        assert!(watcher.should_ignore(&watcher.workspace_path.join("dist/app.js")));
+
+        assert!(!watcher.should_ignore(Path::new("src/main.rs")));
+        assert!(!watcher.should_ignore(Path::new("README.md")));
+        assert!(!watcher.should_ignore(Path::new("package.json")));
+        // Previously matched by substring ("out", "build", "target")
+        assert!(!watcher.should_ignore(Path::new("src/layout.rs")));
+        assert!(!watcher.should_ignore(Path::new("src/rebuild.rs")));
+        assert!(!watcher.should_ignore(Path::new("src/target.rs")));
+    }
+
+    #[test]
+    fn test_gitignore_and_extra_globs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join(".gitignore"), "generated/\n*.bak\n!keep.log\n").unwrap();
+
+        let watcher = FileWatcher::new(temp_dir.path().to_str().unwrap())
+            .unwrap()
+            .with_ignore_globs(&["fixtures/**/*.snap".to_string()]);
+
+        assert!(watcher.should_ignore(Path::new("generated/schema.rs")));
+        assert!(watcher.should_ignore(Path::new("notes.bak")));
+        assert!(!watcher.should_ignore(Path::new("keep.log"))); // .gitignore re-includes
+        assert!(watcher.should_ignore(Path::new("fixtures/a/b.snap")));
+        assert!(!watcher.should_ignore(Path::new("fixtures/a/b.rs")));
+    }
+
+    /// A malformed glob is skipped; the valid ones and the defaults still apply
+    #[test]
+    fn test_invalid_extra_glob_skipped() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let watcher = FileWatcher::new(temp_dir.path().to_str().unwrap())
+            .unwrap()
+            .with_ignore_globs(&["src/{a,b".to_string(), "*.snap".to_string()]);
+
+        assert!(watcher.should_ignore(Path::new("a.snap")));
+        assert!(watcher.should_ignore(Path::new("node_modules/x.js")));
+        assert!(!watcher.should_ignore(Path::new("src/main.rs")));
+        assert!(validate_ignore_glob("src/{a,b").is_err());
+        assert!(validate_ignore_glob("*.snap").is_ok());
+    }
+
+    /// End to end: writes under an ignored directory never reach the channel
+    #[tokio::test]
+    async fn test_ignored_directory_produces_no_update() {
+        use std::time::Duration;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("target/debug")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+
+        let watcher = FileWatcher::new(temp_dir.path().to_str().unwrap()).unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+        watcher.start(tx).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await; // watcher registered
+
+        std::fs::write(temp_dir.path().join("target/debug/main.o"), "artifact").unwrap();
+        std::fs::write(temp_dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+
+        let mut paths = Vec::new();
+        while let Ok(Some(update)) = tokio::time::timeout(Duration::from_millis(500), rx.recv()).await {
+            paths.push(match update {
+                FileUpdate::Created(p) | FileUpdate::Modified(p) | FileUpdate::Deleted(p) => p,
+            });
+        }
+
+        assert!(paths.iter().any(|p| p.ends_with("src/main.rs")), "{:?}", paths);
+        assert!(!paths.iter().any(|p| p.components().any(|c| c.as_os_str() == "target")), "{:?}", paths);
     }
 
     #[tokio::test]
@@ -9,20 +9,30 @@
  * 2. Git commands provide clean API (git status, git log)
  * 3. Polling every 5s acceptable latency (<100ms target for context updates)
  * 4. Detects: branch changes, new commits, staged/unstaged files
- * 5. Result: Reliable git state tracking without complex inotify logic
+ * 5. Diff each poll against the previous snapshot; emit only when something differs
+ * 6. On change, re-poll quickly until the repo settles so a rebase/commit burst
+ *    coalesces into one update instead of several half-finished ones
+ * 7. Result: Reliable git state tracking without complex inotify logic or redundant broadcasts
  *
  * PATTERN: Pattern-MONITOR-001 (Polling-Based State Monitor)
  * RELATED: GitContext, SystemContextProvider
  * PERFORMANCE: <50ms per poll, 5s interval, <10MB memory
  */
 
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tokio::sync::mpsc::Sender;
 use tokio::time::{Duration, interval};
 use anyhow::Result;
 
-use super::types::{GitUpdate, CommitInfo};
+use super::types::{GitUpdate, GitChangeSummary, CommitInfo};
+
+/// Poll interval while waiting for a burst of git operations to finish
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Give up waiting for quiet after this many settle polls (emit latest state)
+const MAX_SETTLE_POLLS: usize = 10;
 
 /**
  * GitMonitor - Polls git repository state
@@ -66,6 +76,10 @@ impl GitMonitor {
      * DESIGN DECISION: Spawn tokio task (non-blocking)
      * WHY: Monitor runs independently, sends updates via channel
      *
+     * First poll always emits (initial snapshot); later polls emit only when
+     * detect_change finds a difference, with the summary attached. A detected
+     * change is debounced: re-polled every SETTLE_INTERVAL until two polls agree.
+     *
      * @param tx - Channel to send GitUpdate events
      */
     pub async fn start(&self, tx: Sender<GitUpdate>) -> Result<()> {
@@ -73,19 +87,33 @@ impl GitMonitor {
 
         tokio::spawn(async move {
             let mut ticker = interval(Duration::from_secs(5));
+            let mut previous: Option<GitUpdate> = None;
 
             loop {
                 ticker.tick().await;
 
                 match Self::poll_git_status(&repo_path) {
-                    Ok(update) => {
+                    Ok(current) => {
+                        if previous.as_ref().is_some_and(|p| Self::same_state(p, &current)) {
+                            continue;
+                        }
+                        let current = Self::settle(current, SETTLE_INTERVAL, MAX_SETTLE_POLLS, || {
+                            Self::poll_git_status(&repo_path)
+                        })
+                        .await;
+
+                        let Some(update) = Self::detect_change(&repo_path, previous.as_ref(), current) else {
+                            continue;
+                        };
+                        previous = Some(update.clone());
+
                         if let Err(e) = tx.send(update).await {
-                            eprintln!("GitMonitor: Failed to send update: {}", e);
+                            tracing::warn!("GitMonitor: Failed to send update: {}", e);
                             break;
                         }
                     }
                     Err(e) => {
-                        eprintln!("GitMonitor: Poll failed: {}", e);
+                        tracing::warn!("GitMonitor: Poll failed: {}", e);
                     }
                 }
             }
@@ -179,8 +207,119 @@ impl GitMonitor {
             staged_files,
             unstaged_files,
             is_dirty,
+            changes: None,
         })
     }
+
+    /**
+     * Keep polling until two consecutive snapshots agree (or the poll budget runs out)
+     *
+     * DESIGN DECISION: Settle on quiet instead of emitting the first changed poll
+     * WHY: A poll landing mid-rebase would report an intermediate state, followed
+     * by another update a few seconds later
+     *
+     * Failed polls are skipped (the last good snapshot is kept).
+     */
+    async fn settle<F>(mut current: GitUpdate, interval: Duration, max_polls: usize, mut poll: F) -> GitUpdate
+    where
+        F: FnMut() -> Result<GitUpdate>,
+    {
+        for _ in 0..max_polls {
+            tokio::time::sleep(interval).await;
+            match poll() {
+                Ok(next) if Self::same_state(&current, &next) => break,
+                Ok(next) => current = next,
+                Err(e) => tracing::debug!("GitMonitor: Settle poll failed: {}", e),
+            }
+        }
+        current
+    }
+
+    /// Branch, commit window, staged and unstaged sets all identical
+    fn same_state(a: &GitUpdate, b: &GitUpdate) -> bool {
+        a.current_branch == b.current_branch
+            && a.staged_files == b.staged_files
+            && a.unstaged_files == b.unstaged_files
+            && a.recent_commits.len() == b.recent_commits.len()
+            && a.recent_commits.iter().zip(&b.recent_commits).all(|(x, y)| x.hash == y.hash)
+    }
+
+    /**
+     * Compare a fresh poll against the previous emitted snapshot
+     *
+     * DESIGN DECISION: Return None when nothing differs
+     * WHY: Idle repos otherwise broadcast an identical GitChanged every 5s
+     *
+     * @return current snapshot with `changes` filled in, or None if unchanged
+     */
+    fn detect_change(repo_path: &str, previous: Option<&GitUpdate>, mut current: GitUpdate) -> Option<GitUpdate> {
+        let Some(previous) = previous else {
+            return Some(current);
+        };
+
+        let summary = Self::summarize(repo_path, previous, &current);
+        if summary == GitChangeSummary::default() {
+            return None;
+        }
+
+        current.changes = Some(summary);
+        Some(current)
+    }
+
+    fn summarize(repo_path: &str, previous: &GitUpdate, current: &GitUpdate) -> GitChangeSummary {
+        let previous_branch = (previous.current_branch != current.current_branch)
+            .then(|| previous.current_branch.clone());
+
+        // REASONING CHAIN:
+        // 1. Previous HEAD still in the window → commits before it are new
+        // 2. Fell out of the window but still an ancestor → burst > window, ask git for the count
+        // 3. Not an ancestor (reset, amend, rebase) → flag rewrite, don't call the window "new"
+        // 4. Branch switched → commit delta between branches isn't meaningful
+        let (new_commits, history_rewritten) = match previous.recent_commits.first() {
+            _ if previous_branch.is_some() => (0, false),
+            None => (current.recent_commits.len(), false),
+            Some(head) => match current.recent_commits.iter().position(|c| c.hash == head.hash) {
+                Some(ahead) => (ahead, false),
+                None => match Self::commits_since(repo_path, &head.hash) {
+                    Some(ahead) => (ahead, false),
+                    None => (0, true),
+                },
+            },
+        };
+
+        let before: HashSet<&PathBuf> = previous.staged_files.iter().collect();
+        let after: HashSet<&PathBuf> = current.staged_files.iter().collect();
+        let unstaged_before: HashSet<&PathBuf> = previous.unstaged_files.iter().collect();
+        let unstaged_after: HashSet<&PathBuf> = current.unstaged_files.iter().collect();
+
+        GitChangeSummary {
+            previous_branch,
+            new_commits,
+            history_rewritten,
+            staged_added: after.difference(&before).map(|p| (*p).clone()).collect(),
+            staged_removed: before.difference(&after).map(|p| (*p).clone()).collect(),
+            unstaged_changed: unstaged_before != unstaged_after,
+        }
+    }
+
+    /// Commits on HEAD since `ancestor`, or None if `ancestor` is not reachable from HEAD
+    fn commits_since(repo_path: &str, ancestor: &str) -> Option<usize> {
+        let is_ancestor = Command::new("git")
+            .args(&["merge-base", "--is-ancestor", ancestor, "HEAD"])
+            .current_dir(repo_path)
+            .status()
+            .ok()?;
+        if !is_ancestor.success() {
+            return None;
+        }
+
+        let count = Command::new("git")
+            .args(&["rev-list", "--count", &format!("{}..HEAD", ancestor)])
+            .current_dir(repo_path)
+            .output()
+            .ok()?;
+        String::from_utf8(count.stdout).ok()?.trim().parse().ok()
+    }
 }
 
 #[cfg(test)]
@@ -260,4 +399,132 @@ mod tests {
         assert!(update.recent_commits.len() > 0);
         assert!(!update.is_dirty);
     }
+
+    fn git(repo_path: &str, args: &[&str]) {
+        Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .unwrap();
+    }
+
+    fn init_repo_with_commit(repo_path: &str) {
+        git(repo_path, &["init"]);
+        git(repo_path, &["config", "user.name", "Test"]);
+        git(repo_path, &["config", "user.email", "test@test.com"]);
+        std::fs::write(Path::new(repo_path).join("test.txt"), "test content").unwrap();
+        git(repo_path, &["add", "test.txt"]);
+        git(repo_path, &["commit", "-m", "Initial commit"]);
+    }
+
+    /**
+     * Test: Unchanged repository produces no update after the initial snapshot
+     */
+    #[tokio::test]
+    async fn test_unchanged_repo_produces_no_update() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_repo_with_commit(repo_path);
+
+        let first = GitMonitor::poll_git_status(repo_path).unwrap();
+        let initial = GitMonitor::detect_change(repo_path, None, first).unwrap();
+        assert!(initial.changes.is_none());
+
+        let second = GitMonitor::poll_git_status(repo_path).unwrap();
+        assert!(GitMonitor::detect_change(repo_path, Some(&initial), second).is_none());
+    }
+
+    /**
+     * Test: Burst of commits summarized as N new commits + staged delta
+     */
+    #[tokio::test]
+    async fn test_commit_burst_summarized() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_repo_with_commit(repo_path);
+
+        let initial = GitMonitor::detect_change(repo_path, None, GitMonitor::poll_git_status(repo_path).unwrap()).unwrap();
+
+        for i in 0..3 {
+            std::fs::write(Path::new(repo_path).join("test.txt"), format!("v{}", i)).unwrap();
+            git(repo_path, &["commit", "-am", &format!("Commit {}", i)]);
+        }
+        std::fs::write(Path::new(repo_path).join("new.txt"), "new").unwrap();
+        git(repo_path, &["add", "new.txt"]);
+
+        let update = GitMonitor::detect_change(repo_path, Some(&initial), GitMonitor::poll_git_status(repo_path).unwrap()).unwrap();
+        let changes = update.changes.unwrap();
+
+        assert_eq!(changes.new_commits, 3);
+        assert!(!changes.history_rewritten);
+        assert_eq!(changes.previous_branch, None);
+        assert_eq!(changes.staged_added, vec![Path::new(repo_path).join("new.txt")]);
+    }
+
+    /**
+     * Test: Burst larger than the polled window is counted, not flagged as rewrite
+     */
+    #[tokio::test]
+    async fn test_burst_beyond_window_counted() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_repo_with_commit(repo_path);
+
+        let initial = GitMonitor::detect_change(repo_path, None, GitMonitor::poll_git_status(repo_path).unwrap()).unwrap();
+
+        for i in 0..12 {
+            git(repo_path, &["commit", "--allow-empty", "-m", &format!("Commit {}", i)]);
+        }
+
+        let update = GitMonitor::detect_change(repo_path, Some(&initial), GitMonitor::poll_git_status(repo_path).unwrap()).unwrap();
+        let changes = update.changes.unwrap();
+
+        assert_eq!(changes.new_commits, 12);
+        assert!(!changes.history_rewritten);
+    }
+
+    /**
+     * Test: Amending HEAD reports a rewrite instead of a window of "new" commits
+     */
+    #[tokio::test]
+    async fn test_amend_flags_history_rewritten() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_repo_with_commit(repo_path);
+
+        let initial = GitMonitor::detect_change(repo_path, None, GitMonitor::poll_git_status(repo_path).unwrap()).unwrap();
+
+        git(repo_path, &["commit", "--amend", "-m", "Reworded initial commit"]);
+
+        let update = GitMonitor::detect_change(repo_path, Some(&initial), GitMonitor::poll_git_status(repo_path).unwrap()).unwrap();
+        let changes = update.changes.unwrap();
+
+        assert!(changes.history_rewritten);
+        assert_eq!(changes.new_commits, 0);
+    }
+
+    /**
+     * Test: Settling keeps polling until two snapshots agree, coalescing the burst
+     */
+    #[tokio::test]
+    async fn test_settle_coalesces_burst() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_repo_with_commit(repo_path);
+
+        let first = GitMonitor::poll_git_status(repo_path).unwrap();
+        let mut polls = 0;
+        let settled = GitMonitor::settle(first, Duration::from_millis(1), 10, || {
+            polls += 1;
+            if polls <= 3 {
+                git(repo_path, &["commit", "--allow-empty", "-m", &format!("Burst {}", polls)]);
+            }
+            GitMonitor::poll_git_status(repo_path)
+        })
+        .await;
+
+        // Three changing polls, then one confirming poll
+        assert_eq!(polls, 4);
+        assert_eq!(settled.recent_commits[0].message, "Burst 3");
+    }
 }
@@ -105,6 +105,16 @@ impl SystemContextProvider {
         })
     }
 
+    /**
+     * Add file watcher ignore globs (on top of defaults and the workspace .gitignore)
+     *
+     * Must be called before start(). Invalid globs are skipped (see FileWatcher::with_ignore_globs).
+     */
+    pub fn with_file_ignore_globs(mut self, globs: &[String]) -> Self {
+        self.file_watcher = self.file_watcher.with_ignore_globs(globs);
+        self
+    }
+
     /**
      * Start all monitors (non-blocking)
      *
@@ -79,6 +79,9 @@ pub struct GitContext {
     pub staged_files: Vec<PathBuf>,
     pub unstaged_files: Vec<PathBuf>,
     pub is_dirty: bool,
+    /// What changed since the previous snapshot (None for the initial snapshot)
+    #[serde(default)]
+    pub last_change: Option<GitChangeSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -161,6 +164,32 @@ pub enum ContextUpdate {
     VoiceRecording(RecordingState),
     /// Command to focus Voice panel in IDE (sent when user presses backtick)
     FocusVoicePanel,
+    /// Analytics event recorded (forwarded only to IPC clients subscribed with analytics: true)
+    EventRecorded(RecordedEvent),
+}
+
+/**
+ * RecordedEvent - Analytics event pushed to IDEs as it is recorded
+ *
+ * DESIGN DECISION: Carry the computed time saved, not just the event type
+ * WHY: IDEs show "you just saved 10 minutes" without knowing the per-type estimates
+ */
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecordedEvent {
+    /// Event type name ("voice_capture", "search", "insertion", "pattern_match")
+    pub event_type: String,
+    pub time_saved_minutes: i32,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<aetherlight_core::analytics::EventType> for RecordedEvent {
+    fn from(event_type: aetherlight_core::analytics::EventType) -> Self {
+        Self {
+            event_type: event_type.as_str().to_string(),
+            time_saved_minutes: event_type.time_saved_minutes(),
+            recorded_at: chrono::Utc::now(),
+        }
+    }
 }
 
 /**
@@ -173,6 +202,8 @@ pub struct GitUpdate {
     pub staged_files: Vec<PathBuf>,
     pub unstaged_files: Vec<PathBuf>,
     pub is_dirty: bool,
+    /// Difference from the previous poll (None for the initial snapshot)
+    pub changes: Option<GitChangeSummary>,
 }
 
 impl From<GitUpdate> for GitContext {
@@ -183,10 +214,41 @@ impl From<GitUpdate> for GitContext {
             staged_files: update.staged_files,
             unstaged_files: update.unstaged_files,
             is_dirty: update.is_dirty,
+            last_change: update.changes,
         }
     }
 }
 
+/**
+ * GitChangeSummary - Difference between two consecutive git snapshots
+ *
+ * DESIGN DECISION: Summarize the delta, not just the new state
+ * WHY: A rebase or scripted burst lands as one poll; consumers should see
+ * "12 new commits on main" rather than guess from a full snapshot
+ *
+ * EXAMPLE:
+ * - previous_branch: Some("main") → switched from main to current branch
+ * - new_commits: 3 (counted from previous HEAD, even past the polled window)
+ * - history_rewritten: true → previous HEAD no longer reachable (reset, amend, rebase)
+ * - staged_added: ["src/auth.rs"], staged_removed: []
+ */
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GitChangeSummary {
+    /// Branch before the switch (None if branch unchanged)
+    pub previous_branch: Option<String>,
+    /// Commits at HEAD not present in the previous snapshot (0 when history_rewritten)
+    pub new_commits: usize,
+    /// Previous HEAD is not an ancestor of the current HEAD on the same branch
+    #[serde(default)]
+    pub history_rewritten: bool,
+    /// Files newly staged since previous snapshot
+    pub staged_added: Vec<PathBuf>,
+    /// Files no longer staged since previous snapshot
+    pub staged_removed: Vec<PathBuf>,
+    /// Unstaged file set differs from previous snapshot
+    pub unstaged_changed: bool,
+}
+
 /**
  * FileUpdate - File system event
  */
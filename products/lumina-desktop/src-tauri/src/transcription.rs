@@ -17,10 +17,13 @@
  * PATTERN: Pattern-WHISPER-001 (OpenAI Whisper API Proxy)
  * PATTERN: Pattern-KEYBOARD-001 (OS-Level Typing)
  * PERFORMANCE: ~2-5s transcription + ~50ms/char typing (feels natural)
- * RELATED: voice.rs (audio capture), main.rs (hotkey handling), /api/desktop/transcribe (server endpoint)
+ * RELATED: voice.rs (audio capture), main.rs (hotkey handling), recording_flow.rs (service traits),
+ * /api/desktop/transcribe (server endpoint)
  */
 
+use crate::recording_flow::{BalanceChecker, TranscriptSink, Transcriber};
 use anyhow::{Context, Result};
+use futures_util::future::BoxFuture;
 use reqwest::multipart;
 use serde::{Deserialize, Serialize};
 use std::io::Write;
@@ -98,6 +101,7 @@ struct ApiErrorResponse {
 /// - 404: API endpoint missing → Log error, show support message
 /// - 500-599: Server error → Show retry dialog
 /// - Network: Connection failed → Show retry dialog with connectivity check
+/// - Timeout: Server did not answer within the HTTP timeouts (settings) → Show "server timed out, retrying"
 /// - Parse: Malformed response → Log error, show support message
 #[derive(Debug)]
 pub enum TranscriptionError {
@@ -121,6 +125,9 @@ pub enum TranscriptionError {
     NetworkError {
         message: String,
     },
+    Timeout {
+        message: String,
+    },
     ParseError {
         message: String,
     },
@@ -138,6 +145,7 @@ impl std::fmt::Display for TranscriptionError {
             Self::NotFound { message } => write!(f, "Not Found: {}", message),
             Self::ServerError { message } => write!(f, "Server Error: {}", message),
             Self::NetworkError { message } => write!(f, "Network Error: {}", message),
+            Self::Timeout { message } => write!(f, "Timeout: {}", message),
             Self::ParseError { message } => write!(f, "Parse Error: {}", message),
         }
     }
@@ -145,19 +153,31 @@ impl std::fmt::Display for TranscriptionError {
 
 impl std::error::Error for TranscriptionError {}
 
+/// Classify a failed request: timeouts are kept apart from other network failures
+fn request_error(context: &str, e: reqwest::Error) -> TranscriptionError {
+    if e.is_timeout() {
+        TranscriptionError::Timeout {
+            message: format!("{}: server did not respond in time ({})", context, e),
+        }
+    } else {
+        TranscriptionError::NetworkError {
+            message: format!("{}: {}", context, e),
+        }
+    }
+}
+
 /**
- * DESIGN DECISION: Send audio at native sample rate to OpenAI Whisper API
- * WHY: OpenAI Whisper API accepts various formats and handles conversion internally
+ * DESIGN DECISION: Send audio to OpenAI Whisper API with the correct sample rate in the header
+ * WHY: WAV header must describe the samples actually sent
  *
  * REASONING CHAIN:
  * 1. OpenAI Whisper API documentation says it accepts multiple audio formats
- * 2. The API performs its own preprocessing and resampling internally
- * 3. Problem was: We were labeling 44.1kHz audio as 16kHz in WAV header
- * 4. Solution: Create WAV with CORRECT sample rate in header
- * 5. Let OpenAI handle any necessary resampling on their end
+ * 2. Problem was: We were labeling 44.1kHz audio as 16kHz in WAV header
+ * 3. Solution: Create WAV with CORRECT sample rate in header
+ * 4. ServerTranscriber requires 16kHz: recording_flow resamples native-rate
+ *    capture before calling transcribe_audio (resample.rs)
  *
  * PATTERN: Pattern-AUDIO-002 (Correct WAV Header Metadata)
- * PERFORMANCE: Zero overhead - no resampling needed
  */
 
 /**
@@ -217,7 +237,11 @@ fn audio_to_wav(samples: &[f32], sample_rate: u32) -> Result<Vec<u8>> {
  *   - 403: Device not active
  *   - 500: Server error
  */
+/// Minimum balance to start a recording (375 tokens = 1 minute of transcription)
+pub const MIN_RECORDING_TOKENS: u64 = 375;
+
 pub async fn check_token_balance(
+    client: &reqwest::Client,
     license_key: &str,
     api_url: &str,
 ) -> Result<TokenBalanceResponse> {
@@ -229,14 +253,15 @@ pub async fn check_token_balance(
     let balance_endpoint = format!("{}/api/tokens/balance", api_url);
 
     // Send GET request to server API
-    println!("📊 Checking token balance...");
-    let client = reqwest::Client::new();
+    tracing::info!("Checking token balance...");
+    crate::rate_limit::server().acquire().await;
     let response = client
         .get(&balance_endpoint)
         .header("Authorization", format!("Bearer {}", license_key))
         .send()
         .await
-        .context("Failed to send balance request to server API")?;
+        // Keep the TranscriptionError so callers can downcast and spot timeouts
+        .map_err(|e| anyhow::Error::new(request_error("Failed to send balance request to server API", e)))?;
 
     // Check for API errors
     let status = response.status();
@@ -252,7 +277,7 @@ pub async fn check_token_balance(
         .await
         .context("Failed to parse balance response")?;
 
-    println!("✅ Balance: {} tokens (~{} minutes remaining)",
+    tracing::info!("Balance: {} tokens (~{} minutes remaining)",
              balance_response.tokens_balance,
              balance_response.minutes_remaining);
 
@@ -287,6 +312,7 @@ pub async fn check_token_balance(
  *   - 500: Server error
  */
 pub async fn transcribe_audio(
+    client: &reqwest::Client,
     audio_samples: &[f32],
     sample_rate: u32,
     license_key: &str,
@@ -299,14 +325,13 @@ pub async fn transcribe_audio(
     }
 
     // Convert audio to WAV format with CORRECT sample rate in header
-    // OpenAI Whisper API will handle any necessary resampling internally
-    println!("📊 Captured {} audio samples at {}Hz", audio_samples.len(), sample_rate);
-    println!("🔄 Converting {} samples to WAV format...", audio_samples.len());
+    tracing::info!("Captured {} audio samples at {}Hz", audio_samples.len(), sample_rate);
+    tracing::info!("Converting {} samples to WAV format...", audio_samples.len());
     let wav_bytes = audio_to_wav(audio_samples, sample_rate)
         .map_err(|e| TranscriptionError::ParseError {
             message: format!("Failed to convert audio to WAV: {}", e),
         })?;
-    println!("✅ WAV file created: {} bytes ({}Hz sample rate)", wav_bytes.len(), sample_rate);
+    tracing::info!("WAV file created: {} bytes ({}Hz sample rate)", wav_bytes.len(), sample_rate);
 
     // Create multipart form with audio file
     let form = multipart::Form::new()
@@ -326,17 +351,15 @@ pub async fn transcribe_audio(
     let transcription_endpoint = format!("{}/api/desktop/transcribe", api_url);
 
     // Send request to server API (proxies to OpenAI)
-    println!("📤 Sending audio to server API ({})", transcription_endpoint);
-    let client = reqwest::Client::new();
+    tracing::info!("Sending audio to server API ({})", transcription_endpoint);
+    crate::rate_limit::server().acquire().await;
     let response = client
         .post(&transcription_endpoint)
         .header("Authorization", format!("Bearer {}", license_key))
         .multipart(form)
         .send()
         .await
-        .map_err(|e| TranscriptionError::NetworkError {
-            message: format!("Connection failed: {}", e),
-        })?;
+        .map_err(|e| request_error("Connection failed", e))?;
 
     // Check for API errors
     let status = response.status();
@@ -384,8 +407,14 @@ pub async fn transcribe_audio(
     let transcription_response: TranscriptionResponse = response
         .json()
         .await
-        .map_err(|e| TranscriptionError::ParseError {
-            message: format!("Failed to parse server API response: {}", e),
+        .map_err(|e| {
+            if e.is_timeout() {
+                request_error("Reading transcription response", e)
+            } else {
+                TranscriptionError::ParseError {
+                    message: format!("Failed to parse server API response: {}", e),
+                }
+            }
         })?;
 
     // Check if transcription was successful
@@ -395,8 +424,8 @@ pub async fn transcribe_audio(
         });
     }
 
-    println!("✅ Transcription received: {} characters", transcription_response.text.len());
-    println!("💰 Tokens used: {}, Balance remaining: {} tokens",
+    tracing::info!("Transcription received: {} characters", transcription_response.text.len());
+    tracing::info!("Tokens used: {}, Balance remaining: {} tokens",
              transcription_response.tokens_used,
              transcription_response.tokens_balance);
 
@@ -424,7 +453,7 @@ pub fn type_transcript(transcript: &str) -> Result<()> {
     let mut enigo = Enigo::new(&Settings::default())
         .context("Failed to initialize keyboard simulator")?;
 
-    println!("⌨️  Typing transcript ({} chars) at cursor position...", transcript.len());
+    tracing::info!("Typing transcript ({} chars) at cursor position...", transcript.len());
 
     for ch in transcript.chars() {
         // Type the character
@@ -435,11 +464,84 @@ pub fn type_transcript(transcript: &str) -> Result<()> {
         thread::sleep(Duration::from_millis(5));
     }
 
-    println!("✅ Typing complete!");
+    tracing::info!("Typing complete!");
 
     Ok(())
 }
 
+/**
+ * Server API implementations of the recording flow services
+ *
+ * DESIGN DECISION: Thin wrappers holding the shared client + license key + API URL
+ * WHY: recording_flow stays free of settings and network; tests swap in mocks
+ */
+pub struct ServerBalanceChecker {
+    client: reqwest::Client,
+    license_key: String,
+    api_url: String,
+}
+
+impl ServerBalanceChecker {
+    pub fn new(client: reqwest::Client, license_key: impl Into<String>, api_url: impl Into<String>) -> Self {
+        Self { client, license_key: license_key.into(), api_url: api_url.into() }
+    }
+}
+
+impl BalanceChecker for ServerBalanceChecker {
+    fn check_balance(&self) -> BoxFuture<'_, Result<TokenBalanceResponse>> {
+        Box::pin(check_token_balance(&self.client, &self.license_key, &self.api_url))
+    }
+}
+
+/// Server-proxied Whisper transcription (see transcribe_audio)
+pub struct ServerTranscriber {
+    client: reqwest::Client,
+    license_key: String,
+    api_url: String,
+    sample_rate: u32,
+}
+
+impl ServerTranscriber {
+    pub fn new(client: reqwest::Client, license_key: impl Into<String>, api_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            license_key: license_key.into(),
+            api_url: api_url.into(),
+            sample_rate: crate::resample::WHISPER_SAMPLE_RATE,
+        }
+    }
+
+    /// Rate audio is resampled to before upload (defaults to Whisper's 16kHz)
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+}
+
+impl Transcriber for ServerTranscriber {
+    /// Whisper is trained on 16kHz audio unless settings chose another rate
+    fn required_sample_rate(&self) -> Option<u32> {
+        Some(self.sample_rate)
+    }
+
+    fn transcribe<'a>(
+        &'a self,
+        samples: &'a [f32],
+        sample_rate: u32,
+    ) -> BoxFuture<'a, Result<String, TranscriptionError>> {
+        Box::pin(transcribe_audio(&self.client, samples, sample_rate, &self.license_key, &self.api_url))
+    }
+}
+
+/// Types transcripts at the OS cursor (see type_transcript)
+pub struct KeyboardSink;
+
+impl TranscriptSink for KeyboardSink {
+    fn type_text(&self, text: &str) -> std::result::Result<(), String> {
+        type_transcript(text).map_err(|e| e.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -562,4 +664,40 @@ mod tests {
         assert_eq!(error.required_usd, 0.02);
         assert_eq!(error.message, "Please add credits to continue.");
     }
+
+    /// A hung server surfaces as TranscriptionError::Timeout within the configured bound
+    #[tokio::test]
+    async fn test_transcribe_times_out_on_slow_server() {
+        use crate::http::{test_server::slow_server, HttpClient};
+        use std::time::Instant;
+
+        let api_url = slow_server(Duration::from_secs(10)).await;
+        let timeout = Duration::from_millis(300);
+        let http = HttpClient::with_timeouts(Duration::from_secs(1), timeout).unwrap();
+        let samples = vec![0.0f32; 1600];
+
+        let start = Instant::now();
+        let result = transcribe_audio(&http.client(), &samples, 16000, "TEST-KEY", &api_url).await;
+        let elapsed = start.elapsed();
+
+        assert!(matches!(result, Err(TranscriptionError::Timeout { .. })), "{:?}", result);
+        // Bound + slack for the shared rate limiter and scheduling
+        assert!(elapsed < timeout + Duration::from_secs(1), "{:?}", elapsed);
+    }
+
+    /// Balance check timeouts stay distinguishable through anyhow
+    #[tokio::test]
+    async fn test_balance_check_timeout_is_downcastable() {
+        use crate::http::{test_server::slow_server, HttpClient};
+
+        let api_url = slow_server(Duration::from_secs(10)).await;
+        let http = HttpClient::with_timeouts(Duration::from_secs(1), Duration::from_millis(300)).unwrap();
+
+        let err = check_token_balance(&http.client(), "TEST-KEY", &api_url).await.unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<TranscriptionError>(),
+            Some(TranscriptionError::Timeout { .. })
+        ), "{:?}", err);
+    }
 }
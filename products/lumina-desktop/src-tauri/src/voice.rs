@@ -160,7 +160,7 @@ impl VoiceCapture {
             .config();
 
         // DEBUG: Print actual device sample rate
-        println!("🎤 Device native sample rate: {}Hz", config.sample_rate.0);
+        tracing::info!("Device native sample rate: {}Hz", config.sample_rate.0);
 
         Ok(Self {
             host,
@@ -190,11 +190,11 @@ impl VoiceCapture {
         let actual_device_config = self.device.default_input_config()
             .map_err(|e| VoiceError::ConfigError(e.to_string()))?;
 
-        println!("🔍 DEBUG Stream Config:");
-        println!("  - Stored config rate: {}Hz", config.sample_rate.0);
-        println!("  - Actual device config rate: {}Hz", actual_device_config.sample_rate().0);
-        println!("  - Sample format: {:?}", actual_device_config.sample_format());
-        println!("  - Channels: {}", actual_device_config.channels());
+        tracing::debug!("Stream Config:");
+        tracing::debug!("Stored config rate: {}Hz", config.sample_rate.0);
+        tracing::debug!("Actual device config rate: {}Hz", actual_device_config.sample_rate().0);
+        tracing::debug!("Sample format: {:?}", actual_device_config.sample_format());
+        tracing::debug!("Channels: {}", actual_device_config.channels());
 
         // Build stream based on sample format
         let stream = match actual_device_config.sample_format() {
@@ -278,7 +278,7 @@ impl VoiceCapture {
                     // Emit audio level event to frontend (non-blocking)
                     let _ = app_handle.emit("audio-level", rms);
                 },
-                |err| eprintln!("Audio stream error: {}", err),
+                |err| tracing::error!("Audio stream error: {}", err),
                 None, // No timeout
             )
             .map_err(|e| VoiceError::StreamError(e.to_string()))?;
@@ -310,6 +310,17 @@ impl VoiceCapture {
         (audio, self.actual_sample_rate)
     }
 
+    /// Native sample rate of the default input device (what the next recording captures at)
+    pub fn default_input_sample_rate() -> Result<u32> {
+        let device = cpal::default_host()
+            .default_input_device()
+            .ok_or(VoiceError::NoDevice)?;
+        let config = device
+            .default_input_config()
+            .map_err(|e| VoiceError::ConfigError(e.to_string()))?;
+        Ok(config.sample_rate().0)
+    }
+
     /**
      * DESIGN DECISION: List available input devices
      * WHY: Users may have multiple microphones (built-in, USB, Bluetooth)
@@ -378,6 +389,39 @@ pub fn stop_recording_global() -> (Vec<f32>, u32) {
     })
 }
 
+/**
+ * Microphone for the hotkey recording flow (recording_flow::AudioSource)
+ *
+ * Start and stop must run on the same thread: the capture lives in ACTIVE_CAPTURE.
+ */
+pub struct GlobalRecorder {
+    buffer: Arc<Mutex<Vec<f32>>>,
+    app_handle: tauri::AppHandle,
+}
+
+impl GlobalRecorder {
+    pub fn new(buffer: Arc<Mutex<Vec<f32>>>, app_handle: tauri::AppHandle) -> Self {
+        Self { buffer, app_handle }
+    }
+}
+
+impl crate::recording_flow::AudioSource for GlobalRecorder {
+    fn start(&self) -> std::result::Result<(), String> {
+        start_recording_global(Arc::clone(&self.buffer), self.app_handle.clone()).map_err(|e| e.to_string())
+    }
+
+    fn stop(&self) -> (Vec<f32>, u32) {
+        stop_recording_global()
+    }
+
+    fn discard(&self) {
+        let _ = stop_recording_global();
+        if let Ok(mut buffer) = self.buffer.lock() {
+            buffer.clear();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
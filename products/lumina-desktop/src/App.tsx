@@ -34,8 +34,25 @@ interface Settings {
   offline_mode: boolean;
   whisper_model: string;
   license_key: string;
+  analytics_retention_days?: number;
+  analytics_durability?: 'safe' | 'fast';
+  embedding_precision?: 'fp32' | 'int8';
+  transcription_sample_rate?: number;
 }
 
+interface AudioFormat {
+  capture_sample_rate: number;
+  transcription_sample_rate: number;
+}
+
+interface CompactionReport {
+  database: string;
+  reclaimed_bytes: number;
+}
+
+const formatBytes = (bytes: number) =>
+  bytes >= 1024 * 1024 ? `${(bytes / (1024 * 1024)).toFixed(1)} MB` : `${Math.round(bytes / 1024)} KB`;
+
 function App() {
   const [isFirstRun, setIsFirstRun] = useState<boolean | null>(null);
   const [currentView, setCurrentView] = useState<'voice' | 'settings' | 'dashboard' | 'invitations'>('voice');
@@ -52,6 +69,9 @@ function App() {
   const [isRecordingHotkey, setIsRecordingHotkey] = useState(false);
   const [isPasteHotkey, setIsPasteHotkey] = useState(false);
   const [saveStatus, setSaveStatus] = useState<'idle' | 'saving' | 'saved' | 'error'>('idle');
+  const [compacting, setCompacting] = useState(false);
+  const [compactResult, setCompactResult] = useState<string | null>(null);
+  const [audioFormat, setAudioFormat] = useState<AudioFormat | null>(null);
 
   // License activation hook (BUG-005)
   const {
@@ -76,6 +96,17 @@ function App() {
     invoke<Settings>('get_settings').then(setSettings).catch(console.error);
   }, []);
 
+  // Microphone rate → transcription rate, refreshed after each save
+  useEffect(() => {
+    if (saveStatus !== 'idle') return;
+    invoke<AudioFormat>('get_audio_format')
+      .then(setAudioFormat)
+      .catch((error) => {
+        console.error('Failed to read audio format:', error);
+        setAudioFormat(null);
+      });
+  }, [saveStatus]);
+
   /**
    * DESIGN DECISION: Always treat save_settings as successful if no exception thrown
    * WHY: Rust backend returns Ok(()) even with warnings (mouse button hotkeys)
@@ -103,6 +134,22 @@ function App() {
     }
   };
 
+  // Maintenance: VACUUM every local database, report space reclaimed
+  const compactDatabases = async () => {
+    setCompacting(true);
+    setCompactResult(null);
+    try {
+      const reports = await invoke<CompactionReport[]>('compact_databases');
+      const reclaimed = reports.reduce((sum, report) => sum + report.reclaimed_bytes, 0);
+      setCompactResult(`Reclaimed ${formatBytes(reclaimed)} across ${reports.length} databases`);
+    } catch (error) {
+      console.error('Failed to compact databases:', error);
+      setCompactResult(`Compaction failed: ${error}`);
+    } finally {
+      setCompacting(false);
+    }
+  };
+
   /**
    * DESIGN DECISION: Use fullscreen modal overlay for hotkey recording
    * WHY: Need to completely block all UI interactions while recording hotkey
@@ -565,6 +612,140 @@ function App() {
                     </button>
                   </div>
                 </div>
+
+                <div style={{ marginBottom: '24px' }}>
+                  <label style={{ display: 'block', marginBottom: '8px', color: '#374151', fontWeight: 500 }}>
+                    Analytics Retention (days)
+                  </label>
+                  <input
+                    type="number"
+                    min={1}
+                    value={settings.analytics_retention_days ?? 90}
+                    onChange={(e) => {
+                      const days = Math.max(1, parseInt(e.target.value, 10) || 1);
+                      setSettings(prev => ({ ...prev, analytics_retention_days: days }));
+                    }}
+                    style={{
+                      width: '100%',
+                      padding: '12px',
+                      border: '2px solid #e5e7eb',
+                      borderRadius: '8px',
+                      fontSize: '14px',
+                      boxSizing: 'border-box'
+                    }}
+                  />
+                  <p style={{ color: '#6b7280', fontSize: '12px', marginTop: '6px' }}>
+                    Older events are summarized into daily totals on startup; time-saved totals are kept.
+                  </p>
+                </div>
+
+                <div style={{ marginBottom: '24px' }}>
+                  <label style={{ display: 'block', marginBottom: '8px', color: '#374151', fontWeight: 500 }}>
+                    Analytics Durability
+                  </label>
+                  <select
+                    value={settings.analytics_durability ?? 'safe'}
+                    onChange={(e) => {
+                      const durability = e.target.value as 'safe' | 'fast';
+                      setSettings(prev => ({ ...prev, analytics_durability: durability }));
+                    }}
+                    style={{
+                      width: '100%',
+                      padding: '12px',
+                      border: '2px solid #e5e7eb',
+                      borderRadius: '8px',
+                      fontSize: '14px',
+                      boxSizing: 'border-box'
+                    }}
+                  >
+                    <option value="safe">Safe (every event survives a power loss)</option>
+                    <option value="fast">Fast (lower write latency, last events may be lost on power loss)</option>
+                  </select>
+                </div>
+
+                <div style={{ marginBottom: '24px' }}>
+                  <label style={{ display: 'block', marginBottom: '8px', color: '#374151', fontWeight: 500 }}>
+                    Embedding Model Precision
+                  </label>
+                  <select
+                    value={settings.embedding_precision ?? 'fp32'}
+                    onChange={(e) => {
+                      const precision = e.target.value as 'fp32' | 'int8';
+                      setSettings(prev => ({ ...prev, embedding_precision: precision }));
+                    }}
+                    style={{
+                      width: '100%',
+                      padding: '12px',
+                      border: '2px solid #e5e7eb',
+                      borderRadius: '8px',
+                      fontSize: '14px',
+                      boxSizing: 'border-box'
+                    }}
+                  >
+                    <option value="fp32">FP32 (full precision)</option>
+                    <option value="int8">Int8 (smaller, uses FP32 if the int8 model is missing)</option>
+                  </select>
+                  <p style={{ color: '#6b7280', fontSize: '12px', marginTop: '6px' }}>
+                    Rebuild the pattern index after changing precision.
+                  </p>
+                </div>
+
+                <div style={{ marginBottom: '24px' }}>
+                  <label style={{ display: 'block', marginBottom: '8px', color: '#374151', fontWeight: 500 }}>
+                    Transcription Sample Rate
+                  </label>
+                  <select
+                    value={settings.transcription_sample_rate ?? 16000}
+                    onChange={(e) => {
+                      const rate = Number(e.target.value);
+                      setSettings(prev => ({ ...prev, transcription_sample_rate: rate }));
+                    }}
+                    style={{
+                      width: '100%',
+                      padding: '12px',
+                      border: '2px solid #e5e7eb',
+                      borderRadius: '8px',
+                      fontSize: '14px',
+                      boxSizing: 'border-box'
+                    }}
+                  >
+                    <option value={16000}>16 kHz (Whisper native, recommended)</option>
+                    <option value={22050}>22.05 kHz</option>
+                    <option value={44100}>44.1 kHz</option>
+                    <option value={48000}>48 kHz</option>
+                  </select>
+                  <p style={{ color: '#6b7280', fontSize: '12px', marginTop: '6px' }}>
+                    {audioFormat
+                      ? `Microphone captures at ${audioFormat.capture_sample_rate} Hz; recordings are sent at ${audioFormat.transcription_sample_rate} Hz.`
+                      : 'No microphone detected.'}
+                  </p>
+                </div>
+
+                <div style={{ marginBottom: '24px' }}>
+                  <label style={{ display: 'block', marginBottom: '8px', color: '#374151', fontWeight: 500 }}>
+                    Maintenance
+                  </label>
+                  <button
+                    onClick={compactDatabases}
+                    disabled={compacting}
+                    style={{
+                      width: '100%',
+                      padding: '12px',
+                      background: 'white',
+                      color: '#374151',
+                      border: '2px solid #e5e7eb',
+                      borderRadius: '8px',
+                      fontSize: '14px',
+                      fontWeight: 500,
+                      cursor: compacting ? 'not-allowed' : 'pointer'
+                    }}
+                  >
+                    {compacting ? 'Compacting...' : 'Compact Databases'}
+                  </button>
+                  <p style={{ color: '#6b7280', fontSize: '12px', marginTop: '6px' }}>
+                    {compactResult ?? 'Reclaims disk space left behind by deleted patterns and pruned analytics.'}
+                  </p>
+                </div>
               </div>
             )}
 
@@ -25,6 +25,11 @@ import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import MetricsCard from './MetricsCard';
 
+interface DomainMetrics {
+  total_events: number;
+  total_time_saved_minutes: number;
+}
+
 interface UsageMetrics {
   period: string;
   total_events: number;
@@ -34,6 +39,7 @@ interface UsageMetrics {
   searches: number;
   insertions: number;
   pattern_matches: number;
+  by_domain: Record<string, DomainMetrics>;
 }
 
 type Period = 'daily' | 'weekly' | 'monthly' | 'all_time';
@@ -239,6 +245,26 @@ export default function Dashboard() {
         </div>
       </div>
 
+      {/* Per-domain Breakdown (only when events carry domain metadata) */}
+      {Object.keys(metrics.by_domain).length > 0 && (
+        <div className="metrics-breakdown">
+          <h2>Time Saved by Domain</h2>
+          <div className="breakdown-grid">
+            {Object.entries(metrics.by_domain)
+              .sort(([, a], [, b]) => b.total_time_saved_minutes - a.total_time_saved_minutes)
+              .map(([domain, domainMetrics]) => (
+                <div className="breakdown-item" key={domain}>
+                  <span className="breakdown-label">{domain}</span>
+                  <span className="breakdown-value">{domainMetrics.total_events}</span>
+                  <span className="breakdown-time">
+                    {domainMetrics.total_time_saved_minutes} min saved
+                  </span>
+                </div>
+              ))}
+          </div>
+        </div>
+      )}
+
       {/* Refresh indicator */}
       <div className="refresh-indicator">
         <span className="refresh-text">
@@ -20,6 +20,7 @@
 
 import { useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import '../styles/InstallationWizard.css';
 
 interface InstallationState {
@@ -31,9 +32,36 @@ interface InstallationState {
   licenseError: string | null;
   isProvisioning: boolean;
   provisioningProgress: number;
+  syncProgress: SyncProgress | null;
+  syncWarning: string | null;
   error: string | null;
 }
 
+/** Payload of the `sync-progress` event (pattern_sync.rs) */
+interface SyncProgress {
+  synced: number;
+  total: number;
+  phase: 'fetching' | 'filtering' | 'inserting';
+}
+
+/** Result of sync_initial_patterns (also the `sync-complete` payload) */
+interface SyncSummary {
+  stored: number;
+  failed_ids: string[];
+  /** Sync stopped early because the storage quota is full */
+  quota_exceeded: boolean;
+}
+
+/** Payload of the `setup-step` event (first_run.rs), in execution order */
+type SetupStep = 'provision_storage' | 'setup_metadata' | 'configure_network' | 'sync_patterns';
+
+interface SetupStepEvent {
+  step: SetupStep;
+  status: 'started' | 'skipped' | 'completed';
+}
+
+const SETUP_STEPS: SetupStep[] = ['provision_storage', 'setup_metadata', 'configure_network', 'sync_patterns'];
+
 const AVAILABLE_DOMAINS = [
   { id: 'marketing', name: 'Marketing & Analytics', description: 'Customer segmentation, campaign optimization' },
   { id: 'legal', name: 'Legal & Compliance', description: 'Case management, document analysis' },
@@ -61,6 +89,8 @@ export default function InstallationWizard({ onComplete }: { onComplete: () => v
     licenseError: null,
     isProvisioning: false,
     provisioningProgress: 0,
+    syncProgress: null,
+    syncWarning: null,
     error: null,
   });
 
@@ -82,44 +112,75 @@ export default function InstallationWizard({ onComplete }: { onComplete: () => v
   };
 
   const startProvisioning = async () => {
-    updateState({ isProvisioning: true, provisioningProgress: 0, error: null });
+    updateState({ isProvisioning: true, provisioningProgress: 0, syncProgress: null, syncWarning: null, error: null });
+
+    // run_first_run_setup runs provision (Storage-001) → metadata (Storage-003) →
+    // network (Storage-002) → sync (Storage-004), skipping steps a failed attempt completed
+    const sync: { summary: SyncSummary | null } = { summary: null };
+    const unlisteners = await Promise.all([
+      listen<SetupStepEvent>('setup-step', (event) => {
+        const index = SETUP_STEPS.indexOf(event.payload.step);
+        if (event.payload.status !== 'completed') {
+          updateState({ provisioningProgress: (index + 1) * 20 });
+        }
+      }),
+      // Sync: 80-100% driven by sync-progress
+      listen<SyncProgress>('sync-progress', (event) => {
+        const { synced, total, phase } = event.payload;
+        const fraction = phase === 'inserting' && total > 0 ? synced / total : 0;
+        updateState({ syncProgress: event.payload, provisioningProgress: 80 + Math.floor(fraction * 19) });
+      }),
+      listen<SyncSummary>('sync-complete', (event) => {
+        sync.summary = event.payload;
+      }),
+    ]);
 
     try {
-      // Step 1: Provision PostgreSQL schema (Storage-001)
-      updateState({ provisioningProgress: 20 });
-      await invoke('provision_postgresql', {
-        storageMb: state.storageMB,
-      });
-
-      // Step 2: Setup SQLite metadata tables (Storage-003)
-      updateState({ provisioningProgress: 40 });
-      await invoke('setup_sqlite_metadata');
-
-      // Step 3: Configure Code.NET pattern network (Storage-002)
-      updateState({ provisioningProgress: 60 });
-      await invoke('configure_pattern_network', {
-        domains: state.selectedDomains,
-      });
-
-      // Step 4: Sync initial patterns (Storage-004)
-      updateState({ provisioningProgress: 80 });
-      await invoke('sync_initial_patterns', {
-        domains: state.selectedDomains,
-        storageMb: state.storageMB,
-      });
-
-      // Complete
+      try {
+        await invoke('run_first_run_setup', {
+          config: { storage_mb: state.storageMB, domains: state.selectedDomains },
+        });
+      } finally {
+        unlisteners.forEach((unlisten) => unlisten());
+      }
+
+      // No summary when sync already completed in an earlier attempt
+      const failedIds = sync.summary?.failed_ids ?? [];
+      const stored = sync.summary?.stored ?? 0;
+      const quotaExceeded = sync.summary?.quota_exceeded ?? false;
+
+      // Partial sync still completes setup; re-running sync retries only the failed patterns
+      if (failedIds.length > 0) {
+        updateState({
+          syncWarning: `${stored} patterns synced, ${failedIds.length} failed (${failedIds.slice(0, 3).join(', ')}${failedIds.length > 3 ? ', ...' : ''}). They will be retried on the next sync.`,
+        });
+      } else if (quotaExceeded) {
+        updateState({
+          syncWarning: `${stored} patterns synced before your storage quota filled up. Free up space or upgrade to sync the rest.`,
+        });
+      }
+
+      // Complete (partial results stay on screen a little longer)
+      const warned = failedIds.length > 0 || quotaExceeded;
       updateState({ provisioningProgress: 100 });
       setTimeout(() => {
         onComplete();
-      }, 1000);
+      }, warned ? 4000 : 1000);
     } catch (error) {
       console.error('Provisioning failed:', error);
-      updateState({
-        error: `Installation failed: ${error}`,
-        isProvisioning: false,
-      });
+      // Stay on the progress screen: it offers Retry / Back to Configuration
+      updateState({ error: `Installation failed: ${error}` });
+    }
+  };
+
+  // Changing choices means starting setup over: undo the partial attempt first
+  const backToConfiguration = async () => {
+    try {
+      await invoke('reset_setup');
+    } catch (error) {
+      console.error('Failed to reset setup:', error);
     }
+    updateState({ currentStep: 3, error: null, isProvisioning: false });
   };
 
   const renderWelcome = () => (
@@ -380,7 +441,7 @@ export default function InstallationWizard({ onComplete }: { onComplete: () => v
         </label>
         <input
           type="text"
-          placeholder="XXXX-XXXX-XXXX-XXXX"
+          placeholder="AL-XXXX-XXXX-XXXX-XXXX"
           value={state.licenseKey}
           onChange={(e) => updateState({ licenseKey: e.target.value, licenseError: null })}
           style={{
@@ -520,18 +581,32 @@ export default function InstallationWizard({ onComplete }: { onComplete: () => v
 
         <div className={`provision-step ${state.provisioningProgress >= 80 ? 'complete' : ''}`}>
           <span className="step-icon">{state.provisioningProgress >= 80 ? '✓' : '⏳'}</span>
-          <span>Syncing {state.selectedDomains.length} domain patterns...</span>
+          <span>
+            Syncing {state.selectedDomains.length} domain patterns...
+            {state.syncProgress?.phase === 'fetching' && ' downloading'}
+            {state.syncProgress?.phase === 'filtering' && ` filtering ${state.syncProgress.total}`}
+            {state.syncProgress?.phase === 'inserting' &&
+              ` ${state.syncProgress.synced} / ${state.syncProgress.total}`}
+          </span>
         </div>
       </div>
 
+      {state.syncWarning && (
+        <div className="error-message">
+          <div className="error-icon">⚠️</div>
+          <p>{state.syncWarning}</p>
+        </div>
+      )}
+
       {state.error && (
         <div className="error-message">
           <div className="error-icon">❌</div>
           <p>{state.error}</p>
-          <button
-            className="wizard-button secondary"
-            onClick={() => updateState({ currentStep: 3, error: null })}
-          >
+          {/* Retry resumes after the last completed step */}
+          <button className="wizard-button primary" onClick={startProvisioning}>
+            Retry
+          </button>
+          <button className="wizard-button secondary" onClick={backToConfiguration}>
             Back to Configuration
           </button>
         </div>
@@ -13,7 +13,7 @@
  * 6. On error (400/403/404/500) → Show error message with retry button
  *
  * Error Handling:
- * - 400: Invalid request → "Check license key format (XXXX-XXXX-XXXX-XXXX)"
+ * - 400: Invalid request → "Check license key format (AL-XXXX-XXXX-XXXX-XXXX)"
  * - 404: Invalid license key → "Key not found - check dashboard"
  * - 403: Already activated → "Deactivate other device first"
  * - 500: Server error → "Try again later or contact support"
@@ -21,7 +21,7 @@
  *
  * UX Design:
  * - Dark theme (#1e1e1e background) matches desktop app aesthetic
- * - Large, clear input field with placeholder "XXXX-XXXX-XXXX-XXXX"
+ * - Large, clear input field with placeholder "AL-XXXX-XXXX-XXXX-XXXX"
  * - Error messages in red (#ef4444) with icon
  * - Loading state during activation ("Activating..." with disabled button)
  * - Help text with link to dashboard
@@ -133,7 +133,7 @@ export const LicenseActivationDialog: React.FC<LicenseActivationDialogProps> = (
           </label>
           <input
             type="text"
-            placeholder="XXXX-XXXX-XXXX-XXXX"
+            placeholder="AL-XXXX-XXXX-XXXX-XXXX"
             value={licenseKey}
             onChange={(e) => {
               setLicenseKey(e.target.value);
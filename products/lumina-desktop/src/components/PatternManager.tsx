@@ -31,9 +31,34 @@ interface Pattern {
   usage_count: number;
   created_at: string;
   updated_at: string;
+  version?: number; // Incremented on every edit (see get_pattern_history)
+  estimated_time_saved_minutes?: number | null; // Credited per match in analytics (default 10)
   status: 'draft' | 'approved' | 'rejected' | 'needs_review';
 }
 
+// Fields sent to update_pattern_fields; omitted fields keep their stored value,
+// null clears confidence_score / estimated_time_saved_minutes
+type PatternPatch = {
+  [K in 'name' | 'description' | 'domain' | 'confidence_score' | 'tags' | 'estimated_time_saved_minutes']?:
+    Pattern[K] | null;
+};
+
+const PATCHABLE_FIELDS = [
+  'name', 'description', 'domain', 'confidence_score', 'tags', 'estimated_time_saved_minutes',
+] as const;
+
+// Only the fields the editor changed, so concurrent edits to other fields survive.
+// A cleared field is sent as explicit null (undefined would be dropped from the JSON).
+function patternPatch(original: Pattern | undefined, updated: Pattern): PatternPatch {
+  const patch: Record<string, unknown> = {};
+  for (const field of PATCHABLE_FIELDS) {
+    if (!original || JSON.stringify(original[field]) !== JSON.stringify(updated[field])) {
+      patch[field] = updated[field] ?? null;
+    }
+  }
+  return patch as PatternPatch;
+}
+
 interface SearchFilters {
   query: string;
   domain?: string;
@@ -125,14 +150,19 @@ export default function PatternManager() {
   const handleSavePattern = async (updatedPattern: Pattern) => {
     try {
       setError(null);
-      await invoke('update_pattern', { pattern: updatedPattern });
+      const original = patterns.find(p => p.id === updatedPattern.id);
+      const version = await invoke<number>('update_pattern_fields', {
+        patternId: updatedPattern.id,
+        changes: patternPatch(original, updatedPattern),
+      });
+      const saved = { ...updatedPattern, version };
 
       // Update local state
       setPatterns(patterns.map(p =>
-        p.id === updatedPattern.id ? updatedPattern : p
+        p.id === saved.id ? saved : p
       ));
 
-      setSelectedPattern(updatedPattern);
+      setSelectedPattern(saved);
       setEditMode(false);
 
       // Success notification (could add toast here)
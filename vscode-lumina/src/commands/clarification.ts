@@ -0,0 +1,78 @@
+/**
+ * Clarification Handler - Multi-turn voice commands from the desktop app
+ *
+ * DESIGN DECISION: Quick pick for suggested answers, input box for free-form ones
+ * WHY: The desktop asks follow-up questions ("What client name would you like?")
+ * that nobody answered; the session expired and the command was lost
+ *
+ * REASONING CHAIN:
+ * 1. Desktop pushes clarificationNeeded (question + suggested options)
+ * 2. Options → quick pick (plus "Other..." for a typed answer); none → input box
+ * 3. Answer sent back with answerClarification (same session id)
+ * 4. Dismissed prompt → no answer, the desktop session expires on its own
+ * 5. conversationComplete → tell the user the command is ready
+ *
+ * PATTERN: Pattern-IPC-002 (Unified IPC Protocol), Pattern-CONVERSATION-001
+ * RELATED: IPC client (client.ts), desktop ipc_server/conversation.rs
+ *
+ * @module commands/clarification
+ */
+
+import * as vscode from 'vscode';
+import { IPCClient } from '../ipc/client';
+import { ClarificationNeeded, ConversationComplete } from '../ipc/protocol';
+
+/** Quick pick entry for typing an answer that is not among the options */
+const OTHER_ANSWER = 'Other...';
+
+/**
+ * Ask the user a clarification question
+ *
+ * @param clarification - Question pushed by the desktop
+ * @returns The answer, or undefined if the user dismissed the prompt
+ */
+async function askClarification(clarification: ClarificationNeeded): Promise<string | undefined> {
+	if (clarification.options.length > 0) {
+		const choice = await vscode.window.showQuickPick([...clarification.options, OTHER_ANSWER], {
+			title: 'Lumina voice command',
+			placeHolder: clarification.question,
+			ignoreFocusOut: true
+		});
+		if (choice !== OTHER_ANSWER) {
+			return choice;
+		}
+	}
+
+	return vscode.window.showInputBox({
+		title: 'Lumina voice command',
+		prompt: clarification.question,
+		ignoreFocusOut: true
+	});
+}
+
+/**
+ * Answer desktop follow-up questions for voice commands
+ *
+ * @param ipcClient - Connected IPC client
+ */
+export function registerClarificationHandler(ipcClient: IPCClient): void {
+	ipcClient.onClarification(
+		async (clarification: ClarificationNeeded) => {
+			const answer = await askClarification(clarification);
+			if (answer === undefined) {
+				console.log(`Clarification dismissed (session ${clarification.session_id})`);
+				return;
+			}
+
+			try {
+				ipcClient.answerClarification(clarification.session_id, answer);
+			} catch (error: any) {
+				vscode.window.showErrorMessage(`Failed to answer voice command: ${error.message}`);
+			}
+		},
+		(complete: ConversationComplete) => {
+			console.log('Voice command complete:', complete.function_id, complete.result);
+			vscode.window.showInformationMessage(`Voice command ready: ${complete.function_id}`);
+		}
+	);
+}
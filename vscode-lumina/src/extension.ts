@@ -32,6 +32,7 @@ import { spawn, ChildProcess } from 'child_process';
 import * as path from 'path';
 import { IPCClient } from './ipc/client';
 import { registerCaptureVoiceCommand } from './commands/captureVoice';
+import { registerClarificationHandler } from './commands/clarification';
 import { checkAndSetupUserDocumentation } from './firstRunSetup';
 import { ResourceSyncManager } from './services/ResourceSyncManager';
 import { syncResourcesCommand } from './commands/syncResources';
@@ -603,6 +604,9 @@ export async function activate(context: vscode.ExtensionContext): Promise<void>
 			);
 		});
 
+		// Follow-up questions for voice commands (missing parameter, confirmation)
+		registerClarificationHandler(ipcClient);
+
 		/**
 		 * DESIGN DECISION: Sync VS Code settings to desktop app on connection
 		 * WHY: User configures API key once in VS Code, desktop app uses it automatically
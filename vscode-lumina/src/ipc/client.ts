@@ -30,7 +30,20 @@ import {
 	isVoiceStatus,
 	isContextUpdate,
 	isFocusVoicePanel,
+	isEventRecorded,
+	isClarificationNeeded,
+	isConversationComplete,
+	isPong,
+	isHelloAck,
+	isUnsupportedVersion,
 	generateMessageId,
+	Hello,
+	Subscribe,
+	EventRecorded,
+	ClarificationNeeded,
+	ClarificationAnswer,
+	ConversationComplete,
+	PROTOCOL_VERSION,
 	ErrorCode,
 	CodeContext
 } from './protocol';
@@ -45,6 +58,22 @@ export type StatusCallback = (status: VoiceStatus) => void;
  */
 export type ContextUpdateCallback = (update: ContextUpdate) => void;
 
+/**
+ * Callback for analytics events pushed by the desktop (requires subscribe({ analytics: true }))
+ */
+export type EventRecordedCallback = (event: EventRecorded) => void;
+
+/**
+ * Callbacks for multi-turn voice commands (follow-up question, completed call)
+ */
+export type ClarificationCallback = (clarification: ClarificationNeeded) => void;
+export type ConversationCompleteCallback = (complete: ConversationComplete) => void;
+
+/**
+ * Update streams requested with subscribe()
+ */
+export type SubscriptionOptions = Pick<Subscribe, 'git' | 'files' | 'docs' | 'analytics'>;
+
 /**
  * Callback for focus panel command (triggered by backtick hotkey)
  *
@@ -68,6 +97,16 @@ interface PendingRequest {
 	statusCallback?: StatusCallback;
 }
 
+/**
+ * In-flight version handshake (hello sent, waiting for helloAck/unsupportedVersion)
+ */
+interface PendingHandshake {
+	id: string;
+	resolve: () => void;
+	reject: (error: Error) => void;
+	timeout: NodeJS.Timeout;
+}
+
 /**
  * IPCClient - Manages WebSocket connection to Lumina desktop
  *
@@ -81,6 +120,11 @@ export class IPCClient {
 	private pendingRequests: Map<string, PendingRequest> = new Map();
 	private contextUpdateCallback: ContextUpdateCallback | null = null;
 	private focusPanelCallback: FocusPanelCallback | null = null;
+	private eventRecordedCallback: EventRecordedCallback | null = null;
+	private clarificationCallback: ClarificationCallback | null = null;
+	private conversationCompleteCallback: ConversationCompleteCallback | null = null;
+	private subscription: SubscriptionOptions | null = null;
+	private pendingHandshake: PendingHandshake | null = null;
 
 	/**
 	 * DESIGN DECISION: Store URL without immediate connection
@@ -102,9 +146,10 @@ export class IPCClient {
 	 * REASONING CHAIN:
 	 * 1. Create WebSocket instance
 	 * 2. Register event handlers (open, error, close, message)
-	 * 3. Return promise that resolves on successful connection
-	 * 4. Route incoming messages by type (response, status)
-	 * 5. Future: Add auto-reconnect logic here
+	 * 3. On open, send hello with PROTOCOL_VERSION
+	 * 4. Resolve on helloAck; reject on unsupportedVersion (desktop closes the socket)
+	 * 5. Route incoming messages by type (response, status)
+	 * 6. Future: Add auto-reconnect logic here
 	 *
 	 * FUTURE: Exponential backoff for reconnection attempts
 	 *
@@ -122,9 +167,8 @@ export class IPCClient {
 			this.ws = new WebSocket(this.url);
 
 			this.ws.on('open', () => {
-				console.log('Connected to Lumina desktop');
-				this.connected = true;
-				resolve();
+				console.log('Connected to Lumina desktop, negotiating protocol version...');
+				this.startHandshake(resolve, reject);
 			});
 
 			this.ws.on('error', (error) => {
@@ -138,6 +182,7 @@ export class IPCClient {
 			this.ws.on('close', () => {
 				console.log('Disconnected from Lumina desktop');
 				this.connected = false;
+				this.failHandshake(new Error('Connection closed during protocol handshake'));
 				// Reject all pending requests
 				this.pendingRequests.forEach((pending, id) => {
 					clearTimeout(pending.timeout);
@@ -165,6 +210,10 @@ export class IPCClient {
 					const message: IPCMessage = JSON.parse(data.toString());
 					console.log('Received message from desktop:', message);
 
+					if (this.pendingHandshake && this.handleHandshakeReply(message)) {
+						return;
+					}
+
 					if (isCaptureVoiceResponse(message)) {
 						// Response: resolve pending request
 						const pending = this.pendingRequests.get(message.id);
@@ -186,6 +235,24 @@ export class IPCClient {
 						if (this.contextUpdateCallback) {
 							this.contextUpdateCallback(message);
 						}
+					} else if (isEventRecorded(message)) {
+						// Analytics push: only arrives after subscribe({ analytics: true })
+						if (this.eventRecordedCallback) {
+							this.eventRecordedCallback(message);
+						}
+					} else if (isClarificationNeeded(message)) {
+						// Follow-up question for a voice command (answer with answerClarification)
+						if (this.clarificationCallback) {
+							this.clarificationCallback(message);
+						} else {
+							console.warn('ClarificationNeeded received but no callback registered');
+						}
+					} else if (isConversationComplete(message)) {
+						if (this.conversationCompleteCallback) {
+							this.conversationCompleteCallback(message);
+						}
+					} else if (isPong(message)) {
+						// Acknowledges ping/subscribe; nothing waits on it
 					} else if (isFocusVoicePanel(message)) {
 						// Focus panel command: show and focus Voice panel
 						console.log('Received FocusVoicePanel command from desktop (backtick pressed)');
@@ -204,6 +271,83 @@ export class IPCClient {
 		});
 	}
 
+	/**
+	 * Send hello and wait for the desktop's verdict on our protocol version
+	 *
+	 * DESIGN DECISION: Connection counts as established only after helloAck
+	 * WHY: A rejected client would otherwise send requests into a closing socket
+	 */
+	private startHandshake(resolve: () => void, reject: (error: Error) => void): void {
+		const hello: Hello = {
+			id: generateMessageId(),
+			type: 'hello',
+			protocol_version: PROTOCOL_VERSION
+		};
+
+		const timeout = setTimeout(() => {
+			this.failHandshake(new Error('Timeout waiting for protocol handshake from desktop'));
+		}, 5000);
+
+		this.pendingHandshake = { id: hello.id, resolve, reject, timeout };
+		this.ws?.send(JSON.stringify(hello));
+	}
+
+	/**
+	 * Settle the pending handshake from a desktop reply
+	 *
+	 * REASONING CHAIN:
+	 * 1. helloAck → connected, resolve connect()
+	 * 2. unsupportedVersion → reject with both versions (desktop closes the socket)
+	 * 3. error → desktop predates the handshake (hello failed to parse), accept it
+	 *
+	 * @returns true if the message was a handshake reply
+	 */
+	private handleHandshakeReply(message: IPCMessage): boolean {
+		const pending = this.pendingHandshake;
+		if (!pending) {
+			return false;
+		}
+
+		if (isHelloAck(message)) {
+			console.log(`Protocol version ${message.protocol_version} accepted by Lumina desktop`);
+		} else if (isUnsupportedVersion(message)) {
+			this.failHandshake(new Error(
+				`Lumina desktop does not support protocol version ${message.requested} ` +
+				`(supported: ${message.supported.join(', ')}). Update the extension or desktop app.`
+			));
+			return true;
+		} else if ((message as { type: string }).type === 'error') {
+			console.log('Lumina desktop predates protocol handshake, continuing without negotiation');
+		} else {
+			return false;
+		}
+
+		clearTimeout(pending.timeout);
+		this.pendingHandshake = null;
+		this.connected = true;
+		// Subscriptions belong to the connection: restore them after a reconnect
+		if (this.subscription) {
+			this.sendSubscribe(this.subscription);
+		}
+		pending.resolve();
+		return true;
+	}
+
+	/**
+	 * Reject the pending handshake (no-op if none in flight)
+	 */
+	private failHandshake(error: Error): void {
+		const pending = this.pendingHandshake;
+		if (!pending) {
+			return;
+		}
+		clearTimeout(pending.timeout);
+		this.pendingHandshake = null;
+		this.connected = false;
+		pending.reject(error);
+		this.ws?.close();
+	}
+
 	/**
 	 * Send voice capture request to Lumina desktop
 	 *
@@ -267,6 +411,34 @@ export class IPCClient {
 		});
 	}
 
+	/**
+	 * Choose which pushed updates the desktop sends to this extension
+	 *
+	 * DESIGN DECISION: Remember the last subscription, re-send it on every connect
+	 * WHY: Desktop keeps subscriptions per connection; a reconnect starts unsubscribed
+	 *
+	 * @param options - Update streams to receive (eventRecorded requires analytics)
+	 */
+	public async subscribe(options: SubscriptionOptions): Promise<void> {
+		this.subscription = options;
+		if (!this.connected || this.ws?.readyState !== WebSocket.OPEN) {
+			// connect() sends the stored subscription once the handshake completes
+			await this.connect();
+			return;
+		}
+		this.sendSubscribe(options);
+	}
+
+	private sendSubscribe(options: SubscriptionOptions): void {
+		const request: Subscribe = {
+			id: generateMessageId(),
+			type: 'subscribe',
+			...options
+		};
+		this.ws?.send(JSON.stringify(request));
+		console.log(`Sent subscribe request (analytics: ${options.analytics})`);
+	}
+
 	/**
 	 * Check if client is connected to desktop
 	 *
@@ -321,6 +493,52 @@ export class IPCClient {
 		console.log('Focus panel callback registered');
 	}
 
+	/**
+	 * Register callback for analytics events (e.g. "you just saved 10 minutes")
+	 *
+	 * Events only arrive after subscribe({ ..., analytics: true }).
+	 *
+	 * @param callback - Function to call when an eventRecorded push arrives
+	 */
+	public onEventRecorded(callback: EventRecordedCallback): void {
+		this.eventRecordedCallback = callback;
+		console.log('Event recorded callback registered');
+	}
+
+	/**
+	 * Register callbacks for multi-turn voice commands
+	 *
+	 * @param onQuestion - Called with each follow-up question (render question + options)
+	 * @param onComplete - Called when the desktop has every parameter for the call
+	 */
+	public onClarification(onQuestion: ClarificationCallback, onComplete: ConversationCompleteCallback): void {
+		this.clarificationCallback = onQuestion;
+		this.conversationCompleteCallback = onComplete;
+		console.log('Clarification callbacks registered');
+	}
+
+	/**
+	 * Answer a ClarificationNeeded question
+	 *
+	 * The desktop replies with another ClarificationNeeded or a ConversationComplete,
+	 * delivered to the onClarification callbacks.
+	 *
+	 * @param sessionId - session_id from the ClarificationNeeded message
+	 * @param answer - Chosen option or free-form answer
+	 */
+	public answerClarification(sessionId: string, answer: string): void {
+		if (!this.isConnected()) {
+			throw new Error('Not connected to desktop');
+		}
+		const request: ClarificationAnswer = {
+			id: generateMessageId(),
+			type: 'clarificationAnswer',
+			session_id: sessionId,
+			answer
+		};
+		this.ws?.send(JSON.stringify(request));
+	}
+
 	/**
 	 * Disconnect from Lumina desktop
 	 *
@@ -328,6 +546,7 @@ export class IPCClient {
 	 * WHY: Prevent resource leaks, reject pending requests with clear error
 	 */
 	public disconnect(): void {
+		this.failHandshake(new Error('Disconnected'));
 		if (this.ws) {
 			this.ws.close();
 			this.ws = null;
@@ -31,6 +31,46 @@ export interface BaseMessage {
 	type: string;
 }
 
+/**
+ * Protocol version spoken by this extension
+ *
+ * DESIGN DECISION: Must match PROTOCOL_VERSION in the desktop's unified_protocol.rs
+ * WHY: Desktop rejects unknown versions with unsupportedVersion and closes the socket
+ */
+export const PROTOCOL_VERSION = 1;
+
+/**
+ * Request: Extension → Desktop - Announce protocol version (first message after connect)
+ *
+ * REASONING CHAIN:
+ * 1. Client connects and sends Hello with PROTOCOL_VERSION
+ * 2. Desktop replies HelloAck if it speaks that version
+ * 3. Otherwise desktop replies UnsupportedVersion and closes the connection
+ */
+export interface Hello extends BaseMessage {
+	type: 'hello';
+	protocol_version: number;
+}
+
+/**
+ * Response: Desktop → Extension - Protocol version accepted
+ */
+export interface HelloAck extends BaseMessage {
+	type: 'helloAck';
+	protocol_version: number;
+}
+
+/**
+ * Response: Desktop → Extension - Protocol version rejected (socket closed after send)
+ */
+export interface UnsupportedVersion extends BaseMessage {
+	type: 'unsupportedVersion';
+	/** Version the extension asked for */
+	requested: number;
+	/** Versions the desktop can speak (ascending) */
+	supported: number[];
+}
+
 /**
  * Code context extracted from VS Code editor
  *
@@ -158,6 +198,10 @@ export enum ErrorCode {
 	CONNECTION_ERROR = 'CONNECTION_ERROR',
 	/** Invalid message format */
 	INVALID_MESSAGE = 'INVALID_MESSAGE',
+	/** Desktop already recording (hotkey or another IDE request) */
+	RECORDING_IN_PROGRESS = 'RECORDING_IN_PROGRESS',
+	/** Token balance below one minute of transcription */
+	INSUFFICIENT_TOKENS = 'INSUFFICIENT_TOKENS',
 	/** Unknown error */
 	UNKNOWN = 'UNKNOWN'
 }
@@ -213,13 +257,118 @@ export interface FocusVoicePanel extends BaseMessage {
 	type: 'focusVoicePanel';
 }
 
+/**
+ * Request: Extension → Desktop - Choose which pushed updates this connection receives
+ *
+ * DESIGN DECISION: One message with a flag per stream, acknowledged with pong
+ * WHY: Subscriptions are per connection; re-sent after every reconnect
+ */
+export interface Subscribe extends BaseMessage {
+	type: 'subscribe';
+	git: boolean;
+	files: boolean;
+	docs: boolean;
+	/** Receive eventRecorded pushes (older desktops ignore the flag) */
+	analytics: boolean;
+}
+
+/**
+ * Push: Desktop → Extension - Analytics event recorded (requires Subscribe.analytics)
+ *
+ * DESIGN DECISION: Carry the computed time saved, not just the event type
+ * WHY: Extension shows "you just saved 10 minutes" without knowing per-type estimates
+ */
+export interface EventRecorded extends BaseMessage {
+	type: 'eventRecorded';
+	/** 'voice_capture' | 'search' | 'insertion' | 'pattern_match' */
+	event_type: string;
+	time_saved_minutes: number;
+	/** ISO 8601 UTC */
+	recorded_at: string;
+}
+
+/**
+ * Push: Desktop → Extension - Follow-up question for a voice command
+ *
+ * DESIGN DECISION: Desktop drives the conversation, extension only renders it
+ * WHY: Session state (missing params, pending confirmation) lives in the conversation manager
+ *
+ * REASONING CHAIN:
+ * 1. captureVoice transcript matches a function but is missing a parameter
+ * 2. Desktop pushes ClarificationNeeded after the CaptureVoiceResponse
+ * 3. Extension shows question + options, replies with ClarificationAnswer
+ * 4. Desktop pushes the next question, or replies ConversationComplete
+ */
+export interface ClarificationNeeded extends BaseMessage {
+	type: 'clarificationNeeded';
+	session_id: string;
+	question: string;
+	/** Suggested answers (may be empty for free-form answers) */
+	options: string[];
+}
+
+/**
+ * Request: Extension → Desktop - User's answer to a ClarificationNeeded question
+ */
+export interface ClarificationAnswer extends BaseMessage {
+	type: 'clarificationAnswer';
+	session_id: string;
+	answer: string;
+}
+
+/**
+ * Response: Desktop → Extension - Function call complete, ready to execute
+ */
+export interface ConversationComplete extends BaseMessage {
+	type: 'conversationComplete';
+	session_id: string;
+	function_id: string;
+	/** { status, function, parameters } */
+	result: Record<string, unknown>;
+}
+
+/**
+ * Response: Desktop → Extension - Reply to ping and acknowledgement of subscribe
+ */
+export interface Pong extends BaseMessage {
+	type: 'pong';
+}
+
 /**
  * Union type of all possible messages
  *
  * DESIGN DECISION: Discriminated union for type narrowing
  * WHY: TypeScript can narrow types based on 'type' field
  */
-export type IPCMessage = CaptureVoiceRequest | CaptureVoiceResponse | VoiceStatus | ContextUpdate | FocusVoicePanel;
+export type IPCMessage =
+	| Hello
+	| HelloAck
+	| UnsupportedVersion
+	| CaptureVoiceRequest
+	| CaptureVoiceResponse
+	| VoiceStatus
+	| ContextUpdate
+	| FocusVoicePanel
+	| Subscribe
+	| EventRecorded
+	| ClarificationNeeded
+	| ClarificationAnswer
+	| ConversationComplete
+	| Pong;
+
+/**
+ * Type guard: Check if message is HelloAck
+ */
+export function isHelloAck(msg: IPCMessage): msg is HelloAck {
+	return msg.type === 'helloAck';
+}
+
+/**
+ * Type guard: Check if message is UnsupportedVersion
+ */
+export function isUnsupportedVersion(msg: IPCMessage): msg is UnsupportedVersion {
+	return msg.type === 'unsupportedVersion';
+}
 
 /**
  * Type guard: Check if message is CaptureVoiceRequest
@@ -256,6 +405,34 @@ export function isFocusVoicePanel(msg: IPCMessage): msg is FocusVoicePanel {
 	return msg.type === 'focusVoicePanel';
 }
 
+/**
+ * Type guard: Check if message is EventRecorded
+ */
+export function isEventRecorded(msg: IPCMessage): msg is EventRecorded {
+	return msg.type === 'eventRecorded';
+}
+
+/**
+ * Type guard: Check if message is ClarificationNeeded
+ */
+export function isClarificationNeeded(msg: IPCMessage): msg is ClarificationNeeded {
+	return msg.type === 'clarificationNeeded';
+}
+
+/**
+ * Type guard: Check if message is ConversationComplete
+ */
+export function isConversationComplete(msg: IPCMessage): msg is ConversationComplete {
+	return msg.type === 'conversationComplete';
+}
+
+/**
+ * Type guard: Check if message is Pong
+ */
+export function isPong(msg: IPCMessage): msg is Pong {
+	return msg.type === 'pong';
+}
+
 /**
  * Generate unique message ID (UUID v4)
  *